@@ -1,8 +1,10 @@
-use super::{Command, Representation};
-use clap::Args;
+use super::{Command, Content, Representation};
+use clap::{Args, ValueEnum};
 use objio::{ObjectWriter};
 use rdftk_core::{error::Error};
-use rdftk_io::{dot};
+use rdftk_core::model::graph::Graph;
+use rdftk_io::{dot, format::Document, mermaid, plantuml};
+use rdftk_iri::IriPrefixMap;
 use std::process::ExitCode;
 
 // ------------------------------------------------------------------------------------------------
@@ -15,15 +17,22 @@ use std::process::ExitCode;
 
 #[derive(Args, Debug)]
 pub(crate) struct DrawCmd {
+    /// Representation of the input, if not provided this is chosen from the input file's
+    /// extension, or its content
     #[arg(short = 'f', long)]
     #[arg(value_enum)]
-    from: Representation,
+    from: Option<Representation>,
 
     /// Input SDML file name to read from, or '-' to read from stdin
     #[arg(short, long)]
     #[clap(value_parser, default_value = "-")]
     input: clio::Input,
 
+    /// Diagram format to write
+    #[arg(long)]
+    #[arg(value_enum, default_value_t = DiagramFormat::Dot)]
+    format: DiagramFormat,
+
     /// File name to write to, or '-' to write to stdout
     #[arg(short, long)]
     #[clap(value_parser, default_value = "-")]
@@ -42,20 +51,70 @@ pub(crate) struct DrawCmd {
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+enum DiagramFormat {
+    Dot,
+    Mermaid,
+    #[value(name = "plantuml")]
+    PlantUml,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
 impl Command for DrawCmd {
     fn execute(mut self) -> Result<ExitCode, Error> {
-        if self.from.is_dataset_representation() {
-            eprintln!("Currently unable to draw diagrams of datasets.");
-            Ok(ExitCode::FAILURE)
-        } else {
-            let graph = super::read_graph(&mut self.input, self.from)?;
-            let writer = dot::DotWriter::default();
-            writer.write(&mut self.output, &graph)?;
-            Ok(ExitCode::SUCCESS)
+        let document = match super::read_content(&mut self.input, self.from)? {
+            Content::Formula(formula) => {
+                Document::Graph(super::formula_to_graph(formula, self.format.name())?)
+            }
+            Content::Document(document) => document,
+        };
+        match (document, self.format) {
+            (Document::DataSet(data_set), DiagramFormat::Dot) => {
+                let writer = dot::DotWriter::default();
+                writer.write(&mut self.output, &data_set)?;
+            }
+            (Document::DataSet(data_set), format) => {
+                // only the dot format has clusters, so named graphs are drawn as one.
+                let mut mappings = IriPrefixMap::default();
+                let mut graph = Graph::default();
+                for named in data_set.graphs() {
+                    for (prefix, iri) in named.prefix_mappings().mappings() {
+                        mappings.insert(prefix.clone(), iri.clone());
+                    }
+                    graph.extend(named.statements().cloned());
+                }
+                graph.set_prefix_mappings(mappings);
+                self.write_graph(&graph, format)?;
+            }
+            (Document::Graph(graph), format) => self.write_graph(&graph, format)?,
+        }
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+impl DiagramFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            DiagramFormat::Dot => dot::NAME,
+            DiagramFormat::Mermaid => mermaid::NAME,
+            DiagramFormat::PlantUml => plantuml::NAME,
+        }
+    }
+}
+
+impl DrawCmd {
+    fn write_graph(&mut self, graph: &Graph, format: DiagramFormat) -> Result<(), Error> {
+        match format {
+            DiagramFormat::Dot => dot::DotWriter::default().write(&mut self.output, graph),
+            DiagramFormat::Mermaid => {
+                mermaid::MermaidWriter::default().write(&mut self.output, graph)
+            }
+            DiagramFormat::PlantUml => {
+                plantuml::PlantUmlWriter::default().write(&mut self.output, graph)
+            }
         }
     }
 }
@@ -1,10 +1,12 @@
 use clap::{Subcommand, ValueEnum};
 use objio::{ObjectReader, ObjectWriter};
 use rdftk_core::{
-    error::Error,
-    model::{data_set::DataSet, graph::Graph},
+    error::{formulae_not_supported_error, unknown_format_error, Error},
+    model::{data_set::DataSet, formulae::Formula, graph::Graph},
 };
-use rdftk_io::{json, nq, nt, trig, turtle, xml};
+use rdftk_io::format::{Document, Format};
+use rdftk_io::n3::{N3Reader, N3Writer};
+use std::io::{BufRead, BufReader};
 use std::process::ExitCode;
 
 // ------------------------------------------------------------------------------------------------
@@ -38,6 +40,16 @@ pub(crate) enum Representation {
     Xml,
 }
 
+///
+/// The result of reading an input; N3 is read as a formula, so that it may be written back to
+/// N3 without loss, all other representations as a document.
+///
+#[derive(Debug)]
+pub(crate) enum Content {
+    Formula(Formula),
+    Document(Document),
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -64,9 +76,18 @@ impl Command for Commands {
     }
 }
 
-impl Representation {
-    pub(crate) fn is_dataset_representation(&self) -> bool {
-        matches!(self, Self::NQuads | Self::Trig)
+impl From<Representation> for Format {
+    fn from(representation: Representation) -> Self {
+        match representation {
+            Representation::Json => Format::Json,
+            Representation::JsonLd => Format::JsonLd,
+            Representation::N3 => Format::N3,
+            Representation::NQuads => Format::NQuads,
+            Representation::NTriples => Format::NTriples,
+            Representation::Trig => Format::TriG,
+            Representation::Turtle => Format::Turtle,
+            Representation::Xml => Format::Xml,
+        }
     }
 }
 
@@ -74,55 +95,43 @@ impl Representation {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-pub(crate) fn read_graph(
+///
+/// Read `input` using the representation `from` if provided, otherwise choose one using the
+/// input's file extension, or failing that its content.
+///
+pub(crate) fn read_content(
     input: &mut clio::Input,
-    from_repr: Representation,
-) -> Result<Graph, Error> {
-    let graph = match from_repr {
-        Representation::Json => {
-            let reader = json::JsonReader::default();
-            reader.read(input)?
-        }
-        Representation::JsonLd => todo!(),
-        Representation::N3 => todo!(),
-        Representation::NTriples => {
-            let reader = nt::NTripleReader::default();
-            reader.read(input)?
-        }
-        Representation::Turtle => {
-            let reader = turtle::TurtleReader::default();
-            reader.read(input)?
-        }
-        Representation::Xml => {
-            let reader = xml::XmlReader::default();
-            reader.read(input)?
-        }
-        Representation::NQuads | Representation::Trig => unreachable!(),
+    from_repr: Option<Representation>,
+) -> Result<Content, Error> {
+    let format = from_repr
+        .map(Format::from)
+        .or_else(|| Format::from_path(input.path().path()));
+    let mut reader = BufReader::new(input);
+    let format = match format {
+        Some(format) => format,
+        None => Format::sniff(reader.fill_buf()?).ok_or_else(|| unknown_format_error("input"))?,
     };
-    Ok(graph)
+    if format == Format::N3 {
+        Ok(Content::Formula(N3Reader::default().read(&mut reader)?))
+    } else {
+        Ok(Content::Document(format.read(&mut reader)?))
+    }
 }
 
-pub(crate) fn read_dataset(
-    input: &mut clio::Input,
-    from_repr: Representation,
-) -> Result<DataSet, Error> {
-    let graph = match from_repr {
-        Representation::NQuads => {
-            let reader = nq::NQuadReader::default();
-            reader.read(input)?
-        }
-        Representation::Trig => {
-            let reader = trig::TrigReader::default();
-            reader.read(input)?
-        }
-        Representation::Json
-        | Representation::JsonLd
-        | Representation::N3
-        | Representation::NTriples
-        | Representation::Turtle
-        | Representation::Xml => unreachable!(),
-    };
-    Ok(graph)
+///
+/// Convert `formula` to a graph to be written in `representation`, failing if it has variables
+/// or nested formulae which that representation cannot express.
+///
+pub(crate) fn formula_to_graph(formula: Formula, representation: &str) -> Result<Graph, Error> {
+    if formula.is_plain_graph() {
+        Ok(formula.into_graph())
+    } else {
+        Err(formulae_not_supported_error(representation))
+    }
+}
+
+pub(crate) fn write_formula(formula: &Formula, output: &mut clio::Output) -> Result<(), Error> {
+    N3Writer::default().write(output, formula)
 }
 
 pub(crate) fn write_graph(
@@ -130,35 +139,11 @@ pub(crate) fn write_graph(
     output: &mut clio::Output,
     to_repr: Representation,
 ) -> Result<(), Error> {
-    match to_repr {
-        Representation::Json => {
-            let writer = json::JsonWriter::default();
-            writer.write(output, graph)?;
-        }
-        Representation::JsonLd => todo!(),
-        Representation::N3 => todo!(),
-        Representation::NQuads => {
-            let writer = nq::NQuadWriter::default();
-            writer.write(output, graph)?;
-        }
-        Representation::NTriples => {
-            let writer = nt::NTripleWriter::default();
-            writer.write(output, graph)?;
-        }
-        Representation::Trig => {
-            let writer = trig::TrigWriter::default();
-            writer.write(output, graph)?;
-        }
-        Representation::Turtle => {
-            let writer = turtle::TurtleWriter::default();
-            writer.write(output, graph)?;
-        }
-        Representation::Xml => {
-            let writer = xml::XmlWriter::default();
-            writer.write(output, graph)?;
-        }
-    };
-    Ok(())
+    let format = Format::from(to_repr);
+    format
+        .graph_writer()
+        .ok_or_else(|| unknown_format_error(format.name()))?
+        .write_graph(output, graph)
 }
 
 pub(crate) fn write_dataset(
@@ -166,23 +151,11 @@ pub(crate) fn write_dataset(
     output: &mut clio::Output,
     to_repr: Representation,
 ) -> Result<(), Error> {
-    match to_repr {
-        Representation::NQuads => {
-            let writer = nq::NQuadWriter::default();
-            writer.write(output, dataset)?;
-        }
-        Representation::Trig => {
-            let writer = trig::TrigWriter::default();
-            writer.write(output, dataset)?;
-        }
-        Representation::Json
-        | Representation::JsonLd
-        | Representation::N3
-        | Representation::NTriples
-        | Representation::Turtle
-        | Representation::Xml => unreachable!(),
-    };
-    Ok(())
+    let format = Format::from(to_repr);
+    format
+        .data_set_writer()
+        .ok_or_else(|| unknown_format_error(format.name()))?
+        .write_data_set(output, dataset)
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -1,6 +1,7 @@
-use super::{Command, Representation};
+use super::{Command, Content, Representation};
 use clap::Args;
 use rdftk_core::error::Error;
+use rdftk_io::format::{Document, Format};
 use std::process::ExitCode;
 
 // ------------------------------------------------------------------------------------------------
@@ -18,9 +19,11 @@ pub(crate) struct ConvertCmd {
     #[clap(value_parser, default_value = "-")]
     output: clio::Output,
 
+    /// Representation of the input, if not provided this is chosen from the input file's
+    /// extension, or its content
     #[arg(short = 'f', long)]
     #[arg(value_enum)]
-    from: Representation,
+    from: Option<Representation>,
 
     /// Input SDML file name to read from, or '-' to read from stdin
     #[arg(short, long)]
@@ -51,9 +54,37 @@ pub(crate) struct ConvertCmd {
 
 impl Command for ConvertCmd {
     fn execute(mut self) -> Result<ExitCode, Error> {
-        let graph = super::read_graph(&mut self.input, self.from)?;
-        super::write_graph(&graph, &mut self.output, self.to)?;
-        Ok(ExitCode::SUCCESS)
+        match super::read_content(&mut self.input, self.from)? {
+            Content::Formula(formula) => {
+                if self.to == Representation::N3 {
+                    super::write_formula(&formula, &mut self.output)?;
+                } else {
+                    let graph = super::formula_to_graph(formula, Format::from(self.to).name())?;
+                    super::write_graph(&graph, &mut self.output, self.to)?;
+                }
+                Ok(ExitCode::SUCCESS)
+            }
+            Content::Document(Document::DataSet(data_set)) => {
+                if Format::from(self.to).is_data_set() {
+                    super::write_dataset(&data_set, &mut self.output, self.to)?;
+                    Ok(ExitCode::SUCCESS)
+                } else if data_set.graphs().all(|graph| !graph.is_named()) {
+                    // a data set with only a default graph, such as most JSON-LD documents.
+                    let graph = data_set.default_graph().cloned().unwrap_or_default();
+                    super::write_graph(&graph, &mut self.output, self.to)?;
+                    Ok(ExitCode::SUCCESS)
+                } else {
+                    eprintln!(
+                        "Currently unable to convert datasets with named graphs to a graph representation."
+                    );
+                    Ok(ExitCode::FAILURE)
+                }
+            }
+            Content::Document(Document::Graph(graph)) => {
+                super::write_graph(&graph, &mut self.output, self.to)?;
+                Ok(ExitCode::SUCCESS)
+            }
+        }
     }
 }
 
@@ -45,6 +45,24 @@ pub enum Error {
         given: String,
     },
     ///
+    /// This signals a syntax error in the source text of a representation, at a known location.
+    ///
+    Syntax {
+        representation: String,
+        message: String,
+        location: SourceLocation,
+    },
+    ///
+    /// This signals that a syntactically valid document could not be processed; `code` names
+    /// the failure, using the representation's own error codes where it defines them, and
+    /// `value` is the offending value.
+    ///
+    Processing {
+        representation: String,
+        code: String,
+        value: String,
+    },
+    ///
     /// The String value provided is not a valid value for it's type.
     ///
     InvalidFromStr {
@@ -111,7 +129,7 @@ pub enum Error {
         representation: String,
     },
     ///
-    /// Cited model.formulae, from N3, are not supported by this representation.
+    /// Cited formulae, from N3, are not supported by this representation.
     ///
     FormulaeNotSupported {
         representation: String,
@@ -122,6 +140,13 @@ pub enum Error {
     QueryResultsFormat {
         representation: String,
     },
+    ///
+    /// The serialization format could not be determined, or is not supported, for the named
+    /// file, MIME type, or content.
+    ///
+    UnknownFormat {
+        name: String,
+    },
 
     ///
     /// An error occurred borrowing from a standard cell type.
@@ -142,7 +167,7 @@ pub enum Error {
     ///
     /// An error parsing Name strings.
     ///
-    Name(::rdftk_iri::NameParseError),
+    Name(::rdftk_iri::error::NameParseError),
     ///
     /// An error occurred converting to UTF-8 text.
     ///
@@ -159,6 +184,20 @@ pub enum Error {
     Unknown(String),
 }
 
+///
+/// The location of an error within the source text being parsed; both `line` and `column` are
+/// 1-based, `column` counts characters, and `offset` is the 0-based byte offset from the start
+/// of the source. The text of the offending line is retained so that a diagnostic can be
+/// rendered without access to the original source.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    line: usize,
+    column: usize,
+    offset: usize,
+    source_line: String,
+}
+
 ///
 /// A Result type that specifically uses this crate's Error.
 ///
@@ -294,6 +333,50 @@ where
     }
 }
 
+///
+/// Create Error object.
+///
+#[inline(always)]
+pub fn unknown_format_error<S>(name: S) -> Error
+where
+    S: Into<String>,
+{
+    Error::UnknownFormat { name: name.into() }
+}
+
+///
+/// Create Error object.
+///
+#[inline(always)]
+pub fn syntax_error<S1, S2>(representation: S1, message: S2, location: SourceLocation) -> Error
+where
+    S1: Into<String>,
+    S2: Into<String>,
+{
+    Error::Syntax {
+        representation: representation.into(),
+        message: message.into(),
+        location,
+    }
+}
+
+///
+/// Create Error object.
+///
+#[inline(always)]
+pub fn processing_error<S1, S2, S3>(representation: S1, code: S2, value: S3) -> Error
+where
+    S1: Into<String>,
+    S2: Into<String>,
+    S3: Into<String>,
+{
+    Error::Processing {
+        representation: representation.into(),
+        code: code.into(),
+        value: value.into(),
+    }
+}
+
 #[inline(always)]
 pub fn unknown_error<E>(source: E) -> Error
 where
@@ -350,6 +433,13 @@ impl Display for Error {
                 Self::ParserExpected { rule_fn, expecting } => format!("Parser was expecting `{expecting}` in function `{rule_fn}`."),
                 Self::ParserUnexpected { rule_fn, given, expecting } => format!("Parser was not expecting `{given}` in function `{rule_fn}`; expecting {expecting:?}."),
                 Self::ParserUnreachable { rule_fn, given } => format!("Parser should not have reached `{given}` in function `{rule_fn}`."),
+                Self::Syntax { representation, message, location } => format!(
+                    "{representation} syntax error at line {}, column {}: {message}\n{}",
+                    location.line(), location.column(), location.diagnostic()
+                ),
+                Self::Processing { representation, code, value } => format!(
+                    "{representation} processing error, {code}: `{value}`."
+                ),
                 Self::InvalidFromStr { value, name } => format!(
                     "The String value `{value}` is not a valid value for it's type: '{name}'."
                 ),
@@ -370,8 +460,9 @@ impl Display for Error {
                 Self::InvalidState =>
                     "Some model element was in an invalid state for the requested operation.".to_string(),
                 Self::RdfStarNotSupported { representation } => format!("Statements as objects, from RDF*, are not supported by the {representation:?} representation."),
-                Self::FormulaeNotSupported { representation } => format!("Cited model.formulae, from N3, are not supported by the {representation:?} representation."),
+                Self::FormulaeNotSupported { representation } => format!("Cited formulae, from N3, are not supported by the {representation:?} representation."),
                 Self::QueryResultsFormat { representation } => format!("Could not read or write query results in the {representation:?} representation."),
+                Self::UnknownFormat { name } => format!("Could not determine a supported serialization format for {name:?}."),
                 Self::Borrow(source) => format!("A cell borrow error occurred; source: {source}"),
                 Self::Io(source) => format!("An I/O error occurred; source: {source}"),
                 Self::Iri(source) =>format!("An error occurred parsing an IRI; source: {source}"),
@@ -386,6 +477,36 @@ impl Display for Error {
     }
 }
 
+impl Error {
+    ///
+    /// Return the location in the source text at which this error occurred, if known.
+    ///
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::Syntax { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Attach `location` to this error, returning a `Syntax` error that describes the original
+    /// one. Errors that already have a location, and errors that report an unsupported feature
+    /// rather than a problem with the source text, are returned unchanged.
+    ///
+    pub fn with_location<S>(self, representation: S, location: SourceLocation) -> Self
+    where
+        S: Into<String>,
+    {
+        match self {
+            Self::Syntax { .. }
+            | Self::RdfStarNotSupported { .. }
+            | Self::FormulaeNotSupported { .. }
+            | Self::Io(_) => self,
+            _ => syntax_error(representation, self.to_string(), location),
+        }
+    }
+}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -420,14 +541,20 @@ impl From<::rdftk_iri::Error> for Error {
     }
 }
 
+impl From<::url::ParseError> for Error {
+    fn from(source: ::url::ParseError) -> Self {
+        Self::Iri(source.into())
+    }
+}
+
 impl From<::language_tags::ParseError> for Error {
     fn from(source: ::language_tags::ParseError) -> Self {
         Self::LanguageTag(source)
     }
 }
 
-impl From<::rdftk_iri::NameParseError> for Error {
-    fn from(source: ::rdftk_iri::NameParseError) -> Self {
+impl From<::rdftk_iri::error::NameParseError> for Error {
+    fn from(source: ::rdftk_iri::error::NameParseError) -> Self {
         Self::Name(source)
     }
 }
@@ -458,6 +585,146 @@ impl<T> Into<Result<T>> for Error {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl SourceLocation {
+    ///
+    /// Construct a new location; `line` and `column` are 1-based, `offset` is the byte offset
+    /// of the location and `source_line` the text of the line it falls on.
+    ///
+    pub fn new<S>(line: usize, column: usize, offset: usize, source_line: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            line,
+            column,
+            offset,
+            source_line: source_line
+                .into()
+                .trim_end_matches(['\r', '\n'])
+                .to_string(),
+        }
+    }
+
+    ///
+    /// Construct a location from a byte `offset` into `source`, computing the line, column and
+    /// line text. Offsets past the end of `source`, or not on a character boundary, are moved
+    /// back to the nearest valid position.
+    ///
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line_start = source[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = source[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or(source.len());
+        Self::new(
+            source[..line_start].matches('\n').count() + 1,
+            source[line_start..offset].chars().count() + 1,
+            offset,
+            &source[line_start..line_end],
+        )
+    }
+
+    ///
+    /// Construct a location from a 1-based `line` and `column` within `source`, computing the
+    /// byte offset and line text.
+    ///
+    pub fn from_line_column(source: &str, line: usize, column: usize) -> Self {
+        let line_start = if line <= 1 {
+            0
+        } else {
+            source
+                .match_indices('\n')
+                .nth(line - 2)
+                .map(|(i, _)| i + 1)
+                .unwrap_or(source.len())
+        };
+        let offset = source[line_start..]
+            .char_indices()
+            .take_while(|(_, c)| *c != '\n')
+            .nth(column.saturating_sub(1))
+            .map(|(i, _)| line_start + i)
+            .unwrap_or_else(|| {
+                source[line_start..]
+                    .find('\n')
+                    .map(|i| line_start + i)
+                    .unwrap_or(source.len())
+            });
+        Self::from_offset(source, offset)
+    }
+
+    ///
+    /// Return a copy of this location moved down by `lines` lines and on by `bytes` bytes; this
+    /// is used when a location was computed relative to a fragment of a larger source, such as
+    /// a single line.
+    ///
+    pub fn offset_by(self, lines: usize, bytes: usize) -> Self {
+        Self {
+            line: self.line + lines,
+            offset: self.offset + bytes,
+            ..self
+        }
+    }
+
+    ///
+    /// The 1-based line number.
+    ///
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    ///
+    /// The 1-based column number, in characters.
+    ///
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    ///
+    /// The 0-based byte offset from the start of the source.
+    ///
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    ///
+    /// The text of the line containing this location, without its line terminator.
+    ///
+    pub fn source_line(&self) -> &str {
+        &self.source_line
+    }
+
+    ///
+    /// Render a caret-style diagnostic, the offending line prefixed with its line number and
+    /// followed by a caret under the offending column.
+    ///
+    pub fn diagnostic(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent: String = self
+            .source_line
+            .chars()
+            .take(self.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        format!(
+            "{gutter} |\n{number} | {}\n{gutter} | {indent}^",
+            self.source_line
+        )
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
@@ -1,44 +1,92 @@
 /*!
-One-line description.
+This module provides types for the Notation3 (N3) *formula* concept.
 
-More detailed description, with
+1. A **formula** is a graph of statements together with a set of quantified variables.
+1. A **variable** is an IRI that is either universally (`@forAll`) or existentially (`@forSome`)
+   quantified within the formula that declares it.
+1. A **quoted formula**, written `{ ... }` in N3, is denoted within the statements of its parent
+   formula by a blank node; the parent holds the mapping from that blank node to the quoted formula.
 
 # Example
 
+The following represents the N3 rule `@forAll :x . { :x a :Man } => { :x a :Mortal } .`
+
+```rust
+use rdftk_core::model::formulae::{Formula, Variable};
+use rdftk_core::model::statement::{BlankNode, Statement};
+use rdftk_iri::Iri;
+use rdftk_names::rdf;
+use std::str::FromStr;
+
+let x = Iri::from_str("http://example.org/#x").unwrap();
+let man = Iri::from_str("http://example.org/#Man").unwrap();
+let mortal = Iri::from_str("http://example.org/#Mortal").unwrap();
+let implies = Iri::from_str("http://www.w3.org/2000/10/swap/log#implies").unwrap();
+
+let antecedent = BlankNode::generate();
+let consequent = BlankNode::generate();
+
+let rule = Formula::default()
+    .with_variable(Variable::for_all(x.clone()))
+    .with_formula(
+        antecedent.clone(),
+        Formula::default().with_statements(vec![
+            Statement::new(x.clone(), rdf::a_type().clone(), man),
+        ]),
+    )
+    .with_formula(
+        consequent.clone(),
+        Formula::default().with_statements(vec![
+            Statement::new(x.clone(), rdf::a_type().clone(), mortal),
+        ]),
+    )
+    .with_statements(vec![Statement::new(antecedent, implies, consequent)]);
+
+assert_eq!(rule.len(), 1);
+assert!(!rule.is_plain_graph());
+```
+
 */
 
-use rdftk_iri::{Iri, IriRef};
+use crate::model::graph::Graph;
+use crate::model::statement::{BlankNode, Statement};
+use rdftk_iri::{Iri, IriPrefixMap};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-use crate::statement::StatementRef;
-use rdftk_iri::IriRef;
-use std::collections::BTreeSet;
-use std::fmt::{Display, Formatter};
-use std::rc::Rc;
-
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Hash)]
+///
+/// The quantification of a formula variable.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Quantification {
     Universal,
     Existential,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A variable, identified by an IRI, quantified in the formula that declares it.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Variable {
     quantification: Quantification,
-    id: IriRef,
+    id: Iri,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A formula is a graph of statements along with any variables it declares and the quoted
+/// formulae that appear within its statements.
+///
+#[derive(Clone, Debug, Default)]
 pub struct Formula {
-    variables: BTreeSet<Variable>,
-    statements: BTreeSet<StatementRef>,
+    variables: Vec<Variable>,
+    graph: Graph,
+    formulae: HashMap<BlankNode, Formula>,
 }
 
-pub type FormulaRef = Rc<Formula>;
-
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -47,7 +95,7 @@ impl Display for Variable {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{} <{}> .",
+            "{} {} .",
             match self.quantification {
                 Quantification::Universal => "@forAll",
                 Quantification::Existential => "@forSome",
@@ -58,26 +106,32 @@ impl Display for Variable {
 }
 
 impl Variable {
-    pub fn universal(iri: IriRef) -> Self {
-        Self {
-            quantification: Quantification::Universal,
-            id: iri.clone(),
-        }
+    pub fn new(quantification: Quantification, id: Iri) -> Self {
+        Self { quantification, id }
     }
 
-    pub fn existential(iri: IriRef) -> Self {
-        Self {
-            quantification: Quantification::Existential,
-            id: iri.clone(),
-        }
+    pub fn universal(id: Iri) -> Self {
+        Self::new(Quantification::Universal, id)
     }
 
-    pub fn for_all(iri: IriRef) -> Self {
-        Self::universal(iri)
+    pub fn existential(id: Iri) -> Self {
+        Self::new(Quantification::Existential, id)
     }
 
-    pub fn for_some(iri: IriRef) -> Self {
-        Self::existential(iri)
+    pub fn for_all(id: Iri) -> Self {
+        Self::universal(id)
+    }
+
+    pub fn for_some(id: Iri) -> Self {
+        Self::existential(id)
+    }
+
+    pub fn quantification(&self) -> Quantification {
+        self.quantification
+    }
+
+    pub fn id(&self) -> &Iri {
+        &self.id
     }
 
     pub fn is_universal(&self) -> bool {
@@ -91,56 +145,180 @@ impl Variable {
 
 // ------------------------------------------------------------------------------------------------
 
-impl Display for Formula {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if !self.statements.is_empty() {
-            let vars: Vec<String> = self
-                .variables
-                .iter()
-                .filter_map(|v| {
-                    if v.quantification == Quantification::Universal {
-                        Some(v.to_string())
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            if !vars.is_empty() {
-                writeln!(f, "{}", vars.join("\n"))?;
-            }
-
-            let vars: Vec<String> = self
-                .variables
-                .iter()
-                .filter_map(|v| {
-                    if v.quantification == Quantification::Existential {
-                        Some(v.to_string())
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            if !vars.is_empty() {
-                writeln!(f, "{}", vars.join("\n"))?;
-            }
-
-            writeln!(f, "{{")?;
-            for st in &self.statements {
-                writeln!(f, "{} .", st)?;
-            }
-        } else {
-            write!(f, "{{}}")?;
+impl From<Graph> for Formula {
+    fn from(graph: Graph) -> Self {
+        Self {
+            graph,
+            ..Default::default()
         }
-        Ok(())
     }
 }
 
 impl Formula {
-    pub fn len(&self) -> usize {
-        self.statements.len()
+    // --------------------------------------------------------------------------------------------
+    // Constructors
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_variable(self, variable: Variable) -> Self {
+        let mut self_mut = self;
+        self_mut.add_variable(variable);
+        self_mut
+    }
+
+    pub fn with_statements(self, statements: Vec<Statement>) -> Self {
+        let mut self_mut = self;
+        self_mut.extend(statements);
+        self_mut
+    }
+
+    pub fn with_formula(self, node: BlankNode, formula: Formula) -> Self {
+        let mut self_mut = self;
+        self_mut.insert_formula(node, formula);
+        self_mut
     }
 
+    pub fn with_mappings(self, mappings: IriPrefixMap) -> Self {
+        let mut self_mut = self;
+        self_mut.set_prefix_mappings(mappings);
+        self_mut
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Access > Statements
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Returns `true` if there are no statements in this formula, else `false`.
+    ///
     pub fn is_empty(&self) -> bool {
-        self.statements.is_empty()
+        self.graph.is_empty()
+    }
+
+    ///
+    /// Return the number of statements in this formula, not including those in quoted formulae.
+    ///
+    pub fn len(&self) -> usize {
+        self.graph.len()
+    }
+
+    ///
+    /// Return an iterator over the statements in this formula.
+    ///
+    pub fn statements(&self) -> impl Iterator<Item = &Statement> {
+        self.graph.statements()
+    }
+
+    ///
+    /// Return the graph of statements in this formula.
+    ///
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    ///
+    /// Return a mutable reference to the graph of statements in this formula.
+    ///
+    pub fn graph_mut(&mut self) -> &mut Graph {
+        &mut self.graph
+    }
+
+    ///
+    /// Returns `true` if this formula declares no variables and contains no quoted formulae, and
+    /// so can be represented by its graph alone.
+    ///
+    pub fn is_plain_graph(&self) -> bool {
+        self.variables.is_empty() && self.formulae.is_empty()
+    }
+
+    ///
+    /// Consume this formula and return its graph; any variables and quoted formulae are
+    /// discarded.
+    ///
+    pub fn into_graph(self) -> Graph {
+        self.graph
+    }
+
+    pub fn prefix_mappings(&self) -> &IriPrefixMap {
+        self.graph.prefix_mappings()
+    }
+
+    pub fn set_prefix_mappings(&mut self, mappings: IriPrefixMap) {
+        self.graph.set_prefix_mappings(mappings);
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Access > Variables
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Return an iterator over the variables declared in this formula, in the order they were
+    /// declared.
+    ///
+    pub fn variables(&self) -> impl Iterator<Item = &Variable> {
+        self.variables.iter()
+    }
+
+    pub fn universals(&self) -> impl Iterator<Item = &Variable> {
+        self.variables.iter().filter(|v| v.is_universal())
+    }
+
+    pub fn existentials(&self) -> impl Iterator<Item = &Variable> {
+        self.variables.iter().filter(|v| v.is_existential())
+    }
+
+    pub fn has_variable(&self, id: &Iri) -> bool {
+        self.variables.iter().any(|v| v.id() == id)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Access > Formulae
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Return an iterator over the quoted formulae in this formula, along with the blank node
+    /// that denotes each within this formula's statements.
+    ///
+    pub fn formulae(&self) -> impl Iterator<Item = (&BlankNode, &Formula)> {
+        self.formulae.iter()
+    }
+
+    ///
+    /// Return the quoted formula denoted by `node`, if it exists.
+    ///
+    pub fn formula(&self, node: &BlankNode) -> Option<&Formula> {
+        self.formulae.get(node)
+    }
+
+    pub fn is_formula(&self, node: &BlankNode) -> bool {
+        self.formulae.contains_key(node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Mutators
+    // --------------------------------------------------------------------------------------------
+
+    pub fn insert(&mut self, statement: Statement) {
+        self.graph.insert(statement);
+    }
+
+    pub fn extend(&mut self, statements: Vec<Statement>) {
+        statements.into_iter().for_each(|st| self.insert(st))
+    }
+
+    ///
+    /// Declare a variable in this formula, this has no effect if a variable with the same
+    /// identifier has already been declared.
+    ///
+    pub fn add_variable(&mut self, variable: Variable) {
+        if !self.has_variable(variable.id()) {
+            self.variables.push(variable);
+        }
+    }
+
+    ///
+    /// Add a quoted formula, denoted by `node` within this formula's statements.
+    ///
+    pub fn insert_formula(&mut self, node: BlankNode, formula: Formula) {
+        let _ = self.formulae.insert(node, formula);
     }
 }
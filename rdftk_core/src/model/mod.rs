@@ -10,6 +10,7 @@ This implementation contains a full stack of components across multiple specific
 1. **ObjectNode** either an IRI, a blank node, a nested statement, or a literal.
 1. **BlankNode** an anonymous subject or object.
 1. **Literal** values that are the object of a statement.
+1. **Formula** a graph with quantified variables and quoted formulae, from Notation3.
 
 */
 
@@ -21,6 +22,9 @@ pub mod data_set;
 
 pub mod features;
 
+#[cfg(feature = "n3_formulae")]
+pub mod formulae;
+
 pub mod graph;
 
 pub mod literal;
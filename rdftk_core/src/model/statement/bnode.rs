@@ -1,4 +1,4 @@
-use rdftk_iri::{Name, NameParser, QName};
+use rdftk_iri::{LocalName, Name, Namespace};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use unique_id::sequence::SequenceGenerator as IDGenerator;
@@ -98,18 +98,36 @@ impl BlankNode {
         S: AsRef<str>,
     {
         let s: &str = s.as_ref();
-        NameParser::BlankNode.is_valid_str(if let Some(s) = s.strip_prefix(BLANK_NODE_PREFIX) {
+        Name::is_valid_str(if let Some(s) = s.strip_prefix(BLANK_NODE_PREFIX) {
             s
         } else {
             s
         })
     }
 
+    ///
+    /// Returns `true` if the string has the form of an identifier constructed by
+    /// [`BlankNode::generate`], else `false`. Readers use this to keep the labels in a document
+    /// apart from the identifiers they generate for anonymous nodes.
+    ///
+    pub fn is_generated_str<S>(s: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        s.as_ref()
+            .strip_prefix('B')
+            .map(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+            .unwrap_or_default()
+    }
+
     ///
     /// Return a qualified version of the blank node, i.e. with the reserved
     /// namespace value `"_"`.
     ///
-    pub fn to_qname(&self) -> QName {
-        QName::new_blank(Name::new_unchecked(&self.0)).unwrap()
+    pub fn to_qname(&self) -> LocalName {
+        LocalName::new(
+            Namespace::new_unchecked(BLANK_NODE_NAMESPACE),
+            Name::new_unchecked(&self.0),
+        )
     }
 }
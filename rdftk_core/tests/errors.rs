@@ -0,0 +1,53 @@
+use rdftk_core::error::{syntax_error, Error, SourceLocation};
+
+const SOURCE: &str = "first line\nsécond line\n\nlast";
+
+#[test]
+fn location_from_offset() {
+    let location = SourceLocation::from_offset(SOURCE, 19);
+    assert_eq!(location.line(), 2);
+    assert_eq!(location.column(), 8);
+    assert_eq!(location.offset(), 19);
+    assert_eq!(location.source_line(), "sécond line");
+}
+
+#[test]
+fn location_from_line_column() {
+    let location = SourceLocation::from_line_column(SOURCE, 2, 8);
+    assert_eq!(location, SourceLocation::from_offset(SOURCE, 19));
+
+    let location = SourceLocation::from_line_column(SOURCE, 4, 99);
+    assert_eq!(location.line(), 4);
+    assert_eq!(location.column(), 5);
+    assert_eq!(location.offset(), SOURCE.len());
+}
+
+#[test]
+fn location_offset_by() {
+    let location = SourceLocation::from_offset("one two", 4).offset_by(9, 120);
+    assert_eq!(location.line(), 10);
+    assert_eq!(location.column(), 5);
+    assert_eq!(location.offset(), 124);
+}
+
+#[test]
+fn syntax_error_diagnostic() {
+    let error = syntax_error(
+        "Example",
+        "unexpected `l`",
+        SourceLocation::from_offset(SOURCE, 19),
+    );
+    assert_eq!(
+        error.to_string(),
+        "Example syntax error at line 2, column 8: unexpected `l`\n  |\n2 | sécond line\n  |        ^"
+    );
+}
+
+#[test]
+fn with_location_keeps_existing_location() {
+    let located =
+        Error::InvalidMatch.with_location("Example", SourceLocation::from_offset(SOURCE, 0));
+    assert_eq!(located.location().unwrap().line(), 1);
+    let relocated = located.with_location("Example", SourceLocation::from_offset(SOURCE, 19));
+    assert_eq!(relocated.location().unwrap().line(), 1);
+}
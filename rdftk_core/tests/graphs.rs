@@ -3,7 +3,10 @@ use rdftk_core::model::{
     literal::Literal,
     statement::{BlankNode, Statement, SubjectNode},
 };
-use rdftk_iri::{Iri, IriPrefixMap, VOCABULARY_DC_TERMS, VOCABULARY_FOAF, VOCABULARY_RDF};
+use rdftk_iri::{
+    vocab::{VOCABULARY_DC_TERMS, VOCABULARY_FOAF, VOCABULARY_RDF},
+    Iri, IriPrefixMap,
+};
 use std::str::FromStr;
 
 pub fn tony_benn_graph() -> Graph {
@@ -0,0 +1,65 @@
+/*!
+Provides for reading and writing a `Graph` or `DataSet` instance in a compact binary format,
+intended for fast interchange between processes rather than for publication.
+
+The format is a stream of records, each a triple, a quad, or a prefix mapping. IRIs are split
+into a prefix and a local name, and these, along with literal datatypes, are kept in lookup
+tables so that each is written in full only once; any term that is the same as the term in
+the same position of the previous record is written as a single byte. Every kind of node is
+written directly, including collections and RDF-star statements, so no reification is needed.
+
+# Example
+
+```rust
+use rdftk_io::binary::{BinaryReader, BinaryWriter};
+# use objio::{ObjectReader, ObjectWriter};
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { Graph::default() }
+
+let mut buffer = Vec::default();
+BinaryWriter::default().write(&mut buffer, &make_graph()).unwrap();
+
+let graph: Graph = BinaryReader::default().read(&mut buffer.as_slice()).unwrap();
+```
+
+# Example Streaming Reader
+
+```rust,no_run
+use rdftk_io::binary::BinaryReader;
+# use std::fs::File;
+# use std::io::BufReader;
+
+let source = BufReader::new(File::open("statements.rdfb").unwrap());
+
+for quad in BinaryReader::default().quads(source) {
+    let (statement, graph_name) = quad.unwrap();
+    println!("{statement} {graph_name:?}");
+}
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "RDFtk Binary";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "rdfb";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/x-rdftk-binary";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod reader;
+pub use reader::{BinaryReader, BinaryStatements};
+
+mod syntax;
+
+mod writer;
+pub use writer::{BinarySink, BinaryWriter, BinaryWriterOptions};
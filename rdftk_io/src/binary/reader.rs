@@ -0,0 +1,428 @@
+use super::syntax::{
+    invalid_data, read_string, read_u8, read_u8_or_end, read_varint, MAGIC, NEW_ENTRY,
+    RECORD_NAMESPACE, RECORD_QUAD, RECORD_TRIPLE, TERM_BLANK, TERM_COLLECTION, TERM_IRI,
+    TERM_LANGUAGE_LITERAL, TERM_PLAIN_LITERAL, TERM_REPEAT, TERM_STATEMENT, TERM_TYPED_LITERAL,
+    VERSION,
+};
+use crate::common::relabel::Relabeler;
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, Collection, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap, Namespace};
+use std::io::{BufReader, Read};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the GraphReader trait, and the data set equivalent, for the binary
+/// format written by [`BinaryWriter`](super::BinaryWriter).
+///
+#[derive(Clone, Debug, Default)]
+pub struct BinaryReader {
+    options: ReaderOptions,
+}
+
+///
+/// An iterator over the statements in a binary stream, and the name of the graph each
+/// belongs to, returned by [`BinaryReader::quads`]. Prefix mappings are collected as they
+/// are read, see [`BinaryStatements::prefix_mappings`].
+///
+#[derive(Debug)]
+pub struct BinaryStatements<R> {
+    source: R,
+    started: bool,
+    pending: Option<u8>,
+    prefixes: Table,
+    names: Table,
+    datatypes: Table,
+    previous: Option<(SubjectNode, Iri, ObjectNode, Option<GraphName>)>,
+    mappings: IriPrefixMap,
+    relabel: Option<Relabeler>,
+    done: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The reader's copy of one of the writer's lookup tables.
+///
+#[derive(Debug, Default)]
+struct Table {
+    slots: Vec<String>,
+    capacity: usize,
+    next: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+/// Guards against allocating huge tables for a corrupt or hostile stream.
+const MAX_TABLE_SIZE: u64 = 1 << 20;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(BinaryReader, ReaderOptions);
+
+impl ObjectReader<Graph> for BinaryReader {
+    type Error = Error;
+
+    ///
+    /// Read a single graph, statements may be in the default graph or in a single named graph,
+    /// a stream with more than one graph must be read as a data set.
+    ///
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Error>
+    where
+        R: Read,
+    {
+        let mut graph = Graph::default();
+        let mut quads = self.quads(BufReader::new(r));
+        for quad in quads.by_ref() {
+            let (statement, graph_name) = quad?;
+            if graph.name() != graph_name.as_ref() {
+                match graph_name {
+                    Some(graph_name) if graph.is_empty() && !graph.is_named() => {
+                        graph.set_name(graph_name)
+                    }
+                    _ => {
+                        return Err(invalid_data(
+                            "the stream contains more than one graph, read it as a data set",
+                        ))
+                    }
+                }
+            }
+            graph.insert(statement);
+        }
+        if self.options.carry_prefixes() {
+            graph.set_prefix_mappings(quads.mappings);
+        }
+        Ok(graph)
+    }
+}
+
+impl ObjectReader<DataSet> for BinaryReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<DataSet, Error>
+    where
+        R: Read,
+    {
+        let mut data_set = DataSet::default();
+        let mut quads = self.quads(BufReader::new(r));
+        for quad in quads.by_ref() {
+            let (statement, graph_name) = quad?;
+            if data_set.graph(&graph_name).is_none() {
+                data_set.insert(match &graph_name {
+                    Some(name) => Graph::named(name.clone()),
+                    None => Graph::default(),
+                });
+            }
+            data_set.graph_mut(&graph_name).unwrap().insert(statement);
+        }
+        if self.options.carry_prefixes() {
+            let names: Vec<Option<GraphName>> = data_set
+                .graphs()
+                .map(|graph| graph.name().cloned())
+                .collect();
+            for name in names {
+                if let Some(graph) = data_set.graph_mut(&name) {
+                    graph.set_prefix_mappings(quads.mappings.clone());
+                }
+            }
+        }
+        Ok(data_set)
+    }
+}
+
+impl BinaryReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Return an iterator that reads statements from `source` as they are requested, rather
+    /// than reading the whole stream into memory.
+    ///
+    pub fn quads<R>(&self, source: R) -> BinaryStatements<R>
+    where
+        R: Read,
+    {
+        BinaryStatements {
+            source,
+            started: false,
+            pending: None,
+            prefixes: Default::default(),
+            names: Default::default(),
+            datatypes: Default::default(),
+            previous: None,
+            mappings: IriPrefixMap::empty(),
+            relabel: match self.options.blank_nodes() {
+                BlankNodeLabels::Preserve => None,
+                BlankNodeLabels::Relabel => Some(Relabeler::default()),
+            },
+            done: false,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<R> Iterator for BinaryStatements<R>
+where
+    R: Read,
+{
+    type Item = Result<(Statement, Option<GraphName>), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.next_quad();
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+        result.transpose()
+    }
+}
+
+impl<R> BinaryStatements<R>
+where
+    R: Read,
+{
+    ///
+    /// The prefix mappings declared in the stream so far.
+    ///
+    pub fn prefix_mappings(&self) -> &IriPrefixMap {
+        &self.mappings
+    }
+
+    fn next_quad(&mut self) -> Result<Option<(Statement, Option<GraphName>)>, Error> {
+        if !self.started {
+            self.start()?;
+        }
+        loop {
+            let Some(record) = read_u8_or_end(&mut self.source)? else {
+                return Ok(None);
+            };
+            match record {
+                RECORD_NAMESPACE => {
+                    let prefix = read_string(&mut self.source)?;
+                    let namespace = Iri::from_str(&read_string(&mut self.source)?)?;
+                    self.mappings
+                        .insert(Namespace::new_unchecked(prefix), namespace);
+                }
+                RECORD_TRIPLE | RECORD_QUAD => return self.quad(record == RECORD_QUAD).map(Some),
+                _ => return Err(invalid_data(format!("unknown record type {record}"))),
+            }
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let mut magic = [0_u8; 4];
+        self.source.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("missing stream header"));
+        }
+        let version = read_u8(&mut self.source)?;
+        if version != VERSION {
+            return Err(invalid_data(format!("unsupported version {version}")));
+        }
+        self.prefixes = Table::new(read_varint(&mut self.source)?)?;
+        self.names = Table::new(read_varint(&mut self.source)?)?;
+        self.datatypes = Table::new(read_varint(&mut self.source)?)?;
+        self.started = true;
+        Ok(())
+    }
+
+    fn quad(&mut self, has_graph_name: bool) -> Result<(Statement, Option<GraphName>), Error> {
+        let previous = self.previous.take();
+        let subject = match self.repeated(previous.as_ref().map(|p| &p.0))? {
+            Some(subject) => subject,
+            None => self.subject()?,
+        };
+        let predicate = match self.repeated(previous.as_ref().map(|p| &p.1))? {
+            Some(predicate) => predicate,
+            None => self.iri()?,
+        };
+        let object = match self.repeated(previous.as_ref().map(|p| &p.2))? {
+            Some(object) => object,
+            None => self.object()?,
+        };
+        let graph_name = if has_graph_name {
+            match self.repeated(previous.as_ref().and_then(|p| p.3.as_ref()))? {
+                Some(graph_name) => Some(graph_name),
+                None => Some(match self.term_type()? {
+                    TERM_IRI => GraphName::Iri(self.iri_value()?),
+                    TERM_BLANK => {
+                        GraphName::BNode(BlankNode::from_str(&read_string(&mut self.source)?)?)
+                    }
+                    term => return Err(invalid_data(format!("invalid graph name term {term}"))),
+                }),
+            }
+        } else {
+            None
+        };
+        self.previous = Some((
+            subject.clone(),
+            predicate.clone(),
+            object.clone(),
+            graph_name.clone(),
+        ));
+
+        let statement = Statement::new(subject, predicate, object);
+        Ok(match &mut self.relabel {
+            Some(relabel) => (
+                relabel.statement(&statement),
+                graph_name.map(|graph_name| relabel.graph_name(graph_name)),
+            ),
+            None => (statement, graph_name),
+        })
+    }
+
+    ///
+    /// If the next term is a repeat return a copy of the previous term, otherwise leave the
+    /// stream positioned at the term.
+    ///
+    fn repeated<T>(&mut self, previous: Option<&T>) -> Result<Option<T>, Error>
+    where
+        T: Clone,
+    {
+        let mut buffer = [0_u8; 1];
+        self.source.read_exact(&mut buffer)?;
+        if buffer[0] == TERM_REPEAT {
+            match previous {
+                Some(previous) => Ok(Some(previous.clone())),
+                None => Err(invalid_data("repeated term without a previous record")),
+            }
+        } else {
+            self.pending = Some(buffer[0]);
+            Ok(None)
+        }
+    }
+
+    fn term_type(&mut self) -> Result<u8, Error> {
+        match self.pending.take() {
+            Some(term) => Ok(term),
+            None => read_u8(&mut self.source),
+        }
+    }
+
+    fn subject(&mut self) -> Result<SubjectNode, Error> {
+        match self.term_type()? {
+            TERM_IRI => Ok(self.iri_value()?.into()),
+            TERM_BLANK => Ok(BlankNode::from_str(&read_string(&mut self.source)?)?.into()),
+            TERM_STATEMENT => Ok(self.nested()?.into()),
+            term => Err(invalid_data(format!("invalid subject term {term}"))),
+        }
+    }
+
+    fn iri(&mut self) -> Result<Iri, Error> {
+        match self.term_type()? {
+            TERM_IRI => self.iri_value(),
+            term => Err(invalid_data(format!("invalid predicate term {term}"))),
+        }
+    }
+
+    fn object(&mut self) -> Result<ObjectNode, Error> {
+        Ok(match self.term_type()? {
+            TERM_IRI => self.iri_value()?.into(),
+            TERM_BLANK => BlankNode::from_str(&read_string(&mut self.source)?)?.into(),
+            TERM_PLAIN_LITERAL => Literal::plain(read_string(&mut self.source)?).into(),
+            TERM_LANGUAGE_LITERAL => {
+                let lexical_form = read_string(&mut self.source)?;
+                let language = LanguageTag::from_str(&read_string(&mut self.source)?)?;
+                Literal::with_language(lexical_form, language).into()
+            }
+            TERM_TYPED_LITERAL => {
+                let lexical_form = read_string(&mut self.source)?;
+                let data_type = Self::entry(&mut self.source, &mut self.datatypes)?;
+                Literal::with_data_type(lexical_form, DataType::from(Iri::from_str(&data_type)?))
+                    .into()
+            }
+            TERM_STATEMENT => self.nested()?.into(),
+            TERM_COLLECTION => {
+                let length = read_varint(&mut self.source)?;
+                let mut members = Vec::default();
+                for _ in 0..length {
+                    members.push(self.object()?);
+                }
+                Collection::from(members).into()
+            }
+            term => return Err(invalid_data(format!("invalid object term {term}"))),
+        })
+    }
+
+    fn nested(&mut self) -> Result<Statement, Error> {
+        let subject = self.subject()?;
+        let predicate = self.iri()?;
+        let object = self.object()?;
+        Ok(Statement::new(subject, predicate, object))
+    }
+
+    fn iri_value(&mut self) -> Result<Iri, Error> {
+        let prefix = Self::entry(&mut self.source, &mut self.prefixes)?;
+        let name = Self::entry(&mut self.source, &mut self.names)?;
+        Ok(Iri::from_str(&format!("{prefix}{name}"))?)
+    }
+
+    fn entry(source: &mut R, table: &mut Table) -> Result<String, Error> {
+        match read_varint(source)? {
+            NEW_ENTRY => {
+                let value = read_string(source)?;
+                table.insert(value.clone())?;
+                Ok(value)
+            }
+            id => table.get(id),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Table {
+    fn new(capacity: u64) -> Result<Self, Error> {
+        if capacity == 0 || capacity > MAX_TABLE_SIZE {
+            return Err(invalid_data(format!(
+                "invalid lookup table size {capacity}"
+            )));
+        }
+        Ok(Self {
+            slots: Vec::default(),
+            capacity: capacity as usize,
+            next: 0,
+        })
+    }
+
+    fn get(&self, id: u64) -> Result<String, Error> {
+        self.slots
+            .get((id - 1) as usize)
+            .cloned()
+            .ok_or_else(|| invalid_data(format!("unknown lookup table entry {id}")))
+    }
+
+    fn insert(&mut self, value: String) -> Result<(), Error> {
+        if self.capacity == 0 {
+            return Err(invalid_data("lookup table used before the stream header"));
+        }
+        if self.slots.len() < self.capacity {
+            self.slots.push(value);
+        } else {
+            self.slots[self.next] = value;
+        }
+        self.next = (self.next + 1) % self.capacity;
+        Ok(())
+    }
+}
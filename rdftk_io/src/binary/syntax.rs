@@ -0,0 +1,148 @@
+use rdftk_core::error::Error;
+use std::io::{Read, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const MAGIC: &[u8; 4] = b"RDFB";
+pub(super) const VERSION: u8 = 1;
+
+pub(super) const RECORD_NAMESPACE: u8 = 1;
+pub(super) const RECORD_TRIPLE: u8 = 2;
+pub(super) const RECORD_QUAD: u8 = 3;
+
+/// The term is the same as the one in the same position of the previous record.
+pub(super) const TERM_REPEAT: u8 = 0;
+pub(super) const TERM_IRI: u8 = 1;
+pub(super) const TERM_BLANK: u8 = 2;
+pub(super) const TERM_PLAIN_LITERAL: u8 = 3;
+pub(super) const TERM_LANGUAGE_LITERAL: u8 = 4;
+pub(super) const TERM_TYPED_LITERAL: u8 = 5;
+pub(super) const TERM_STATEMENT: u8 = 6;
+pub(super) const TERM_COLLECTION: u8 = 7;
+
+/// A table reference of zero introduces a new entry, its value follows immediately.
+pub(super) const NEW_ENTRY: u64 = 0;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return an error for content that does not conform to the binary format.
+///
+pub(super) fn invalid_data<S>(message: S) -> Error
+where
+    S: Into<String>,
+{
+    Error::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("{}: {}", super::NAME, message.into()),
+    ))
+}
+
+///
+/// Split an IRI into the prefix, up to and including the last `#` or `/`, and the local
+/// name, the prefix is usually shared by many IRIs.
+///
+pub(super) fn split_iri(iri: &str) -> (&str, &str) {
+    match iri.rfind(['#', '/']) {
+        Some(index) => iri.split_at(index + 1),
+        None => ("", iri),
+    }
+}
+
+pub(super) fn write_u8<W>(w: &mut W, value: u8) -> Result<(), Error>
+where
+    W: Write,
+{
+    Ok(w.write_all(&[value])?)
+}
+
+///
+/// Write an unsigned LEB128 integer.
+///
+pub(super) fn write_varint<W>(w: &mut W, value: u64) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut value = value;
+    let mut buffer = [0_u8; 10];
+    let mut length = 0;
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer[length] = byte;
+            length += 1;
+            break;
+        }
+        buffer[length] = byte | 0x80;
+        length += 1;
+    }
+    Ok(w.write_all(&buffer[..length])?)
+}
+
+pub(super) fn write_string<W>(w: &mut W, value: &str) -> Result<(), Error>
+where
+    W: Write,
+{
+    write_varint(w, value.len() as u64)?;
+    Ok(w.write_all(value.as_bytes())?)
+}
+
+///
+/// Read a single byte, returning `None` at the end of the stream.
+///
+pub(super) fn read_u8_or_end<R>(r: &mut R) -> Result<Option<u8>, Error>
+where
+    R: Read,
+{
+    let mut buffer = [0_u8; 1];
+    loop {
+        return match r.read(&mut buffer) {
+            Ok(0) => Ok(None),
+            Ok(_) => Ok(Some(buffer[0])),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => Err(e.into()),
+        };
+    }
+}
+
+pub(super) fn read_u8<R>(r: &mut R) -> Result<u8, Error>
+where
+    R: Read,
+{
+    let mut buffer = [0_u8; 1];
+    r.read_exact(&mut buffer)?;
+    Ok(buffer[0])
+}
+
+pub(super) fn read_varint<R>(r: &mut R) -> Result<u64, Error>
+where
+    R: Read,
+{
+    let mut value = 0_u64;
+    for shift in (0..64).step_by(7) {
+        let byte = read_u8(r)?;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(invalid_data("variable-length integer is too long"))
+}
+
+pub(super) fn read_string<R>(r: &mut R) -> Result<String, Error>
+where
+    R: Read,
+{
+    let length = read_varint(r)?;
+    let mut buffer = Vec::default();
+    let read = r.take(length).read_to_end(&mut buffer)?;
+    if read as u64 != length {
+        return Err(invalid_data("string is truncated"));
+    }
+    Ok(String::from_utf8(buffer)?)
+}
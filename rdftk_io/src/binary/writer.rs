@@ -0,0 +1,421 @@
+use super::syntax::{
+    split_iri, write_string, write_u8, write_varint, MAGIC, NEW_ENTRY, RECORD_NAMESPACE,
+    RECORD_QUAD, RECORD_TRIPLE, TERM_BLANK, TERM_COLLECTION, TERM_IRI, TERM_LANGUAGE_LITERAL,
+    TERM_PLAIN_LITERAL, TERM_REPEAT, TERM_STATEMENT, TERM_TYPED_LITERAL, VERSION,
+};
+use crate::common::literal::unescape_lexical_form;
+use crate::{DataSetWriter, GraphWriter, StatementSink};
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, Namespace};
+use std::collections::HashMap;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The sizes of the lookup tables used to avoid repeating IRI prefixes, local names, and
+/// datatypes; once a table is full the oldest entry is replaced. Larger tables compress long
+/// streams better but require more memory in both the writer and the reader.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BinaryWriterOptions {
+    prefix_table_size: usize,
+    name_table_size: usize,
+    datatype_table_size: usize,
+}
+
+///
+/// This struct implements the `ObjectWriter` trait for graphs and data sets, and will write
+/// out a binary stream of statements.
+///
+#[derive(Debug, Default)]
+pub struct BinaryWriter {
+    options: BinaryWriterOptions,
+}
+
+///
+/// An implementation of [`StatementSink`], returned by [`BinaryWriter::sink`], which writes
+/// each statement as it is received. Statements passed to `statement` are written to the
+/// default graph, use `quad` to write a statement to a named graph.
+///
+#[derive(Debug)]
+pub struct BinarySink<W> {
+    writer: W,
+    options: BinaryWriterOptions,
+    prefixes: Table,
+    names: Table,
+    datatypes: Table,
+    previous: Option<Previous>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A lookup table with a fixed number of slots, filled in order and then reused from the
+/// first slot; the reader maintains an identical table as it reads the stream.
+///
+#[derive(Debug)]
+struct Table {
+    ids: HashMap<String, u64>,
+    slots: Vec<String>,
+    capacity: usize,
+    next: usize,
+}
+
+///
+/// The terms of the previous record, any that are repeated in the next record are written
+/// as a single byte.
+///
+#[derive(Debug)]
+struct Previous {
+    subject: SubjectNode,
+    predicate: Iri,
+    object: ObjectNode,
+    graph_name: Option<GraphName>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const DEFAULT_PREFIX_TABLE_SIZE: usize = 150;
+const DEFAULT_NAME_TABLE_SIZE: usize = 4000;
+const DEFAULT_DATATYPE_TABLE_SIZE: usize = 32;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for BinaryWriterOptions {
+    fn default() -> Self {
+        Self {
+            prefix_table_size: DEFAULT_PREFIX_TABLE_SIZE,
+            name_table_size: DEFAULT_NAME_TABLE_SIZE,
+            datatype_table_size: DEFAULT_DATATYPE_TABLE_SIZE,
+        }
+    }
+}
+
+impl BinaryWriterOptions {
+    pub fn with_prefix_table_size(self, prefix_table_size: usize) -> Self {
+        let mut self_mut = self;
+        self_mut.set_prefix_table_size(prefix_table_size);
+        self_mut
+    }
+
+    pub fn with_name_table_size(self, name_table_size: usize) -> Self {
+        let mut self_mut = self;
+        self_mut.set_name_table_size(name_table_size);
+        self_mut
+    }
+
+    pub fn with_datatype_table_size(self, datatype_table_size: usize) -> Self {
+        let mut self_mut = self;
+        self_mut.set_datatype_table_size(datatype_table_size);
+        self_mut
+    }
+
+    pub fn prefix_table_size(&self) -> usize {
+        self.prefix_table_size
+    }
+
+    pub fn set_prefix_table_size(&mut self, prefix_table_size: usize) {
+        self.prefix_table_size = prefix_table_size.max(1);
+    }
+
+    pub fn name_table_size(&self) -> usize {
+        self.name_table_size
+    }
+
+    pub fn set_name_table_size(&mut self, name_table_size: usize) {
+        self.name_table_size = name_table_size.max(1);
+    }
+
+    pub fn datatype_table_size(&self) -> usize {
+        self.datatype_table_size
+    }
+
+    pub fn set_datatype_table_size(&mut self, datatype_table_size: usize) {
+        self.datatype_table_size = datatype_table_size.max(1);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(BinaryWriter, BinaryWriterOptions);
+
+impl ObjectWriter<Graph> for BinaryWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut sink = self.sink(w);
+        sink.start()?;
+        for (prefix, namespace) in graph.prefix_mappings().mappings() {
+            sink.prefix(prefix, namespace)?;
+        }
+        for statement in graph.statements() {
+            sink.quad(statement, graph.name())?;
+        }
+        sink.finish()
+    }
+}
+
+impl GraphWriter for BinaryWriter {}
+
+impl ObjectWriter<DataSet> for BinaryWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, data_set: &DataSet) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut sink = self.sink(w);
+        sink.start()?;
+        for graph in data_set.graphs() {
+            for (prefix, namespace) in graph.prefix_mappings().mappings() {
+                sink.prefix(prefix, namespace)?;
+            }
+            for statement in graph.statements() {
+                sink.quad(statement, graph.name())?;
+            }
+        }
+        sink.finish()
+    }
+}
+
+impl DataSetWriter for BinaryWriter {}
+
+impl BinaryWriter {
+    pub fn with_options(self, options: BinaryWriterOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Return a [`StatementSink`] that writes statements to `w` as they are received, using
+    /// this writer's options.
+    ///
+    pub fn sink<W>(&self, w: W) -> BinarySink<W>
+    where
+        W: Write,
+    {
+        BinarySink {
+            writer: w,
+            options: self.options,
+            prefixes: Table::new(self.options.prefix_table_size),
+            names: Table::new(self.options.name_table_size),
+            datatypes: Table::new(self.options.datatype_table_size),
+            previous: None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<W> StatementSink for BinarySink<W>
+where
+    W: Write,
+{
+    fn start(&mut self) -> Result<(), Error> {
+        self.writer.write_all(MAGIC)?;
+        write_u8(&mut self.writer, VERSION)?;
+        write_varint(&mut self.writer, self.options.prefix_table_size as u64)?;
+        write_varint(&mut self.writer, self.options.name_table_size as u64)?;
+        write_varint(&mut self.writer, self.options.datatype_table_size as u64)
+    }
+
+    fn prefix(&mut self, prefix: &Namespace, namespace: &Iri) -> Result<(), Error> {
+        write_u8(&mut self.writer, RECORD_NAMESPACE)?;
+        write_string(&mut self.writer, prefix.as_ref())?;
+        write_string(&mut self.writer, &format!("{namespace:#}"))
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        self.quad(statement, None)
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+impl<W> BinarySink<W>
+where
+    W: Write,
+{
+    ///
+    /// Write a single statement to the graph named `graph_name`, or to the default graph if
+    /// `graph_name` is `None`.
+    ///
+    pub fn quad(
+        &mut self,
+        statement: &Statement,
+        graph_name: Option<&GraphName>,
+    ) -> Result<(), Error> {
+        let previous = self.previous.take();
+        let previous = previous.as_ref();
+
+        write_u8(
+            &mut self.writer,
+            if graph_name.is_some() {
+                RECORD_QUAD
+            } else {
+                RECORD_TRIPLE
+            },
+        )?;
+        if previous.is_some_and(|previous| &previous.subject == statement.subject()) {
+            write_u8(&mut self.writer, TERM_REPEAT)?;
+        } else {
+            self.subject(statement.subject())?;
+        }
+        if previous.is_some_and(|previous| &previous.predicate == statement.predicate()) {
+            write_u8(&mut self.writer, TERM_REPEAT)?;
+        } else {
+            self.iri(statement.predicate())?;
+        }
+        if previous.is_some_and(|previous| &previous.object == statement.object()) {
+            write_u8(&mut self.writer, TERM_REPEAT)?;
+        } else {
+            self.object(statement.object())?;
+        }
+        if let Some(graph_name) = graph_name {
+            if previous.is_some_and(|previous| previous.graph_name.as_ref() == Some(graph_name)) {
+                write_u8(&mut self.writer, TERM_REPEAT)?;
+            } else {
+                match graph_name {
+                    GraphName::BNode(node) => self.blank(node.as_ref())?,
+                    GraphName::Iri(iri) => self.iri(iri)?,
+                }
+            }
+        }
+
+        self.previous = Some(Previous {
+            subject: statement.subject().clone(),
+            predicate: statement.predicate().clone(),
+            object: statement.object().clone(),
+            graph_name: graph_name.cloned(),
+        });
+        Ok(())
+    }
+
+    fn subject(&mut self, subject: &SubjectNode) -> Result<(), Error> {
+        match subject {
+            SubjectNode::Blank(node) => self.blank(node.as_ref()),
+            SubjectNode::Resource(iri) => self.iri(iri),
+            SubjectNode::Statement(statement) => self.nested(statement),
+        }
+    }
+
+    fn object(&mut self, object: &ObjectNode) -> Result<(), Error> {
+        match object {
+            ObjectNode::Blank(node) => self.blank(node.as_ref()),
+            ObjectNode::Resource(iri) => self.iri(iri),
+            ObjectNode::Literal(literal) => self.literal(literal),
+            ObjectNode::Collection(collection) => {
+                write_u8(&mut self.writer, TERM_COLLECTION)?;
+                write_varint(&mut self.writer, collection.len() as u64)?;
+                for member in collection.iter() {
+                    self.object(member)?;
+                }
+                Ok(())
+            }
+            ObjectNode::Statement(statement) => self.nested(statement),
+        }
+    }
+
+    fn nested(&mut self, statement: &Statement) -> Result<(), Error> {
+        write_u8(&mut self.writer, TERM_STATEMENT)?;
+        self.subject(statement.subject())?;
+        self.iri(statement.predicate())?;
+        self.object(statement.object())
+    }
+
+    fn blank(&mut self, label: &str) -> Result<(), Error> {
+        write_u8(&mut self.writer, TERM_BLANK)?;
+        write_string(&mut self.writer, label)
+    }
+
+    fn iri(&mut self, iri: &Iri) -> Result<(), Error> {
+        let iri = format!("{iri:#}");
+        let (prefix, name) = split_iri(&iri);
+        write_u8(&mut self.writer, TERM_IRI)?;
+        Self::reference(&mut self.writer, &mut self.prefixes, prefix)?;
+        Self::reference(&mut self.writer, &mut self.names, name)
+    }
+
+    fn literal(&mut self, literal: &Literal) -> Result<(), Error> {
+        let lexical_form = unescape_lexical_form(literal.lexical_form());
+        match (literal.language(), literal.data_type()) {
+            (Some(language), _) => {
+                write_u8(&mut self.writer, TERM_LANGUAGE_LITERAL)?;
+                write_string(&mut self.writer, &lexical_form)?;
+                write_string(&mut self.writer, &language.to_string())
+            }
+            (None, Some(data_type)) => {
+                write_u8(&mut self.writer, TERM_TYPED_LITERAL)?;
+                write_string(&mut self.writer, &lexical_form)?;
+                Self::reference(
+                    &mut self.writer,
+                    &mut self.datatypes,
+                    &format!("{:#}", data_type.as_iri()),
+                )
+            }
+            (None, None) => {
+                write_u8(&mut self.writer, TERM_PLAIN_LITERAL)?;
+                write_string(&mut self.writer, &lexical_form)
+            }
+        }
+    }
+
+    fn reference(w: &mut W, table: &mut Table, value: &str) -> Result<(), Error> {
+        match table.get(value) {
+            Some(id) => write_varint(w, id),
+            None => {
+                table.insert(value);
+                write_varint(w, NEW_ENTRY)?;
+                write_string(w, value)
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Table {
+    fn new(capacity: usize) -> Self {
+        Self {
+            ids: Default::default(),
+            slots: Vec::default(),
+            capacity,
+            next: 0,
+        }
+    }
+
+    fn get(&self, value: &str) -> Option<u64> {
+        self.ids.get(value).copied()
+    }
+
+    fn insert(&mut self, value: &str) {
+        if self.slots.len() < self.capacity {
+            self.slots.push(value.to_string());
+        } else {
+            let _ = self.ids.remove(&self.slots[self.next]);
+            self.slots[self.next] = value.to_string();
+        }
+        let _ = self.ids.insert(value.to_string(), self.next as u64 + 1);
+        self.next = (self.next + 1) % self.capacity;
+    }
+}
@@ -0,0 +1,235 @@
+/*!
+Collects the nodes and edges of a graph for the diagram and graph-analysis writers which,
+unlike the dot writer, draw each distinct node once with no clusters.
+*/
+
+use crate::common::literal::unescape_lexical_form;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap};
+use std::collections::HashMap;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The kinds of node that may be styled separately; nested statements are labelled
+/// `<< s p o >>` and collections `( a b )` using the labels of their members.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum NodeKind {
+    Blank,
+    Iri,
+    Literal,
+    Statement,
+    Collection,
+}
+
+///
+/// A node, its `id` is unique within the diagram. The `label` may be a prefixed name where the
+/// `value` is always the full IRI, blank node label, or unescaped lexical form.
+///
+#[derive(Debug)]
+#[cfg_attr(not(any(feature = "cytoscape", feature = "graphml")), allow(dead_code))]
+pub(crate) struct Node {
+    pub(crate) id: usize,
+    pub(crate) kind: NodeKind,
+    pub(crate) label: String,
+    pub(crate) value: String,
+    pub(crate) data_type: Option<String>,
+    pub(crate) language: Option<String>,
+    /// Literal-valued properties folded into the node, as pairs of predicate label and value.
+    pub(crate) properties: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(not(any(feature = "cytoscape", feature = "graphml")), allow(dead_code))]
+pub(crate) struct Edge {
+    pub(crate) subject: usize,
+    pub(crate) object: usize,
+    pub(crate) label: String,
+    pub(crate) predicate: String,
+}
+
+///
+/// The nodes, in the order they were first seen, and the edges of a graph.
+///
+#[derive(Debug, Default)]
+pub(crate) struct Diagram {
+    ids: HashMap<String, usize>,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Diagram {
+    ///
+    /// Collect the nodes and edges of `graph`, if `prefixed_labels` is set then IRI node labels
+    /// are compressed using the graph's prefix mappings; edge labels are always compressed. If
+    /// `fold_literals` is set then literal objects are added to the properties of their subject
+    /// rather than as separate nodes.
+    ///
+    pub(crate) fn from_graph(graph: &Graph, prefixed_labels: bool, fold_literals: bool) -> Self {
+        let mappings = graph.prefix_mappings();
+        let mut diagram = Self::default();
+        for statement in graph.statements() {
+            let subject = diagram.subject_id(statement.subject(), mappings, prefixed_labels);
+            let label = iri_label(statement.predicate(), mappings, true);
+            match statement.object().as_literal() {
+                Some(literal) if fold_literals => {
+                    diagram.nodes[subject - 1]
+                        .properties
+                        .push((label, unescape_lexical_form(literal.lexical_form())));
+                }
+                _ => {
+                    let object = diagram.object_id(statement.object(), mappings, prefixed_labels);
+                    diagram.edges.push(Edge {
+                        subject,
+                        object,
+                        label,
+                        predicate: format!("{:#}", statement.predicate()),
+                    });
+                }
+            }
+        }
+        diagram
+    }
+
+    pub(crate) fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.iter()
+    }
+
+    pub(crate) fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges.iter()
+    }
+
+    fn subject_id(&mut self, node: &SubjectNode, mappings: &IriPrefixMap, prefixed: bool) -> usize {
+        let key = node.to_string();
+        if let Some(blank) = node.as_blank() {
+            self.id(key, NodeKind::Blank, blank.to_string(), None)
+        } else if let Some(iri) = node.as_resource() {
+            let label = iri_label(iri, mappings, prefixed);
+            self.id(key, NodeKind::Iri, format!("{iri:#}"), Some(label))
+        } else {
+            let label = subject_label(node, mappings, prefixed);
+            self.id(key.clone(), NodeKind::Statement, key, Some(label))
+        }
+    }
+
+    fn object_id(&mut self, node: &ObjectNode, mappings: &IriPrefixMap, prefixed: bool) -> usize {
+        let key = node.to_string();
+        if let Some(blank) = node.as_blank() {
+            self.id(key, NodeKind::Blank, blank.to_string(), None)
+        } else if let Some(iri) = node.as_resource() {
+            let label = iri_label(iri, mappings, prefixed);
+            self.id(key, NodeKind::Iri, format!("{iri:#}"), Some(label))
+        } else if let Some(literal) = node.as_literal() {
+            let id = self.id(
+                key,
+                NodeKind::Literal,
+                unescape_lexical_form(literal.lexical_form()),
+                None,
+            );
+            let node = &mut self.nodes[id - 1];
+            node.data_type = literal.data_type().map(|dt| format!("{:#}", dt.as_iri()));
+            node.language = literal.language().map(|lang| lang.to_string());
+            id
+        } else {
+            let kind = if node.is_collection() {
+                NodeKind::Collection
+            } else {
+                NodeKind::Statement
+            };
+            let label = object_label(node, mappings, prefixed);
+            self.id(key.clone(), kind, key, Some(label))
+        }
+    }
+
+    fn id(&mut self, key: String, kind: NodeKind, value: String, label: Option<String>) -> usize {
+        if let Some(id) = self.ids.get(&key) {
+            *id
+        } else {
+            let id = self.nodes.len() + 1;
+            let _ = self.ids.insert(key, id);
+            self.nodes.push(Node {
+                id,
+                kind,
+                label: label.unwrap_or_else(|| value.clone()),
+                value,
+                data_type: None,
+                language: None,
+                properties: Default::default(),
+            });
+            id
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl NodeKind {
+    #[cfg_attr(not(any(feature = "cytoscape", feature = "graphml")), allow(dead_code))]
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Blank => "blank",
+            Self::Iri => "iri",
+            Self::Literal => "literal",
+            Self::Statement => "statement",
+            Self::Collection => "collection",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn subject_label(node: &SubjectNode, mappings: &IriPrefixMap, prefixed: bool) -> String {
+    if let Some(iri) = node.as_resource() {
+        iri_label(iri, mappings, prefixed)
+    } else if let Some(statement) = node.as_statement() {
+        statement_label(&statement, mappings, prefixed)
+    } else {
+        node.to_string()
+    }
+}
+
+fn object_label(node: &ObjectNode, mappings: &IriPrefixMap, prefixed: bool) -> String {
+    if let Some(iri) = node.as_resource() {
+        iri_label(iri, mappings, prefixed)
+    } else if let Some(statement) = node.as_statement() {
+        statement_label(&statement, mappings, prefixed)
+    } else if let Some(collection) = node.as_collection() {
+        let members = collection
+            .iter()
+            .map(|member| object_label(member, mappings, prefixed))
+            .collect::<Vec<String>>();
+        if members.is_empty() {
+            "( )".to_string()
+        } else {
+            format!("( {} )", members.join(" "))
+        }
+    } else {
+        node.to_string()
+    }
+}
+
+fn statement_label(statement: &Statement, mappings: &IriPrefixMap, prefixed: bool) -> String {
+    format!(
+        "<< {} {} {} >>",
+        subject_label(statement.subject(), mappings, prefixed),
+        iri_label(statement.predicate(), mappings, prefixed),
+        object_label(statement.object(), mappings, prefixed)
+    )
+}
+
+fn iri_label(iri: &Iri, mappings: &IriPrefixMap, prefixed: bool) -> String {
+    match mappings.compress(iri) {
+        Some(qname) if prefixed => qname.to_string(),
+        _ => format!("{iri:#}"),
+    }
+}
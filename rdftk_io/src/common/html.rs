@@ -0,0 +1,68 @@
+use rdftk_core::error::Error;
+use rdftk_iri::Iri;
+use scraper::{ElementRef, Html};
+use std::io::Read;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read and parse an HTML document from `r`; HTML parsing does not fail, and any content that
+/// is not valid UTF-8 is replaced.
+///
+pub(crate) fn read_html_document<R>(r: &mut R) -> Result<Html, Error>
+where
+    R: Read + ?Sized,
+{
+    let mut source = Vec::default();
+    let _ = r.read_to_end(&mut source)?;
+    Ok(Html::parse_document(&String::from_utf8_lossy(&source)))
+}
+
+///
+/// Determine the base IRI of the document from the first `base` element, resolved against
+/// `base`, usually from the reader options.
+///
+pub(crate) fn document_base(
+    root: ElementRef<'_>,
+    base: Option<&Iri>,
+) -> Result<Option<url::Url>, Error> {
+    let base = base.map(|base| AsRef::<url::Url>::as_ref(base).clone());
+    let href = root
+        .descendent_elements()
+        .find(|element| element.value().name() == ELEMENT_BASE)
+        .and_then(|element| element.value().attr(ATTRIBUTE_HREF));
+    Ok(match (base, href) {
+        (Some(base), Some(href)) => Some(base.join(href)?),
+        (None, Some(href)) => Some(url::Url::parse(href)?),
+        (base, None) => base,
+    })
+}
+
+///
+/// Return the value of the `xml:lang`, or `lang`, attribute of `element` or of its nearest
+/// ancestor with either.
+///
+#[cfg(feature = "microdata")]
+pub(crate) fn element_language(element: ElementRef<'_>) -> Option<&str> {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .find_map(|element| {
+            element
+                .value()
+                .attr(ATTRIBUTE_XML_LANG)
+                .or_else(|| element.value().attr(ATTRIBUTE_LANG))
+        })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const ELEMENT_BASE: &str = "base";
+const ATTRIBUTE_HREF: &str = "href";
+#[cfg(feature = "microdata")]
+const ATTRIBUTE_LANG: &str = "lang";
+#[cfg(feature = "microdata")]
+const ATTRIBUTE_XML_LANG: &str = "xml:lang";
@@ -0,0 +1,169 @@
+use rdftk_core::error::{syntax_error, Error, SourceLocation};
+use serde_json::Value;
+#[cfg(feature = "json-ld")]
+use std::io::Read;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read a JSON value from `r`, reporting any syntax error with its location in the source.
+///
+#[cfg(feature = "json-ld")]
+pub(crate) fn read_json_value<R>(representation: &str, r: &mut R) -> Result<Value, Error>
+where
+    R: Read + ?Sized,
+{
+    let mut source = String::new();
+    let _ = r.read_to_string(&mut source)?;
+    parse_json_value(representation, &source)
+}
+
+///
+/// Parse a JSON value from `source`, reporting any syntax error with its location in the source.
+///
+pub(crate) fn parse_json_value(representation: &str, source: &str) -> Result<Value, Error> {
+    serde_json::from_str(source).map_err(|e| json_syntax_error(representation, source, e))
+}
+
+///
+/// Format `path`, a sequence of object keys and array indices, as a JSON Pointer (RFC 6901).
+///
+#[cfg(feature = "json")]
+pub(crate) fn json_pointer(path: &[&str]) -> String {
+    path.iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+///
+/// Return the location in `source`, which must be well-formed JSON, of the value reached by
+/// following `path`. Where the path cannot be followed, the location of the last value reached
+/// is returned.
+///
+#[cfg(feature = "json")]
+pub(crate) fn member_location(source: &str, path: &[&str]) -> SourceLocation {
+    let bytes = source.as_bytes();
+    SourceLocation::from_offset(source, value_offset(bytes, skip_whitespace(bytes, 0), path))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn json_syntax_error(representation: &str, source: &str, e: serde_json::Error) -> Error {
+    // serde reports a 1-based line, and a 1-based column counted in bytes.
+    let line_start = source
+        .split_inclusive('\n')
+        .take(e.line().saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>();
+    let location = SourceLocation::from_offset(source, line_start + e.column().saturating_sub(1));
+    // the error's display form is suffixed with its position, which is reported separately.
+    let message = e.to_string();
+    let message = message
+        .strip_suffix(&format!(" at line {} column {}", e.line(), e.column()))
+        .unwrap_or(&message);
+    syntax_error(representation, message, location)
+}
+
+#[cfg(feature = "json")]
+fn value_offset(bytes: &[u8], start: usize, path: &[&str]) -> usize {
+    let Some((segment, rest)) = path.split_first() else {
+        return start;
+    };
+    match bytes.get(start) {
+        Some(b'{') => {
+            // duplicate keys are allowed, and the last value is the one that is kept.
+            let mut found = None;
+            let mut i = skip_whitespace(bytes, start + 1);
+            while bytes.get(i) == Some(&b'"') {
+                let key_end = string_end(bytes, i);
+                let key: Option<String> = serde_json::from_slice(&bytes[i..key_end]).ok();
+                let value = skip_whitespace(bytes, skip_whitespace(bytes, key_end) + 1);
+                if key.as_deref() == Some(*segment) {
+                    found = Some(value);
+                }
+                i = skip_whitespace(bytes, value_end(bytes, value));
+                if bytes.get(i) == Some(&b',') {
+                    i = skip_whitespace(bytes, i + 1);
+                }
+            }
+            found.map_or(start, |value| value_offset(bytes, value, rest))
+        }
+        Some(b'[') => {
+            let Ok(index) = segment.parse::<usize>() else {
+                return start;
+            };
+            let mut i = skip_whitespace(bytes, start + 1);
+            for _ in 0..index {
+                i = skip_whitespace(bytes, value_end(bytes, i));
+                if bytes.get(i) != Some(&b',') {
+                    return start;
+                }
+                i = skip_whitespace(bytes, i + 1);
+            }
+            match bytes.get(i) {
+                None | Some(b']') => start,
+                _ => value_offset(bytes, i, rest),
+            }
+        }
+        _ => start,
+    }
+}
+
+#[cfg(feature = "json")]
+fn value_end(bytes: &[u8], start: usize) -> usize {
+    match bytes.get(start) {
+        Some(b'"') => string_end(bytes, start),
+        Some(b'{' | b'[') => {
+            let mut depth = 0;
+            let mut i = start;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'"' => {
+                        i = string_end(bytes, i);
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return i + 1;
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            bytes.len()
+        }
+        _ => bytes[start.min(bytes.len())..]
+            .iter()
+            .position(|b| matches!(b, b',' | b'}' | b']' | b' ' | b'\t' | b'\r' | b'\n'))
+            .map_or(bytes.len(), |i| start + i),
+    }
+}
+
+#[cfg(feature = "json")]
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(feature = "json")]
+fn skip_whitespace(bytes: &[u8], start: usize) -> usize {
+    start
+        + bytes[start.min(bytes.len())..]
+            .iter()
+            .take_while(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+            .count()
+}
@@ -0,0 +1,148 @@
+#[cfg(any(feature = "nq", feature = "nt"))]
+use rdftk_core::error::{rdf_star_not_supported_error, Error};
+#[cfg(any(
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "trig",
+    feature = "turtle"
+))]
+use rdftk_core::model::literal::Literal;
+#[cfg(any(feature = "nq", feature = "nt"))]
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Reverse the escaping applied to a literal's lexical form by the core model, which uses the
+/// Rust `Debug` representation of the original string.
+///
+pub(crate) fn unescape_lexical_form(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => result.push('\t'),
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some('0') => result.push('\0'),
+                Some('u') => {
+                    let hex = chars
+                        .by_ref()
+                        .skip_while(|c| *c == '{')
+                        .take_while(|c| *c != '}')
+                        .collect::<String>();
+                    result.push(
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .unwrap_or(char::REPLACEMENT_CHARACTER),
+                    );
+                }
+                Some(c) => result.push(c),
+                None => result.push(c),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+///
+/// Return the lexical form of `literal` as a quoted string, `"lex"`, escaped according to the
+/// `ECHAR` and `UCHAR` rules shared by N-Triples, N-Quads, Turtle, TriG, and N3.
+///
+#[cfg(any(
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "trig",
+    feature = "turtle"
+))]
+pub(crate) fn quoted_lexical_form(literal: &Literal) -> String {
+    let value = unescape_lexical_form(literal.lexical_form());
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '\u{8}' => result.push_str("\\b"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push_str("\\n"),
+            '\u{c}' => result.push_str("\\f"),
+            '\r' => result.push_str("\\r"),
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04X}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+///
+/// Return the full form of `literal`, `"lex"`, `"lex"@lang`, or `"lex"^^<dt>`, as required by
+/// the line-based formats which have no shorthand for numbers, booleans, or IRIs.
+///
+#[cfg(any(feature = "nq", feature = "nt"))]
+pub(crate) fn full_literal_string(literal: &Literal) -> String {
+    match (literal.data_type(), literal.language()) {
+        (Some(data_type), None) => {
+            format!("{}^^{}", quoted_lexical_form(literal), data_type.as_iri())
+        }
+        (None, Some(language)) => format!("{}@{language}", quoted_lexical_form(literal)),
+        _ => quoted_lexical_form(literal),
+    }
+}
+
+///
+/// Return `subject` in the form used by the line-based formats, where a nested statement is
+/// written as a quoted triple.
+///
+#[cfg(any(feature = "nq", feature = "nt"))]
+pub(crate) fn full_subject_string(name: &str, subject: &SubjectNode) -> Result<String, Error> {
+    match subject {
+        SubjectNode::Statement(statement) => quoted_triple_string(name, statement),
+        _ => Ok(subject.to_string()),
+    }
+}
+
+///
+/// Return `object` in the form used by the line-based formats, where a literal is written in
+/// its full form and a nested statement is written as a quoted triple.
+///
+#[cfg(any(feature = "nq", feature = "nt"))]
+pub(crate) fn full_object_string(name: &str, object: &ObjectNode) -> Result<String, Error> {
+    match object {
+        ObjectNode::Statement(statement) => quoted_triple_string(name, statement),
+        ObjectNode::Literal(literal) => Ok(full_literal_string(literal)),
+        _ => Ok(object.to_string()),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// ```text
+/// quotedTriple  ::= '<<' subject predicate object '>>'
+/// ```
+///
+#[cfg(any(feature = "nq", feature = "nt"))]
+fn quoted_triple_string(name: &str, statement: &Statement) -> Result<String, Error> {
+    if statement.object().is_collection() {
+        // a collection is a set of statements and so cannot be quoted.
+        return rdf_star_not_supported_error(name).into();
+    }
+    Ok(format!(
+        "<< {} {} {} >>",
+        full_subject_string(name, statement.subject())?,
+        statement.predicate(),
+        full_object_string(name, statement.object())?
+    ))
+}
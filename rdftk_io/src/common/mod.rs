@@ -6,9 +6,43 @@ Internal, common, code for reader and writer implementations.
 // Modules
 // ------------------------------------------------------------------------------------------------
 
-#[cfg(feature = "nt")]
+#[cfg(any(
+    feature = "cytoscape",
+    feature = "graphml",
+    feature = "mermaid",
+    feature = "plantuml"
+))]
+pub(crate) mod diagram;
+
+#[cfg(feature = "turtle")]
 pub(crate) mod indenter;
 
+#[cfg(any(feature = "microdata", feature = "rdfa"))]
+pub(crate) mod html;
+
+#[cfg(any(feature = "hextuples", feature = "json", feature = "json-ld"))]
+pub(crate) mod json;
+
+#[cfg(any(
+    feature = "binary",
+    feature = "cytoscape",
+    feature = "dot",
+    feature = "graphml",
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "json-ld",
+    feature = "mermaid",
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "plantuml",
+    feature = "trig",
+    feature = "trix",
+    feature = "turtle",
+    feature = "xml"
+))]
+pub(crate) mod literal;
+
 #[cfg(any(
     feature = "n3",
     feature = "nq",
@@ -17,3 +51,30 @@ pub(crate) mod indenter;
     feature = "turtle"
 ))]
 pub(crate) mod parser;
+
+#[cfg(any(
+    feature = "binary",
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "json",
+    feature = "json-ld",
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "rdfa",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
+pub(crate) mod relabel;
+
+#[cfg(any(
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "nq",
+    feature = "nt",
+    feature = "trix",
+    feature = "turtle",
+    feature = "xml"
+))]
+pub(crate) mod simplify;
@@ -1,10 +1,16 @@
 #![allow(clippy::upper_case_acronyms)] // << generated by pest.
 
+use crate::ReaderOptions;
+use pest::error::InputLocation;
+use pest::iterators::Pair;
 use pest::Parser as _;
 use pest_derive::Parser;
-use rdftk_core::error::Error;
+use rdftk_core::error::{syntax_error, Error, SourceLocation};
 use rdftk_core::model::data_set::DataSet;
-use rdftk_core::model::graph::Graph;
+#[cfg(feature = "n3")]
+use rdftk_core::model::formulae::Formula;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::statement::Statement;
 use tracing::{span, Level};
 
 // ------------------------------------------------------------------------------------------------
@@ -66,71 +72,173 @@ macro_rules! pest_error {
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-pub(crate) fn parse_ntriple_doc<S>(source: S) -> Result<Graph, Error>
+///
+/// Move the location of a syntax error reported for a single line of an N-Triples or N-Quads
+/// source to its place in the whole source; `lines` is the number of lines, and `bytes` the
+/// number of bytes, that came before the line that was parsed.
+///
+pub(crate) fn relocate_error(error: Error, lines: usize, bytes: usize) -> Error {
+    match error {
+        Error::Syntax {
+            representation,
+            message,
+            location,
+        } => syntax_error(representation, message, location.offset_by(lines, bytes)),
+        _ => error,
+    }
+}
+
+pub(crate) fn parse_ntriple_line<S>(source: S) -> Result<Option<Statement>, Error>
 where
     S: AsRef<str>,
 {
-    let span = span!(Level::TRACE, "NTriples");
-    let _guard = span.enter();
-    let mut parsed =
-        CommonParser::parse(Rule::ntripleDoc, source.as_ref()).map_err(|e| Error::Tokenizer {
-            representation: "NTriples".into(),
-            source: Box::new(e),
-        })?;
+    let mut parsed = CommonParser::parse(Rule::ntripleLine, source.as_ref())
+        .map_err(|e| pest_syntax_error(NTRIPLES, e))?;
+    let top_node = parsed.next().unwrap();
+    ntriples::parse_line(top_node)
+}
+
+pub(crate) fn parse_nquad_line<S>(
+    source: S,
+) -> Result<Option<(Statement, Option<GraphName>)>, Error>
+where
+    S: AsRef<str>,
+{
+    let mut parsed = CommonParser::parse(Rule::nquadLine, source.as_ref())
+        .map_err(|e| pest_syntax_error(NQUADS, e))?;
     let top_node = parsed.next().unwrap();
-    ntriples::parse_doc(top_node)
+    nquads::parse_line(top_node)
 }
 
-pub(crate) fn parse_nquad_doc<S>(source: S) -> Result<DataSet, Error>
+pub(crate) fn parse_turtle_doc<S>(source: S, options: &ReaderOptions) -> Result<Graph, Error>
 where
     S: AsRef<str>,
 {
-    let span = span!(Level::TRACE, "NQuads");
+    let span = span!(Level::TRACE, "Turtle");
     let _guard = span.enter();
-    let mut parsed =
-        CommonParser::parse(Rule::nquadDoc, source.as_ref()).map_err(|e| Error::Tokenizer {
-            representation: "NQuads".into(),
-            source: Box::new(e),
-        })?;
+    let mut parsed = CommonParser::parse(Rule::turtleStarDoc, source.as_ref())
+        .map_err(|e| pest_syntax_error(TURTLE, e))?;
     let top_node = parsed.next().unwrap();
-    nquads::parse_doc(top_node)
+    turtle::parse_doc(top_node, options)
 }
 
-pub(crate) fn parse_turtle_doc<S>(_source: S) -> Result<Graph, Error>
+///
+/// Parse a Turtle document one statement at a time, skipping any statement that cannot be
+/// parsed and returning the errors for them alongside the statements that could be.
+///
+pub(crate) fn parse_turtle_doc_lenient<S>(source: S, options: &ReaderOptions) -> (Graph, Vec<Error>)
 where
     S: AsRef<str>,
 {
     let span = span!(Level::TRACE, "Turtle");
     let _guard = span.enter();
-    todo!()
+    turtle::parse_doc_lenient(source.as_ref(), options)
 }
 
-pub(crate) fn parse_trig_doc<S>(_source: S) -> Result<DataSet, Error>
+pub(crate) fn parse_trig_doc<S>(source: S, options: &ReaderOptions) -> Result<DataSet, Error>
 where
     S: AsRef<str>,
 {
     let span = span!(Level::TRACE, "TRiG");
     let _guard = span.enter();
-    todo!()
+    let mut parsed = CommonParser::parse(Rule::trigDoc, source.as_ref())
+        .map_err(|e| pest_syntax_error(TRIG, e))?;
+    let top_node = parsed.next().unwrap();
+    trig::parse_doc(top_node, options)
 }
 
-pub(crate) fn parse_n3_doc<S>(_source: S) -> Result<Graph, Error>
+#[cfg(feature = "n3")]
+pub(crate) fn parse_n3_doc<S>(source: S, options: &ReaderOptions) -> Result<Formula, Error>
 where
     S: AsRef<str>,
 {
     let span = span!(Level::TRACE, "N3");
     let _guard = span.enter();
-    todo!()
+    let mut parsed =
+        CommonParser::parse(Rule::n3Doc, source.as_ref()).map_err(|e| pest_syntax_error(N3, e))?;
+    let top_node = parsed.next().unwrap();
+    n3::parse_doc(top_node, options)
 }
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+const NTRIPLES: &str = "NTriples";
+const NQUADS: &str = "NQuads";
+const TURTLE: &str = "Turtle";
+const TRIG: &str = "TriG";
+#[cfg(feature = "n3")]
+const N3: &str = "N3";
+
 #[derive(Parser)]
 #[grammar = "common/common.pest"]
 struct CommonParser;
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return a function that attaches the start of `input_pair` as the location of an error
+/// raised while walking it. The location is only computed if an error actually occurs.
+///
+fn locate<'i>(
+    representation: &'static str,
+    input_pair: &Pair<'i, Rule>,
+) -> impl FnOnce(Error) -> Error + 'i {
+    let start = input_pair.as_span().start_pos();
+    move |e| e.with_location(representation, position_location(&start))
+}
+
+///
+/// The location of the start of `input_pair`, for errors that are raised directly as syntax
+/// errors rather than attached by [`locate`].
+///
+fn pair_location(input_pair: &Pair<'_, Rule>) -> SourceLocation {
+    position_location(&input_pair.as_span().start_pos())
+}
+
+fn position_location(position: &pest::Position<'_>) -> SourceLocation {
+    let (line, column) = position.line_col();
+    SourceLocation::new(line, column, position.pos(), position.line_of())
+}
+
+///
+/// Move the location of an error raised while parsing `source[start..]` to its place in the
+/// whole of `source`.
+///
+fn rebase_error(error: Error, source: &str, start: usize) -> Error {
+    match error {
+        Error::Syntax {
+            representation,
+            message,
+            location,
+        } => syntax_error(
+            representation,
+            message,
+            SourceLocation::from_offset(source, start + location.offset()),
+        ),
+        _ => error,
+    }
+}
+
+fn pest_syntax_error(representation: &str, e: pest::error::Error<Rule>) -> Error {
+    let offset = match e.location {
+        InputLocation::Pos(offset) => offset,
+        InputLocation::Span((offset, _)) => offset,
+    };
+    let (line, column) = match e.line_col {
+        pest::error::LineColLocation::Pos(line_col) => line_col,
+        pest::error::LineColLocation::Span(line_col, _) => line_col,
+    };
+    syntax_error(
+        representation,
+        e.variant.message(),
+        SourceLocation::new(line, column, offset, e.line()),
+    )
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -143,4 +251,5 @@ mod turtle;
 
 mod trig;
 
+#[cfg(feature = "n3")]
 mod n3;
@@ -1,33 +1,528 @@
-// use ...
+use super::turtle::{blank_node, iri, literal, prefix_or_base, ParserState};
+use super::{locate, Rule, N3};
+use crate::ReaderOptions;
+use pest::iterators::Pair;
+use rdftk_core::error::Error;
+use rdftk_core::model::formulae::{Formula, Quantification, Variable};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement};
+use rdftk_iri::Iri;
+use rdftk_names::{log, owl, rdf};
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
-// Public Macros
+// Public Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Public Types
-// ------------------------------------------------------------------------------------------------
+pub(super) fn parse_doc(
+    input_pair: Pair<'_, Rule>,
+    options: &ReaderOptions,
+) -> Result<Formula, Error> {
+    parse_rule!("n3Doc" entry input_pair);
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+    let mut state = ParserState::new(N3, options);
+    let mut scope = Scope::default();
 
-// ------------------------------------------------------------------------------------------------
-// Private Macros
-// ------------------------------------------------------------------------------------------------
+    if input_pair.as_rule() == Rule::n3Doc {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::n3Statement => {
+                    let located = locate(N3, &inner_pair);
+                    statement(inner_pair, &mut state, &mut scope).map_err(located)?;
+                }
+                Rule::sparqlDirective => {
+                    let located = locate(N3, &inner_pair);
+                    prefix_or_base(inner_pair.into_inner().next().unwrap(), &mut state)
+                        .map_err(located)?;
+                }
+                Rule::END_OF_STATEMENT | Rule::EOI => {}
+                _ => {
+                    return Err(pest_error!(
+                        unexpected
+                        RULE_FN,
+                        &inner_pair,
+                        [Rule::n3Statement, Rule::sparqlDirective, Rule::EOI]
+                    ));
+                }
+            }
+        }
+        // quick variables at the top level have no parent, they are quantified in the
+        // document's formula.
+        let mut formula = scope.formula;
+        scope
+            .quick_variables
+            .into_iter()
+            .for_each(|v| formula.add_variable(v));
+        if let Some(mappings) = state.into_mappings() {
+            formula.set_prefix_mappings(mappings);
+        }
+        Ok(formula)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3Doc]))
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// The formula being constructed, along with any quick variables (`?x`) found within it; these
+/// are universally quantified in the *parent* formula.
+///
+#[derive(Debug, Default)]
+struct Scope {
+    formula: Formula,
+    quick_variables: Vec<Variable>,
+}
+
+///
+/// The namespace used to name quick variables when the document has no base IRI.
+///
+const QUICK_VARIABLE_NAMESPACE: &str = "http://www.w3.org/2000/10/swap/var#";
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Scope {
+    fn insert(
+        &mut self,
+        subject: &ObjectNode,
+        predicate: &Iri,
+        object: ObjectNode,
+        inverse: bool,
+    ) -> Result<(), Error> {
+        const RULE_FN: &str = "n3Triples";
+        let (subject, object) = if inverse {
+            (object, subject.clone())
+        } else {
+            (subject.clone(), object)
+        };
+        let subject = subject.to_subject().ok_or_else(|| Error::ParserExpected {
+            rule_fn: RULE_FN.into(),
+            expecting: "subject that is not a literal".into(),
+        })?;
+        self.formula
+            .insert(Statement::new(subject, predicate.clone(), object));
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn statement(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<(), Error> {
+    parse_rule!("n3Statement" entry input_pair);
+
+    if input_pair.as_rule() == Rule::n3Statement {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::n3Directive => prefix_or_base(inner_pair.into_inner().next().unwrap(), state),
+            Rule::n3Universal => {
+                quantification(inner_pair, Quantification::Universal, state, scope)
+            }
+            Rule::n3Existential => {
+                quantification(inner_pair, Quantification::Existential, state, scope)
+            }
+            Rule::n3Triples => triples(inner_pair, state, scope),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [
+                    Rule::n3Directive,
+                    Rule::n3Universal,
+                    Rule::n3Existential,
+                    Rule::n3Triples
+                ]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3Statement]))
+    }
+}
+
+fn quantification(
+    input_pair: Pair<'_, Rule>,
+    quantification: Quantification,
+    state: &ParserState,
+    scope: &mut Scope,
+) -> Result<(), Error> {
+    parse_rule!("n3Quantification" entry input_pair);
+
+    if matches!(
+        input_pair.as_rule(),
+        Rule::n3Universal | Rule::n3Existential
+    ) {
+        for inner_pair in input_pair.into_inner() {
+            let id = iri(inner_pair, state)?;
+            scope
+                .formula
+                .add_variable(Variable::new(quantification, id));
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(
+            unexpected
+            RULE_FN,
+            &input_pair,
+            [Rule::n3Universal, Rule::n3Existential]
+        ))
+    }
+}
+
+fn triples(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<(), Error> {
+    parse_rule!("n3Triples" entry input_pair);
+
+    if input_pair.as_rule() == Rule::n3Triples {
+        let mut inner_pairs = input_pair.into_inner();
+        let subject_pair = inner_pairs.next().unwrap();
+        let subject = expression(subject_pair.into_inner().next().unwrap(), state, scope)?;
+        if let Some(inner_pair) = inner_pairs.next() {
+            predicate_object_list(inner_pair, &subject, state, scope)?;
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3Triples]))
+    }
+}
+
+fn predicate_object_list(
+    input_pair: Pair<'_, Rule>,
+    subject: &ObjectNode,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<(), Error> {
+    parse_rule!("n3PredicateObjectList" entry input_pair);
+
+    if input_pair.as_rule() == Rule::n3PredicateObjectList {
+        let mut inner_pairs = input_pair.into_inner();
+        while let Some(verb_pair) = inner_pairs.next() {
+            let (predicate, inverse) = verb(verb_pair, state, scope)?;
+            let object_list = inner_pairs.next().unwrap();
+            for object_pair in object_list.into_inner() {
+                let object = expression(object_pair.into_inner().next().unwrap(), state, scope)?;
+                scope.insert(subject, &predicate, object, inverse)?;
+            }
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3PredicateObjectList]))
+    }
+}
+
+///
+/// Returns the predicate IRI, and `true` if the subject and object are to be swapped as is the
+/// case for `is ... of`, `<-`, and `<=`.
+///
+fn verb(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<(Iri, bool), Error> {
+    parse_rule!("n3Verb" entry input_pair);
+
+    if input_pair.as_rule() == Rule::n3Verb {
+        let verb_str = input_pair.as_str();
+        match input_pair.into_inner().next() {
+            Some(inner_pair) if inner_pair.as_rule() == Rule::n3Predicate => {
+                // the only case where the expression doesn't start the predicate is "<-".
+                let inner_start = inner_pair.as_span().start();
+                let expression_pair = inner_pair.into_inner().next().unwrap();
+                let inverse = expression_pair.as_span().start() > inner_start;
+                let predicate = expression(expression_pair, state, scope)?;
+                Ok((predicate_iri(predicate)?, inverse))
+            }
+            Some(inner_pair) if inner_pair.as_rule() == Rule::expression => {
+                // either "has" expression, or "is" expression "of".
+                let inverse = verb_str.starts_with("is");
+                let predicate = expression(inner_pair, state, scope)?;
+                Ok((predicate_iri(predicate)?, inverse))
+            }
+            Some(inner_pair) => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::n3Predicate, Rule::expression]
+            )),
+            None => match verb_str {
+                "a" => Ok((rdf::a_type().clone(), false)),
+                "=" => Ok((owl::same_as().clone(), false)),
+                "=>" => Ok((log::implies().clone(), false)),
+                "<=" => Ok((log::implies().clone(), true)),
+                _ => Err(Error::ParserUnreachable {
+                    rule_fn: RULE_FN.into(),
+                    given: verb_str.into(),
+                }),
+            },
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3Verb]))
+    }
+}
+
+fn predicate_iri(predicate: ObjectNode) -> Result<Iri, Error> {
+    const RULE_FN: &str = "n3Predicate";
+    match predicate {
+        ObjectNode::Resource(iri) => Ok(iri),
+        _ => Err(Error::ParserExpected {
+            rule_fn: RULE_FN.into(),
+            expecting: "predicate that is an IRI".into(),
+        }),
+    }
+}
+
+fn expression(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<ObjectNode, Error> {
+    parse_rule!("expression" entry input_pair);
+
+    if input_pair.as_rule() == Rule::expression {
+        path(input_pair.into_inner().next().unwrap(), state, scope)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::expression]))
+    }
+}
+
+///
+/// Paths are parsed right-recursively but are evaluated from the left, `x!p1!p2` is `(x!p1)!p2`,
+/// so the items and operators are collected first.
+///
+fn path(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<ObjectNode, Error> {
+    parse_rule!("path" entry input_pair);
+
+    let mut items = Vec::default();
+    let mut operators = Vec::default();
+    let mut next_pair = Some(input_pair);
+    while let Some(path_pair) = next_pair {
+        if path_pair.as_rule() != Rule::path {
+            return Err(pest_error!(unexpected RULE_FN, &path_pair, [Rule::path]));
+        }
+        let path_str = path_pair.as_str();
+        let path_start = path_pair.as_span().start();
+        let mut inner_pairs = path_pair.into_inner();
+        let item_pair = inner_pairs.next().unwrap();
+        let item_end = item_pair.as_span().end() - path_start;
+        items.push(item_pair);
+        next_pair = inner_pairs.next();
+        if let Some(rest_pair) = &next_pair {
+            let rest_start = rest_pair.as_span().start() - path_start;
+            operators.push(path_operator(&path_str[item_end..rest_start]));
+        }
+    }
+
+    let mut items = items.into_iter();
+    let mut node = path_item(items.next().unwrap(), state, scope)?;
+    for (item_pair, is_forward) in items.zip(operators) {
+        let predicate = predicate_iri(path_item(item_pair, state, scope)?)?;
+        let next_node: ObjectNode = BlankNode::generate().into();
+        if is_forward {
+            scope.insert(&node, &predicate, next_node.clone(), false)?;
+        } else {
+            scope.insert(&next_node, &predicate, node, false)?;
+        }
+        node = next_node;
+    }
+    Ok(node)
+}
+
+///
+/// Returns `true` for the forward path operator `!`, and `false` for the reverse operator `^`;
+/// the text between path items may include whitespace and comments.
+///
+fn path_operator(between: &str) -> bool {
+    let mut in_comment = false;
+    for c in between.chars() {
+        match (in_comment, c) {
+            (true, '\n') | (true, '\r') => in_comment = false,
+            (true, _) => {}
+            (false, '#') => in_comment = true,
+            (false, '!') => return true,
+            (false, '^') => return false,
+            _ => {}
+        }
+    }
+    unreachable!("the grammar requires a path operator")
+}
+
+fn path_item(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<ObjectNode, Error> {
+    parse_rule!("pathItem" entry input_pair);
+
+    if input_pair.as_rule() == Rule::pathItem {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(iri(inner_pair, state)?.into()),
+            Rule::blankNode => Ok(blank_node(inner_pair, state)?.into()),
+            Rule::quickVar => Ok(quick_variable(inner_pair, state, scope)?.into()),
+            Rule::n3Collection => collection(inner_pair, state, scope),
+            Rule::n3BlankNodePropertyList => {
+                Ok(blank_node_property_list(inner_pair, state, scope)?.into())
+            }
+            Rule::turtleLiteral => Ok(literal(inner_pair, state)?.into()),
+            Rule::formula => Ok(formula(inner_pair, state, scope)?.into()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [
+                    Rule::iri,
+                    Rule::blankNode,
+                    Rule::quickVar,
+                    Rule::n3Collection,
+                    Rule::n3BlankNodePropertyList,
+                    Rule::turtleLiteral,
+                    Rule::formula
+                ]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::pathItem]))
+    }
+}
+
+fn quick_variable(
+    input_pair: Pair<'_, Rule>,
+    state: &ParserState,
+    scope: &mut Scope,
+) -> Result<Iri, Error> {
+    parse_rule!("quickVar" entry input_pair);
+
+    if input_pair.as_rule() == Rule::quickVar {
+        // strip the leading '?'
+        let name = &input_pair.as_str()[1..];
+        let id = if state.has_base() {
+            state.resolve_iri(&format!("#{name}"))?
+        } else {
+            Iri::from_str(&format!("{QUICK_VARIABLE_NAMESPACE}{name}"))?
+        };
+        if !scope.quick_variables.iter().any(|v| v.id() == &id) {
+            scope.quick_variables.push(Variable::universal(id.clone()));
+        }
+        Ok(id)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::quickVar]))
+    }
+}
+
+fn collection(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<ObjectNode, Error> {
+    parse_rule!("n3Collection" entry input_pair);
+
+    if input_pair.as_rule() == Rule::n3Collection {
+        let members = input_pair
+            .into_inner()
+            .map(|inner_pair| expression(inner_pair.into_inner().next().unwrap(), state, scope))
+            .collect::<Result<Vec<ObjectNode>, Error>>()?;
+        let mut list: ObjectNode = rdf::nil().clone().into();
+        for member in members.into_iter().rev() {
+            let node: ObjectNode = BlankNode::generate().into();
+            scope.insert(&node, rdf::first(), member, false)?;
+            scope.insert(&node, rdf::rest(), list, false)?;
+            list = node;
+        }
+        Ok(list)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3Collection]))
+    }
+}
+
+fn blank_node_property_list(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<BlankNode, Error> {
+    parse_rule!("n3BlankNodePropertyList" entry input_pair);
+
+    if input_pair.as_rule() == Rule::n3BlankNodePropertyList {
+        let node = BlankNode::generate();
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        predicate_object_list(inner_pair, &node.clone().into(), state, scope)?;
+        Ok(node)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::n3BlankNodePropertyList]))
+    }
+}
+
+///
+/// A quoted formula is parsed into it's own scope, and is denoted in the parent by a new blank
+/// node. Any quick variables used in the quoted formula are quantified in the parent.
+///
+fn formula(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    parent: &mut Scope,
+) -> Result<BlankNode, Error> {
+    parse_rule!("formula" entry input_pair);
+
+    if input_pair.as_rule() == Rule::formula {
+        let mut scope = Scope::default();
+        if let Some(inner_pair) = input_pair.into_inner().next() {
+            formula_content(inner_pair, state, &mut scope)?;
+        }
+        scope
+            .quick_variables
+            .into_iter()
+            .for_each(|v| parent.formula.add_variable(v));
+        let node = BlankNode::generate();
+        parent.formula.insert_formula(node.clone(), scope.formula);
+        Ok(node)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::formula]))
+    }
+}
+
+fn formula_content(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    scope: &mut Scope,
+) -> Result<(), Error> {
+    parse_rule!("formulaContent" entry input_pair);
+
+    if input_pair.as_rule() == Rule::formulaContent {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::n3Statement => statement(inner_pair, state, scope)?,
+                Rule::sparqlPrefix | Rule::sparqlBase => prefix_or_base(inner_pair, state)?,
+                Rule::formulaContent => formula_content(inner_pair, state, scope)?,
+                _ => {
+                    return Err(pest_error!(
+                        unexpected
+                        RULE_FN,
+                        &inner_pair,
+                        [
+                            Rule::n3Statement,
+                            Rule::sparqlPrefix,
+                            Rule::sparqlBase,
+                            Rule::formulaContent
+                        ]
+                    ));
+                }
+            }
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::formulaContent]))
+    }
+}
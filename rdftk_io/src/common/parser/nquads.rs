@@ -1,41 +1,40 @@
-use super::ntriples::{object as nt_object, predicate as nt_predicate, subject as nt_subject};
-use super::Rule;
+use super::ntriples::{
+    iri_ref as nt_iri_ref, object as nt_object, predicate as nt_predicate, subject as nt_subject,
+};
+use super::{locate, Rule, NQUADS};
 use pest::iterators::Pair;
 use rdftk_core::error::Error;
-use rdftk_core::model::data_set::DataSet;
-use rdftk_core::model::graph::{Graph, GraphName};
-use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_core::model::graph::GraphName;
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
 use rdftk_iri::Iri;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-pub(super) fn parse_doc(input_pair: Pair<'_, Rule>) -> Result<DataSet, Error> {
-    parse_rule!("nquadDoc" entry input_pair);
+pub(super) fn parse_line(
+    input_pair: Pair<'_, Rule>,
+) -> Result<Option<(Statement, Option<GraphName>)>, Error> {
+    parse_rule!("nquadLine" entry input_pair);
 
-    let mut data_set = DataSet::default();
-
-    if input_pair.as_rule() == Rule::nquadDoc {
-        for inner_pair in input_pair.into_inner() {
-            match inner_pair.as_rule() {
-                Rule::nquad => {
-                    nquad(inner_pair, &mut data_set)?;
-                }
-                Rule::EOI => {}
-                _ => {
-                    return Err(pest_error!(
-                        unexpected
-                        RULE_FN,
-                        &inner_pair,
-                        [Rule::nquad, Rule::EOI]
-                    ));
-                }
+    if input_pair.as_rule() == Rule::nquadLine {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::nquad => {
+                let located = locate(NQUADS, &inner_pair);
+                Ok(Some(nquad(inner_pair).map_err(located)?))
             }
+            Rule::EOI => Ok(None),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::nquad, Rule::EOI]
+            )),
         }
-        Ok(data_set)
     } else {
-        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::nquadDoc]))
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::nquadLine]))
     }
 }
 
@@ -43,11 +42,31 @@ pub(super) fn parse_doc(input_pair: Pair<'_, Rule>) -> Result<DataSet, Error> {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-fn subject_to_graph_name(subject: SubjectNode) -> GraphName {
-    subject.into()
+fn graph_label(input_pair: Pair<'_, Rule>) -> Result<GraphName, Error> {
+    parse_rule!("nquadGraphLabel" entry input_pair);
+
+    if input_pair.as_rule() == Rule::nquadGraphLabel {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => Ok(nt_iri_ref(inner_pair)?.into()),
+            Rule::blankNode => {
+                let node = inner_pair.as_str();
+                // strip the leading '_:'
+                Ok(BlankNode::from_str(&node[2..])?.into())
+            }
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::IRIREF, Rule::blankNode]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::nquadGraphLabel]))
+    }
 }
 
-fn nquad(input_pair: Pair<'_, Rule>, data_set: &mut DataSet) -> Result<(), Error> {
+fn nquad(input_pair: Pair<'_, Rule>) -> Result<(Statement, Option<GraphName>), Error> {
     parse_rule!("nquad" entry input_pair);
 
     if input_pair.as_rule() == Rule::nquad {
@@ -56,22 +75,12 @@ fn nquad(input_pair: Pair<'_, Rule>, data_set: &mut DataSet) -> Result<(), Error
         let predicate: Iri = nt_predicate(inner_pairs.next().unwrap())?;
         let object: ObjectNode = nt_object(inner_pairs.next().unwrap())?;
         let statement: Statement = Statement::new(subject, predicate, object);
-        let graph: &mut Graph = if let Some(new_inner_pair) = inner_pairs.next() {
-            let graph_name = subject_to_graph_name(nt_subject(new_inner_pair)?);
-            if let Some(graph) = data_set.graph_mut(&Some(graph_name.clone())) {
-                graph
-            } else {
-                data_set.insert(Graph::named(graph_name.clone()));
-                data_set.graph_mut(&Some(graph_name)).unwrap()
-            }
-        } else if let Some(graph) = data_set.graph_mut(&None) {
-            graph
-        } else {
-            data_set.insert(Graph::default());
-            data_set.graph_mut(&None).unwrap()
-        };
-        graph.insert(statement);
-        Ok(())
+        let graph_name = inner_pairs
+            .next()
+            .filter(|pair| pair.as_rule() == Rule::nquadGraphLabel)
+            .map(graph_label)
+            .transpose()?;
+        Ok((statement, graph_name))
     } else {
         Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::nquad]))
     }
@@ -1,7 +1,6 @@
-use super::Rule;
+use super::{locate, Rule, NTRIPLES};
 use pest::iterators::Pair;
-use rdftk_core::error::Error;
-use rdftk_core::model::graph::Graph;
+use rdftk_core::error::{invalid_from_str_error, Error};
 use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
 use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
 use rdftk_iri::Iri;
@@ -12,49 +11,48 @@ use std::str::FromStr;
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-pub(super) fn parse_doc(input_pair: Pair<'_, Rule>) -> Result<Graph, Error> {
-    parse_rule!("parse_doc" entry input_pair);
+pub(super) fn parse_line(input_pair: Pair<'_, Rule>) -> Result<Option<Statement>, Error> {
+    parse_rule!("parse_line" entry input_pair);
 
-    let mut graph = Graph::default();
-
-    if input_pair.as_rule() == Rule::ntripleDoc {
-        for inner_pair in input_pair.into_inner() {
-            match inner_pair.as_rule() {
-                Rule::ntriple => {
-                    let st = triple(inner_pair)?;
-                    graph.insert(st);
-                }
-                Rule::EOI => {
-                    return Ok(graph);
-                }
-                _ => {
-                    return Err(pest_error!(
-                        unexpected
-                        RULE_FN,
-                        &inner_pair,
-                        [Rule::ntriple, Rule::EOI]
-                    ));
-                }
+    if input_pair.as_rule() == Rule::ntripleLine {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::ntriple => {
+                let located = locate(NTRIPLES, &inner_pair);
+                Ok(Some(triple(inner_pair).map_err(located)?))
             }
+            Rule::EOI => Ok(None),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::ntriple, Rule::EOI]
+            )),
         }
     } else {
-        return Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::ntripleDoc]));
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::ntripleLine]))
     }
-
-    unreachable!()
 }
 
 fn triple(input_pair: Pair<'_, Rule>) -> Result<Statement, Error> {
     parse_rule!("triple" entry input_pair);
 
-    if input_pair.as_rule() == Rule::ntriple {
+    if matches!(
+        input_pair.as_rule(),
+        Rule::ntriple | Rule::ntripleQuotedTriple
+    ) {
         let mut inner_pairs = input_pair.into_inner();
         let subject = subject(inner_pairs.next().unwrap())?;
         let predicate = predicate(inner_pairs.next().unwrap())?;
         let object = object(inner_pairs.next().unwrap())?;
         Ok(Statement::new(subject, predicate, object))
     } else {
-        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::ntriple]))
+        Err(pest_error!(
+            unexpected
+            RULE_FN,
+            &input_pair,
+            [Rule::ntriple, Rule::ntripleQuotedTriple]
+        ))
     }
 }
 
@@ -71,11 +69,12 @@ pub(crate) fn subject(input_pair: Pair<'_, Rule>) -> Result<SubjectNode, Error>
                 let node = &node[2..];
                 Ok(BlankNode::from_str(node)?.into())
             }
+            Rule::ntripleQuotedTriple => Ok(triple(inner_pair)?.into()),
             _ => Err(pest_error!(
                 unexpected
                 RULE_FN,
                 &inner_pair,
-                [Rule::IRIREF, Rule::blankNode]
+                [Rule::IRIREF, Rule::blankNode, Rule::ntripleQuotedTriple]
             )),
         }
     } else {
@@ -115,11 +114,17 @@ pub(crate) fn object(input_pair: Pair<'_, Rule>) -> Result<ObjectNode, Error> {
                 let literal = literal(inner_pair)?;
                 Ok(literal.into())
             }
+            Rule::ntripleQuotedTriple => Ok(triple(inner_pair)?.into()),
             _ => Err(pest_error!(
                  unexpected
                 RULE_FN,
                 &inner_pair,
-                [Rule::IRIREF, Rule::blankNode, Rule::ntripleLiteral]
+                [
+                    Rule::IRIREF,
+                    Rule::blankNode,
+                    Rule::ntripleLiteral,
+                    Rule::ntripleQuotedTriple
+                ]
             )),
         }
     } else {
@@ -148,7 +153,7 @@ fn rdf_literal(input_pair: Pair<'_, Rule>) -> Result<Literal, Error> {
         if let Some(other) = inner_pair.next() {
             match other.as_rule() {
                 Rule::IRIREF => {
-                    let data_type = DataType::Other(iri_ref(other)?);
+                    let data_type = DataType::from(iri_ref(other)?);
                     Ok(Literal::with_data_type(&lexical_form, data_type))
                 }
                 Rule::LANGTAG => {
@@ -179,7 +184,7 @@ fn string(input_pair: Pair<'_, Rule>) -> Result<String, Error> {
             Rule::STRING_LITERAL_QUOTE => {
                 let inner_pair = inner_pair.into_inner().next().unwrap();
                 if inner_pair.as_rule() == Rule::QUOTE_INNER {
-                    Ok(inner_pair.as_str().to_string())
+                    Ok(unescape_string(inner_pair.as_str()))
                 } else {
                     Err(pest_error!(
                         unexpected
@@ -201,28 +206,26 @@ fn string(input_pair: Pair<'_, Rule>) -> Result<String, Error> {
     }
 }
 
-fn iri_ref(input_pair: Pair<'_, Rule>) -> Result<Iri, Error> {
+pub(crate) fn iri_ref(input_pair: Pair<'_, Rule>) -> Result<Iri, Error> {
     parse_rule!("iri_ref" entry input_pair);
 
     if input_pair.as_rule() == Rule::IRIREF {
         let iri = input_pair.as_str().to_string();
         // strip the '<' and '>' characters.
-        let iri_str = unescape_iri(&iri[1..iri.len() - 1]);
+        let iri_str = unescape_iri(&iri[1..iri.len() - 1])?;
         Ok(Iri::from_str(&iri_str)?)
     } else {
         Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::IRIREF]))
     }
 }
 
-fn lang_tag(input_pair: Pair<'_, Rule>) -> Result<LanguageTag, Error> {
+pub(crate) fn lang_tag(input_pair: Pair<'_, Rule>) -> Result<LanguageTag, Error> {
     parse_rule!("lang_tag" entry input_pair);
 
     if input_pair.as_rule() == Rule::LANGTAG {
         let tag = input_pair.as_str().to_string();
-        println!("**{tag}**");
         // strip the leading '@'
         let tag = &tag[1..];
-        println!("**{tag}**");
         Ok(LanguageTag::parse(tag)?)
     } else {
         Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::LANGTAG]))
@@ -236,7 +239,7 @@ lazy_static::lazy_static! {
         Regex::new(r"(\\U[[:xdigit:]]{8})|(\\u[[:xdigit:]]{4})").unwrap();
 }
 
-fn unescape_iri(iri: &str) -> String {
+pub(crate) fn unescape_iri(iri: &str) -> Result<String, Error> {
     let (new_iri, end) =
         UNICODE_ESC
             .captures_iter(iri)
@@ -253,12 +256,56 @@ fn unescape_iri(iri: &str) -> String {
                 )
             });
 
-    format!("{}{}", new_iri, &iri[end..])
+    let new_iri = format!("{}{}", new_iri, &iri[end..]);
+
+    // escapes may not be used to introduce characters that are not allowed in an IRIREF.
+    if new_iri
+        .chars()
+        .any(|c| c <= ' ' || "<>\"{}|^`\\".contains(c))
+    {
+        Err(invalid_from_str_error(new_iri, "IRIREF"))
+    } else {
+        Ok(new_iri)
+    }
+}
+
+///
+/// Replace the `ECHAR` and `UCHAR` escape sequences in a string literal with the characters
+/// they represent.
+///
+pub(crate) fn unescape_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => result.push('\t'),
+                Some('b') => result.push('\u{0008}'),
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some('f') => result.push('\u{000C}'),
+                Some('u') => {
+                    result.push(hex_to_char(&chars.by_ref().take(4).collect::<String>()));
+                }
+                Some('U') => {
+                    result.push(hex_to_char(&chars.by_ref().take(8).collect::<String>()));
+                }
+                Some(c) => result.push(c),
+                None => result.push(c),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
 }
 
 fn unescape_uchar(uchar: &str) -> char {
+    hex_to_char(&uchar[2..])
+}
+
+fn hex_to_char(hex: &str) -> char {
     use std::char;
-    let uchar = &uchar[2..];
-    let uchar_u32 = u32::from_str_radix(uchar, 16).unwrap();
-    char::from_u32(uchar_u32).unwrap()
+    let hex_u32 = u32::from_str_radix(hex, 16).unwrap();
+    char::from_u32(hex_u32).unwrap_or(char::REPLACEMENT_CHARACTER)
 }
@@ -1,33 +1,272 @@
-// use ...
+use super::turtle::{
+    blank_node, blank_node_property_list, collection, directive, iri, predicate_object_list,
+    triples, ParserState,
+};
+use super::{locate, Rule, TRIG};
+use crate::ReaderOptions;
+use pest::iterators::Pair;
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::statement::SubjectNode;
+use std::collections::HashMap;
 
 // ------------------------------------------------------------------------------------------------
-// Public Macros
+// Public Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Public Types
-// ------------------------------------------------------------------------------------------------
+pub(super) fn parse_doc(
+    input_pair: Pair<'_, Rule>,
+    options: &ReaderOptions,
+) -> Result<DataSet, Error> {
+    parse_rule!("trigDoc" entry input_pair);
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+    let mut state = ParserState::new(TRIG, options);
+    let mut graphs = Graphs::default();
 
-// ------------------------------------------------------------------------------------------------
-// Private Macros
-// ------------------------------------------------------------------------------------------------
+    if input_pair.as_rule() == Rule::trigDoc {
+        for inner_pair in input_pair.into_inner() {
+            let located = locate(TRIG, &inner_pair);
+            match inner_pair.as_rule() {
+                Rule::directive => {
+                    directive(inner_pair, &mut state).map_err(located)?;
+                }
+                Rule::triplesOrGraph => {
+                    triples_or_graph(inner_pair, &mut state, &mut graphs).map_err(located)?;
+                }
+                Rule::wrappedGraph => {
+                    wrapped_graph(inner_pair, &mut state, graphs.graph_mut(None))
+                        .map_err(located)?;
+                }
+                Rule::triples2 => {
+                    triples2(inner_pair, &mut state, graphs.graph_mut(None)).map_err(located)?;
+                }
+                Rule::namedGraph => {
+                    named_graph(inner_pair, &mut state, &mut graphs).map_err(located)?;
+                }
+                Rule::EOI => {}
+                _ => {
+                    return Err(pest_error!(
+                        unexpected
+                        RULE_FN,
+                        &inner_pair,
+                        [
+                            Rule::directive,
+                            Rule::triplesOrGraph,
+                            Rule::wrappedGraph,
+                            Rule::triples2,
+                            Rule::namedGraph,
+                            Rule::EOI
+                        ]
+                    ));
+                }
+            }
+        }
+        let mappings = state.into_mappings();
+        Ok(graphs
+            .0
+            .into_values()
+            .map(|mut graph| {
+                if let Some(mappings) = &mappings {
+                    graph.set_prefix_mappings(mappings.clone());
+                }
+                graph
+            })
+            .collect())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::trigDoc]))
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Graphs are created on first use, either by a graph block or by a statement in the default
+/// graph, so that multiple blocks with the same name are merged.
+///
+#[derive(Debug, Default)]
+struct Graphs(HashMap<Option<GraphName>, Graph>);
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Graphs {
+    fn graph_mut(&mut self, name: Option<GraphName>) -> &mut Graph {
+        self.0.entry(name.clone()).or_insert_with(|| match name {
+            None => Graph::default(),
+            Some(name) => Graph::named(name),
+        })
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn named_graph(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graphs: &mut Graphs,
+) -> Result<(), Error> {
+    parse_rule!("namedGraph" entry input_pair);
+
+    if input_pair.as_rule() == Rule::namedGraph {
+        let mut inner_pairs = input_pair.into_inner();
+        let name = label_or_subject(inner_pairs.next().unwrap(), state)?;
+        let graph = graphs.graph_mut(Some(name.into()));
+        wrapped_graph(inner_pairs.next().unwrap(), state, graph)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::namedGraph]))
+    }
+}
+
+fn triples_or_graph(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graphs: &mut Graphs,
+) -> Result<(), Error> {
+    parse_rule!("triplesOrGraph" entry input_pair);
+
+    if input_pair.as_rule() == Rule::triplesOrGraph {
+        let mut inner_pairs = input_pair.into_inner();
+        let label = label_or_subject(inner_pairs.next().unwrap(), state)?;
+        let inner_pair = inner_pairs.next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::wrappedGraph => {
+                let graph = graphs.graph_mut(Some(label.into()));
+                wrapped_graph(inner_pair, state, graph)
+            }
+            Rule::predicateObjectList => {
+                predicate_object_list(inner_pair, &label, state, graphs.graph_mut(None))
+            }
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::wrappedGraph, Rule::predicateObjectList]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::triplesOrGraph]))
+    }
+}
+
+fn triples2(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<(), Error> {
+    parse_rule!("triples2" entry input_pair);
+
+    if input_pair.as_rule() == Rule::triples2 {
+        let mut inner_pairs = input_pair.into_inner();
+        let inner_pair = inner_pairs.next().unwrap();
+        let subject: SubjectNode = match inner_pair.as_rule() {
+            Rule::blankNodePropertyList => {
+                blank_node_property_list(inner_pair, state, graph)?.into()
+            }
+            Rule::collection => collection(inner_pair, state, graph)?
+                .to_subject()
+                .expect("a collection is always a subject"),
+            _ => {
+                return Err(pest_error!(
+                    unexpected
+                    RULE_FN,
+                    &inner_pair,
+                    [Rule::blankNodePropertyList, Rule::collection]
+                ));
+            }
+        };
+        for inner_pair in inner_pairs {
+            match inner_pair.as_rule() {
+                Rule::predicateObjectList => {
+                    predicate_object_list(inner_pair, &subject, state, graph)?;
+                }
+                Rule::END_OF_STATEMENT => {}
+                _ => {
+                    return Err(pest_error!(
+                        unexpected
+                        RULE_FN,
+                        &inner_pair,
+                        [Rule::predicateObjectList, Rule::END_OF_STATEMENT]
+                    ));
+                }
+            }
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::triples2]))
+    }
+}
+
+fn wrapped_graph(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<(), Error> {
+    parse_rule!("wrappedGraph" entry input_pair);
+
+    if input_pair.as_rule() == Rule::wrappedGraph {
+        if let Some(inner_pair) = input_pair.into_inner().next() {
+            triples_block(inner_pair, state, graph)?;
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::wrappedGraph]))
+    }
+}
+
+fn triples_block(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<(), Error> {
+    parse_rule!("triplesBlock" entry input_pair);
+
+    if input_pair.as_rule() == Rule::triplesBlock {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::triples => triples(inner_pair, state, graph)?,
+                Rule::triplesBlock => triples_block(inner_pair, state, graph)?,
+                Rule::END_OF_STATEMENT => {}
+                _ => {
+                    return Err(pest_error!(
+                        unexpected
+                        RULE_FN,
+                        &inner_pair,
+                        [Rule::triples, Rule::triplesBlock, Rule::END_OF_STATEMENT]
+                    ));
+                }
+            }
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::triplesBlock]))
+    }
+}
+
+fn label_or_subject(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<SubjectNode, Error> {
+    parse_rule!("labelOrSubject" entry input_pair);
+
+    if input_pair.as_rule() == Rule::labelOrSubject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(iri(inner_pair, state)?.into()),
+            Rule::blankNode => Ok(blank_node(inner_pair, state)?.into()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::iri, Rule::blankNode]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::labelOrSubject]))
+    }
+}
@@ -1,33 +1,781 @@
-// use ...
-
-// ------------------------------------------------------------------------------------------------
-// Public Macros
-// ------------------------------------------------------------------------------------------------
+use super::ntriples::{lang_tag, unescape_iri, unescape_string};
+use super::{locate, pair_location, pest_syntax_error, rebase_error, CommonParser, Rule, TURTLE};
+use crate::common::relabel::DocumentLabels;
+use crate::ReaderOptions;
+use pest::iterators::Pair;
+use pest::Parser as _;
+use rdftk_core::error::{syntax_error, Error, SourceLocation};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap, Namespace};
+use rdftk_names::{rdf, xsd};
+use std::collections::HashMap;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// The state carried across a Turtle (or TriG) document; the current base IRI, the set of
+/// prefixes declared so far, and the blank nodes generated for each label. The representation
+/// is used to report errors located at the term that caused them.
+///
+#[derive(Debug, Default)]
+pub(super) struct ParserState {
+    representation: &'static str,
+    base: Option<Iri>,
+    prefixes: HashMap<String, String>,
+    mappings: IriPrefixMap,
+    carry_prefixes: bool,
+    blank_nodes: DocumentLabels,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Macros
-// ------------------------------------------------------------------------------------------------
+pub(super) fn parse_doc(
+    input_pair: Pair<'_, Rule>,
+    options: &ReaderOptions,
+) -> Result<Graph, Error> {
+    parse_rule!("turtleStarDoc" entry input_pair);
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+    let mut state = ParserState::new(TURTLE, options);
+    let mut graph = Graph::default();
+
+    if input_pair.as_rule() == Rule::turtleStarDoc {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::statement => {
+                    let located = locate(TURTLE, &inner_pair);
+                    statement(inner_pair, &mut state, &mut graph).map_err(located)?;
+                }
+                Rule::EOI => {}
+                _ => {
+                    return Err(pest_error!(
+                        unexpected
+                        RULE_FN,
+                        &inner_pair,
+                        [Rule::statement, Rule::EOI]
+                    ));
+                }
+            }
+        }
+        if let Some(mappings) = state.into_mappings() {
+            graph.set_prefix_mappings(mappings);
+        }
+        Ok(graph)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtleStarDoc]))
+    }
+}
+
+///
+/// Parse `source` one statement at a time; a statement that fails to parse is skipped by
+/// resynchronizing at the next `.` that is followed by whitespace, and its error is added to
+/// the returned list. A statement is only added to the graph if all of it was parsed.
+///
+pub(super) fn parse_doc_lenient(source: &str, options: &ReaderOptions) -> (Graph, Vec<Error>) {
+    let mut state = ParserState::new(TURTLE, options);
+    let mut graph = Graph::default();
+    let mut errors = Vec::default();
+    let mut start = 0;
+
+    while start < source.len() {
+        let rest = &source[start..];
+        match CommonParser::parse(Rule::turtleStatement, rest) {
+            Ok(mut parsed) => {
+                let inner_pair = parsed.next().unwrap().into_inner().next().unwrap();
+                if inner_pair.as_rule() == Rule::EOI {
+                    break;
+                }
+                let end = start + inner_pair.as_span().end();
+                let located = locate(TURTLE, &inner_pair);
+                let mut statements = Graph::default();
+                match statement(inner_pair, &mut state, &mut statements).map_err(located) {
+                    Ok(()) => graph.merge(&statements),
+                    Err(e) => errors.push(rebase_error(e, source, start)),
+                }
+                start = end;
+            }
+            Err(e) => {
+                let e = rebase_error(pest_syntax_error(TURTLE, e), source, start);
+                let failed_at = e.location().map(|l| l.offset()).unwrap_or(start);
+                start = resynchronize(source, failed_at);
+                errors.push(e);
+            }
+        }
+    }
+
+    if let Some(mappings) = state.into_mappings() {
+        graph.set_prefix_mappings(mappings);
+    }
+    (graph, errors)
+}
+
+pub(super) fn directive(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<(), Error> {
+    parse_rule!("directive" entry input_pair);
+
+    if input_pair.as_rule() == Rule::directive {
+        prefix_or_base(input_pair.into_inner().next().unwrap(), state)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::directive]))
+    }
+}
+
+///
+/// Handle any of the prefix or base declarations, these are shared by Turtle, TriG and N3.
+///
+pub(super) fn prefix_or_base(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<(), Error> {
+    parse_rule!("prefixOrBase" entry input_pair);
+
+    match input_pair.as_rule() {
+        Rule::prefixID | Rule::sparqlPrefix | Rule::n3PrefixID => {
+            let mut inner_pairs = input_pair.into_inner();
+            let prefix = inner_pairs.next().unwrap().as_str();
+            let prefix = &prefix[..prefix.len() - 1];
+            let iri = state.resolve_iri_ref(inner_pairs.next().unwrap())?;
+            state.add_prefix(prefix, iri);
+            Ok(())
+        }
+        Rule::base | Rule::sparqlBase | Rule::n3Base => {
+            let iri = state.resolve_iri_ref(input_pair.into_inner().next().unwrap())?;
+            state.base = Some(iri);
+            Ok(())
+        }
+        _ => Err(pest_error!(
+            unexpected
+            RULE_FN,
+            &input_pair,
+            [
+                Rule::prefixID,
+                Rule::base,
+                Rule::sparqlPrefix,
+                Rule::sparqlBase,
+                Rule::n3PrefixID,
+                Rule::n3Base
+            ]
+        )),
+    }
+}
+
+pub(super) fn triples(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<(), Error> {
+    parse_rule!("triples" entry input_pair);
+
+    if input_pair.as_rule() == Rule::triples {
+        let mut inner_pairs = input_pair.into_inner();
+        let inner_pair = inner_pairs.next().unwrap();
+        let subject = match inner_pair.as_rule() {
+            Rule::turtleSubject => subject(inner_pair, state, graph)?,
+            Rule::blankNodePropertyList => {
+                blank_node_property_list(inner_pair, state, graph)?.into()
+            }
+            _ => {
+                return Err(pest_error!(
+                    unexpected
+                    RULE_FN,
+                    &inner_pair,
+                    [Rule::turtleSubject, Rule::blankNodePropertyList]
+                ));
+            }
+        };
+        if let Some(inner_pair) = inner_pairs.next() {
+            predicate_object_list(inner_pair, &subject, state, graph)?;
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::triples]))
+    }
+}
+
+pub(super) fn predicate_object_list(
+    input_pair: Pair<'_, Rule>,
+    subject: &SubjectNode,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<(), Error> {
+    parse_rule!("predicateObjectList" entry input_pair);
+
+    if input_pair.as_rule() == Rule::predicateObjectList {
+        for inner_pair in input_pair.into_inner() {
+            if inner_pair.as_rule() == Rule::verbObjectList {
+                let mut inner_pairs = inner_pair.into_inner();
+                let predicate = verb(inner_pairs.next().unwrap(), state)?;
+                let object_list = inner_pairs.next().unwrap();
+                let mut asserted: Option<Statement> = None;
+                for object_pair in object_list.into_inner() {
+                    match object_pair.as_rule() {
+                        Rule::turtleObject => {
+                            let object = object(object_pair, state, graph)?;
+                            let statement =
+                                Statement::new(subject.clone(), predicate.clone(), object);
+                            graph.insert(statement.clone());
+                            asserted = Some(statement);
+                        }
+                        Rule::annotation => {
+                            // the grammar only allows an annotation after an object.
+                            let quoted = asserted.take().unwrap();
+                            predicate_object_list(
+                                object_pair.into_inner().next().unwrap(),
+                                &quoted.into(),
+                                state,
+                                graph,
+                            )?;
+                        }
+                        _ => {
+                            return Err(pest_error!(
+                                unexpected
+                                RULE_FN,
+                                &object_pair,
+                                [Rule::turtleObject, Rule::annotation]
+                            ));
+                        }
+                    }
+                }
+            } else {
+                return Err(pest_error!(
+                    unexpected
+                    RULE_FN,
+                    &inner_pair,
+                    [Rule::verbObjectList]
+                ));
+            }
+        }
+        Ok(())
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::predicateObjectList]))
+    }
+}
+
+pub(super) fn blank_node_property_list(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<BlankNode, Error> {
+    parse_rule!("blankNodePropertyList" entry input_pair);
+
+    if input_pair.as_rule() == Rule::blankNodePropertyList {
+        let node = BlankNode::generate();
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        predicate_object_list(inner_pair, &node.clone().into(), state, graph)?;
+        Ok(node)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::blankNodePropertyList]))
+    }
+}
+
+pub(super) fn collection(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<ObjectNode, Error> {
+    parse_rule!("collection" entry input_pair);
+
+    if input_pair.as_rule() == Rule::collection {
+        let members = input_pair
+            .into_inner()
+            .map(|inner_pair| object(inner_pair, state, graph))
+            .collect::<Result<Vec<ObjectNode>, Error>>()?;
+        let mut list: ObjectNode = rdf::nil().clone().into();
+        for member in members.into_iter().rev() {
+            let node = BlankNode::generate();
+            graph.insert(Statement::new(node.clone(), rdf::first().clone(), member));
+            graph.insert(Statement::new(node.clone(), rdf::rest().clone(), list));
+            list = node.into();
+        }
+        Ok(list)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::collection]))
+    }
+}
+
+pub(super) fn iri(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<Iri, Error> {
+    parse_rule!("iri" entry input_pair);
+
+    if input_pair.as_rule() == Rule::iri {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => state.resolve_iri_ref(inner_pair),
+            Rule::prefixedName => prefixed_name(inner_pair, state),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::IRIREF, Rule::prefixedName]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::iri]))
+    }
+}
+
+pub(super) fn blank_node(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<BlankNode, Error> {
+    parse_rule!("blankNode" entry input_pair);
+
+    if input_pair.as_rule() == Rule::blankNode {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::BLANK_NODE_LABEL => {
+                let located = locate(state.representation, &inner_pair);
+                // strip the leading '_:'
+                state
+                    .labeled_blank_node(&inner_pair.as_str()[2..])
+                    .map_err(located)
+            }
+            Rule::ANON => Ok(BlankNode::generate()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::BLANK_NODE_LABEL, Rule::ANON]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::blankNode]))
+    }
+}
+
+pub(super) fn literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<Literal, Error> {
+    parse_rule!("turtleLiteral" entry input_pair);
+
+    if input_pair.as_rule() == Rule::turtleLiteral {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::turtleRdfLiteral => rdf_literal(inner_pair, state),
+            Rule::NumericLiteral => numeric_literal(inner_pair),
+            Rule::BooleanLiteral => Ok(Literal::with_data_type(
+                inner_pair.as_str(),
+                DataType::Boolean,
+            )),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [
+                    Rule::turtleRdfLiteral,
+                    Rule::NumericLiteral,
+                    Rule::BooleanLiteral
+                ]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtleLiteral]))
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl ParserState {
+    pub(super) fn new(representation: &'static str, options: &ReaderOptions) -> Self {
+        Self {
+            representation,
+            base: options.base().cloned(),
+            carry_prefixes: options.carry_prefixes(),
+            blank_nodes: DocumentLabels::new(options.blank_nodes()),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn has_base(&self) -> bool {
+        self.base.is_some()
+    }
+
+    ///
+    /// The prefixes declared in the document, if they are to be carried into the result.
+    ///
+    pub(super) fn into_mappings(self) -> Option<IriPrefixMap> {
+        self.carry_prefixes.then_some(self.mappings)
+    }
+
+    fn add_prefix(&mut self, prefix: &str, iri: Iri) {
+        if prefix.is_empty() {
+            self.mappings.set_default_namespace(iri.clone());
+        } else {
+            self.mappings
+                .insert(Namespace::new_unchecked(prefix), iri.clone());
+        }
+        let _ = self.prefixes.insert(prefix.to_string(), format!("{iri:#}"));
+    }
+
+    ///
+    /// Labeled blank nodes are scoped to the document, and are kept apart from the nodes
+    /// generated for anonymous blank nodes.
+    ///
+    fn labeled_blank_node(&mut self, label: &str) -> Result<BlankNode, Error> {
+        // check the label is valid, even when it is not retained.
+        let _ = BlankNode::from_str(label)?;
+        Ok(self.blank_nodes.blank_node(label))
+    }
+
+    pub(super) fn resolve_iri_ref(&self, input_pair: Pair<'_, Rule>) -> Result<Iri, Error> {
+        let located = locate(self.representation, &input_pair);
+        let iri = input_pair.as_str();
+        // strip the '<' and '>' characters.
+        unescape_iri(&iri[1..iri.len() - 1])
+            .and_then(|iri| self.resolve_iri(&iri))
+            .map_err(located)
+    }
+
+    pub(super) fn resolve_iri(&self, iri: &str) -> Result<Iri, Error> {
+        if let Some(base) = &self.base {
+            let base: &url::Url = base.as_ref();
+            Ok(Iri::from(base.join(iri)?))
+        } else {
+            Ok(Iri::from_str(iri)?)
+        }
+    }
+
+    fn expand(&self, prefix: &str, local: &str, location: SourceLocation) -> Result<Iri, Error> {
+        if let Some(namespace) = self.prefixes.get(prefix) {
+            Iri::from_str(&format!("{namespace}{local}"))
+                .map_err(|e| Error::from(e).with_location(self.representation, location))
+        } else {
+            Err(syntax_error(
+                self.representation,
+                format!("undeclared prefix `{prefix}`"),
+                location,
+            ))
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+///
+/// Return the offset just past the first `.` at or after `offset` that is followed by
+/// whitespace, or the end of `source`, and so probably ends a statement.
+///
+fn resynchronize(source: &str, offset: usize) -> usize {
+    let bytes = source.as_bytes();
+    (offset..bytes.len())
+        .find(|&i| bytes[i] == b'.' && bytes.get(i + 1).is_none_or(|c| c.is_ascii_whitespace()))
+        .map(|i| i + 1)
+        .unwrap_or(source.len())
+}
+
+fn statement(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<(), Error> {
+    parse_rule!("statement" entry input_pair);
+
+    if input_pair.as_rule() == Rule::statement {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::directive => directive(inner_pair, state),
+            Rule::triples => triples(inner_pair, state, graph),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::directive, Rule::triples]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::statement]))
+    }
+}
+
+fn subject(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<SubjectNode, Error> {
+    parse_rule!("turtleSubject" entry input_pair);
+
+    if input_pair.as_rule() == Rule::turtleSubject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(iri(inner_pair, state)?.into()),
+            Rule::blankNode => Ok(blank_node(inner_pair, state)?.into()),
+            Rule::collection => Ok(collection(inner_pair, state, graph)?.to_subject().unwrap()),
+            Rule::tripleX => Ok(triple_x(inner_pair, state)?.into()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::iri, Rule::blankNode, Rule::collection, Rule::tripleX]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtleSubject]))
+    }
+}
+
+fn verb(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<Iri, Error> {
+    parse_rule!("verb" entry input_pair);
+
+    if input_pair.as_rule() == Rule::verb {
+        if let Some(inner_pair) = input_pair.into_inner().next() {
+            predicate(inner_pair, state)
+        } else {
+            // the keyword 'a'
+            Ok(rdf::a_type().clone())
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::verb]))
+    }
+}
+
+fn predicate(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<Iri, Error> {
+    parse_rule!("turtlePredicate" entry input_pair);
+
+    if input_pair.as_rule() == Rule::turtlePredicate {
+        iri(input_pair.into_inner().next().unwrap(), state)
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtlePredicate]))
+    }
+}
+
+fn object(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &mut Graph,
+) -> Result<ObjectNode, Error> {
+    parse_rule!("turtleObject" entry input_pair);
+
+    if input_pair.as_rule() == Rule::turtleObject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(iri(inner_pair, state)?.into()),
+            Rule::blankNode => Ok(blank_node(inner_pair, state)?.into()),
+            Rule::turtleLiteral => Ok(literal(inner_pair, state)?.into()),
+            Rule::collection => collection(inner_pair, state, graph),
+            Rule::blankNodePropertyList => {
+                Ok(blank_node_property_list(inner_pair, state, graph)?.into())
+            }
+            Rule::tripleX => Ok(triple_x(inner_pair, state)?.into()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [
+                    Rule::iri,
+                    Rule::blankNode,
+                    Rule::turtleLiteral,
+                    Rule::collection,
+                    Rule::blankNodePropertyList,
+                    Rule::tripleX
+                ]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtleObject]))
+    }
+}
+
+fn triple_x(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Statement, Error> {
+    parse_rule!("tripleX" entry input_pair);
+
+    if input_pair.as_rule() == Rule::tripleX {
+        let mut inner_pairs = input_pair.into_inner();
+        let subject = subject_x(inner_pairs.next().unwrap(), state)?;
+        let predicate = predicate(inner_pairs.next().unwrap(), state)?;
+        let object = object_x(inner_pairs.next().unwrap(), state)?;
+        Ok(Statement::new(subject, predicate, object))
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::tripleX]))
+    }
+}
+
+fn subject_x(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<SubjectNode, Error> {
+    parse_rule!("subjectX" entry input_pair);
+
+    if input_pair.as_rule() == Rule::subjectX {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(iri(inner_pair, state)?.into()),
+            Rule::blankNode => Ok(blank_node(inner_pair, state)?.into()),
+            Rule::tripleX => Ok(triple_x(inner_pair, state)?.into()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::iri, Rule::blankNode, Rule::tripleX]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::subjectX]))
+    }
+}
+
+fn object_x(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<ObjectNode, Error> {
+    parse_rule!("objectX" entry input_pair);
+
+    if input_pair.as_rule() == Rule::objectX {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(iri(inner_pair, state)?.into()),
+            Rule::blankNode => Ok(blank_node(inner_pair, state)?.into()),
+            Rule::turtleLiteral => Ok(literal(inner_pair, state)?.into()),
+            Rule::tripleX => Ok(triple_x(inner_pair, state)?.into()),
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [
+                    Rule::iri,
+                    Rule::blankNode,
+                    Rule::turtleLiteral,
+                    Rule::tripleX
+                ]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::objectX]))
+    }
+}
+
+fn prefixed_name(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<Iri, Error> {
+    parse_rule!("prefixedName" entry input_pair);
+
+    if input_pair.as_rule() == Rule::prefixedName {
+        let location = pair_location(&input_pair);
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::PNAME_LN => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let prefix = inner_pairs.next().unwrap().as_str();
+                let local = inner_pairs.next().unwrap().as_str();
+                state.expand(
+                    &prefix[..prefix.len() - 1],
+                    &unescape_local_name(local),
+                    location,
+                )
+            }
+            Rule::PNAME_NS => {
+                let prefix = inner_pair.as_str();
+                state.expand(&prefix[..prefix.len() - 1], "", location)
+            }
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [Rule::PNAME_LN, Rule::PNAME_NS]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::prefixedName]))
+    }
+}
+
+fn rdf_literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<Literal, Error> {
+    parse_rule!("turtleRdfLiteral" entry input_pair);
+
+    if input_pair.as_rule() == Rule::turtleRdfLiteral {
+        let mut inner_pairs = input_pair.into_inner();
+        let lexical_form = string(inner_pairs.next().unwrap())?;
+
+        if let Some(other) = inner_pairs.next() {
+            match other.as_rule() {
+                Rule::iri => {
+                    let data_type = DataType::from(iri(other, state)?);
+                    Ok(Literal::with_data_type(&lexical_form, data_type))
+                }
+                Rule::LANGTAG => {
+                    let located = locate(state.representation, &other);
+                    let lang_tag = lang_tag(other).map_err(located)?;
+                    Ok(Literal::with_language(&lexical_form, lang_tag))
+                }
+                _ => Err(pest_error!(
+                    unexpected
+                    RULE_FN,
+                    &other,
+                    [Rule::iri, Rule::LANGTAG]
+                )),
+            }
+        } else {
+            Ok(Literal::plain(&lexical_form))
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtleRdfLiteral]))
+    }
+}
+
+fn numeric_literal(input_pair: Pair<'_, Rule>) -> Result<Literal, Error> {
+    parse_rule!("NumericLiteral" entry input_pair);
+
+    if input_pair.as_rule() == Rule::NumericLiteral {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        let data_type = match inner_pair.as_rule() {
+            Rule::Integer => DataType::from(xsd::integer().clone()),
+            Rule::Decimal => DataType::Decimal,
+            Rule::Double => DataType::Double,
+            _ => {
+                return Err(pest_error!(
+                    unexpected
+                    RULE_FN,
+                    &inner_pair,
+                    [Rule::Integer, Rule::Decimal, Rule::Double]
+                ));
+            }
+        };
+        Ok(Literal::with_data_type(inner_pair.as_str(), data_type))
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::NumericLiteral]))
+    }
+}
+
+fn string(input_pair: Pair<'_, Rule>) -> Result<String, Error> {
+    parse_rule!("turtleString" entry input_pair);
+
+    if input_pair.as_rule() == Rule::turtleString {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::STRING_LITERAL_QUOTE
+            | Rule::STRING_LITERAL_SINGLE_QUOTE
+            | Rule::STRING_LITERAL_LONG_QUOTE
+            | Rule::STRING_LITERAL_LONG_SINGLE_QUOTE => {
+                let inner_pair = inner_pair.into_inner().next().unwrap();
+                Ok(unescape_string(inner_pair.as_str()))
+            }
+            _ => Err(pest_error!(
+                unexpected
+                RULE_FN,
+                &inner_pair,
+                [
+                    Rule::STRING_LITERAL_QUOTE,
+                    Rule::STRING_LITERAL_SINGLE_QUOTE,
+                    Rule::STRING_LITERAL_LONG_QUOTE,
+                    Rule::STRING_LITERAL_LONG_SINGLE_QUOTE
+                ]
+            )),
+        }
+    } else {
+        Err(pest_error!(unexpected RULE_FN, &input_pair, [Rule::turtleString]))
+    }
+}
+
+fn unescape_local_name(local: &str) -> String {
+    // PN_LOCAL_ESC sequences are replaced with the escaped character, percent
+    // encoded values are retained as-is.
+    let mut result = String::with_capacity(local.len());
+    let mut chars = local.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(c) = chars.next() {
+                result.push(c);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
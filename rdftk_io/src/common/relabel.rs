@@ -0,0 +1,165 @@
+#[cfg(any(
+    feature = "json-ld",
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "rdfa",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
+use crate::BlankNodeLabels;
+#[cfg(any(feature = "binary", feature = "hextuples", feature = "nq"))]
+use rdftk_core::model::graph::GraphName;
+use rdftk_core::model::statement::BlankNode;
+#[cfg(any(
+    feature = "binary",
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "json",
+    feature = "nq",
+    feature = "nt"
+))]
+use rdftk_core::model::statement::{Collection, ObjectNode, Statement, SubjectNode};
+use std::collections::HashMap;
+#[cfg(any(
+    feature = "json-ld",
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "rdfa",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Replaces the blank nodes in statements with generated ones, consistently for the lifetime
+/// of the relabeler; this is used by readers that create blank nodes directly from their
+/// labels when the `BlankNodeLabels::Relabel` option is set.
+///
+#[cfg(any(
+    feature = "binary",
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "json",
+    feature = "nq",
+    feature = "nt"
+))]
+#[derive(Debug, Default)]
+pub(crate) struct Relabeler {
+    labels: HashMap<BlankNode, BlankNode>,
+}
+
+///
+/// Maps the blank node labels in a single document to blank nodes, for readers that also
+/// generate nodes for anonymous blank nodes. A preserved label is kept as the identifier of its
+/// node unless it is not a valid blank node name, or has the form of a generated identifier; such
+/// labels are mapped to generated nodes, as all labels are when relabeling, so that a label can
+/// never clash with a node generated for an anonymous node.
+///
+#[cfg(any(
+    feature = "json-ld",
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "rdfa",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
+#[derive(Debug, Default)]
+pub(crate) struct DocumentLabels {
+    relabel: bool,
+    labels: HashMap<String, BlankNode>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(any(
+    feature = "binary",
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "json",
+    feature = "nq",
+    feature = "nt"
+))]
+impl Relabeler {
+    pub(crate) fn statement(&mut self, statement: &Statement) -> Statement {
+        let subject = match statement.subject() {
+            SubjectNode::Blank(node) => self.blank_node(node).into(),
+            SubjectNode::Statement(nested) => self.statement(nested).into(),
+            subject => subject.clone(),
+        };
+        let object = self.object(statement.object());
+        Statement::new(subject, statement.predicate().clone(), object)
+    }
+
+    fn object(&mut self, object: &ObjectNode) -> ObjectNode {
+        match object {
+            ObjectNode::Blank(node) => self.blank_node(node).into(),
+            ObjectNode::Statement(nested) => self.statement(nested).into(),
+            ObjectNode::Collection(collection) => collection
+                .iter()
+                .map(|member| self.object(member))
+                .collect::<Collection>()
+                .into(),
+            object => object.clone(),
+        }
+    }
+
+    #[cfg(any(feature = "binary", feature = "hextuples", feature = "nq"))]
+    pub(crate) fn graph_name(&mut self, graph_name: GraphName) -> GraphName {
+        match graph_name {
+            GraphName::BNode(node) => GraphName::BNode(self.blank_node(&node)),
+            graph_name => graph_name,
+        }
+    }
+
+    fn blank_node(&mut self, node: &BlankNode) -> BlankNode {
+        self.labels
+            .entry(node.clone())
+            .or_insert_with(BlankNode::generate)
+            .clone()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(any(
+    feature = "json-ld",
+    feature = "n3",
+    feature = "nq",
+    feature = "nt",
+    feature = "rdfa",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
+impl DocumentLabels {
+    pub(crate) fn new(blank_nodes: BlankNodeLabels) -> Self {
+        Self {
+            relabel: blank_nodes == BlankNodeLabels::Relabel,
+            labels: Default::default(),
+        }
+    }
+
+    pub(crate) fn blank_node(&mut self, label: &str) -> BlankNode {
+        if !self.relabel && !BlankNode::is_generated_str(label) {
+            if let Ok(node) = BlankNode::from_str(label) {
+                return node;
+            }
+        }
+        self.labels
+            .entry(label.to_string())
+            .or_insert_with(BlankNode::generate)
+            .clone()
+    }
+}
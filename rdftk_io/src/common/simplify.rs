@@ -0,0 +1,86 @@
+use rdftk_core::error::Error;
+use rdftk_core::model::statement::Statement;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Replace any nested statement or collection in `statement` by its reified form, in the same
+/// manner as `Graph::simplify`, so that statements can be simplified one at a time by
+/// streaming writers.
+///
+#[cfg(any(
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "nq",
+    feature = "nt",
+    feature = "trix",
+    feature = "xml"
+))]
+pub(crate) fn simplify_statement(statement: &Statement) -> Result<Vec<Statement>, Error> {
+    let mut statements = reify_statement(statement)?;
+    let mut reified = reify_collection(&statements[0])?;
+    statements[0] = reified.remove(0);
+    statements.extend(reified);
+    Ok(statements)
+}
+
+///
+/// Replace a collection in the object of `statement` by its reified form, leaving any nested
+/// statement in place. The first statement returned takes the place of `statement`.
+///
+#[cfg(any(
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "nq",
+    feature = "nt",
+    feature = "trix",
+    feature = "xml"
+))]
+pub(crate) fn reify_collection(statement: &Statement) -> Result<Vec<Statement>, Error> {
+    match statement.object().as_collection() {
+        Some(object_collection) => {
+            let (subject, mut statements) = object_collection.reify()?;
+            statements.insert(
+                0,
+                Statement::new(
+                    statement.subject().clone(),
+                    statement.predicate().clone(),
+                    subject.to_object(),
+                ),
+            );
+            Ok(statements)
+        }
+        None => Ok(vec![statement.clone()]),
+    }
+}
+
+///
+/// Replace any nested statement in the subject or object of `statement` by its reified form,
+/// leaving collections in place. The first statement returned takes the place of `statement`.
+///
+pub(crate) fn reify_statement(statement: &Statement) -> Result<Vec<Statement>, Error> {
+    let mut statements = vec![];
+    let subject = match statement.subject().as_statement() {
+        Some(subject_statement) => {
+            let (subject, reified) = subject_statement.reify()?;
+            statements.extend(reified);
+            subject
+        }
+        None => statement.subject().clone(),
+    };
+    let object = match statement.object().as_statement() {
+        Some(object_statement) => {
+            let (subject, reified) = object_statement.reify()?;
+            statements.extend(reified);
+            subject.to_object()
+        }
+        None => statement.object().clone(),
+    };
+    statements.insert(
+        0,
+        Statement::new(subject, statement.predicate().clone(), object),
+    );
+    Ok(statements)
+}
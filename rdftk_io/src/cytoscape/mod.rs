@@ -0,0 +1,55 @@
+/*!
+Provides for writing a `Graph` instance as [Cytoscape.js](https://js.cytoscape.org/) elements
+JSON, an object with `nodes` and `edges` arrays that may be passed as the `elements` of a
+Cytoscape.js graph, or imported into Cytoscape.
+
+Each distinct IRI, blank node, and literal is written as a node whose `data` has a `kind` of
+`iri`, `blank`, or `literal` and a `label`; the `value` holds the full IRI, blank node label, or
+literal value, and literals also have a `datatype` or `language` where present. Each statement is
+written as an edge whose `data` has the predicate as its `label`. Nested statements and
+collections are written with a `kind` of `statement` or `collection`, labelled `<< s p o >>` or
+`( a b )`, with their string form as the value.
+
+If the `fold_literals` option is set literal values are instead added to the `data` of their
+subject node, keyed by predicate; multiple values for the same predicate are written as an array.
+
+# Example
+
+```rust
+use rdftk_io::cytoscape::{CytoscapeOptions, CytoscapeWriter};
+# use objio::{HasOptions, ObjectWriter};
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { rdftk_core::model::graph::Graph::default() }
+
+let options = CytoscapeOptions::default()
+    .with_fold_literals(true)
+    .with_prefixed_labels(true);
+
+let writer = CytoscapeWriter::default().with_options(options);
+
+let result = writer.write_to_string(&make_graph());
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "Cytoscape.js";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "cyjs";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/json";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod syntax;
+
+mod writer;
+pub use writer::{CytoscapeOptions, CytoscapeWriter};
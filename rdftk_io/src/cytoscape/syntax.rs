@@ -0,0 +1,20 @@
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const KEY_NODES: &str = "nodes";
+pub(super) const KEY_EDGES: &str = "edges";
+pub(super) const KEY_DATA: &str = "data";
+
+pub(super) const KEY_ID: &str = "id";
+pub(super) const KEY_KIND: &str = "kind";
+pub(super) const KEY_LABEL: &str = "label";
+pub(super) const KEY_VALUE: &str = "value";
+pub(super) const KEY_DATATYPE: &str = "datatype";
+pub(super) const KEY_LANGUAGE: &str = "language";
+pub(super) const KEY_SOURCE: &str = "source";
+pub(super) const KEY_TARGET: &str = "target";
+pub(super) const KEY_PREDICATE: &str = "predicate";
+
+pub(super) const NODE_ID_PREFIX: &str = "n";
+pub(super) const EDGE_ID_PREFIX: &str = "e";
@@ -0,0 +1,192 @@
+use super::syntax::{
+    EDGE_ID_PREFIX, KEY_DATA, KEY_DATATYPE, KEY_EDGES, KEY_ID, KEY_KIND, KEY_LABEL, KEY_LANGUAGE,
+    KEY_NODES, KEY_PREDICATE, KEY_SOURCE, KEY_TARGET, KEY_VALUE, NODE_ID_PREFIX,
+};
+use crate::common::diagram::{Diagram, Node};
+use crate::GraphWriter;
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::Graph;
+use serde_json::{Map, Value};
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// This struct implements the `GraphWriter` trait and will write out the elements JSON for
+/// the entire graph.
+///
+#[derive(Debug, Default)]
+pub struct CytoscapeWriter {
+    options: CytoscapeOptions,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CytoscapeOptions {
+    /// Determines whether IRI node labels are compressed using the graph's prefix mappings.
+    /// Default is `false`.
+    prefixed_labels: bool,
+    /// Determines whether literal values are added to the data of their subject node, rather
+    /// than written as separate nodes. Default is `false`.
+    fold_literals: bool,
+    /// Should the output be pretty-printed. Default is `false`.
+    pretty_print: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl CytoscapeOptions {
+    pub fn with_prefixed_labels(self, prefixed_labels: bool) -> Self {
+        Self {
+            prefixed_labels,
+            ..self
+        }
+    }
+
+    pub fn set_prefixed_labels(&mut self, prefixed_labels: bool) {
+        self.prefixed_labels = prefixed_labels;
+    }
+
+    pub fn prefixed_labels(&self) -> bool {
+        self.prefixed_labels
+    }
+
+    pub fn with_fold_literals(self, fold_literals: bool) -> Self {
+        Self {
+            fold_literals,
+            ..self
+        }
+    }
+
+    pub fn set_fold_literals(&mut self, fold_literals: bool) {
+        self.fold_literals = fold_literals;
+    }
+
+    pub fn fold_literals(&self) -> bool {
+        self.fold_literals
+    }
+
+    pub fn with_pretty_print(self, pretty_print: bool) -> Self {
+        Self {
+            pretty_print,
+            ..self
+        }
+    }
+
+    pub fn set_pretty_print(&mut self, pretty_print: bool) {
+        self.pretty_print = pretty_print;
+    }
+
+    pub fn pretty_print(&self) -> bool {
+        self.pretty_print
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(CytoscapeWriter, CytoscapeOptions);
+
+impl CytoscapeWriter {
+    pub fn with_options(self, options: CytoscapeOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+impl ObjectWriter<Graph> for CytoscapeWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let diagram = Diagram::from_graph(
+            graph,
+            self.options.prefixed_labels,
+            self.options.fold_literals,
+        );
+
+        let nodes = diagram.nodes().map(node_element).collect();
+        let edges = diagram
+            .edges()
+            .enumerate()
+            .map(|(index, edge)| {
+                let mut data = Map::new();
+                let _ = data.insert(
+                    KEY_ID.to_string(),
+                    format!("{EDGE_ID_PREFIX}{}", index + 1).into(),
+                );
+                let _ = data.insert(
+                    KEY_SOURCE.to_string(),
+                    format!("{NODE_ID_PREFIX}{}", edge.subject).into(),
+                );
+                let _ = data.insert(
+                    KEY_TARGET.to_string(),
+                    format!("{NODE_ID_PREFIX}{}", edge.object).into(),
+                );
+                let _ = data.insert(KEY_LABEL.to_string(), edge.label.clone().into());
+                let _ = data.insert(KEY_PREDICATE.to_string(), edge.predicate.clone().into());
+                element(data)
+            })
+            .collect();
+
+        let mut elements = Map::new();
+        let _ = elements.insert(KEY_NODES.to_string(), Value::Array(nodes));
+        let _ = elements.insert(KEY_EDGES.to_string(), Value::Array(edges));
+        let elements = Value::Object(elements);
+
+        if self.options.pretty_print {
+            serde_json::to_writer_pretty(w, &elements)
+        } else {
+            serde_json::to_writer(w, &elements)
+        }
+        .map_err(|e| Error::Io(e.into()))
+    }
+}
+
+impl GraphWriter for CytoscapeWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn node_element(node: &Node) -> Value {
+    let mut data = Map::new();
+    let _ = data.insert(
+        KEY_ID.to_string(),
+        format!("{NODE_ID_PREFIX}{}", node.id).into(),
+    );
+    let _ = data.insert(KEY_KIND.to_string(), node.kind.as_str().into());
+    let _ = data.insert(KEY_LABEL.to_string(), node.label.clone().into());
+    let _ = data.insert(KEY_VALUE.to_string(), node.value.clone().into());
+    if let Some(data_type) = &node.data_type {
+        let _ = data.insert(KEY_DATATYPE.to_string(), data_type.clone().into());
+    }
+    if let Some(language) = &node.language {
+        let _ = data.insert(KEY_LANGUAGE.to_string(), language.clone().into());
+    }
+    for (predicate, value) in &node.properties {
+        match data.get_mut(predicate) {
+            None => {
+                let _ = data.insert(predicate.clone(), value.clone().into());
+            }
+            Some(Value::Array(values)) => values.push(value.clone().into()),
+            Some(existing) => {
+                let first = existing.take();
+                *existing = Value::Array(vec![first, value.clone().into()]);
+            }
+        }
+    }
+    element(data)
+}
+
+fn element(data: Map<String, Value>) -> Value {
+    let mut element = Map::new();
+    let _ = element.insert(KEY_DATA.to_string(), Value::Object(data));
+    Value::Object(element)
+}
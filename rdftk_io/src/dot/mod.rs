@@ -1,7 +1,13 @@
 /*!
-Provides for writing a `Graph` instance in the [GraphViz](https://graphviz.gitlab.io/) dot file
-format.
+Provides for writing a `Graph` or `DataSet` instance in the [GraphViz](https://graphviz.gitlab.io/)
+dot file format. When writing a data set each named graph is drawn as a cluster labelled with
+the graph's name, nodes that appear in more than one graph are drawn only once. Nested
+statements are drawn as a cluster containing their subject, object, and the edge between them.
 
+Beyond the shape and color of each kind of node, [`DotOptions`] allows IRI labels to be
+compressed using the graph's prefix mappings, the rank direction and layout engine to be set,
+predicates to be included or excluded (for example to hide `rdf:type` edges), and the literal
+values of each subject to be folded into a single record-shaped node.
 
 # Example
 
@@ -11,7 +17,11 @@ use rdftk_io::dot::{DotOptions, DotWriter};
 # use rdftk_core::model::graph::Graph;
 # fn make_graph() -> Graph { rdftk_core::model::graph::Graph::default() }
 
-let mut options = DotOptions::default().with_blank_labels(true);
+let mut options = DotOptions::default()
+    .with_blank_labels(true)
+    .with_prefixed_labels(true)
+    .with_rank_direction("LR")
+    .with_fold_literals(true);
 
 let writer = DotWriter::default().with_options(options);
 
@@ -1,10 +1,12 @@
-use crate::GraphWriter;
+use crate::common::literal::unescape_lexical_form;
+use crate::{DataSetWriter, GraphWriter};
 use objio::HasOptions;
 use objio::{impl_has_options, ObjectWriter};
 use rdftk_core::error::Error;
-use rdftk_core::model::graph::Graph;
-use rdftk_core::model::statement::{ObjectNode, SubjectNode};
-use std::cell::RefCell;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap};
 use std::collections::HashMap;
 use std::io::Write;
 
@@ -35,15 +37,30 @@ pub struct DotOptions {
     literal_color: String,
     /// The prefix string used to generate internal node identifiers. Default is `node_`.
     node_prefix: String,
+    /// Determines whether IRI node labels are compressed using the graph's prefix mappings.
+    /// Default is `false`.
+    prefixed_labels: bool,
+    /// The direction of the graph layout, as the dot `rankdir` attribute. Default is `BT`.
+    rank_direction: String,
+    /// The layout engine hint, as the dot `layout` attribute. Default is none.
+    layout: Option<String>,
+    /// If not empty, only statements with one of these predicates are drawn. Default is empty.
+    include_predicates: Vec<Iri>,
+    /// Statements with one of these predicates are not drawn. Default is empty.
+    exclude_predicates: Vec<Iri>,
+    /// Determines whether all the literal values of a subject are drawn as a single record
+    /// node, rather than a node for each literal. Default is `false`.
+    fold_literals: bool,
 }
 
 ///
-/// This struct implements the `GraphWriter` trait and will write out a serialized form for the
-/// entire graph.
+/// This struct implements the `GraphWriter` and `DataSetWriter` traits and will write out a
+/// serialized form for the entire graph, or data set. Each named graph is written as a
+/// `subgraph cluster_*` labelled with the graph's name; a node that appears in more than one
+/// graph is written once, outside of any cluster.
 ///
 #[derive(Debug, Default)]
 pub struct DotWriter {
-    nodes: RefCell<HashMap<String, Node>>,
     options: DotOptions,
 }
 
@@ -54,8 +71,11 @@ pub struct DotWriter {
 #[derive(Debug)]
 enum NodeKind {
     Blank,
-    Iri,
+    /// An IRI node, with the full IRI used as its URL.
+    Iri(String),
     Literal,
+    /// The folded literal values of a subject, one field for each.
+    Record(Vec<String>),
 }
 
 #[derive(Debug)]
@@ -63,6 +83,56 @@ struct Node {
     id: String,
     kind: NodeKind,
     label: String,
+    home: Home,
+}
+
+///
+/// Where a node, or nested statement, is declared; those used only by a single named graph are
+/// declared within that graph's cluster, and the terms of a nested statement within the
+/// statement's own cluster.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Home {
+    Cluster(usize),
+    Statement(usize),
+    Shared,
+}
+
+///
+/// One end of an edge; an edge to a nested statement is drawn to the first node within the
+/// statement's cluster and clipped at the cluster boundary.
+///
+#[derive(Clone, Debug)]
+enum End {
+    Node(String),
+    Statement { cluster: usize, anchor: String },
+}
+
+#[derive(Debug)]
+struct Edge {
+    subject: End,
+    object: End,
+    label: String,
+}
+
+#[derive(Debug)]
+struct Quoted {
+    home: Home,
+}
+
+///
+/// The content of the graphs being written. Nodes are kept in the order they were first seen,
+/// keyed by the string form of the node so that each is only written once.
+///
+#[derive(Debug)]
+struct Diagram<'a> {
+    options: &'a DotOptions,
+    ids: HashMap<String, usize>,
+    nodes: Vec<Node>,
+    statement_ids: HashMap<String, (usize, String)>,
+    statements: Vec<Quoted>,
+    clusters: Vec<String>,
+    edges: Vec<Edge>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -80,6 +150,12 @@ impl Default for DotOptions {
             literal_shape: "record".to_string(),
             literal_color: "black".to_string(),
             node_prefix: "node_".to_string(),
+            prefixed_labels: false,
+            rank_direction: "BT".to_string(),
+            layout: None,
+            include_predicates: Default::default(),
+            exclude_predicates: Default::default(),
+            fold_literals: false,
         }
     }
 }
@@ -260,6 +336,133 @@ impl DotOptions {
     pub fn node_prefix(&self) -> &String {
         &self.node_prefix
     }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_prefixed_labels(self, prefixed_labels: bool) -> Self {
+        Self {
+            prefixed_labels,
+            ..self
+        }
+    }
+
+    pub fn set_prefixed_labels(&mut self, prefixed_labels: bool) {
+        self.prefixed_labels = prefixed_labels;
+    }
+
+    pub fn prefixed_labels(&self) -> bool {
+        self.prefixed_labels
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_rank_direction<S>(self, rank_direction: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            rank_direction: rank_direction.into(),
+            ..self
+        }
+    }
+
+    pub fn set_rank_direction<S>(&mut self, rank_direction: S)
+    where
+        S: Into<String>,
+    {
+        self.rank_direction = rank_direction.into();
+    }
+
+    pub fn rank_direction(&self) -> &String {
+        &self.rank_direction
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_layout<S>(self, layout: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            layout: Some(layout.into()),
+            ..self
+        }
+    }
+
+    pub fn set_layout<S>(&mut self, layout: S)
+    where
+        S: Into<String>,
+    {
+        self.layout = Some(layout.into());
+    }
+
+    pub fn unset_layout(&mut self) {
+        self.layout = None;
+    }
+
+    pub fn layout(&self) -> Option<&String> {
+        self.layout.as_ref()
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_include_predicates(self, include_predicates: Vec<Iri>) -> Self {
+        Self {
+            include_predicates,
+            ..self
+        }
+    }
+
+    pub fn set_include_predicates(&mut self, include_predicates: Vec<Iri>) {
+        self.include_predicates = include_predicates;
+    }
+
+    pub fn include_predicates(&self) -> &Vec<Iri> {
+        &self.include_predicates
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_exclude_predicates(self, exclude_predicates: Vec<Iri>) -> Self {
+        Self {
+            exclude_predicates,
+            ..self
+        }
+    }
+
+    pub fn set_exclude_predicates(&mut self, exclude_predicates: Vec<Iri>) {
+        self.exclude_predicates = exclude_predicates;
+    }
+
+    pub fn exclude_predicates(&self) -> &Vec<Iri> {
+        &self.exclude_predicates
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_fold_literals(self, fold_literals: bool) -> Self {
+        Self {
+            fold_literals,
+            ..self
+        }
+    }
+
+    pub fn set_fold_literals(&mut self, fold_literals: bool) {
+        self.fold_literals = fold_literals;
+    }
+
+    pub fn fold_literals(&self) -> bool {
+        self.fold_literals
+    }
+
+    ///
+    /// Returns `true` if statements with `predicate` should be drawn, according to the
+    /// include and exclude lists.
+    ///
+    pub fn is_drawn(&self, predicate: &Iri) -> bool {
+        (self.include_predicates.is_empty() || self.include_predicates.contains(predicate))
+            && !self.exclude_predicates.contains(predicate)
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -281,162 +484,451 @@ impl ObjectWriter<Graph> for DotWriter {
     where
         W: Write,
     {
-        writeln!(w, "digraph {{\n    rankdir=BT\n    charset=\"utf-8\";")?;
+        let mut diagram = Diagram::new(&self.options);
+        diagram.add_graph(graph);
+        self.write_diagram(w, &diagram)
+    }
+}
+
+impl GraphWriter for DotWriter {}
+
+impl ObjectWriter<DataSet> for DotWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, data_set: &DataSet) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut diagram = Diagram::new(&self.options);
+        for graph in data_set.graphs() {
+            diagram.add_graph(graph);
+        }
+        self.write_diagram(w, &diagram)
+    }
+}
+
+impl DataSetWriter for DotWriter {}
 
-        // TODO: emit graph name
+impl DotWriter {
+    ///
+    /// Create a new writer with the provided options, this is used to override the default
+    /// options that are used when calling `Default::default`.
+    ///
+    pub fn new(options: DotOptions) -> Self {
+        Self { options }
+    }
+
+    fn write_diagram<W>(&self, w: &mut W, diagram: &Diagram<'_>) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        writeln!(
+            w,
+            "digraph {{\n    rankdir={}\n    charset=\"utf-8\";",
+            self.options.rank_direction
+        )?;
+        if let Some(layout) = &self.options.layout {
+            writeln!(w, "    layout={layout};")?;
+        }
+        if !diagram.statements.is_empty() {
+            writeln!(w, "    compound=true;")?;
+        }
 
         writeln!(w)?;
 
-        let mappings = graph.prefix_mappings();
-        for statement in graph.statements() {
-            writeln!(
-                w,
-                "    \"{}{}\" -> \"node_{}\" [label=\"{}\"];",
-                self.options.node_prefix,
-                self.subject_id(statement.subject()),
-                self.object_id(statement.object()),
-                match mappings.compress(statement.predicate()) {
-                    None => statement.predicate().to_string(),
-                    Some(qname) => qname.to_string(),
-                }
-            )?;
+        // edges are written outside of the clusters so that shared nodes are not drawn within
+        // the first cluster to use them.
+        for (index, label) in diagram.clusters.iter().enumerate() {
+            let index = index + 1;
+            writeln!(w, "    subgraph cluster_{index} {{")?;
+            writeln!(w, "        label=\"{label}\";")?;
+            self.write_content(w, diagram, Home::Cluster(index), "        ")?;
+            writeln!(w, "    }}")?;
+        }
+
+        for edge in &diagram.edges {
+            self.write_edge(w, edge)?;
         }
 
         writeln!(w)?;
 
-        for node in self.nodes.borrow().values() {
-            match node.kind {
-                NodeKind::Blank => {
-                    if self.options.blank_labels {
-                        writeln!(
-                            w,
-                            "    \"{}{}\" [label=\"{}{}\",shape={},color={}];",
-                            self.options.node_prefix,
-                            node.id,
-                            self.options.node_prefix,
-                            node.id,
-                            self.options.blank_shape,
-                            self.options.blank_color
-                        )?;
-                    } else {
-                        writeln!(
-                            w,
-                            "    \"{}{}\" [label=\"\",shape={},color={}];",
-                            self.options.node_prefix,
-                            node.id,
-                            self.options.blank_shape,
-                            self.options.blank_color
-                        )?;
-                    }
-                }
-                NodeKind::Iri => {
+        self.write_content(w, diagram, Home::Shared, "    ")?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    ///
+    /// Write the nodes, and the clusters for nested statements, declared in `home`.
+    ///
+    fn write_content<W>(
+        &self,
+        w: &mut W,
+        diagram: &Diagram<'_>,
+        home: Home,
+        indent: &str,
+    ) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        for node in diagram.nodes.iter().filter(|n| n.home == home) {
+            self.write_node(w, node, indent)?;
+        }
+        for (index, _) in diagram
+            .statements
+            .iter()
+            .enumerate()
+            .filter(|(_, statement)| statement.home == home)
+        {
+            let index = index + 1;
+            writeln!(w, "{indent}subgraph cluster_statement_{index} {{")?;
+            writeln!(w, "{indent}    label=\"\";\n{indent}    style=dashed;")?;
+            self.write_content(w, diagram, Home::Statement(index), &format!("{indent}    "))?;
+            writeln!(w, "{indent}}}")?;
+        }
+        Ok(())
+    }
+
+    fn write_edge<W>(&self, w: &mut W, edge: &Edge) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        let mut attributes = format!("label=\"{}\"", edge.label);
+        let subject = match &edge.subject {
+            End::Node(id) => id,
+            End::Statement { cluster, anchor } => {
+                attributes.push_str(&format!(",ltail=cluster_statement_{cluster}"));
+                anchor
+            }
+        };
+        let object = match &edge.object {
+            End::Node(id) => id,
+            End::Statement { cluster, anchor } => {
+                attributes.push_str(&format!(",lhead=cluster_statement_{cluster}"));
+                anchor
+            }
+        };
+        writeln!(
+            w,
+            "    \"{}{}\" -> \"{}{}\" [{}];",
+            self.options.node_prefix, subject, self.options.node_prefix, object, attributes
+        )?;
+        Ok(())
+    }
+
+    fn write_node<W>(&self, w: &mut W, node: &Node, indent: &str) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        match &node.kind {
+            NodeKind::Blank => {
+                if self.options.blank_labels {
                     writeln!(
                         w,
-                        "    \"{}{}\" [URL=\"{}\",label=\"{}\",shape={},color={}];",
+                        "{indent}\"{}{}\" [label=\"{}{}\",shape={},color={}];",
                         self.options.node_prefix,
                         node.id,
-                        node.label,
-                        node.label,
-                        self.options.iri_shape,
-                        self.options.iri_color
+                        self.options.node_prefix,
+                        node.id,
+                        self.options.blank_shape,
+                        self.options.blank_color
                     )?;
-                }
-                NodeKind::Literal => {
+                } else {
                     writeln!(
                         w,
-                        "    \"{}{}\" [label=\"{}\",shape={},color={}];",
+                        "{indent}\"{}{}\" [label=\"\",shape={},color={}];",
                         self.options.node_prefix,
                         node.id,
-                        node.label,
-                        self.options.literal_shape,
-                        self.options.literal_color
+                        self.options.blank_shape,
+                        self.options.blank_color
                     )?;
                 }
             }
+            NodeKind::Iri(url) => {
+                writeln!(
+                    w,
+                    "{indent}\"{}{}\" [URL=\"{}\",label=\"{}\",shape={},color={}];",
+                    self.options.node_prefix,
+                    node.id,
+                    url,
+                    node.label,
+                    self.options.iri_shape,
+                    self.options.iri_color
+                )?;
+            }
+            NodeKind::Literal => {
+                let label = if self.options.literal_shape == "record" {
+                    escape_record_field(&node.label)
+                } else {
+                    node.label.clone()
+                };
+                writeln!(
+                    w,
+                    "{indent}\"{}{}\" [label=\"{}\",shape={},color={}];",
+                    self.options.node_prefix,
+                    node.id,
+                    label,
+                    self.options.literal_shape,
+                    self.options.literal_color
+                )?;
+            }
+            NodeKind::Record(fields) => {
+                writeln!(
+                    w,
+                    "{indent}\"{}{}\" [label=\"{{{}}}\",shape=record,color={}];",
+                    self.options.node_prefix,
+                    node.id,
+                    fields.join("|"),
+                    self.options.literal_color
+                )?;
+            }
         }
-        writeln!(w, "}}")?;
         Ok(())
     }
 }
 
-impl GraphWriter for DotWriter {}
+// ------------------------------------------------------------------------------------------------
 
-impl DotWriter {
-    ///
-    /// Create a new writer with the provided options, this is used to override the default
-    /// options that are used when calling `Default::default`.
-    ///
-    pub fn new(options: DotOptions) -> Self {
+impl<'a> Diagram<'a> {
+    fn new(options: &'a DotOptions) -> Self {
         Self {
-            nodes: Default::default(),
             options,
+            ids: Default::default(),
+            nodes: Default::default(),
+            statement_ids: Default::default(),
+            statements: Default::default(),
+            clusters: Default::default(),
+            edges: Default::default(),
         }
     }
 
-    fn subject_id(&self, node: &SubjectNode) -> String {
-        let mut nodes = self.nodes.borrow_mut();
-        if let Some(node) = nodes.get(&node.to_string()) {
-            node.id.clone()
+    fn add_graph(&mut self, graph: &Graph) {
+        let home = match graph.name() {
+            Some(name) => {
+                self.clusters.push(match name {
+                    GraphName::BNode(node) => format!("_:{node}"),
+                    GraphName::Iri(iri) => format!("{iri:#}"),
+                });
+                Home::Cluster(self.clusters.len())
+            }
+            None => Home::Shared,
+        };
+        let mappings = graph.prefix_mappings();
+        for statement in graph.statements() {
+            if !self.options.is_drawn(statement.predicate()) {
+                continue;
+            }
+            let subject = self.subject_end(statement.subject(), home, "", mappings);
+            match statement.object().as_literal() {
+                Some(literal) if self.options.fold_literals => {
+                    let field = format!(
+                        "{}: {}",
+                        predicate_label(statement.predicate(), mappings),
+                        unescape_lexical_form(literal.lexical_form())
+                    );
+                    self.fold(
+                        statement.subject(),
+                        subject,
+                        home,
+                        escape_record_field(&escape_label(&field)),
+                    );
+                }
+                _ => {
+                    let object = self.object_end(statement.object(), home, "", mappings);
+                    self.edges.push(Edge {
+                        subject,
+                        object,
+                        label: predicate_label(statement.predicate(), mappings),
+                    });
+                }
+            }
+        }
+    }
+
+    ///
+    /// Add `field` to the record node holding the literal values of `subject` in the graph
+    /// `home`, creating the node, and the edge to it, if needed.
+    ///
+    fn fold(&mut self, subject: &SubjectNode, end: End, home: Home, field: String) {
+        let key = format!("{subject}\u{0}{home:?}");
+        if let Some(index) = self.ids.get(&key) {
+            if let NodeKind::Record(fields) = &mut self.nodes[*index].kind {
+                fields.push(field);
+            }
+        } else {
+            let id = self.id(key, NodeKind::Record(vec![field]), String::new(), home);
+            self.edges.push(Edge {
+                subject: end,
+                object: End::Node(id),
+                label: String::new(),
+            });
+        }
+    }
+
+    fn subject_end(
+        &mut self,
+        node: &SubjectNode,
+        home: Home,
+        scope: &str,
+        mappings: &IriPrefixMap,
+    ) -> End {
+        let key = format!("{scope}{node}");
+        if let Some(blank) = node.as_blank() {
+            End::Node(self.id(key, NodeKind::Blank, blank.as_ref().into(), home))
+        } else if let Some(iri) = node.as_resource() {
+            End::Node(self.iri_id(key, iri, home, mappings))
+        } else if let Some(statement) = node.as_statement() {
+            self.statement_end(&statement, home, scope, mappings)
         } else {
-            let id = format!("{}", nodes.len() + 1);
-            if node.is_blank() {
-                let _ = nodes.insert(
-                    node.to_string(),
-                    Node {
-                        id: id.clone(),
-                        kind: NodeKind::Blank,
-                        label: node.as_blank().unwrap().as_ref().into(),
-                    },
-                );
-            } else if node.is_resource() {
-                let _ = nodes.insert(
-                    node.to_string(),
-                    Node {
-                        id: id.clone(),
-                        kind: NodeKind::Iri,
-                        label: node.as_resource().unwrap().to_string(),
-                    },
-                );
+            End::Node(self.other_id(key, home))
+        }
+    }
+
+    fn object_end(
+        &mut self,
+        node: &ObjectNode,
+        home: Home,
+        scope: &str,
+        mappings: &IriPrefixMap,
+    ) -> End {
+        let key = format!("{scope}{node}");
+        if let Some(blank) = node.as_blank() {
+            End::Node(self.id(key, NodeKind::Blank, blank.as_ref().into(), home))
+        } else if let Some(iri) = node.as_resource() {
+            End::Node(self.iri_id(key, iri, home, mappings))
+        } else if let Some(literal) = node.as_literal() {
+            let label = escape_label(&unescape_lexical_form(literal.lexical_form()));
+            End::Node(self.id(key, NodeKind::Literal, label, home))
+        } else if let Some(statement) = node.as_statement() {
+            self.statement_end(&statement, home, scope, mappings)
+        } else {
+            End::Node(self.other_id(key, home))
+        }
+    }
+
+    ///
+    /// Nested statements are drawn as a cluster containing their own subject and object nodes,
+    /// and the edge between them; these nodes are not shared with the rest of the graph.
+    ///
+    fn statement_end(
+        &mut self,
+        statement: &Statement,
+        home: Home,
+        scope: &str,
+        mappings: &IriPrefixMap,
+    ) -> End {
+        let key = format!("{scope}{statement}");
+        if let Some((index, anchor)) = self.statement_ids.get(&key) {
+            let quoted = &mut self.statements[*index];
+            if quoted.home != home {
+                quoted.home = Home::Shared;
             }
-            id
+            return End::Statement {
+                cluster: index + 1,
+                anchor: anchor.clone(),
+            };
         }
+        let index = self.statements.len();
+        self.statements.push(Quoted { home });
+        let inner_home = Home::Statement(index + 1);
+        let inner_scope = format!("{key}\u{0}");
+        let subject = self.subject_end(statement.subject(), inner_home, &inner_scope, mappings);
+        let object = self.object_end(statement.object(), inner_home, &inner_scope, mappings);
+        let anchor = match &subject {
+            End::Node(id) => id.clone(),
+            End::Statement { anchor, .. } => anchor.clone(),
+        };
+        self.edges.push(Edge {
+            subject,
+            object,
+            label: predicate_label(statement.predicate(), mappings),
+        });
+        let _ = self.statement_ids.insert(key, (index, anchor.clone()));
+        End::Statement {
+            cluster: index + 1,
+            anchor,
+        }
+    }
+
+    fn iri_id(&mut self, key: String, iri: &Iri, home: Home, mappings: &IriPrefixMap) -> String {
+        let url = format!("{iri:#}");
+        let label = match mappings.compress(iri) {
+            Some(qname) if self.options.prefixed_labels => qname.to_string(),
+            _ => url.clone(),
+        };
+        self.id(key, NodeKind::Iri(url), label, home)
+    }
+
+    ///
+    /// Collections are drawn as a literal-style node labelled with their string form.
+    ///
+    fn other_id(&mut self, key: String, home: Home) -> String {
+        let label = escape_label(&key);
+        self.id(key, NodeKind::Literal, label, home)
     }
 
-    fn object_id(&self, node: &ObjectNode) -> String {
-        let mut nodes = self.nodes.borrow_mut();
-        if let Some(node) = nodes.get(&node.to_string()) {
+    ///
+    /// Return the identifier for the node with the string form `key`, adding it if this is
+    /// the first time it has been seen. A node seen from more than one graph is shared.
+    ///
+    fn id(&mut self, key: String, kind: NodeKind, label: String, home: Home) -> String {
+        if let Some(index) = self.ids.get(&key) {
+            let node = &mut self.nodes[*index];
+            if node.home != home {
+                node.home = Home::Shared;
+            }
             node.id.clone()
         } else {
-            let id = format!("{}", nodes.len() + 1);
-            if node.is_blank() {
-                let _ = nodes.insert(
-                    node.to_string(),
-                    Node {
-                        id: id.clone(),
-                        kind: NodeKind::Blank,
-                        label: node.as_blank().unwrap().as_ref().into(),
-                    },
-                );
-            } else if node.is_resource() {
-                let _ = nodes.insert(
-                    node.to_string(),
-                    Node {
-                        id: id.clone(),
-                        kind: NodeKind::Iri,
-                        label: node.as_resource().unwrap().to_string(),
-                    },
-                );
-            } else if node.is_literal() {
-                let _ = nodes.insert(
-                    node.to_string(),
-                    Node {
-                        id: id.clone(),
-                        kind: NodeKind::Literal,
-                        label: node.as_literal().unwrap().lexical_form().clone(),
-                    },
-                );
-            }
+            let id = format!("{}", self.nodes.len() + 1);
+            let _ = self.ids.insert(key, self.nodes.len());
+            self.nodes.push(Node {
+                id: id.clone(),
+                kind,
+                label,
+                home,
+            });
             id
         }
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn predicate_label(predicate: &Iri, mappings: &IriPrefixMap) -> String {
+    match mappings.compress(predicate) {
+        None => format!("{predicate:#}"),
+        Some(qname) => qname.to_string(),
+    }
+}
+
+///
+/// Escape the characters that have a meaning within a quoted label, so that a literal's
+/// value, which may contain quotes, backslashes, or new lines, is drawn as it is.
+///
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+///
+/// Escape the characters that have a meaning within a record label.
+///
+fn escape_record_field(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,755 @@
+/*!
+Provides a registry of the serialization formats supported by this crate, allowing a format
+to be selected by file extension, by MIME type, including `Accept` header negotiation, or by
+inspecting the leading bytes of some content. Each format provides boxed reader and writer
+factories so that callers need not know the format in advance.
+
+# Example
+
+```rust,no_run
+use rdftk_io::format::{Document, Format};
+
+let format = Format::from_accept("text/turtle;q=0.8, application/n-triples").unwrap();
+assert_eq!(format, Format::NTriples);
+
+match rdftk_io::read_path("example.ttl").unwrap() {
+    Document::Graph(graph) => println!("read {} statements", graph.len()),
+    Document::DataSet(data_set) => println!("read {} graphs", data_set.len()),
+}
+```
+
+*/
+
+use rdftk_core::error::{unknown_format_error, Error};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::Graph;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The serialization formats known to this crate. A format may be known but not supported if
+/// the feature that provides it is not enabled, see [`Format::is_supported`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Format {
+    /// [RDF 1.1 Turtle](https://www.w3.org/TR/turtle/), requires the `turtle` feature.
+    Turtle,
+    /// [RDF 1.1 N-Triples](https://www.w3.org/TR/n-triples/), requires the `nt` feature.
+    NTriples,
+    /// [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/), requires the `nq` feature.
+    NQuads,
+    /// [RDF 1.1 TriG](https://www.w3.org/TR/trig/), requires the `trig` feature.
+    TriG,
+    /// [Notation3](https://www.w3.org/TeamSubmission/n3/), requires the `n3` feature.
+    N3,
+    /// [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/), requires the `xml`
+    /// feature.
+    Xml,
+    /// [JSON-LD 1.1](https://www.w3.org/TR/json-ld/), requires the `json-ld` feature.
+    JsonLd,
+    /// [RDF 1.1 JSON Alternate Serialization](https://www.w3.org/TR/rdf-json/), requires the
+    /// `json` feature.
+    Json,
+    /// [TriX](https://www.hpl.hp.com/techreports/2004/HPL-2004-56.html), requires the `trix`
+    /// feature.
+    TriX,
+    /// [HexTuples](https://github.com/ontola/hextuples), requires the `hextuples` feature.
+    HexTuples,
+    /// The RDFtk binary format, see [`crate::binary`], requires the `binary` feature.
+    Binary,
+    /// [HDT](https://www.w3.org/Submission/HDT/), requires the `hdt` feature.
+    Hdt,
+}
+
+///
+/// The result of reading content in a format chosen at runtime; formats that describe data
+/// sets, see [`Format::is_data_set`], produce a `DataSet`, all others a `Graph`.
+///
+#[derive(Debug)]
+pub enum Document {
+    Graph(Graph),
+    DataSet(DataSet),
+}
+
+///
+/// An object-safe form of `ObjectReader<Graph>`, implemented for all graph readers, so that
+/// readers may be boxed.
+///
+pub trait DynGraphReader {
+    fn read_graph(&self, r: &mut dyn Read) -> Result<Graph, Error>;
+}
+
+///
+/// An object-safe form of `ObjectReader<DataSet>`, implemented for all data set readers, so
+/// that readers may be boxed.
+///
+pub trait DynDataSetReader {
+    fn read_data_set(&self, r: &mut dyn Read) -> Result<DataSet, Error>;
+}
+
+///
+/// An object-safe form of [`GraphWriter`](crate::GraphWriter), implemented for all graph
+/// writers, so that writers may be boxed.
+///
+pub trait DynGraphWriter {
+    fn write_graph(&self, w: &mut dyn Write, graph: &Graph) -> Result<(), Error>;
+}
+
+///
+/// An object-safe form of [`DataSetWriter`](crate::DataSetWriter), implemented for all data
+/// set writers, so that writers may be boxed.
+///
+pub trait DynDataSetWriter {
+    fn write_data_set(&self, w: &mut dyn Write, data_set: &DataSet) -> Result<(), Error>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read the file at `path`, choosing the format from the file's extension or, if the extension
+/// is not recognized, from the file's content.
+///
+pub fn read_path<P>(path: P) -> Result<Document, Error>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let mut reader = BufReader::new(File::open(path)?);
+    let format = match Format::from_path(path) {
+        Some(format) => format,
+        None => Format::sniff(reader.fill_buf()?)
+            .ok_or_else(|| unknown_format_error(path.display().to_string()))?,
+    };
+    format.read(&mut reader)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const HDT_MAGIC: &[u8] = b"$HDT";
+const BINARY_MAGIC: &[u8] = b"RDFB";
+
+struct MediaRange<'a> {
+    type_name: &'a str,
+    sub_type: &'a str,
+    quality: f32,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const ALL_FORMATS: [Format; 12] = [
+    Format::Turtle,
+    Format::NTriples,
+    Format::NQuads,
+    Format::TriG,
+    Format::N3,
+    Format::Xml,
+    Format::JsonLd,
+    Format::Json,
+    Format::TriX,
+    Format::HexTuples,
+    Format::Binary,
+    Format::Hdt,
+];
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Format {
+    ///
+    /// Return all the supported formats, in order of preference.
+    ///
+    pub fn all() -> impl Iterator<Item = Self> {
+        ALL_FORMATS.into_iter().filter(Self::is_supported)
+    }
+
+    ///
+    /// Returns `true` if the feature providing this format is enabled.
+    ///
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Turtle => cfg!(feature = "turtle"),
+            Self::NTriples => cfg!(feature = "nt"),
+            Self::NQuads => cfg!(feature = "nq"),
+            Self::TriG => cfg!(feature = "trig"),
+            Self::N3 => cfg!(feature = "n3"),
+            Self::Xml => cfg!(feature = "xml"),
+            Self::JsonLd => cfg!(feature = "json-ld"),
+            Self::Json => cfg!(feature = "json"),
+            Self::TriX => cfg!(feature = "trix"),
+            Self::HexTuples => cfg!(feature = "hextuples"),
+            Self::Binary => cfg!(feature = "binary"),
+            Self::Hdt => cfg!(feature = "hdt"),
+        }
+    }
+
+    ///
+    /// Returns `true` if this format describes a data set rather than a single graph.
+    ///
+    pub fn is_data_set(&self) -> bool {
+        matches!(
+            self,
+            Self::NQuads | Self::TriG | Self::JsonLd | Self::TriX | Self::HexTuples | Self::Binary
+        )
+    }
+
+    ///
+    /// The display name of this format.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Turtle => "Turtle",
+            Self::NTriples => "N-Triples",
+            Self::NQuads => "N-Quads",
+            Self::TriG => "TriG",
+            Self::N3 => "N3",
+            Self::Xml => "XML",
+            Self::JsonLd => "JSON-LD",
+            Self::Json => "JSON",
+            Self::TriX => "TriX",
+            Self::HexTuples => "HexTuples",
+            Self::Binary => "RDFtk Binary",
+            Self::Hdt => "HDT",
+        }
+    }
+
+    ///
+    /// The file extensions used for this format, the first is the preferred extension.
+    ///
+    pub fn file_extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Turtle => &["ttl"],
+            Self::NTriples => &["nt"],
+            Self::NQuads => &["nq"],
+            Self::TriG => &["trig"],
+            Self::N3 => &["n3"],
+            Self::Xml => &["rdf", "owl"],
+            Self::JsonLd => &["jsonld"],
+            Self::Json => &["json", "rj"],
+            Self::TriX => &["trix"],
+            Self::HexTuples => &["hext"],
+            Self::Binary => &["rdfb"],
+            Self::Hdt => &["hdt"],
+        }
+    }
+
+    ///
+    /// The MIME types used for this format, the first is the preferred type.
+    ///
+    pub fn mime_types(&self) -> &'static [&'static str] {
+        match self {
+            Self::Turtle => &["text/turtle", "application/x-turtle"],
+            Self::NTriples => &["application/n-triples"],
+            Self::NQuads => &["application/n-quads"],
+            Self::TriG => &["text/trig", "application/trig"],
+            Self::N3 => &["application/n3", "text/n3", "text/rdf+n3"],
+            Self::Xml => &["application/rdf+xml"],
+            Self::JsonLd => &["application/ld+json"],
+            Self::Json => &["application/rdf+json"],
+            Self::TriX => &["application/trix"],
+            Self::HexTuples => &["application/hex+x-ndjson"],
+            Self::Binary => &["application/x-rdftk-binary"],
+            Self::Hdt => &["application/vnd.hdt"],
+        }
+    }
+
+    ///
+    /// Return the supported format that uses the file extension `extension`, ignoring case and
+    /// any leading `'.'`.
+    ///
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let extension = extension.trim_start_matches('.');
+        Self::all().find(|format| {
+            format
+                .file_extensions()
+                .iter()
+                .any(|ext| ext.eq_ignore_ascii_case(extension))
+        })
+    }
+
+    ///
+    /// Return the supported format for the extension of the file named by `path`.
+    ///
+    pub fn from_path<P>(path: P) -> Option<Self>
+    where
+        P: AsRef<Path>,
+    {
+        path.as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(Self::from_extension)
+    }
+
+    ///
+    /// Return the supported format for the MIME type `mime_type`, ignoring case and any
+    /// parameters such as `charset`.
+    ///
+    pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+        let mime_type = mime_type.split(';').next().unwrap_or_default().trim();
+        Self::all().find(|format| {
+            format
+                .mime_types()
+                .iter()
+                .any(|mt| mt.eq_ignore_ascii_case(mime_type))
+        })
+    }
+
+    ///
+    /// Return the supported format most preferred by the HTTP `Accept` header value `accept`.
+    ///
+    /// Each format takes the quality value of the most specific media range that matches one of
+    /// its MIME types, so that `text/*;q=0.5` applies to Turtle unless `text/turtle` is also
+    /// present. Media ranges with a quality value of zero are not acceptable. Where formats
+    /// have the same quality the one listed first in the header is chosen, and for wildcard
+    /// ranges the order of [`Format::all`].
+    ///
+    pub fn from_accept(accept: &str) -> Option<Self> {
+        let ranges: Vec<MediaRange<'_>> = accept.split(',').filter_map(MediaRange::parse).collect();
+        let mut best: Option<(Self, f32, usize)> = None;
+        for format in Self::all() {
+            let matched = format
+                .mime_types()
+                .iter()
+                .filter_map(|mime_type| {
+                    ranges
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, range)| {
+                            range
+                                .specificity(mime_type)
+                                .map(|specificity| (specificity, range.quality, index))
+                        })
+                        .max_by_key(|(specificity, _, _)| *specificity)
+                })
+                .filter(|(_, quality, _)| *quality > 0.0)
+                .max_by(|lhs, rhs| lhs.1.total_cmp(&rhs.1).then_with(|| rhs.2.cmp(&lhs.2)));
+            if let Some((_, quality, index)) = matched {
+                if best.is_none_or(|(_, best_quality, best_index)| {
+                    quality > best_quality || (quality == best_quality && index < best_index)
+                }) {
+                    best = Some((format, quality, index));
+                }
+            }
+        }
+        best.map(|(format, _, _)| format)
+    }
+
+    ///
+    /// Attempt to determine the format of `content` from its leading bytes. This is a
+    /// heuristic, it looks for the magic bytes of the binary formats, the root element of XML
+    /// documents, JSON objects and arrays, directives such as `@prefix`, N3-only syntax, graph
+    /// blocks, and the number of terms on the first line of N-Triples or N-Quads content.
+    ///
+    pub fn sniff(content: &[u8]) -> Option<Self> {
+        if content.starts_with(HDT_MAGIC) {
+            return Some(Self::Hdt).filter(Self::is_supported);
+        } else if content.starts_with(BINARY_MAGIC) {
+            return Some(Self::Binary).filter(Self::is_supported);
+        }
+        let content = content.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(content);
+        let text = String::from_utf8_lossy(content);
+        let text = text.trim_start();
+        let format = if text.starts_with("<?xml")
+            || text.starts_with("<rdf:RDF")
+            || text.starts_with("<!DOCTYPE rdf:RDF")
+            || text.starts_with("<TriX")
+        {
+            Some(sniff_xml(text))
+        } else if let Some(rest) = text.strip_prefix('{') {
+            if rest.trim_start().starts_with(['"', '}']) {
+                Some(sniff_json(text))
+            } else {
+                Some(Self::TriG)
+            }
+        } else if let Some(rest) = text.strip_prefix('[') {
+            if rest.trim_start().starts_with(['{', ']']) {
+                Some(Self::JsonLd)
+            } else if rest.trim_start().starts_with('"') {
+                Some(Self::HexTuples)
+            } else {
+                Some(Self::Turtle)
+            }
+        } else {
+            sniff_text(text)
+        };
+        format.filter(Self::is_supported)
+    }
+
+    ///
+    /// Return a new reader for this format, if it is supported and reads graphs.
+    ///
+    #[allow(unreachable_patterns)]
+    pub fn graph_reader(&self) -> Option<Box<dyn DynGraphReader>> {
+        match self {
+            #[cfg(feature = "turtle")]
+            Self::Turtle => Some(Box::new(crate::turtle::TurtleReader::default())),
+            #[cfg(feature = "nt")]
+            Self::NTriples => Some(Box::new(crate::nt::NTripleReader::default())),
+            #[cfg(feature = "n3")]
+            Self::N3 => Some(Box::new(crate::n3::N3Reader::default())),
+            #[cfg(feature = "xml")]
+            Self::Xml => Some(Box::new(crate::xml::XmlReader::default())),
+            #[cfg(feature = "json-ld")]
+            Self::JsonLd => Some(Box::new(crate::json_ld::JsonLdReader::default())),
+            #[cfg(feature = "json")]
+            Self::Json => Some(Box::new(crate::json::JsonReader::default())),
+            #[cfg(feature = "hextuples")]
+            Self::HexTuples => Some(Box::new(crate::hextuples::HexTuplesReader::default())),
+            #[cfg(feature = "binary")]
+            Self::Binary => Some(Box::new(crate::binary::BinaryReader::default())),
+            #[cfg(feature = "hdt")]
+            Self::Hdt => Some(Box::new(crate::hdt::HdtReader::default())),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Return a new reader for this format, if it is supported and reads data sets.
+    ///
+    #[allow(unreachable_patterns)]
+    pub fn data_set_reader(&self) -> Option<Box<dyn DynDataSetReader>> {
+        match self {
+            #[cfg(feature = "nq")]
+            Self::NQuads => Some(Box::new(crate::nq::NQuadReader::default())),
+            #[cfg(feature = "trig")]
+            Self::TriG => Some(Box::new(crate::trig::TrigReader::default())),
+            #[cfg(feature = "json-ld")]
+            Self::JsonLd => Some(Box::new(crate::json_ld::JsonLdReader::default())),
+            #[cfg(feature = "trix")]
+            Self::TriX => Some(Box::new(crate::trix::TrixReader::default())),
+            #[cfg(feature = "hextuples")]
+            Self::HexTuples => Some(Box::new(crate::hextuples::HexTuplesReader::default())),
+            #[cfg(feature = "binary")]
+            Self::Binary => Some(Box::new(crate::binary::BinaryReader::default())),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Return a new writer for this format, if it is supported and writes graphs.
+    ///
+    #[allow(unreachable_patterns)]
+    pub fn graph_writer(&self) -> Option<Box<dyn DynGraphWriter>> {
+        match self {
+            #[cfg(feature = "turtle")]
+            Self::Turtle => Some(Box::new(crate::turtle::TurtleWriter::default())),
+            #[cfg(feature = "nt")]
+            Self::NTriples => Some(Box::new(crate::nt::NTripleWriter::default())),
+            #[cfg(feature = "nq")]
+            Self::NQuads => Some(Box::new(crate::nq::NQuadWriter::default())),
+            #[cfg(feature = "trig")]
+            Self::TriG => Some(Box::new(crate::trig::TrigWriter::default())),
+            #[cfg(feature = "n3")]
+            Self::N3 => Some(Box::new(crate::n3::N3Writer::default())),
+            #[cfg(feature = "xml")]
+            Self::Xml => Some(Box::new(crate::xml::XmlWriter::default())),
+            #[cfg(feature = "json-ld")]
+            Self::JsonLd => Some(Box::new(crate::json_ld::JsonLdWriter::default())),
+            #[cfg(feature = "json")]
+            Self::Json => Some(Box::new(crate::json::JsonWriter::default())),
+            #[cfg(feature = "trix")]
+            Self::TriX => Some(Box::new(crate::trix::TrixWriter::default())),
+            #[cfg(feature = "hextuples")]
+            Self::HexTuples => Some(Box::new(crate::hextuples::HexTuplesWriter::default())),
+            #[cfg(feature = "binary")]
+            Self::Binary => Some(Box::new(crate::binary::BinaryWriter::default())),
+            #[cfg(feature = "hdt")]
+            Self::Hdt => Some(Box::new(crate::hdt::HdtWriter::default())),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Return a new writer for this format, if it is supported and writes data sets.
+    ///
+    #[allow(unreachable_patterns)]
+    pub fn data_set_writer(&self) -> Option<Box<dyn DynDataSetWriter>> {
+        match self {
+            #[cfg(feature = "nq")]
+            Self::NQuads => Some(Box::new(crate::nq::NQuadWriter::default())),
+            #[cfg(feature = "trig")]
+            Self::TriG => Some(Box::new(crate::trig::TrigWriter::default())),
+            #[cfg(feature = "json-ld")]
+            Self::JsonLd => Some(Box::new(crate::json_ld::JsonLdWriter::default())),
+            #[cfg(feature = "trix")]
+            Self::TriX => Some(Box::new(crate::trix::TrixWriter::default())),
+            #[cfg(feature = "hextuples")]
+            Self::HexTuples => Some(Box::new(crate::hextuples::HexTuplesWriter::default())),
+            #[cfg(feature = "binary")]
+            Self::Binary => Some(Box::new(crate::binary::BinaryWriter::default())),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Read `r` in this format, returning a `DataSet` if this is a data set format and
+    /// otherwise a `Graph`. N3 is read as a `Graph`, and so fails for documents with variables
+    /// or cited formulae; use `n3::N3Reader` to read these as a `Formula`.
+    ///
+    pub fn read(&self, r: &mut dyn Read) -> Result<Document, Error> {
+        if self.is_data_set() {
+            let reader = self
+                .data_set_reader()
+                .ok_or_else(|| unknown_format_error(self.name()))?;
+            Ok(Document::DataSet(reader.read_data_set(r)?))
+        } else {
+            let reader = self
+                .graph_reader()
+                .ok_or_else(|| unknown_format_error(self.name()))?;
+            Ok(Document::Graph(reader.read_graph(r)?))
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<Graph> for Document {
+    fn from(graph: Graph) -> Self {
+        Self::Graph(graph)
+    }
+}
+
+impl From<DataSet> for Document {
+    fn from(data_set: DataSet) -> Self {
+        Self::DataSet(data_set)
+    }
+}
+
+impl From<Document> for DataSet {
+    fn from(document: Document) -> Self {
+        match document {
+            Document::Graph(graph) => DataSet::from(graph),
+            Document::DataSet(data_set) => data_set,
+        }
+    }
+}
+
+impl Document {
+    ///
+    /// Returns `true` if the document was read as a single graph.
+    ///
+    pub fn is_graph(&self) -> bool {
+        matches!(self, Self::Graph(_))
+    }
+
+    ///
+    /// Returns `true` if the document was read as a data set.
+    ///
+    pub fn is_data_set(&self) -> bool {
+        matches!(self, Self::DataSet(_))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T> DynGraphReader for T
+where
+    T: objio::ObjectReader<Graph, Error = Error>,
+{
+    fn read_graph(&self, r: &mut dyn Read) -> Result<Graph, Error> {
+        let mut r = r;
+        self.read(&mut r)
+    }
+}
+
+impl<T> DynDataSetReader for T
+where
+    T: objio::ObjectReader<DataSet, Error = Error>,
+{
+    fn read_data_set(&self, r: &mut dyn Read) -> Result<DataSet, Error> {
+        let mut r = r;
+        self.read(&mut r)
+    }
+}
+
+impl<T> DynGraphWriter for T
+where
+    T: objio::ObjectWriter<Graph, Error = Error>,
+{
+    fn write_graph(&self, w: &mut dyn Write, graph: &Graph) -> Result<(), Error> {
+        let mut w = w;
+        self.write(&mut w, graph)
+    }
+}
+
+impl<T> DynDataSetWriter for T
+where
+    T: objio::ObjectWriter<DataSet, Error = Error>,
+{
+    fn write_data_set(&self, w: &mut dyn Write, data_set: &DataSet) -> Result<(), Error> {
+        let mut w = w;
+        self.write(&mut w, data_set)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> MediaRange<'a> {
+    fn parse(range: &'a str) -> Option<Self> {
+        let mut parts = range.split(';');
+        let (type_name, sub_type) = parts.next()?.trim().split_once('/')?;
+        let mut quality = 1.0;
+        for parameter in parts {
+            if let Some((name, value)) = parameter.split_once('=') {
+                if name.trim().eq_ignore_ascii_case("q") {
+                    quality = value.trim().parse().ok()?;
+                }
+            }
+        }
+        Some(Self {
+            type_name: type_name.trim(),
+            sub_type: sub_type.trim(),
+            quality,
+        })
+    }
+
+    fn specificity(&self, mime_type: &str) -> Option<u8> {
+        let (type_name, sub_type) = mime_type.split_once('/')?;
+        if self.type_name == "*" && self.sub_type == "*" {
+            Some(0)
+        } else if !self.type_name.eq_ignore_ascii_case(type_name) {
+            None
+        } else if self.sub_type == "*" {
+            Some(1)
+        } else if self.sub_type.eq_ignore_ascii_case(sub_type) {
+            Some(2)
+        } else {
+            None
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Choose between the XML formats by the name of the root element, skipping any declaration,
+/// processing instructions, comments, and document type.
+///
+fn sniff_xml(text: &str) -> Format {
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment
+                .split_once("-->")
+                .map(|(_, rest)| rest)
+                .unwrap_or_default();
+        } else if !rest.starts_with(['?', '!']) {
+            let name = rest
+                .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .next()
+                .unwrap_or_default();
+            if name.rsplit(':').next() == Some("TriX") {
+                return Format::TriX;
+            }
+            break;
+        }
+    }
+    Format::Xml
+}
+
+fn sniff_json(text: &str) -> Format {
+    if ["\"@context\"", "\"@id\"", "\"@graph\"", "\"@type\""]
+        .iter()
+        .any(|keyword| text.contains(keyword))
+    {
+        Format::JsonLd
+    } else {
+        Format::Json
+    }
+}
+
+fn sniff_text(text: &str) -> Option<Format> {
+    let mut lines = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    let first = lines.next()?;
+    let is_directive = ["@prefix", "@base", "PREFIX ", "BASE "]
+        .iter()
+        .any(|directive| {
+            first.len() >= directive.len()
+                && first[..directive.len()].eq_ignore_ascii_case(directive)
+        });
+    if ["@forAll", "@forSome", "@keywords", "=>"]
+        .iter()
+        .any(|n3| text.contains(n3))
+    {
+        Some(Format::N3)
+    } else if first.starts_with('<') || first.starts_with("_:") {
+        match count_terms(first) {
+            Some(3) => Some(Format::NTriples),
+            Some(4) => Some(Format::NQuads),
+            _ => Some(Format::Turtle),
+        }
+    } else if is_directive || first.contains(':') {
+        if text.lines().any(|line| {
+            let line = line.trim();
+            line.starts_with("GRAPH ") || line.split('"').next().unwrap_or_default().contains('{')
+        }) {
+            Some(Format::TriG)
+        } else {
+            Some(Format::Turtle)
+        }
+    } else {
+        None
+    }
+}
+
+///
+/// Count the terms in a single line of N-Triples or N-Quads, returning `None` if the line is
+/// not a complete statement in either syntax.
+///
+fn count_terms(line: &str) -> Option<usize> {
+    let mut chars = line.chars().peekable();
+    let mut count = 0;
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        match chars.next()? {
+            '.' => {
+                let rest = chars.collect::<String>();
+                let rest = rest.trim_start();
+                return (rest.is_empty() || rest.starts_with('#')).then_some(count);
+            }
+            '<' => {
+                chars.find(|c| *c == '>')?;
+            }
+            '_' => while chars.next_if(|c| !c.is_whitespace()).is_some() {},
+            '"' => {
+                while match chars.next()? {
+                    '\\' => chars.next().is_some(),
+                    '"' => false,
+                    _ => true,
+                } {}
+                if chars.next_if_eq(&'@').is_some() {
+                    while chars
+                        .next_if(|c| c.is_alphanumeric() || *c == '-')
+                        .is_some()
+                    {}
+                } else if chars.next_if_eq(&'^').is_some() {
+                    chars.next_if_eq(&'^')?;
+                    chars.next_if_eq(&'<')?;
+                    chars.find(|c| *c == '>')?;
+                }
+            }
+            _ => return None,
+        }
+        count += 1;
+    }
+}
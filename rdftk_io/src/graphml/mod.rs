@@ -0,0 +1,55 @@
+/*!
+Provides for writing a `Graph` instance in the [GraphML](http://graphml.graphdrawing.org/)
+format, for loading into graph-analysis tools such as Gephi and yEd.
+
+Each distinct IRI, blank node, and literal is written as a `node` element, with a `kind` of
+`iri`, `blank`, or `literal` and a `label`; the `value` holds the full IRI, blank node label, or
+literal value, and literals also have a `datatype` or `language` where present. Each statement is
+written as a directed `edge` with the predicate as its `label`. Nested statements and collections
+are written with a `kind` of `statement` or `collection`, labelled `<< s p o >>` or `( a b )`,
+with their string form as the value.
+
+If the `fold_literals` option is set literal values are instead written as node attributes, with
+one GraphML key for each predicate; multiple values for the same predicate are separated by
+newlines.
+
+# Example
+
+```rust
+use rdftk_io::graphml::{GraphMlOptions, GraphMlWriter};
+# use objio::{HasOptions, ObjectWriter};
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { rdftk_core::model::graph::Graph::default() }
+
+let options = GraphMlOptions::default()
+    .with_fold_literals(true)
+    .with_pretty_print(true);
+
+let writer = GraphMlWriter::default().with_options(options);
+
+let result = writer.write_to_string(&make_graph());
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "GraphML";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "graphml";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/graphml+xml";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod syntax;
+
+mod writer;
+pub use writer::{GraphMlOptions, GraphMlWriter};
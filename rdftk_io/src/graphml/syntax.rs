@@ -0,0 +1,41 @@
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const GRAPHML_NAMESPACE: &str = "http://graphml.graphdrawing.org/xmlns";
+
+pub(super) const DEFAULT_ENCODING: &str = "utf-8";
+
+pub(super) const ELEMENT_GRAPHML: &str = "graphml";
+pub(super) const ELEMENT_KEY: &str = "key";
+pub(super) const ELEMENT_GRAPH: &str = "graph";
+pub(super) const ELEMENT_NODE: &str = "node";
+pub(super) const ELEMENT_EDGE: &str = "edge";
+pub(super) const ELEMENT_DATA: &str = "data";
+
+pub(super) const ATTRIBUTE_ID: &str = "id";
+pub(super) const ATTRIBUTE_FOR: &str = "for";
+pub(super) const ATTRIBUTE_NAME: &str = "attr.name";
+pub(super) const ATTRIBUTE_TYPE: &str = "attr.type";
+pub(super) const ATTRIBUTE_KEY: &str = "key";
+pub(super) const ATTRIBUTE_EDGE_DEFAULT: &str = "edgedefault";
+pub(super) const ATTRIBUTE_SOURCE: &str = "source";
+pub(super) const ATTRIBUTE_TARGET: &str = "target";
+
+pub(super) const FOR_NODE: &str = "node";
+pub(super) const FOR_EDGE: &str = "edge";
+pub(super) const TYPE_STRING: &str = "string";
+pub(super) const EDGE_DEFAULT_DIRECTED: &str = "directed";
+
+pub(super) const KEY_KIND: &str = "kind";
+pub(super) const KEY_LABEL: &str = "label";
+pub(super) const KEY_VALUE: &str = "value";
+pub(super) const KEY_DATATYPE: &str = "datatype";
+pub(super) const KEY_LANGUAGE: &str = "language";
+pub(super) const KEY_EDGE_LABEL: &str = "edge_label";
+pub(super) const KEY_PREDICATE: &str = "predicate";
+pub(super) const KEY_PROPERTY_PREFIX: &str = "property_";
+
+pub(super) const DEFAULT_GRAPH_ID: &str = "G";
+pub(super) const NODE_ID_PREFIX: &str = "n";
+pub(super) const EDGE_ID_PREFIX: &str = "e";
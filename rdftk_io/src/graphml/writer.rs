@@ -0,0 +1,324 @@
+use super::syntax::{
+    ATTRIBUTE_EDGE_DEFAULT, ATTRIBUTE_FOR, ATTRIBUTE_ID, ATTRIBUTE_KEY, ATTRIBUTE_NAME,
+    ATTRIBUTE_SOURCE, ATTRIBUTE_TARGET, ATTRIBUTE_TYPE, DEFAULT_ENCODING, DEFAULT_GRAPH_ID,
+    EDGE_DEFAULT_DIRECTED, EDGE_ID_PREFIX, ELEMENT_DATA, ELEMENT_EDGE, ELEMENT_GRAPH,
+    ELEMENT_GRAPHML, ELEMENT_KEY, ELEMENT_NODE, FOR_EDGE, FOR_NODE, GRAPHML_NAMESPACE,
+    KEY_DATATYPE, KEY_EDGE_LABEL, KEY_KIND, KEY_LABEL, KEY_LANGUAGE, KEY_PREDICATE,
+    KEY_PROPERTY_PREFIX, KEY_VALUE, NODE_ID_PREFIX, TYPE_STRING,
+};
+use crate::common::diagram::{Diagram, Node};
+use crate::GraphWriter;
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::{Graph, GraphName};
+use std::collections::HashMap;
+use std::io::Write;
+use xml::common::XmlVersion;
+use xml::writer::{EventWriter, XmlEvent};
+use xml::EmitterConfig;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Options that control how the GraphML writer will render a graph.
+///
+#[derive(Clone, Debug)]
+pub struct GraphMlOptions {
+    /// Determines whether IRI node labels are compressed using the graph's prefix mappings.
+    /// Default is `false`.
+    prefixed_labels: bool,
+    /// Determines whether literal values are written as attributes of their subject node,
+    /// rather than as separate nodes. Default is `false`.
+    fold_literals: bool,
+    /// Should the output be pretty-printed, including indentation. Default is `false`.
+    pretty_print: bool,
+    /// The encoding to specify in the XML declaration. Default is "utf-8".
+    encoding: String,
+}
+
+///
+/// This struct implements the `GraphWriter` trait and will write out a GraphML document for
+/// the entire graph.
+///
+#[derive(Debug, Default)]
+pub struct GraphMlWriter {
+    options: GraphMlOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for GraphMlOptions {
+    fn default() -> Self {
+        Self {
+            prefixed_labels: false,
+            fold_literals: false,
+            pretty_print: false,
+            encoding: String::from(DEFAULT_ENCODING),
+        }
+    }
+}
+
+impl GraphMlOptions {
+    pub fn with_prefixed_labels(self, prefixed_labels: bool) -> Self {
+        Self {
+            prefixed_labels,
+            ..self
+        }
+    }
+
+    pub fn with_fold_literals(self, fold_literals: bool) -> Self {
+        Self {
+            fold_literals,
+            ..self
+        }
+    }
+
+    pub fn with_pretty_print(self, pretty_print: bool) -> Self {
+        Self {
+            pretty_print,
+            ..self
+        }
+    }
+
+    pub fn with_encoding<S>(self, encoding: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            encoding: encoding.into(),
+            ..self
+        }
+    }
+
+    pub fn prefixed_labels(&self) -> bool {
+        self.prefixed_labels
+    }
+
+    pub fn set_prefixed_labels(&mut self, prefixed_labels: bool) {
+        self.prefixed_labels = prefixed_labels;
+    }
+
+    pub fn fold_literals(&self) -> bool {
+        self.fold_literals
+    }
+
+    pub fn set_fold_literals(&mut self, fold_literals: bool) {
+        self.fold_literals = fold_literals;
+    }
+
+    pub fn pretty_print(&self) -> bool {
+        self.pretty_print
+    }
+
+    pub fn set_pretty_print(&mut self, pretty_print: bool) {
+        self.pretty_print = pretty_print;
+    }
+
+    pub fn encoding(&self) -> &String {
+        &self.encoding
+    }
+
+    pub fn set_encoding<S>(&mut self, encoding: S)
+    where
+        S: Into<String>,
+    {
+        self.encoding = encoding.into();
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(GraphMlWriter, GraphMlOptions);
+
+impl ObjectWriter<Graph> for GraphMlWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let diagram = Diagram::from_graph(
+            graph,
+            self.options.prefixed_labels,
+            self.options.fold_literals,
+        );
+
+        let mut writer = EmitterConfig::new()
+            .perform_indent(self.options.pretty_print)
+            .normalize_empty_elements(self.options.pretty_print)
+            .create_writer(w);
+        writer
+            .write(XmlEvent::StartDocument {
+                version: XmlVersion::Version10,
+                encoding: Some(&self.options.encoding),
+                standalone: None,
+            })
+            .map_err(xml_error)?;
+        writer
+            .write(XmlEvent::start_element(ELEMENT_GRAPHML).default_ns(GRAPHML_NAMESPACE))
+            .map_err(xml_error)?;
+
+        for (id, name) in [
+            (KEY_KIND, KEY_KIND),
+            (KEY_LABEL, KEY_LABEL),
+            (KEY_VALUE, KEY_VALUE),
+            (KEY_DATATYPE, KEY_DATATYPE),
+            (KEY_LANGUAGE, KEY_LANGUAGE),
+        ] {
+            write_key(&mut writer, id, FOR_NODE, name)?;
+        }
+        write_key(&mut writer, KEY_EDGE_LABEL, FOR_EDGE, KEY_LABEL)?;
+        write_key(&mut writer, KEY_PREDICATE, FOR_EDGE, KEY_PREDICATE)?;
+
+        // each distinct folded predicate has its own key, in the order they are first seen.
+        let mut property_keys: HashMap<&String, String> = Default::default();
+        for node in diagram.nodes() {
+            for (predicate, _) in &node.properties {
+                if !property_keys.contains_key(predicate) {
+                    let id = format!("{KEY_PROPERTY_PREFIX}{}", property_keys.len() + 1);
+                    write_key(&mut writer, &id, FOR_NODE, predicate)?;
+                    let _ = property_keys.insert(predicate, id);
+                }
+            }
+        }
+
+        let graph_id = match graph.name() {
+            Some(GraphName::Iri(name)) => format!("{name:#}"),
+            Some(GraphName::BNode(name)) => format!("_:{name}"),
+            None => DEFAULT_GRAPH_ID.to_string(),
+        };
+        writer
+            .write(
+                XmlEvent::start_element(ELEMENT_GRAPH)
+                    .attr(ATTRIBUTE_ID, &graph_id)
+                    .attr(ATTRIBUTE_EDGE_DEFAULT, EDGE_DEFAULT_DIRECTED),
+            )
+            .map_err(xml_error)?;
+
+        for node in diagram.nodes() {
+            write_node(&mut writer, node, &property_keys)?;
+        }
+
+        for (index, edge) in diagram.edges().enumerate() {
+            let id = format!("{EDGE_ID_PREFIX}{}", index + 1);
+            let source = format!("{NODE_ID_PREFIX}{}", edge.subject);
+            let target = format!("{NODE_ID_PREFIX}{}", edge.object);
+            writer
+                .write(
+                    XmlEvent::start_element(ELEMENT_EDGE)
+                        .attr(ATTRIBUTE_ID, &id)
+                        .attr(ATTRIBUTE_SOURCE, &source)
+                        .attr(ATTRIBUTE_TARGET, &target),
+                )
+                .map_err(xml_error)?;
+            write_data(&mut writer, KEY_EDGE_LABEL, &edge.label)?;
+            write_data(&mut writer, KEY_PREDICATE, &edge.predicate)?;
+            writer
+                .write(XmlEvent::end_element().name(ELEMENT_EDGE))
+                .map_err(xml_error)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element().name(ELEMENT_GRAPH))
+            .map_err(xml_error)?;
+        writer
+            .write(XmlEvent::end_element().name(ELEMENT_GRAPHML))
+            .map_err(xml_error)?;
+        Ok(writer.inner_mut().flush()?)
+    }
+}
+
+impl GraphWriter for GraphMlWriter {}
+
+impl GraphMlWriter {
+    pub fn with_options(self, options: GraphMlOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn xml_error(e: xml::writer::Error) -> Error {
+    Error::Tokenizer {
+        representation: super::NAME.into(),
+        source: Box::new(e),
+    }
+}
+
+fn write_key<W: Write>(
+    writer: &mut EventWriter<W>,
+    id: &str,
+    for_element: &str,
+    name: &str,
+) -> Result<(), Error> {
+    writer
+        .write(
+            XmlEvent::start_element(ELEMENT_KEY)
+                .attr(ATTRIBUTE_ID, id)
+                .attr(ATTRIBUTE_FOR, for_element)
+                .attr(ATTRIBUTE_NAME, name)
+                .attr(ATTRIBUTE_TYPE, TYPE_STRING),
+        )
+        .map_err(xml_error)?;
+    writer
+        .write(XmlEvent::end_element().name(ELEMENT_KEY))
+        .map_err(xml_error)
+}
+
+fn write_node<W: Write>(
+    writer: &mut EventWriter<W>,
+    node: &Node,
+    property_keys: &HashMap<&String, String>,
+) -> Result<(), Error> {
+    let id = format!("{NODE_ID_PREFIX}{}", node.id);
+    writer
+        .write(XmlEvent::start_element(ELEMENT_NODE).attr(ATTRIBUTE_ID, &id))
+        .map_err(xml_error)?;
+    write_data(writer, KEY_KIND, node.kind.as_str())?;
+    write_data(writer, KEY_LABEL, &node.label)?;
+    write_data(writer, KEY_VALUE, &node.value)?;
+    if let Some(data_type) = &node.data_type {
+        write_data(writer, KEY_DATATYPE, data_type)?;
+    }
+    if let Some(language) = &node.language {
+        write_data(writer, KEY_LANGUAGE, language)?;
+    }
+    let mut written: Vec<&String> = Default::default();
+    for (predicate, _) in &node.properties {
+        if !written.contains(&predicate) {
+            let values = node
+                .properties
+                .iter()
+                .filter(|(other, _)| other == predicate)
+                .map(|(_, value)| value.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            write_data(writer, &property_keys[predicate], &values)?;
+            written.push(predicate);
+        }
+    }
+    writer
+        .write(XmlEvent::end_element().name(ELEMENT_NODE))
+        .map_err(xml_error)
+}
+
+fn write_data<W: Write>(writer: &mut EventWriter<W>, key: &str, value: &str) -> Result<(), Error> {
+    writer
+        .write(XmlEvent::start_element(ELEMENT_DATA).attr(ATTRIBUTE_KEY, key))
+        .map_err(xml_error)?;
+    writer
+        .write(XmlEvent::characters(value))
+        .map_err(xml_error)?;
+    writer
+        .write(XmlEvent::end_element().name(ELEMENT_DATA))
+        .map_err(xml_error)
+}
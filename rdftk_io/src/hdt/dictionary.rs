@@ -0,0 +1,409 @@
+/*!
+The HDT _Four Section_ dictionary, which maps each term to an integer identifier. Terms that
+appear as both subject and object are stored once, in the shared section, and each section is
+a sorted list of strings with plain front coding (PFC).
+*/
+
+use super::encoding::{
+    crc32c, crc8, decode_vbyte, invalid_data, read_u8, read_vbyte, write_sequence, write_vbyte,
+    Sequence, Source,
+};
+use crate::common::literal::unescape_lexical_form;
+use rdftk_core::error::Error;
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, SubjectNode};
+use rdftk_iri::Iri;
+use std::cell::RefCell;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A dictionary read from an HDT file, sections are read directly from the file as terms are
+/// requested.
+///
+#[derive(Debug)]
+pub(super) struct Dictionary {
+    shared: Section,
+    subjects: Section,
+    predicates: Section,
+    objects: Section,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const DICTIONARY_FORMAT_FOUR: &str = "<http://purl.org/HDT/hdt#dictionaryFour>";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn subject_to_string(subject: &SubjectNode) -> Result<String, Error> {
+    match subject {
+        SubjectNode::Blank(node) => Ok(format!("_:{node}")),
+        SubjectNode::Resource(iri) => Ok(format!("{iri:#}")),
+        SubjectNode::Statement(_) => Err(invalid_data("statements may not be used as terms")),
+    }
+}
+
+pub(super) fn object_to_string(object: &ObjectNode) -> Result<String, Error> {
+    match object {
+        ObjectNode::Blank(node) => Ok(format!("_:{node}")),
+        ObjectNode::Resource(iri) => Ok(format!("{iri:#}")),
+        ObjectNode::Literal(literal) => {
+            let value = unescape_lexical_form(literal.lexical_form());
+            if value.contains('\0') {
+                return Err(invalid_data("literals may not contain the NUL character"));
+            }
+            Ok(match (literal.language(), literal.data_type()) {
+                (Some(language), _) => format!("\"{value}\"@{language}"),
+                (None, Some(data_type)) => format!("\"{value}\"^^<{:#}>", data_type.as_iri()),
+                (None, None) => format!("\"{value}\""),
+            })
+        }
+        _ => Err(invalid_data(
+            "statements and collections may not be used as terms",
+        )),
+    }
+}
+
+pub(super) fn string_to_subject(value: &str) -> Result<SubjectNode, Error> {
+    match value.strip_prefix("_:") {
+        Some(label) => Ok(BlankNode::from_str(label)?.into()),
+        None => Ok(Iri::from_str(value)?.into()),
+    }
+}
+
+pub(super) fn string_to_object(value: &str) -> Result<ObjectNode, Error> {
+    if let Some(literal) = value.strip_prefix('"') {
+        let end = literal
+            .rfind('"')
+            .ok_or_else(|| invalid_data(format!("unterminated literal {value:?}")))?;
+        let (lexical_form, suffix) = (&literal[..end], &literal[end + 1..]);
+        Ok(if let Some(language) = suffix.strip_prefix('@') {
+            Literal::with_language(lexical_form, LanguageTag::from_str(language)?)
+        } else if let Some(data_type) = suffix
+            .strip_prefix("^^<")
+            .and_then(|data_type| data_type.strip_suffix('>'))
+        {
+            Literal::with_data_type(lexical_form, DataType::from(Iri::from_str(data_type)?))
+        } else {
+            Literal::plain(lexical_form)
+        }
+        .into())
+    } else {
+        Ok(string_to_subject(value)?.to_object())
+    }
+}
+
+///
+/// Write a PFC dictionary section containing `strings`, which must already be sorted.
+///
+pub(super) fn write_section<W>(
+    w: &mut W,
+    strings: &[&String],
+    block_size: usize,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut data = Vec::default();
+    let mut blocks = Vec::default();
+    let mut previous: &[u8] = &[];
+    for (index, string) in strings.iter().enumerate() {
+        let string = string.as_bytes();
+        if index % block_size == 0 {
+            blocks.push(data.len() as u64);
+            data.extend(string);
+        } else {
+            let shared = previous
+                .iter()
+                .zip(string)
+                .take_while(|(lhs, rhs)| lhs == rhs)
+                .count();
+            write_vbyte(&mut data, shared as u64);
+            data.extend(&string[shared..]);
+        }
+        data.push(0);
+        previous = string;
+    }
+    blocks.push(data.len() as u64);
+
+    let mut header = vec![SECTION_TYPE_PFC];
+    write_vbyte(&mut header, strings.len() as u64);
+    write_vbyte(&mut header, data.len() as u64);
+    write_vbyte(&mut header, block_size as u64);
+    header.push(crc8(&header));
+    w.write_all(&header)?;
+    write_sequence(w, &blocks)?;
+    w.write_all(&data)?;
+    w.write_all(&crc32c(&data).to_le_bytes())?;
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single PFC section, the most recently decoded block is cached as lookups tend to touch
+/// the same block repeatedly.
+///
+#[derive(Debug)]
+struct Section {
+    len: u64,
+    block_size: u64,
+    blocks: Sequence,
+    offset: u64,
+    cache: RefCell<Option<(u64, Vec<String>)>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const SECTION_TYPE_PFC: u8 = 2;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Dictionary {
+    ///
+    /// Read the sections of the dictionary, leaving `r` positioned after it.
+    ///
+    pub(super) fn open<R>(r: &mut R) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(Self {
+            shared: Section::open(r)?,
+            subjects: Section::open(r)?,
+            predicates: Section::open(r)?,
+            objects: Section::open(r)?,
+        })
+    }
+
+    pub(super) fn subject_id<R>(
+        &self,
+        source: &Source<R>,
+        subject: &str,
+    ) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.shared_or(source, &self.subjects, subject)
+    }
+
+    pub(super) fn predicate_id<R>(
+        &self,
+        source: &Source<R>,
+        predicate: &str,
+    ) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.predicates.locate(source, predicate)
+    }
+
+    pub(super) fn object_id<R>(
+        &self,
+        source: &Source<R>,
+        object: &str,
+    ) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.shared_or(source, &self.objects, object)
+    }
+
+    pub(super) fn subject<R>(&self, source: &Source<R>, id: u64) -> Result<String, Error>
+    where
+        R: Read + Seek,
+    {
+        self.shared_or_string(source, &self.subjects, id)
+    }
+
+    pub(super) fn predicate<R>(&self, source: &Source<R>, id: u64) -> Result<String, Error>
+    where
+        R: Read + Seek,
+    {
+        self.predicates.string(source, id)
+    }
+
+    pub(super) fn object<R>(&self, source: &Source<R>, id: u64) -> Result<String, Error>
+    where
+        R: Read + Seek,
+    {
+        self.shared_or_string(source, &self.objects, id)
+    }
+
+    fn shared_or<R>(
+        &self,
+        source: &Source<R>,
+        section: &Section,
+        value: &str,
+    ) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        match self.shared.locate(source, value)? {
+            Some(id) => Ok(Some(id)),
+            None => Ok(section
+                .locate(source, value)?
+                .map(|id| id + self.shared.len)),
+        }
+    }
+
+    fn shared_or_string<R>(
+        &self,
+        source: &Source<R>,
+        section: &Section,
+        id: u64,
+    ) -> Result<String, Error>
+    where
+        R: Read + Seek,
+    {
+        if id <= self.shared.len {
+            self.shared.string(source, id)
+        } else {
+            section.string(source, id - self.shared.len)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Section {
+    fn open<R>(r: &mut R) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut header = vec![read_u8(r)?];
+        if header[0] != SECTION_TYPE_PFC {
+            return Err(invalid_data(format!(
+                "unsupported dictionary section type {}",
+                header[0]
+            )));
+        }
+        let len = read_vbyte(r, &mut header)?;
+        let data_len = read_vbyte(r, &mut header)?;
+        let block_size = read_vbyte(r, &mut header)?;
+        if read_u8(r)? != crc8(&header) {
+            return Err(invalid_data("dictionary section checksum does not match"));
+        }
+        if block_size == 0 {
+            return Err(invalid_data("dictionary section has a block size of zero"));
+        }
+        let blocks = Sequence::open(r)?;
+        let offset = r.stream_position()?;
+        let _ = r.seek(SeekFrom::Current(data_len as i64 + 4))?;
+        Ok(Self {
+            len,
+            block_size,
+            blocks,
+            offset,
+            cache: Default::default(),
+        })
+    }
+
+    ///
+    /// Return the string with the identifier `id`, counting from one.
+    ///
+    fn string<R>(&self, source: &Source<R>, id: u64) -> Result<String, Error>
+    where
+        R: Read + Seek,
+    {
+        if id == 0 || id > self.len {
+            return Err(invalid_data(format!(
+                "dictionary identifier {id} is out of range"
+            )));
+        }
+        let block = (id - 1) / self.block_size;
+        let strings = self.block(source, block)?;
+        strings
+            .get(((id - 1) % self.block_size) as usize)
+            .cloned()
+            .ok_or_else(|| invalid_data("dictionary block is truncated"))
+    }
+
+    ///
+    /// Return the identifier of `value`, if present, using a binary search over the first
+    /// string of each block.
+    ///
+    fn locate<R>(&self, source: &Source<R>, value: &str) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        let (mut low, mut high) = (0, self.len.div_ceil(self.block_size));
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            let first = self.block(source, middle)?.first().cloned();
+            if first.as_deref().is_some_and(|first| first <= value) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(self
+            .block(source, low)?
+            .iter()
+            .position(|string| string == value)
+            .map(|index| low * self.block_size + index as u64 + 1))
+    }
+
+    fn block<R>(&self, source: &Source<R>, block: u64) -> Result<Vec<String>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut cache = self
+            .cache
+            .try_borrow_mut()
+            .map_err(|_| Error::InvalidState)?;
+        if let Some((cached, strings)) = &*cache {
+            if *cached == block {
+                return Ok(strings.clone());
+            }
+        }
+        let start = self.blocks.get(source, block)?;
+        let end = self.blocks.get(source, block + 1)?;
+        if end < start {
+            return Err(invalid_data("dictionary block offsets are not in order"));
+        }
+        let data = source.read_at(self.offset + start, (end - start) as usize)?;
+
+        let count = (self.len - block * self.block_size).min(self.block_size) as usize;
+        let mut strings: Vec<String> = Vec::with_capacity(count);
+        let mut position = 0;
+        while strings.len() < count && position < data.len() {
+            let mut bytes = match strings.last() {
+                None => Vec::default(),
+                Some(previous) => {
+                    let shared = decode_vbyte(&data, &mut position)? as usize;
+                    previous
+                        .as_bytes()
+                        .get(..shared)
+                        .ok_or_else(|| invalid_data("dictionary prefix is too long"))?
+                        .to_vec()
+                }
+            };
+            let end = data[position..]
+                .iter()
+                .position(|byte| *byte == 0)
+                .ok_or_else(|| invalid_data("dictionary string is not terminated"))?;
+            bytes.extend(&data[position..position + end]);
+            position += end + 1;
+            strings.push(String::from_utf8(bytes)?);
+        }
+        *cache = Some((block, strings.clone()));
+        Ok(strings)
+    }
+}
@@ -0,0 +1,617 @@
+/*!
+The low-level encodings used by HDT; control information, variable-length integers, log
+sequences, bitmaps, and the checksums that protect each of them.
+*/
+
+use rdftk_core::error::Error;
+use std::cell::RefCell;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The control information that starts each part of an HDT file, it identifies the type of
+/// the part, the format used to encode it, and a set of `key=value;` properties.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct ControlInfo {
+    control_type: u8,
+    format: String,
+    properties: Vec<(String, String)>,
+}
+
+///
+/// A shared reader that allows sections of the file to be read at arbitrary offsets.
+///
+#[derive(Debug)]
+pub(super) struct Source<R> {
+    inner: RefCell<R>,
+}
+
+///
+/// A log sequence, a packed array of integers all encoded with the same number of bits,
+/// which is read directly from the file as values are requested.
+///
+#[derive(Debug)]
+pub(super) struct Sequence {
+    offset: u64,
+    bits: u8,
+    len: u64,
+    cache: Chunk,
+}
+
+///
+/// A bitmap, read directly from the file as bits are requested. A small index of the number
+/// of set bits before each block is built when the bitmap is opened to support `select1`.
+///
+#[derive(Debug)]
+pub(super) struct Bitmap {
+    offset: u64,
+    len: u64,
+    index: Vec<u64>,
+    cache: Chunk,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const CONTROL_TYPE_GLOBAL: u8 = 1;
+pub(super) const CONTROL_TYPE_HEADER: u8 = 2;
+pub(super) const CONTROL_TYPE_DICTIONARY: u8 = 3;
+pub(super) const CONTROL_TYPE_TRIPLES: u8 = 4;
+
+pub(super) const SEQUENCE_TYPE_LOG64: u8 = 1;
+pub(super) const BITMAP_TYPE_PLAIN: u8 = 1;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return an error for content that does not conform to the HDT format.
+///
+pub(super) fn invalid_data<S>(message: S) -> Error
+where
+    S: Into<String>,
+{
+    Error::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("{}: {}", super::NAME, message.into()),
+    ))
+}
+
+pub(super) fn write_vbyte(out: &mut Vec<u8>, value: u64) {
+    let mut value = value;
+    while value > 0x7F {
+        out.push((value & 0x7F) as u8);
+        value >>= 7;
+    }
+    out.push(value as u8 | 0x80);
+}
+
+///
+/// Read a variable-length integer, appending the bytes read to `raw` so that they may be
+/// included in a checksum.
+///
+pub(super) fn read_vbyte<R>(r: &mut R, raw: &mut Vec<u8>) -> Result<u64, Error>
+where
+    R: Read,
+{
+    let mut value = 0_u64;
+    for shift in (0..64).step_by(7) {
+        let byte = read_u8(r)?;
+        raw.push(byte);
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 != 0 {
+            return Ok(value);
+        }
+    }
+    Err(invalid_data("variable-length integer is too long"))
+}
+
+pub(super) fn decode_vbyte(data: &[u8], position: &mut usize) -> Result<u64, Error> {
+    let mut raw = Vec::default();
+    let mut slice = data
+        .get(*position..)
+        .ok_or_else(|| invalid_data("variable-length integer is truncated"))?;
+    let value = read_vbyte(&mut slice, &mut raw)?;
+    *position += raw.len();
+    Ok(value)
+}
+
+pub(super) fn read_u8<R>(r: &mut R) -> Result<u8, Error>
+where
+    R: Read,
+{
+    let mut buffer = [0_u8; 1];
+    r.read_exact(&mut buffer)?;
+    Ok(buffer[0])
+}
+
+///
+/// Write a log sequence, using the fewest bits that can represent the largest value.
+///
+pub(super) fn write_sequence<W>(w: &mut W, values: &[u64]) -> Result<(), Error>
+where
+    W: Write,
+{
+    let bits = values
+        .iter()
+        .max()
+        .map(|max| u64::BITS - max.leading_zeros())
+        .unwrap_or_default() as usize;
+    let mut header = vec![SEQUENCE_TYPE_LOG64, bits as u8];
+    write_vbyte(&mut header, values.len() as u64);
+    header.push(crc8(&header));
+    w.write_all(&header)?;
+
+    let mut data = vec![0_u8; (bits * values.len()).div_ceil(8)];
+    for (index, value) in values.iter().enumerate() {
+        let bit = index * bits;
+        let value = (*value as u128) << (bit % 8);
+        for (offset, byte) in value.to_le_bytes().iter().enumerate() {
+            match data.get_mut(bit / 8 + offset) {
+                Some(target) => *target |= byte,
+                None => break,
+            }
+        }
+    }
+    w.write_all(&data)?;
+    w.write_all(&crc32c(&data).to_le_bytes())?;
+    Ok(())
+}
+
+pub(super) fn write_bitmap<W>(w: &mut W, bits: &[bool]) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut header = vec![BITMAP_TYPE_PLAIN];
+    write_vbyte(&mut header, bits.len() as u64);
+    header.push(crc8(&header));
+    w.write_all(&header)?;
+
+    let mut data = vec![0_u8; bits.len().div_ceil(8)];
+    for (index, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+        data[index / 8] |= 1 << (index % 8);
+    }
+    w.write_all(&data)?;
+    w.write_all(&crc32c(&data).to_le_bytes())?;
+    Ok(())
+}
+
+///
+/// CRC-8-CCITT, used for the headers of sequences, bitmaps, and dictionary sections.
+///
+pub(super) fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0_u8, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+///
+/// CRC-16-ANSI, used for control information.
+///
+pub(super) fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0_u16, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u16, |crc, _| {
+            if crc & 1 != 0 {
+                (crc >> 1) ^ 0xA001
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+///
+/// CRC-32C (Castagnoli), used for the data of sequences, bitmaps, and dictionary sections.
+///
+pub(super) fn crc32c(data: &[u8]) -> u32 {
+    !data.iter().fold(!0_u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 != 0 {
+                (crc >> 1) ^ 0x82F6_3B78
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single cached region of a section, most reads are close to the previous one.
+///
+#[derive(Debug, Default)]
+struct Chunk {
+    region: RefCell<(u64, Vec<u8>)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const COOKIE: &[u8; 4] = b"$HDT";
+
+const CHUNK_SIZE: u64 = 4096;
+
+/// The number of bits in each block of the select index.
+const INDEX_BLOCK_BITS: u64 = CHUNK_SIZE * 8;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ControlInfo {
+    pub(super) fn new<S>(control_type: u8, format: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            control_type,
+            format: format.into(),
+            properties: Vec::default(),
+        }
+    }
+
+    pub(super) fn with_property<K, V>(self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: ToString,
+    {
+        let mut self_mut = self;
+        self_mut.properties.push((key.into(), value.to_string()));
+        self_mut
+    }
+
+    pub(super) fn control_type(&self) -> u8 {
+        self.control_type
+    }
+
+    pub(super) fn format(&self) -> &str {
+        &self.format
+    }
+
+    pub(super) fn property(&self, key: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub(super) fn write<W>(&self, w: &mut W) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        let mut bytes = COOKIE.to_vec();
+        bytes.push(self.control_type);
+        bytes.extend(self.format.as_bytes());
+        bytes.push(0);
+        for (key, value) in &self.properties {
+            bytes.extend(format!("{key}={value};").as_bytes());
+        }
+        bytes.push(0);
+        let crc = crc16(&bytes);
+        bytes.extend(crc.to_le_bytes());
+        w.write_all(&bytes)?;
+        Ok(())
+    }
+
+    pub(super) fn read<R>(r: &mut R, control_type: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        let mut bytes = vec![0_u8; COOKIE.len() + 1];
+        r.read_exact(&mut bytes)?;
+        if &bytes[..COOKIE.len()] != COOKIE {
+            return Err(invalid_data("missing control information"));
+        }
+        if bytes[COOKIE.len()] != control_type {
+            return Err(invalid_data(format!(
+                "expected control information of type {control_type}, not {}",
+                bytes[COOKIE.len()]
+            )));
+        }
+        let format = read_terminated(r, &mut bytes)?;
+        let properties = read_terminated(r, &mut bytes)?;
+        let mut crc = [0_u8; 2];
+        r.read_exact(&mut crc)?;
+        if u16::from_le_bytes(crc) != crc16(&bytes) {
+            return Err(invalid_data("control information checksum does not match"));
+        }
+        Ok(Self {
+            control_type,
+            format,
+            properties: properties
+                .split(';')
+                .filter_map(|property| property.split_once('='))
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<R> Source<R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(inner: R) -> Self {
+        Self {
+            inner: RefCell::new(inner),
+        }
+    }
+
+    pub(super) fn read_at(&self, offset: u64, len: usize) -> Result<Vec<u8>, Error> {
+        let mut inner = self
+            .inner
+            .try_borrow_mut()
+            .map_err(|_| Error::InvalidState)?;
+        let _ = inner.seek(SeekFrom::Start(offset))?;
+        let mut buffer = vec![0_u8; len];
+        inner.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Sequence {
+    ///
+    /// Read the header of a log sequence, leaving `r` positioned after the sequence.
+    ///
+    pub(super) fn open<R>(r: &mut R) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut header = vec![read_u8(r)?];
+        if header[0] != SEQUENCE_TYPE_LOG64 {
+            return Err(invalid_data(format!(
+                "unsupported sequence type {}",
+                header[0]
+            )));
+        }
+        let bits = read_u8(r)?;
+        header.push(bits);
+        let len = read_vbyte(r, &mut header)?;
+        check_crc8(r, &header)?;
+        if bits > 64 {
+            return Err(invalid_data(format!("sequence values of {bits} bits")));
+        }
+        let offset = r.stream_position()?;
+        let _ = r.seek(SeekFrom::Current(
+            (bits as u64 * len).div_ceil(8) as i64 + 4,
+        ))?;
+        Ok(Self {
+            offset,
+            bits,
+            len,
+            cache: Chunk::default(),
+        })
+    }
+
+    pub(super) fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub(super) fn get<R>(&self, source: &Source<R>, index: u64) -> Result<u64, Error>
+    where
+        R: Read + Seek,
+    {
+        if index >= self.len {
+            return Err(invalid_data(format!(
+                "sequence index {index} is out of range"
+            )));
+        }
+        if self.bits == 0 {
+            return Ok(0);
+        }
+        let bit = index * self.bits as u64;
+        let end = (bit + self.bits as u64).div_ceil(8);
+        let bytes = self.cache.read(
+            source,
+            self.offset,
+            self.data_len(),
+            bit / 8,
+            (end - bit / 8) as usize,
+        )?;
+        let mut buffer = [0_u8; 16];
+        buffer[..bytes.len()].copy_from_slice(&bytes);
+        let value = u128::from_le_bytes(buffer) >> (bit % 8);
+        Ok((value & ((1_u128 << self.bits) - 1)) as u64)
+    }
+
+    fn data_len(&self) -> u64 {
+        (self.bits as u64 * self.len).div_ceil(8)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Bitmap {
+    ///
+    /// Read the header of a bitmap, and build its index, leaving `r` positioned after the
+    /// bitmap.
+    ///
+    pub(super) fn open<R>(r: &mut R) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut header = vec![read_u8(r)?];
+        if header[0] != BITMAP_TYPE_PLAIN {
+            return Err(invalid_data(format!(
+                "unsupported bitmap type {}",
+                header[0]
+            )));
+        }
+        let len = read_vbyte(r, &mut header)?;
+        check_crc8(r, &header)?;
+        let offset = r.stream_position()?;
+
+        let mut index = Vec::default();
+        let mut ones = 0_u64;
+        let mut remaining = len.div_ceil(8);
+        let mut block = vec![0_u8; CHUNK_SIZE as usize];
+        while remaining > 0 {
+            let size = remaining.min(CHUNK_SIZE) as usize;
+            r.read_exact(&mut block[..size])?;
+            index.push(ones);
+            ones += block[..size]
+                .iter()
+                .map(|byte| byte.count_ones() as u64)
+                .sum::<u64>();
+            remaining -= size as u64;
+        }
+        let _ = r.seek(SeekFrom::Current(4))?;
+        Ok(Self {
+            offset,
+            len,
+            index,
+            cache: Chunk::default(),
+        })
+    }
+
+    pub(super) fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub(super) fn get<R>(&self, source: &Source<R>, index: u64) -> Result<bool, Error>
+    where
+        R: Read + Seek,
+    {
+        if index >= self.len {
+            return Err(invalid_data(format!(
+                "bitmap index {index} is out of range"
+            )));
+        }
+        let byte = self
+            .cache
+            .read(source, self.offset, self.data_len(), index / 8, 1)?[0];
+        Ok(byte & (1 << (index % 8)) != 0)
+    }
+
+    ///
+    /// Return the position of the `count`th set bit, counting from one.
+    ///
+    pub(super) fn select1<R>(&self, source: &Source<R>, count: u64) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        if count == 0 {
+            return Ok(None);
+        }
+        let block = self.index.partition_point(|ones| *ones < count);
+        if block == 0 {
+            return Ok(None);
+        }
+        let block = (block - 1) as u64;
+        let mut ones = self.index[block as usize];
+        let start = block * CHUNK_SIZE;
+        let size = (self.data_len() - start).min(CHUNK_SIZE);
+        let bytes = self
+            .cache
+            .read(source, self.offset, self.data_len(), start, size as usize)?;
+        for (i, byte) in bytes.iter().enumerate() {
+            let byte_ones = byte.count_ones() as u64;
+            if ones + byte_ones >= count {
+                let mut byte = *byte;
+                loop {
+                    let bit = byte.trailing_zeros() as u64;
+                    ones += 1;
+                    if ones == count {
+                        let position = block * INDEX_BLOCK_BITS + i as u64 * 8 + bit;
+                        return Ok((position < self.len).then_some(position));
+                    }
+                    byte &= byte - 1;
+                }
+            }
+            ones += byte_ones;
+        }
+        Ok(None)
+    }
+
+    fn data_len(&self) -> u64 {
+        self.len.div_ceil(8)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Chunk {
+    ///
+    /// Read `len` bytes at `start` within the section of `section_len` bytes at `offset`,
+    /// using the cached region if it contains them.
+    ///
+    fn read<R>(
+        &self,
+        source: &Source<R>,
+        offset: u64,
+        section_len: u64,
+        start: u64,
+        len: usize,
+    ) -> Result<Vec<u8>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut region = self
+            .region
+            .try_borrow_mut()
+            .map_err(|_| Error::InvalidState)?;
+        let (region_start, data) = &*region;
+        let end = start + len as u64;
+        if start < *region_start || end > *region_start + data.len() as u64 {
+            if end > section_len {
+                return Err(invalid_data("read beyond the end of a section"));
+            }
+            let size = (section_len - start).min(CHUNK_SIZE.max(len as u64));
+            *region = (start, source.read_at(offset + start, size as usize)?);
+        }
+        let (region_start, data) = &*region;
+        let from = (start - region_start) as usize;
+        Ok(data[from..from + len].to_vec())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_terminated<R>(r: &mut R, raw: &mut Vec<u8>) -> Result<String, Error>
+where
+    R: Read,
+{
+    let mut value = Vec::default();
+    loop {
+        let byte = read_u8(r)?;
+        raw.push(byte);
+        if byte == 0 {
+            break;
+        }
+        value.push(byte);
+    }
+    String::from_utf8(value).map_err(Error::from)
+}
+
+fn check_crc8<R>(r: &mut R, header: &[u8]) -> Result<(), Error>
+where
+    R: Read,
+{
+    if read_u8(r)? != crc8(header) {
+        Err(invalid_data("header checksum does not match"))
+    } else {
+        Ok(())
+    }
+}
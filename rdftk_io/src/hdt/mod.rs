@@ -0,0 +1,81 @@
+/*!
+Provides for reading and writing a `Graph` instance in the
+W3C [Binary RDF Representation for Publication and Exchange (HDT)](https://www.w3.org/Submission/HDT/)
+format.
+
+HDT files are composed of a header, a dictionary mapping each term to an integer, and the
+triples as compact adjacency lists of these integers. This implementation uses the _Four
+Section_ dictionary with front-coded string blocks and _Bitmap Triples_ in subject, predicate,
+object order, as produced by the reference implementation.
+
+# Example Writer
+
+```rust
+use rdftk_io::hdt::HdtWriter;
+# use objio::ObjectWriter;
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { Graph::default() }
+
+let writer = HdtWriter::default();
+
+let mut buffer = Vec::default();
+writer.write(&mut buffer, &make_graph()).unwrap();
+```
+
+# Example Queries
+
+An [`HdtFile`] reads only the structure of a file when it is opened, so that statements
+matching a pattern can be found without loading the whole file into memory.
+
+```rust,no_run
+use rdftk_io::hdt::HdtFile;
+use rdftk_core::model::statement::SubjectNode;
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+let file = HdtFile::open("dbpedia.hdt").unwrap();
+let subject = SubjectNode::from(Iri::from_str("http://dbpedia.org/resource/Rust").unwrap());
+
+for statement in file.matches(Some(&subject), None, None).unwrap() {
+    println!("{}", statement.unwrap());
+}
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "HDT";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "hdt";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/vnd.hdt";
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const GLOBAL_FORMAT: &str = "<http://purl.org/HDT/hdt#HDTv1>";
+
+const HEADER_FORMAT: &str = "ntriples";
+
+const TRIPLES_FORMAT_BITMAP: &str = "<http://purl.org/HDT/hdt#triplesBitmap>";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod dictionary;
+
+mod encoding;
+
+mod reader;
+pub use reader::{HdtFile, HdtReader, HdtStatements};
+
+mod writer;
+pub use writer::{HdtWriter, HdtWriterOptions};
@@ -0,0 +1,400 @@
+use super::dictionary::{
+    object_to_string, string_to_object, string_to_subject, subject_to_string, Dictionary,
+    DICTIONARY_FORMAT_FOUR,
+};
+use super::encoding::{
+    invalid_data, Bitmap, ControlInfo, Sequence, Source, CONTROL_TYPE_DICTIONARY,
+    CONTROL_TYPE_GLOBAL, CONTROL_TYPE_HEADER, CONTROL_TYPE_TRIPLES,
+};
+use super::{GLOBAL_FORMAT, HEADER_FORMAT, TRIPLES_FORMAT_BITMAP};
+use crate::common::relabel::Relabeler;
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::Iri;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the GraphReader trait that reads an entire HDT file into memory; to
+/// query a large file without loading it use [`HdtFile`] instead.
+///
+#[derive(Clone, Debug, Default)]
+pub struct HdtReader {
+    options: ReaderOptions,
+}
+
+///
+/// An HDT file opened for querying in place. Only the control information, section headers,
+/// and a small index for each triples bitmap are read when the file is opened, dictionary
+/// blocks and triples are read from the underlying source as they are needed.
+///
+#[derive(Debug)]
+pub struct HdtFile<R> {
+    source: Source<R>,
+    dictionary: Dictionary,
+    triples: Triples,
+}
+
+///
+/// An iterator over the statements in an [`HdtFile`] that match a pattern, returned by
+/// [`HdtFile::matches`].
+///
+#[derive(Debug)]
+pub struct HdtStatements<'a, R> {
+    file: &'a HdtFile<R>,
+    predicate: Option<u64>,
+    object: Option<u64>,
+    subject: u64,
+    pair: u64,
+    pair_end: u64,
+    pair_predicate: u64,
+    last_of_subject: bool,
+    position: u64,
+    in_pair: bool,
+    skip_pair: bool,
+    done: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Triples {
+    bitmap_y: Bitmap,
+    bitmap_z: Bitmap,
+    sequence_y: Sequence,
+    sequence_z: Sequence,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(HdtReader, ReaderOptions);
+
+impl ObjectReader<Graph> for HdtReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Error>
+    where
+        R: Read,
+    {
+        let mut buffer = Vec::default();
+        let _ = r.read_to_end(&mut buffer)?;
+        let file = HdtFile::new(Cursor::new(buffer))?;
+        let mut relabel = match self.options.blank_nodes() {
+            BlankNodeLabels::Preserve => None,
+            BlankNodeLabels::Relabel => Some(Relabeler::default()),
+        };
+        let mut graph = Graph::default();
+        for statement in file.statements()? {
+            let statement = statement?;
+            graph.insert(match &mut relabel {
+                Some(relabel) => relabel.statement(&statement),
+                None => statement,
+            });
+        }
+        Ok(graph)
+    }
+}
+
+impl HdtReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl HdtFile<BufReader<File>> {
+    ///
+    /// Open the HDT file at `path` for querying.
+    ///
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R> HdtFile<R>
+where
+    R: Read + Seek,
+{
+    ///
+    /// Read the structure of an HDT file from `r`, which must be positioned at the start of
+    /// the file.
+    ///
+    pub fn new(r: R) -> Result<Self, Error> {
+        let mut r = r;
+        let global = ControlInfo::read(&mut r, CONTROL_TYPE_GLOBAL)?;
+        expect_format(&global, GLOBAL_FORMAT)?;
+
+        let header = ControlInfo::read(&mut r, CONTROL_TYPE_HEADER)?;
+        expect_format(&header, HEADER_FORMAT)?;
+        let length: i64 = header
+            .property("length")
+            .and_then(|length| length.parse().ok())
+            .ok_or_else(|| invalid_data("header has no length"))?;
+        let _ = r.seek(SeekFrom::Current(length))?;
+
+        let dictionary = ControlInfo::read(&mut r, CONTROL_TYPE_DICTIONARY)?;
+        expect_format(&dictionary, DICTIONARY_FORMAT_FOUR)?;
+        let dictionary = Dictionary::open(&mut r)?;
+
+        let triples = ControlInfo::read(&mut r, CONTROL_TYPE_TRIPLES)?;
+        expect_format(&triples, TRIPLES_FORMAT_BITMAP)?;
+        if !matches!(triples.property("order"), None | Some("1")) {
+            return Err(invalid_data(
+                "only triples in subject-predicate-object order are supported",
+            ));
+        }
+        let triples = Triples {
+            bitmap_y: Bitmap::open(&mut r)?,
+            bitmap_z: Bitmap::open(&mut r)?,
+            sequence_y: Sequence::open(&mut r)?,
+            sequence_z: Sequence::open(&mut r)?,
+        };
+        if triples.bitmap_y.len() != triples.sequence_y.len()
+            || triples.bitmap_z.len() != triples.sequence_z.len()
+        {
+            return Err(invalid_data(
+                "triples bitmaps and sequences differ in length",
+            ));
+        }
+
+        Ok(Self {
+            source: Source::new(r),
+            dictionary,
+            triples,
+        })
+    }
+
+    ///
+    /// Returns the number of triples in the file.
+    ///
+    pub fn len(&self) -> usize {
+        self.triples.sequence_z.len() as usize
+    }
+
+    ///
+    /// Returns `true` if the file contains no triples, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Return an iterator over all the statements in the file, in subject, predicate, object
+    /// order.
+    ///
+    pub fn statements(&self) -> Result<HdtStatements<'_, R>, Error> {
+        self.matches(None, None, None)
+    }
+
+    ///
+    /// Return an iterator over the statements that match the pattern, where `None` matches any
+    /// node. Patterns with a subject only read the triples of that subject, any other pattern
+    /// requires a scan of all the triples in the file, although terms are only read from the
+    /// dictionary for matching statements. A pattern that uses a term not in the file returns
+    /// an empty iterator.
+    ///
+    pub fn matches(
+        &self,
+        subject: Option<&SubjectNode>,
+        predicate: Option<&Iri>,
+        object: Option<&ObjectNode>,
+    ) -> Result<HdtStatements<'_, R>, Error> {
+        let mut statements = HdtStatements {
+            file: self,
+            predicate: None,
+            object: None,
+            subject: 1,
+            pair: 0,
+            pair_end: self.triples.sequence_y.len(),
+            pair_predicate: 0,
+            last_of_subject: false,
+            position: 0,
+            in_pair: false,
+            skip_pair: false,
+            done: false,
+        };
+        if let Some(predicate) = predicate {
+            match self
+                .dictionary
+                .predicate_id(&self.source, &format!("{predicate:#}"))?
+            {
+                Some(id) => statements.predicate = Some(id),
+                None => return Ok(statements.empty()),
+            }
+        }
+        if let Some(object) = object {
+            if matches!(object, ObjectNode::Collection(_) | ObjectNode::Statement(_)) {
+                return Ok(statements.empty());
+            }
+            match self
+                .dictionary
+                .object_id(&self.source, &object_to_string(object)?)?
+            {
+                Some(id) => statements.object = Some(id),
+                None => return Ok(statements.empty()),
+            }
+        }
+        if let Some(subject) = subject {
+            if subject.is_statement() {
+                return Ok(statements.empty());
+            }
+            let id = match self
+                .dictionary
+                .subject_id(&self.source, &subject_to_string(subject)?)?
+            {
+                Some(id) => id,
+                None => return Ok(statements.empty()),
+            };
+            let bitmap_y = &self.triples.bitmap_y;
+            let Some(last) = bitmap_y.select1(&self.source, id)? else {
+                return Ok(statements.empty());
+            };
+            statements.subject = id;
+            statements.pair = match id {
+                1 => 0,
+                _ => {
+                    bitmap_y
+                        .select1(&self.source, id - 1)?
+                        .ok_or_else(|| invalid_data("subject index is inconsistent"))?
+                        + 1
+                }
+            };
+            statements.pair_end = last + 1;
+            statements.position = match statements.pair {
+                0 => 0,
+                pair => {
+                    self.triples
+                        .bitmap_z
+                        .select1(&self.source, pair)?
+                        .ok_or_else(|| invalid_data("predicate index is inconsistent"))?
+                        + 1
+                }
+            };
+        }
+        Ok(statements)
+    }
+
+    fn statement(&self, subject: u64, predicate: u64, object: u64) -> Result<Statement, Error> {
+        Ok(Statement::new(
+            string_to_subject(&self.dictionary.subject(&self.source, subject)?)?,
+            Iri::from_str(&self.dictionary.predicate(&self.source, predicate)?)?,
+            string_to_object(&self.dictionary.object(&self.source, object)?)?,
+        ))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<R> Iterator for HdtStatements<'_, R>
+where
+    R: Read + Seek,
+{
+    type Item = Result<Statement, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_triple() {
+            Ok(Some((subject, predicate, object))) => {
+                let statement = self.file.statement(subject, predicate, object);
+                self.done = statement.is_err();
+                Some(statement)
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<R> HdtStatements<'_, R>
+where
+    R: Read + Seek,
+{
+    fn empty(self) -> Self {
+        let mut self_mut = self;
+        self_mut.done = true;
+        self_mut
+    }
+
+    ///
+    /// Walk the adjacency lists from the current position, `pair` is the next entry in `Y`
+    /// and `position` the next entry in `Z`; the subject is incremented whenever the previous
+    /// pair was the last for its subject.
+    ///
+    fn next_triple(&mut self) -> Result<Option<(u64, u64, u64)>, Error> {
+        let source = &self.file.source;
+        let triples = &self.file.triples;
+        loop {
+            if self.in_pair {
+                let position = self.position;
+                self.position += 1;
+                if triples.bitmap_z.get(source, position)? {
+                    self.in_pair = false;
+                }
+                if self.skip_pair {
+                    continue;
+                }
+                let object = triples.sequence_z.get(source, position)?;
+                if self.object.is_none_or(|expected| expected == object) {
+                    return Ok(Some((self.subject, self.pair_predicate, object)));
+                }
+            } else {
+                if self.pair >= self.pair_end {
+                    return Ok(None);
+                }
+                if self.last_of_subject {
+                    self.subject += 1;
+                }
+                self.last_of_subject = triples.bitmap_y.get(source, self.pair)?;
+                self.pair_predicate = triples.sequence_y.get(source, self.pair)?;
+                self.skip_pair = self
+                    .predicate
+                    .is_some_and(|expected| expected != self.pair_predicate);
+                self.pair += 1;
+                self.in_pair = true;
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn expect_format(control: &ControlInfo, format: &str) -> Result<(), Error> {
+    if control.format() == format {
+        Ok(())
+    } else {
+        Err(invalid_data(format!(
+            "unsupported format {:?} for control information of type {}",
+            control.format(),
+            control.control_type()
+        )))
+    }
+}
@@ -0,0 +1,282 @@
+use super::dictionary::{
+    object_to_string, subject_to_string, write_section, DICTIONARY_FORMAT_FOUR,
+};
+use super::encoding::{
+    write_bitmap, write_sequence, ControlInfo, CONTROL_TYPE_DICTIONARY, CONTROL_TYPE_GLOBAL,
+    CONTROL_TYPE_HEADER, CONTROL_TYPE_TRIPLES,
+};
+use super::{GLOBAL_FORMAT, HEADER_FORMAT, TRIPLES_FORMAT_BITMAP};
+use crate::common::simplify::simplify_statement;
+use crate::GraphWriter;
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::Graph;
+use rdftk_iri::Iri;
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HdtWriterOptions {
+    base: Option<Iri>,
+    block_size: usize,
+}
+
+///
+/// This struct implements the `ObjectWriter` trait for graphs and will write out an HDT file
+/// using the _Four Section_ dictionary and _Bitmap Triples_ in subject, predicate, object order.
+///
+#[derive(Debug, Default)]
+pub struct HdtWriter {
+    options: HdtWriterOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const DEFAULT_BLOCK_SIZE: usize = 16;
+
+const DEFAULT_DATASET: &str = "_:dataset";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for HdtWriterOptions {
+    fn default() -> Self {
+        Self {
+            base: None,
+            block_size: DEFAULT_BLOCK_SIZE,
+        }
+    }
+}
+
+impl HdtWriterOptions {
+    ///
+    /// The IRI used to identify the dataset in the HDT header, if not set a blank node is
+    /// used.
+    ///
+    pub fn with_base(self, base: Iri) -> Self {
+        let mut self_mut = self;
+        self_mut.base = Some(base);
+        self_mut
+    }
+
+    ///
+    /// The number of strings in each front-coded block of the dictionary; larger blocks make
+    /// for a smaller file but slower lookups.
+    ///
+    pub fn with_block_size(self, block_size: usize) -> Self {
+        let mut self_mut = self;
+        self_mut.set_block_size(block_size);
+        self_mut
+    }
+
+    pub fn base(&self) -> Option<&Iri> {
+        self.base.as_ref()
+    }
+
+    pub fn set_base(&mut self, base: Iri) {
+        self.base = Some(base);
+    }
+
+    pub fn unset_base(&mut self) {
+        self.base = None;
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    pub fn set_block_size(&mut self, block_size: usize) {
+        self.block_size = block_size.max(1);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(HdtWriter, HdtWriterOptions);
+
+impl ObjectWriter<Graph> for HdtWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut terms = Vec::with_capacity(graph.len());
+        for statement in graph.statements() {
+            for statement in simplify_statement(statement)? {
+                terms.push((
+                    subject_to_string(statement.subject())?,
+                    format!("{:#}", statement.predicate()),
+                    object_to_string(statement.object())?,
+                ));
+            }
+        }
+
+        let subjects: BTreeSet<&String> = terms.iter().map(|(s, _, _)| s).collect();
+        let predicates: BTreeSet<&String> = terms.iter().map(|(_, p, _)| p).collect();
+        let objects: BTreeSet<&String> = terms.iter().map(|(_, _, o)| o).collect();
+        let shared: Vec<&String> = subjects.intersection(&objects).copied().collect();
+        let subjects: Vec<&String> = subjects.difference(&objects).copied().collect();
+        let objects: Vec<&String> = objects
+            .into_iter()
+            .filter(|object| shared.binary_search(object).is_err())
+            .collect();
+        let predicates: Vec<&String> = predicates.into_iter().collect();
+
+        let shared_ids = identifiers(&shared, 0);
+        let subject_ids = identifiers(&subjects, shared.len());
+        let predicate_ids = identifiers(&predicates, 0);
+        let object_ids = identifiers(&objects, shared.len());
+        let mut triples: Vec<(u64, u64, u64)> = terms
+            .iter()
+            .map(|(s, p, o)| {
+                (
+                    shared_ids
+                        .get(s)
+                        .or_else(|| subject_ids.get(s))
+                        .copied()
+                        .unwrap(),
+                    predicate_ids[p],
+                    shared_ids
+                        .get(o)
+                        .or_else(|| object_ids.get(o))
+                        .copied()
+                        .unwrap(),
+                )
+            })
+            .collect();
+        triples.sort_unstable();
+        triples.dedup();
+
+        ControlInfo::new(CONTROL_TYPE_GLOBAL, GLOBAL_FORMAT).write(w)?;
+
+        let header = self.header(
+            triples.len(),
+            predicates.len(),
+            shared.len() + subjects.len(),
+            shared.len() + objects.len(),
+        );
+        ControlInfo::new(CONTROL_TYPE_HEADER, HEADER_FORMAT)
+            .with_property("length", header.len())
+            .write(w)?;
+        w.write_all(header.as_bytes())?;
+
+        let size_strings: usize = shared
+            .iter()
+            .chain(&subjects)
+            .chain(&predicates)
+            .chain(&objects)
+            .map(|string| string.len())
+            .sum();
+        ControlInfo::new(CONTROL_TYPE_DICTIONARY, DICTIONARY_FORMAT_FOUR)
+            .with_property("mapping", 1)
+            .with_property("sizeStrings", size_strings)
+            .write(w)?;
+        let block_size = self.options.block_size;
+        write_section(w, &shared, block_size)?;
+        write_section(w, &subjects, block_size)?;
+        write_section(w, &predicates, block_size)?;
+        write_section(w, &objects, block_size)?;
+
+        ControlInfo::new(CONTROL_TYPE_TRIPLES, TRIPLES_FORMAT_BITMAP)
+            .with_property("order", 1)
+            .with_property("numTriples", triples.len())
+            .write(w)?;
+        self.write_triples(w, &triples)?;
+
+        Ok(w.flush()?)
+    }
+}
+
+impl GraphWriter for HdtWriter {}
+
+impl HdtWriter {
+    pub fn with_options(self, options: HdtWriterOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// The header is a small N-Triples document with statistics that describe the dataset.
+    ///
+    fn header(&self, triples: usize, properties: usize, subjects: usize, objects: usize) -> String {
+        let dataset = match &self.options.base {
+            Some(base) => base.to_string(),
+            None => DEFAULT_DATASET.to_string(),
+        };
+        [
+            (
+                "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>",
+                "<http://purl.org/HDT/hdt#Dataset>".to_string(),
+            ),
+            (
+                "<http://rdfs.org/ns/void#triples>",
+                format!("\"{triples}\""),
+            ),
+            (
+                "<http://rdfs.org/ns/void#properties>",
+                format!("\"{properties}\""),
+            ),
+            (
+                "<http://rdfs.org/ns/void#distinctSubjects>",
+                format!("\"{subjects}\""),
+            ),
+            (
+                "<http://rdfs.org/ns/void#distinctObjects>",
+                format!("\"{objects}\""),
+            ),
+        ]
+        .iter()
+        .map(|(predicate, object)| format!("{dataset} {predicate} {object} .\n"))
+        .collect()
+    }
+
+    ///
+    /// Write the sorted triples as two levels of adjacency lists; `Y` holds the predicates of
+    /// each subject and `Z` the objects of each subject/predicate pair, with each bitmap marking
+    /// the last entry of its list.
+    ///
+    fn write_triples<W>(&self, w: &mut W, triples: &[(u64, u64, u64)]) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        let mut sequence_y = Vec::default();
+        let mut bitmap_y = Vec::default();
+        let mut sequence_z = Vec::with_capacity(triples.len());
+        let mut bitmap_z = Vec::with_capacity(triples.len());
+        for (index, (subject, predicate, object)) in triples.iter().enumerate() {
+            let next = triples.get(index + 1);
+            if index == 0 || triples[index - 1].0 != *subject || triples[index - 1].1 != *predicate
+            {
+                sequence_y.push(*predicate);
+                bitmap_y.push(next.is_none_or(|next| next.0 != *subject));
+            }
+            sequence_z.push(*object);
+            bitmap_z.push(next.is_none_or(|next| next.0 != *subject || next.1 != *predicate));
+        }
+        write_bitmap(w, &bitmap_y)?;
+        write_bitmap(w, &bitmap_z)?;
+        write_sequence(w, &sequence_y)?;
+        write_sequence(w, &sequence_z)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn identifiers<'a>(strings: &[&'a String], offset: usize) -> HashMap<&'a String, u64> {
+    strings
+        .iter()
+        .enumerate()
+        .map(|(index, string)| (*string, (offset + index + 1) as u64))
+        .collect()
+}
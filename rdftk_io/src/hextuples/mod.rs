@@ -0,0 +1,65 @@
+/*!
+Provides for reading and writing a `Graph` or `DataSet` instance in the
+[HexTuples](https://github.com/ontola/hextuples) format, a newline-delimited JSON (NDJSON)
+serialization.
+
+Each line is a JSON array of six strings: the subject, predicate, value, datatype, language,
+and graph name of a single statement. The datatype is `"globalId"` for an IRI value and
+`"localId"` for a blank node, and the graph name is empty for the default graph. As each line
+stands alone both the reader and the writer work in a streaming fashion. Nested statements and
+collections are written in their reified form.
+
+# Example
+
+```rust
+use rdftk_io::hextuples::{HexTuplesReader, HexTuplesWriter};
+# use objio::{ObjectReader, ObjectWriter};
+# use rdftk_core::model::data_set::DataSet;
+# fn make_data_set() -> DataSet { DataSet::default() }
+
+let ndjson = HexTuplesWriter::default().write_to_string(&make_data_set()).unwrap();
+
+let data_set: DataSet = HexTuplesReader::default().read_from_string(&ndjson).unwrap();
+```
+
+# Example Streaming Reader
+
+```rust
+use rdftk_io::hextuples::HexTuplesReader;
+
+let source = r#"["http://example.org/a", "http://example.org/b", "c", "http://www.w3.org/2001/XMLSchema#string", "", ""]
+["_:d", "http://example.org/e", "http://example.org/f", "globalId", "", "http://example.org/g"]
+"#;
+
+for quad in HexTuplesReader::default().quads(source.as_bytes()) {
+    let (statement, graph_name) = quad.unwrap();
+    println!("{statement} {graph_name:?}");
+}
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "HexTuples";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "hext";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/hex+x-ndjson";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod reader;
+pub use reader::{HexTuplesReader, HexTuplesStatements};
+
+mod syntax;
+
+mod writer;
+pub use writer::{HexTuplesSink, HexTuplesWriter};
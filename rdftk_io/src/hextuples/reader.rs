@@ -0,0 +1,275 @@
+use super::syntax::{BNODE_PREFIX, TUPLE_LENGTH, TYPE_GLOBAL_ID, TYPE_LOCAL_ID};
+use super::NAME;
+use crate::common::json::parse_json_value;
+use crate::common::relabel::Relabeler;
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::{syntax_error, Error, SourceLocation};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::Iri;
+use rdftk_names::{rdf, xsd};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+use tracing::warn;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the `ObjectReader` trait for graphs and data sets in the HexTuples
+/// representation. A lenient reader skips, with a warning, any line that is not a valid tuple.
+///
+#[derive(Debug, Default)]
+pub struct HexTuplesReader {
+    options: ReaderOptions,
+}
+
+///
+/// An iterator over the quads in a HexTuples source, returned by [`HexTuplesReader::quads`].
+/// The source is read one line at a time so that only the current line is held in memory.
+///
+#[derive(Debug)]
+pub struct HexTuplesStatements<R> {
+    source: R,
+    line: String,
+    lines_read: usize,
+    bytes_read: usize,
+    relabel: Option<Relabeler>,
+    done: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(HexTuplesReader, ReaderOptions);
+
+impl ObjectReader<Graph> for HexTuplesReader {
+    type Error = Error;
+
+    ///
+    /// Read a single graph, statements may be in the default graph or in a single named graph,
+    /// a source with more than one graph must be read as a data set.
+    ///
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Error>
+    where
+        R: Read,
+    {
+        let mut graph = Graph::default();
+        for quad in self.quads(BufReader::new(r)) {
+            let Some((statement, graph_name)) = self.skip_if_lenient(quad)? else {
+                continue;
+            };
+            if graph.name() != graph_name.as_ref() {
+                match graph_name {
+                    Some(graph_name) if graph.is_empty() && !graph.is_named() => {
+                        graph.set_name(graph_name)
+                    }
+                    _ => {
+                        return Err(Error::Io(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!(
+                                "{NAME}: the source contains more than one graph, read it as a data set"
+                            ),
+                        )))
+                    }
+                }
+            }
+            graph.insert(statement);
+        }
+        Ok(graph)
+    }
+}
+
+impl ObjectReader<DataSet> for HexTuplesReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<DataSet, Error>
+    where
+        R: Read,
+    {
+        let mut data_set = DataSet::default();
+        for quad in self.quads(BufReader::new(r)) {
+            let Some((statement, graph_name)) = self.skip_if_lenient(quad)? else {
+                continue;
+            };
+            if data_set.graph(&graph_name).is_none() {
+                data_set.insert(match &graph_name {
+                    Some(name) => Graph::named(name.clone()),
+                    None => Graph::default(),
+                });
+            }
+            data_set.graph_mut(&graph_name).unwrap().insert(statement);
+        }
+        Ok(data_set)
+    }
+}
+
+impl HexTuplesReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Return an iterator that reads quads from `source` as they are requested, rather than
+    /// reading the whole document into memory.
+    ///
+    pub fn quads<R>(&self, source: R) -> HexTuplesStatements<R>
+    where
+        R: BufRead,
+    {
+        HexTuplesStatements {
+            source,
+            line: String::new(),
+            lines_read: 0,
+            bytes_read: 0,
+            relabel: match self.options.blank_nodes() {
+                BlankNodeLabels::Preserve => None,
+                BlankNodeLabels::Relabel => Some(Relabeler::default()),
+            },
+            done: false,
+        }
+    }
+
+    fn skip_if_lenient<T>(&self, result: Result<T, Error>) -> Result<Option<T>, Error> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if self.options.is_lenient() && !matches!(e, Error::Io(_)) => {
+                warn!("HexTuplesReader skipped line: {e}");
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<R> Iterator for HexTuplesStatements<R>
+where
+    R: BufRead,
+{
+    type Item = Result<(Statement, Option<GraphName>), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.source.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(length) => {
+                    let (lines, bytes) = (self.lines_read, self.bytes_read);
+                    self.lines_read += 1;
+                    self.bytes_read += length;
+                    if self.line.trim().is_empty() {
+                        continue;
+                    }
+                    return Some(match parse_tuple(self.line.trim_end()) {
+                        Ok((statement, graph_name)) => Ok(match &mut self.relabel {
+                            Some(relabel) => (
+                                relabel.statement(&statement),
+                                graph_name.map(|name| relabel.graph_name(name)),
+                            ),
+                            None => (statement, graph_name),
+                        }),
+                        Err(e) => Err(relocate_error(e, &self.line, lines, bytes)),
+                    });
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+        None
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn parse_tuple(line: &str) -> Result<(Statement, Option<GraphName>), Error> {
+    let tuple = match parse_json_value(NAME, line)? {
+        Value::Array(values) if values.len() == TUPLE_LENGTH => values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(value) => Ok(value),
+                _ => Err(tuple_error(line, "each member of a tuple must be a string")),
+            })
+            .collect::<Result<Vec<String>, Error>>()?,
+        _ => {
+            return Err(tuple_error(
+                line,
+                format!("expected an array of {TUPLE_LENGTH} strings"),
+            ))
+        }
+    };
+    let (subject, predicate, value, data_type, language, graph) = (
+        &tuple[0], &tuple[1], &tuple[2], &tuple[3], &tuple[4], &tuple[5],
+    );
+
+    let subject: SubjectNode = match subject.strip_prefix(BNODE_PREFIX) {
+        Some(label) => BlankNode::from_str(label)?.into(),
+        None => Iri::from_str(subject)?.into(),
+    };
+    let predicate = Iri::from_str(predicate)?;
+    let object: ObjectNode = if data_type == TYPE_GLOBAL_ID {
+        Iri::from_str(value)?.into()
+    } else if data_type == TYPE_LOCAL_ID {
+        BlankNode::from_str(value.strip_prefix(BNODE_PREFIX).unwrap_or(value))?.into()
+    } else if !language.is_empty() {
+        Literal::with_language(value, LanguageTag::from_str(language)?).into()
+    } else if data_type.is_empty() {
+        Literal::plain(value).into()
+    } else {
+        let data_type = Iri::from_str(data_type)?;
+        if &data_type == xsd::string() {
+            Literal::plain(value).into()
+        } else if &data_type == rdf::lang_string() {
+            return Err(tuple_error(
+                line,
+                "a rdf:langString literal requires a language",
+            ));
+        } else {
+            Literal::with_data_type(value, DataType::from(data_type)).into()
+        }
+    };
+    let graph_name = if graph.is_empty() {
+        None
+    } else {
+        Some(match graph.strip_prefix(BNODE_PREFIX) {
+            Some(label) => GraphName::BNode(BlankNode::from_str(label)?),
+            None => GraphName::Iri(Iri::from_str(graph)?),
+        })
+    };
+    Ok((Statement::new(subject, predicate, object), graph_name))
+}
+
+fn tuple_error<S>(line: &str, message: S) -> Error
+where
+    S: Into<String>,
+{
+    syntax_error(NAME, message, SourceLocation::from_offset(line, 0))
+}
+
+///
+/// Move the location of a syntax error in `line` to its place in the whole source; errors
+/// without a location are given the start of the line.
+///
+fn relocate_error(error: Error, line: &str, lines: usize, bytes: usize) -> Error {
+    match error {
+        Error::Syntax {
+            representation,
+            message,
+            location,
+        } => syntax_error(representation, message, location.offset_by(lines, bytes)),
+        error => error.with_location(NAME, SourceLocation::new(lines + 1, 1, bytes, line)),
+    }
+}
@@ -0,0 +1,9 @@
+/// The number of strings in each tuple: subject, predicate, value, datatype, language, graph.
+pub(super) const TUPLE_LENGTH: usize = 6;
+
+/// The datatype position of a tuple whose value is an IRI.
+pub(super) const TYPE_GLOBAL_ID: &str = "globalId";
+/// The datatype position of a tuple whose value is a blank node.
+pub(super) const TYPE_LOCAL_ID: &str = "localId";
+
+pub(super) const BNODE_PREFIX: &str = "_:";
@@ -0,0 +1,187 @@
+use super::syntax::{BNODE_PREFIX, TYPE_GLOBAL_ID, TYPE_LOCAL_ID};
+use crate::common::literal::unescape_lexical_form;
+use crate::common::simplify::simplify_statement;
+use crate::{DataSetWriter, GraphWriter, StatementSink};
+use objio::ObjectWriter;
+use rdftk_core::error::{rdf_star_not_supported_error, Error};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, Namespace};
+use rdftk_names::{rdf, xsd};
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// This struct implements the `ObjectWriter` trait for graphs and data sets, and will write
+/// out one JSON array per line for each statement.
+///
+#[derive(Debug, Default)]
+pub struct HexTuplesWriter {}
+
+///
+/// An implementation of [`StatementSink`], returned by [`HexTuplesWriter::sink`], which writes
+/// each statement as it is received. Statements passed to `statement` are written to the
+/// default graph, use `quad` to write a statement to a named graph.
+///
+#[derive(Debug)]
+pub struct HexTuplesSink<W> {
+    writer: W,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ObjectWriter<Graph> for HexTuplesWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut sink = self.sink(w);
+        sink.start()?;
+        for statement in graph.statements() {
+            sink.quad(statement, graph.name())?;
+        }
+        sink.finish()
+    }
+}
+
+impl GraphWriter for HexTuplesWriter {}
+
+impl ObjectWriter<DataSet> for HexTuplesWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, data_set: &DataSet) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut sink = self.sink(w);
+        sink.start()?;
+        for graph in data_set.graphs() {
+            for statement in graph.statements() {
+                sink.quad(statement, graph.name())?;
+            }
+        }
+        sink.finish()
+    }
+}
+
+impl DataSetWriter for HexTuplesWriter {}
+
+impl HexTuplesWriter {
+    ///
+    /// Return a [`StatementSink`] that writes statements to `w` as they are received.
+    ///
+    pub fn sink<W>(&self, w: W) -> HexTuplesSink<W>
+    where
+        W: Write,
+    {
+        HexTuplesSink { writer: w }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<W> StatementSink for HexTuplesSink<W>
+where
+    W: Write,
+{
+    fn start(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn prefix(&mut self, _: &Namespace, _: &Iri) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        self.quad(statement, None)
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+impl<W> HexTuplesSink<W>
+where
+    W: Write,
+{
+    ///
+    /// Write a single statement to the graph named `graph_name`, or to the default graph if
+    /// `graph_name` is `None`.
+    ///
+    pub fn quad(
+        &mut self,
+        statement: &Statement,
+        graph_name: Option<&GraphName>,
+    ) -> Result<(), Error> {
+        let graph_name = match graph_name {
+            Some(GraphName::Iri(iri)) => format!("{iri:#}"),
+            Some(GraphName::BNode(blank)) => format!("{BNODE_PREFIX}{blank}"),
+            None => String::new(),
+        };
+        for statement in simplify_statement(statement)? {
+            let (value, data_type, language) = object_strings(statement.object())?;
+            let tuple = [
+                subject_string(statement.subject())?,
+                format!("{:#}", statement.predicate()),
+                value,
+                data_type,
+                language,
+                graph_name.clone(),
+            ];
+            serde_json::to_writer(&mut self.writer, &tuple).map_err(|e| Error::Io(e.into()))?;
+            self.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn subject_string(subject: &SubjectNode) -> Result<String, Error> {
+    if let Some(iri) = subject.as_resource() {
+        Ok(format!("{iri:#}"))
+    } else if let Some(blank) = subject.as_blank() {
+        Ok(format!("{BNODE_PREFIX}{blank}"))
+    } else {
+        rdf_star_not_supported_error(super::NAME).into()
+    }
+}
+
+///
+/// Return the value, datatype, and language positions of a tuple for `object`.
+///
+fn object_strings(object: &ObjectNode) -> Result<(String, String, String), Error> {
+    if let Some(iri) = object.as_resource() {
+        Ok((format!("{iri:#}"), TYPE_GLOBAL_ID.into(), String::new()))
+    } else if let Some(blank) = object.as_blank() {
+        Ok((
+            format!("{BNODE_PREFIX}{blank}"),
+            TYPE_LOCAL_ID.into(),
+            String::new(),
+        ))
+    } else if let Some(literal) = object.as_literal() {
+        let value = unescape_lexical_form(literal.lexical_form());
+        Ok(match (literal.data_type(), literal.language()) {
+            (Some(data_type), _) => (value, format!("{:#}", data_type.as_iri()), String::new()),
+            (None, Some(language)) => (
+                value,
+                format!("{:#}", rdf::lang_string()),
+                language.to_string(),
+            ),
+            (None, None) => (value, format!("{:#}", xsd::string()), String::new()),
+        })
+    } else {
+        rdf_star_not_supported_error(super::NAME).into()
+    }
+}
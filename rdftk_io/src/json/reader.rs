@@ -1,18 +1,20 @@
 use super::NAME;
+use crate::common::json::{json_pointer, member_location, parse_json_value};
+use crate::common::relabel::Relabeler;
 use crate::json::syntax::{
     BNODE_PREFIX, OBJ_KEY_DATATYPE, OBJ_KEY_LANG, OBJ_KEY_TYPE, OBJ_KEY_VALUE, OBJ_TYPE_BNODE,
     OBJ_TYPE_LITERAL, OBJ_TYPE_URI,
 };
-use objio::ObjectReader;
-use rdftk_core::error::Error;
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::{syntax_error, Error};
 use rdftk_core::model::graph::Graph;
 use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
-use rdftk_core::model::statement::{BlankNode, Statement, SubjectNode};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
 use rdftk_iri::Iri;
 use serde_json::{Map, Value};
 use std::io::Read;
 use std::str::FromStr;
-use tracing::error;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -22,12 +24,16 @@ use tracing::error;
 /// An implementation of the GraphReader trait to read resources in the JSON representation.
 ///
 #[derive(Debug, Default)]
-pub struct JsonReader {}
+pub struct JsonReader {
+    options: ReaderOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_has_options!(JsonReader, ReaderOptions);
+
 impl ObjectReader<Graph> for JsonReader {
     type Error = Error;
 
@@ -35,8 +41,28 @@ impl ObjectReader<Graph> for JsonReader {
     where
         R: Read,
     {
-        let value: Value = serde_json::from_reader(r).map_err(json_error)?;
-        parse_graph(value)
+        let mut source = String::new();
+        let _ = r.read_to_string(&mut source)?;
+        let value = parse_json_value(NAME, &source)?;
+        let graph = parse_graph(&source, &value)?;
+        match self.options.blank_nodes() {
+            BlankNodeLabels::Preserve => Ok(graph),
+            BlankNodeLabels::Relabel => {
+                let mut relabel = Relabeler::default();
+                Ok(graph
+                    .statements()
+                    .map(|statement| relabel.statement(statement))
+                    .collect())
+            }
+        }
+    }
+}
+
+impl JsonReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
     }
 }
 
@@ -46,229 +72,272 @@ impl ObjectReader<Graph> for JsonReader {
 
 macro_rules! parse_rule {
     ($rule_fn:literal entry) => {
-        const RULE_FN: &'static str = $rule_fn;
         ::tracing::trace!("{}(...)", $rule_fn);
     };
 }
 
-fn json_error(err: serde_json::Error) -> Error {
-    Error::Tokenizer {
-        representation: NAME.into(),
-        source: Box::new(err),
-    }
+///
+/// Create a syntax error located at the value reached by following `path` from the root of
+/// the document, the path is also reported as a JSON Pointer.
+///
+fn member_error<S>(source: &str, path: &[&str], message: S) -> Error
+where
+    S: AsRef<str>,
+{
+    let message = if path.is_empty() {
+        format!("{} for the document", message.as_ref())
+    } else {
+        format!("{} for member `{}`", message.as_ref(), json_pointer(path))
+    };
+    syntax_error(NAME, message, member_location(source, path))
 }
 
-fn value_variant(value: &Value) -> String {
+fn value_variant(value: &Value) -> &'static str {
     match value {
-        Value::Null => "Null",
-        Value::Bool(_) => "Bool",
-        Value::Number(_) => "Number",
-        Value::String(_) => "String",
-        Value::Array(_) => "Array",
-        Value::Object(_) => "Object",
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
     }
-    .to_string()
 }
 
-fn parse_graph(value: Value) -> Result<Graph, Error> {
+fn parse_graph(source: &str, value: &Value) -> Result<Graph, Error> {
     parse_rule!("parse_graph" entry);
 
     if let Value::Object(json) = value {
         let mut graph = Graph::default();
         for (subject, predicate_objects) in json.iter() {
-            parse_statements(subject, predicate_objects, &mut graph)?;
+            parse_statements(source, subject, predicate_objects, &mut graph)?;
         }
         Ok(graph)
     } else {
-        error!("rule {RULE_FN} expecting `Object` variant");
-        Err(Error::ParserUnexpected {
-            rule_fn: RULE_FN.into(),
-            given: value_variant(&value),
-            expecting: vec!["Object".into()],
-        })
+        Err(member_error(
+            source,
+            &[],
+            format!("expecting an object, found {}", value_variant(value)),
+        ))
     }
 }
 
 fn parse_statements(
+    source: &str,
     subject: &str,
     predicate_objects: &Value,
     graph: &mut Graph,
 ) -> Result<(), Error> {
     parse_rule!("parse_statements" entry);
 
+    let path = [subject];
     if let Value::Object(json) = predicate_objects {
         let subject = if let Some(subject) = subject.strip_prefix(BNODE_PREFIX) {
-            BlankNode::from_str(subject)?.into()
+            BlankNode::from_str(subject).map(SubjectNode::from)
         } else {
-            Iri::from_str(subject)?.into()
-        };
+            Iri::from_str(subject)
+                .map(SubjectNode::from)
+                .map_err(Error::from)
+        }
+        .map_err(|e| member_error(source, &path, format!("invalid subject, {e}")))?;
         for (predicate, objects) in json.iter() {
-            parse_predicates(&subject, predicate, objects, graph)?;
+            parse_predicates(source, &subject, &path, predicate, objects, graph)?;
         }
         Ok(())
     } else {
-        error!("rule {RULE_FN} expecting `Object` variant");
-        Err(Error::ParserUnexpected {
-            rule_fn: "parse_statements".into(),
-            given: value_variant(predicate_objects),
-            expecting: vec!["Object".into()],
-        })
+        Err(member_error(
+            source,
+            &path,
+            format!(
+                "expecting an object of predicates, found {}",
+                value_variant(predicate_objects)
+            ),
+        ))
     }
 }
 
 fn parse_predicates(
+    source: &str,
     subject: &SubjectNode,
+    path: &[&str],
     predicate: &str,
     objects: &Value,
     graph: &mut Graph,
 ) -> Result<(), Error> {
     parse_rule!("parse_predicates" entry);
 
+    let path = [path, &[predicate]].concat();
     if let Value::Array(json) = objects {
-        let predicate = Iri::from_str(predicate)?;
-        for object in json {
-            parse_object(subject, &predicate, object, graph)?;
+        let predicate = Iri::from_str(predicate)
+            .map_err(|e| member_error(source, &path, format!("invalid predicate, {e}")))?;
+        for (index, object) in json.iter().enumerate() {
+            let index = index.to_string();
+            let path = [path.as_slice(), &[index.as_str()]].concat();
+            parse_object(source, subject, &predicate, &path, object, graph)?;
         }
         Ok(())
     } else {
-        error!("rule {RULE_FN} expecting `Array` variant");
-        Err(Error::ParserUnexpected {
-            rule_fn: RULE_FN.into(),
-            given: value_variant(objects),
-            expecting: vec!["Array".into()],
-        })
+        Err(member_error(
+            source,
+            &path,
+            format!(
+                "expecting an array of objects, found {}",
+                value_variant(objects)
+            ),
+        ))
     }
 }
 
 fn parse_object(
+    source: &str,
     subject: &SubjectNode,
     predicate: &Iri,
+    path: &[&str],
     object: &Value,
     graph: &mut Graph,
 ) -> Result<(), Error> {
     parse_rule!("parse_object" entry);
 
     if let Value::Object(json) = object {
-        match json.get(OBJ_KEY_TYPE) {
+        let object: ObjectNode = match json.get(OBJ_KEY_TYPE) {
+            Some(Value::String(s)) if s == OBJ_TYPE_LITERAL => {
+                parse_literal_object(source, path, json)?.into()
+            }
+            Some(Value::String(s)) if s == OBJ_TYPE_BNODE => {
+                parse_bnode_object(source, path, json)?.into()
+            }
+            Some(Value::String(s)) if s == OBJ_TYPE_URI => {
+                parse_uri_object(source, path, json)?.into()
+            }
             Some(Value::String(s)) => {
-                if s == OBJ_TYPE_LITERAL {
-                    parse_literal_object(subject, predicate, json, graph)
-                } else if s == OBJ_TYPE_BNODE {
-                    parse_bnode_object(subject, predicate, json, graph)
-                } else if s == OBJ_TYPE_URI {
-                    parse_uri_object(subject, predicate, json, graph)
-                } else {
-                    error!("parse_object() - unknown 'type' key value: {}", s);
-                    Err(Error::ParserUnexpected {
-                        rule_fn: RULE_FN.into(),
-                        given: s.into(),
-                        expecting: vec![
-                            OBJ_TYPE_LITERAL.into(),
-                            OBJ_TYPE_BNODE.into(),
-                            OBJ_TYPE_URI.into(),
-                        ],
-                    })
-                }
+                return Err(member_error(
+                    source,
+                    &[path, &[OBJ_KEY_TYPE]].concat(),
+                    format!(
+                        "unknown object type `{s}`, expecting one of `{OBJ_TYPE_LITERAL}`, \
+                         `{OBJ_TYPE_BNODE}` or `{OBJ_TYPE_URI}`"
+                    ),
+                ))
             }
-            _ => {
-                error!(
-                    "rule {RULE_FN} expecting object to have key {}",
-                    OBJ_KEY_TYPE
-                );
-                Err(Error::ParserExpected {
-                    rule_fn: RULE_FN.into(),
-                    expecting: OBJ_KEY_TYPE.into(),
-                })
+            Some(value) => {
+                return Err(member_error(
+                    source,
+                    &[path, &[OBJ_KEY_TYPE]].concat(),
+                    format!("expecting a string, found {}", value_variant(value)),
+                ))
             }
-        }
+            None => {
+                return Err(member_error(
+                    source,
+                    path,
+                    format!("missing key `{OBJ_KEY_TYPE}`"),
+                ))
+            }
+        };
+        graph.insert(Statement::new(subject.clone(), predicate.clone(), object));
+        Ok(())
     } else {
-        error!("rule {RULE_FN} expecting `Object` variant");
-        Err(Error::ParserUnexpected {
-            rule_fn: RULE_FN.into(),
-            given: value_variant(object),
-            expecting: vec!["Object".into()],
-        })
+        Err(member_error(
+            source,
+            path,
+            format!("expecting an object, found {}", value_variant(object)),
+        ))
     }
 }
 
 fn parse_literal_object(
-    subject: &SubjectNode,
-    predicate: &Iri,
+    source: &str,
+    path: &[&str],
     object: &Map<String, Value>,
-    graph: &mut Graph,
-) -> Result<(), Error> {
+) -> Result<Literal, Error> {
     parse_rule!("parse_literal_object" entry);
+
     let value = object.get(OBJ_KEY_VALUE);
     let language = object.get(OBJ_KEY_LANG);
     let data_type = object.get(OBJ_KEY_DATATYPE);
 
-    let object = match (value, language, data_type) {
-        (Some(Value::String(v)), None, None) => Literal::plain(v),
+    match (value, language, data_type) {
+        (Some(Value::String(v)), None, None) => Ok(Literal::plain(v)),
         (Some(Value::String(v)), Some(Value::String(l)), None) => {
-            Literal::with_language(v, LanguageTag::from_str(l)?)
+            let language = LanguageTag::from_str(l).map_err(|e| {
+                member_error(
+                    source,
+                    &[path, &[OBJ_KEY_LANG]].concat(),
+                    format!("invalid language tag, {e}"),
+                )
+            })?;
+            Ok(Literal::with_language(v, language))
         }
         (Some(Value::String(v)), None, Some(Value::String(d))) => {
-            let data_type = Iri::from_str(d)?;
-            Literal::with_data_type(v, DataType::from(data_type))
+            let data_type = Iri::from_str(d).map_err(|e| {
+                member_error(
+                    source,
+                    &[path, &[OBJ_KEY_DATATYPE]].concat(),
+                    format!("invalid data type, {e}"),
+                )
+            })?;
+            Ok(Literal::with_data_type(v, DataType::from(data_type)))
         }
-        _ => {
-            error!("parse_literal_object() - bad value/data type/language combination");
-            return Err(Error::ParserUnreachable {
-                rule_fn: RULE_FN.into(),
-                given: "bad value/data type/language combination".into(),
-            });
-        }
-    };
-    let st = Statement::new(subject.clone(), predicate.clone(), object);
-    graph.insert(st);
-    Ok(())
+        _ => Err(member_error(
+            source,
+            path,
+            format!(
+                "a literal must have a string `{OBJ_KEY_VALUE}`, and at most one of a string \
+                 `{OBJ_KEY_LANG}` or `{OBJ_KEY_DATATYPE}`"
+            ),
+        )),
+    }
 }
 
 fn parse_bnode_object(
-    subject: &SubjectNode,
-    predicate: &Iri,
+    source: &str,
+    path: &[&str],
     object: &Map<String, Value>,
-    graph: &mut Graph,
-) -> Result<(), Error> {
+) -> Result<BlankNode, Error> {
     parse_rule!("parse_bnode_object" entry);
-    if let Some(Value::String(s)) = object.get(OBJ_KEY_VALUE) {
-        let object = BlankNode::from_str(&s[2..])?;
-        let st = Statement::new(subject.clone(), predicate.clone(), object);
-        graph.insert(st);
-        Ok(())
-    } else {
-        error!(
-            "rule {RULE_FN} expecting object to have key {}",
-            OBJ_KEY_VALUE
-        );
-        Err(Error::ParserExpected {
-            rule_fn: RULE_FN.into(),
-            expecting: OBJ_KEY_VALUE.into(),
-        })
-    }
+
+    let value = string_value(source, path, object)?;
+    BlankNode::from_str(value.strip_prefix(BNODE_PREFIX).unwrap_or(value)).map_err(|e| {
+        member_error(
+            source,
+            &[path, &[OBJ_KEY_VALUE]].concat(),
+            format!("invalid blank node, {e}"),
+        )
+    })
 }
 
 fn parse_uri_object(
-    subject: &SubjectNode,
-    predicate: &Iri,
+    source: &str,
+    path: &[&str],
     object: &Map<String, Value>,
-    graph: &mut Graph,
-) -> Result<(), Error> {
+) -> Result<Iri, Error> {
     parse_rule!("parse_uri_object" entry);
 
-    if let Some(Value::String(s)) = object.get(OBJ_KEY_VALUE) {
-        let object = Iri::from_str(s)?;
-        let st = Statement::new(subject.clone(), predicate.clone(), object);
-        graph.insert(st);
-        Ok(())
-    } else {
-        error!(
-            "rule {RULE_FN} expecting object to have key {}",
-            OBJ_KEY_VALUE
-        );
-        Err(Error::ParserExpected {
-            rule_fn: RULE_FN.into(),
-            expecting: OBJ_KEY_VALUE.into(),
-        })
+    let value = string_value(source, path, object)?;
+    Iri::from_str(value).map_err(|e| {
+        member_error(
+            source,
+            &[path, &[OBJ_KEY_VALUE]].concat(),
+            format!("invalid IRI, {e}"),
+        )
+    })
+}
+
+fn string_value<'a>(
+    source: &str,
+    path: &[&str],
+    object: &'a Map<String, Value>,
+) -> Result<&'a str, Error> {
+    match object.get(OBJ_KEY_VALUE) {
+        Some(Value::String(s)) => Ok(s),
+        Some(value) => Err(member_error(
+            source,
+            &[path, &[OBJ_KEY_VALUE]].concat(),
+            format!("expecting a string, found {}", value_variant(value)),
+        )),
+        None => Err(member_error(
+            source,
+            path,
+            format!("missing key `{OBJ_KEY_VALUE}`"),
+        )),
     }
 }
@@ -0,0 +1,974 @@
+use super::context::{Context, ContextProcessor, TermDefinition};
+use super::syntax::{
+    as_array, is_list_object, is_value_object, KW_CONTEXT, KW_DIRECTION, KW_GRAPH, KW_ID, KW_INDEX,
+    KW_JSON, KW_LANGUAGE, KW_LIST, KW_NEST, KW_NONE, KW_REVERSE, KW_SET, KW_TYPE, KW_VALUE,
+    KW_VOCAB,
+};
+use rdftk_core::error::Error;
+use rdftk_iri::Iri;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implements the [Compaction Algorithm](https://www.w3.org/TR/json-ld11-api/#compaction-algorithm)
+/// which shortens IRIs to terms and compact IRIs, and values to native JSON values where the
+/// context allows.
+///
+#[derive(Debug)]
+pub(super) struct Compactor<'a> {
+    processor: ContextProcessor<'a>,
+    compact_arrays: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An active context together with its inverse, which is used to select the best term for an
+/// IRI and value; see [Inverse Context Creation](https://www.w3.org/TR/json-ld11-api/#inverse-context-creation).
+///
+#[derive(Debug)]
+struct ActiveContext {
+    context: Context,
+    inverse: HashMap<String, HashMap<String, TypeLanguageMap>>,
+    default_language: String,
+}
+
+#[derive(Debug, Default)]
+struct TypeLanguageMap {
+    language: HashMap<String, String>,
+    type_map: HashMap<String, String>,
+    any: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const KW_ANY: &str = "@any";
+const KW_NULL: &str = "@null";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Compactor<'a> {
+    pub(super) fn new(processor: ContextProcessor<'a>, compact_arrays: bool) -> Self {
+        Self {
+            processor,
+            compact_arrays,
+        }
+    }
+
+    ///
+    /// Compact an expanded document against `context`, which may be a context or a document
+    /// with an `@context` entry. The result is always a map, including the `@context` entry
+    /// unless the context is empty.
+    ///
+    pub(super) fn compact_document(
+        &self,
+        context: &Value,
+        expanded: &Value,
+        base_url: Option<&Iri>,
+    ) -> Result<Value, Error> {
+        let context = match context {
+            Value::Object(map) if map.contains_key(KW_CONTEXT) => &map[KW_CONTEXT],
+            context => context,
+        };
+        let active = self.processor.process(
+            &Context::new(base_url.cloned()),
+            context,
+            base_url,
+            false,
+            true,
+        )?;
+        let active = Rc::new(ActiveContext::new(active));
+
+        let mut result = match self.compact(&active, None, expanded)? {
+            Value::Array(values) if values.is_empty() => Map::default(),
+            Value::Array(values) => {
+                let mut result = Map::default();
+                let _ = result.insert(
+                    active.compact_keyword(KW_GRAPH).to_string(),
+                    Value::Array(values),
+                );
+                result
+            }
+            Value::Object(result) => result,
+            _ => Map::default(),
+        };
+        let is_empty = match context {
+            Value::Null => true,
+            Value::Object(map) => map.is_empty(),
+            Value::Array(values) => values.is_empty(),
+            _ => false,
+        };
+        if !is_empty {
+            let _ = result.insert(KW_CONTEXT.to_string(), context.clone());
+        }
+        Ok(Value::Object(result))
+    }
+
+    fn compact(
+        &self,
+        active: &Rc<ActiveContext>,
+        active_property: Option<&str>,
+        element: &Value,
+    ) -> Result<Value, Error> {
+        const RULE_FN: &str = "compact";
+        trace!("{RULE_FN}({active_property:?}, ...)");
+
+        match element {
+            Value::Array(elements) => {
+                let mut result = Vec::default();
+                for element in elements {
+                    let compacted = self.compact(active, active_property, element)?;
+                    if !compacted.is_null() {
+                        result.push(compacted);
+                    }
+                }
+                let container = active.container(active_property);
+                if result.len() != 1
+                    || !self.compact_arrays
+                    || active_property == Some(KW_GRAPH)
+                    || active_property == Some(KW_SET)
+                    || container.iter().any(|c| c == KW_LIST || c == KW_SET)
+                {
+                    Ok(Value::Array(result))
+                } else {
+                    Ok(result.remove(0))
+                }
+            }
+            Value::Object(element) => self.compact_map(active, active_property, element),
+            element => Ok(element.clone()),
+        }
+    }
+
+    fn compact_map(
+        &self,
+        active: &Rc<ActiveContext>,
+        active_property: Option<&str>,
+        element: &Map<String, Value>,
+    ) -> Result<Value, Error> {
+        let property_definition = active_property
+            .and_then(|property| active.context.term(property))
+            .cloned();
+
+        // revert to the previous context, unless this is a value object or a node reference.
+        let mut active = match active.context.previous() {
+            Some(previous)
+                if !element.contains_key(KW_VALUE)
+                    && (element.len() != 1 || !element.contains_key(KW_ID)) =>
+            {
+                Rc::new(ActiveContext::new(previous.clone()))
+            }
+            _ => active.clone(),
+        };
+
+        if let Some(context) = property_definition
+            .as_ref()
+            .and_then(|definition| definition.context())
+        {
+            active = Rc::new(ActiveContext::new(self.processor.process(
+                &active.context,
+                context,
+                property_definition.as_ref().and_then(|d| d.base_url()),
+                true,
+                true,
+            )?));
+        }
+
+        if element.contains_key(KW_VALUE) || element.contains_key(KW_ID) {
+            let result = active.compact_value(active_property, element);
+            let is_json = property_definition
+                .as_ref()
+                .and_then(|definition| definition.type_mapping())
+                .map(|type_mapping| type_mapping == KW_JSON)
+                .unwrap_or_default();
+            if !(result.is_object() || result.is_array()) || is_json {
+                return Ok(result);
+            }
+        }
+
+        let inside_reverse = active_property == Some(KW_REVERSE);
+        let mut result = Map::default();
+
+        // apply any type-scoped contexts, in order of the compacted type.
+        let type_scoped = active.clone();
+        if let Some(types) = element.get(KW_TYPE) {
+            let mut compacted_types = as_array(types.clone())
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|type_iri| type_scoped.compact_iri(type_iri, None, true, false))
+                .collect::<Vec<_>>();
+            compacted_types.sort();
+            for term in compacted_types {
+                if let Some(definition) = type_scoped.context.term(&term) {
+                    if let Some(context) = definition.context() {
+                        active = Rc::new(ActiveContext::new(self.processor.process(
+                            &active.context,
+                            context,
+                            definition.base_url(),
+                            false,
+                            false,
+                        )?));
+                    }
+                }
+            }
+        }
+
+        for (expanded_property, expanded_value) in element {
+            match expanded_property.as_str() {
+                KW_ID => {
+                    let compacted = expanded_value
+                        .as_str()
+                        .map(|id| Value::String(active.compact_iri(id, None, false, false)))
+                        .unwrap_or_else(|| expanded_value.clone());
+                    let _ = result.insert(active.compact_keyword(KW_ID), compacted);
+                    continue;
+                }
+                KW_TYPE => {
+                    let compacted = as_array(expanded_value.clone())
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(|type_iri| {
+                            Value::String(type_scoped.compact_iri(type_iri, None, true, false))
+                        })
+                        .collect::<Vec<_>>();
+                    let alias = active.compact_keyword(KW_TYPE);
+                    let as_array = active.container(Some(&alias)).iter().any(|c| c == KW_SET)
+                        || !self.compact_arrays;
+                    add_value(&mut result, &alias, Value::Array(compacted), as_array);
+                    continue;
+                }
+                KW_REVERSE => {
+                    let compacted = self.compact(&active, Some(KW_REVERSE), expanded_value)?;
+                    if let Value::Object(compacted) = compacted {
+                        let mut remaining = Map::default();
+                        for (property, value) in compacted {
+                            match active.context.term(&property) {
+                                Some(definition) if definition.is_reverse() => {
+                                    let as_array =
+                                        definition.has_container(KW_SET) || !self.compact_arrays;
+                                    add_value(&mut result, &property, value, as_array);
+                                }
+                                _ => {
+                                    let _ = remaining.insert(property, value);
+                                }
+                            }
+                        }
+                        if !remaining.is_empty() {
+                            let _ = result.insert(
+                                active.compact_keyword(KW_REVERSE),
+                                Value::Object(remaining),
+                            );
+                        }
+                    }
+                    continue;
+                }
+                KW_INDEX
+                    if active
+                        .container(active_property)
+                        .iter()
+                        .any(|c| c == KW_INDEX) =>
+                {
+                    continue;
+                }
+                KW_DIRECTION | KW_INDEX | KW_LANGUAGE | KW_VALUE => {
+                    let _ = result.insert(
+                        active.compact_keyword(expanded_property),
+                        expanded_value.clone(),
+                    );
+                    continue;
+                }
+                _ => {}
+            }
+
+            let expanded_values = as_array(expanded_value.clone());
+            if expanded_values.is_empty() {
+                let item_active_property = active.compact_iri(
+                    expanded_property,
+                    Some(expanded_value),
+                    true,
+                    inside_reverse,
+                );
+                let nest_result = nest_result(&active, &mut result, &item_active_property);
+                add_value(
+                    nest_result,
+                    &item_active_property,
+                    Value::Array(Vec::default()),
+                    true,
+                );
+            }
+
+            for expanded_item in &expanded_values {
+                let item_active_property = active.compact_iri(
+                    expanded_property,
+                    Some(expanded_item),
+                    true,
+                    inside_reverse,
+                );
+                let definition = active.context.term(&item_active_property).cloned();
+                let container = definition
+                    .as_ref()
+                    .map(|definition| definition.container().clone())
+                    .unwrap_or_default();
+                let has_container = |name: &str| container.iter().any(|c| c == name);
+                let as_array = has_container(KW_SET)
+                    || expanded_property == KW_GRAPH
+                    || expanded_property == KW_LIST
+                    || !self.compact_arrays;
+
+                let is_list = is_list_object(expanded_item);
+                let mut compacted_item = self.compact(
+                    &active,
+                    Some(&item_active_property),
+                    if is_list {
+                        &expanded_item[KW_LIST]
+                    } else {
+                        expanded_item
+                    },
+                )?;
+
+                let nest_result = nest_result(&active, &mut result, &item_active_property);
+                if is_list {
+                    if !compacted_item.is_array() {
+                        compacted_item = Value::Array(vec![compacted_item]);
+                    }
+                    if has_container(KW_LIST) {
+                        let _ = nest_result.insert(item_active_property, compacted_item);
+                    } else {
+                        let mut list_object = Map::default();
+                        let _ = list_object.insert(active.compact_keyword(KW_LIST), compacted_item);
+                        if let Some(index) = expanded_item.get(KW_INDEX) {
+                            let _ =
+                                list_object.insert(active.compact_keyword(KW_INDEX), index.clone());
+                        }
+                        add_value(
+                            nest_result,
+                            &item_active_property,
+                            Value::Object(list_object),
+                            as_array,
+                        );
+                    }
+                } else if has_container(KW_LANGUAGE)
+                    || has_container(KW_INDEX)
+                    || has_container(KW_ID)
+                    || has_container(KW_TYPE)
+                {
+                    let map_key = if has_container(KW_LANGUAGE) {
+                        if let Some(value) = compacted_item.get(KW_VALUE).cloned() {
+                            compacted_item = value;
+                        }
+                        expanded_item
+                            .get(KW_LANGUAGE)
+                            .and_then(Value::as_str)
+                            .map(str::to_string)
+                    } else if has_container(KW_INDEX) {
+                        match definition.as_ref().and_then(|d| d.index()) {
+                            Some(index_key) if index_key != KW_INDEX => {
+                                let container_key =
+                                    active.compact_iri(index_key, None, true, false);
+                                take_first_string(&mut compacted_item, &container_key)
+                            }
+                            _ => expanded_item
+                                .get(KW_INDEX)
+                                .and_then(Value::as_str)
+                                .map(str::to_string),
+                        }
+                    } else if has_container(KW_ID) {
+                        let alias = active.compact_keyword(KW_ID);
+                        compacted_item
+                            .as_object_mut()
+                            .and_then(|map| map.remove(&alias))
+                            .and_then(|id| id.as_str().map(str::to_string))
+                    } else {
+                        let alias = active.compact_keyword(KW_TYPE);
+                        let map_key = take_first_string(&mut compacted_item, &alias);
+                        let id_alias = active.compact_keyword(KW_ID);
+                        if compacted_item
+                            .as_object()
+                            .map(|map| map.len() == 1 && map.contains_key(&id_alias))
+                            .unwrap_or_default()
+                        {
+                            let mut reference = Map::default();
+                            let _ =
+                                reference.insert(KW_ID.to_string(), expanded_item[KW_ID].clone());
+                            compacted_item = self.compact(
+                                &active,
+                                Some(&item_active_property),
+                                &Value::Object(reference),
+                            )?;
+                        }
+                        map_key
+                    };
+                    let map_key = map_key.unwrap_or_else(|| active.compact_keyword(KW_NONE));
+                    let map_object = nest_result
+                        .entry(item_active_property)
+                        .or_insert_with(|| Value::Object(Map::default()));
+                    if let Value::Object(map_object) = map_object {
+                        add_value(map_object, &map_key, compacted_item, as_array);
+                    }
+                } else {
+                    add_value(nest_result, &item_active_property, compacted_item, as_array);
+                }
+            }
+        }
+
+        Ok(Value::Object(result))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ActiveContext {
+    fn new(context: Context) -> Self {
+        let default_language = context
+            .default_language()
+            .map(|language| language.to_lowercase())
+            .unwrap_or_else(|| KW_NONE.to_string());
+
+        let mut terms = context.terms().collect::<Vec<_>>();
+        terms.sort_by(|(lhs, _), (rhs, _)| lhs.len().cmp(&rhs.len()).then(lhs.cmp(rhs)));
+
+        let mut inverse: HashMap<String, HashMap<String, TypeLanguageMap>> = Default::default();
+        for (term, definition) in terms {
+            let Some(iri) = definition.iri() else {
+                continue;
+            };
+            let container = container_key(definition);
+            let type_language = inverse
+                .entry(iri.clone())
+                .or_default()
+                .entry(container)
+                .or_default();
+            let term = term.to_string();
+            if type_language.any.is_none() {
+                type_language.any = Some(term.clone());
+            }
+            let language_key =
+                |language: &Option<String>, direction: &Option<String>| match (language, direction)
+                {
+                    (Some(language), Some(direction)) => {
+                        format!("{}_{direction}", language.to_lowercase())
+                    }
+                    (Some(language), None) => language.to_lowercase(),
+                    (None, Some(direction)) => format!("_{direction}"),
+                    (None, None) => KW_NULL.to_string(),
+                };
+            if definition.is_reverse() {
+                let _ = type_language
+                    .type_map
+                    .entry(KW_REVERSE.to_string())
+                    .or_insert(term);
+            } else if definition.type_mapping().map(String::as_str) == Some(KW_NONE) {
+                let _ = type_language
+                    .language
+                    .entry(KW_ANY.to_string())
+                    .or_insert(term.clone());
+                let _ = type_language
+                    .type_map
+                    .entry(KW_ANY.to_string())
+                    .or_insert(term);
+            } else if let Some(type_mapping) = definition.type_mapping() {
+                let _ = type_language
+                    .type_map
+                    .entry(type_mapping.clone())
+                    .or_insert(term);
+            } else if let (Some(language), Some(direction)) =
+                (definition.language(), definition.direction())
+            {
+                let _ = type_language
+                    .language
+                    .entry(language_key(language, direction))
+                    .or_insert(term);
+            } else if let Some(language) = definition.language() {
+                let _ = type_language
+                    .language
+                    .entry(language_key(language, &None))
+                    .or_insert(term);
+            } else if let Some(direction) = definition.direction() {
+                let key = match direction {
+                    Some(direction) => format!("_{direction}"),
+                    None => KW_NONE.to_string(),
+                };
+                let _ = type_language.language.entry(key).or_insert(term);
+            } else if let Some(direction) = context.default_direction() {
+                let key = format!("{default_language}_{direction}");
+                let _ = type_language.language.entry(key).or_insert(term.clone());
+                let _ = type_language
+                    .language
+                    .entry(KW_NONE.to_string())
+                    .or_insert(term.clone());
+                let _ = type_language
+                    .type_map
+                    .entry(KW_NONE.to_string())
+                    .or_insert(term);
+            } else {
+                let _ = type_language
+                    .language
+                    .entry(default_language.clone())
+                    .or_insert(term.clone());
+                let _ = type_language
+                    .language
+                    .entry(KW_NONE.to_string())
+                    .or_insert(term.clone());
+                let _ = type_language
+                    .type_map
+                    .entry(KW_NONE.to_string())
+                    .or_insert(term);
+            }
+        }
+
+        Self {
+            context,
+            inverse,
+            default_language,
+        }
+    }
+
+    fn container(&self, property: Option<&str>) -> Vec<String> {
+        property
+            .and_then(|property| self.context.term(property))
+            .map(|definition| definition.container().clone())
+            .unwrap_or_default()
+    }
+
+    fn compact_keyword(&self, keyword: &str) -> String {
+        self.compact_iri(keyword, None, true, false)
+    }
+
+    ///
+    /// See [IRI Compaction](https://www.w3.org/TR/json-ld11-api/#iri-compaction).
+    ///
+    fn compact_iri(&self, iri: &str, value: Option<&Value>, vocab: bool, reverse: bool) -> String {
+        if vocab && self.inverse.contains_key(iri) {
+            if let Some(term) = self.select_term(iri, value, reverse) {
+                return term;
+            }
+        }
+
+        if vocab {
+            if let Some(suffix) = self
+                .context
+                .vocabulary()
+                .and_then(|vocabulary| iri.strip_prefix(vocabulary.as_str()))
+            {
+                if !suffix.is_empty() && self.context.term(suffix).is_none() {
+                    return suffix.to_string();
+                }
+            }
+        }
+
+        let mut compact_iri: Option<String> = None;
+        for (term, definition) in self.context.terms() {
+            let Some(term_iri) = definition.iri() else {
+                continue;
+            };
+            if term_iri == iri || !iri.starts_with(term_iri.as_str()) || !definition.is_prefix() {
+                continue;
+            }
+            let candidate = format!("{term}:{}", &iri[term_iri.len()..]);
+            let is_shorter = match &compact_iri {
+                None => true,
+                Some(current) => {
+                    candidate.len() < current.len()
+                        || (candidate.len() == current.len() && candidate < *current)
+                }
+            };
+            let is_unused = match self.context.term(&candidate) {
+                None => true,
+                Some(definition) => {
+                    definition.iri().map(String::as_str) == Some(iri) && value.is_none()
+                }
+            };
+            if is_shorter && is_unused {
+                compact_iri = Some(candidate);
+            }
+        }
+        if let Some(compact_iri) = compact_iri {
+            return compact_iri;
+        }
+
+        if !vocab {
+            if let Some(base) = self.context.base() {
+                if let Ok(absolute) = url::Url::parse(iri) {
+                    let base: &url::Url = base.as_ref();
+                    if let Some(relative) = base.make_relative(&absolute) {
+                        if !relative.is_empty() && !relative.contains(':') {
+                            return relative;
+                        }
+                    }
+                }
+            }
+        }
+        iri.to_string()
+    }
+
+    ///
+    /// Determine the containers, and preferred values, for `value` and then select the first
+    /// matching term from the inverse context; see
+    /// [Term Selection](https://www.w3.org/TR/json-ld11-api/#term-selection).
+    ///
+    fn select_term(&self, iri: &str, value: Option<&Value>, reverse: bool) -> Option<String> {
+        let map = value.and_then(Value::as_object);
+        let has_index = map
+            .map(|map| map.contains_key(KW_INDEX))
+            .unwrap_or_default();
+        let mut containers: Vec<&str> = Vec::default();
+        let mut type_language = KW_LANGUAGE;
+        let mut type_language_value = KW_NULL.to_string();
+
+        if has_index {
+            containers.extend([KW_INDEX, "@index@set"]);
+        }
+        if reverse {
+            type_language = KW_TYPE;
+            type_language_value = KW_REVERSE.to_string();
+            containers.push(KW_SET);
+        } else if let (true, Some(map)) = (value.map(is_list_object).unwrap_or_default(), map) {
+            if !has_index {
+                containers.push(KW_LIST);
+            }
+            let list = map[KW_LIST].as_array().cloned().unwrap_or_default();
+            let mut common_language = if list.is_empty() {
+                Some(self.default_language.clone())
+            } else {
+                None
+            };
+            let mut common_type: Option<String> = None;
+            for item in &list {
+                let (item_language, item_type) = if is_value_object(item) {
+                    match (item.get(KW_LANGUAGE), item.get(KW_TYPE)) {
+                        (Some(language), _) => (
+                            language_value(Some(language), item.get(KW_DIRECTION)),
+                            KW_NONE.to_string(),
+                        ),
+                        (None, Some(Value::String(item_type))) => {
+                            (KW_NONE.to_string(), item_type.clone())
+                        }
+                        _ => (KW_NULL.to_string(), KW_NONE.to_string()),
+                    }
+                } else {
+                    (KW_NONE.to_string(), KW_ID.to_string())
+                };
+                match &common_language {
+                    None => common_language = Some(item_language),
+                    Some(common) if *common != item_language && is_value_object(item) => {
+                        common_language = Some(KW_NONE.to_string())
+                    }
+                    _ => {}
+                }
+                match &common_type {
+                    None => common_type = Some(item_type),
+                    Some(common) if *common != item_type => common_type = Some(KW_NONE.to_string()),
+                    _ => {}
+                }
+                if common_language.as_deref() == Some(KW_NONE)
+                    && common_type.as_deref() == Some(KW_NONE)
+                {
+                    break;
+                }
+            }
+            let common_language = common_language.unwrap_or_else(|| KW_NONE.to_string());
+            let common_type = common_type.unwrap_or_else(|| KW_NONE.to_string());
+            if common_type != KW_NONE {
+                type_language = KW_TYPE;
+                type_language_value = common_type;
+            } else {
+                type_language_value = common_language;
+            }
+        } else {
+            match map {
+                Some(map) if map.contains_key(KW_VALUE) => {
+                    if map.contains_key(KW_DIRECTION) || map.contains_key(KW_LANGUAGE) {
+                        type_language_value =
+                            language_value(map.get(KW_LANGUAGE), map.get(KW_DIRECTION));
+                        if !has_index && map.contains_key(KW_LANGUAGE) {
+                            containers.extend([KW_LANGUAGE, "@language@set"]);
+                        }
+                    } else if let Some(Value::String(value_type)) = map.get(KW_TYPE) {
+                        type_language = KW_TYPE;
+                        type_language_value = value_type.clone();
+                    }
+                }
+                _ => {
+                    type_language = KW_TYPE;
+                    type_language_value = KW_ID.to_string();
+                    containers.extend([KW_ID, "@id@set", KW_TYPE, "@set@type"]);
+                }
+            }
+            containers.push(KW_SET);
+        }
+
+        containers.push(KW_NONE);
+        if !has_index {
+            containers.extend([KW_INDEX, "@index@set"]);
+        }
+        if map
+            .map(|map| map.len() == 1 && map.contains_key(KW_VALUE))
+            .unwrap_or_default()
+        {
+            containers.extend([KW_LANGUAGE, "@language@set"]);
+        }
+
+        let mut preferred_values: Vec<String> = Vec::default();
+        if type_language_value == KW_REVERSE {
+            preferred_values.push(KW_REVERSE.to_string());
+        }
+        let id = map.and_then(|map| map.get(KW_ID)).and_then(Value::as_str);
+        if let (true, Some(id)) = (
+            type_language_value == KW_ID || type_language_value == KW_REVERSE,
+            id,
+        ) {
+            let compacted = self.compact_iri(id, None, true, false);
+            let is_term = self
+                .context
+                .term(&compacted)
+                .and_then(TermDefinition::iri)
+                .map(String::as_str)
+                == Some(id);
+            if is_term {
+                preferred_values.extend([KW_VOCAB, KW_ID, KW_NONE].map(str::to_string));
+            } else {
+                preferred_values.extend([KW_ID, KW_VOCAB, KW_NONE].map(str::to_string));
+            }
+        } else {
+            preferred_values.push(type_language_value);
+            preferred_values.push(KW_NONE.to_string());
+            if map
+                .and_then(|map| map.get(KW_LIST))
+                .and_then(Value::as_array)
+                .map(Vec::is_empty)
+                .unwrap_or_default()
+            {
+                type_language = KW_ANY;
+            }
+        }
+        preferred_values.push(KW_ANY.to_string());
+        let with_direction = preferred_values
+            .iter()
+            .filter_map(|value| value.find('_').map(|index| value[index..].to_string()))
+            .collect::<Vec<_>>();
+        preferred_values.extend(with_direction);
+
+        let container_map = self.inverse.get(iri)?;
+        for container in containers {
+            let Some(type_language_map) = container_map.get(container) else {
+                continue;
+            };
+            for value in &preferred_values {
+                let term = match type_language {
+                    KW_LANGUAGE => type_language_map.language.get(value),
+                    KW_TYPE => type_language_map.type_map.get(value),
+                    _ if value == KW_NONE => type_language_map.any.as_ref(),
+                    _ => None,
+                };
+                if let Some(term) = term {
+                    return Some(term.clone());
+                }
+            }
+        }
+        None
+    }
+
+    ///
+    /// See [Value Compaction](https://www.w3.org/TR/json-ld11-api/#value-compaction).
+    ///
+    fn compact_value(&self, active_property: Option<&str>, value: &Map<String, Value>) -> Value {
+        let definition = active_property.and_then(|property| self.context.term(property));
+        let type_mapping = definition.and_then(TermDefinition::type_mapping);
+        let language = match definition.and_then(TermDefinition::language) {
+            Some(language) => language.clone(),
+            None => self.context.default_language().cloned(),
+        }
+        .map(|language| language.to_lowercase());
+        let direction = match definition.and_then(TermDefinition::direction) {
+            Some(direction) => direction.clone(),
+            None => self.context.default_direction().cloned(),
+        };
+        let index_ok = !value.contains_key(KW_INDEX)
+            || definition
+                .map(|definition| definition.has_container(KW_INDEX))
+                .unwrap_or_default();
+
+        if value.contains_key(KW_ID)
+            && (value.len() == 1 || (value.len() == 2 && value.contains_key(KW_INDEX)))
+        {
+            if let Some(id) = value[KW_ID].as_str() {
+                match type_mapping.map(String::as_str) {
+                    Some(KW_ID) => return Value::String(self.compact_iri(id, None, false, false)),
+                    Some(KW_VOCAB) => {
+                        return Value::String(self.compact_iri(id, None, true, false))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !value.contains_key(KW_VALUE) {
+            return Value::Object(value.clone());
+        }
+
+        let value_type = value.get(KW_TYPE).and_then(Value::as_str);
+        let mut result = value.clone();
+        if value_type.is_some() && value_type == type_mapping.map(String::as_str) {
+            return value[KW_VALUE].clone();
+        } else if type_mapping.map(String::as_str) == Some(KW_NONE) || value_type.is_some() {
+            if let Some(value_type) = value_type {
+                let _ = result.insert(
+                    KW_TYPE.to_string(),
+                    Value::String(self.compact_iri(value_type, None, true, false)),
+                );
+            }
+        } else if !value[KW_VALUE].is_string() {
+            if index_ok {
+                return value[KW_VALUE].clone();
+            }
+        } else {
+            let value_language = value
+                .get(KW_LANGUAGE)
+                .and_then(Value::as_str)
+                .map(str::to_lowercase);
+            let value_direction = value
+                .get(KW_DIRECTION)
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            if value_language == language && value_direction == direction && index_ok {
+                return value[KW_VALUE].clone();
+            }
+        }
+
+        Value::Object(
+            result
+                .into_iter()
+                .filter(|(key, _)| key != KW_INDEX || !index_ok)
+                .map(|(key, value)| (self.compact_keyword(&key), value))
+                .collect(),
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The key for a term's container mapping within the inverse context, its containers sorted and
+/// concatenated.
+///
+fn container_key(definition: &TermDefinition) -> String {
+    let mut container = definition.container().clone();
+    if container.is_empty() {
+        KW_NONE.to_string()
+    } else {
+        container.sort();
+        container.concat()
+    }
+}
+
+fn language_value(language: Option<&Value>, direction: Option<&Value>) -> String {
+    match (
+        language.and_then(Value::as_str),
+        direction.and_then(Value::as_str),
+    ) {
+        (Some(language), Some(direction)) => format!("{}_{direction}", language.to_lowercase()),
+        (Some(language), None) => language.to_lowercase(),
+        (None, Some(direction)) => format!("_{direction}"),
+        (None, None) => KW_NULL.to_string(),
+    }
+}
+
+///
+/// Add a compacted value to `key`, the value is always an array if `as_array` is set or more
+/// than one value is present.
+///
+fn add_value(map: &mut Map<String, Value>, key: &str, value: Value, as_array: bool) {
+    if as_array && !map.get(key).map(Value::is_array).unwrap_or_default() {
+        let existing = map.remove(key).map(as_array_value).unwrap_or_default();
+        let _ = map.insert(key.to_string(), Value::Array(existing));
+    }
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                add_value(map, key, value, as_array);
+            }
+        }
+        value => match map.get_mut(key) {
+            None => {
+                let _ = map.insert(key.to_string(), value);
+            }
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+        },
+    }
+}
+
+fn as_array_value(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(values) => values,
+        value => vec![value],
+    }
+}
+
+///
+/// Returns the map that `property` should be added to, this is the result itself unless the
+/// property is nested.
+///
+fn nest_result<'m>(
+    active: &ActiveContext,
+    result: &'m mut Map<String, Value>,
+    property: &str,
+) -> &'m mut Map<String, Value> {
+    let nest_term = active
+        .context
+        .term(property)
+        .and_then(TermDefinition::nest)
+        .filter(|nest_term| {
+            *nest_term == KW_NEST
+                || active.context.expand_iri(nest_term, false, true).as_deref() == Some(KW_NEST)
+        })
+        .cloned();
+    match nest_term {
+        Some(nest_term) => {
+            let nested = result
+                .entry(nest_term)
+                .or_insert_with(|| Value::Object(Map::default()));
+            if !nested.is_object() {
+                *nested = Value::Object(Map::default());
+            }
+            nested.as_object_mut().unwrap()
+        }
+        None => result,
+    }
+}
+
+///
+/// Remove the first string value of `key` from a compacted node, used to take map keys from
+/// `@type` and property-valued indexes.
+///
+fn take_first_string(compacted: &mut Value, key: &str) -> Option<String> {
+    let map = compacted.as_object_mut()?;
+    let mut values = as_array_value(map.remove(key)?);
+    let first = if values.first().map(Value::is_string).unwrap_or_default() {
+        values.remove(0).as_str().map(str::to_string)
+    } else {
+        None
+    };
+    match values.len() {
+        0 => {}
+        1 => {
+            let _ = map.insert(key.to_string(), values.remove(0));
+        }
+        _ => {
+            let _ = map.insert(key.to_string(), Value::Array(values));
+        }
+    }
+    first
+}
@@ -0,0 +1,1068 @@
+use super::loader::DocumentLoader;
+use super::syntax::{
+    is_absolute_iri, is_blank_node_id, is_keyword, looks_like_keyword, processing_error,
+    value_variant, KW_BASE, KW_CONTAINER, KW_CONTEXT, KW_DIRECTION, KW_GRAPH, KW_ID, KW_IMPORT,
+    KW_INDEX, KW_JSON, KW_LANGUAGE, KW_LIST, KW_NEST, KW_NONE, KW_PREFIX, KW_PROPAGATE,
+    KW_PROTECTED, KW_REVERSE, KW_SET, KW_TYPE, KW_VERSION, KW_VOCAB,
+};
+use rdftk_core::error::Error;
+use rdftk_iri::Iri;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The *active context* used to interpret terms, see
+/// [Context Processing](https://www.w3.org/TR/json-ld11-api/#context-processing-algorithms).
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct Context {
+    base: Option<Iri>,
+    original_base: Option<Iri>,
+    vocabulary: Option<String>,
+    default_language: Option<String>,
+    default_direction: Option<String>,
+    terms: HashMap<String, TermDefinition>,
+    previous: Option<Box<Context>>,
+}
+
+///
+/// A term definition within a [`Context`]; where the IRI mapping is `None` the term is
+/// explicitly mapped to `null` and is ignored during expansion.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct TermDefinition {
+    iri: Option<String>,
+    prefix: bool,
+    protected: bool,
+    reverse: bool,
+    base_url: Option<Iri>,
+    context: Option<Value>,
+    container: Vec<String>,
+    direction: Option<Option<String>>,
+    index: Option<String>,
+    language: Option<Option<String>>,
+    nest: Option<String>,
+    type_mapping: Option<String>,
+}
+
+///
+/// Processes local contexts into an active context, retrieving remote contexts via the document
+/// loader.
+///
+#[derive(Debug)]
+pub(super) struct ContextProcessor<'a> {
+    loader: &'a dyn DocumentLoader,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The state used while creating term definitions from a single local context.
+///
+struct LocalContext<'a> {
+    local: &'a Map<String, Value>,
+    defined: HashMap<String, bool>,
+    base_url: Option<&'a Iri>,
+    remote_contexts: &'a mut Vec<String>,
+    override_protected: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Limits the depth of nested remote contexts, guarding against deep or circular references.
+///
+const MAX_REMOTE_CONTEXTS: usize = 32;
+
+const CONTAINERS: &[&str] = &[
+    KW_GRAPH,
+    KW_ID,
+    KW_INDEX,
+    KW_LANGUAGE,
+    KW_LIST,
+    KW_SET,
+    KW_TYPE,
+];
+
+const TERM_DEFINITION_KEYS: &[&str] = &[
+    KW_CONTAINER,
+    KW_CONTEXT,
+    KW_DIRECTION,
+    KW_ID,
+    KW_INDEX,
+    KW_LANGUAGE,
+    KW_NEST,
+    KW_PREFIX,
+    KW_PROTECTED,
+    KW_REVERSE,
+    KW_TYPE,
+];
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Context {
+    pub(super) fn new(base: Option<Iri>) -> Self {
+        Self {
+            original_base: base.clone(),
+            base,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn base(&self) -> Option<&Iri> {
+        self.base.as_ref()
+    }
+
+    pub(super) fn vocabulary(&self) -> Option<&String> {
+        self.vocabulary.as_ref()
+    }
+
+    pub(super) fn default_language(&self) -> Option<&String> {
+        self.default_language.as_ref()
+    }
+
+    pub(super) fn default_direction(&self) -> Option<&String> {
+        self.default_direction.as_ref()
+    }
+
+    pub(super) fn previous(&self) -> Option<&Context> {
+        self.previous.as_deref()
+    }
+
+    pub(super) fn term(&self, term: &str) -> Option<&TermDefinition> {
+        self.terms.get(term)
+    }
+
+    pub(super) fn terms(&self) -> impl Iterator<Item = (&String, &TermDefinition)> {
+        self.terms.iter()
+    }
+
+    fn has_protected_terms(&self) -> bool {
+        self.terms.values().any(|term| term.protected)
+    }
+
+    ///
+    /// Expand `value` using this context, see
+    /// [IRI Expansion](https://www.w3.org/TR/json-ld11-api/#iri-expansion). Returns `None` if the
+    /// value is mapped to `null`, or has the form of a keyword but is not one.
+    ///
+    pub(super) fn expand_iri(
+        &self,
+        value: &str,
+        document_relative: bool,
+        vocab: bool,
+    ) -> Option<String> {
+        if is_keyword(value) {
+            return Some(value.to_string());
+        } else if looks_like_keyword(value) {
+            return None;
+        }
+        if vocab {
+            if let Some(term) = self.terms.get(value) {
+                return term.iri.clone();
+            }
+        }
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if prefix == "_" || suffix.starts_with("//") {
+                return Some(value.to_string());
+            }
+            if let Some(term) = self.terms.get(prefix) {
+                if let (Some(iri), true) = (&term.iri, term.prefix) {
+                    return Some(format!("{iri}{suffix}"));
+                }
+            }
+            if is_absolute_iri(value) {
+                return Some(value.to_string());
+            }
+        }
+        if vocab {
+            if let Some(vocabulary) = &self.vocabulary {
+                return Some(format!("{vocabulary}{value}"));
+            }
+        }
+        if document_relative {
+            return Some(resolve(self.base.as_ref(), value));
+        }
+        Some(value.to_string())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TermDefinition {
+    pub(super) fn iri(&self) -> Option<&String> {
+        self.iri.as_ref()
+    }
+
+    pub(super) fn is_prefix(&self) -> bool {
+        self.prefix
+    }
+
+    pub(super) fn is_reverse(&self) -> bool {
+        self.reverse
+    }
+
+    pub(super) fn base_url(&self) -> Option<&Iri> {
+        self.base_url.as_ref()
+    }
+
+    pub(super) fn context(&self) -> Option<&Value> {
+        self.context.as_ref()
+    }
+
+    pub(super) fn has_container(&self, container: &str) -> bool {
+        self.container.iter().any(|c| c == container)
+    }
+
+    pub(super) fn container(&self) -> &Vec<String> {
+        &self.container
+    }
+
+    pub(super) fn direction(&self) -> Option<&Option<String>> {
+        self.direction.as_ref()
+    }
+
+    pub(super) fn index(&self) -> Option<&String> {
+        self.index.as_ref()
+    }
+
+    pub(super) fn language(&self) -> Option<&Option<String>> {
+        self.language.as_ref()
+    }
+
+    pub(super) fn nest(&self) -> Option<&String> {
+        self.nest.as_ref()
+    }
+
+    pub(super) fn type_mapping(&self) -> Option<&String> {
+        self.type_mapping.as_ref()
+    }
+
+    ///
+    /// Compare two definitions ignoring the protected flag, used to allow the identical
+    /// redefinition of a protected term.
+    ///
+    fn same_as(&self, other: &Self) -> bool {
+        let mut lhs = self.clone();
+        lhs.protected = other.protected;
+        &lhs == other
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> ContextProcessor<'a> {
+    pub(super) fn new(loader: &'a dyn DocumentLoader) -> Self {
+        Self { loader }
+    }
+
+    ///
+    /// Process a local context into a new active context, see
+    /// [Context Processing Algorithm](https://www.w3.org/TR/json-ld11-api/#algorithm).
+    ///
+    pub(super) fn process(
+        &self,
+        active: &Context,
+        local: &Value,
+        base_url: Option<&Iri>,
+        override_protected: bool,
+        propagate: bool,
+    ) -> Result<Context, Error> {
+        self.process_inner(
+            active,
+            local,
+            base_url,
+            &mut Vec::default(),
+            override_protected,
+            propagate,
+            true,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_inner(
+        &self,
+        active: &Context,
+        local: &Value,
+        base_url: Option<&Iri>,
+        remote_contexts: &mut Vec<String>,
+        override_protected: bool,
+        propagate: bool,
+        validate_scoped: bool,
+    ) -> Result<Context, Error> {
+        const RULE_FN: &str = "process_context";
+        trace!("{RULE_FN}(...)");
+
+        let mut result = active.clone();
+        let propagate = match local.as_object().and_then(|map| map.get(KW_PROPAGATE)) {
+            Some(Value::Bool(propagate)) => *propagate,
+            _ => propagate,
+        };
+        if !propagate && result.previous.is_none() {
+            result.previous = Some(Box::new(active.clone()));
+        }
+
+        let locals = match local {
+            Value::Array(locals) => locals.iter().collect(),
+            local => vec![local],
+        };
+
+        for context in locals {
+            match context {
+                Value::Null => {
+                    if !override_protected && result.has_protected_terms() {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid context nullification",
+                            "null",
+                        ));
+                    }
+                    let previous = result.previous.take();
+                    result = Context::new(active.original_base.clone());
+                    if !propagate {
+                        result.previous = previous;
+                    }
+                }
+                Value::String(context) => {
+                    let context_url = resolve(base_url.or(active.base.as_ref()), context);
+                    if !validate_scoped && remote_contexts.contains(&context_url) {
+                        continue;
+                    }
+                    if remote_contexts.len() >= MAX_REMOTE_CONTEXTS {
+                        return Err(processing_error(RULE_FN, "context overflow", &context_url));
+                    }
+                    remote_contexts.push(context_url.clone());
+                    let (document_url, loaded) = self.load_context(&context_url)?;
+                    result = self.process_inner(
+                        &result,
+                        &loaded,
+                        Some(&document_url),
+                        remote_contexts,
+                        override_protected,
+                        true,
+                        validate_scoped,
+                    )?;
+                }
+                Value::Object(context) => {
+                    self.process_map(
+                        &mut result,
+                        context,
+                        base_url,
+                        remote_contexts,
+                        override_protected,
+                    )?;
+                }
+                context => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid local context",
+                        value_variant(context),
+                    ));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn process_map(
+        &self,
+        result: &mut Context,
+        context: &Map<String, Value>,
+        base_url: Option<&Iri>,
+        remote_contexts: &mut Vec<String>,
+        override_protected: bool,
+    ) -> Result<(), Error> {
+        const RULE_FN: &str = "process_context";
+
+        match context.get(KW_VERSION) {
+            None => {}
+            Some(Value::Number(n)) if n.as_f64() == Some(1.1) => {}
+            Some(version) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @version value",
+                    version.to_string(),
+                ));
+            }
+        }
+
+        let imported;
+        let context = match context.get(KW_IMPORT) {
+            None => context,
+            Some(Value::String(import)) => {
+                let import_url = resolve(base_url.or(result.base.as_ref()), import);
+                let (_, loaded) = self.load_context(&import_url)?;
+                match loaded {
+                    Value::Object(mut import_context) => {
+                        if import_context.contains_key(KW_IMPORT) {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid context entry",
+                                &import_url,
+                            ));
+                        }
+                        for (key, value) in context {
+                            let _ = import_context.insert(key.clone(), value.clone());
+                        }
+                        imported = import_context;
+                        &imported
+                    }
+                    loaded => {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid remote context",
+                            value_variant(&loaded),
+                        ));
+                    }
+                }
+            }
+            Some(import) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @import value",
+                    value_variant(import),
+                ));
+            }
+        };
+
+        if remote_contexts.is_empty() {
+            match context.get(KW_BASE) {
+                None => {}
+                Some(Value::Null) => result.base = None,
+                Some(Value::String(base)) => {
+                    let base = resolve(result.base.as_ref(), base);
+                    result.base = Some(
+                        Iri::from_str(&base)
+                            .map_err(|_| processing_error(RULE_FN, "invalid base IRI", &base))?,
+                    );
+                }
+                Some(base) => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid base IRI",
+                        value_variant(base),
+                    ));
+                }
+            }
+        }
+
+        match context.get(KW_VOCAB) {
+            None => {}
+            Some(Value::Null) => result.vocabulary = None,
+            Some(Value::String(vocab)) => {
+                let vocab = result
+                    .expand_iri(vocab, true, true)
+                    .ok_or_else(|| processing_error(RULE_FN, "invalid vocab mapping", vocab))?;
+                result.vocabulary = Some(vocab);
+            }
+            Some(vocab) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid vocab mapping",
+                    value_variant(vocab),
+                ));
+            }
+        }
+
+        match context.get(KW_LANGUAGE) {
+            None => {}
+            Some(Value::Null) => result.default_language = None,
+            Some(Value::String(language)) => {
+                result.default_language = Some(language.to_lowercase());
+            }
+            Some(language) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid default language",
+                    value_variant(language),
+                ));
+            }
+        }
+
+        match context.get(KW_DIRECTION) {
+            None => {}
+            Some(Value::Null) => result.default_direction = None,
+            Some(Value::String(direction)) if direction == "ltr" || direction == "rtl" => {
+                result.default_direction = Some(direction.clone());
+            }
+            Some(direction) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid base direction",
+                    direction.to_string(),
+                ));
+            }
+        }
+
+        if let Some(propagate) = context.get(KW_PROPAGATE) {
+            if !propagate.is_boolean() {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @propagate value",
+                    value_variant(propagate),
+                ));
+            }
+        }
+
+        let protected = match context.get(KW_PROTECTED) {
+            None => false,
+            Some(Value::Bool(protected)) => *protected,
+            Some(protected) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @protected value",
+                    value_variant(protected),
+                ));
+            }
+        };
+
+        let mut local = LocalContext {
+            local: context,
+            defined: HashMap::default(),
+            base_url,
+            remote_contexts,
+            override_protected,
+        };
+        for term in context.keys().filter(|key| {
+            ![
+                KW_BASE,
+                KW_DIRECTION,
+                KW_IMPORT,
+                KW_LANGUAGE,
+                KW_PROPAGATE,
+                KW_PROTECTED,
+                KW_VERSION,
+                KW_VOCAB,
+            ]
+            .contains(&key.as_str())
+        }) {
+            self.create_term(result, &mut local, term, protected)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Create a term definition in `active` for the entry `term` in the local context, see
+    /// [Create Term Definition](https://www.w3.org/TR/json-ld11-api/#create-term-definition).
+    ///
+    fn create_term(
+        &self,
+        active: &mut Context,
+        local: &mut LocalContext<'_>,
+        term: &str,
+        protected: bool,
+    ) -> Result<(), Error> {
+        const RULE_FN: &str = "create_term_definition";
+        trace!("{RULE_FN}({term:?})");
+
+        match local.defined.get(term) {
+            Some(true) => return Ok(()),
+            Some(false) => {
+                return Err(processing_error(RULE_FN, "cyclic IRI mapping", term));
+            }
+            None => {}
+        }
+        if term.is_empty() {
+            return Err(processing_error(RULE_FN, "invalid term definition", term));
+        }
+        let _ = local.defined.insert(term.to_string(), false);
+
+        let value = local.local.get(term).cloned().unwrap_or_default();
+
+        if term == KW_TYPE {
+            let valid = value
+                .as_object()
+                .map(|map| {
+                    !map.is_empty()
+                        && map.iter().all(|(k, v)| {
+                            (k == KW_CONTAINER && v == KW_SET)
+                                || (k == KW_PROTECTED && v.is_boolean())
+                        })
+                })
+                .unwrap_or_default();
+            if !valid {
+                return Err(processing_error(RULE_FN, "keyword redefinition", term));
+            }
+        } else if is_keyword(term) {
+            return Err(processing_error(RULE_FN, "keyword redefinition", term));
+        } else if looks_like_keyword(term) {
+            let _ = local.defined.insert(term.to_string(), true);
+            return Ok(());
+        }
+
+        let previous = active.terms.remove(term);
+
+        let (value, simple_term) = match value {
+            Value::Null => {
+                let mut map = Map::default();
+                let _ = map.insert(KW_ID.into(), Value::Null);
+                (map, false)
+            }
+            Value::String(iri) => {
+                let mut map = Map::default();
+                let _ = map.insert(KW_ID.into(), Value::String(iri));
+                (map, true)
+            }
+            Value::Object(map) => (map, false),
+            value => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid term definition",
+                    value_variant(&value),
+                ));
+            }
+        };
+
+        let mut definition = TermDefinition {
+            base_url: local.base_url.cloned(),
+            ..Default::default()
+        };
+
+        if let Some(key) = value
+            .keys()
+            .find(|key| !TERM_DEFINITION_KEYS.contains(&key.as_str()))
+        {
+            return Err(processing_error(RULE_FN, "invalid term definition", key));
+        }
+
+        definition.protected = match value.get(KW_PROTECTED) {
+            None => protected,
+            Some(Value::Bool(protected)) => *protected,
+            Some(protected) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @protected value",
+                    value_variant(protected),
+                ));
+            }
+        };
+
+        match value.get(KW_TYPE) {
+            None => {}
+            Some(Value::String(type_mapping)) => {
+                let type_mapping = self
+                    .expand_iri_defining(active, local, type_mapping, false, true)?
+                    .unwrap_or_default();
+                if type_mapping == KW_ID
+                    || type_mapping == KW_JSON
+                    || type_mapping == KW_NONE
+                    || type_mapping == KW_VOCAB
+                    || is_absolute_iri(&type_mapping)
+                {
+                    definition.type_mapping = Some(type_mapping);
+                } else {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid type mapping",
+                        type_mapping,
+                    ));
+                }
+            }
+            Some(type_mapping) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid type mapping",
+                    value_variant(type_mapping),
+                ));
+            }
+        }
+
+        if let Some(reverse) = value.get(KW_REVERSE) {
+            if value.contains_key(KW_ID) || value.contains_key(KW_NEST) {
+                return Err(processing_error(RULE_FN, "invalid reverse property", term));
+            }
+            let Value::String(reverse) = reverse else {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid IRI mapping",
+                    value_variant(reverse),
+                ));
+            };
+            if looks_like_keyword(reverse) {
+                let _ = local.defined.insert(term.to_string(), true);
+                return Ok(());
+            }
+            let iri = self
+                .expand_iri_defining(active, local, reverse, false, true)?
+                .unwrap_or_default();
+            if !iri.contains(':') {
+                return Err(processing_error(RULE_FN, "invalid IRI mapping", iri));
+            }
+            definition.iri = Some(iri);
+            match value.get(KW_CONTAINER) {
+                None | Some(Value::Null) => {}
+                Some(Value::String(container)) if container == KW_SET || container == KW_INDEX => {
+                    definition.container = vec![container.clone()];
+                }
+                Some(container) => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid reverse property",
+                        container.to_string(),
+                    ));
+                }
+            }
+            definition.reverse = true;
+            return self.finish_term(active, local, term, definition, previous);
+        }
+
+        match value.get(KW_ID) {
+            Some(id) if id.as_str() != Some(term) => match id {
+                Value::Null => {}
+                Value::String(id) => {
+                    if !is_keyword(id) && looks_like_keyword(id) {
+                        let _ = local.defined.insert(term.to_string(), true);
+                        return Ok(());
+                    }
+                    let iri = self
+                        .expand_iri_defining(active, local, id, false, true)?
+                        .unwrap_or_default();
+                    if !(is_keyword(&iri) || iri.contains(':')) {
+                        return Err(processing_error(RULE_FN, "invalid IRI mapping", iri));
+                    }
+                    if iri == KW_CONTEXT {
+                        return Err(processing_error(RULE_FN, "invalid keyword alias", term));
+                    }
+                    let inner_colon = colon_index(term)
+                        .map(|i| i < term.len() - 1)
+                        .unwrap_or_default();
+                    if inner_colon || term.contains('/') {
+                        let _ = local.defined.insert(term.to_string(), true);
+                        let term_iri =
+                            self.expand_iri_defining(active, local, term, false, true)?;
+                        if term_iri.as_ref() != Some(&iri) {
+                            return Err(processing_error(RULE_FN, "invalid IRI mapping", term));
+                        }
+                    }
+                    if !term.contains(':')
+                        && !term.contains('/')
+                        && simple_term
+                        && (iri.ends_with([':', '/', '?', '#', '[', ']', '@'])
+                            || is_blank_node_id(&iri))
+                    {
+                        definition.prefix = true;
+                    }
+                    definition.iri = Some(iri);
+                }
+                id => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid IRI mapping",
+                        value_variant(id),
+                    ));
+                }
+            },
+            _ => {
+                if let Some(colon) = colon_index(term) {
+                    let (prefix, suffix) = (&term[..colon], &term[colon + 1..]);
+                    if local.local.contains_key(prefix) {
+                        self.create_term(active, local, prefix, protected)?;
+                    }
+                    definition.iri = match active.terms.get(prefix).and_then(|t| t.iri.as_ref()) {
+                        Some(iri) => Some(format!("{iri}{suffix}")),
+                        None => Some(term.to_string()),
+                    };
+                } else if term.contains('/') {
+                    let iri = active.expand_iri(term, false, true).unwrap_or_default();
+                    if !is_absolute_iri(&iri) {
+                        return Err(processing_error(RULE_FN, "invalid IRI mapping", term));
+                    }
+                    definition.iri = Some(iri);
+                } else if term == KW_TYPE {
+                    definition.iri = Some(KW_TYPE.to_string());
+                } else if let Some(vocabulary) = &active.vocabulary {
+                    definition.iri = Some(format!("{vocabulary}{term}"));
+                } else {
+                    return Err(processing_error(RULE_FN, "invalid IRI mapping", term));
+                }
+            }
+        }
+
+        if let Some(container) = value.get(KW_CONTAINER) {
+            definition.container = container_mapping(container)?;
+            if definition.has_container(KW_TYPE) {
+                match &definition.type_mapping {
+                    None => definition.type_mapping = Some(KW_ID.to_string()),
+                    Some(type_mapping) if type_mapping == KW_ID || type_mapping == KW_VOCAB => {}
+                    Some(type_mapping) => {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid type mapping",
+                            type_mapping,
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let Some(index) = value.get(KW_INDEX) {
+            match index {
+                Value::String(index)
+                    if definition.has_container(KW_INDEX) && !is_keyword(index) =>
+                {
+                    definition.index = Some(index.clone());
+                }
+                index => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid term definition",
+                        index.to_string(),
+                    ));
+                }
+            }
+        }
+
+        if let Some(context) = value.get(KW_CONTEXT) {
+            // validate the scoped context now, it is processed again when the term is used.
+            let _ = self
+                .process_inner(
+                    active,
+                    context,
+                    local.base_url,
+                    local.remote_contexts,
+                    true,
+                    true,
+                    false,
+                )
+                .map_err(|e| {
+                    processing_error(RULE_FN, "invalid scoped context", format!("{term}, {e}"))
+                })?;
+            definition.context = Some(context.clone());
+        }
+
+        if !value.contains_key(KW_TYPE) {
+            match value.get(KW_LANGUAGE) {
+                None => {}
+                Some(Value::Null) => definition.language = Some(None),
+                Some(Value::String(language)) => {
+                    definition.language = Some(Some(language.to_lowercase()));
+                }
+                Some(language) => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid language mapping",
+                        value_variant(language),
+                    ));
+                }
+            }
+            match value.get(KW_DIRECTION) {
+                None => {}
+                Some(Value::Null) => definition.direction = Some(None),
+                Some(Value::String(direction)) if direction == "ltr" || direction == "rtl" => {
+                    definition.direction = Some(Some(direction.clone()));
+                }
+                Some(direction) => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid base direction",
+                        direction.to_string(),
+                    ));
+                }
+            }
+        }
+
+        match value.get(KW_NEST) {
+            None => {}
+            Some(Value::String(nest)) if nest == KW_NEST || !is_keyword(nest) => {
+                definition.nest = Some(nest.clone());
+            }
+            Some(nest) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @nest value",
+                    nest.to_string(),
+                ));
+            }
+        }
+
+        match value.get(KW_PREFIX) {
+            None => {}
+            Some(Value::Bool(prefix)) if !term.contains(':') && !term.contains('/') => {
+                if *prefix
+                    && definition
+                        .iri
+                        .as_deref()
+                        .map(is_keyword)
+                        .unwrap_or_default()
+                {
+                    return Err(processing_error(RULE_FN, "invalid term definition", term));
+                }
+                definition.prefix = *prefix;
+            }
+            Some(prefix) => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid @prefix value",
+                    prefix.to_string(),
+                ));
+            }
+        }
+
+        self.finish_term(active, local, term, definition, previous)
+    }
+
+    fn finish_term(
+        &self,
+        active: &mut Context,
+        local: &mut LocalContext<'_>,
+        term: &str,
+        definition: TermDefinition,
+        previous: Option<TermDefinition>,
+    ) -> Result<(), Error> {
+        let definition = match previous {
+            Some(previous) if !local.override_protected && previous.protected => {
+                if !definition.same_as(&previous) {
+                    return Err(processing_error(
+                        "create_term_definition",
+                        "protected term redefinition",
+                        term,
+                    ));
+                }
+                previous
+            }
+            _ => definition,
+        };
+        let _ = active.terms.insert(term.to_string(), definition);
+        let _ = local.defined.insert(term.to_string(), true);
+        Ok(())
+    }
+
+    ///
+    /// IRI expansion used while processing a local context, this will first define any term, or
+    /// prefix, that the value depends upon.
+    ///
+    fn expand_iri_defining(
+        &self,
+        active: &mut Context,
+        local: &mut LocalContext<'_>,
+        value: &str,
+        document_relative: bool,
+        vocab: bool,
+    ) -> Result<Option<String>, Error> {
+        if is_keyword(value) || looks_like_keyword(value) {
+            return Ok(active.expand_iri(value, document_relative, vocab));
+        }
+        if local.local.contains_key(value) && local.defined.get(value) != Some(&true) {
+            self.create_term(active, local, value, false)?;
+        }
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if prefix != "_"
+                && !suffix.starts_with("//")
+                && local.local.contains_key(prefix)
+                && local.defined.get(prefix) != Some(&true)
+            {
+                self.create_term(active, local, prefix, false)?;
+            }
+        }
+        Ok(active.expand_iri(value, document_relative, vocab))
+    }
+
+    fn load_context(&self, url: &str) -> Result<(Iri, Value), Error> {
+        const RULE_FN: &str = "load_context";
+
+        let iri = Iri::from_str(url)
+            .map_err(|_| processing_error(RULE_FN, "loading remote context failed", url))?;
+        let document = self.loader.load(&iri)?;
+        let document_url = document.document_url().clone();
+        match document.into_document() {
+            Value::Object(mut map) => match map.remove(KW_CONTEXT) {
+                Some(context) => Ok((document_url, context)),
+                None => Err(processing_error(RULE_FN, "invalid remote context", url)),
+            },
+            _ => Err(processing_error(RULE_FN, "invalid remote context", url)),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolve `value` against `base`, if there is no base, or the value cannot be resolved, the
+/// value is returned unchanged.
+///
+pub(super) fn resolve(base: Option<&Iri>, value: &str) -> String {
+    match base {
+        Some(base) => {
+            let base: &url::Url = base.as_ref();
+            base.join(value)
+                .map(|url| url.to_string())
+                .unwrap_or_else(|_| value.to_string())
+        }
+        None => value.to_string(),
+    }
+}
+
+///
+/// Returns the index of the first colon in `term` that is not the first character.
+///
+fn colon_index(term: &str) -> Option<usize> {
+    term.char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == ':')
+        .map(|(i, _)| i)
+}
+
+fn container_mapping(container: &Value) -> Result<Vec<String>, Error> {
+    const RULE_FN: &str = "create_term_definition";
+
+    let values = match container {
+        Value::String(value) => vec![value.clone()],
+        Value::Array(values) => values
+            .iter()
+            .map(|value| match value {
+                Value::String(value) => Ok(value.clone()),
+                value => Err(processing_error(
+                    RULE_FN,
+                    "invalid container mapping",
+                    value_variant(value),
+                )),
+            })
+            .collect::<Result<Vec<String>, Error>>()?,
+        container => {
+            return Err(processing_error(
+                RULE_FN,
+                "invalid container mapping",
+                value_variant(container),
+            ));
+        }
+    };
+    let has = |c: &str| values.iter().any(|v| v == c);
+    let valid = values.iter().all(|v| CONTAINERS.contains(&v.as_str()))
+        && match values.len() {
+            0 => false,
+            1 => true,
+            _ => {
+                !has(KW_LIST)
+                    && if has(KW_GRAPH) {
+                        values
+                            .iter()
+                            .all(|v| v == KW_GRAPH || v == KW_ID || v == KW_INDEX || v == KW_SET)
+                    } else {
+                        has(KW_SET) && values.iter().all(|v| v != KW_LIST) && values.len() == 2
+                    }
+            }
+        };
+    if valid {
+        Ok(values)
+    } else {
+        Err(processing_error(
+            RULE_FN,
+            "invalid container mapping",
+            container.to_string(),
+        ))
+    }
+}
@@ -0,0 +1,903 @@
+use super::context::{Context, ContextProcessor};
+use super::syntax::{
+    add_value, as_array, is_graph_object, is_list_object, is_value_object, processing_error,
+    value_variant, KW_CONTEXT, KW_DEFAULT, KW_DIRECTION, KW_EMBED, KW_EXPLICIT, KW_GRAPH, KW_ID,
+    KW_INCLUDED, KW_INDEX, KW_JSON, KW_LANGUAGE, KW_LIST, KW_NEST, KW_NONE, KW_OMIT_DEFAULT,
+    KW_REQUIRE_ALL, KW_REVERSE, KW_SET, KW_TYPE, KW_VALUE, KW_VOCAB,
+};
+use rdftk_core::error::Error;
+use rdftk_iri::Iri;
+use serde_json::{Map, Value};
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implements the [Expansion Algorithm](https://www.w3.org/TR/json-ld11-api/#expansion-algorithm).
+///
+#[derive(Debug)]
+pub(super) struct Expander<'a> {
+    processor: ContextProcessor<'a>,
+    frame_expansion: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The state shared by the keys of a single map during expansion, this is also used for the
+/// content of any nested properties.
+///
+struct MapState<'a> {
+    active: &'a Context,
+    type_scoped: &'a Context,
+    active_property: Option<&'a str>,
+    input_type: Option<String>,
+    base_url: Option<&'a Iri>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Expander<'a> {
+    pub(super) fn new(processor: ContextProcessor<'a>) -> Self {
+        Self {
+            processor,
+            frame_expansion: false,
+        }
+    }
+
+    ///
+    /// If `true` the input is a frame, framing keywords are retained, `@id` and `@type` may be
+    /// wildcards or match-none patterns, and empty node objects are not dropped; see
+    /// [Framing Requirements](https://www.w3.org/TR/json-ld11-framing/#framing-requirements).
+    ///
+    pub(super) fn with_frame_expansion(self, frame_expansion: bool) -> Self {
+        Self {
+            frame_expansion,
+            ..self
+        }
+    }
+
+    ///
+    /// Expand a complete document, the result is always an array of node objects.
+    ///
+    pub(super) fn expand_document(
+        &self,
+        active: &Context,
+        document: &Value,
+        base_url: Option<&Iri>,
+    ) -> Result<Value, Error> {
+        let expanded = self.expand(active, None, document, base_url, false)?;
+        Ok(match expanded {
+            Value::Object(mut map) if map.len() == 1 && map.contains_key(KW_GRAPH) => {
+                map.remove(KW_GRAPH).unwrap()
+            }
+            Value::Null => Value::Array(Vec::default()),
+            Value::Array(values) => Value::Array(values),
+            value => Value::Array(vec![value]),
+        })
+    }
+
+    pub(super) fn expand(
+        &self,
+        active: &Context,
+        active_property: Option<&str>,
+        element: &Value,
+        base_url: Option<&Iri>,
+        from_map: bool,
+    ) -> Result<Value, Error> {
+        const RULE_FN: &str = "expand";
+        trace!("{RULE_FN}({active_property:?}, ...)");
+
+        let property_definition = active_property.and_then(|p| active.term(p));
+        let property_scoped_context = property_definition.and_then(|d| d.context());
+
+        match element {
+            Value::Null => Ok(Value::Null),
+            Value::Array(elements) => {
+                let is_list = property_definition
+                    .map(|d| d.has_container(KW_LIST))
+                    .unwrap_or_default();
+                let mut result = Vec::default();
+                for item in elements {
+                    let expanded =
+                        self.expand(active, active_property, item, base_url, from_map)?;
+                    match expanded {
+                        Value::Array(values) if is_list => {
+                            let mut list = Map::default();
+                            let _ = list.insert(KW_LIST.into(), Value::Array(values));
+                            result.push(Value::Object(list));
+                        }
+                        Value::Array(values) => result.extend(values),
+                        Value::Null => {}
+                        value => result.push(value),
+                    }
+                }
+                Ok(Value::Array(result))
+            }
+            Value::Object(element) => self.expand_map(
+                active,
+                active_property,
+                element,
+                base_url,
+                from_map,
+                property_scoped_context,
+            ),
+            scalar => {
+                if active_property.is_none() || active_property == Some(KW_GRAPH) {
+                    return Ok(Value::Null);
+                }
+                let active_property = active_property.unwrap();
+                match property_scoped_context {
+                    Some(context) => {
+                        let active = self.processor.process(
+                            active,
+                            context,
+                            property_definition.and_then(|d| d.base_url()),
+                            true,
+                            true,
+                        )?;
+                        self.expand_value(&active, active_property, scalar)
+                    }
+                    None => self.expand_value(active, active_property, scalar),
+                }
+            }
+        }
+    }
+
+    fn expand_map(
+        &self,
+        active: &Context,
+        active_property: Option<&str>,
+        element: &Map<String, Value>,
+        base_url: Option<&Iri>,
+        from_map: bool,
+        property_scoped_context: Option<&Value>,
+    ) -> Result<Value, Error> {
+        const RULE_FN: &str = "expand";
+
+        // revert to the previous context, unless this is a value object or a node reference.
+        let expands_to = |key: &String, keyword: &str| {
+            active.expand_iri(key, false, true).as_deref() == Some(keyword)
+        };
+        let mut active = match active.previous() {
+            Some(previous)
+                if !from_map
+                    && !element.keys().any(|key| expands_to(key, KW_VALUE))
+                    && (element.len() != 1
+                        || !element.keys().all(|key| expands_to(key, KW_ID))) =>
+            {
+                previous.clone()
+            }
+            _ => active.clone(),
+        };
+
+        if let Some(context) = property_scoped_context {
+            let definition_base = active_property
+                .and_then(|p| active.term(p))
+                .and_then(|d| d.base_url().cloned());
+            active = self.processor.process(
+                &active,
+                context,
+                definition_base.as_ref().or(base_url),
+                true,
+                true,
+            )?;
+        }
+
+        if let Some(context) = element.get(KW_CONTEXT) {
+            active = self
+                .processor
+                .process(&active, context, base_url, false, true)?;
+        }
+
+        let type_scoped = active.clone();
+        let mut input_type = None;
+        for key in element
+            .keys()
+            .filter(|key| type_scoped.expand_iri(key, false, true).as_deref() == Some(KW_TYPE))
+        {
+            let mut types = as_array(element[key].clone())
+                .into_iter()
+                .filter_map(|value| value.as_str().map(str::to_string))
+                .collect::<Vec<String>>();
+            types.sort();
+            for term in &types {
+                if let Some(definition) = type_scoped.term(term) {
+                    if let Some(context) = definition.context() {
+                        active = self.processor.process(
+                            &active,
+                            context,
+                            definition.base_url(),
+                            false,
+                            false,
+                        )?;
+                    }
+                }
+            }
+            if input_type.is_none() {
+                input_type = types
+                    .last()
+                    .and_then(|last| type_scoped.expand_iri(last, false, true));
+            }
+        }
+
+        let state = MapState {
+            active: &active,
+            type_scoped: &type_scoped,
+            active_property,
+            input_type,
+            base_url,
+        };
+        let mut result = Map::default();
+        self.expand_entries(&state, element, &mut result)?;
+
+        if result.contains_key(KW_VALUE) && self.frame_expansion {
+            // value patterns in a frame may use wildcards, and so are not validated.
+        } else if let Some(value) = result.get(KW_VALUE) {
+            if result.keys().any(|key| {
+                ![KW_DIRECTION, KW_INDEX, KW_LANGUAGE, KW_TYPE, KW_VALUE].contains(&key.as_str())
+            }) || (result.contains_key(KW_TYPE)
+                && (result.contains_key(KW_LANGUAGE) || result.contains_key(KW_DIRECTION)))
+            {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid value object",
+                    Value::Object(result.clone()).to_string(),
+                ));
+            }
+            let type_value = result.get(KW_TYPE).and_then(|t| t.as_str());
+            if type_value == Some(KW_JSON) {
+                // any JSON value is allowed.
+            } else if value.is_null() || value.as_array().map(Vec::is_empty).unwrap_or_default() {
+                return Ok(Value::Null);
+            } else if !value.is_string() && result.contains_key(KW_LANGUAGE) {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid language-tagged value",
+                    value.to_string(),
+                ));
+            } else if let Some(type_value) = result.get(KW_TYPE) {
+                let valid = type_value
+                    .as_str()
+                    .map(|t| t.contains(':') && !t.starts_with("_:"))
+                    .unwrap_or_default();
+                if !valid {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid typed value",
+                        type_value.to_string(),
+                    ));
+                }
+            }
+        } else if let Some(type_value) = result.get_mut(KW_TYPE) {
+            if !type_value.is_array() {
+                *type_value = Value::Array(vec![type_value.take()]);
+            }
+        } else if result.contains_key(KW_SET) || result.contains_key(KW_LIST) {
+            if result.len() > 2 || (result.len() == 2 && !result.contains_key(KW_INDEX)) {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid set or list object",
+                    Value::Object(result.clone()).to_string(),
+                ));
+            }
+            if let Some(set) = result.remove(KW_SET) {
+                return Ok(set);
+            }
+        }
+
+        if result.len() == 1 && result.contains_key(KW_LANGUAGE) {
+            return Ok(Value::Null);
+        }
+
+        if !self.frame_expansion && (active_property.is_none() || active_property == Some(KW_GRAPH))
+        {
+            // drop free-floating values, and node objects with no properties.
+            if result.is_empty()
+                || result.contains_key(KW_VALUE)
+                || result.contains_key(KW_LIST)
+                || (result.len() == 1 && result.contains_key(KW_ID))
+            {
+                return Ok(Value::Null);
+            }
+        }
+
+        Ok(Value::Object(result))
+    }
+
+    fn expand_entries(
+        &self,
+        state: &MapState<'_>,
+        element: &Map<String, Value>,
+        result: &mut Map<String, Value>,
+    ) -> Result<(), Error> {
+        const RULE_FN: &str = "expand";
+
+        let active = state.active;
+        let mut nests = Vec::default();
+
+        for (key, value) in element {
+            if key == KW_CONTEXT {
+                continue;
+            }
+            let expanded_property = match active.expand_iri(key, false, true) {
+                Some(property) if property.contains(':') || property.starts_with('@') => property,
+                _ => continue,
+            };
+
+            if expanded_property.starts_with('@') {
+                if state.active_property == Some(KW_REVERSE) {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid reverse property map",
+                        key,
+                    ));
+                }
+                if result.contains_key(&expanded_property)
+                    && expanded_property != KW_INCLUDED
+                    && expanded_property != KW_TYPE
+                {
+                    return Err(processing_error(RULE_FN, "colliding keywords", key));
+                }
+                let expanded_value = match expanded_property.as_str() {
+                    KW_ID if self.frame_expansion => self.expand_frame_ids(active, value)?,
+                    KW_TYPE if self.frame_expansion => self.expand_frame_types(state, value)?,
+                    KW_VALUE | KW_LANGUAGE if self.frame_expansion => value.clone(),
+                    KW_DEFAULT | KW_EMBED | KW_EXPLICIT | KW_OMIT_DEFAULT | KW_REQUIRE_ALL
+                        if self.frame_expansion =>
+                    {
+                        Value::Array(vec![value.clone()])
+                    }
+                    KW_ID => match value {
+                        Value::String(id) => match active.expand_iri(id, true, false) {
+                            Some(id) => Value::String(id),
+                            None => continue,
+                        },
+                        value => {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid @id value",
+                                value_variant(value),
+                            ));
+                        }
+                    },
+                    KW_TYPE => {
+                        let types = match value {
+                            Value::String(_) => vec![value],
+                            Value::Array(values) if values.iter().all(Value::is_string) => {
+                                values.iter().collect()
+                            }
+                            value => {
+                                return Err(processing_error(
+                                    RULE_FN,
+                                    "invalid type value",
+                                    value.to_string(),
+                                ));
+                            }
+                        };
+                        let expanded = types
+                            .into_iter()
+                            .filter_map(|t| {
+                                state
+                                    .type_scoped
+                                    .expand_iri(t.as_str().unwrap(), true, true)
+                            })
+                            .map(Value::String)
+                            .collect::<Vec<Value>>();
+                        match result.remove(KW_TYPE) {
+                            Some(existing) => {
+                                let mut existing = as_array(existing);
+                                existing.extend(expanded);
+                                Value::Array(existing)
+                            }
+                            None if value.is_string() && expanded.len() == 1 => {
+                                expanded.into_iter().next().unwrap()
+                            }
+                            None => Value::Array(expanded),
+                        }
+                    }
+                    KW_GRAPH => Value::Array(as_array(self.expand(
+                        active,
+                        Some(KW_GRAPH),
+                        value,
+                        state.base_url,
+                        false,
+                    )?)),
+                    KW_INCLUDED => {
+                        let included =
+                            as_array(self.expand(active, None, value, state.base_url, false)?);
+                        if let Some(invalid) = included
+                            .iter()
+                            .find(|v| !v.is_object() || is_value_object(v) || is_list_object(v))
+                        {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid @included value",
+                                invalid.to_string(),
+                            ));
+                        }
+                        let mut existing = as_array(result.remove(KW_INCLUDED).unwrap_or_default());
+                        existing.extend(included);
+                        Value::Array(existing)
+                    }
+                    KW_VALUE => {
+                        if state.input_type.as_deref() == Some(KW_JSON) {
+                            value.clone()
+                        } else if value.is_null() {
+                            let _ = result.insert(KW_VALUE.into(), Value::Null);
+                            continue;
+                        } else if value.is_array() || value.is_object() {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid value object value",
+                                value_variant(value),
+                            ));
+                        } else {
+                            value.clone()
+                        }
+                    }
+                    KW_LANGUAGE => match value {
+                        Value::String(language) => Value::String(language.to_lowercase()),
+                        value => {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid language-tagged string",
+                                value_variant(value),
+                            ));
+                        }
+                    },
+                    KW_DIRECTION => match value.as_str() {
+                        Some("ltr") | Some("rtl") => value.clone(),
+                        _ => {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid base direction",
+                                value.to_string(),
+                            ));
+                        }
+                    },
+                    KW_INDEX => match value {
+                        Value::String(_) => value.clone(),
+                        value => {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid @index value",
+                                value_variant(value),
+                            ));
+                        }
+                    },
+                    KW_LIST => {
+                        if state.active_property.is_none()
+                            || state.active_property == Some(KW_GRAPH)
+                        {
+                            continue;
+                        }
+                        Value::Array(as_array(self.expand(
+                            active,
+                            state.active_property,
+                            value,
+                            state.base_url,
+                            false,
+                        )?))
+                    }
+                    KW_SET => {
+                        self.expand(active, state.active_property, value, state.base_url, false)?
+                    }
+                    KW_REVERSE => {
+                        if !value.is_object() {
+                            return Err(processing_error(
+                                RULE_FN,
+                                "invalid @reverse value",
+                                value_variant(value),
+                            ));
+                        }
+                        let expanded =
+                            self.expand(active, Some(KW_REVERSE), value, state.base_url, false)?;
+                        if let Value::Object(mut expanded) = expanded {
+                            if let Some(Value::Object(reversed)) = expanded.remove(KW_REVERSE) {
+                                for (property, items) in reversed {
+                                    add_value(result, &property, items);
+                                }
+                            }
+                            if !expanded.is_empty() {
+                                let mut reverse_map = match result.remove(KW_REVERSE) {
+                                    Some(Value::Object(map)) => map,
+                                    _ => Map::default(),
+                                };
+                                for (property, items) in expanded {
+                                    for item in as_array(items) {
+                                        if is_value_object(&item) || is_list_object(&item) {
+                                            return Err(processing_error(
+                                                RULE_FN,
+                                                "invalid reverse property value",
+                                                item.to_string(),
+                                            ));
+                                        }
+                                        add_value(&mut reverse_map, &property, item);
+                                    }
+                                }
+                                let _ =
+                                    result.insert(KW_REVERSE.into(), Value::Object(reverse_map));
+                            }
+                        }
+                        continue;
+                    }
+                    KW_NEST => {
+                        nests.push(key);
+                        continue;
+                    }
+                    _ => continue,
+                };
+                let _ = result.insert(expanded_property, expanded_value);
+                continue;
+            }
+
+            let definition = active.term(key);
+            let has_container = |container: &str| {
+                definition
+                    .map(|d| d.has_container(container))
+                    .unwrap_or_default()
+            };
+
+            let expanded_value = if definition
+                .and_then(|d| d.type_mapping())
+                .map(|t| t == KW_JSON)
+                .unwrap_or_default()
+            {
+                let mut json = Map::default();
+                let _ = json.insert(KW_VALUE.into(), value.clone());
+                let _ = json.insert(KW_TYPE.into(), Value::String(KW_JSON.into()));
+                Value::Object(json)
+            } else if has_container(KW_LANGUAGE) && value.is_object() {
+                self.expand_language_map(active, key, value.as_object().unwrap())?
+            } else if (has_container(KW_INDEX) || has_container(KW_TYPE) || has_container(KW_ID))
+                && value.is_object()
+            {
+                self.expand_index_map(state, key, value.as_object().unwrap())?
+            } else {
+                self.expand(active, Some(key), value, state.base_url, false)?
+            };
+
+            if expanded_value.is_null() {
+                continue;
+            }
+
+            let expanded_value = if has_container(KW_LIST) && !is_list_object(&expanded_value) {
+                let mut list = Map::default();
+                let _ = list.insert(KW_LIST.into(), Value::Array(as_array(expanded_value)));
+                Value::Object(list)
+            } else {
+                expanded_value
+            };
+
+            let expanded_value =
+                if has_container(KW_GRAPH) && !has_container(KW_ID) && !has_container(KW_INDEX) {
+                    Value::Array(
+                        as_array(expanded_value)
+                            .into_iter()
+                            .map(|item| {
+                                let mut graph = Map::default();
+                                let _ = graph.insert(KW_GRAPH.into(), Value::Array(as_array(item)));
+                                Value::Object(graph)
+                            })
+                            .collect(),
+                    )
+                } else {
+                    expanded_value
+                };
+
+            if definition.map(|d| d.is_reverse()).unwrap_or_default() {
+                let reverse_map = result
+                    .entry(KW_REVERSE.to_string())
+                    .or_insert_with(|| Value::Object(Map::default()))
+                    .as_object_mut()
+                    .unwrap();
+                for item in as_array(expanded_value) {
+                    if is_value_object(&item) || is_list_object(&item) {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid reverse property value",
+                            item.to_string(),
+                        ));
+                    }
+                    add_value(reverse_map, &expanded_property, item);
+                }
+            } else {
+                add_value(result, &expanded_property, expanded_value);
+            }
+        }
+
+        for nesting_key in nests {
+            for nested_value in as_array(element[nesting_key].clone()) {
+                match nested_value {
+                    Value::Object(nested)
+                        if !nested.keys().any(|key| {
+                            active.expand_iri(key, false, true).as_deref() == Some(KW_VALUE)
+                        }) =>
+                    {
+                        self.expand_entries(state, &nested, result)?;
+                    }
+                    nested_value => {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid @nest value",
+                            nested_value.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// In a frame `@id` is always an array, of IRIs or the wildcard `{}`.
+    ///
+    fn expand_frame_ids(&self, active: &Context, value: &Value) -> Result<Value, Error> {
+        const RULE_FN: &str = "expand_frame_ids";
+
+        let mut result = Vec::default();
+        for id in as_array(value.clone()) {
+            match id {
+                Value::String(id) => {
+                    if let Some(id) = active.expand_iri(&id, true, false) {
+                        result.push(Value::String(id));
+                    }
+                }
+                Value::Object(wildcard) if wildcard.is_empty() => {
+                    result.push(Value::Object(wildcard));
+                }
+                id => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid @id value",
+                        value_variant(&id),
+                    ));
+                }
+            }
+        }
+        Ok(Value::Array(result))
+    }
+
+    ///
+    /// In a frame `@type` is always an array, of IRIs, the wildcard `{}`, or a single default
+    /// type `{"@default": type}`.
+    ///
+    fn expand_frame_types(&self, state: &MapState<'_>, value: &Value) -> Result<Value, Error> {
+        const RULE_FN: &str = "expand_frame_types";
+
+        let mut result = Vec::default();
+        for type_value in as_array(value.clone()) {
+            match type_value {
+                Value::String(type_value) => {
+                    if let Some(type_value) = state.type_scoped.expand_iri(&type_value, true, true)
+                    {
+                        result.push(Value::String(type_value));
+                    }
+                }
+                Value::Object(wildcard) if wildcard.is_empty() => {
+                    result.push(Value::Object(wildcard));
+                }
+                Value::Object(default)
+                    if default.len() == 1
+                        && default.get(KW_DEFAULT).is_some_and(Value::is_string) =>
+                {
+                    let type_value = default[KW_DEFAULT].as_str().unwrap();
+                    let mut default = Map::default();
+                    if let Some(type_value) = state.type_scoped.expand_iri(type_value, true, true) {
+                        let _ = default.insert(KW_DEFAULT.into(), Value::String(type_value));
+                    }
+                    result.push(Value::Object(default));
+                }
+                type_value => {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid type value",
+                        type_value.to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(Value::Array(result))
+    }
+
+    fn expand_language_map(
+        &self,
+        active: &Context,
+        key: &str,
+        value: &Map<String, Value>,
+    ) -> Result<Value, Error> {
+        const RULE_FN: &str = "expand_language_map";
+
+        let direction = match active.term(key).and_then(|d| d.direction()) {
+            Some(direction) => direction.clone(),
+            None => active.default_direction().cloned(),
+        };
+        let mut result = Vec::default();
+        for (language, language_value) in value {
+            for item in as_array(language_value.clone()) {
+                match item {
+                    Value::Null => {}
+                    Value::String(_) => {
+                        let mut v = Map::default();
+                        let _ = v.insert(KW_VALUE.into(), item);
+                        if active.expand_iri(language, false, true).as_deref() != Some(KW_NONE) {
+                            let _ = v
+                                .insert(KW_LANGUAGE.into(), Value::String(language.to_lowercase()));
+                        }
+                        if let Some(direction) = &direction {
+                            let _ = v.insert(KW_DIRECTION.into(), Value::String(direction.clone()));
+                        }
+                        result.push(Value::Object(v));
+                    }
+                    item => {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid language map value",
+                            value_variant(&item),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(Value::Array(result))
+    }
+
+    fn expand_index_map(
+        &self,
+        state: &MapState<'_>,
+        key: &str,
+        value: &Map<String, Value>,
+    ) -> Result<Value, Error> {
+        const RULE_FN: &str = "expand_index_map";
+
+        let active = state.active;
+        let definition = active.term(key).unwrap();
+        let index_key = definition.index().map(String::as_str).unwrap_or(KW_INDEX);
+        let is_id_or_type = definition.has_container(KW_ID) || definition.has_container(KW_TYPE);
+
+        let mut result = Vec::default();
+        for (index, index_value) in value {
+            let mut map_context = match (is_id_or_type, active.previous()) {
+                (true, Some(previous)) => previous.clone(),
+                _ => active.clone(),
+            };
+            if definition.has_container(KW_TYPE) {
+                if let Some(index_definition) = map_context.term(index) {
+                    if let Some(context) = index_definition.context() {
+                        let base = index_definition.base_url().cloned();
+                        map_context = self.processor.process(
+                            &map_context,
+                            context,
+                            base.as_ref(),
+                            false,
+                            true,
+                        )?;
+                    }
+                }
+            }
+            let expanded_index = active.expand_iri(index, false, true);
+            let is_none = expanded_index.as_deref() == Some(KW_NONE);
+            let index_value = Value::Array(as_array(index_value.clone()));
+            let items = as_array(self.expand(
+                &map_context,
+                Some(key),
+                &index_value,
+                state.base_url,
+                true,
+            )?);
+            for item in items {
+                let mut item = if definition.has_container(KW_GRAPH) && !is_graph_object(&item) {
+                    let mut graph = Map::default();
+                    let _ = graph.insert(KW_GRAPH.into(), Value::Array(as_array(item)));
+                    Value::Object(graph)
+                } else {
+                    item
+                };
+                let Some(item_map) = item.as_object_mut() else {
+                    return Err(processing_error(
+                        RULE_FN,
+                        "invalid value object",
+                        item.to_string(),
+                    ));
+                };
+                if definition.has_container(KW_INDEX) && index_key != KW_INDEX && !is_none {
+                    let re_expanded =
+                        self.expand_value(active, index_key, &Value::String(index.clone()))?;
+                    let expanded_index_key = active
+                        .expand_iri(index_key, false, true)
+                        .unwrap_or_default();
+                    let mut values = vec![re_expanded];
+                    values.extend(as_array(
+                        item_map.remove(&expanded_index_key).unwrap_or_default(),
+                    ));
+                    let _ = item_map.insert(expanded_index_key, Value::Array(values));
+                    if item_map.contains_key(KW_VALUE) && item_map.len() > 1 {
+                        return Err(processing_error(
+                            RULE_FN,
+                            "invalid value object",
+                            item.to_string(),
+                        ));
+                    }
+                } else if definition.has_container(KW_INDEX)
+                    && !item_map.contains_key(KW_INDEX)
+                    && !is_none
+                {
+                    let _ = item_map.insert(KW_INDEX.into(), Value::String(index.clone()));
+                } else if definition.has_container(KW_ID)
+                    && !item_map.contains_key(KW_ID)
+                    && !is_none
+                {
+                    let id = active.expand_iri(index, true, false).unwrap_or_default();
+                    let _ = item_map.insert(KW_ID.into(), Value::String(id));
+                } else if definition.has_container(KW_TYPE) && !is_none {
+                    let mut types = vec![Value::String(expanded_index.clone().unwrap_or_default())];
+                    types.extend(as_array(item_map.remove(KW_TYPE).unwrap_or_default()));
+                    let _ = item_map.insert(KW_TYPE.into(), Value::Array(types));
+                }
+                result.push(item);
+            }
+        }
+        Ok(Value::Array(result))
+    }
+
+    ///
+    /// Implements [Value Expansion](https://www.w3.org/TR/json-ld11-api/#value-expansion).
+    ///
+    pub(super) fn expand_value(
+        &self,
+        active: &Context,
+        active_property: &str,
+        value: &Value,
+    ) -> Result<Value, Error> {
+        let definition = active.term(active_property);
+        let type_mapping = definition.and_then(|d| d.type_mapping());
+        let mut result = Map::default();
+        match (type_mapping.map(String::as_str), value) {
+            (Some(KW_ID), Value::String(id)) => {
+                let id = active.expand_iri(id, true, false);
+                let _ = result.insert(KW_ID.into(), id.map(Value::String).unwrap_or_default());
+            }
+            (Some(KW_VOCAB), Value::String(id)) => {
+                let id = active.expand_iri(id, true, true);
+                let _ = result.insert(KW_ID.into(), id.map(Value::String).unwrap_or_default());
+            }
+            (type_mapping, value) => {
+                let _ = result.insert(KW_VALUE.into(), value.clone());
+                match type_mapping {
+                    Some(type_mapping)
+                        if type_mapping != KW_ID
+                            && type_mapping != KW_VOCAB
+                            && type_mapping != KW_NONE =>
+                    {
+                        let _ = result.insert(KW_TYPE.into(), Value::String(type_mapping.into()));
+                    }
+                    _ if value.is_string() => {
+                        let language = match definition.and_then(|d| d.language()) {
+                            Some(language) => language.clone(),
+                            None => active.default_language().cloned(),
+                        };
+                        if let Some(language) = language {
+                            let _ = result.insert(KW_LANGUAGE.into(), Value::String(language));
+                        }
+                        let direction = match definition.and_then(|d| d.direction()) {
+                            Some(direction) => direction.clone(),
+                            None => active.default_direction().cloned(),
+                        };
+                        if let Some(direction) = direction {
+                            let _ = result.insert(KW_DIRECTION.into(), Value::String(direction));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(Value::Object(result))
+    }
+}
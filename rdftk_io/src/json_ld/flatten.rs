@@ -0,0 +1,284 @@
+use super::syntax::{
+    as_array, is_blank_node_id, BNODE_PREFIX, KW_DEFAULT, KW_GRAPH, KW_ID, KW_INCLUDED, KW_INDEX,
+    KW_LIST, KW_REVERSE, KW_TYPE, KW_VALUE,
+};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A map from node identifier to node object, ordered by identifier.
+///
+pub(super) type NodeMap = BTreeMap<String, Map<String, Value>>;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generates node maps for all graphs in a document, relabeling blank nodes as `_:b0`, `_:b1`,
+/// etc. in the order they are encountered.
+///
+#[derive(Debug, Default)]
+struct NodeMapGenerator {
+    graphs: BTreeMap<String, NodeMap>,
+    blank_nodes: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+enum ActiveSubject {
+    None,
+    Node(String),
+    Reverse(String),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Flatten an expanded document, see
+/// [Flattening Algorithm](https://www.w3.org/TR/json-ld11-api/#flattening-algorithm). Every node
+/// is moved to the top level, or the top level of its named graph, and nodes are ordered by
+/// identifier.
+///
+pub(super) fn flatten(expanded: &Value) -> Value {
+    let mut graphs = node_maps(expanded);
+    let mut default_graph = graphs.remove(KW_DEFAULT).unwrap_or_default();
+    for (graph_name, graph) in graphs {
+        let _ = node_entry(&mut default_graph, &graph_name).insert(
+            KW_GRAPH.to_string(),
+            Value::Array(
+                graph
+                    .into_values()
+                    .filter(|node| !is_reference(node))
+                    .map(Value::Object)
+                    .collect(),
+            ),
+        );
+    }
+    Value::Array(
+        default_graph
+            .into_values()
+            .filter(|node| !is_reference(node))
+            .map(Value::Object)
+            .collect(),
+    )
+}
+
+///
+/// Generate the node maps for every graph in an expanded document, keyed by graph name with the
+/// default graph named `@default`.
+///
+pub(super) fn node_maps(expanded: &Value) -> BTreeMap<String, NodeMap> {
+    let mut generator = NodeMapGenerator::default();
+    let _ = generator.graphs.entry(KW_DEFAULT.to_string()).or_default();
+    generator.generate(expanded, KW_DEFAULT, &ActiveSubject::None, None, None);
+    generator.graphs
+}
+
+///
+/// Returns the node with identifier `id`, creating it if not present.
+///
+pub(super) fn node_entry<'a>(node_map: &'a mut NodeMap, id: &str) -> &'a mut Map<String, Value> {
+    node_map.entry(id.to_string()).or_insert_with(|| {
+        let mut node = Map::default();
+        let _ = node.insert(KW_ID.to_string(), Value::String(id.to_string()));
+        node
+    })
+}
+
+///
+/// Add `value` to the array `key` unless it is already present, returning its index if added.
+///
+pub(super) fn add_unique(node: &mut Map<String, Value>, key: &str, value: Value) -> Option<usize> {
+    let entry = node
+        .entry(key.to_string())
+        .or_insert_with(|| Value::Array(Vec::default()));
+    if !entry.is_array() {
+        *entry = Value::Array(vec![entry.take()]);
+    }
+    let Value::Array(values) = entry else {
+        unreachable!()
+    };
+    if values.contains(&value) {
+        None
+    } else {
+        values.push(value);
+        Some(values.len() - 1)
+    }
+}
+
+///
+/// Returns `true` if the node has no content other than its identifier.
+///
+pub(super) fn is_reference(node: &Map<String, Value>) -> bool {
+    node.len() == 1 && node.contains_key(KW_ID)
+}
+
+pub(super) fn reference(id: &str) -> Value {
+    let mut reference = Map::default();
+    let _ = reference.insert(KW_ID.to_string(), Value::String(id.to_string()));
+    Value::Object(reference)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl NodeMapGenerator {
+    fn blank_node(&mut self, id: Option<&str>) -> String {
+        let next = format!("{BNODE_PREFIX}b{}", self.blank_nodes.len());
+        match id {
+            Some(id) => self
+                .blank_nodes
+                .entry(id.to_string())
+                .or_insert(next)
+                .clone(),
+            None => {
+                // reserve the label so it cannot be issued again.
+                let _ = self.blank_nodes.insert(next.clone(), next.clone());
+                next
+            }
+        }
+    }
+
+    fn relabel(&mut self, id: &str) -> String {
+        if is_blank_node_id(id) {
+            self.blank_node(Some(id))
+        } else {
+            id.to_string()
+        }
+    }
+
+    ///
+    /// See [Node Map Generation](https://www.w3.org/TR/json-ld11-api/#node-map-generation).
+    ///
+    fn generate(
+        &mut self,
+        element: &Value,
+        graph_name: &str,
+        subject: &ActiveSubject,
+        property: Option<&str>,
+        mut list: Option<&mut Vec<Value>>,
+    ) {
+        let element = match element {
+            Value::Array(elements) => {
+                for element in elements {
+                    self.generate(element, graph_name, subject, property, list.as_deref_mut());
+                }
+                return;
+            }
+            Value::Object(element) => element,
+            _ => return,
+        };
+
+        let mut types = Vec::default();
+        for type_value in as_array(element.get(KW_TYPE).cloned().unwrap_or_default()) {
+            types.push(match type_value {
+                Value::String(type_value) => Value::String(self.relabel(&type_value)),
+                type_value => type_value,
+            });
+        }
+
+        if element.contains_key(KW_VALUE) {
+            let mut element = element.clone();
+            if !types.is_empty() {
+                let _ = element.insert(KW_TYPE.to_string(), types.remove(0));
+            }
+            self.add_to_subject(graph_name, subject, property, list, Value::Object(element));
+        } else if let Some(members) = element.get(KW_LIST) {
+            let mut result = Vec::default();
+            self.generate(members, graph_name, subject, property, Some(&mut result));
+            let mut list_object = Map::default();
+            let _ = list_object.insert(KW_LIST.to_string(), Value::Array(result));
+            match (list, subject, property) {
+                (Some(list), _, _) => list.push(Value::Object(list_object)),
+                (None, ActiveSubject::Node(subject), Some(property)) => {
+                    let node = node_entry(self.node_map(graph_name), subject);
+                    if let Value::Array(values) = node
+                        .entry(property.to_string())
+                        .or_insert_with(|| Value::Array(Vec::default()))
+                    {
+                        values.push(Value::Object(list_object));
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            let id = match element.get(KW_ID).and_then(Value::as_str) {
+                Some(id) => self.relabel(id),
+                None => self.blank_node(None),
+            };
+            let _ = node_entry(self.node_map(graph_name), &id);
+
+            match (subject, property) {
+                (ActiveSubject::Reverse(referenced), Some(property)) => {
+                    let node = node_entry(self.node_map(graph_name), &id);
+                    let _ = add_unique(node, property, reference(referenced));
+                }
+                (ActiveSubject::Node(_), Some(_)) => {
+                    self.add_to_subject(graph_name, subject, property, list, reference(&id));
+                }
+                _ => {}
+            }
+
+            let node = node_entry(self.node_map(graph_name), &id);
+            for type_value in types {
+                let _ = add_unique(node, KW_TYPE, type_value);
+            }
+            if let Some(index) = element.get(KW_INDEX) {
+                let _ = node.insert(KW_INDEX.to_string(), index.clone());
+            }
+
+            if let Some(Value::Object(reverse)) = element.get(KW_REVERSE) {
+                let referenced = ActiveSubject::Reverse(id.clone());
+                for (property, values) in reverse {
+                    self.generate(values, graph_name, &referenced, Some(property), None);
+                }
+            }
+            if let Some(graph) = element.get(KW_GRAPH) {
+                let _ = self.node_map(&id);
+                self.generate(graph, &id, &ActiveSubject::None, None, None);
+            }
+            if let Some(included) = element.get(KW_INCLUDED) {
+                self.generate(included, graph_name, &ActiveSubject::None, None, None);
+            }
+
+            let node_subject = ActiveSubject::Node(id.clone());
+            for (property, value) in element.iter().filter(|(key, _)| !key.starts_with('@')) {
+                let property = self.relabel(property);
+                let node = node_entry(self.node_map(graph_name), &id);
+                let _ = node
+                    .entry(property.clone())
+                    .or_insert_with(|| Value::Array(Vec::default()));
+                self.generate(value, graph_name, &node_subject, Some(&property), None);
+            }
+        }
+    }
+
+    fn node_map(&mut self, graph_name: &str) -> &mut NodeMap {
+        self.graphs.entry(graph_name.to_string()).or_default()
+    }
+
+    fn add_to_subject(
+        &mut self,
+        graph_name: &str,
+        subject: &ActiveSubject,
+        property: Option<&str>,
+        list: Option<&mut Vec<Value>>,
+        value: Value,
+    ) {
+        match (list, subject, property) {
+            (Some(list), _, _) => list.push(value),
+            (None, ActiveSubject::Node(subject), Some(property)) => {
+                let node = node_entry(self.node_map(graph_name), subject);
+                let _ = add_unique(node, property, value);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,652 @@
+use super::flatten::{add_unique, is_reference, node_entry, node_maps, reference, NodeMap};
+use super::syntax::{
+    add_value, as_array, is_blank_node_id, is_keyword, is_list_object, processing_error,
+    value_variant, KW_CONTEXT, KW_DEFAULT, KW_EMBED, KW_EXPLICIT, KW_GRAPH, KW_ID, KW_LANGUAGE,
+    KW_LIST, KW_OMIT_DEFAULT, KW_PRESERVE, KW_REQUIRE_ALL, KW_REVERSE, KW_TYPE, KW_VALUE,
+};
+use super::writer::JsonLdEmbed;
+use rdftk_core::error::Error;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The values of the framing flags used when a frame does not specify its own.
+///
+#[derive(Clone, Copy, Debug)]
+pub(super) struct FrameFlags {
+    pub(super) embed: JsonLdEmbed,
+    pub(super) explicit: bool,
+    pub(super) omit_default: bool,
+    pub(super) require_all: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The state of a single framing operation; node maps are keyed by graph name, and include the
+/// merged graph `@merged`.
+///
+#[derive(Debug)]
+struct FramingState<'a> {
+    defaults: FrameFlags,
+    graphs: &'a BTreeMap<String, NodeMap>,
+    unique_embeds: HashMap<String, HashSet<String>>,
+    subject_stack: Vec<(String, String)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const KW_MERGED: &str = "@merged";
+const KW_NULL: &str = "@null";
+const KW_ALWAYS: &str = "@always";
+const KW_ONCE: &str = "@once";
+const KW_NEVER: &str = "@never";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Frame an expanded document using an expanded frame, see
+/// [Framing Algorithm](https://www.w3.org/TR/json-ld11-framing/#framing-algorithm). Nodes from
+/// all graphs are matched, and blank node identifiers used only once are removed from nodes.
+///
+/// The result is in expanded form, default values are wrapped in `@preserve` entries which are
+/// removed by [`remove_preserve`] once the result has been compacted.
+///
+pub(super) fn frame_document(
+    expanded: &Value,
+    expanded_frame: &Value,
+    defaults: FrameFlags,
+) -> Result<Vec<Value>, Error> {
+    const RULE_FN: &str = "frame_document";
+    trace!("{RULE_FN}(...)");
+
+    let frame = match expanded_frame {
+        Value::Array(frames) if frames.len() > 1 => {
+            return Err(processing_error(
+                RULE_FN,
+                "invalid frame",
+                expanded_frame.to_string(),
+            ));
+        }
+        frames => single_frame(frames)?,
+    };
+
+    let mut graphs = node_maps(expanded);
+    let merged = merge_node_maps(&graphs);
+    let subjects = merged.keys().cloned().collect::<Vec<_>>();
+    let _ = graphs.insert(KW_MERGED.to_string(), merged);
+
+    let mut state = FramingState {
+        defaults,
+        graphs: &graphs,
+        unique_embeds: Default::default(),
+        subject_stack: Default::default(),
+    };
+    let mut framed = state.frame(KW_MERGED, &subjects, &frame, false, true)?;
+
+    let mut counts = HashMap::default();
+    for value in &framed {
+        count_blank_node_ids(value, &mut counts);
+    }
+    for value in &mut framed {
+        prune_blank_node_ids(value, &counts);
+    }
+    Ok(framed)
+}
+
+///
+/// Replace the `@preserve` entries in a compacted, framed, document with their values; the
+/// value `@null` becomes `null`, and is removed from arrays. Any `@context` entry is unchanged.
+///
+pub(super) fn remove_preserve(value: Value) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(remove_preserve)
+                .filter(|value| !value.is_null())
+                .collect(),
+        ),
+        Value::Object(mut map) => match map.remove(KW_PRESERVE) {
+            Some(preserved) => remove_preserve(preserved),
+            None => Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| {
+                        if key == KW_CONTEXT {
+                            (key, value)
+                        } else {
+                            (key, remove_preserve(value))
+                        }
+                    })
+                    .collect(),
+            ),
+        },
+        Value::String(value) if value == KW_NULL => Value::Null,
+        value => value,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FramingState<'_> {
+    ///
+    /// Frame the nodes identified by `subjects` in `graph`, returning the output for each match.
+    /// Embedded nodes may be replaced by references depending on the `@embed` flag.
+    ///
+    fn frame(
+        &mut self,
+        graph: &str,
+        subjects: &[String],
+        frame: &Map<String, Value>,
+        embedded: bool,
+        top_level: bool,
+    ) -> Result<Vec<Value>, Error> {
+        let flags = FrameFlags {
+            embed: embed_flag(frame, self.defaults.embed)?,
+            explicit: bool_flag(frame, KW_EXPLICIT, self.defaults.explicit),
+            omit_default: bool_flag(frame, KW_OMIT_DEFAULT, self.defaults.omit_default),
+            require_all: bool_flag(frame, KW_REQUIRE_ALL, self.defaults.require_all),
+        };
+        let graphs = self.graphs;
+        let node_map = &graphs[graph];
+
+        let mut result = Vec::default();
+        for id in subjects {
+            let Some(subject) = node_map.get(id) else {
+                continue;
+            };
+            if !filter_subject(node_map, subject, frame, flags.require_all) {
+                continue;
+            }
+
+            // each top-level match is framed independently of the others.
+            if top_level {
+                self.unique_embeds.clear();
+            }
+            let unique_embeds = self.unique_embeds.entry(graph.to_string()).or_default();
+            if !embedded && unique_embeds.contains(id) {
+                continue;
+            }
+            let is_circular = self
+                .subject_stack
+                .iter()
+                .any(|(stack_id, stack_graph)| stack_id == id && stack_graph == graph);
+            if embedded
+                && (flags.embed == JsonLdEmbed::Never
+                    || is_circular
+                    || (flags.embed == JsonLdEmbed::Once && unique_embeds.contains(id)))
+            {
+                result.push(reference(id));
+                continue;
+            }
+            let _ = unique_embeds.insert(id.clone());
+
+            self.subject_stack.push((id.clone(), graph.to_string()));
+            let output = self.frame_subject(graph, id, subject, frame, &flags)?;
+            let _ = self.subject_stack.pop();
+            result.push(Value::Object(output));
+        }
+        Ok(result)
+    }
+
+    fn frame_subject(
+        &mut self,
+        graph: &str,
+        id: &str,
+        subject: &Map<String, Value>,
+        frame: &Map<String, Value>,
+        flags: &FrameFlags,
+    ) -> Result<Map<String, Value>, Error> {
+        let graphs = self.graphs;
+        let mut output = Map::default();
+        let _ = output.insert(KW_ID.to_string(), Value::String(id.to_string()));
+
+        // the subject also names a graph, nodes in the merged graph are only framed if requested.
+        if let Some(named_graph) = graphs.get(id) {
+            let subframe = match frame.get(KW_GRAPH) {
+                None if graph == KW_MERGED => None,
+                None => Some(Map::default()),
+                Some(frames) => Some(single_frame(frames)?),
+            };
+            if let Some(subframe) = subframe {
+                let subjects = named_graph.keys().cloned().collect::<Vec<_>>();
+                for framed in self.frame(id, &subjects, &subframe, false, false)? {
+                    add_value(&mut output, KW_GRAPH, framed);
+                }
+            }
+        }
+
+        for (property, values) in subject {
+            if is_keyword(property) {
+                let _ = output.insert(property.clone(), values.clone());
+                continue;
+            }
+            if flags.explicit && !frame.contains_key(property) {
+                continue;
+            }
+            let subframe = match frame.get(property) {
+                Some(frames) => single_frame(frames)?,
+                None => implicit_frame(flags),
+            };
+            for value in as_array(values.clone()) {
+                if is_list_object(&value) {
+                    let list_frame = match subframe.get(KW_LIST) {
+                        Some(frames) => single_frame(frames)?,
+                        None => implicit_frame(flags),
+                    };
+                    let mut list = Vec::default();
+                    for item in as_array(value[KW_LIST].clone()) {
+                        match item.as_object() {
+                            Some(item) if is_reference(item) => {
+                                let item_id = item[KW_ID].as_str().unwrap_or_default();
+                                list.extend(self.frame(
+                                    graph,
+                                    &[item_id.to_string()],
+                                    &list_frame,
+                                    true,
+                                    false,
+                                )?);
+                            }
+                            _ => list.push(item),
+                        }
+                    }
+                    let mut list_object = Map::default();
+                    let _ = list_object.insert(KW_LIST.to_string(), Value::Array(list));
+                    add_value(&mut output, property, Value::Object(list_object));
+                } else if value.as_object().map(is_reference).unwrap_or_default() {
+                    let value_id = value[KW_ID].as_str().unwrap_or_default();
+                    for framed in
+                        self.frame(graph, &[value_id.to_string()], &subframe, true, false)?
+                    {
+                        add_value(&mut output, property, framed);
+                    }
+                } else if value_match(&subframe, &value) {
+                    add_value(&mut output, property, value);
+                }
+            }
+        }
+
+        // add default values for properties in the frame but not in the output.
+        for (property, frames) in frame {
+            let next = frames
+                .as_array()
+                .and_then(|frames| frames.first())
+                .and_then(Value::as_object);
+            if property == KW_TYPE {
+                let default_type = next.and_then(|next| next.get(KW_DEFAULT));
+                if let (false, Some(default_type)) = (output.contains_key(KW_TYPE), default_type) {
+                    let _ = output.insert(
+                        KW_TYPE.to_string(),
+                        Value::Array(vec![default_type.clone()]),
+                    );
+                }
+                continue;
+            } else if is_keyword(property) || output.contains_key(property) {
+                continue;
+            }
+            let next = next.cloned().unwrap_or_default();
+            if !bool_flag(&next, KW_OMIT_DEFAULT, flags.omit_default) {
+                let preserve = match next
+                    .get(KW_DEFAULT)
+                    .and_then(Value::as_array)
+                    .and_then(|default| default.first())
+                {
+                    Some(default) => as_array(default.clone()),
+                    None => vec![Value::String(KW_NULL.to_string())],
+                };
+                let mut preserve_object = Map::default();
+                let _ = preserve_object.insert(KW_PRESERVE.to_string(), Value::Array(preserve));
+                add_value(&mut output, property, Value::Object(preserve_object));
+            }
+        }
+
+        // embed nodes that reference this subject.
+        if let Some(Value::Object(reverse_frame)) = frame.get(KW_REVERSE) {
+            let node_map = &graphs[graph];
+            for (reverse_property, frames) in reverse_frame {
+                let subframe = single_frame(frames)?;
+                for (reverse_id, reverse_node) in node_map {
+                    let is_referenced = reverse_node
+                        .get(reverse_property)
+                        .and_then(Value::as_array)
+                        .map(|values| {
+                            values
+                                .iter()
+                                .any(|value| value.get(KW_ID).and_then(Value::as_str) == Some(id))
+                        })
+                        .unwrap_or_default();
+                    if is_referenced {
+                        let framed = self.frame(
+                            graph,
+                            std::slice::from_ref(reverse_id),
+                            &subframe,
+                            true,
+                            false,
+                        )?;
+                        let reverse = output
+                            .entry(KW_REVERSE.to_string())
+                            .or_insert_with(|| Value::Object(Map::default()));
+                        if let Value::Object(reverse) = reverse {
+                            add_value(reverse, reverse_property, Value::Array(framed));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// See [Merge Node Maps](https://www.w3.org/TR/json-ld11-api/#merge-node-maps).
+///
+fn merge_node_maps(graphs: &BTreeMap<String, NodeMap>) -> NodeMap {
+    let mut merged = NodeMap::default();
+    for node_map in graphs.values() {
+        for (id, node) in node_map {
+            let merged_node = node_entry(&mut merged, id);
+            for (property, values) in node {
+                if property != KW_TYPE && is_keyword(property) {
+                    let _ = merged_node.insert(property.clone(), values.clone());
+                } else {
+                    let _ = merged_node
+                        .entry(property.clone())
+                        .or_insert_with(|| Value::Array(Vec::default()));
+                    for value in as_array(values.clone()) {
+                        let _ = add_unique(merged_node, property, value);
+                    }
+                }
+            }
+        }
+    }
+    merged
+}
+
+///
+/// Returns the single frame in an array of frames, an empty array is treated as the wildcard
+/// frame `{}`.
+///
+fn single_frame(frames: &Value) -> Result<Map<String, Value>, Error> {
+    const RULE_FN: &str = "single_frame";
+
+    match frames {
+        Value::Object(frame) => Ok(frame.clone()),
+        Value::Array(frames) => match frames.first() {
+            None => Ok(Map::default()),
+            Some(Value::Object(frame)) => Ok(frame.clone()),
+            Some(frame) => Err(processing_error(
+                RULE_FN,
+                "invalid frame",
+                value_variant(frame),
+            )),
+        },
+        frame => Err(processing_error(
+            RULE_FN,
+            "invalid frame",
+            value_variant(frame),
+        )),
+    }
+}
+
+///
+/// The frame used for properties not present in the current frame, it only carries the current
+/// flags.
+///
+fn implicit_frame(flags: &FrameFlags) -> Map<String, Value> {
+    let embed = match flags.embed {
+        JsonLdEmbed::Always => KW_ALWAYS,
+        JsonLdEmbed::Once => KW_ONCE,
+        JsonLdEmbed::Never => KW_NEVER,
+    };
+    let mut frame = Map::default();
+    let _ = frame.insert(
+        KW_EMBED.to_string(),
+        Value::Array(vec![Value::String(embed.to_string())]),
+    );
+    let _ = frame.insert(
+        KW_EXPLICIT.to_string(),
+        Value::Array(vec![Value::Bool(flags.explicit)]),
+    );
+    let _ = frame.insert(
+        KW_REQUIRE_ALL.to_string(),
+        Value::Array(vec![Value::Bool(flags.require_all)]),
+    );
+    frame
+}
+
+fn frame_flag<'a>(frame: &'a Map<String, Value>, flag: &str) -> Option<&'a Value> {
+    frame.get(flag).map(|value| match value {
+        Value::Array(values) => values.first().unwrap_or(value),
+        value => value,
+    })
+}
+
+fn bool_flag(frame: &Map<String, Value>, flag: &str, default: bool) -> bool {
+    frame_flag(frame, flag)
+        .and_then(Value::as_bool)
+        .unwrap_or(default)
+}
+
+fn embed_flag(frame: &Map<String, Value>, default: JsonLdEmbed) -> Result<JsonLdEmbed, Error> {
+    const RULE_FN: &str = "embed_flag";
+
+    match frame_flag(frame, KW_EMBED) {
+        None => Ok(default),
+        Some(Value::Bool(true)) => Ok(JsonLdEmbed::Once),
+        Some(Value::Bool(false)) => Ok(JsonLdEmbed::Never),
+        Some(Value::String(embed)) if embed == KW_ALWAYS => Ok(JsonLdEmbed::Always),
+        Some(Value::String(embed)) if embed == KW_ONCE => Ok(JsonLdEmbed::Once),
+        Some(Value::String(embed)) if embed == KW_NEVER => Ok(JsonLdEmbed::Never),
+        Some(embed) => Err(processing_error(
+            RULE_FN,
+            "invalid @embed value",
+            embed.to_string(),
+        )),
+    }
+}
+
+fn is_wildcard(value: Option<&Value>) -> bool {
+    matches!(value, Some(Value::Object(map)) if map.is_empty())
+}
+
+///
+/// Returns `true` if the subject matches the frame, see
+/// [Frame Matching](https://www.w3.org/TR/json-ld11-framing/#frame-matching).
+///
+fn filter_subject(
+    node_map: &NodeMap,
+    subject: &Map<String, Value>,
+    frame: &Map<String, Value>,
+    require_all: bool,
+) -> bool {
+    let mut wildcard = true;
+    let mut matches_some = false;
+
+    for (key, frame_values) in frame {
+        let frame_values = frame_values
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let node_values = subject
+            .get(key)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let matched = match key.as_str() {
+            KW_ID => {
+                let id = subject.get(KW_ID);
+                let matched = is_wildcard(frame_values.first())
+                    || frame_values.iter().any(|value| Some(value) == id);
+                if !require_all {
+                    return matched;
+                }
+                matched
+            }
+            KW_TYPE => {
+                wildcard = false;
+                if frame_values.is_empty() {
+                    if !node_values.is_empty() {
+                        return false;
+                    }
+                    true
+                } else if frame_values.len() == 1 && is_wildcard(frame_values.first()) {
+                    !node_values.is_empty()
+                } else {
+                    let matched = frame_values.iter().any(|type_value| {
+                        type_value.get(KW_DEFAULT).is_some() || node_values.contains(type_value)
+                    });
+                    if !require_all {
+                        return matched;
+                    }
+                    matched
+                }
+            }
+            key if is_keyword(key) => continue,
+            _ => {
+                wildcard = false;
+                let property_frame = frame_values.first().and_then(Value::as_object);
+                let has_default = property_frame
+                    .map(|frame| frame.contains_key(KW_DEFAULT))
+                    .unwrap_or_default();
+                if node_values.is_empty() && has_default {
+                    continue;
+                }
+                if !node_values.is_empty() && frame_values.is_empty() {
+                    return false;
+                }
+                match property_frame {
+                    None => node_values.is_empty(),
+                    Some(property_frame) if property_frame.contains_key(KW_LIST) => {
+                        node_values.first().map(is_list_object).unwrap_or_default()
+                    }
+                    Some(property_frame) if property_frame.contains_key(KW_VALUE) => node_values
+                        .iter()
+                        .any(|value| value_match(property_frame, value)),
+                    Some(property_frame) if is_reference(property_frame) => {
+                        node_values.iter().any(|value| {
+                            value
+                                .get(KW_ID)
+                                .and_then(Value::as_str)
+                                .and_then(|id| node_map.get(id))
+                                .map(|node| {
+                                    filter_subject(node_map, node, property_frame, require_all)
+                                })
+                                .unwrap_or_default()
+                        })
+                    }
+                    Some(_) => !node_values.is_empty(),
+                }
+            }
+        };
+
+        if !matched && require_all {
+            return false;
+        }
+        matches_some = matches_some || matched;
+    }
+
+    wildcard || matches_some
+}
+
+///
+/// Returns `true` if a value object matches a value pattern, which may specify `@value`, `@type`,
+/// and `@language` each as a list of values, or the wildcard `{}`.
+///
+fn value_match(pattern: &Map<String, Value>, value: &Value) -> bool {
+    let Some(value) = value.as_object() else {
+        return false;
+    };
+    let pattern_values = |key: &str| as_array(pattern.get(key).cloned().unwrap_or_default());
+    let values = pattern_values(KW_VALUE);
+    let types = pattern_values(KW_TYPE);
+    let languages = pattern_values(KW_LANGUAGE);
+    if values.is_empty() && types.is_empty() && languages.is_empty() {
+        return true;
+    }
+
+    let matches = |patterns: &[Value], actual: Option<&Value>| match actual {
+        None => patterns.is_empty(),
+        Some(actual) => patterns.contains(actual) || is_wildcard(patterns.first()),
+    };
+    let value_matches = value
+        .get(KW_VALUE)
+        .map(|actual| values.contains(actual))
+        .unwrap_or_default()
+        || is_wildcard(values.first());
+    value_matches
+        && matches(&types, value.get(KW_TYPE))
+        && matches(&languages, value.get(KW_LANGUAGE))
+}
+
+fn count_blank_node_ids(value: &Value, counts: &mut HashMap<String, usize>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                count_blank_node_ids(value, counts);
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map {
+                match (key.as_str(), value) {
+                    (KW_ID, Value::String(id)) if is_blank_node_id(id) => {
+                        *counts.entry(id.clone()).or_default() += 1;
+                    }
+                    (KW_TYPE, Value::Array(types)) => {
+                        for type_value in types.iter().filter_map(Value::as_str) {
+                            if is_blank_node_id(type_value) {
+                                *counts.entry(type_value.to_string()).or_default() += 1;
+                            }
+                        }
+                    }
+                    _ => count_blank_node_ids(value, counts),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn prune_blank_node_ids(value: &mut Value, counts: &HashMap<String, usize>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                prune_blank_node_ids(value, counts);
+            }
+        }
+        Value::Object(map) => {
+            // a node reference is meaningless without its identifier.
+            let is_unique = !is_reference(map)
+                && map
+                    .get(KW_ID)
+                    .and_then(Value::as_str)
+                    .map(|id| counts.get(id) == Some(&1))
+                    .unwrap_or_default();
+            if is_unique {
+                let _ = map.remove(KW_ID);
+            }
+            for value in map.values_mut() {
+                prune_blank_node_ids(value, counts);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,346 @@
+use super::flatten::{add_unique, is_reference, node_entry, reference, NodeMap};
+use super::syntax::{
+    processing_error, BNODE_PREFIX, KW_DEFAULT, KW_GRAPH, KW_JSON, KW_LANGUAGE, KW_LIST, KW_TYPE,
+    KW_VALUE,
+};
+use super::NAME;
+use crate::common::literal::unescape_lexical_form;
+use rdftk_core::error::{rdf_star_not_supported_error, Error};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::{ObjectNode, SubjectNode};
+use rdftk_names::{rdf, xsd};
+use serde_json::{Map, Number, Value};
+use std::collections::{BTreeMap, HashMap};
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Convert a data set into an expanded document, see
+/// [Serialize RDF as JSON-LD](https://www.w3.org/TR/json-ld11-api/#serialize-rdf-as-json-ld-algorithm).
+/// Well-formed RDF collections are converted into list objects and, if `use_native_types` is
+/// set, boolean and numeric literals into native JSON values.
+///
+pub(super) fn from_data_set(data_set: &DataSet, use_native_types: bool) -> Result<Value, Error> {
+    let mut state = State {
+        use_native_types,
+        ..Default::default()
+    };
+    for graph in data_set.graphs() {
+        state.graph(graph)?;
+    }
+    state.convert_lists();
+    Ok(state.into_document())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies a single value, by index, within a property of a node.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Usage {
+    node: String,
+    property: String,
+    index: usize,
+}
+
+#[derive(Debug)]
+enum Reference {
+    Once(Usage),
+    Many,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    use_native_types: bool,
+    graphs: BTreeMap<String, NodeMap>,
+    referenced_once: HashMap<String, Reference>,
+    nil_usages: BTreeMap<String, Vec<Usage>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl State {
+    fn graph(&mut self, graph: &Graph) -> Result<(), Error> {
+        const RULE_FN: &str = "graph";
+        trace!("{RULE_FN}({:?})", graph.name());
+
+        if graph
+            .statements()
+            .any(|st| st.subject().is_statement() || st.object().is_statement())
+        {
+            return rdf_star_not_supported_error(NAME).into();
+        }
+        // only collections remain to be reified.
+        let graph = graph.simplify()?;
+
+        let graph_name = match graph.name() {
+            None => KW_DEFAULT.to_string(),
+            Some(GraphName::Iri(iri)) => format!("{iri:#}"),
+            Some(GraphName::BNode(node)) => format!("{BNODE_PREFIX}{node}"),
+        };
+        if graph_name != KW_DEFAULT {
+            let _ = node_entry(
+                self.graphs.entry(KW_DEFAULT.to_string()).or_default(),
+                &graph_name,
+            );
+        }
+
+        for statement in graph.statements() {
+            let subject = subject_id(statement.subject());
+            let predicate = format!("{:#}", statement.predicate());
+            let object = statement.object();
+            let node_map = self.graphs.entry(graph_name.clone()).or_default();
+            let _ = node_entry(node_map, &subject);
+            let object_id = object_id(object);
+            if let Some(object_id) = &object_id {
+                let _ = node_entry(node_map, object_id);
+            }
+
+            if statement.predicate() == rdf::a_type() {
+                if let Some(object_id) = object_id {
+                    add_unique(
+                        node_entry(node_map, &subject),
+                        KW_TYPE,
+                        Value::String(object_id),
+                    );
+                    continue;
+                }
+            }
+
+            let value = match (object, &object_id) {
+                (_, Some(object_id)) => reference(object_id),
+                (ObjectNode::Literal(literal), None) => {
+                    literal_value(literal, self.use_native_types)?
+                }
+                _ => unreachable!(),
+            };
+            let node = node_entry(node_map, &subject);
+            let Some(index) = add_unique(node, &predicate, value) else {
+                continue;
+            };
+            let usage = Usage {
+                node: subject,
+                property: predicate,
+                index,
+            };
+
+            match object_id {
+                Some(object_id) if object_id == format!("{:#}", rdf::nil()) => {
+                    self.nil_usages
+                        .entry(graph_name.clone())
+                        .or_default()
+                        .push(usage);
+                }
+                Some(object_id) if object.is_blank() => {
+                    let _ = self
+                        .referenced_once
+                        .entry(object_id)
+                        .and_modify(|reference| *reference = Reference::Many)
+                        .or_insert(Reference::Once(usage));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Replace chains of `rdf:first`/`rdf:rest` nodes, working back from each use of `rdf:nil`,
+    /// with list objects. Lists may be nested, so the members of each are only resolved once all
+    /// chains in the graph are known.
+    ///
+    fn convert_lists(&mut self) {
+        let rdf_first = format!("{:#}", rdf::first());
+        let rdf_rest = format!("{:#}", rdf::rest());
+        for (graph_name, usages) in &self.nil_usages {
+            let Some(node_map) = self.graphs.get_mut(graph_name) else {
+                continue;
+            };
+            let mut lists: HashMap<Usage, Vec<String>> = Default::default();
+            for usage in usages {
+                let mut head = usage.clone();
+                let mut list_nodes = Vec::default();
+                while head.property == rdf_rest
+                    && is_well_formed_list_node(node_map, &self.referenced_once, &head.node)
+                {
+                    list_nodes.push(head.node.clone());
+                    let Some(Reference::Once(next)) = self.referenced_once.get(&head.node) else {
+                        unreachable!()
+                    };
+                    head = next.clone();
+                }
+                list_nodes.reverse();
+                let _ = lists.insert(head, list_nodes);
+            }
+
+            // heads within other lists are resolved as members of those lists.
+            let heads = lists
+                .keys()
+                .filter(|head| {
+                    !lists
+                        .values()
+                        .any(|list_nodes| list_nodes.contains(&head.node))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            for head in heads {
+                let list = list_object(node_map, &lists, &head, &rdf_first);
+                node_map.get_mut(&head.node).unwrap()[&head.property][head.index] = list;
+            }
+            for list_node in lists.values().flatten() {
+                let _ = node_map.remove(list_node);
+            }
+        }
+    }
+
+    fn into_document(self) -> Value {
+        let mut graphs = self.graphs;
+        let default_graph = graphs.remove(KW_DEFAULT).unwrap_or_default();
+        let mut result = Vec::default();
+        for (id, mut node) in default_graph {
+            if let Some(graph) = graphs.remove(&id) {
+                let _ = node.insert(
+                    KW_GRAPH.to_string(),
+                    Value::Array(
+                        graph
+                            .into_values()
+                            .filter(|node| !is_reference(node))
+                            .map(Value::Object)
+                            .collect(),
+                    ),
+                );
+            }
+            if !is_reference(&node) {
+                result.push(Value::Object(node));
+            }
+        }
+        Value::Array(result)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A well-formed list node is a blank node referenced exactly once, with exactly one
+/// `rdf:first` and one `rdf:rest` value, and with no other properties except an optional type
+/// of `rdf:List`.
+///
+fn is_well_formed_list_node(
+    node_map: &NodeMap,
+    referenced_once: &HashMap<String, Reference>,
+    id: &str,
+) -> bool {
+    let Some(node) = node_map.get(id) else {
+        return false;
+    };
+    let has_one = |key: String| {
+        node.get(&key)
+            .and_then(Value::as_array)
+            .map(|values| values.len() == 1)
+            .unwrap_or_default()
+    };
+    let list_type = Value::Array(vec![Value::String(format!("{:#}", rdf::list()))]);
+    id.starts_with(BNODE_PREFIX)
+        && matches!(referenced_once.get(id), Some(Reference::Once(_)))
+        && has_one(format!("{:#}", rdf::first()))
+        && has_one(format!("{:#}", rdf::rest()))
+        && (node.len() == 3 || (node.len() == 4 && node.get(KW_TYPE) == Some(&list_type)))
+}
+
+fn list_object(
+    node_map: &NodeMap,
+    lists: &HashMap<Usage, Vec<String>>,
+    head: &Usage,
+    rdf_first: &str,
+) -> Value {
+    let members = lists[head]
+        .iter()
+        .map(|list_node| {
+            let member = Usage {
+                node: list_node.clone(),
+                property: rdf_first.to_string(),
+                index: 0,
+            };
+            if lists.contains_key(&member) {
+                list_object(node_map, lists, &member, rdf_first)
+            } else {
+                node_map[list_node][rdf_first][0].clone()
+            }
+        })
+        .collect();
+    let mut list = Map::default();
+    let _ = list.insert(KW_LIST.to_string(), Value::Array(members));
+    Value::Object(list)
+}
+
+fn subject_id(subject: &SubjectNode) -> String {
+    match subject {
+        SubjectNode::Blank(node) => format!("{BNODE_PREFIX}{node}"),
+        SubjectNode::Resource(iri) => format!("{iri:#}"),
+        SubjectNode::Statement(_) => unreachable!(),
+    }
+}
+
+fn object_id(object: &ObjectNode) -> Option<String> {
+    match object {
+        ObjectNode::Blank(node) => Some(format!("{BNODE_PREFIX}{node}")),
+        ObjectNode::Resource(iri) => Some(format!("{iri:#}")),
+        _ => None,
+    }
+}
+
+///
+/// Convert a literal to a value object, see
+/// [RDF to Object Conversion](https://www.w3.org/TR/json-ld11-api/#rdf-to-object-conversion).
+///
+fn literal_value(literal: &Literal, use_native_types: bool) -> Result<Value, Error> {
+    const RULE_FN: &str = "literal_value";
+
+    let lexical_form = unescape_lexical_form(literal.lexical_form());
+    let data_type = literal.data_type().map(|data_type| data_type.as_iri());
+    let mut result = Map::default();
+    let mut value = Value::String(lexical_form.clone());
+    let mut type_value = None;
+
+    if data_type == Some(rdf::json()) {
+        value = serde_json::from_str(&lexical_form)
+            .map_err(|_| processing_error(RULE_FN, "invalid JSON literal", &lexical_form))?;
+        type_value = Some(KW_JSON.to_string());
+    } else if use_native_types && data_type == Some(xsd::boolean()) && lexical_form == "true" {
+        value = Value::Bool(true);
+    } else if use_native_types && data_type == Some(xsd::boolean()) && lexical_form == "false" {
+        value = Value::Bool(false);
+    } else if let (true, Some(number)) = (
+        use_native_types && data_type == Some(xsd::integer()),
+        lexical_form.parse::<i64>().ok().map(Number::from),
+    ) {
+        value = Value::Number(number);
+    } else if let (true, Some(number)) = (
+        use_native_types && data_type == Some(xsd::double()),
+        lexical_form.parse::<f64>().ok().and_then(Number::from_f64),
+    ) {
+        value = Value::Number(number);
+    } else if let Some(language) = literal.language() {
+        let _ = result.insert(KW_LANGUAGE.to_string(), Value::String(language.to_string()));
+    } else if let Some(data_type) = data_type.filter(|data_type| *data_type != xsd::string()) {
+        type_value = Some(format!("{data_type:#}"));
+    }
+
+    let _ = result.insert(KW_VALUE.to_string(), value);
+    if let Some(type_value) = type_value {
+        let _ = result.insert(KW_TYPE.to_string(), Value::String(type_value));
+    }
+    Ok(Value::Object(result))
+}
@@ -0,0 +1,184 @@
+use super::NAME;
+use crate::common::json::read_json_value;
+use rdftk_core::error::Error;
+use rdftk_iri::Iri;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::{BufReader, ErrorKind};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A document returned by a [`DocumentLoader`], the document URL is the final location of the
+/// document and is used as the base for any relative references within it.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteDocument {
+    document_url: Iri,
+    document: Value,
+}
+
+///
+/// A document loader is used to retrieve remote contexts, referenced by IRI from `@context` and
+/// `@import`, during processing.
+///
+/// No loader provided by this crate accesses the network; applications that wish to do so may
+/// provide their own implementation.
+///
+pub trait DocumentLoader: Debug {
+    ///
+    /// Load and parse the JSON document identified by `url`.
+    ///
+    fn load(&self, url: &Iri) -> Result<RemoteDocument, Error>;
+}
+
+///
+/// A loader that refuses to load any document, this is the default loader for the reader and so
+/// any document referencing a remote context will fail unless another loader is provided.
+///
+#[derive(Clone, Debug, Default)]
+pub struct NoDocumentLoader {}
+
+///
+/// A loader that resolves `file:` URLs directly, and other URLs by mapping an IRI prefix onto a
+/// local directory.
+///
+#[derive(Clone, Debug, Default)]
+pub struct FileDocumentLoader {
+    mappings: Vec<(String, PathBuf)>,
+}
+
+///
+/// A loader that returns documents from a fixed, in-memory, set.
+///
+#[derive(Clone, Debug, Default)]
+pub struct StaticDocumentLoader {
+    documents: HashMap<String, Value>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RemoteDocument {
+    pub fn new(document_url: Iri, document: Value) -> Self {
+        Self {
+            document_url,
+            document,
+        }
+    }
+
+    pub fn document_url(&self) -> &Iri {
+        &self.document_url
+    }
+
+    pub fn document(&self) -> &Value {
+        &self.document
+    }
+
+    pub fn into_document(self) -> Value {
+        self.document
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl DocumentLoader for NoDocumentLoader {
+    fn load(&self, url: &Iri) -> Result<RemoteDocument, Error> {
+        Err(load_error(
+            ErrorKind::Unsupported,
+            format!("no document loader configured to load {url}"),
+        ))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl DocumentLoader for FileDocumentLoader {
+    fn load(&self, url: &Iri) -> Result<RemoteDocument, Error> {
+        let path = self.resolve(url).ok_or_else(|| {
+            load_error(
+                ErrorKind::NotFound,
+                format!("no local mapping for document {url}"),
+            )
+        })?;
+        let file = File::open(&path)?;
+        let document = read_json_value(NAME, &mut BufReader::new(file))?;
+        Ok(RemoteDocument::new(url.clone(), document))
+    }
+}
+
+impl FileDocumentLoader {
+    ///
+    /// Map any IRI starting with `prefix` onto `directory`, the remainder of the IRI is used
+    /// as a path relative to that directory.
+    ///
+    pub fn with_mapping<S, P>(self, prefix: S, directory: P) -> Self
+    where
+        S: Into<String>,
+        P: Into<PathBuf>,
+    {
+        let mut self_mut = self;
+        self_mut.mappings.push((prefix.into(), directory.into()));
+        self_mut
+    }
+
+    fn resolve(&self, url: &Iri) -> Option<PathBuf> {
+        let url_str = format!("{url:#}");
+        self.mappings
+            .iter()
+            .filter(|(prefix, _)| url_str.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(prefix, directory)| directory.join(&url_str[prefix.len()..]))
+            .or_else(|| {
+                let url: &url::Url = url.as_ref();
+                if url.scheme() == "file" {
+                    url.to_file_path().ok()
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl DocumentLoader for StaticDocumentLoader {
+    fn load(&self, url: &Iri) -> Result<RemoteDocument, Error> {
+        let url_str = format!("{url:#}");
+        match self.documents.get(&url_str) {
+            Some(document) => Ok(RemoteDocument::new(url.clone(), document.clone())),
+            None => Err(load_error(
+                ErrorKind::NotFound,
+                format!("no static document for {url}"),
+            )),
+        }
+    }
+}
+
+impl StaticDocumentLoader {
+    pub fn with_document<S>(self, url: S, document: Value) -> Result<Self, Error>
+    where
+        S: AsRef<str>,
+    {
+        // normalize the key in the same way as the IRI passed to `load`.
+        let url = Iri::from_str(url.as_ref())?;
+        let mut self_mut = self;
+        let _ = self_mut.documents.insert(format!("{url:#}"), document);
+        Ok(self_mut)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn load_error(kind: ErrorKind, message: String) -> Error {
+    Error::Io(std::io::Error::new(kind, message))
+}
@@ -2,8 +2,100 @@
 Provides support for the W3C's
 [JSON-LD 1.1](https://www.w3.org/TR/json-ld/), _A JSON-based Serialization for Linked Data_, format.
 
+The reader implements the
+[Expansion Algorithm](https://www.w3.org/TR/json-ld11-api/#expansion-algorithm), including
+context processing, and then deserializes the expanded form into a `DataSet`, or `Graph`.
+Remote contexts are retrieved using a [`DocumentLoader`]; the default loader refuses to load any
+document so that processing never accesses the network unless the application chooses to.
+
+# Example Reader
+
+```rust
+use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::json_ld::{JsonLdReader, JsonLdReaderOptions, StaticDocumentLoader};
+use serde_json::json;
+
+let loader = StaticDocumentLoader::default()
+    .with_document(
+        "https://example.org/context.jsonld",
+        json!({
+            "@context": {
+                "name": "http://xmlns.com/foaf/0.1/name",
+                "knows": { "@id": "http://xmlns.com/foaf/0.1/knows", "@type": "@id" }
+            }
+        }),
+    )
+    .unwrap();
+
+let reader = JsonLdReader::default()
+    .with_options(JsonLdReaderOptions::default().with_loader(loader));
+
+let graph: Graph = reader.read_from_string(r#"{
+    "@context": "https://example.org/context.jsonld",
+    "@id": "https://example.org/people/alice",
+    "name": "Alice",
+    "knows": "https://example.org/people/bob"
+}"#).unwrap();
+
+assert_eq!(graph.len(), 2);
+```
+
+# Example Writer
+
+The writer produces a compacted document by default, using a context derived from the prefix
+mappings of the graph unless a context is provided in the options.
+
+```rust
+use objio::ObjectWriter;
+use rdftk_io::json_ld::{JsonLdForm, JsonLdWriter, JsonLdWriterOptions};
+use serde_json::json;
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { Graph::default() }
+
+let writer = JsonLdWriter::default().with_options(
+    JsonLdWriterOptions::default()
+        .with_form(JsonLdForm::Compacted)
+        .with_context(json!({ "foaf": "http://xmlns.com/foaf/0.1/" }))
+        .with_pretty_print(true),
+);
+
+let result = writer.write_to_string(&make_graph());
+```
+
+# Example Framing
+
+The framed form implements [JSON-LD 1.1 Framing](https://www.w3.org/TR/json-ld11-framing/),
+nodes matching the frame are written at the top level with the nodes they reference embedded
+in the shape given by the frame.
+
+```rust
+use objio::ObjectWriter;
+use rdftk_io::json_ld::{JsonLdEmbed, JsonLdForm, JsonLdWriter, JsonLdWriterOptions};
+use serde_json::json;
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { Graph::default() }
+
+let writer = JsonLdWriter::default().with_options(
+    JsonLdWriterOptions::default()
+        .with_form(JsonLdForm::Framed)
+        .with_frame(json!({
+            "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+            "@type": "Person",
+            "knows": { "@explicit": true, "name": {} }
+        }))
+        .with_embed(JsonLdEmbed::Always),
+);
+
+let result = writer.write_to_string(&make_graph());
+```
+
 */
 
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
 /// The display name of this serialization format.
 pub const NAME: &str = "JSON-LD";
 
@@ -17,8 +109,29 @@ pub const MIME_TYPE: &str = "application/ld+json";
 // Modules
 // ------------------------------------------------------------------------------------------------
 
-#[doc(hidden)]
-pub mod reader;
+mod syntax;
+
+mod context;
+
+mod expand;
+
+mod to_rdf;
+
+mod from_rdf;
+
+mod flatten;
+
+mod compact;
+
+mod frame;
+
+mod loader;
+pub use loader::{
+    DocumentLoader, FileDocumentLoader, NoDocumentLoader, RemoteDocument, StaticDocumentLoader,
+};
+
+mod reader;
+pub use reader::{JsonLdReader, JsonLdReaderOptions};
 
-#[doc(hidden)]
-pub mod writer;
+mod writer;
+pub use writer::{JsonLdEmbed, JsonLdForm, JsonLdWriter, JsonLdWriterOptions};
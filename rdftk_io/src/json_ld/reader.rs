@@ -1,26 +1,206 @@
-
-// use ...
+use super::context::{Context, ContextProcessor};
+use super::expand::Expander;
+use super::loader::{DocumentLoader, NoDocumentLoader};
+use super::syntax::{processing_error, KW_CONTEXT};
+use super::to_rdf::to_data_set;
+use super::NAME;
+use crate::common::json::read_json_value;
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::Graph;
+use rdftk_iri::Iri;
+use serde_json::Value;
+use std::io::Read;
+use std::rc::Rc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+///
+/// Options to change the behavior of the [`JsonLdReader`] implementation.
+///
+#[derive(Clone, Debug)]
+pub struct JsonLdReaderOptions {
+    reader: ReaderOptions,
+    expand_context: Option<Value>,
+    loader: Rc<dyn DocumentLoader>,
+}
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// This struct implements the `ObjectReader` trait for data sets, and graphs, by expanding the
+/// JSON-LD document and then deserializing the expanded form to RDF.
+///
+#[derive(Debug, Default)]
+pub struct JsonLdReader {
+    options: JsonLdReaderOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Functions
-// ------------------------------------------------------------------------------------------------
+impl Default for JsonLdReaderOptions {
+    fn default() -> Self {
+        Self {
+            reader: ReaderOptions::default(),
+            expand_context: None,
+            loader: Rc::new(NoDocumentLoader::default()),
+        }
+    }
+}
+
+impl JsonLdReaderOptions {
+    ///
+    /// The base IRI used to resolve relative IRIs in the document, usually the location the
+    /// document was retrieved from.
+    ///
+    pub fn with_base(self, base: Iri) -> Self {
+        Self {
+            reader: self.reader.with_base(base),
+            ..self
+        }
+    }
+
+    ///
+    /// The options shared with other readers; the base IRI and blank node labeling are used,
+    /// the document's context is not carried into the resulting graphs as prefixes.
+    ///
+    pub fn with_reader_options(self, reader: ReaderOptions) -> Self {
+        Self { reader, ..self }
+    }
+
+    ///
+    /// A context that is applied before any context within the document itself.
+    ///
+    pub fn with_expand_context(self, expand_context: Value) -> Self {
+        Self {
+            expand_context: Some(expand_context),
+            ..self
+        }
+    }
+
+    ///
+    /// The loader used to retrieve remote contexts, the default loader will not load any remote
+    /// documents.
+    ///
+    pub fn with_loader<L>(self, loader: L) -> Self
+    where
+        L: DocumentLoader + 'static,
+    {
+        Self {
+            loader: Rc::new(loader),
+            ..self
+        }
+    }
+
+    pub fn base(&self) -> Option<&Iri> {
+        self.reader.base()
+    }
+
+    pub fn set_base(&mut self, base: Iri) {
+        self.reader.set_base(base);
+    }
+
+    pub fn unset_base(&mut self) {
+        self.reader.unset_base();
+    }
+
+    pub fn reader_options(&self) -> &ReaderOptions {
+        &self.reader
+    }
+
+    pub fn set_reader_options(&mut self, reader: ReaderOptions) {
+        self.reader = reader;
+    }
+
+    pub fn expand_context(&self) -> Option<&Value> {
+        self.expand_context.as_ref()
+    }
+
+    pub fn set_expand_context(&mut self, expand_context: Value) {
+        self.expand_context = Some(expand_context);
+    }
+
+    pub fn unset_expand_context(&mut self) {
+        self.expand_context = None;
+    }
+
+    pub fn loader(&self) -> &dyn DocumentLoader {
+        self.loader.as_ref()
+    }
+
+    pub fn set_loader<L>(&mut self, loader: L)
+    where
+        L: DocumentLoader + 'static,
+    {
+        self.loader = Rc::new(loader);
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(JsonLdReader, JsonLdReaderOptions);
+
+impl ObjectReader<DataSet> for JsonLdReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<DataSet, Self::Error>
+    where
+        R: Read,
+    {
+        let document = read_json_value(NAME, r)?;
+        to_data_set(
+            &self.expand(&document)?,
+            self.options.reader_options().blank_nodes(),
+        )
+    }
+}
+
+impl ObjectReader<Graph> for JsonLdReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Self::Error>
+    where
+        R: Read,
+    {
+        let data_set: DataSet = self.read(r)?;
+        if let Some(graph) = data_set.graphs().find(|graph| graph.is_named()) {
+            return Err(processing_error(
+                "read",
+                "named graphs are not supported when reading a graph",
+                graph.name().unwrap().to_string(),
+            ));
+        }
+        Ok(data_set.default_graph().cloned().unwrap_or_default())
+    }
+}
+
+impl JsonLdReader {
+    pub fn with_options(self, options: JsonLdReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Expand the document, returning the
+    /// [expanded document form](https://www.w3.org/TR/json-ld11/#expanded-document-form) which
+    /// is always an array of node objects.
+    ///
+    pub fn expand(&self, document: &Value) -> Result<Value, Error> {
+        let processor = ContextProcessor::new(self.options.loader());
+        let mut active = Context::new(self.options.base().cloned());
+        if let Some(expand_context) = self.options.expand_context() {
+            let expand_context = match expand_context {
+                Value::Object(map) if map.contains_key(KW_CONTEXT) => &map[KW_CONTEXT],
+                expand_context => expand_context,
+            };
+            active =
+                processor.process(&active, expand_context, self.options.base(), false, true)?;
+        }
+        Expander::new(processor).expand_document(&active, document, self.options.base())
+    }
+}
@@ -0,0 +1,201 @@
+use super::NAME;
+use rdftk_core::error::Error;
+use serde_json::{Map, Value};
+use tracing::error;
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const KW_BASE: &str = "@base";
+pub(super) const KW_CONTAINER: &str = "@container";
+pub(super) const KW_CONTEXT: &str = "@context";
+pub(super) const KW_DEFAULT: &str = "@default";
+pub(super) const KW_DIRECTION: &str = "@direction";
+pub(super) const KW_EMBED: &str = "@embed";
+pub(super) const KW_EXPLICIT: &str = "@explicit";
+pub(super) const KW_GRAPH: &str = "@graph";
+pub(super) const KW_ID: &str = "@id";
+pub(super) const KW_IMPORT: &str = "@import";
+pub(super) const KW_INCLUDED: &str = "@included";
+pub(super) const KW_INDEX: &str = "@index";
+pub(super) const KW_JSON: &str = "@json";
+pub(super) const KW_LANGUAGE: &str = "@language";
+pub(super) const KW_LIST: &str = "@list";
+pub(super) const KW_NEST: &str = "@nest";
+pub(super) const KW_NONE: &str = "@none";
+pub(super) const KW_OMIT_DEFAULT: &str = "@omitDefault";
+pub(super) const KW_PREFIX: &str = "@prefix";
+pub(super) const KW_PRESERVE: &str = "@preserve";
+pub(super) const KW_PROPAGATE: &str = "@propagate";
+pub(super) const KW_PROTECTED: &str = "@protected";
+pub(super) const KW_REQUIRE_ALL: &str = "@requireAll";
+pub(super) const KW_REVERSE: &str = "@reverse";
+pub(super) const KW_SET: &str = "@set";
+pub(super) const KW_TYPE: &str = "@type";
+pub(super) const KW_VALUE: &str = "@value";
+pub(super) const KW_VERSION: &str = "@version";
+pub(super) const KW_VOCAB: &str = "@vocab";
+
+const KEYWORDS: &[&str] = &[
+    KW_BASE,
+    KW_CONTAINER,
+    KW_CONTEXT,
+    KW_DEFAULT,
+    KW_DIRECTION,
+    KW_EMBED,
+    KW_EXPLICIT,
+    KW_GRAPH,
+    KW_ID,
+    KW_IMPORT,
+    KW_INCLUDED,
+    KW_INDEX,
+    KW_JSON,
+    KW_LANGUAGE,
+    KW_LIST,
+    KW_NEST,
+    KW_NONE,
+    KW_OMIT_DEFAULT,
+    KW_PREFIX,
+    KW_PRESERVE,
+    KW_PROPAGATE,
+    KW_PROTECTED,
+    KW_REQUIRE_ALL,
+    KW_REVERSE,
+    KW_SET,
+    KW_TYPE,
+    KW_VALUE,
+    KW_VERSION,
+    KW_VOCAB,
+];
+
+pub(super) const BNODE_PREFIX: &str = "_:";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn is_keyword(s: &str) -> bool {
+    KEYWORDS.contains(&s)
+}
+
+///
+/// Returns `true` if `s` has the form of a keyword, `@` followed by one or more ASCII letters;
+/// such values are reserved for future use and are ignored rather than treated as terms.
+///
+pub(super) fn looks_like_keyword(s: &str) -> bool {
+    s.strip_prefix('@')
+        .map(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_alphabetic()))
+        .unwrap_or_default()
+}
+
+pub(super) fn is_blank_node_id(s: &str) -> bool {
+    s.starts_with(BNODE_PREFIX)
+}
+
+///
+/// Returns `true` if `s` is an absolute IRI, that is it has a scheme.
+///
+pub(super) fn is_absolute_iri(s: &str) -> bool {
+    match s.split_once(':') {
+        Some((scheme, _)) => {
+            let mut chars = scheme.chars();
+            chars
+                .next()
+                .map(|c| c.is_ascii_alphabetic())
+                .unwrap_or_default()
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+        None => false,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn is_value_object(value: &Value) -> bool {
+    value
+        .as_object()
+        .map(|map| map.contains_key(KW_VALUE))
+        .unwrap_or_default()
+}
+
+pub(super) fn is_list_object(value: &Value) -> bool {
+    value
+        .as_object()
+        .map(|map| map.contains_key(KW_LIST))
+        .unwrap_or_default()
+}
+
+pub(super) fn is_graph_object(value: &Value) -> bool {
+    value
+        .as_object()
+        .map(|map| {
+            map.contains_key(KW_GRAPH)
+                && map
+                    .keys()
+                    .all(|k| k == KW_GRAPH || k == KW_ID || k == KW_INDEX || k == KW_CONTEXT)
+        })
+        .unwrap_or_default()
+}
+
+///
+/// Returns the value as an array, wrapping any non-array value.
+///
+pub(super) fn as_array(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(values) => values,
+        Value::Null => Vec::default(),
+        value => vec![value],
+    }
+}
+
+///
+/// Append `value`, or each member if it is an array, to the array entry `key` in `map`.
+///
+pub(super) fn add_value(map: &mut Map<String, Value>, key: &str, value: Value) {
+    let entry = map
+        .entry(key.to_string())
+        .or_insert_with(|| Value::Array(Vec::default()));
+    if !entry.is_array() {
+        *entry = Value::Array(vec![entry.take()]);
+    }
+    if let Value::Array(values) = entry {
+        values.extend(as_array(value));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn json_error(err: serde_json::Error) -> Error {
+    Error::Tokenizer {
+        representation: NAME.into(),
+        source: Box::new(err),
+    }
+}
+
+pub(super) fn value_variant(value: &Value) -> String {
+    match value {
+        Value::Null => "Null",
+        Value::Bool(_) => "Bool",
+        Value::Number(_) => "Number",
+        Value::String(_) => "String",
+        Value::Array(_) => "Array",
+        Value::Object(_) => "Object",
+    }
+    .to_string()
+}
+
+///
+/// Create an error for a processing failure, `code` is the error code from the JSON-LD 1.1
+/// Processing Algorithms and API specification.
+///
+pub(super) fn processing_error<S>(rule_fn: &str, code: &str, given: S) -> Error
+where
+    S: AsRef<str>,
+{
+    error!(
+        "rule {rule_fn} failed with `{code}` given `{}`",
+        given.as_ref()
+    );
+    rdftk_core::error::processing_error(NAME, code, given.as_ref())
+}
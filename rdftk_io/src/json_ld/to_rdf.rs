@@ -0,0 +1,344 @@
+use super::syntax::{
+    is_blank_node_id, is_list_object, is_value_object, processing_error, value_variant,
+    BNODE_PREFIX, KW_DIRECTION, KW_GRAPH, KW_ID, KW_INCLUDED, KW_JSON, KW_LANGUAGE, KW_LIST,
+    KW_REVERSE, KW_TYPE, KW_VALUE,
+};
+use crate::common::relabel::DocumentLabels;
+use crate::BlankNodeLabels;
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::Iri;
+use rdftk_names::{rdf, xsd};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::{trace, warn};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Convert an expanded document into a data set, see
+/// [Deserialize JSON-LD to RDF](https://www.w3.org/TR/json-ld11-api/#deserialize-json-ld-to-rdf-algorithm).
+///
+/// Rather than first generating a node map the expanded document is walked directly, as the
+/// resulting graphs are sets of statements any duplication is removed on insert.
+///
+pub(super) fn to_data_set(
+    expanded: &Value,
+    blank_nodes: BlankNodeLabels,
+) -> Result<DataSet, Error> {
+    let mut state = State {
+        blank_nodes: DocumentLabels::new(blank_nodes),
+        ..Default::default()
+    };
+    for node in expanded.as_array().into_iter().flatten() {
+        let _ = state.node(node, &None)?;
+    }
+    Ok(state.graphs.into_values().collect())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Graphs are created on first use, blank node identifiers are relabeled consistently across the
+/// whole document when relabeling, or when they could not be kept.
+///
+#[derive(Debug, Default)]
+struct State {
+    graphs: HashMap<Option<GraphName>, Graph>,
+    blank_nodes: DocumentLabels,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl State {
+    fn insert(&mut self, graph_name: &Option<GraphName>, statement: Statement) {
+        self.graphs
+            .entry(graph_name.clone())
+            .or_insert_with(|| match graph_name {
+                None => Graph::default(),
+                Some(name) => Graph::named(name.clone()),
+            })
+            .insert(statement);
+    }
+
+    fn blank_node(&mut self, label: &str) -> BlankNode {
+        self.blank_nodes.blank_node(label)
+    }
+
+    ///
+    /// Returns the subject for an identifier, or `None` if it is not a well-formed IRI or
+    /// blank node identifier.
+    ///
+    fn subject(&mut self, id: &str) -> Option<SubjectNode> {
+        if is_blank_node_id(id) {
+            Some(self.blank_node(&id[BNODE_PREFIX.len()..]).into())
+        } else {
+            well_formed_iri(id).map(SubjectNode::from)
+        }
+    }
+
+    ///
+    /// Add the statements for a node object to the graph `graph_name`, returning the node's
+    /// subject.
+    ///
+    fn node(
+        &mut self,
+        node: &Value,
+        graph_name: &Option<GraphName>,
+    ) -> Result<Option<SubjectNode>, Error> {
+        const RULE_FN: &str = "node";
+        trace!("{RULE_FN}(...)");
+
+        let Value::Object(node) = node else {
+            return Err(processing_error(
+                RULE_FN,
+                "invalid node object",
+                value_variant(node),
+            ));
+        };
+
+        let subject = match node.get(KW_ID) {
+            Some(Value::String(id)) => self.subject(id),
+            _ => Some(BlankNode::generate().into()),
+        };
+
+        if let Some(types) = node.get(KW_TYPE) {
+            for type_value in types.as_array().into_iter().flatten() {
+                if let (Some(subject), Some(object)) = (
+                    &subject,
+                    type_value
+                        .as_str()
+                        .and_then(|t| self.subject(t))
+                        .map(|t| t.to_object()),
+                ) {
+                    self.insert(
+                        graph_name,
+                        Statement::new(subject.clone(), rdf::a_type().clone(), object),
+                    );
+                }
+            }
+        }
+
+        for (property, values) in node.iter().filter(|(key, _)| !key.starts_with('@')) {
+            let predicate = well_formed_iri(property);
+            for value in values.as_array().into_iter().flatten() {
+                let object = self.object(value, graph_name)?;
+                if let (Some(subject), Some(predicate), Some(object)) =
+                    (&subject, &predicate, object)
+                {
+                    self.insert(
+                        graph_name,
+                        Statement::new(subject.clone(), predicate.clone(), object),
+                    );
+                }
+            }
+        }
+
+        if let Some(Value::Object(reverse)) = node.get(KW_REVERSE) {
+            for (property, values) in reverse {
+                let predicate = well_formed_iri(property);
+                for value in values.as_array().into_iter().flatten() {
+                    let reverse_subject = self.node(value, graph_name)?;
+                    if let (Some(reverse_subject), Some(predicate), Some(object)) = (
+                        reverse_subject,
+                        &predicate,
+                        subject.as_ref().map(|s| s.to_object()),
+                    ) {
+                        self.insert(
+                            graph_name,
+                            Statement::new(reverse_subject, predicate.clone(), object),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(Value::Array(nodes)) = node.get(KW_GRAPH) {
+            if let Some(name) = &subject {
+                let name = Some(GraphName::from(name.clone()));
+                for node in nodes {
+                    let _ = self.node(node, &name)?;
+                }
+            }
+        }
+
+        if let Some(Value::Array(nodes)) = node.get(KW_INCLUDED) {
+            for node in nodes {
+                let _ = self.node(node, graph_name)?;
+            }
+        }
+
+        Ok(subject)
+    }
+
+    fn object(
+        &mut self,
+        value: &Value,
+        graph_name: &Option<GraphName>,
+    ) -> Result<Option<ObjectNode>, Error> {
+        if is_value_object(value) {
+            literal(value.as_object().unwrap()).map(|literal| literal.map(ObjectNode::from))
+        } else if is_list_object(value) {
+            let items = value[KW_LIST].as_array().cloned().unwrap_or_default();
+            self.list(&items, graph_name).map(Some)
+        } else {
+            Ok(self
+                .node(value, graph_name)?
+                .map(|subject| subject.to_object()))
+        }
+    }
+
+    ///
+    /// Add the statements for a list, returning its head, see
+    /// [List to RDF Conversion](https://www.w3.org/TR/json-ld11-api/#list-to-rdf-conversion).
+    ///
+    fn list(
+        &mut self,
+        items: &[Value],
+        graph_name: &Option<GraphName>,
+    ) -> Result<ObjectNode, Error> {
+        let members = items
+            .iter()
+            .map(|item| self.object(item, graph_name))
+            .collect::<Result<Vec<Option<ObjectNode>>, Error>>()?;
+        let mut list: ObjectNode = rdf::nil().clone().into();
+        for member in members.into_iter().rev() {
+            let node = BlankNode::generate();
+            if let Some(member) = member {
+                self.insert(
+                    graph_name,
+                    Statement::new(node.clone(), rdf::first().clone(), member),
+                );
+            }
+            self.insert(
+                graph_name,
+                Statement::new(node.clone(), rdf::rest().clone(), list),
+            );
+            list = node.into();
+        }
+        Ok(list)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn well_formed_iri(iri: &str) -> Option<Iri> {
+    if is_blank_node_id(iri) {
+        warn!("blank node predicates are not supported, ignoring {iri}");
+        None
+    } else {
+        Iri::from_str(iri)
+            .map_err(|_| warn!("ignoring relative, or invalid, IRI {iri}"))
+            .ok()
+    }
+}
+
+///
+/// Convert a value object to a literal, see
+/// [Object to RDF Conversion](https://www.w3.org/TR/json-ld11-api/#object-to-rdf-conversion).
+///
+fn literal(value: &Map<String, Value>) -> Result<Option<Literal>, Error> {
+    const RULE_FN: &str = "literal";
+
+    let data_type = match value.get(KW_TYPE) {
+        Some(Value::String(data_type)) if data_type == KW_JSON => Some(rdf::json().clone()),
+        Some(Value::String(data_type)) => match well_formed_iri(data_type) {
+            Some(data_type) => Some(data_type),
+            None => return Ok(None),
+        },
+        _ => None,
+    };
+    let language = match value.get(KW_LANGUAGE) {
+        Some(Value::String(language)) => match LanguageTag::parse(language) {
+            Ok(language) => Some(language),
+            Err(_) => {
+                warn!("ignoring literal with invalid language tag {language}");
+                return Ok(None);
+            }
+        },
+        _ => None,
+    };
+    if value.contains_key(KW_DIRECTION) {
+        warn!("base direction is not supported, ignoring @direction");
+    }
+
+    let json_value = &value[KW_VALUE];
+    let (lexical_form, data_type) = if data_type.as_ref() == Some(rdf::json()) {
+        (canonical_json(json_value), data_type)
+    } else {
+        match json_value {
+            Value::Bool(v) => (
+                v.to_string(),
+                data_type.or_else(|| Some(xsd::boolean().clone())),
+            ),
+            Value::Number(v) if is_double(v) || data_type.as_ref() == Some(xsd::double()) => (
+                canonical_double(v.as_f64().unwrap_or_default()),
+                data_type.or_else(|| Some(xsd::double().clone())),
+            ),
+            Value::Number(v) => (
+                canonical_integer(v),
+                data_type.or_else(|| Some(xsd::integer().clone())),
+            ),
+            Value::String(v) => (v.clone(), data_type),
+            v => {
+                return Err(processing_error(
+                    RULE_FN,
+                    "invalid value object value",
+                    value_variant(v),
+                ));
+            }
+        }
+    };
+
+    Ok(Some(match (data_type, language) {
+        (Some(data_type), _) => Literal::with_data_type(lexical_form, DataType::from(data_type)),
+        (None, Some(language)) => Literal::with_language(lexical_form, language),
+        (None, None) => Literal::plain(lexical_form),
+    }))
+}
+
+fn is_double(v: &Number) -> bool {
+    match v.as_f64() {
+        Some(f) if !(v.is_i64() || v.is_u64()) => f.fract() != 0.0 || f.abs() >= 1e21,
+        _ => false,
+    }
+}
+
+fn canonical_integer(v: &Number) -> String {
+    if v.is_i64() || v.is_u64() {
+        v.to_string()
+    } else {
+        format!("{:.0}", v.as_f64().unwrap_or_default())
+    }
+}
+
+///
+/// The canonical lexical form of `xsd:double`, a mantissa with a single digit before the
+/// decimal point followed by an exponent, for example `1.1E0`.
+///
+fn canonical_double(v: f64) -> String {
+    let formatted = format!("{v:E}");
+    match formatted.split_once('E') {
+        Some((mantissa, exponent)) if !mantissa.contains('.') => {
+            format!("{mantissa}.0E{exponent}")
+        }
+        _ => formatted,
+    }
+}
+
+fn canonical_json(v: &Value) -> String {
+    // object keys are ordered, and no whitespace is added, by the default serializer.
+    serde_json::to_string(v).unwrap_or_default()
+}
@@ -1,26 +1,452 @@
-
-// use ...
+use super::compact::Compactor;
+use super::context::{Context, ContextProcessor};
+use super::expand::Expander;
+use super::flatten::flatten;
+use super::frame::{frame_document, remove_preserve, FrameFlags};
+use super::from_rdf::from_data_set;
+use super::loader::{DocumentLoader, NoDocumentLoader};
+use super::syntax::{json_error, KW_CONTEXT, KW_VOCAB};
+use crate::{DataSetWriter, GraphWriter};
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::Graph;
+use rdftk_iri::{Iri, IriPrefixMap};
+use serde_json::{Map, Value};
+use std::io::Write;
+use std::rc::Rc;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+///
+/// The document form produced by the [`JsonLdWriter`], see
+/// [Forms of JSON-LD](https://www.w3.org/TR/json-ld11/#forms-of-json-ld).
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonLdForm {
+    /// All IRIs, types, and values are written in full, with no context.
+    Expanded,
+    /// IRIs and values are shortened using a context, either the one supplied in the writer
+    /// options or one derived from the prefix mappings of the graphs being written.
+    #[default]
+    Compacted,
+    /// All nodes are written at the top level, or the top level of their named graph, and blank
+    /// nodes are relabeled; if a context is supplied in the writer options it is also compacted.
+    Flattened,
+    /// Nodes are embedded within each other in the shape described by the frame supplied in the
+    /// writer options, and then compacted using the frame's context; with no frame every node is
+    /// written at the top level with the nodes it references embedded.
+    Framed,
+}
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// The default value of the `@embed` flag used when framing, see
+/// [Framing Keywords](https://www.w3.org/TR/json-ld11-framing/#framing-keywords).
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonLdEmbed {
+    /// A node is embedded every time it is referenced, unless this would create a cycle.
+    Always,
+    /// A node is embedded the first time it is referenced, later references use its identifier.
+    #[default]
+    Once,
+    /// Nodes are never embedded, all references use the node's identifier.
+    Never,
+}
+
+///
+/// Options to change the behavior of the [`JsonLdWriter`] implementation.
+///
+#[derive(Clone, Debug)]
+pub struct JsonLdWriterOptions {
+    form: JsonLdForm,
+    context: Option<Value>,
+    loader: Rc<dyn DocumentLoader>,
+    use_native_types: bool,
+    compact_arrays: bool,
+    pretty_print: bool,
+    frame: Option<Value>,
+    embed: JsonLdEmbed,
+    explicit: bool,
+    omit_default: bool,
+    require_all: bool,
+}
+
+///
+/// This struct implements the `GraphWriter` and `DataSetWriter` traits, converting the statements
+/// to JSON-LD and then writing them in the form chosen in the options.
+///
+#[derive(Debug, Default)]
+pub struct JsonLdWriter {
+    options: JsonLdWriterOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Default for JsonLdWriterOptions {
+    fn default() -> Self {
+        Self {
+            form: Default::default(),
+            context: None,
+            loader: Rc::new(NoDocumentLoader::default()),
+            use_native_types: false,
+            compact_arrays: true,
+            pretty_print: false,
+            frame: None,
+            embed: Default::default(),
+            explicit: false,
+            omit_default: false,
+            require_all: false,
+        }
+    }
+}
+
+impl JsonLdWriterOptions {
+    pub fn with_form(self, form: JsonLdForm) -> Self {
+        Self { form, ..self }
+    }
+
+    ///
+    /// The context used for compaction; this may be a context, a document with an `@context`
+    /// entry, or the IRI of a remote context which is retrieved with the options' loader.
+    ///
+    pub fn with_context(self, context: Value) -> Self {
+        Self {
+            context: Some(context),
+            ..self
+        }
+    }
+
+    ///
+    /// The loader used to retrieve remote contexts, the default loader will not load any remote
+    /// documents.
+    ///
+    pub fn with_loader<L>(self, loader: L) -> Self
+    where
+        L: DocumentLoader + 'static,
+    {
+        Self {
+            loader: Rc::new(loader),
+            ..self
+        }
+    }
+
+    ///
+    /// If `true`, `xsd:boolean`, `xsd:integer`, and `xsd:double` literals are written as native
+    /// JSON values.
+    ///
+    pub fn with_use_native_types(self, use_native_types: bool) -> Self {
+        Self {
+            use_native_types,
+            ..self
+        }
+    }
+
+    ///
+    /// If `true`, the default, arrays with a single value are replaced by that value when
+    /// compacting.
+    ///
+    pub fn with_compact_arrays(self, compact_arrays: bool) -> Self {
+        Self {
+            compact_arrays,
+            ..self
+        }
+    }
+
+    pub fn with_pretty_print(self, pretty_print: bool) -> Self {
+        Self {
+            pretty_print,
+            ..self
+        }
+    }
+
+    ///
+    /// The frame used when writing the [`JsonLdForm::Framed`] form; this may be a frame
+    /// document, or the IRI of a remote frame which is retrieved with the options' loader.
+    /// Setting a frame also selects the framed form.
+    ///
+    pub fn with_frame(self, frame: Value) -> Self {
+        Self {
+            form: JsonLdForm::Framed,
+            frame: Some(frame),
+            ..self
+        }
+    }
+
+    ///
+    /// The value of `@embed` for any frame that does not specify one.
+    ///
+    pub fn with_embed(self, embed: JsonLdEmbed) -> Self {
+        Self { embed, ..self }
+    }
+
+    ///
+    /// The value of `@explicit` for any frame that does not specify one; if `true` only the
+    /// properties named in the frame are written.
+    ///
+    pub fn with_explicit(self, explicit: bool) -> Self {
+        Self { explicit, ..self }
+    }
+
+    ///
+    /// The value of `@omitDefault` for any frame that does not specify one; if `true` properties
+    /// named in the frame but missing from a node are omitted rather than written as `null`, or
+    /// their `@default` value.
+    ///
+    pub fn with_omit_default(self, omit_default: bool) -> Self {
+        Self {
+            omit_default,
+            ..self
+        }
+    }
+
+    ///
+    /// The value of `@requireAll` for any frame that does not specify one; if `true` a node must
+    /// match every property in the frame, rather than any one of them.
+    ///
+    pub fn with_require_all(self, require_all: bool) -> Self {
+        Self {
+            require_all,
+            ..self
+        }
+    }
+
+    pub fn form(&self) -> JsonLdForm {
+        self.form
+    }
+
+    pub fn set_form(&mut self, form: JsonLdForm) {
+        self.form = form;
+    }
+
+    pub fn context(&self) -> Option<&Value> {
+        self.context.as_ref()
+    }
+
+    pub fn set_context(&mut self, context: Value) {
+        self.context = Some(context);
+    }
+
+    pub fn unset_context(&mut self) {
+        self.context = None;
+    }
+
+    pub fn loader(&self) -> &dyn DocumentLoader {
+        self.loader.as_ref()
+    }
+
+    pub fn set_loader<L>(&mut self, loader: L)
+    where
+        L: DocumentLoader + 'static,
+    {
+        self.loader = Rc::new(loader);
+    }
+
+    pub fn use_native_types(&self) -> bool {
+        self.use_native_types
+    }
+
+    pub fn set_use_native_types(&mut self, use_native_types: bool) {
+        self.use_native_types = use_native_types;
+    }
+
+    pub fn compact_arrays(&self) -> bool {
+        self.compact_arrays
+    }
+
+    pub fn set_compact_arrays(&mut self, compact_arrays: bool) {
+        self.compact_arrays = compact_arrays;
+    }
+
+    pub fn pretty_print(&self) -> bool {
+        self.pretty_print
+    }
+
+    pub fn set_pretty_print(&mut self, pretty_print: bool) {
+        self.pretty_print = pretty_print;
+    }
+
+    pub fn frame(&self) -> Option<&Value> {
+        self.frame.as_ref()
+    }
+
+    pub fn set_frame(&mut self, frame: Value) {
+        self.form = JsonLdForm::Framed;
+        self.frame = Some(frame);
+    }
+
+    pub fn unset_frame(&mut self) {
+        self.frame = None;
+    }
+
+    pub fn embed(&self) -> JsonLdEmbed {
+        self.embed
+    }
+
+    pub fn set_embed(&mut self, embed: JsonLdEmbed) {
+        self.embed = embed;
+    }
+
+    pub fn explicit(&self) -> bool {
+        self.explicit
+    }
+
+    pub fn set_explicit(&mut self, explicit: bool) {
+        self.explicit = explicit;
+    }
+
+    pub fn omit_default(&self) -> bool {
+        self.omit_default
+    }
+
+    pub fn set_omit_default(&mut self, omit_default: bool) {
+        self.omit_default = omit_default;
+    }
+
+    pub fn require_all(&self) -> bool {
+        self.require_all
+    }
+
+    pub fn set_require_all(&mut self, require_all: bool) {
+        self.require_all = require_all;
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
-// Private Functions
-// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(JsonLdWriter, JsonLdWriterOptions);
+
+impl ObjectWriter<DataSet> for JsonLdWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, data_set: &DataSet) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let document = self.to_document(data_set)?;
+        if self.options.pretty_print() {
+            serde_json::to_writer_pretty(w, &document).map_err(json_error)?;
+        } else {
+            serde_json::to_writer(w, &document).map_err(json_error)?;
+        }
+        Ok(())
+    }
+}
+
+impl DataSetWriter for JsonLdWriter {}
+
+impl ObjectWriter<Graph> for JsonLdWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        self.write(w, &DataSet::from(graph.clone()))
+    }
+}
+
+impl GraphWriter for JsonLdWriter {}
+
+impl JsonLdWriter {
+    pub fn with_options(self, options: JsonLdWriterOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Convert the data set into a JSON-LD document in the form chosen in the options.
+    ///
+    pub fn to_document(&self, data_set: &DataSet) -> Result<Value, Error> {
+        let expanded = from_data_set(data_set, self.options.use_native_types())?;
+        let compactor = || {
+            Compactor::new(
+                ContextProcessor::new(self.options.loader()),
+                self.options.compact_arrays(),
+            )
+        };
+        match (self.options.form(), self.options.context()) {
+            (JsonLdForm::Expanded, _) => Ok(expanded),
+            (JsonLdForm::Flattened, None) => Ok(flatten(&expanded)),
+            (JsonLdForm::Flattened, Some(context)) => {
+                compactor().compact_document(context, &flatten(&expanded), None)
+            }
+            (JsonLdForm::Compacted, _) => {
+                compactor().compact_document(&self.context(data_set), &expanded, None)
+            }
+            (JsonLdForm::Framed, _) => {
+                let loader = self.options.loader();
+                let frame = match self.options.frame() {
+                    Some(Value::String(iri)) => loader.load(&Iri::from_str(iri)?)?.into_document(),
+                    Some(frame) => frame.clone(),
+                    None => Value::Object(Map::default()),
+                };
+                let expanded_frame = Expander::new(ContextProcessor::new(loader))
+                    .with_frame_expansion(true)
+                    .expand_document(&Context::new(None), &frame, None)?;
+                let framed = frame_document(
+                    &expanded,
+                    &expanded_frame,
+                    FrameFlags {
+                        embed: self.options.embed(),
+                        explicit: self.options.explicit(),
+                        omit_default: self.options.omit_default(),
+                        require_all: self.options.require_all(),
+                    },
+                )?;
+                let context = match frame.get(KW_CONTEXT) {
+                    Some(context) => context.clone(),
+                    None => self.context(data_set),
+                };
+                let compacted =
+                    compactor().compact_document(&context, &Value::Array(framed), None)?;
+                Ok(remove_preserve(compacted))
+            }
+        }
+    }
+
+    ///
+    /// The context supplied in the options, or one derived from the prefix mappings of the graphs
+    /// in the data set.
+    ///
+    fn context(&self, data_set: &DataSet) -> Value {
+        match self.options.context() {
+            Some(context) => context.clone(),
+            None => {
+                let mut mappings = IriPrefixMap::empty();
+                for graph in data_set.graphs() {
+                    for (prefix, iri) in graph.prefix_mappings().mappings() {
+                        mappings.insert(prefix.clone(), iri.clone());
+                    }
+                }
+                context_from_mappings(&mappings)
+            }
+        }
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
-// Modules
+// Private Functions
 // ------------------------------------------------------------------------------------------------
+
+///
+/// Derive a context from prefix mappings, the default namespace becomes the vocabulary mapping
+/// and every other prefix a term usable in compact IRIs.
+///
+fn context_from_mappings(mappings: &IriPrefixMap) -> Value {
+    let mut context = Map::default();
+    for (prefix, iri) in mappings.mappings() {
+        let key = match prefix.name_string() {
+            Some(name) => name.to_string(),
+            None => KW_VOCAB.to_string(),
+        };
+        let _ = context.insert(key, Value::String(format!("{iri:#}")));
+    }
+    Value::Object(context)
+}
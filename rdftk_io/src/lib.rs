@@ -4,29 +4,44 @@
 //!
 //! The following are some well-known formats (see [Wikipedia](https://en.wikipedia.org/wiki/Resource_Description_Framework#Serialization_formats)
 //! for a description of different serializations), support is indicated in the final column with
-//! an **R** for read support and **W** for write support. One additional module, `dot` allows for the
-//! creation of [GraphViz](https://graphviz.gitlab.io/) dot files for a visualization of a graph's structure.
+//! an **R** for read support and **W** for write support. The final five modules, `dot`, `mermaid`,
+//! `plantuml`, `graphml`, and `cytoscape`, write a graph for visualization or analysis rather
+//! than interchange and, like the HTML readers `rdfa` and `microdata`, are not part of the
+//! `format` registry.
 //!
-//!
-//! | Module    | Name                                                                                                | MIME Type                   | R/W     |
-//! |-----------|---------------------------------------------------------------------------------------------------- |-----------------------------|---------|
-//! | `nt`      | [RDF 1.1 N-Triples](https://www.w3.org/TR/n-triples/); A line-based syntax for an RDF graph         | `application/n-triples`     | **R+W** |
-//! | `nq`      | [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/); A line-based syntax for RDF datasets             | `application/n-quads`       | **W**   |
-//! | `turtle`  | [RDF 1.1 Turtle](https://www.w3.org/TR/turtle/); Terse RDF Triple Language                          | `text/turtle`               | **W**   |
-//! | `trig`    | [RDF 1.1 TriG](https://www.w3.org/TR/trig/); RDF Dataset Language                                   | `application/trig`          |         |
-//! | `xml`     | [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)                                     | `application/rdf+xml`       | **W**   |
-//! | `json`    | [RDF 1.1 JSON Alternate Serialization](https://www.w3.org/TR/rdf-json/)                             | `application/rdf+json`      | **W**   |
-//! | `n3`      | [Notation3 (N3): A readable RDF syntax](https://www.w3.org/TeamSubmission/n3/)                      | `text/rdf+n3`               | **W**   |
-//! | TBD       | [Binary RDF Representation for Publication and Exchange (HDT)](https://www.w3.org/Submission/HDT/)  | N/A                         |         |
-//! | TBD       | [RDF Binary using Apache Thrift](https://afs.github.io/rdf-thrift/)                                 | `application/x-binary-rdf`  |         |
-//! | TBD       | [JSON-LD 1.1](https://www.w3.org/TR/json-ld/); A JSON-based Serialization for Linked Data           | `application/ld+json`       |         |
-//! | TBD       | [RDFa Core 1.1 - Third Edition](https://www.w3.org/TR/rdfa-core/)                                   | `text/html`                 |         |
+//! | Module      | Name                                                                                               | MIME Type                    | R/W     |
+//! |-------------|----------------------------------------------------------------------------------------------------|------------------------------|---------|
+//! | `nt`        | [RDF 1.1 N-Triples](https://www.w3.org/TR/n-triples/); A line-based syntax for an RDF graph        | `application/n-triples`      | **R+W** |
+//! | `nq`        | [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/); A line-based syntax for RDF datasets            | `application/n-quads`        | **R+W** |
+//! | `turtle`    | [RDF 1.1 Turtle](https://www.w3.org/TR/turtle/); Terse RDF Triple Language                         | `text/turtle`                | **R+W** |
+//! | `trig`      | [RDF 1.1 TriG](https://www.w3.org/TR/trig/); RDF Dataset Language                                  | `text/trig`                  | **R+W** |
+//! | `n3`        | [Notation3 (N3): A readable RDF syntax](https://www.w3.org/TeamSubmission/n3/)                     | `application/n3`             | **R+W** |
+//! | `xml`       | [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)                                    | `application/rdf+xml`        | **R+W** |
+//! | `json_ld`   | [JSON-LD 1.1](https://www.w3.org/TR/json-ld/); A JSON-based Serialization for Linked Data          | `application/ld+json`        | **R+W** |
+//! | `json`      | [RDF 1.1 JSON Alternate Serialization](https://www.w3.org/TR/rdf-json/)                            | `application/rdf+json`       | **R+W** |
+//! | `trix`      | [TriX: RDF Triples in XML](https://www.hpl.hp.com/techreports/2004/HPL-2004-56.html)               | `application/trix`           | **R+W** |
+//! | `hextuples` | [HexTuples](https://github.com/ontola/hextuples); newline-delimited JSON, one array per statement  | `application/hex+x-ndjson`   | **R+W** |
+//! | `binary`    | RDFtk Binary; a compact stream of statements with lookup tables, see the module documentation      | `application/x-rdftk-binary` | **R+W** |
+//! | `hdt`       | [Binary RDF Representation for Publication and Exchange (HDT)](https://www.w3.org/Submission/HDT/) | `application/vnd.hdt`        | **R+W** |
+//! | `rdfa`      | [RDFa Core 1.1 - Third Edition](https://www.w3.org/TR/rdfa-core/)                                  | `text/html`                  | **R**   |
+//! | `microdata` | [Microdata to RDF](https://www.w3.org/TR/microdata-rdf/)                                           | `text/html`                  | **R**   |
+//! | `dot`       | [GraphViz](https://graphviz.gitlab.io/) dot; a diagram of a graph or data set                      | `text/vnd.graphviz`          | **W**   |
+//! | `mermaid`   | [Mermaid](https://mermaid.js.org/) flowchart; a diagram that renders in Markdown                   | `text/vnd.mermaid`           | **W**   |
+//! | `plantuml`  | [PlantUML](https://plantuml.com/) object diagram                                                   | `text/vnd.plantuml`          | **W**   |
+//! | `graphml`   | [GraphML](http://graphml.graphdrawing.org/); an XML format for graph-analysis tools                | `application/graphml+xml`    | **W**   |
+//! | `cytoscape` | [Cytoscape.js](https://js.cytoscape.org/) elements JSON; for graph-analysis tools                  | `application/json`           | **W**   |
 //!
 //! Each module will also provide public constants `NAME`, `FILE_EXTENSION`, and `MIME_TYPE`.
+//! The `format` module collects these into a single registry which can choose a format by
+//! file extension, MIME type, or content, and the `read_path` function uses it to read a file
+//! without knowing its format in advance.
 //!
 
+use rdftk_core::error::Error;
 use rdftk_core::model::data_set::DataSet;
 use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::Statement;
+use rdftk_iri::{Iri, Namespace};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -38,6 +53,148 @@ pub trait GraphWriter: ObjectWriter<Graph, Error = rdftk_core::error::Error> {}
 
 pub trait DataSetWriter: ObjectWriter<DataSet, Error = rdftk_core::error::Error> {}
 
+///
+/// A writer that accepts statements one at a time, rather than requiring a complete `Graph`,
+/// so that data can be converted or generated without first being collected in memory.
+///
+/// A sink is driven by calling `start` once, then any number of `prefix` and `statement`
+/// calls, and finally `finish`; formats that have no prefix syntax ignore `prefix`. Sinks are
+/// usually created from the corresponding writer, for example `NTripleWriter::sink`, so that
+/// they share the writer's options.
+///
+pub trait StatementSink {
+    /// Called once, before any other method, to write any document header.
+    fn start(&mut self) -> Result<(), Error>;
+
+    /// Declare a prefix which may be used to abbreviate any following statements.
+    fn prefix(&mut self, prefix: &Namespace, namespace: &Iri) -> Result<(), Error>;
+
+    /// Write a single statement.
+    fn statement(&mut self, statement: &Statement) -> Result<(), Error>;
+
+    /// Called once, after all statements, to write any document trailer and flush the output.
+    fn finish(&mut self) -> Result<(), Error>;
+}
+
+///
+/// How a reader treats the blank node labels in a document.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlankNodeLabels {
+    /// Keep each label as the identifier of its blank node, except that a label with the form
+    /// of a generated identifier is replaced so that it cannot clash with the identifier of an
+    /// anonymous node in the same document.
+    #[default]
+    Preserve,
+    /// Replace each label with a generated identifier, so that blank nodes from different
+    /// documents cannot clash when their graphs are merged.
+    Relabel,
+}
+
+///
+/// Options, shared by all readers, to change the behavior of the reader. Not every option
+/// applies to every representation; the base IRI is ignored by those that only allow
+/// absolute IRIs, and prefixes are only carried by those that can declare them.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReaderOptions {
+    base: Option<Iri>,
+    carry_prefixes: bool,
+    blank_nodes: BlankNodeLabels,
+    lenient: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for ReaderOptions {
+    fn default() -> Self {
+        Self {
+            base: None,
+            carry_prefixes: true,
+            blank_nodes: Default::default(),
+            lenient: false,
+        }
+    }
+}
+
+impl ReaderOptions {
+    ///
+    /// The base IRI used to resolve relative IRIs in the document, usually the location the
+    /// document was retrieved from. Any base declared within the document is resolved against
+    /// this value.
+    ///
+    pub fn with_base(self, base: Iri) -> Self {
+        Self {
+            base: Some(base),
+            ..self
+        }
+    }
+
+    ///
+    /// When set, the default, the prefixes declared in the document are added to the prefix
+    /// mappings of the resulting graph.
+    ///
+    pub fn with_carry_prefixes(self, carry_prefixes: bool) -> Self {
+        Self {
+            carry_prefixes,
+            ..self
+        }
+    }
+
+    pub fn with_blank_nodes(self, blank_nodes: BlankNodeLabels) -> Self {
+        Self {
+            blank_nodes,
+            ..self
+        }
+    }
+
+    ///
+    /// When lenient, a statement that cannot be parsed is skipped, rather than failing the
+    /// whole read. This is supported by the N-Triples, N-Quads, and Turtle readers.
+    ///
+    pub fn with_lenient(self, lenient: bool) -> Self {
+        Self { lenient, ..self }
+    }
+
+    pub fn base(&self) -> Option<&Iri> {
+        self.base.as_ref()
+    }
+
+    pub fn set_base(&mut self, base: Iri) {
+        self.base = Some(base);
+    }
+
+    pub fn unset_base(&mut self) {
+        self.base = None;
+    }
+
+    pub fn carry_prefixes(&self) -> bool {
+        self.carry_prefixes
+    }
+
+    pub fn set_carry_prefixes(&mut self, carry_prefixes: bool) {
+        self.carry_prefixes = carry_prefixes;
+    }
+
+    pub fn blank_nodes(&self) -> BlankNodeLabels {
+        self.blank_nodes
+    }
+
+    pub fn set_blank_nodes(&mut self, blank_nodes: BlankNodeLabels) {
+        self.blank_nodes = blank_nodes;
+    }
+
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -45,12 +202,39 @@ pub trait DataSetWriter: ObjectWriter<DataSet, Error = rdftk_core::error::Error>
 #[macro_use]
 mod common;
 
+pub mod format;
+pub use format::read_path;
+
+#[cfg(feature = "binary")]
+pub mod binary;
+
+#[cfg(feature = "cytoscape")]
+pub mod cytoscape;
+
 #[cfg(feature = "dot")]
 pub mod dot;
 
+#[cfg(feature = "graphml")]
+pub mod graphml;
+
+#[cfg(feature = "hdt")]
+pub mod hdt;
+
+#[cfg(feature = "hextuples")]
+pub mod hextuples;
+
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "json-ld")]
+pub mod json_ld;
+
+#[cfg(feature = "mermaid")]
+pub mod mermaid;
+
+#[cfg(feature = "microdata")]
+pub mod microdata;
+
 #[cfg(feature = "n3")]
 pub mod n3;
 
@@ -60,9 +244,18 @@ pub mod nq;
 #[cfg(feature = "nt")]
 pub mod nt;
 
+#[cfg(feature = "plantuml")]
+pub mod plantuml;
+
+#[cfg(feature = "rdfa")]
+pub mod rdfa;
+
 #[cfg(feature = "trig")]
 pub mod trig;
 
+#[cfg(feature = "trix")]
+pub mod trix;
+
 #[cfg(feature = "turtle")]
 pub mod turtle;
 
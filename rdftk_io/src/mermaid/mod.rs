@@ -0,0 +1,47 @@
+/*!
+Provides for writing a `Graph` instance as a [Mermaid](https://mermaid.js.org/) flowchart, which
+may be embedded in a Markdown code block with the `mermaid` language tag.
+
+As with the `dot` module each kind of node, blank, IRI, and literal, has its own shape and color;
+colors are applied using a `classDef` for each kind. Nested statements and collections also have
+their own shape and color, and are labelled `<< s p o >>` and `( a b )` respectively.
+
+# Example
+
+```rust
+use rdftk_io::mermaid::{MermaidOptions, MermaidShape, MermaidWriter};
+# use objio::{HasOptions, ObjectWriter};
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { rdftk_core::model::graph::Graph::default() }
+
+let options = MermaidOptions::default()
+    .with_iri_shape(MermaidShape::Hexagon)
+    .with_prefixed_labels(true)
+    .with_direction("LR");
+
+let writer = MermaidWriter::default().with_options(options);
+
+let result = writer.write_to_string(&make_graph());
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "Mermaid";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "mmd";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "text/vnd.mermaid";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod writer;
+pub use writer::{MermaidOptions, MermaidShape, MermaidWriter};
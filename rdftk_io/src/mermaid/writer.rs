@@ -0,0 +1,496 @@
+use crate::common::diagram::{Diagram, NodeKind};
+use crate::GraphWriter;
+use objio::HasOptions;
+use objio::{impl_has_options, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::Graph;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The flowchart node shapes supported by Mermaid.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MermaidShape {
+    #[default]
+    Rectangle,
+    Rounded,
+    Stadium,
+    Subroutine,
+    Cylinder,
+    Circle,
+    DoubleCircle,
+    Asymmetric,
+    Rhombus,
+    Hexagon,
+    Parallelogram,
+    Trapezoid,
+}
+
+///
+/// Used to set configurable components of the generated flowchart, these follow the same
+/// model as `DotOptions` with a shape and color for each kind of node.
+///
+#[derive(Debug)]
+pub struct MermaidOptions {
+    /// The shape used to render a blank node. Default is `Circle`.
+    blank_shape: MermaidShape,
+    /// The color name used to render a blank node. Default is `green`.
+    blank_color: String,
+    /// Determines whether labels are included in blank node shapes. Default is `false`.
+    blank_labels: bool,
+    /// The shape used to render an IRI node. Default is `Stadium`.
+    iri_shape: MermaidShape,
+    /// The color name used to render an IRI node. Default is `blue`.
+    iri_color: String,
+    /// The shape used to render a literal node. Default is `Rectangle`.
+    literal_shape: MermaidShape,
+    /// The color name used to render a literal node. Default is `black`.
+    literal_color: String,
+    /// The shape used to render a nested statement. Default is `Hexagon`.
+    statement_shape: MermaidShape,
+    /// The color name used to render a nested statement. Default is `orange`.
+    statement_color: String,
+    /// The shape used to render a collection. Default is `Subroutine`.
+    collection_shape: MermaidShape,
+    /// The color name used to render a collection. Default is `purple`.
+    collection_color: String,
+    /// The prefix string used to generate internal node identifiers. Default is `node_`.
+    node_prefix: String,
+    /// Determines whether IRI node labels are compressed using the graph's prefix mappings.
+    /// Default is `false`.
+    prefixed_labels: bool,
+    /// The direction of the flowchart, one of `TB`, `BT`, `LR`, or `RL`. Default is `BT`.
+    direction: String,
+}
+
+///
+/// This struct implements the `GraphWriter` trait and will write out a Mermaid flowchart for
+/// the entire graph.
+///
+#[derive(Debug, Default)]
+pub struct MermaidWriter {
+    options: MermaidOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl MermaidShape {
+    fn delimiters(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Rectangle => ("[", "]"),
+            Self::Rounded => ("(", ")"),
+            Self::Stadium => ("([", "])"),
+            Self::Subroutine => ("[[", "]]"),
+            Self::Cylinder => ("[(", ")]"),
+            Self::Circle => ("((", "))"),
+            Self::DoubleCircle => ("(((", ")))"),
+            Self::Asymmetric => (">", "]"),
+            Self::Rhombus => ("{", "}"),
+            Self::Hexagon => ("{{", "}}"),
+            Self::Parallelogram => ("[/", "/]"),
+            Self::Trapezoid => ("[/", "\\]"),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for MermaidOptions {
+    fn default() -> Self {
+        Self {
+            blank_shape: MermaidShape::Circle,
+            blank_color: "green".to_string(),
+            blank_labels: false,
+            iri_shape: MermaidShape::Stadium,
+            iri_color: "blue".to_string(),
+            literal_shape: MermaidShape::Rectangle,
+            literal_color: "black".to_string(),
+            statement_shape: MermaidShape::Hexagon,
+            statement_color: "orange".to_string(),
+            collection_shape: MermaidShape::Subroutine,
+            collection_color: "purple".to_string(),
+            node_prefix: "node_".to_string(),
+            prefixed_labels: false,
+            direction: "BT".to_string(),
+        }
+    }
+}
+
+impl MermaidOptions {
+    pub fn with_blank_shape(self, blank_shape: MermaidShape) -> Self {
+        Self {
+            blank_shape,
+            ..self
+        }
+    }
+
+    pub fn set_blank_shape(&mut self, blank_shape: MermaidShape) {
+        self.blank_shape = blank_shape;
+    }
+
+    pub fn blank_shape(&self) -> MermaidShape {
+        self.blank_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_blank_color<S>(self, blank_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            blank_color: blank_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_blank_color<S>(&mut self, blank_color: S)
+    where
+        S: Into<String>,
+    {
+        self.blank_color = blank_color.into();
+    }
+
+    pub fn blank_color(&self) -> &String {
+        &self.blank_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_blank_labels(self, blank_labels: bool) -> Self {
+        Self {
+            blank_labels,
+            ..self
+        }
+    }
+
+    pub fn set_blank_labels(&mut self, blank_labels: bool) {
+        self.blank_labels = blank_labels;
+    }
+
+    pub fn blank_labels(&self) -> bool {
+        self.blank_labels
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_iri_shape(self, iri_shape: MermaidShape) -> Self {
+        Self { iri_shape, ..self }
+    }
+
+    pub fn set_iri_shape(&mut self, iri_shape: MermaidShape) {
+        self.iri_shape = iri_shape;
+    }
+
+    pub fn iri_shape(&self) -> MermaidShape {
+        self.iri_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_iri_color<S>(self, iri_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            iri_color: iri_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_iri_color<S>(&mut self, iri_color: S)
+    where
+        S: Into<String>,
+    {
+        self.iri_color = iri_color.into();
+    }
+
+    pub fn iri_color(&self) -> &String {
+        &self.iri_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_literal_shape(self, literal_shape: MermaidShape) -> Self {
+        Self {
+            literal_shape,
+            ..self
+        }
+    }
+
+    pub fn set_literal_shape(&mut self, literal_shape: MermaidShape) {
+        self.literal_shape = literal_shape;
+    }
+
+    pub fn literal_shape(&self) -> MermaidShape {
+        self.literal_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_literal_color<S>(self, literal_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            literal_color: literal_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_literal_color<S>(&mut self, literal_color: S)
+    where
+        S: Into<String>,
+    {
+        self.literal_color = literal_color.into();
+    }
+
+    pub fn literal_color(&self) -> &String {
+        &self.literal_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_statement_shape(self, statement_shape: MermaidShape) -> Self {
+        Self {
+            statement_shape,
+            ..self
+        }
+    }
+
+    pub fn set_statement_shape(&mut self, statement_shape: MermaidShape) {
+        self.statement_shape = statement_shape;
+    }
+
+    pub fn statement_shape(&self) -> MermaidShape {
+        self.statement_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_statement_color<S>(self, statement_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            statement_color: statement_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_statement_color<S>(&mut self, statement_color: S)
+    where
+        S: Into<String>,
+    {
+        self.statement_color = statement_color.into();
+    }
+
+    pub fn statement_color(&self) -> &String {
+        &self.statement_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_collection_shape(self, collection_shape: MermaidShape) -> Self {
+        Self {
+            collection_shape,
+            ..self
+        }
+    }
+
+    pub fn set_collection_shape(&mut self, collection_shape: MermaidShape) {
+        self.collection_shape = collection_shape;
+    }
+
+    pub fn collection_shape(&self) -> MermaidShape {
+        self.collection_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_collection_color<S>(self, collection_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            collection_color: collection_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_collection_color<S>(&mut self, collection_color: S)
+    where
+        S: Into<String>,
+    {
+        self.collection_color = collection_color.into();
+    }
+
+    pub fn collection_color(&self) -> &String {
+        &self.collection_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_node_prefix<S>(self, node_prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            node_prefix: node_prefix.into(),
+            ..self
+        }
+    }
+
+    pub fn set_node_prefix<S>(&mut self, node_prefix: S)
+    where
+        S: Into<String>,
+    {
+        self.node_prefix = node_prefix.into();
+    }
+
+    pub fn node_prefix(&self) -> &String {
+        &self.node_prefix
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_prefixed_labels(self, prefixed_labels: bool) -> Self {
+        Self {
+            prefixed_labels,
+            ..self
+        }
+    }
+
+    pub fn set_prefixed_labels(&mut self, prefixed_labels: bool) {
+        self.prefixed_labels = prefixed_labels;
+    }
+
+    pub fn prefixed_labels(&self) -> bool {
+        self.prefixed_labels
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_direction<S>(self, direction: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            direction: direction.into(),
+            ..self
+        }
+    }
+
+    pub fn set_direction<S>(&mut self, direction: S)
+    where
+        S: Into<String>,
+    {
+        self.direction = direction.into();
+    }
+
+    pub fn direction(&self) -> &String {
+        &self.direction
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(MermaidWriter, MermaidOptions);
+
+impl MermaidWriter {
+    pub fn with_options(self, options: MermaidOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+impl ObjectWriter<Graph> for MermaidWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let diagram = Diagram::from_graph(graph, self.options.prefixed_labels, false);
+        let prefix = &self.options.node_prefix;
+
+        writeln!(w, "flowchart {}", self.options.direction)?;
+
+        let mut classes: [(&str, &String, Vec<String>); 5] = [
+            ("blank", &self.options.blank_color, Vec::default()),
+            ("iri", &self.options.iri_color, Vec::default()),
+            ("literal", &self.options.literal_color, Vec::default()),
+            ("statement", &self.options.statement_color, Vec::default()),
+            ("collection", &self.options.collection_color, Vec::default()),
+        ];
+        for node in diagram.nodes() {
+            let (shape, label, class) = match node.kind {
+                NodeKind::Blank if self.options.blank_labels => {
+                    (self.options.blank_shape, node.label.as_str(), 0)
+                }
+                NodeKind::Blank => (self.options.blank_shape, " ", 0),
+                NodeKind::Iri => (self.options.iri_shape, node.label.as_str(), 1),
+                NodeKind::Literal => (self.options.literal_shape, node.label.as_str(), 2),
+                NodeKind::Statement => (self.options.statement_shape, node.label.as_str(), 3),
+                NodeKind::Collection => (self.options.collection_shape, node.label.as_str(), 4),
+            };
+            let (open, close) = shape.delimiters();
+            writeln!(
+                w,
+                "    {prefix}{}{open}\"{}\"{close}",
+                node.id,
+                escape_label(label)
+            )?;
+            classes[class].2.push(format!("{prefix}{}", node.id));
+        }
+
+        for edge in diagram.edges() {
+            writeln!(
+                w,
+                "    {prefix}{} -->|\"{}\"| {prefix}{}",
+                edge.subject,
+                escape_label(&edge.label),
+                edge.object
+            )?;
+        }
+
+        for (class, color, ids) in classes.iter().filter(|(_, _, ids)| !ids.is_empty()) {
+            writeln!(w, "    classDef {class} stroke:{color}")?;
+            writeln!(w, "    class {} {class}", ids.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl GraphWriter for MermaidWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Labels are quoted, so only characters with a meaning within a quoted string, or to the
+/// HTML label renderer, need to be replaced with Mermaid entity codes.
+///
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '#' => escaped.push_str("#35;"),
+            '"' => escaped.push_str("#quot;"),
+            '<' => escaped.push_str("#lt;"),
+            '>' => escaped.push_str("#gt;"),
+            '\n' => escaped.push_str("<br>"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
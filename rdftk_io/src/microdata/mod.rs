@@ -0,0 +1,57 @@
+/*!
+Provides for reading [HTML Microdata](https://html.spec.whatwg.org/multipage/microdata.html)
+items as RDF, following the [Microdata to RDF](https://www.w3.org/TR/microdata-rdf/) algorithm.
+
+# Example Reader
+
+Each top-level item, an element with `itemscope` that is not itself the value of an
+`itemprop`, is described by a blank node or, where present, the resolved `itemid`. Each
+`itemtype` is added as an `rdf:type` and the first type determines the vocabulary used to
+create property IRIs from `itemprop` names; names that are absolute IRIs are used as-is.
+Properties of nested items without a type use the vocabulary of the enclosing item, and
+`itemref` is followed to include properties from elsewhere in the document.
+
+```rust
+use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::microdata::MicrodataReader;
+
+let reader = MicrodataReader::default();
+
+let graph: Graph = reader.read_from_string(r#"<!DOCTYPE html>
+<html>
+  <body>
+    <div itemscope itemtype="https://schema.org/Person">
+      <span itemprop="name">Alice</span>
+      <a itemprop="url" href="https://example.org/alice">Home</a>
+      <div itemprop="address" itemscope itemtype="https://schema.org/PostalAddress">
+        <span itemprop="addressLocality">Seattle</span>
+      </div>
+    </div>
+  </body>
+</html>"#).unwrap();
+
+assert_eq!(graph.len(), 6);
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "Microdata";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "html";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "text/html";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod reader;
+pub use reader::MicrodataReader;
@@ -0,0 +1,339 @@
+use crate::common::html::{document_base, element_language, read_html_document};
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::Iri;
+use rdftk_names::{rdf, xsd};
+use regex::Regex;
+use scraper::ElementRef;
+use std::collections::HashMap;
+use std::io::Read;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the GraphReader trait to read the Microdata items embedded in an HTML
+/// document.
+///
+#[derive(Clone, Debug, Default)]
+pub struct MicrodataReader {
+    options: ReaderOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The vocabulary of the enclosing item, used by nested items without an `itemtype`.
+///
+#[derive(Clone, Debug, Default)]
+struct Context {
+    vocabulary: Option<String>,
+}
+
+#[derive(Debug)]
+struct Parser<'a> {
+    base: Option<url::Url>,
+    ids: HashMap<&'a str, ElementRef<'a>>,
+    items: Vec<(ElementRef<'a>, SubjectNode)>,
+    graph: Graph,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const ATTRIBUTE_CONTENT: &str = "content";
+const ATTRIBUTE_DATA: &str = "data";
+const ATTRIBUTE_DATETIME: &str = "datetime";
+const ATTRIBUTE_HREF: &str = "href";
+const ATTRIBUTE_ID: &str = "id";
+const ATTRIBUTE_ITEMID: &str = "itemid";
+const ATTRIBUTE_ITEMPROP: &str = "itemprop";
+const ATTRIBUTE_ITEMREF: &str = "itemref";
+const ATTRIBUTE_ITEMSCOPE: &str = "itemscope";
+const ATTRIBUTE_ITEMTYPE: &str = "itemtype";
+const ATTRIBUTE_SRC: &str = "src";
+const ATTRIBUTE_VALUE: &str = "value";
+
+const SRC_ELEMENTS: &[&str] = &[
+    "audio", "embed", "iframe", "img", "source", "track", "video",
+];
+const HREF_ELEMENTS: &[&str] = &["a", "area", "link"];
+
+lazy_static::lazy_static! {
+    static ref DATE: Regex = Regex::new(r"^-?\d{4,}-\d{2}-\d{2}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref TIME: Regex =
+        Regex::new(r"^\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref DATE_TIME: Regex = Regex::new(
+        r"^-?\d{4,}-\d{2}-\d{2}T\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:\d{2})?$"
+    )
+    .unwrap();
+    static ref DURATION: Regex = Regex::new(
+        r"^-?P(\d+Y)?(\d+M)?(\d+D)?(T(\d+H)?(\d+M)?(\d+(\.\d+)?S)?)?$"
+    )
+    .unwrap();
+    static ref YEAR_MONTH: Regex = Regex::new(r"^-?\d{4,}-\d{2}$").unwrap();
+    static ref YEAR: Regex = Regex::new(r"^-?\d{4,}$").unwrap();
+    static ref INTEGER: Regex = Regex::new(r"^[+-]?\d+$").unwrap();
+    static ref DOUBLE: Regex = Regex::new(r"^[+-]?(\d+(\.\d*)?|\.\d+)([eE][+-]?\d+)?$").unwrap();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(MicrodataReader, ReaderOptions);
+
+impl ObjectReader<Graph> for MicrodataReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Error>
+    where
+        R: Read,
+    {
+        let document = read_html_document(r)?;
+        let root = document.root_element();
+        let mut ids = HashMap::default();
+        for element in root.descendent_elements() {
+            if let Some(id) = element.value().attr(ATTRIBUTE_ID) {
+                let _ = ids.entry(id).or_insert(element);
+            }
+        }
+        let mut parser = Parser {
+            base: document_base(root, self.options.base())?,
+            ids,
+            items: Vec::default(),
+            graph: Graph::default(),
+        };
+        // SPEC: §4 Algorithm, for each top-level item generate its triples.
+        for element in root.descendent_elements() {
+            if is_item(element) && element.value().attr(ATTRIBUTE_ITEMPROP).is_none() {
+                let _ = parser.item(element, &Context::default())?;
+            }
+        }
+        Ok(parser.graph)
+    }
+}
+
+impl MicrodataReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Parser<'a> {
+    ///
+    /// Generate the triples for an item, returning the node that identifies it.
+    ///
+    fn item(&mut self, item: ElementRef<'a>, context: &Context) -> Result<SubjectNode, Error> {
+        // SPEC: §4.1 Generate the triples, step 1; an item is only described once.
+        if let Some((_, subject)) = self.items.iter().find(|(element, _)| *element == item) {
+            return Ok(subject.clone());
+        }
+        let subject: SubjectNode = match item.value().attr(ATTRIBUTE_ITEMID) {
+            Some(id) => self.resolve(id.trim())?.into(),
+            None => BlankNode::generate().into(),
+        };
+        self.items.push((item, subject.clone()));
+
+        let types: Vec<Iri> = item
+            .value()
+            .attr(ATTRIBUTE_ITEMTYPE)
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|a_type| Iri::from_str(a_type).ok())
+            .collect();
+        for a_type in &types {
+            self.insert(subject.clone(), rdf::a_type().clone(), a_type.into());
+        }
+        let context = match types.first() {
+            Some(a_type) => Context {
+                vocabulary: Some(vocabulary(a_type)),
+            },
+            None => context.clone(),
+        };
+
+        for property in self.properties(item) {
+            let value = match property.value().attr(ATTRIBUTE_ITEMSCOPE) {
+                Some(_) => self.item(property, &context)?.to_object(),
+                None => self.property_value(property)?,
+            };
+            for name in property
+                .value()
+                .attr(ATTRIBUTE_ITEMPROP)
+                .unwrap_or_default()
+                .split_whitespace()
+            {
+                if let Some(predicate) = predicate(name, &context) {
+                    self.insert(subject.clone(), predicate, value.clone());
+                }
+            }
+        }
+        Ok(subject)
+    }
+
+    ///
+    /// The HTML _properties of an item_ algorithm, the elements with `itemprop` that are
+    /// descendants of the item, or of the elements it references with `itemref`, without
+    /// crossing into nested items.
+    ///
+    fn properties(&self, item: ElementRef<'a>) -> Vec<ElementRef<'a>> {
+        let mut results = Vec::default();
+        let mut memory = vec![item];
+        let mut pending: Vec<ElementRef<'a>> = item.child_elements().collect();
+        pending.extend(
+            item.value()
+                .attr(ATTRIBUTE_ITEMREF)
+                .unwrap_or_default()
+                .split_whitespace()
+                .filter_map(|id| self.ids.get(id).copied()),
+        );
+        while let Some(element) = pending.pop() {
+            if memory.contains(&element) {
+                continue;
+            }
+            memory.push(element);
+            if !is_item(element) {
+                pending.extend(element.child_elements());
+            }
+            if element.value().attr(ATTRIBUTE_ITEMPROP).is_some() {
+                results.push(element);
+            }
+        }
+        results
+    }
+
+    ///
+    /// The HTML _property value_ of a non-item element, as an IRI or literal.
+    ///
+    fn property_value(&self, element: ElementRef<'_>) -> Result<ObjectNode, Error> {
+        let value = element.value();
+        let name = value.name();
+        let url_attribute = if SRC_ELEMENTS.contains(&name) {
+            Some(ATTRIBUTE_SRC)
+        } else if HREF_ELEMENTS.contains(&name) {
+            Some(ATTRIBUTE_HREF)
+        } else if name == "object" {
+            Some(ATTRIBUTE_DATA)
+        } else {
+            None
+        };
+        Ok(if let Some(attribute) = url_attribute {
+            match value.attr(attribute) {
+                Some(url) => self.resolve(url.trim())?.into(),
+                None => Literal::plain("").into(),
+            }
+        } else if name == "meta" {
+            self.literal(element, value.attr(ATTRIBUTE_CONTENT).unwrap_or_default())
+                .into()
+        } else if name == "data" || name == "meter" {
+            let number = value.attr(ATTRIBUTE_VALUE).unwrap_or_default();
+            if INTEGER.is_match(number) {
+                Literal::with_data_type(number, DataType::from(xsd::integer().clone())).into()
+            } else if DOUBLE.is_match(number) {
+                Literal::with_data_type(number, DataType::Double).into()
+            } else {
+                self.literal(element, number).into()
+            }
+        } else if name == "time" {
+            let date_time = match value.attr(ATTRIBUTE_DATETIME) {
+                Some(date_time) => date_time.to_string(),
+                None => element.text().collect(),
+            };
+            match time_data_type(&date_time) {
+                Some(data_type) => Literal::with_data_type(date_time, data_type).into(),
+                None => self.literal(element, &date_time).into(),
+            }
+        } else {
+            self.literal(element, &element.text().collect::<String>())
+                .into()
+        })
+    }
+
+    fn literal(&self, element: ElementRef<'_>, value: &str) -> Literal {
+        match element_language(element).and_then(|language| LanguageTag::from_str(language).ok()) {
+            Some(language) => Literal::with_language(value, language),
+            None => Literal::plain(value),
+        }
+    }
+
+    fn resolve(&self, iri: &str) -> Result<Iri, Error> {
+        match &self.base {
+            Some(base) => Ok(Iri::from(base.join(iri)?)),
+            None => Ok(Iri::from_str(iri)?),
+        }
+    }
+
+    fn insert(&mut self, subject: SubjectNode, predicate: Iri, object: ObjectNode) {
+        self.graph
+            .insert(Statement::new(subject, predicate, object));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn is_item(element: ElementRef<'_>) -> bool {
+    element.value().attr(ATTRIBUTE_ITEMSCOPE).is_some()
+}
+
+///
+/// The vocabulary of an item type is the type with its final path segment, or fragment,
+/// removed; for example both `https://schema.org/Person` and `https://schema.org/Place` are
+/// in the vocabulary `https://schema.org/`.
+///
+fn vocabulary(a_type: &Iri) -> String {
+    let a_type = format!("{a_type:#}");
+    match a_type.rfind('#').or_else(|| a_type.rfind('/')) {
+        Some(index) => a_type[..=index].to_string(),
+        None => a_type,
+    }
+}
+
+///
+/// Create the predicate for a property name, names that are absolute IRIs are used as-is, any
+/// other name is appended to the vocabulary of the item; names are ignored if the item has no
+/// vocabulary.
+///
+fn predicate(name: &str, context: &Context) -> Option<Iri> {
+    if name.contains(':') {
+        Iri::from_str(name).ok()
+    } else {
+        context
+            .vocabulary
+            .as_ref()
+            .and_then(|vocabulary| Iri::from_str(&format!("{vocabulary}{name}")).ok())
+    }
+}
+
+fn time_data_type(value: &str) -> Option<DataType> {
+    if DATE.is_match(value) {
+        Some(DataType::Date)
+    } else if TIME.is_match(value) {
+        Some(DataType::Time)
+    } else if DATE_TIME.is_match(value) {
+        Some(DataType::DateTime)
+    } else if value.len() > 1 && !value.ends_with('T') && DURATION.is_match(value) {
+        Some(DataType::Duration)
+    } else if YEAR_MONTH.is_match(value) {
+        Some(DataType::from(xsd::g_year_month().clone()))
+    } else if YEAR.is_match(value) {
+        Some(DataType::from(xsd::g_year().clone()))
+    } else {
+        None
+    }
+}
@@ -1,7 +1,33 @@
 /*!
-Provides for reading and writing a `Graph` instance in the
+Provides for reading and writing a `Formula`, or `Graph`, instance in the
 proposed W3C [Notation3 (N3)](https://www.w3.org/TeamSubmission/n3/), _a readable RDF syntax_,
 format.
+
+Quoted formulae (`{ ... }`) and variables declared with `@forAll` and `@forSome` are mapped onto
+the [`Formula`](rdftk_core::model::formulae::Formula) model; a document read as a `Graph` must
+contain neither.
+
+# Example
+
+```rust
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::formulae::Formula;
+use rdftk_io::n3::{N3Reader, N3Writer};
+
+let reader = N3Reader::default();
+let formula: Formula = reader.read_from_string(r#"
+@prefix : <http://example.org/#> .
+@forAll :x .
+{ :x a :Man } => { :x a :Mortal } .
+"#).unwrap();
+assert_eq!(formula.universals().count(), 1);
+assert_eq!(formula.formulae().count(), 2);
+
+let writer = N3Writer::default();
+let result = writer.write_to_string(&formula).unwrap();
+assert!(result.contains("@forAll :x ."));
+```
+
 */
 
 // ------------------------------------------------------------------------------------------------
@@ -27,4 +53,5 @@ pub const FORMAT_IRI: &str = "http://www.w3.org/ns/formats/N3";
 mod reader;
 pub use reader::N3Reader;
 
-pub mod writer;
+mod writer;
+pub use writer::{N3Writer, N3WriterOptions};
@@ -1,6 +1,8 @@
 use crate::common::parser::parse_n3_doc;
-use objio::ObjectReader;
-use rdftk_core::error::Error;
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::{formulae_not_supported_error, Error};
+use rdftk_core::model::formulae::Formula;
 use rdftk_core::model::graph::Graph;
 use std::io::Read;
 
@@ -8,22 +10,54 @@ use std::io::Read;
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// This struct implements the `ObjectReader` trait for formulae, and for graphs. Reading a graph
+/// will fail if the document declares variables or contains quoted formulae.
+///
 #[derive(Debug, Default)]
-pub struct N3Reader {}
+pub struct N3Reader {
+    options: ReaderOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl ObjectReader<Graph> for N3Reader {
+impl_has_options!(N3Reader, ReaderOptions);
+
+impl ObjectReader<Formula> for N3Reader {
     type Error = Error;
 
-    fn read<R>(&self, r: &mut R) -> Result<Graph, Self::Error>
+    fn read<R>(&self, r: &mut R) -> Result<Formula, Self::Error>
     where
         R: Read,
     {
         let mut buffer = String::new();
         r.read_to_string(&mut buffer)?;
-        parse_n3_doc(buffer)
+        parse_n3_doc(buffer, &self.options)
+    }
+}
+
+impl ObjectReader<Graph> for N3Reader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Self::Error>
+    where
+        R: Read,
+    {
+        let formula: Formula = self.read(r)?;
+        if formula.is_plain_graph() {
+            Ok(formula.into_graph())
+        } else {
+            Err(formulae_not_supported_error(super::NAME))
+        }
+    }
+}
+
+impl N3Reader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
     }
 }
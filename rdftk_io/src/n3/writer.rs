@@ -1,25 +1,266 @@
-// use ...
+use crate::common::literal::quoted_lexical_form;
+use crate::GraphWriter;
+use itertools::Itertools;
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::{rdf_star_not_supported_error, Error};
+use rdftk_core::model::formulae::{Formula, Variable};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap};
+use rdftk_names::{log, owl, rdf};
+use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+///
+/// Options to change the behavior of the [`N3Writer`] implementation.
+///
+#[derive(Clone, Debug)]
+pub struct N3WriterOptions {
+    indent_width: usize,
+    use_verb_shorthand: bool,
+}
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// This struct implements the `ObjectWriter` trait for formulae, and for graphs, writing quoted
+/// formulae inline as `{ ... }` along with any variable declarations.
+///
+#[derive(Debug, Default)]
+pub struct N3Writer {
+    options: N3WriterOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Functions
-// ------------------------------------------------------------------------------------------------
+impl Default for N3WriterOptions {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            use_verb_shorthand: true,
+        }
+    }
+}
+
+impl N3WriterOptions {
+    pub fn with_indent_width(self, indent_width: usize) -> Self {
+        Self {
+            indent_width,
+            ..self
+        }
+    }
+
+    ///
+    /// If `true` the predicates `rdf:type`, `owl:sameAs`, and `log:implies` are written as
+    /// `a`, `=`, and `=>` respectively.
+    ///
+    pub fn with_verb_shorthand(self, use_verb_shorthand: bool) -> Self {
+        Self {
+            use_verb_shorthand,
+            ..self
+        }
+    }
+
+    pub fn indent_width(&self) -> usize {
+        self.indent_width
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: usize) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn use_verb_shorthand(&self) -> bool {
+        self.use_verb_shorthand
+    }
+
+    pub fn set_use_verb_shorthand(&mut self, use_verb_shorthand: bool) {
+        self.use_verb_shorthand = use_verb_shorthand;
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(N3Writer, N3WriterOptions);
+
+impl N3Writer {
+    pub fn with_options(self, options: N3WriterOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+impl ObjectWriter<Formula> for N3Writer {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, formula: &Formula) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mappings = formula.prefix_mappings();
+        if !mappings.is_empty() {
+            for (prefix, namespace) in mappings.mappings().sorted() {
+                let prefix = prefix.name_string().unwrap_or("");
+                writeln!(w, "@prefix {prefix}: <{namespace:#}> .")?;
+            }
+            writeln!(w)?;
+        }
+        write!(w, "{}", self.formula_content(formula, mappings, 0)?)?;
+        Ok(())
+    }
+}
+
+impl ObjectWriter<Graph> for N3Writer {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        self.write(w, &Formula::from(graph.clone()))
+    }
+}
+
+impl GraphWriter for N3Writer {}
+
+impl N3Writer {
+    ///
+    /// Returns the variable declarations and statements of `formula`, each on it's own line at
+    /// the given depth of indentation.
+    ///
+    fn formula_content(
+        &self,
+        formula: &Formula,
+        mappings: &IriPrefixMap,
+        depth: usize,
+    ) -> Result<String, Error> {
+        let indent = self.indent(depth);
+        let mut content = String::new();
+
+        for (keyword, variables) in [
+            ("@forAll", formula.universals().collect::<Vec<&Variable>>()),
+            ("@forSome", formula.existentials().collect()),
+        ] {
+            if !variables.is_empty() {
+                let variables = variables
+                    .iter()
+                    .map(|v| self.iri(v.id(), mappings))
+                    .join(", ");
+                content.push_str(&format!("{indent}{keyword} {variables} .\n"));
+            }
+        }
+
+        let statements = formula
+            .statements()
+            .map(|st| self.statement(st, formula, mappings, depth))
+            .collect::<Result<Vec<(String, String, String)>, Error>>()?;
+        for (subject, group) in &statements.into_iter().sorted().chunk_by(|st| st.0.clone()) {
+            let predicate_objects = group
+                .map(|(_, predicate, object)| format!("{predicate} {object}"))
+                .join(&format!(" ;\n{}", self.indent(depth + 1)));
+            content.push_str(&format!("{indent}{subject} {predicate_objects} .\n"));
+        }
+        Ok(content)
+    }
+
+    fn statement(
+        &self,
+        statement: &Statement,
+        formula: &Formula,
+        mappings: &IriPrefixMap,
+        depth: usize,
+    ) -> Result<(String, String, String), Error> {
+        Ok((
+            self.subject(statement.subject(), formula, mappings, depth)?,
+            self.predicate(statement.predicate(), mappings),
+            self.object(statement.object(), formula, mappings, depth)?,
+        ))
+    }
+
+    fn subject(
+        &self,
+        subject: &SubjectNode,
+        formula: &Formula,
+        mappings: &IriPrefixMap,
+        depth: usize,
+    ) -> Result<String, Error> {
+        match subject {
+            SubjectNode::Blank(node) => self.object(&node.into(), formula, mappings, depth),
+            SubjectNode::Resource(iri) => Ok(self.iri(iri, mappings)),
+            SubjectNode::Statement(_) => Err(rdf_star_not_supported_error(super::NAME)),
+        }
+    }
+
+    fn predicate(&self, predicate: &Iri, mappings: &IriPrefixMap) -> String {
+        if self.options.use_verb_shorthand() {
+            if predicate == rdf::a_type() {
+                return "a".to_string();
+            } else if predicate == owl::same_as() {
+                return "=".to_string();
+            } else if predicate == log::implies() {
+                return "=>".to_string();
+            }
+        }
+        self.iri(predicate, mappings)
+    }
+
+    fn object(
+        &self,
+        object: &ObjectNode,
+        formula: &Formula,
+        mappings: &IriPrefixMap,
+        depth: usize,
+    ) -> Result<String, Error> {
+        match object {
+            ObjectNode::Blank(node) => {
+                if let Some(quoted) = formula.formula(node) {
+                    let content = self.formula_content(quoted, mappings, depth + 1)?;
+                    if content.is_empty() {
+                        Ok("{}".to_string())
+                    } else {
+                        Ok(format!("{{\n{content}{}}}", self.indent(depth)))
+                    }
+                } else {
+                    Ok(format!("_:{node}"))
+                }
+            }
+            ObjectNode::Resource(iri) => Ok(self.iri(iri, mappings)),
+            ObjectNode::Literal(literal) => Ok(self.literal(literal, mappings)),
+            ObjectNode::Collection(collection) => Ok(format!(
+                "( {} )",
+                collection
+                    .iter()
+                    .map(|member| self.object(member, formula, mappings, depth))
+                    .collect::<Result<Vec<String>, Error>>()?
+                    .join(" ")
+            )),
+            ObjectNode::Statement(_) => Err(rdf_star_not_supported_error(super::NAME)),
+        }
+    }
+
+    fn literal(&self, literal: &Literal, mappings: &IriPrefixMap) -> String {
+        let lexical_form = quoted_lexical_form(literal);
+        match (literal.language(), literal.data_type()) {
+            (Some(language), _) => format!("{lexical_form}@{language}"),
+            (None, Some(data_type)) => {
+                format!("{lexical_form}^^{}", self.iri(data_type.as_iri(), mappings))
+            }
+            (None, None) => lexical_form,
+        }
+    }
+
+    fn iri(&self, iri: &Iri, mappings: &IriPrefixMap) -> String {
+        match mappings.compress(iri) {
+            Some(qname) => qname.to_string(),
+            None => format!("<{iri:#}>"),
+        }
+    }
+
+    fn indent(&self, depth: usize) -> String {
+        " ".repeat(depth * self.options.indent_width())
+    }
+}
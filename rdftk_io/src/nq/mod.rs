@@ -19,6 +19,22 @@ let writer = NQuadWriter::default();
 let result = writer.write_to_string(&make_data_set());
 ```
 
+# Example Streaming Reader
+
+The `NQuadReader::quads` method returns an iterator that reads one line at a time
+from any `BufRead` source, so large documents need not be held in memory.
+
+```rust
+use rdftk_io::nq::NQuadReader;
+
+let source = "<http://example.org/a> <http://example.org/p> \"a\" <http://example.org/g> .\n";
+
+for quad in NQuadReader::default().quads(source.as_bytes()) {
+    let (statement, graph_name) = quad.unwrap();
+    println!("{statement} in {graph_name:?}");
+}
+```
+
 */
 
 // ------------------------------------------------------------------------------------------------
@@ -42,7 +58,7 @@ pub const FORMAT_IRI: &str = "http://www.w3.org/ns/formats/N-Quads";
 // ------------------------------------------------------------------------------------------------
 
 mod reader;
-pub use reader::NQuadReader;
+pub use reader::{NQuadReader, NQuadStatements, Quad};
 
 mod writer;
-pub use writer::NQuadWriter;
+pub use writer::{NQuadSink, NQuadWriter, NQuadWriterOptions};
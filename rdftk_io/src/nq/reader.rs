@@ -1,19 +1,48 @@
-use crate::common::parser::parse_nquad_doc;
-use objio::ObjectReader;
-use rdftk_core::{error::Error, model::data_set::DataSet};
-use std::io::Read;
+use crate::common::parser::{parse_nquad_line, relocate_error};
+use crate::common::relabel::Relabeler;
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::statement::Statement;
+use std::io::{BufRead, BufReader, Read};
+use tracing::warn;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// A statement, and the name of the graph it belongs to; `None` denotes the default graph.
+///
+pub type Quad = (Statement, Option<GraphName>);
+
 #[derive(Debug, Default)]
-pub struct NQuadReader {}
+pub struct NQuadReader {
+    options: ReaderOptions,
+}
+
+///
+/// An iterator over the quads in an N-Quads source, returned by [`NQuadReader::quads`]. The
+/// source is read one line at a time so that only the current line is held in memory.
+///
+#[derive(Debug)]
+pub struct NQuadStatements<R> {
+    source: R,
+    line: String,
+    lines_read: usize,
+    bytes_read: usize,
+    relabel: Option<Relabeler>,
+    done: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_has_options!(NQuadReader, ReaderOptions);
+
 impl ObjectReader<DataSet> for NQuadReader {
     type Error = Error;
 
@@ -21,8 +50,111 @@ impl ObjectReader<DataSet> for NQuadReader {
     where
         R: Read,
     {
-        let mut buffer = String::new();
-        r.read_to_string(&mut buffer)?;
-        parse_nquad_doc(buffer)
+        let (data_set, diagnostics) = self.read_with_diagnostics(r)?;
+        diagnostics
+            .iter()
+            .for_each(|e| warn!("NQuadReader skipped line: {e}"));
+        Ok(data_set)
+    }
+}
+
+impl NQuadReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Read a data set, returning it along with the errors for any lines that were skipped.
+    /// Unless the reader is lenient the first error fails the read, and so the list of
+    /// diagnostics is always empty.
+    ///
+    pub fn read_with_diagnostics<R>(&self, r: &mut R) -> Result<(DataSet, Vec<Error>), Error>
+    where
+        R: Read,
+    {
+        let mut data_set = DataSet::default();
+        let mut diagnostics = Vec::default();
+        for quad in self.quads(BufReader::new(r)) {
+            match quad {
+                Ok((statement, graph_name)) => {
+                    if data_set.graph(&graph_name).is_none() {
+                        data_set.insert(match &graph_name {
+                            Some(name) => Graph::named(name.clone()),
+                            None => Graph::default(),
+                        });
+                    }
+                    data_set.graph_mut(&graph_name).unwrap().insert(statement);
+                }
+                Err(e) if self.options.is_lenient() && !matches!(e, Error::Io(_)) => {
+                    diagnostics.push(e)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok((data_set, diagnostics))
+    }
+
+    ///
+    /// Return an iterator that reads quads from `source` as they are requested, rather than
+    /// reading the whole document into memory.
+    ///
+    pub fn quads<R>(&self, source: R) -> NQuadStatements<R>
+    where
+        R: BufRead,
+    {
+        NQuadStatements {
+            source,
+            line: String::new(),
+            lines_read: 0,
+            bytes_read: 0,
+            relabel: match self.options.blank_nodes() {
+                BlankNodeLabels::Preserve => None,
+                BlankNodeLabels::Relabel => Some(Relabeler::default()),
+            },
+            done: false,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<R> Iterator for NQuadStatements<R>
+where
+    R: BufRead,
+{
+    type Item = Result<Quad, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.source.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(length) => {
+                    let (lines, bytes) = (self.lines_read, self.bytes_read);
+                    self.lines_read += 1;
+                    self.bytes_read += length;
+                    match parse_nquad_line(&self.line) {
+                        Ok(Some((statement, graph_name))) => {
+                            return Some(Ok(match &mut self.relabel {
+                                Some(relabel) => (
+                                    relabel.statement(&statement),
+                                    graph_name.map(|name| relabel.graph_name(name)),
+                                ),
+                                None => (statement, graph_name),
+                            }))
+                        }
+                        Ok(None) => {}
+                        Err(e) => return Some(Err(relocate_error(e, lines, bytes))),
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+        None
     }
 }
@@ -1,20 +1,44 @@
-use objio::ObjectWriter;
+use crate::common::literal::{full_object_string, full_subject_string};
+use crate::common::simplify::{reify_collection, simplify_statement};
+use crate::{GraphWriter, StatementSink};
+use objio::{HasOptions, ObjectWriter};
 use rdftk_core::error::Error;
 use rdftk_core::model::data_set::DataSet;
-use rdftk_core::model::graph::Graph;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::statement::Statement;
+use rdftk_iri::{Iri, Namespace};
 use std::io::Write;
 
-use crate::GraphWriter;
-
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NQuadWriterOptions {
+    reify_statements: bool,
+}
+
 ///
-/// .
+/// This struct implements the `ObjectWriter` trait for data sets and graphs. Literals are
+/// always written in their full form, and nested statements are written as quoted triples,
+/// `<< s p o >>`, unless the `reify_statements` option is set; collections are always written
+/// in their reified form.
 ///
 #[derive(Debug, Default)]
-pub struct NQuadWriter {}
+pub struct NQuadWriter {
+    options: NQuadWriterOptions,
+}
+
+///
+/// An implementation of [`StatementSink`], returned by [`NQuadWriter::sink`], which writes
+/// each statement as it is received. Statements passed to `statement` are written to the
+/// default graph, use `quad` to write a statement to a named graph.
+///
+#[derive(Debug)]
+pub struct NQuadSink<W> {
+    writer: W,
+    options: NQuadWriterOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -27,10 +51,14 @@ impl ObjectWriter<DataSet> for NQuadWriter {
     where
         W: Write,
     {
+        let mut sink = self.sink(w);
+        sink.start()?;
         for graph in data_set.graphs() {
-            self.write(w, graph)?;
+            for statement in graph.statements() {
+                sink.quad(statement, graph.name())?;
+            }
         }
-        Ok(())
+        sink.finish()
     }
 }
 
@@ -41,21 +69,107 @@ impl ObjectWriter<Graph> for NQuadWriter {
     where
         W: Write,
     {
-        let simple_graph = graph.simplify()?;
-        let graph_name = simple_graph.name();
-        for subject in simple_graph.subjects() {
-            for predicate in simple_graph.predicates_for(subject) {
-                for object in simple_graph.objects_for(subject, predicate) {
-                    if let Some(graph_name) = graph_name {
-                        writeln!(w, "{} <{}> {} {} .", subject, predicate, object, graph_name)?;
-                    } else {
-                        writeln!(w, "{} <{}> {} .", subject, predicate, object)?;
-                    }
-                }
+        let mut sink = self.sink(w);
+        sink.start()?;
+        for statement in graph.statements() {
+            sink.quad(statement, graph.name())?;
+        }
+        sink.finish()
+    }
+}
+
+impl GraphWriter for NQuadWriter {}
+
+impl NQuadWriter {
+    ///
+    /// Return a [`StatementSink`] that writes statements to `w` as they are received, using
+    /// this writer's options.
+    ///
+    pub fn sink<W>(&self, w: W) -> NQuadSink<W>
+    where
+        W: Write,
+    {
+        NQuadSink {
+            writer: w,
+            options: self.options,
+        }
+    }
+}
+
+impl HasOptions<NQuadWriterOptions> for NQuadWriter {
+    fn set_options(&mut self, options: NQuadWriterOptions) {
+        self.options = options;
+    }
+
+    fn options(&self) -> &NQuadWriterOptions {
+        &self.options
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<W> StatementSink for NQuadSink<W>
+where
+    W: Write,
+{
+    fn start(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn prefix(&mut self, _: &Namespace, _: &Iri) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        self.quad(statement, None)
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+impl<W> NQuadSink<W>
+where
+    W: Write,
+{
+    ///
+    /// Write a single statement to the graph named `graph_name`, or to the default graph if
+    /// `graph_name` is `None`.
+    ///
+    pub fn quad(
+        &mut self,
+        statement: &Statement,
+        graph_name: Option<&GraphName>,
+    ) -> Result<(), Error> {
+        let statements = if self.options.reify_statements {
+            simplify_statement(statement)?
+        } else {
+            reify_collection(statement)?
+        };
+        for statement in statements {
+            let subject = full_subject_string(super::NAME, statement.subject())?;
+            let predicate = statement.predicate();
+            let object = full_object_string(super::NAME, statement.object())?;
+            if let Some(graph_name) = graph_name {
+                writeln!(self.writer, "{subject} {predicate} {object} {graph_name} .")?;
+            } else {
+                writeln!(self.writer, "{subject} {predicate} {object} .")?;
             }
         }
         Ok(())
     }
 }
 
-impl GraphWriter for NQuadWriter {}
+// ------------------------------------------------------------------------------------------------
+
+impl NQuadWriterOptions {
+    ///
+    /// Write nested statements in their reified form, rather than as quoted triples.
+    ///
+    pub fn reify_statements(self, flag: bool) -> Self {
+        let mut self_mut = self;
+        self_mut.reify_statements = flag;
+        self_mut
+    }
+}
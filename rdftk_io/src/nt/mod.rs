@@ -17,6 +17,21 @@ let writer = NTripleWriter::default();
 let result = writer.write_to_string(&make_graph());
 ```
 
+# Example Streaming Reader
+
+The `NTripleReader::statements` method returns an iterator that reads one line at a time
+from any `BufRead` source, so large documents need not be held in memory.
+
+```rust
+use rdftk_io::nt::NTripleReader;
+
+let source = "<http://example.org/a> <http://example.org/p> \"a\" .\n";
+
+for statement in NTripleReader::default().statements(source.as_bytes()) {
+    println!("{}", statement.unwrap());
+}
+```
+
 */
 
 // ------------------------------------------------------------------------------------------------
@@ -37,7 +52,7 @@ pub const MIME_TYPE: &str = "application/n-triples";
 // ------------------------------------------------------------------------------------------------
 
 mod reader;
-pub use reader::NTripleReader;
+pub use reader::{NTripleReader, NTripleStatements};
 
 mod writer;
-pub use writer::{NTripleWriter, NTripleWriterOptions};
+pub use writer::{NTripleSink, NTripleWriter, NTripleWriterOptions};
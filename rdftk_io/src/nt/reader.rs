@@ -1,20 +1,43 @@
-use crate::common::parser::parse_ntriple_doc;
-use objio::ObjectReader;
+use crate::common::parser::{parse_ntriple_line, relocate_error};
+use crate::common::relabel::Relabeler;
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
 use rdftk_core::error::Error;
 use rdftk_core::model::graph::Graph;
-use std::io::Read;
+use rdftk_core::model::statement::Statement;
+use std::io::{BufRead, BufReader, Read};
+use tracing::warn;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Default)]
-pub struct NTripleReader {}
+pub struct NTripleReader {
+    options: ReaderOptions,
+}
+
+///
+/// An iterator over the statements in an N-Triples source, returned by
+/// [`NTripleReader::statements`]. The source is read one line at a time so that only the
+/// current line is held in memory.
+///
+#[derive(Debug)]
+pub struct NTripleStatements<R> {
+    source: R,
+    line: String,
+    lines_read: usize,
+    bytes_read: usize,
+    relabel: Option<Relabeler>,
+    done: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_has_options!(NTripleReader, ReaderOptions);
+
 impl ObjectReader<Graph> for NTripleReader {
     type Error = Error;
 
@@ -22,8 +45,100 @@ impl ObjectReader<Graph> for NTripleReader {
     where
         R: Read,
     {
-        let mut buffer = String::new();
-        r.read_to_string(&mut buffer)?;
-        parse_ntriple_doc(buffer)
+        let (graph, diagnostics) = self.read_with_diagnostics(r)?;
+        diagnostics
+            .iter()
+            .for_each(|e| warn!("NTripleReader skipped line: {e}"));
+        Ok(graph)
+    }
+}
+
+impl NTripleReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Read a graph, returning it along with the errors for any lines that were skipped.
+    /// Unless the reader is lenient the first error fails the read, and so the list of
+    /// diagnostics is always empty.
+    ///
+    pub fn read_with_diagnostics<R>(&self, r: &mut R) -> Result<(Graph, Vec<Error>), Error>
+    where
+        R: Read,
+    {
+        let mut graph = Graph::default();
+        let mut diagnostics = Vec::default();
+        for statement in self.statements(BufReader::new(r)) {
+            match statement {
+                Ok(statement) => graph.insert(statement),
+                Err(e) if self.options.is_lenient() && !matches!(e, Error::Io(_)) => {
+                    diagnostics.push(e)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok((graph, diagnostics))
+    }
+
+    ///
+    /// Return an iterator that reads statements from `source` as they are requested, rather
+    /// than reading the whole document into memory.
+    ///
+    pub fn statements<R>(&self, source: R) -> NTripleStatements<R>
+    where
+        R: BufRead,
+    {
+        NTripleStatements {
+            source,
+            line: String::new(),
+            lines_read: 0,
+            bytes_read: 0,
+            relabel: match self.options.blank_nodes() {
+                BlankNodeLabels::Preserve => None,
+                BlankNodeLabels::Relabel => Some(Relabeler::default()),
+            },
+            done: false,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<R> Iterator for NTripleStatements<R>
+where
+    R: BufRead,
+{
+    type Item = Result<Statement, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.source.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(length) => {
+                    let (lines, bytes) = (self.lines_read, self.bytes_read);
+                    self.lines_read += 1;
+                    self.bytes_read += length;
+                    match parse_ntriple_line(&self.line) {
+                        Ok(Some(statement)) => {
+                            return Some(Ok(match &mut self.relabel {
+                                Some(relabel) => relabel.statement(&statement),
+                                None => statement,
+                            }))
+                        }
+                        Ok(None) => {}
+                        Err(e) => return Some(Err(relocate_error(e, lines, bytes))),
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+        None
     }
 }
@@ -1,7 +1,11 @@
-use crate::GraphWriter;
+use crate::common::literal::{full_object_string, full_subject_string};
+use crate::common::simplify::{reify_collection, simplify_statement};
+use crate::{GraphWriter, StatementSink};
 use objio::{HasOptions, ObjectWriter};
 use rdftk_core::error::Error;
 use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::Statement;
+use rdftk_iri::{Iri, Namespace};
 use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
@@ -10,18 +14,32 @@ use std::io::Write;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct NTripleWriterOptions {
-    force_string_literals: bool,
+    reify_statements: bool,
 }
 
 ///
 /// This struct implements the `ObjectWriter` trait for graphs and will write out a serialized
-/// form of the entire graph.
+/// form of the entire graph. Literals are always written in their full form, `"lex"^^<dt>` or
+/// `"lex"@lang`, as N-Triples has no shorthand for numbers or booleans. Nested statements are
+/// written as N-Triples-star quoted triples, `<< s p o >>`, which `NTripleReader` also reads,
+/// unless the `reify_statements` option is set; collections are always written in their
+/// reified form.
 ///
 #[derive(Debug, Default)]
 pub struct NTripleWriter {
     options: NTripleWriterOptions,
 }
 
+///
+/// An implementation of [`StatementSink`], returned by [`NTripleWriter::sink`], which writes
+/// each statement as it is received.
+///
+#[derive(Debug)]
+pub struct NTripleSink<W> {
+    writer: W,
+    options: NTripleWriterOptions,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -33,24 +51,33 @@ impl ObjectWriter<Graph> for NTripleWriter {
     where
         W: Write,
     {
-        let simple_graph = graph.simplify()?;
-        for subject in simple_graph.subjects() {
-            for predicate in simple_graph.predicates_for(subject) {
-                for object in simple_graph.objects_for(subject, predicate) {
-                    if self.options().force_string_literals {
-                        writeln!(w, "{subject} <{predicate}> {object:#} .")?;
-                    } else {
-                        writeln!(w, "{subject} <{predicate}> {object} .")?;
-                    }
-                }
-            }
+        let mut sink = self.sink(w);
+        sink.start()?;
+        for statement in graph.statements() {
+            sink.statement(statement)?;
         }
-        Ok(())
+        sink.finish()
     }
 }
 
 impl GraphWriter for NTripleWriter {}
 
+impl NTripleWriter {
+    ///
+    /// Return a [`StatementSink`] that writes statements to `w` as they are received, using
+    /// this writer's options.
+    ///
+    pub fn sink<W>(&self, w: W) -> NTripleSink<W>
+    where
+        W: Write,
+    {
+        NTripleSink {
+            writer: w,
+            options: self.options,
+        }
+    }
+}
+
 impl HasOptions<NTripleWriterOptions> for NTripleWriter {
     fn set_options(&mut self, options: NTripleWriterOptions) {
         self.options = options;
@@ -63,10 +90,55 @@ impl HasOptions<NTripleWriterOptions> for NTripleWriter {
 
 // ------------------------------------------------------------------------------------------------
 
+impl<W> StatementSink for NTripleSink<W>
+where
+    W: Write,
+{
+    fn start(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn prefix(&mut self, _: &Namespace, _: &Iri) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        let statements = if self.options.reify_statements {
+            simplify_statement(statement)?
+        } else {
+            reify_collection(statement)?
+        };
+        for statement in statements {
+            writeln!(
+                self.writer,
+                "{} {} {} .",
+                full_subject_string(super::NAME, statement.subject())?,
+                statement.predicate(),
+                full_object_string(super::NAME, statement.object())?
+            )?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
 impl NTripleWriterOptions {
-    pub fn force_string_literals(self, flag: bool) -> Self {
+    #[deprecated(note = "literals are always written in their full form")]
+    pub fn force_string_literals(self, _flag: bool) -> Self {
+        self
+    }
+
+    ///
+    /// Write nested statements in their reified form, rather than as quoted triples.
+    ///
+    pub fn reify_statements(self, flag: bool) -> Self {
         let mut self_mut = self;
-        self_mut.force_string_literals = flag;
+        self_mut.reify_statements = flag;
         self_mut
     }
 }
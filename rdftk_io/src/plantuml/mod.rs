@@ -0,0 +1,47 @@
+/*!
+Provides for writing a `Graph` instance as a [PlantUML](https://plantuml.com/) diagram, using the
+deployment diagram elements so that each kind of node may be given its own shape.
+
+As with the `dot` module each kind of node, blank, IRI, and literal, has its own shape and color;
+the shape is a PlantUML element keyword such as `rectangle`, `usecase`, or `circle`, and the color
+is applied to the element's border. Nested statements and collections also have their own shape
+and color, and are labelled `<< s p o >>` and `( a b )` respectively.
+
+# Example
+
+```rust
+use rdftk_io::plantuml::{PlantUmlOptions, PlantUmlWriter};
+# use objio::{HasOptions, ObjectWriter};
+# use rdftk_core::model::graph::Graph;
+# fn make_graph() -> Graph { rdftk_core::model::graph::Graph::default() }
+
+let options = PlantUmlOptions::default()
+    .with_literal_shape("card")
+    .with_left_to_right(true);
+
+let writer = PlantUmlWriter::default().with_options(options);
+
+let result = writer.write_to_string(&make_graph());
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "PlantUML";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "puml";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "text/vnd.plantuml";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod writer;
+pub use writer::{PlantUmlOptions, PlantUmlWriter};
@@ -0,0 +1,490 @@
+use crate::common::diagram::{Diagram, NodeKind};
+use crate::GraphWriter;
+use objio::HasOptions;
+use objio::{impl_has_options, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::graph::Graph;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Used to set configurable components of the generated diagram, these follow the same model
+/// as `DotOptions` with a shape and color for each kind of node.
+///
+#[derive(Debug)]
+pub struct PlantUmlOptions {
+    /// The element keyword used to render a blank node. Default is `circle`.
+    blank_shape: String,
+    /// The color name used to render a blank node. Default is `green`.
+    blank_color: String,
+    /// Determines whether labels are included in blank node shapes. Default is `false`.
+    blank_labels: bool,
+    /// The element keyword used to render an IRI node. Default is `usecase`.
+    iri_shape: String,
+    /// The color name used to render an IRI node. Default is `blue`.
+    iri_color: String,
+    /// The element keyword used to render a literal node. Default is `rectangle`.
+    literal_shape: String,
+    /// The color name used to render a literal node. Default is `black`.
+    literal_color: String,
+    /// The element keyword used to render a nested statement. Default is `card`.
+    statement_shape: String,
+    /// The color name used to render a nested statement. Default is `orange`.
+    statement_color: String,
+    /// The element keyword used to render a collection. Default is `stack`.
+    collection_shape: String,
+    /// The color name used to render a collection. Default is `purple`.
+    collection_color: String,
+    /// The prefix string used to generate internal node identifiers. Default is `node_`.
+    node_prefix: String,
+    /// Determines whether IRI node labels are compressed using the graph's prefix mappings.
+    /// Default is `false`.
+    prefixed_labels: bool,
+    /// Determines whether the diagram is laid out left to right, rather than top to bottom.
+    /// Default is `false`.
+    left_to_right: bool,
+}
+
+///
+/// This struct implements the `GraphWriter` trait and will write out a PlantUML diagram for
+/// the entire graph.
+///
+#[derive(Debug, Default)]
+pub struct PlantUmlWriter {
+    options: PlantUmlOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for PlantUmlOptions {
+    fn default() -> Self {
+        Self {
+            blank_shape: "circle".to_string(),
+            blank_color: "green".to_string(),
+            blank_labels: false,
+            iri_shape: "usecase".to_string(),
+            iri_color: "blue".to_string(),
+            literal_shape: "rectangle".to_string(),
+            literal_color: "black".to_string(),
+            statement_shape: "card".to_string(),
+            statement_color: "orange".to_string(),
+            collection_shape: "stack".to_string(),
+            collection_color: "purple".to_string(),
+            node_prefix: "node_".to_string(),
+            prefixed_labels: false,
+            left_to_right: false,
+        }
+    }
+}
+
+impl PlantUmlOptions {
+    pub fn with_blank_shape<S>(self, blank_shape: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            blank_shape: blank_shape.into(),
+            ..self
+        }
+    }
+
+    pub fn set_blank_shape<S>(&mut self, blank_shape: S)
+    where
+        S: Into<String>,
+    {
+        self.blank_shape = blank_shape.into();
+    }
+
+    pub fn blank_shape(&self) -> &String {
+        &self.blank_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_blank_color<S>(self, blank_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            blank_color: blank_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_blank_color<S>(&mut self, blank_color: S)
+    where
+        S: Into<String>,
+    {
+        self.blank_color = blank_color.into();
+    }
+
+    pub fn blank_color(&self) -> &String {
+        &self.blank_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_blank_labels(self, blank_labels: bool) -> Self {
+        Self {
+            blank_labels,
+            ..self
+        }
+    }
+
+    pub fn set_blank_labels(&mut self, blank_labels: bool) {
+        self.blank_labels = blank_labels;
+    }
+
+    pub fn blank_labels(&self) -> bool {
+        self.blank_labels
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_iri_shape<S>(self, iri_shape: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            iri_shape: iri_shape.into(),
+            ..self
+        }
+    }
+
+    pub fn set_iri_shape<S>(&mut self, iri_shape: S)
+    where
+        S: Into<String>,
+    {
+        self.iri_shape = iri_shape.into();
+    }
+
+    pub fn iri_shape(&self) -> &String {
+        &self.iri_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_iri_color<S>(self, iri_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            iri_color: iri_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_iri_color<S>(&mut self, iri_color: S)
+    where
+        S: Into<String>,
+    {
+        self.iri_color = iri_color.into();
+    }
+
+    pub fn iri_color(&self) -> &String {
+        &self.iri_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_literal_shape<S>(self, literal_shape: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            literal_shape: literal_shape.into(),
+            ..self
+        }
+    }
+
+    pub fn set_literal_shape<S>(&mut self, literal_shape: S)
+    where
+        S: Into<String>,
+    {
+        self.literal_shape = literal_shape.into();
+    }
+
+    pub fn literal_shape(&self) -> &String {
+        &self.literal_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_literal_color<S>(self, literal_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            literal_color: literal_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_literal_color<S>(&mut self, literal_color: S)
+    where
+        S: Into<String>,
+    {
+        self.literal_color = literal_color.into();
+    }
+
+    pub fn literal_color(&self) -> &String {
+        &self.literal_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_statement_shape<S>(self, statement_shape: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            statement_shape: statement_shape.into(),
+            ..self
+        }
+    }
+
+    pub fn set_statement_shape<S>(&mut self, statement_shape: S)
+    where
+        S: Into<String>,
+    {
+        self.statement_shape = statement_shape.into();
+    }
+
+    pub fn statement_shape(&self) -> &String {
+        &self.statement_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_statement_color<S>(self, statement_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            statement_color: statement_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_statement_color<S>(&mut self, statement_color: S)
+    where
+        S: Into<String>,
+    {
+        self.statement_color = statement_color.into();
+    }
+
+    pub fn statement_color(&self) -> &String {
+        &self.statement_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_collection_shape<S>(self, collection_shape: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            collection_shape: collection_shape.into(),
+            ..self
+        }
+    }
+
+    pub fn set_collection_shape<S>(&mut self, collection_shape: S)
+    where
+        S: Into<String>,
+    {
+        self.collection_shape = collection_shape.into();
+    }
+
+    pub fn collection_shape(&self) -> &String {
+        &self.collection_shape
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_collection_color<S>(self, collection_color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            collection_color: collection_color.into(),
+            ..self
+        }
+    }
+
+    pub fn set_collection_color<S>(&mut self, collection_color: S)
+    where
+        S: Into<String>,
+    {
+        self.collection_color = collection_color.into();
+    }
+
+    pub fn collection_color(&self) -> &String {
+        &self.collection_color
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_node_prefix<S>(self, node_prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            node_prefix: node_prefix.into(),
+            ..self
+        }
+    }
+
+    pub fn set_node_prefix<S>(&mut self, node_prefix: S)
+    where
+        S: Into<String>,
+    {
+        self.node_prefix = node_prefix.into();
+    }
+
+    pub fn node_prefix(&self) -> &String {
+        &self.node_prefix
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_prefixed_labels(self, prefixed_labels: bool) -> Self {
+        Self {
+            prefixed_labels,
+            ..self
+        }
+    }
+
+    pub fn set_prefixed_labels(&mut self, prefixed_labels: bool) {
+        self.prefixed_labels = prefixed_labels;
+    }
+
+    pub fn prefixed_labels(&self) -> bool {
+        self.prefixed_labels
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn with_left_to_right(self, left_to_right: bool) -> Self {
+        Self {
+            left_to_right,
+            ..self
+        }
+    }
+
+    pub fn set_left_to_right(&mut self, left_to_right: bool) {
+        self.left_to_right = left_to_right;
+    }
+
+    pub fn left_to_right(&self) -> bool {
+        self.left_to_right
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(PlantUmlWriter, PlantUmlOptions);
+
+impl PlantUmlWriter {
+    pub fn with_options(self, options: PlantUmlOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+impl ObjectWriter<Graph> for PlantUmlWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let diagram = Diagram::from_graph(graph, self.options.prefixed_labels, false);
+        let prefix = &self.options.node_prefix;
+
+        writeln!(w, "@startuml")?;
+        if self.options.left_to_right {
+            writeln!(w, "left to right direction")?;
+        }
+
+        for node in diagram.nodes() {
+            let (shape, color, label) = match node.kind {
+                NodeKind::Blank if self.options.blank_labels => (
+                    &self.options.blank_shape,
+                    &self.options.blank_color,
+                    node.label.as_str(),
+                ),
+                NodeKind::Blank => (&self.options.blank_shape, &self.options.blank_color, " "),
+                NodeKind::Iri => (
+                    &self.options.iri_shape,
+                    &self.options.iri_color,
+                    node.label.as_str(),
+                ),
+                NodeKind::Literal => (
+                    &self.options.literal_shape,
+                    &self.options.literal_color,
+                    node.label.as_str(),
+                ),
+                NodeKind::Statement => (
+                    &self.options.statement_shape,
+                    &self.options.statement_color,
+                    node.label.as_str(),
+                ),
+                NodeKind::Collection => (
+                    &self.options.collection_shape,
+                    &self.options.collection_color,
+                    node.label.as_str(),
+                ),
+            };
+            writeln!(
+                w,
+                "{shape} \"{}\" as {prefix}{} #line:{color}",
+                escape_label(label),
+                node.id
+            )?;
+        }
+
+        for edge in diagram.edges() {
+            writeln!(
+                w,
+                "{prefix}{} --> {prefix}{} : {}",
+                edge.subject,
+                edge.object,
+                escape_label(&edge.label)
+            )?;
+        }
+
+        writeln!(w, "@enduml")?;
+        Ok(())
+    }
+}
+
+impl GraphWriter for PlantUmlWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Replace the characters that would end a quoted label, or be read as an escape sequence,
+/// with numeric entities; line breaks are written as PlantUML's `\n`.
+///
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '"' => escaped.push_str("&#34;"),
+            '\\' => escaped.push_str("&#92;"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
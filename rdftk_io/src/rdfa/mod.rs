@@ -0,0 +1,64 @@
+/*!
+Provides for reading [RDFa 1.1 Core](https://www.w3.org/TR/rdfa-core/) statements embedded in
+HTML documents.
+
+# Example Reader
+
+The reader implements the RDFa Core processing sequence for the `vocab`, `prefix`, `about`,
+`property`, `typeof`, `resource`, `rel`, `rev`, `href`, `src`, `content`, `datatype`, and
+`inlist` attributes, along with the prefixes and terms of the
+[initial context](https://www.w3.org/2011/rdfa-context/rdfa-1.1). The `head` and `body`
+elements describe the document itself, as in [HTML+RDFa](https://www.w3.org/TR/html-rdfa/).
+
+Relative IRIs are resolved against any `base` element in the document, which is itself
+resolved against the base IRI provided in the options. As the document is the subject of any
+statements not otherwise identified one of these must provide an absolute IRI.
+
+```rust
+use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::ReaderOptions;
+use rdftk_io::rdfa::RdfaReader;
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+let reader = RdfaReader::default().with_options(
+    ReaderOptions::default().with_base(Iri::from_str("http://example.org/page.html").unwrap()),
+);
+
+let graph: Graph = reader.read_from_string(r##"<!DOCTYPE html>
+<html>
+  <body vocab="http://schema.org/">
+    <div typeof="Person" about="#alice">
+      <span property="name">Alice</span>
+      <a property="knows" href="#bob">Bob</a>
+    </div>
+  </body>
+</html>"##).unwrap();
+
+assert_eq!(graph.len(), 4);
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "RDFa";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "html";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "text/html";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod syntax;
+
+mod reader;
+pub use reader::RdfaReader;
@@ -0,0 +1,590 @@
+use crate::common::html::{document_base, read_html_document};
+use crate::rdfa::syntax::{
+    ATTRIBUTE_ABOUT, ATTRIBUTE_CONTENT, ATTRIBUTE_DATATYPE, ATTRIBUTE_HREF, ATTRIBUTE_INLIST,
+    ATTRIBUTE_LANG, ATTRIBUTE_PREFIX, ATTRIBUTE_PROPERTY, ATTRIBUTE_REL, ATTRIBUTE_RESOURCE,
+    ATTRIBUTE_REV, ATTRIBUTE_SRC, ATTRIBUTE_TYPEOF, ATTRIBUTE_VOCAB, ATTRIBUTE_XMLNS_PREFIX,
+    ATTRIBUTE_XML_LANG, DEFAULT_PREFIX_NAMESPACE, ELEMENT_BODY, ELEMENT_HEAD, INITIAL_PREFIXES,
+    INITIAL_TERMS, RDFA_USES_VOCABULARY,
+};
+use crate::common::relabel::DocumentLabels;
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::{absolute_iri_expected_error, Error};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap, Namespace};
+use rdftk_names::rdf;
+use scraper::ElementRef;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+use std::rc::Rc;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the GraphReader trait to read the RDFa statements embedded in an HTML
+/// document.
+///
+#[derive(Clone, Debug, Default)]
+pub struct RdfaReader {
+    options: ReaderOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A list of objects for an `inlist` predicate, shared between the elements that add to it.
+///
+type List = Rc<RefCell<Vec<ObjectNode>>>;
+
+///
+/// The lists of a subject, by predicate; this is shared with descendant elements until one of
+/// them establishes a new subject.
+///
+type ListMapping = Rc<RefCell<Vec<(Iri, List)>>>;
+
+///
+/// A statement, with a known subject or object and predicate, waiting for a descendant element
+/// to provide the other node.
+///
+#[derive(Clone, Debug)]
+enum Incomplete {
+    Forward(Iri),
+    Backward(Iri),
+    List(List),
+}
+
+///
+/// The evaluation context passed from an element to its children.
+///
+#[derive(Clone, Debug)]
+struct Context {
+    parent_subject: SubjectNode,
+    parent_object: Option<SubjectNode>,
+    prefixes: HashMap<String, Iri>,
+    incomplete: Vec<Incomplete>,
+    lists: ListMapping,
+    language: Option<LanguageTag>,
+    vocabulary: Option<Iri>,
+}
+
+#[derive(Debug)]
+struct Parser {
+    base: url::Url,
+    graph: Graph,
+    blank_nodes: DocumentLabels,
+    mappings: BTreeMap<String, Iri>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(RdfaReader, ReaderOptions);
+
+impl ObjectReader<Graph> for RdfaReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<Graph, Error>
+    where
+        R: Read,
+    {
+        let document = read_html_document(r)?;
+        let root = document.root_element();
+
+        let base = document_base(root, self.options.base())?
+            .ok_or_else(|| absolute_iri_expected_error(""))?;
+        let mut parser = Parser {
+            base: base.clone(),
+            graph: Graph::default(),
+            blank_nodes: DocumentLabels::new(self.options.blank_nodes()),
+            mappings: BTreeMap::default(),
+        };
+        let context = Context {
+            parent_subject: Iri::from(base).into(),
+            parent_object: None,
+            prefixes: HashMap::default(),
+            incomplete: Vec::default(),
+            lists: ListMapping::default(),
+            language: None,
+            vocabulary: None,
+        };
+        parser.element(root, &context, true)?;
+
+        if self.options.carry_prefixes() {
+            let mut mappings = IriPrefixMap::empty();
+            for (prefix, iri) in parser.mappings {
+                mappings.insert(Namespace::new_unchecked(prefix), iri);
+            }
+            parser.graph.set_prefix_mappings(mappings);
+        }
+        Ok(parser.graph)
+    }
+}
+
+impl RdfaReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Parser {
+    ///
+    /// Process a single element, and its descendants, following the RDFa Core
+    /// [processing sequence](https://www.w3.org/TR/rdfa-core/#s_sequence).
+    ///
+    fn element(
+        &mut self,
+        element: ElementRef<'_>,
+        context: &Context,
+        is_root: bool,
+    ) -> Result<(), Error> {
+        let value = element.value();
+        let mut local = context.clone();
+
+        // SPEC: §7.5 step 2, the default vocabulary.
+        if let Some(vocabulary) = value.attr(ATTRIBUTE_VOCAB) {
+            local.vocabulary = if vocabulary.trim().is_empty() {
+                None
+            } else {
+                let vocabulary = self.resolve(vocabulary.trim())?;
+                self.insert(
+                    Iri::from(self.base.clone()).into(),
+                    Iri::from_str(RDFA_USES_VOCABULARY)?,
+                    vocabulary.clone().into(),
+                );
+                Some(vocabulary)
+            };
+        }
+
+        // SPEC: §7.5 step 3, prefix mappings from xmlns:* and @prefix.
+        for (name, iri) in value.attrs() {
+            if let Some(prefix) = name.strip_prefix(ATTRIBUTE_XMLNS_PREFIX) {
+                self.declare(&mut local, prefix, iri)?;
+            }
+        }
+        if let Some(prefixes) = value.attr(ATTRIBUTE_PREFIX) {
+            let mut tokens = prefixes.split_whitespace();
+            while let Some(prefix) = tokens.next() {
+                if let (Some(prefix), Some(iri)) = (prefix.strip_suffix(':'), tokens.next()) {
+                    self.declare(&mut local, prefix, iri)?;
+                }
+            }
+        }
+
+        // SPEC: §7.5 step 4, the current language.
+        if let Some(language) = value
+            .attr(ATTRIBUTE_XML_LANG)
+            .or_else(|| value.attr(ATTRIBUTE_LANG))
+        {
+            local.language = LanguageTag::from_str(language).ok();
+        }
+
+        let has_property = value.attr(ATTRIBUTE_PROPERTY).is_some();
+        // HTML+RDFa: where @property is present, terms in @rel and @rev are ignored.
+        let rel = value
+            .attr(ATTRIBUTE_REL)
+            .map(|rel| self.predicates(rel, &local, !has_property))
+            .filter(|rel| !(has_property && rel.is_empty()));
+        let rev = value
+            .attr(ATTRIBUTE_REV)
+            .map(|rev| self.predicates(rev, &local, !has_property))
+            .filter(|rev| !(has_property && rev.is_empty()));
+        let property = value
+            .attr(ATTRIBUTE_PROPERTY)
+            .map(|property| self.predicates(property, &local, true));
+        let types = value
+            .attr(ATTRIBUTE_TYPEOF)
+            .map(|types| self.predicates(types, &local, true));
+        let resource = match value.attr(ATTRIBUTE_RESOURCE) {
+            Some(resource) => self.safe_curie_or_iri(resource, &local)?,
+            None => None,
+        };
+        let reference = match resource {
+            Some(resource) => Some(resource),
+            None => match value.attr(ATTRIBUTE_HREF).or(value.attr(ATTRIBUTE_SRC)) {
+                Some(iri) => Some(self.resolve(iri)?.into()),
+                None => None,
+            },
+        };
+        let mut about = match value.attr(ATTRIBUTE_ABOUT) {
+            Some(about) => self.safe_curie_or_iri(about, &local)?,
+            None => None,
+        };
+        // HTML+RDFa: head and body act as if they had an empty @about.
+        if about.is_none()
+            && reference.is_none()
+            && [ELEMENT_HEAD, ELEMENT_BODY].contains(&value.name())
+        {
+            about = Some(Iri::from(self.base.clone()).into());
+        }
+        let content = value.attr(ATTRIBUTE_CONTENT);
+        let data_type = value.attr(ATTRIBUTE_DATATYPE);
+        let in_list = value.attr(ATTRIBUTE_INLIST).is_some();
+
+        let mut skip = false;
+        let mut new_subject: Option<SubjectNode>;
+        let mut current_object: Option<SubjectNode> = None;
+        let mut typed_resource: Option<SubjectNode> = None;
+        let mut incomplete: Vec<Incomplete> = Vec::default();
+
+        if rel.is_none() && rev.is_none() {
+            if property.is_some() && content.is_none() && data_type.is_none() {
+                // SPEC: §7.5 step 5.1, @property without @content or @datatype.
+                new_subject = about
+                    .clone()
+                    .or_else(|| is_root.then(|| Iri::from(self.base.clone()).into()))
+                    .or_else(|| context.parent_object.clone());
+                if types.is_some() {
+                    typed_resource = about.clone().or_else(|| {
+                        if is_root {
+                            Some(Iri::from(self.base.clone()).into())
+                        } else {
+                            reference
+                                .clone()
+                                .or_else(|| Some(BlankNode::generate().into()))
+                        }
+                    });
+                    current_object = typed_resource.clone();
+                }
+            } else {
+                // SPEC: §7.5 step 5.2
+                new_subject = about.clone().or_else(|| reference.clone());
+                if new_subject.is_none() {
+                    if is_root {
+                        new_subject = Some(Iri::from(self.base.clone()).into());
+                    } else if types.is_some() {
+                        new_subject = Some(BlankNode::generate().into());
+                    } else if let Some(parent_object) = &context.parent_object {
+                        new_subject = Some(parent_object.clone());
+                        skip = property.is_none();
+                    }
+                }
+                if types.is_some() {
+                    typed_resource = new_subject.clone();
+                }
+            }
+        } else {
+            // SPEC: §7.5 step 6, @rel or @rev are present.
+            new_subject = about.clone();
+            if types.is_some() {
+                typed_resource = new_subject.clone();
+            }
+            if new_subject.is_none() {
+                new_subject = if is_root {
+                    Some(Iri::from(self.base.clone()).into())
+                } else {
+                    context.parent_object.clone()
+                };
+            }
+            current_object = reference.clone();
+            if types.is_some() && about.is_none() {
+                if current_object.is_none() {
+                    current_object = Some(BlankNode::generate().into());
+                }
+                typed_resource = current_object.clone();
+            }
+        }
+
+        // SPEC: §7.5 step 7, types for the typed resource.
+        if let (Some(typed_resource), Some(types)) = (&typed_resource, &types) {
+            for a_type in types {
+                self.insert(
+                    typed_resource.clone(),
+                    rdf::a_type().clone(),
+                    a_type.clone().into(),
+                );
+            }
+        }
+
+        // SPEC: §7.5 step 8, a new subject starts a new list mapping.
+        let lists = if new_subject.is_some() && new_subject != context.parent_object {
+            ListMapping::default()
+        } else {
+            context.lists.clone()
+        };
+
+        let has_relations = rel.is_some() || rev.is_some();
+        let rel = rel.unwrap_or_default();
+        let rev = rev.unwrap_or_default();
+        if let Some(subject) = &new_subject {
+            if let Some(object) = &current_object {
+                // SPEC: §7.5 step 9, statements for @rel and @rev.
+                for predicate in &rel {
+                    if in_list {
+                        list(&lists, predicate)
+                            .borrow_mut()
+                            .push(object.to_object());
+                    } else {
+                        self.insert(subject.clone(), predicate.clone(), object.to_object());
+                    }
+                }
+                for predicate in &rev {
+                    self.insert(object.clone(), predicate.clone(), subject.to_object());
+                }
+            } else if !rel.is_empty() || !rev.is_empty() {
+                // SPEC: §7.5 step 10, incomplete statements for @rel and @rev.
+                current_object = Some(BlankNode::generate().into());
+                for predicate in rel {
+                    incomplete.push(if in_list {
+                        Incomplete::List(list(&lists, &predicate))
+                    } else {
+                        Incomplete::Forward(predicate)
+                    });
+                }
+                for predicate in rev {
+                    incomplete.push(Incomplete::Backward(predicate));
+                }
+            }
+
+            // SPEC: §7.5 step 11, the current property value.
+            if let Some(property) = property.filter(|property| !property.is_empty()) {
+                let data_type = data_type.map(|data_type| self.predicate(data_type, &local, true));
+                let object: ObjectNode = if let Some(Some(data_type)) = data_type {
+                    if &data_type == rdf::xml_literal() || &data_type == rdf::html() {
+                        Literal::with_data_type(element.inner_html(), DataType::from(data_type))
+                            .into()
+                    } else {
+                        Literal::with_data_type(
+                            content.map(str::to_string).unwrap_or_else(|| text(element)),
+                            DataType::from(data_type),
+                        )
+                        .into()
+                    }
+                } else if let Some(content) = content {
+                    literal(content, &local.language).into()
+                } else if let (None, false, Some(reference)) =
+                    (&data_type, has_relations, &reference)
+                {
+                    reference.to_object()
+                } else if let (None, true, None, Some(typed_resource)) = (
+                    &data_type,
+                    types.is_some(),
+                    value.attr(ATTRIBUTE_ABOUT),
+                    &typed_resource,
+                ) {
+                    typed_resource.to_object()
+                } else {
+                    literal(&text(element), &local.language).into()
+                };
+                for predicate in property {
+                    if in_list {
+                        list(&lists, &predicate).borrow_mut().push(object.clone());
+                    } else {
+                        self.insert(subject.clone(), predicate, object.clone());
+                    }
+                }
+            }
+
+            // SPEC: §7.5 step 12, complete the incomplete statements of the parent.
+            if !skip {
+                for pending in &context.incomplete {
+                    match pending {
+                        Incomplete::Forward(predicate) => self.insert(
+                            context.parent_subject.clone(),
+                            predicate.clone(),
+                            subject.to_object(),
+                        ),
+                        Incomplete::Backward(predicate) => self.insert(
+                            subject.clone(),
+                            predicate.clone(),
+                            context.parent_subject.to_object(),
+                        ),
+                        Incomplete::List(list) => list.borrow_mut().push(subject.to_object()),
+                    }
+                }
+            }
+        }
+
+        // SPEC: §7.5 step 13, process the child elements.
+        let child_context = if skip {
+            Context {
+                prefixes: local.prefixes,
+                language: local.language,
+                vocabulary: local.vocabulary,
+                ..context.clone()
+            }
+        } else {
+            Context {
+                parent_subject: new_subject
+                    .clone()
+                    .unwrap_or_else(|| context.parent_subject.clone()),
+                parent_object: current_object
+                    .or_else(|| new_subject.clone())
+                    .or_else(|| Some(context.parent_subject.clone())),
+                prefixes: local.prefixes,
+                incomplete,
+                lists: lists.clone(),
+                language: local.language,
+                vocabulary: local.vocabulary,
+            }
+        };
+        for child in element.children().filter_map(ElementRef::wrap) {
+            self.element(child, &child_context, false)?;
+        }
+
+        // SPEC: §7.5 step 14, lists started by this element.
+        if let Some(subject) = &new_subject {
+            if !Rc::ptr_eq(&lists, &context.lists) {
+                for (predicate, list) in lists.borrow().iter() {
+                    let head = self.collection(list.borrow().clone());
+                    self.insert(subject.clone(), predicate.clone(), head);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, subject: SubjectNode, predicate: Iri, object: ObjectNode) {
+        self.graph
+            .insert(Statement::new(subject, predicate, object));
+    }
+
+    fn collection(&mut self, members: Vec<ObjectNode>) -> ObjectNode {
+        let mut rest: ObjectNode = rdf::nil().clone().into();
+        for member in members.into_iter().rev() {
+            let node = BlankNode::generate();
+            self.insert(node.clone().into(), rdf::first().clone(), member);
+            self.insert(node.clone().into(), rdf::rest().clone(), rest);
+            rest = node.into();
+        }
+        rest
+    }
+
+    fn declare(&mut self, context: &mut Context, prefix: &str, iri: &str) -> Result<(), Error> {
+        // the prefix "_" is reserved for blank nodes, and may not be mapped.
+        let prefix = prefix.to_lowercase();
+        if prefix.is_empty() || prefix == "_" {
+            return Ok(());
+        }
+        let iri = self.resolve(iri)?;
+        let _ = self.mappings.insert(prefix.clone(), iri.clone());
+        let _ = context.prefixes.insert(prefix, iri);
+        Ok(())
+    }
+
+    fn resolve(&self, iri: &str) -> Result<Iri, Error> {
+        Ok(Iri::from(self.base.join(iri)?))
+    }
+
+    ///
+    /// Expand a CURIE using the in-scope prefixes or those of the initial context; returns
+    /// `None` for blank nodes and for unknown prefixes.
+    ///
+    fn curie(&mut self, value: &str, context: &Context) -> Option<Iri> {
+        let (prefix, reference) = value.split_once(':')?;
+        let prefix = prefix.to_lowercase();
+        let namespace = if prefix.is_empty() {
+            Iri::from_str(DEFAULT_PREFIX_NAMESPACE).ok()?
+        } else if let Some(namespace) = context.prefixes.get(&prefix) {
+            namespace.clone()
+        } else {
+            let (_, namespace) = INITIAL_PREFIXES.iter().find(|(p, _)| *p == prefix)?;
+            let namespace = Iri::from_str(namespace).ok()?;
+            let _ = self
+                .mappings
+                .entry(prefix.clone())
+                .or_insert_with(|| namespace.clone());
+            namespace
+        };
+        Iri::from_str(&format!("{namespace:#}{reference}")).ok()
+    }
+
+    ///
+    /// Resolve a _TERMorCURIEorAbsIRI_ value, returning `None` if it cannot be resolved.
+    ///
+    fn predicate(&mut self, value: &str, context: &Context, allow_terms: bool) -> Option<Iri> {
+        if value.starts_with("_:") {
+            None
+        } else if value.contains(':') {
+            self.curie(value, context)
+                .or_else(|| Iri::from_str(value).ok())
+        } else if !allow_terms {
+            None
+        } else if let Some(vocabulary) = &context.vocabulary {
+            Iri::from_str(&format!("{vocabulary:#}{value}")).ok()
+        } else {
+            INITIAL_TERMS
+                .iter()
+                .find(|(term, _)| *term == value)
+                .or_else(|| {
+                    INITIAL_TERMS
+                        .iter()
+                        .find(|(term, _)| term.eq_ignore_ascii_case(value))
+                })
+                .and_then(|(_, iri)| Iri::from_str(iri).ok())
+        }
+    }
+
+    fn predicates(&mut self, values: &str, context: &Context, allow_terms: bool) -> Vec<Iri> {
+        values
+            .split_whitespace()
+            .filter_map(|value| self.predicate(value, context, allow_terms))
+            .collect()
+    }
+
+    ///
+    /// Resolve a _SafeCURIEorCURIEorIRI_ value, returning `None` for a safe CURIE that cannot
+    /// be resolved.
+    ///
+    fn safe_curie_or_iri(
+        &mut self,
+        value: &str,
+        context: &Context,
+    ) -> Result<Option<SubjectNode>, Error> {
+        if let Some(safe) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            Ok(match safe.strip_prefix("_:") {
+                Some(label) => Some(self.blank_node(label).into()),
+                None => self.curie(safe, context).map(SubjectNode::from),
+            })
+        } else if let Some(label) = value.strip_prefix("_:") {
+            Ok(Some(self.blank_node(label).into()))
+        } else if let Some(iri) = self.curie(value, context) {
+            Ok(Some(iri.into()))
+        } else {
+            Ok(Some(self.resolve(value)?.into()))
+        }
+    }
+
+    fn blank_node(&mut self, label: &str) -> BlankNode {
+        self.blank_nodes.blank_node(label)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn list(lists: &ListMapping, predicate: &Iri) -> List {
+    let mut lists = lists.borrow_mut();
+    match lists.iter().find(|(p, _)| p == predicate) {
+        Some((_, list)) => list.clone(),
+        None => {
+            let list = List::default();
+            lists.push((predicate.clone(), list.clone()));
+            list
+        }
+    }
+}
+
+fn literal(value: &str, language: &Option<LanguageTag>) -> Literal {
+    match language {
+        Some(language) => Literal::with_language(value, language.clone()),
+        None => Literal::plain(value),
+    }
+}
+
+fn text(element: ElementRef<'_>) -> String {
+    element.text().collect()
+}
@@ -0,0 +1,94 @@
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const RDFA_NAMESPACE: &str = "http://www.w3.org/ns/rdfa#";
+pub(super) const RDFA_USES_VOCABULARY: &str = "http://www.w3.org/ns/rdfa#usesVocabulary";
+
+/// The mapping for CURIEs with an empty prefix, such as `:next`.
+pub(super) const DEFAULT_PREFIX_NAMESPACE: &str = "http://www.w3.org/1999/xhtml/vocab#";
+
+pub(super) const ATTRIBUTE_ABOUT: &str = "about";
+pub(super) const ATTRIBUTE_CONTENT: &str = "content";
+pub(super) const ATTRIBUTE_DATATYPE: &str = "datatype";
+pub(super) const ATTRIBUTE_HREF: &str = "href";
+pub(super) const ATTRIBUTE_INLIST: &str = "inlist";
+pub(super) const ATTRIBUTE_LANG: &str = "lang";
+pub(super) const ATTRIBUTE_PREFIX: &str = "prefix";
+pub(super) const ATTRIBUTE_PROPERTY: &str = "property";
+pub(super) const ATTRIBUTE_REL: &str = "rel";
+pub(super) const ATTRIBUTE_RESOURCE: &str = "resource";
+pub(super) const ATTRIBUTE_REV: &str = "rev";
+pub(super) const ATTRIBUTE_SRC: &str = "src";
+pub(super) const ATTRIBUTE_TYPEOF: &str = "typeof";
+pub(super) const ATTRIBUTE_VOCAB: &str = "vocab";
+pub(super) const ATTRIBUTE_XML_LANG: &str = "xml:lang";
+pub(super) const ATTRIBUTE_XMLNS_PREFIX: &str = "xmlns:";
+
+pub(super) const ELEMENT_BODY: &str = "body";
+pub(super) const ELEMENT_HEAD: &str = "head";
+
+///
+/// The prefix mappings of the [RDFa 1.1 initial context](https://www.w3.org/2011/rdfa-context/rdfa-1.1),
+/// including the widely used vocabularies.
+///
+pub(super) const INITIAL_PREFIXES: &[(&str, &str)] = &[
+    ("as", "https://www.w3.org/ns/activitystreams#"),
+    ("cc", "http://creativecommons.org/ns#"),
+    ("csvw", "http://www.w3.org/ns/csvw#"),
+    ("ctag", "http://commontag.org/ns#"),
+    ("dc", "http://purl.org/dc/terms/"),
+    ("dc11", "http://purl.org/dc/elements/1.1/"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("dqv", "http://www.w3.org/ns/dqv#"),
+    ("duv", "https://www.w3.org/ns/duv#"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("gr", "http://purl.org/goodrelations/v1#"),
+    ("grddl", "http://www.w3.org/2003/g/data-view#"),
+    ("ical", "http://www.w3.org/2002/12/cal/icaltzd#"),
+    ("jsonld", "http://www.w3.org/ns/json-ld#"),
+    ("ldp", "http://www.w3.org/ns/ldp#"),
+    ("ma", "http://www.w3.org/ns/ma-ont#"),
+    ("oa", "http://www.w3.org/ns/oa#"),
+    ("odrl", "http://www.w3.org/ns/odrl/2/"),
+    ("og", "http://ogp.me/ns#"),
+    ("org", "http://www.w3.org/ns/org#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("prov", "http://www.w3.org/ns/prov#"),
+    ("qb", "http://purl.org/linked-data/cube#"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfa", RDFA_NAMESPACE),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("rev", "http://purl.org/stuff/rev#"),
+    ("rif", "http://www.w3.org/2007/rif#"),
+    ("rr", "http://www.w3.org/ns/r2rml#"),
+    ("schema", "http://schema.org/"),
+    ("sd", "http://www.w3.org/ns/sparql-service-description#"),
+    ("sioc", "http://rdfs.org/sioc/ns#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("skosxl", "http://www.w3.org/2008/05/skos-xl#"),
+    ("sosa", "http://www.w3.org/ns/sosa/"),
+    ("ssn", "http://www.w3.org/ns/ssn/"),
+    ("time", "http://www.w3.org/2006/time#"),
+    ("v", "http://rdf.data-vocabulary.org/#"),
+    ("vcard", "http://www.w3.org/2006/vcard/ns#"),
+    ("void", "http://rdfs.org/ns/void#"),
+    ("wdr", "http://www.w3.org/2007/05/powder#"),
+    ("wdrs", "http://www.w3.org/2007/05/powder-s#"),
+    ("xhv", DEFAULT_PREFIX_NAMESPACE),
+    ("xml", "http://www.w3.org/XML/1998/namespace"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
+///
+/// The term mappings of the RDFa 1.1 initial context.
+///
+pub(super) const INITIAL_TERMS: &[(&str, &str)] = &[
+    (
+        "describedby",
+        "http://www.w3.org/2007/05/powder-s#describedby",
+    ),
+    ("license", "http://www.w3.org/1999/xhtml/vocab#license"),
+    ("role", "http://www.w3.org/1999/xhtml/vocab#role"),
+];
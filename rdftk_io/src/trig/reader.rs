@@ -1,5 +1,6 @@
 use crate::common::parser::parse_trig_doc;
-use objio::ObjectReader;
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
 use rdftk_core::error::Error;
 use rdftk_core::model::data_set::DataSet;
 use std::io::Read;
@@ -9,12 +10,16 @@ use std::io::Read;
 // ------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Default)]
-pub struct TrigReader {}
+pub struct TrigReader {
+    options: ReaderOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_has_options!(TrigReader, ReaderOptions);
+
 impl ObjectReader<DataSet> for TrigReader {
     type Error = Error;
 
@@ -24,6 +29,14 @@ impl ObjectReader<DataSet> for TrigReader {
     {
         let mut buffer = String::new();
         r.read_to_string(&mut buffer)?;
-        parse_trig_doc(buffer)
+        parse_trig_doc(buffer, &self.options)
+    }
+}
+
+impl TrigReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
     }
 }
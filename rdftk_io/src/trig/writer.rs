@@ -1,9 +1,15 @@
-use crate::turtle::TurtleWriterOptions;
+use crate::turtle::writer::statement_blank_nodes;
+use crate::turtle::{TurtleWriter, TurtleWriterOptions};
 use crate::{DataSetWriter, GraphWriter};
+use itertools::Itertools;
 use objio::{impl_has_options, HasOptions, ObjectWriter};
 use rdftk_core::error::Error;
 use rdftk_core::model::data_set::DataSet;
 use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::BlankNode;
+use rdftk_iri::IriPrefixMap;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -66,6 +72,38 @@ impl TrigWriter {
         self_mut.set_options(options);
         self_mut
     }
+
+    fn turtle_writer(&self) -> TurtleWriter {
+        TurtleWriter::default().with_options(self.options().turtle.clone())
+    }
+
+    ///
+    /// ```text
+    /// [7g]  wrappedGraph  ::= '{' triplesBlock? '}'
+    /// ```
+    ///
+    fn write_graph<W>(
+        &self,
+        w: &mut W,
+        turtle: &TurtleWriter,
+        graph: &Graph,
+        shared: &HashSet<BlankNode>,
+    ) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        if let Some(name) = graph.name() {
+            if !self.options().omit_graph_keyword() {
+                w.write_all(b"GRAPH ")?;
+            }
+            w.write_all(name.to_string().as_bytes())?;
+            w.write_all(b" ")?;
+        }
+        w.write_all(b"{\n")?;
+        turtle.write_graph_triples(w, graph, shared)?;
+        w.write_all(b"}\n")?;
+        Ok(())
+    }
 }
 
 impl ObjectWriter<DataSet> for TrigWriter {
@@ -73,10 +111,36 @@ impl ObjectWriter<DataSet> for TrigWriter {
 
     fn write<W>(&self, w: &mut W, data_set: &DataSet) -> Result<(), Self::Error>
     where
-        W: std::io::Write,
+        W: Write,
     {
+        // Prefixes are declared once for the whole document, so each graph is written using the
+        // union of all mappings; where a prefix is mapped differently the first one wins.
+        let mut mappings = IriPrefixMap::empty();
         for graph in data_set.graphs() {
-            self.write(w, graph)?;
+            for (prefix, namespace) in graph.prefix_mappings().mappings() {
+                if mappings.get_namespace(prefix).is_none() {
+                    mappings.insert(prefix.clone(), namespace.clone());
+                }
+            }
+        }
+        let graphs = data_set
+            .graphs()
+            .sorted_by_key(|graph| graph.name().map(|name| name.to_string()))
+            .map(|graph| {
+                let mut graph = graph.clone();
+                graph.set_prefix_mappings(mappings.clone());
+                graph
+            })
+            .collect::<Vec<_>>();
+
+        let shared = shared_blank_nodes(&graphs);
+
+        let turtle = self.turtle_writer();
+        if let Some(graph) = graphs.first() {
+            turtle.write_directives(w, graph)?;
+        }
+        for graph in &graphs {
+            self.write_graph(w, &turtle, graph, &shared)?;
         }
         Ok(())
     }
@@ -89,22 +153,41 @@ impl ObjectWriter<Graph> for TrigWriter {
 
     fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
     where
-        W: std::io::prelude::Write,
+        W: Write,
     {
-        if let Some(name) = graph.name() {
-            if !self.options().omit_graph_keyword() {
-                w.write_all(b"GRAPH ")?;
-            }
-            w.write_all(name.to_string().as_bytes())?;
-            w.write_all(b" ")?;
-        }
-        w.write_all(b"{\n")?;
-
-        // TODO: call the TurtleWriter
+        let shared = shared_blank_nodes(std::slice::from_ref(graph));
 
-        w.write_all(b"}\n")?;
-        Ok(())
+        let turtle = self.turtle_writer();
+        turtle.write_directives(w, graph)?;
+        self.write_graph(w, &turtle, graph, &shared)
     }
 }
 
 impl GraphWriter for TrigWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the blank nodes used in more than one graph, where a blank graph name counts as a
+/// separate use; these have to be written by label in every graph to keep their co-reference.
+///
+fn shared_blank_nodes(graphs: &[Graph]) -> HashSet<BlankNode> {
+    let mut uses: HashMap<BlankNode, usize> = HashMap::default();
+    for graph in graphs {
+        let mut blanks = HashSet::default();
+        for statement in graph.statements() {
+            statement_blank_nodes(statement, &mut blanks);
+        }
+        for blank in blanks {
+            *uses.entry(blank).or_default() += 1;
+        }
+        if let Some(blank) = graph.name().and_then(|name| name.as_blank()) {
+            *uses.entry(blank.clone()).or_default() += 1;
+        }
+    }
+    uses.into_iter()
+        .filter_map(|(blank, count)| (count > 1).then_some(blank))
+        .collect()
+}
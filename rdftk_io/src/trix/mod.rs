@@ -0,0 +1,70 @@
+/*!
+Provides for reading and writing a `DataSet` instance in the
+[TriX](https://www.hpl.hp.com/techreports/2004/HPL-2004-56.html) XML format.
+
+Each graph is written as a `graph` element, named by an optional leading `uri` or `id`
+element, containing a `triple` element for each statement. Nested statements and collections
+are written in their reified form.
+
+# Example Reader
+
+```rust
+use objio::ObjectReader;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_io::trix::TrixReader;
+
+let data_set: DataSet = TrixReader::default().read_from_string(r#"<?xml version="1.0"?>
+<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/">
+  <graph>
+    <uri>http://example.org/graphs#one</uri>
+    <triple>
+      <uri>http://example.org/people#alice</uri>
+      <uri>http://xmlns.com/foaf/0.1/name</uri>
+      <plainLiteral xml:lang="en">Alice</plainLiteral>
+    </triple>
+  </graph>
+</TriX>"#).unwrap();
+
+assert_eq!(data_set.len(), 1);
+```
+
+# Example Writer
+
+```rust
+use rdftk_io::trix::{TrixWriter, TrixWriterOptions};
+# use objio::{HasOptions, ObjectWriter};
+# use rdftk_core::model::data_set::DataSet;
+# fn make_data_set() -> DataSet { DataSet::default() }
+
+let writer = TrixWriter::default()
+    .with_options(TrixWriterOptions::default().with_pretty_print(true));
+
+println!("{}", writer.write_to_string(&make_data_set()).unwrap());
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "TriX";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "trix";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/trix";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod reader;
+pub use reader::TrixReader;
+
+mod syntax;
+
+mod writer;
+pub use writer::{TrixWriter, TrixWriterOptions};
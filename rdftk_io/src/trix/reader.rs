@@ -0,0 +1,339 @@
+use super::syntax::{
+    ATTRIBUTE_DATATYPE, ATTRIBUTE_XML_LANG, ELEMENT_GRAPH, ELEMENT_ID, ELEMENT_PLAIN_LITERAL,
+    ELEMENT_TRIPLE, ELEMENT_TRIX, ELEMENT_TYPED_LITERAL, ELEMENT_URI, TRIX_NAMESPACE,
+    XML_NAMESPACE,
+};
+use crate::{BlankNodeLabels, ReaderOptions};
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::{Error, SourceLocation};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::Iri;
+use std::collections::HashMap;
+use std::io::Read;
+use std::str::FromStr;
+use tracing::trace;
+use xml::attribute::OwnedAttribute;
+use xml::common::{Position, TextPosition};
+use xml::name::OwnedName;
+use xml::reader::{EventReader, XmlEvent};
+use xml::ParserConfig;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the `ObjectReader` trait to read data sets in the TriX representation.
+/// Each `graph` element in the document is added to the data set, graphs with the same name
+/// are merged.
+///
+#[derive(Clone, Debug, Default)]
+pub struct TrixReader {
+    options: ReaderOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single node within a `graph` or `triple` element.
+///
+#[derive(Clone, Debug)]
+enum Term {
+    Iri(Iri),
+    Blank(BlankNode),
+    Literal(Literal),
+}
+
+struct Parser<'a> {
+    source: &'a [u8],
+    events: EventReader<&'a [u8]>,
+    base: Option<url::Url>,
+    relabel: bool,
+    blank_nodes: HashMap<String, BlankNode>,
+    data_set: DataSet,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(TrixReader, ReaderOptions);
+
+impl ObjectReader<DataSet> for TrixReader {
+    type Error = Error;
+
+    fn read<R>(&self, r: &mut R) -> Result<DataSet, Error>
+    where
+        R: Read,
+    {
+        let mut source = Vec::default();
+        r.read_to_end(&mut source)?;
+        let mut parser = Parser {
+            source: &source,
+            events: ParserConfig::new()
+                .trim_whitespace(false)
+                .whitespace_to_characters(true)
+                .cdata_to_characters(true)
+                .coalesce_characters(true)
+                .ignore_comments(true)
+                .create_reader(source.as_slice()),
+            base: self
+                .options
+                .base()
+                .map(|base| AsRef::<url::Url>::as_ref(base).clone()),
+            relabel: self.options.blank_nodes() == BlankNodeLabels::Relabel,
+            blank_nodes: HashMap::default(),
+            data_set: DataSet::default(),
+        };
+        parser.document()?;
+        Ok(parser.data_set)
+    }
+}
+
+impl TrixReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Parser<'_> {
+    fn document(&mut self) -> Result<(), Error> {
+        loop {
+            match self.next()? {
+                XmlEvent::StartElement { name, .. } if is_trix_name(&name, ELEMENT_TRIX) => {
+                    while let Some((name, _)) = self.next_element()? {
+                        if is_trix_name(&name, ELEMENT_GRAPH) {
+                            self.graph()?;
+                        } else {
+                            return Err(self.unexpected(&name, ELEMENT_TRIX));
+                        }
+                    }
+                }
+                XmlEvent::StartElement { name, .. } => {
+                    return Err(self.error(format!(
+                        "expected a {ELEMENT_TRIX} document element, not {name}"
+                    )))
+                }
+                XmlEvent::EndDocument => return Ok(()),
+                event => trace!("TrixReader::document ignoring event: {:?}", event),
+            }
+        }
+    }
+
+    fn graph(&mut self) -> Result<(), Error> {
+        let mut graph_name = None;
+        let mut statements = Vec::default();
+        while let Some((name, attributes)) = self.next_element()? {
+            if is_trix_name(&name, ELEMENT_TRIPLE) {
+                statements.push(self.triple()?);
+            } else if graph_name.is_none() && statements.is_empty() {
+                graph_name = Some(match self.term(&name, &attributes)? {
+                    Term::Iri(iri) => GraphName::Iri(iri),
+                    Term::Blank(blank) => GraphName::BNode(blank),
+                    Term::Literal(_) => {
+                        return Err(self.error("a graph may not be named by a literal"))
+                    }
+                });
+            } else {
+                return Err(self.unexpected(&name, ELEMENT_GRAPH));
+            }
+        }
+        if self.data_set.graph(&graph_name).is_none() {
+            self.data_set.insert(match &graph_name {
+                Some(name) => Graph::named(name.clone()),
+                None => Graph::default(),
+            });
+        }
+        self.data_set
+            .graph_mut(&graph_name)
+            .unwrap()
+            .extend(statements);
+        Ok(())
+    }
+
+    fn triple(&mut self) -> Result<Statement, Error> {
+        let mut terms = Vec::with_capacity(3);
+        while let Some((name, attributes)) = self.next_element()? {
+            if terms.len() == 3 {
+                return Err(self.unexpected(&name, ELEMENT_TRIPLE));
+            }
+            terms.push(self.term(&name, &attributes)?);
+        }
+        let mut terms = terms.into_iter();
+        match (terms.next(), terms.next(), terms.next()) {
+            (Some(subject), Some(Term::Iri(predicate)), Some(object)) => {
+                let subject: SubjectNode = match subject {
+                    Term::Iri(iri) => iri.into(),
+                    Term::Blank(blank) => blank.into(),
+                    Term::Literal(_) => {
+                        return Err(self.error("the subject of a triple may not be a literal"))
+                    }
+                };
+                let object: ObjectNode = match object {
+                    Term::Iri(iri) => iri.into(),
+                    Term::Blank(blank) => blank.into(),
+                    Term::Literal(literal) => literal.into(),
+                };
+                Ok(Statement::new(subject, predicate, object))
+            }
+            (_, Some(_), Some(_)) => {
+                Err(self.error("the predicate of a triple must be a uri element"))
+            }
+            _ => Err(self.error("a triple must have exactly three elements")),
+        }
+    }
+
+    fn term(&mut self, name: &OwnedName, attributes: &[OwnedAttribute]) -> Result<Term, Error> {
+        let text = self.text(name)?;
+        if is_trix_name(name, ELEMENT_URI) {
+            Ok(Term::Iri(self.resolve(text.trim())?))
+        } else if is_trix_name(name, ELEMENT_ID) {
+            Ok(Term::Blank(self.blank_node(text.trim())?))
+        } else if is_trix_name(name, ELEMENT_PLAIN_LITERAL) {
+            let language = attributes.iter().find(|attribute| {
+                attribute.name.namespace.as_deref() == Some(XML_NAMESPACE)
+                    && attribute.name.local_name == ATTRIBUTE_XML_LANG
+            });
+            Ok(Term::Literal(match language {
+                Some(language) if !language.value.is_empty() => {
+                    Literal::with_language(&text, LanguageTag::from_str(&language.value)?)
+                }
+                _ => Literal::plain(&text),
+            }))
+        } else if is_trix_name(name, ELEMENT_TYPED_LITERAL) {
+            let data_type = attributes
+                .iter()
+                .find(|attribute| {
+                    attribute.name.namespace.is_none()
+                        && attribute.name.local_name == ATTRIBUTE_DATATYPE
+                })
+                .ok_or_else(|| {
+                    self.error(format!(
+                        "a {ELEMENT_TYPED_LITERAL} element requires a {ATTRIBUTE_DATATYPE} attribute"
+                    ))
+                })?;
+            Ok(Term::Literal(Literal::with_data_type(
+                &text,
+                DataType::from(self.resolve(&data_type.value)?),
+            )))
+        } else {
+            Err(self.error(format!("unexpected element {name}")))
+        }
+    }
+
+    ///
+    /// Return the next child element of the current element, skipping whitespace, or `None`
+    /// when the current element ends.
+    ///
+    fn next_element(&mut self) -> Result<Option<(OwnedName, Vec<OwnedAttribute>)>, Error> {
+        loop {
+            match self.next()? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => return Ok(Some((name, attributes))),
+                XmlEvent::EndElement { .. } => return Ok(None),
+                XmlEvent::Characters(text) if text.trim().is_empty() => {}
+                XmlEvent::Characters(_) => {
+                    return Err(self.error("unexpected text between elements"))
+                }
+                XmlEvent::EndDocument => return Err(self.error("unexpected end of document")),
+                event => trace!("TrixReader::next_element ignoring event: {:?}", event),
+            }
+        }
+    }
+
+    ///
+    /// Return the text content of the element `name`, which may not contain other elements.
+    ///
+    fn text(&mut self, name: &OwnedName) -> Result<String, Error> {
+        let mut content = String::new();
+        loop {
+            match self.next()? {
+                XmlEvent::Characters(text) => content.push_str(&text),
+                XmlEvent::EndElement { .. } => return Ok(content),
+                XmlEvent::StartElement { .. } => {
+                    return Err(self.error(format!("the {name} element may only contain text")))
+                }
+                XmlEvent::EndDocument => return Err(self.error("unexpected end of document")),
+                event => trace!("TrixReader::text ignoring event: {:?}", event),
+            }
+        }
+    }
+
+    fn next(&mut self) -> Result<XmlEvent, Error> {
+        let source = self.source;
+        self.events.next().map_err(|e| xml_error(source, e))
+    }
+
+    fn resolve(&self, iri: &str) -> Result<Iri, Error> {
+        match &self.base {
+            Some(base) => Ok(Iri::from(base.join(iri)?)),
+            None => Ok(Iri::from_str(iri)?),
+        }
+    }
+
+    fn blank_node(&mut self, label: &str) -> Result<BlankNode, Error> {
+        if self.relabel {
+            Ok(self
+                .blank_nodes
+                .entry(label.to_string())
+                .or_insert_with(BlankNode::generate)
+                .clone())
+        } else {
+            Ok(BlankNode::from_str(label)?)
+        }
+    }
+
+    fn unexpected(&self, name: &OwnedName, parent: &str) -> Error {
+        self.error(format!("unexpected element {name} within {parent}"))
+    }
+
+    fn error<S>(&self, message: S) -> Error
+    where
+        S: Into<String>,
+    {
+        rdftk_core::error::syntax_error(
+            super::NAME,
+            message,
+            text_location(self.source, self.events.position()),
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn is_trix_name(name: &OwnedName, local_name: &str) -> bool {
+    name.namespace.as_deref() == Some(TRIX_NAMESPACE) && name.local_name == local_name
+}
+
+fn xml_error(source: &[u8], e: xml::reader::Error) -> Error {
+    // the error's display form is prefixed with its position, which is reported separately.
+    let message = e.to_string();
+    let message = message
+        .strip_prefix(&format!("{} ", e.position()))
+        .unwrap_or(&message);
+    rdftk_core::error::syntax_error(super::NAME, message, text_location(source, e.position()))
+}
+
+///
+/// Convert the 0-based row and column reported by the XML reader into a location.
+///
+fn text_location(source: &[u8], position: TextPosition) -> SourceLocation {
+    SourceLocation::from_line_column(
+        &String::from_utf8_lossy(source),
+        position.row as usize + 1,
+        position.column as usize + 1,
+    )
+}
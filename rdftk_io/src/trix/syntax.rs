@@ -0,0 +1,21 @@
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+pub(super) const TRIX_NAMESPACE: &str = "http://www.w3.org/2004/03/trix/trix-1/";
+
+pub(super) const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+pub(super) const DEFAULT_ENCODING: &str = "utf-8";
+
+pub(super) const ELEMENT_TRIX: &str = "TriX";
+pub(super) const ELEMENT_GRAPH: &str = "graph";
+pub(super) const ELEMENT_TRIPLE: &str = "triple";
+pub(super) const ELEMENT_URI: &str = "uri";
+pub(super) const ELEMENT_ID: &str = "id";
+pub(super) const ELEMENT_PLAIN_LITERAL: &str = "plainLiteral";
+pub(super) const ELEMENT_TYPED_LITERAL: &str = "typedLiteral";
+
+pub(super) const ATTRIBUTE_DATATYPE: &str = "datatype";
+pub(super) const ATTRIBUTE_XML_LANG: &str = "lang";
+pub(super) const ATTRIBUTE_XML_LANG_PREFIXED: &str = "xml:lang";
@@ -0,0 +1,278 @@
+use super::syntax::{
+    ATTRIBUTE_DATATYPE, ATTRIBUTE_XML_LANG_PREFIXED, DEFAULT_ENCODING, ELEMENT_GRAPH, ELEMENT_ID,
+    ELEMENT_PLAIN_LITERAL, ELEMENT_TRIPLE, ELEMENT_TRIX, ELEMENT_TYPED_LITERAL, ELEMENT_URI,
+    TRIX_NAMESPACE,
+};
+use crate::common::literal::unescape_lexical_form;
+use crate::common::simplify::simplify_statement;
+use crate::{DataSetWriter, GraphWriter};
+use objio::{impl_has_options, HasOptions, ObjectWriter};
+use rdftk_core::error::{rdf_star_not_supported_error, Error};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::{ObjectNode, SubjectNode};
+use rdftk_iri::Iri;
+use std::io::Write;
+use xml::common::XmlVersion;
+use xml::writer::{EventWriter, XmlEvent};
+use xml::EmitterConfig;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Options that control how the TriX writer will render a graph or data set.
+///
+#[derive(Clone, Debug)]
+pub struct TrixWriterOptions {
+    /// Should the output be pretty-printed, including indentation. Default is `false`.
+    pretty_print: bool,
+    /// The encoding to specify in the XML declaration. Default is "utf-8".
+    encoding: String,
+}
+
+///
+/// This struct implements the `ObjectWriter` trait for graphs and data sets, each graph is
+/// written as a single `graph` element. Nested statements and collections are written in
+/// their reified form.
+///
+#[derive(Debug, Default)]
+pub struct TrixWriter {
+    options: TrixWriterOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for TrixWriterOptions {
+    fn default() -> Self {
+        Self {
+            pretty_print: false,
+            encoding: String::from(DEFAULT_ENCODING),
+        }
+    }
+}
+
+impl TrixWriterOptions {
+    pub fn with_pretty_print(self, pretty_print: bool) -> Self {
+        Self {
+            pretty_print,
+            ..self
+        }
+    }
+
+    pub fn with_encoding<S>(self, encoding: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            encoding: encoding.into(),
+            ..self
+        }
+    }
+
+    pub fn pretty_print(&self) -> bool {
+        self.pretty_print
+    }
+
+    pub fn set_pretty_print(&mut self, pretty_print: bool) {
+        self.pretty_print = pretty_print;
+    }
+
+    pub fn encoding(&self) -> &String {
+        &self.encoding
+    }
+
+    pub fn set_encoding<S>(&mut self, encoding: S)
+    where
+        S: Into<String>,
+    {
+        self.encoding = encoding.into();
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl_has_options!(TrixWriter, TrixWriterOptions);
+
+impl ObjectWriter<Graph> for TrixWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, graph: &Graph) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut writer = self.emitter_config().create_writer(w);
+        self.start_document(&mut writer)?;
+        write_graph(&mut writer, graph)?;
+        self.end_document(&mut writer)
+    }
+}
+
+impl GraphWriter for TrixWriter {}
+
+impl ObjectWriter<DataSet> for TrixWriter {
+    type Error = Error;
+
+    fn write<W>(&self, w: &mut W, data_set: &DataSet) -> Result<(), Self::Error>
+    where
+        W: Write,
+    {
+        let mut writer = self.emitter_config().create_writer(w);
+        self.start_document(&mut writer)?;
+        for graph in data_set.graphs() {
+            write_graph(&mut writer, graph)?;
+        }
+        self.end_document(&mut writer)
+    }
+}
+
+impl DataSetWriter for TrixWriter {}
+
+impl TrixWriter {
+    pub fn with_options(self, options: TrixWriterOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    fn emitter_config(&self) -> EmitterConfig {
+        EmitterConfig::new()
+            .perform_indent(self.options.pretty_print)
+            .normalize_empty_elements(self.options.pretty_print)
+    }
+
+    fn start_document<W: Write>(&self, writer: &mut EventWriter<W>) -> Result<(), Error> {
+        writer
+            .write(XmlEvent::StartDocument {
+                version: XmlVersion::Version10,
+                encoding: Some(&self.options.encoding),
+                standalone: None,
+            })
+            .map_err(xml_error)?;
+        writer
+            .write(XmlEvent::start_element(ELEMENT_TRIX).default_ns(TRIX_NAMESPACE))
+            .map_err(xml_error)
+    }
+
+    fn end_document<W: Write>(&self, writer: &mut EventWriter<W>) -> Result<(), Error> {
+        writer
+            .write(XmlEvent::end_element().name(ELEMENT_TRIX))
+            .map_err(xml_error)?;
+        Ok(writer.inner_mut().flush()?)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn xml_error(e: xml::writer::Error) -> Error {
+    Error::Tokenizer {
+        representation: super::NAME.into(),
+        source: Box::new(e),
+    }
+}
+
+fn write_graph<W: Write>(writer: &mut EventWriter<W>, graph: &Graph) -> Result<(), Error> {
+    writer
+        .write(XmlEvent::start_element(ELEMENT_GRAPH))
+        .map_err(xml_error)?;
+    match graph.name() {
+        Some(GraphName::Iri(name)) => write_iri(writer, name)?,
+        Some(GraphName::BNode(name)) => write_text_element(writer, ELEMENT_ID, name.as_ref())?,
+        None => {}
+    }
+    for statement in graph.statements() {
+        for statement in simplify_statement(statement)? {
+            writer
+                .write(XmlEvent::start_element(ELEMENT_TRIPLE))
+                .map_err(xml_error)?;
+            write_subject(writer, statement.subject())?;
+            write_iri(writer, statement.predicate())?;
+            write_object(writer, statement.object())?;
+            writer
+                .write(XmlEvent::end_element().name(ELEMENT_TRIPLE))
+                .map_err(xml_error)?;
+        }
+    }
+    writer
+        .write(XmlEvent::end_element().name(ELEMENT_GRAPH))
+        .map_err(xml_error)
+}
+
+fn write_subject<W: Write>(
+    writer: &mut EventWriter<W>,
+    subject: &SubjectNode,
+) -> Result<(), Error> {
+    if let Some(iri) = subject.as_resource() {
+        write_iri(writer, iri)
+    } else if let Some(blank) = subject.as_blank() {
+        write_text_element(writer, ELEMENT_ID, blank.as_ref())
+    } else {
+        rdf_star_not_supported_error(super::NAME).into()
+    }
+}
+
+fn write_object<W: Write>(writer: &mut EventWriter<W>, object: &ObjectNode) -> Result<(), Error> {
+    if let Some(iri) = object.as_resource() {
+        write_iri(writer, iri)
+    } else if let Some(blank) = object.as_blank() {
+        write_text_element(writer, ELEMENT_ID, blank.as_ref())
+    } else if let Some(literal) = object.as_literal() {
+        write_literal(writer, literal)
+    } else {
+        rdf_star_not_supported_error(super::NAME).into()
+    }
+}
+
+fn write_iri<W: Write>(writer: &mut EventWriter<W>, iri: &Iri) -> Result<(), Error> {
+    write_text_element(writer, ELEMENT_URI, &format!("{iri:#}"))
+}
+
+fn write_literal<W: Write>(writer: &mut EventWriter<W>, literal: &Literal) -> Result<(), Error> {
+    let data_type = literal
+        .data_type()
+        .map(|data_type| format!("{:#}", data_type.as_iri()));
+    let language = literal.language().map(|language| language.to_string());
+    let name = if data_type.is_some() {
+        ELEMENT_TYPED_LITERAL
+    } else {
+        ELEMENT_PLAIN_LITERAL
+    };
+    let mut element = XmlEvent::start_element(name);
+    if let Some(data_type) = &data_type {
+        element = element.attr(ATTRIBUTE_DATATYPE, data_type);
+    } else if let Some(language) = &language {
+        element = element.attr(ATTRIBUTE_XML_LANG_PREFIXED, language);
+    }
+    writer.write(element).map_err(xml_error)?;
+    writer
+        .write(XmlEvent::Characters(&unescape_lexical_form(
+            literal.lexical_form(),
+        )))
+        .map_err(xml_error)?;
+    writer
+        .write(XmlEvent::end_element().name(name))
+        .map_err(xml_error)
+}
+
+fn write_text_element<W: Write>(
+    writer: &mut EventWriter<W>,
+    name: &str,
+    text: &str,
+) -> Result<(), Error> {
+    writer
+        .write(XmlEvent::start_element(name))
+        .map_err(xml_error)?;
+    writer
+        .write(XmlEvent::Characters(text))
+        .map_err(xml_error)?;
+    writer
+        .write(XmlEvent::end_element().name(name))
+        .map_err(xml_error)
+}
@@ -63,5 +63,5 @@ pub const MIME_TYPE: &str = "text/turtle";
 mod reader;
 pub use reader::TurtleReader;
 
-mod writer;
-pub use writer::{TurtleWriter, TurtleWriterOptions};
+pub(crate) mod writer;
+pub use writer::{TurtleSink, TurtleWriter, TurtleWriterOptions};
@@ -1,29 +1,63 @@
-use crate::common::parser::parse_turtle_doc;
-use objio::ObjectReader;
+use crate::common::parser::{parse_turtle_doc, parse_turtle_doc_lenient};
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
 use rdftk_core::error::Error;
 use rdftk_core::model::graph::Graph;
 use std::io::Read;
+use tracing::warn;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Default)]
-pub struct TurtleReader {}
+pub struct TurtleReader {
+    options: ReaderOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_has_options!(TurtleReader, ReaderOptions);
+
 impl ObjectReader<Graph> for TurtleReader {
     type Error = Error;
 
     fn read<R>(&self, r: &mut R) -> Result<Graph, Self::Error>
+    where
+        R: Read,
+    {
+        let (graph, diagnostics) = self.read_with_diagnostics(r)?;
+        diagnostics
+            .iter()
+            .for_each(|e| warn!("TurtleReader skipped statement: {e}"));
+        Ok(graph)
+    }
+}
+
+impl TurtleReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
+    }
+
+    ///
+    /// Read a graph, returning it along with the errors for any statements that were skipped.
+    /// Unless the reader is lenient the first error fails the read, and so the list of
+    /// diagnostics is always empty.
+    ///
+    pub fn read_with_diagnostics<R>(&self, r: &mut R) -> Result<(Graph, Vec<Error>), Error>
     where
         R: Read,
     {
         let mut buffer = String::new();
         r.read_to_string(&mut buffer)?;
-        parse_turtle_doc(buffer)
+        if self.options.is_lenient() {
+            Ok(parse_turtle_doc_lenient(buffer, &self.options))
+        } else {
+            Ok((parse_turtle_doc(buffer, &self.options)?, Vec::default()))
+        }
     }
 }
@@ -1,16 +1,19 @@
-use crate::{common::indenter::Indenter, GraphWriter};
+use crate::{
+    common::{indenter::Indenter, literal::quoted_lexical_form, simplify::reify_statement},
+    GraphWriter, StatementSink,
+};
 use itertools::Itertools;
 use objio::{impl_has_options, HasOptions, ObjectWriter};
 use rdftk_core::{
-    error::{Error, Result},
+    error::{rdf_star_not_supported_error, Error, Result},
     model::{
         graph::Graph,
         literal::{DataType, Literal},
-        statement::{Collection, ObjectNode, Statement, SubjectNode},
+        statement::{BlankNode, Collection, ObjectNode, Statement, SubjectNode},
     },
 };
-use rdftk_iri::Iri;
-use rdftk_names::{dc::elements, foaf, owl, rdf, rdfs, skos};
+use rdftk_iri::{Iri, IriPrefixMap, Namespace};
+use rdftk_names::{dc::elements, foaf, owl, rdf, rdfs, skos, xsd};
 use std::{
     cell::RefCell,
     collections::{BTreeMap, HashSet},
@@ -31,7 +34,6 @@ use tracing::trace;
 pub struct TurtleWriterOptions {
     id_base: Option<Iri>,
     nest_blank_nodes: bool,
-    outdent_blank_node_end: bool,
     outdent_collection_end: bool,
     use_rdf_type_a: bool,
     use_sparql_style: bool,
@@ -41,10 +43,13 @@ pub struct TurtleWriterOptions {
     convert_base: Vec<(Iri, Iri)>,
     indent_width: usize,
     predicate_padding: bool,
+    reify_statements: bool,
+    annotations: bool,
 }
 
 ///
-/// An implementation of `ObjectWriter` for Graphs.
+/// An implementation of `ObjectWriter` for Graphs. Nested statements are written as Turtle-star
+/// quoted triples, `<< s p o >>`, unless the `reify_statements` option is set.
 ///
 #[derive(Clone, Debug, Default)]
 pub struct TurtleWriter {
@@ -52,6 +57,21 @@ pub struct TurtleWriter {
     context: RefCell<WriterContext>,
 }
 
+///
+/// An implementation of [`StatementSink`], returned by [`TurtleWriter::sink`], which writes
+/// each statement as it is received. As the graph is not available statements are not sorted
+/// or nested, instead consecutive statements with the same subject are written together as a
+/// predicate-object list. Only prefixes declared on the sink are used to compress IRIs.
+///
+#[derive(Debug)]
+pub struct TurtleSink<W> {
+    writer: W,
+    turtle: TurtleWriter,
+    mappings: IriPrefixMap,
+    subject: Option<SubjectNode>,
+    after_directive: bool,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
@@ -111,6 +131,7 @@ enum PredicateGroupOrdering {
 struct WriterContext {
     indenter: Indenter,
     blanks_to_write: HashSet<SubjectNode>,
+    labeled_blanks: HashSet<BlankNode>,
 }
 
 const DECL_BASE_TTL: &str = "@base";
@@ -133,6 +154,10 @@ const SPACE_SEPARATOR: &str = " ";
 const END_OF_STATEMENT: &str = " .";
 const END_OF_LINE: &str = "\n";
 const RDF_TYPE_A: &str = "a";
+const QUOTED_TRIPLE_START: &str = "<<";
+const QUOTED_TRIPLE_END: &str = ">>";
+const ANNOTATION_START: &str = "{|";
+const ANNOTATION_END: &str = "|}";
 
 // ------------------------------------------------------------------------------------------------
 // Implementations > Options
@@ -143,7 +168,6 @@ impl Default for TurtleWriterOptions {
         Self {
             id_base: None,
             nest_blank_nodes: true,
-            outdent_blank_node_end: false,
             outdent_collection_end: false,
             use_rdf_type_a: false,
             use_sparql_style: false,
@@ -153,6 +177,8 @@ impl Default for TurtleWriterOptions {
             convert_base: Vec::new(),
             indent_width: 4,
             predicate_padding: false,
+            reify_statements: false,
+            annotations: false,
         }
     }
 }
@@ -207,6 +233,20 @@ impl TurtleWriterOptions {
         }
     }
 
+    pub fn with_reified_statements(self) -> Self {
+        Self {
+            reify_statements: true,
+            ..self
+        }
+    }
+
+    pub fn with_annotations(self) -> Self {
+        Self {
+            annotations: true,
+            ..self
+        }
+    }
+
     pub fn with_nested_blank_nodes(self) -> Self {
         Self {
             nest_blank_nodes: true,
@@ -321,6 +361,31 @@ impl TurtleWriterOptions {
     pub fn set_predicate_padding(&mut self, predicate_padding: bool) {
         self.predicate_padding = predicate_padding;
     }
+
+    ///
+    /// If true, nested statements are replaced by their reified form, using `rdf:subject`,
+    /// `rdf:predicate` and `rdf:object`, rather than being written as quoted triples.
+    ///
+    pub fn reify_statements(&self) -> bool {
+        self.reify_statements
+    }
+
+    pub fn set_reify_statements(&mut self, reify_statements: bool) {
+        self.reify_statements = reify_statements;
+    }
+
+    ///
+    /// If true, statements about a quoted triple that is also asserted in the graph are written
+    /// with the annotation syntax, `:s :p :o {| :source :x |}`, after the asserted triple.
+    /// Annotations are not written by the streaming [`TurtleSink`].
+    ///
+    pub fn annotations(&self) -> bool {
+        self.annotations
+    }
+
+    pub fn set_annotations(&mut self, annotations: bool) {
+        self.annotations = annotations;
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -344,34 +409,35 @@ impl ObjectWriter<Graph> for TurtleWriter {
     where
         W: Write,
     {
-        let (nested, plain): (Vec<_>, Vec<_>) =
-            graph.statements().partition(|stmt| stmt.is_nested());
-
-        let mut reified_statements: Vec<Statement> = nested
-            .into_iter()
-            .map(|stmt| {
-                let (subject, mut new) = stmt.reify().unwrap();
-                new.push(Statement::new(
-                    stmt.subject(),
-                    stmt.predicate().clone(),
-                    subject.to_object(),
-                ));
-                new
-            })
-            .flatten()
-            .collect();
-        plain
-            .into_iter()
-            .for_each(|s| reified_statements.push(s.clone()));
-
-        let mut denested_graph = Graph::from(reified_statements);
-        denested_graph.set_prefix_mappings(graph.prefix_mappings().clone());
-        self.write_turtle_doc(w, &denested_graph)
+        if self.options.reify_statements() {
+            self.write_turtle_doc(w, &reify_graph(graph)?)
+        } else {
+            self.write_turtle_doc(w, graph)
+        }
     }
 }
 
 impl GraphWriter for TurtleWriter {}
 
+impl TurtleWriter {
+    ///
+    /// Return a [`StatementSink`] that writes statements to `w` as they are received, using
+    /// this writer's options.
+    ///
+    pub fn sink<W>(&self, w: W) -> TurtleSink<W>
+    where
+        W: Write,
+    {
+        TurtleSink {
+            writer: w,
+            turtle: Self::default().with_options(self.options.clone()),
+            mappings: IriPrefixMap::empty(),
+            subject: None,
+            after_directive: false,
+        }
+    }
+}
+
 impl TurtleWriter {
     // ---------------------------------------------------------------------------------------------
     // Formatting
@@ -430,6 +496,10 @@ impl TurtleWriter {
         graph
             .node_subjects()
             .into_iter()
+            .filter(|subject| match subject {
+                SubjectNode::Statement(quoted) => !self.is_annotation(graph, quoted),
+                _ => true,
+            })
             .sorted()
             .cloned()
             .collect::<Vec<SubjectNode>>()
@@ -440,7 +510,7 @@ impl TurtleWriter {
         trace!("max_len_predicates");
         let all_predicates_as_strings = predicates
             .iter()
-            .map(|iri| self.compress_iri(graph, iri))
+            .map(|iri| self.compress_iri(graph.prefix_mappings(), iri))
             .collect::<Result<Vec<String>>>()?
             .iter()
             .fold(0, |a, b| a.max(b.len()));
@@ -472,20 +542,7 @@ impl TurtleWriter {
     ///
     fn write_turtle_doc<W: Write>(&self, w: &mut W, graph: &Graph) -> Result<()> {
         trace!("write_turtle_doc");
-        {
-            let mut context_mut = self.context.borrow_mut();
-            context_mut.indenter =
-                Indenter::default().with_default_indent_width(self.options.indent_width());
-            // Initialize with all the blank nodes in the graph
-            context_mut.blanks_to_write = graph
-                .blank_node_subjects()
-                .iter()
-                .map(|s| {
-                    let s = *s;
-                    s.clone()
-                })
-                .collect();
-        }
+        self.reset_context(graph, &HashSet::default());
 
         // [3] directives
         self.write_base_iri(w)?;
@@ -497,6 +554,78 @@ impl TurtleWriter {
         Ok(())
     }
 
+    ///
+    /// Write only the directives, base and prefixes, for `graph`; this is used by the TriG writer
+    /// which writes a single set of directives for all graphs in a data set.
+    ///
+    pub(crate) fn write_directives<W: Write>(&self, w: &mut W, graph: &Graph) -> Result<()> {
+        trace!("write_directives");
+        self.write_base_iri(w)?;
+        self.write_prefixes(w, graph)
+    }
+
+    ///
+    /// Write only the triples for `graph`, without any directives; this is used by the TriG
+    /// writer for the content of each graph block. The blank nodes in `shared` are also used
+    /// outside of `graph`, and so are always written by label rather than nested.
+    ///
+    pub(crate) fn write_graph_triples<W: Write>(
+        &self,
+        w: &mut W,
+        graph: &Graph,
+        shared: &HashSet<BlankNode>,
+    ) -> Result<()> {
+        trace!("write_graph_triples");
+        if self.options.reify_statements() {
+            let graph = reify_graph(graph)?;
+            self.reset_context(&graph, shared);
+            self.write_triples(w, &graph, WriterStatusFlags::default())
+        } else {
+            self.reset_context(graph, shared);
+            self.write_triples(w, graph, WriterStatusFlags::default())
+        }
+    }
+
+    fn reset_context(&self, graph: &Graph, shared: &HashSet<BlankNode>) {
+        let mut context_mut = self.context.borrow_mut();
+        context_mut.indenter =
+            Indenter::default().with_default_indent_width(self.options.indent_width());
+        // Initialize with all the blank nodes in the graph
+        context_mut.blanks_to_write = graph
+            .blank_node_subjects()
+            .iter()
+            .map(|s| {
+                let s = *s;
+                s.clone()
+            })
+            .collect();
+        // Blank nodes within quoted triples, or annotations, and those shared with other graphs
+        // are always written by label
+        let mut labeled_blanks = shared.clone();
+        for statement in graph.statements() {
+            if let SubjectNode::Statement(quoted) = statement.subject() {
+                statement_blank_nodes(quoted, &mut labeled_blanks);
+                if let (true, ObjectNode::Blank(blank)) =
+                    (self.is_annotation(graph, quoted), statement.object())
+                {
+                    let _ = labeled_blanks.insert(blank.clone());
+                }
+            }
+            if let ObjectNode::Statement(quoted) = statement.object() {
+                statement_blank_nodes(quoted, &mut labeled_blanks);
+            }
+        }
+        context_mut.labeled_blanks = labeled_blanks;
+    }
+
+    ///
+    /// Returns true if statements about `quoted` are to be written as an annotation on the
+    /// asserted triple, rather than with `quoted` as a subject.
+    ///
+    fn is_annotation(&self, graph: &Graph, quoted: &Statement) -> bool {
+        self.options.annotations() && !quoted.object().is_collection() && graph.contains(quoted)
+    }
+
     ///
     /// ```text
     /// [5]   base        ::= '@base' IRIREF '.'
@@ -512,11 +641,7 @@ impl TurtleWriter {
                 } else {
                     (DECL_BASE_TTL, END_OF_STATEMENT)
                 };
-            writeln!(
-                w,
-                "{decl} {IRI_START}{}{IRI_END}{eos}",
-                base.to_string().as_str()
-            )?;
+            writeln!(w, "{decl} {IRI_START}{:#}{IRI_END}{eos}", base)?;
             if !self.options.use_intellij_style() {
                 writeln!(w)?;
             }
@@ -535,38 +660,38 @@ impl TurtleWriter {
         let mappings = graph.prefix_mappings();
         if !mappings.is_empty() {
             for (prefix, namespace) in mappings.mappings().sorted() {
-                let prefix = prefix.as_ref().map(|n| n.as_ref()).unwrap_or("");
-                let mut namespace_str = namespace.to_string();
-                // If we have any base Iri conversions to do for any of the
-                // namespaces, then do it now:
-                for (from_base, to_base) in self.options.convert_base().iter() {
-                    let from_base_str = from_base.to_string();
-                    if namespace_str.starts_with(from_base_str.as_str()) {
-                        namespace_str = format!(
-                            "{}{}",
-                            to_base.to_string().as_str(),
-                            &namespace_str[from_base_str.len()..]
-                        );
-                        break;
-                    }
-                }
-                trace!("write_prefixes {prefix}: {IRI_START}{namespace_str}{IRI_END}");
-                let (decl, eos) =
-                    if self.options.use_sparql_style() && !self.options.use_intellij_style() {
-                        (DECL_PREFIX_SPARQL, "")
-                    } else {
-                        (DECL_PREFIX_TTL, END_OF_STATEMENT)
-                    };
-                writeln!(
-                    w,
-                    "{decl} {prefix}{NAME_SEPARATOR} {IRI_START}{namespace_str}{IRI_END}{eos}"
-                )?;
+                self.write_prefix(w, prefix, namespace)?;
             }
             writeln!(w)?;
         }
         Ok(())
     }
 
+    fn write_prefix<W: Write>(&self, w: &mut W, prefix: &Namespace, namespace: &Iri) -> Result<()> {
+        let prefix = prefix.name_string().unwrap_or("");
+        let mut namespace_str = format!("{namespace:#}");
+        // If we have any base Iri conversions to do for any of the
+        // namespaces, then do it now:
+        for (from_base, to_base) in self.options.convert_base().iter() {
+            let from_base_str = format!("{from_base:#}");
+            if namespace_str.starts_with(from_base_str.as_str()) {
+                namespace_str = format!("{:#}{}", to_base, &namespace_str[from_base_str.len()..]);
+                break;
+            }
+        }
+        trace!("write_prefixes {prefix}: {IRI_START}{namespace_str}{IRI_END}");
+        let (decl, eos) = if self.options.use_sparql_style() && !self.options.use_intellij_style() {
+            (DECL_PREFIX_SPARQL, "")
+        } else {
+            (DECL_PREFIX_TTL, END_OF_STATEMENT)
+        };
+        writeln!(
+            w,
+            "{decl} {prefix}{NAME_SEPARATOR} {IRI_START}{namespace_str}{IRI_END}{eos}"
+        )?;
+        Ok(())
+    }
+
     ///
     /// ```text
     /// [6]   triples  ::= subject predicateObjectList
@@ -590,12 +715,22 @@ impl TurtleWriter {
         // been written to the turtle file yet but have been referred to during
         // the call to `write_normal_subjects` above. Now process those
         // unwritten blank nodes and add them to the end of the file.
-        let context = self.context.borrow();
-        // Iterate over all blank nodes
-        for subject in context.blanks_to_write.iter() {
-            context.indenter.reset_depth();
-            self.write_subject(w, graph, subject, flags)?;
-            self.write_predicate_object_list(w, graph, subject, flags)?;
+        let remaining = self
+            .context
+            .borrow()
+            .blanks_to_write
+            .iter()
+            .sorted()
+            .cloned()
+            .collect_vec();
+        // Iterate over all blank nodes, skipping any nested while writing an earlier one
+        for subject in remaining {
+            if !self.context.borrow_mut().blanks_to_write.remove(&subject) {
+                continue;
+            }
+            self.context.borrow().indenter.reset_depth();
+            self.write_subject(w, graph, &subject, flags)?;
+            self.write_predicate_object_list(w, graph, &subject, flags)?;
             writeln!(w)?;
         }
         Ok(())
@@ -603,7 +738,7 @@ impl TurtleWriter {
 
     ///
     /// ```text
-    /// [10]  subject  ::= iri | BlankNode | collection
+    /// [10]  subject  ::= iri | BlankNode | collection | quotedTriple
     /// ```
     ///
     fn write_subject<W: Write>(
@@ -625,10 +760,10 @@ impl TurtleWriter {
                 write!(w, "{initial}{BLANK_NODE_PREFIX}{NAME_SEPARATOR}{blank}")?;
             }
             (SubjectNode::Resource(_), _) => {
-                self.write_iri(w, graph, subject.as_resource().unwrap())?;
+                self.write_iri(w, graph.prefix_mappings(), subject.as_resource().unwrap())?;
             }
-            (SubjectNode::Statement(_), _) => {
-                unreachable!("RDF-* Statements are not supported in Turtle representation")
+            (SubjectNode::Statement(quoted), _) => {
+                self.write_quoted_triple(w, graph.prefix_mappings(), quoted.as_ref())?;
             }
             _ => {}
         }
@@ -659,7 +794,8 @@ impl TurtleWriter {
         };
 
         for (group, ref mut preds) in PredicateGroupOrdering::group_predicates(&all_predicates) {
-            preds.sort_by_cached_key(|iri| self.compress_iri(graph, iri).unwrap());
+            preds
+                .sort_by_cached_key(|iri| self.compress_iri(graph.prefix_mappings(), iri).unwrap());
             for predicate in preds {
                 count += 1;
                 let flags = WriterStatusFlags {
@@ -693,7 +829,7 @@ impl TurtleWriter {
             let type_verb = if self.options.use_rdf_type_a {
                 RDF_TYPE_A.to_string()
             } else {
-                self.compress_iri(graph, rdf::a_type())?
+                self.compress_iri(graph.prefix_mappings(), rdf::a_type())?
             };
             return if self.options.place_type_on_subject_line() {
                 Ok(write!(w, " {type_verb} ")?)
@@ -704,7 +840,7 @@ impl TurtleWriter {
         }
         // Otherwise, go to the next line and write it as a normal predicate-Iri
         self.new_line(w, flags)?;
-        let pred = self.compress_iri(graph, predicate)?;
+        let pred = self.compress_iri(graph.prefix_mappings(), predicate)?;
         self.write_padded(w, pred.as_str(), max_len)
     }
 
@@ -715,21 +851,21 @@ impl TurtleWriter {
     /// ```
     ///
     #[inline(always)]
-    fn write_iri<W: Write>(&self, w: &mut W, graph: &Graph, iri: &Iri) -> Result<()> {
+    fn write_iri<W: Write>(&self, w: &mut W, mappings: &IriPrefixMap, iri: &Iri) -> Result<()> {
         trace!(name: "write_iri", ?iri);
-        Ok(write!(w, "{}", self.compress_iri(graph, iri)?)?)
+        Ok(write!(w, "{}", self.compress_iri(mappings, iri)?)?)
     }
 
     /// Compress any Iri to its "QName" given the supplied set of prefixes and
     /// their namespace Iris. If we're encountering an Iri whose prefix
     /// equals the given (optional) `convert_to_base` Iri then write it to
     /// Turtle as if it's an Iri with the default base.
-    fn compress_iri(&self, graph: &Graph, iri: &Iri) -> Result<String> {
+    fn compress_iri(&self, mappings: &IriPrefixMap, iri: &Iri) -> Result<String> {
         trace!(name: "compress_iri", ?iri);
-        let mut iri_str = iri.to_string();
+        let mut iri_str = format!("{iri:#}");
         if let Some(id_base) = &self.options.id_base() {
             if let Some(ref convert_to_id_base) = self.options.convert_to_id_base() {
-                let target_id_base = convert_to_id_base.to_string();
+                let target_id_base = format!("{convert_to_id_base:#}");
                 if iri_str.starts_with(target_id_base.as_str()) {
                     return Ok(format!(
                         "{IRI_START}{}{IRI_END}",
@@ -737,7 +873,7 @@ impl TurtleWriter {
                     ));
                 }
             }
-            let id_base_str = id_base.to_string();
+            let id_base_str = format!("{id_base:#}");
             if iri_str.starts_with(id_base_str.as_str()) {
                 return Ok(format!(
                     "{IRI_START}{}{IRI_END}",
@@ -746,18 +882,14 @@ impl TurtleWriter {
             }
         }
         for (from_base, to_base) in self.options.convert_base().iter() {
-            let from_base_str = from_base.to_string();
+            let from_base_str = format!("{from_base:#}");
             if iri_str.starts_with(from_base_str.as_str()) {
-                iri_str = format!(
-                    "{}{}",
-                    to_base.to_string().as_str(),
-                    &iri_str[from_base_str.len()..]
-                );
+                iri_str = format!("{:#}{}", to_base, &iri_str[from_base_str.len()..]);
             }
         }
         let iri = Iri::from_str(iri_str.as_str())?;
-        Ok(match graph.prefix_mappings().compress(&iri) {
-            None => format!("{IRI_START}{iri}{IRI_END}"),
+        Ok(match mappings.compress(&iri) {
+            None => format!("{IRI_START}{iri:#}{IRI_END}"),
             Some(_qname) => format!("{_qname}"),
         })
     }
@@ -773,50 +905,56 @@ impl TurtleWriter {
     ///                           | STRING_LITERAL_LONG_QUOTE
     /// ```
     //
-    fn write_literal<W: Write>(&self, w: &mut W, graph: &Graph, literal: &Literal) -> Result<()> {
+    fn write_literal<W: Write>(
+        &self,
+        w: &mut W,
+        mappings: &IriPrefixMap,
+        literal: &Literal,
+    ) -> Result<()> {
         trace!(name: "write_literal", ?literal);
-        Ok(match literal.data_type() {
+        match literal.data_type() {
             Some(DataType::Iri) => {
-                let iri = Iri::parse(literal.lexical_form())?;
-                self.write_iri(w, graph, &iri)?
+                let iri = Iri::from_str(literal.lexical_form())?;
+                self.write_iri(w, mappings, &iri)?;
             }
-            Some(DataType::Boolean)
-            | Some(DataType::Long)
-            | Some(DataType::Int)
-            | Some(DataType::Short)
-            | Some(DataType::Byte)
-            | Some(DataType::UnsignedLong)
-            | Some(DataType::UnsignedInt)
-            | Some(DataType::UnsignedShort)
-            | Some(DataType::UnsignedByte)
-            | Some(DataType::Float)
-            | Some(DataType::Double)
-            | Some(DataType::Decimal) => write!(w, "{}", literal.lexical_form())?,
+            _ if is_shorthand_literal(literal) => write!(w, "{}", literal.lexical_form())?,
             _ => {
-                write!(w, "{:?}", literal.lexical_form())?;
+                write!(w, "{}", quoted_lexical_form(literal))?;
                 match (literal.data_type(), literal.language()) {
                     (Some(data_type), None) => {
                         write!(w, "{DATATYPE_PREFIX}")?;
                         let iri = data_type.as_iri();
-                        self.write_iri(w, graph, iri)?;
+                        self.write_iri(w, mappings, iri)?;
                     }
                     (None, Some(language)) => write!(w, "{LANGUAGE_PREFIX}{}", language)?,
                     _ => (),
                 }
             }
-        })
+        }
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn write_predicate_object_object<W: Write>(
         &self,
         w: &mut W,
         graph: &Graph,
+        subject: &SubjectNode,
+        predicate: &Iri,
         object: &ObjectNode,
         max_len: usize,
         flags: WriterStatusFlags,
     ) -> Result<()> {
         trace!(name: "write_predicate_object_object", ?object, max_len, ?flags);
         self.write_object(w, graph, object, flags)?;
+        if self.options.annotations() && !flags.is_being_sorted {
+            let quoted = Statement::new(subject.clone(), predicate.clone(), object.clone());
+            if self.is_annotation(graph, &quoted)
+                && graph.contains_subject(&SubjectNode::from(&quoted))
+            {
+                self.write_annotation(w, graph, &quoted)?;
+            }
+        }
         if flags.is_last_of_predicate {
             if flags.is_last_of_subject {
                 self.outdent();
@@ -844,6 +982,7 @@ impl TurtleWriter {
     ///
     /// ```text
     /// [12]  object  ::= iri | BlankNode | collection | blankNodePropertyList | literal
+    ///                 | quotedTriple
     /// ````
     ///
     fn write_object<W: Write>(
@@ -855,6 +994,9 @@ impl TurtleWriter {
     ) -> Result<()> {
         trace!(name: "write_object", ?object, ?flags);
         match &object {
+            ObjectNode::Blank(blank) if self.context.borrow().labeled_blanks.contains(blank) => {
+                write!(w, "{BLANK_NODE_PREFIX}{NAME_SEPARATOR}{blank}")?;
+            }
             ObjectNode::Blank(blank) => {
                 if self.options.nest_blank_nodes()
                     && graph.contains_subject(&object.to_subject().unwrap())
@@ -869,16 +1011,135 @@ impl TurtleWriter {
                 }
             }
             ObjectNode::Resource(iri) => {
-                self.write_iri(w, graph, iri)?;
+                self.write_iri(w, graph.prefix_mappings(), iri)?;
             }
             ObjectNode::Literal(value) => {
-                self.write_literal(w, graph, value)?;
+                self.write_literal(w, graph.prefix_mappings(), value)?;
             }
             ObjectNode::Collection(lst) => {
                 self.write_collection(w, graph, lst, flags)?;
             }
-            ObjectNode::Statement(_) => {
-                unreachable!("RDF-* Statements are not supported in Turtle representation")
+            ObjectNode::Statement(quoted) => {
+                self.write_quoted_triple(w, graph.prefix_mappings(), quoted.as_ref())?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// ```text
+    /// quotedTriple  ::= '<<' qtSubject verb qtObject '>>'
+    /// qtSubject     ::= iri | BlankNode | quotedTriple
+    /// qtObject      ::= iri | BlankNode | literal | quotedTriple
+    /// ```
+    ///
+    fn write_quoted_triple<W: Write>(
+        &self,
+        w: &mut W,
+        mappings: &IriPrefixMap,
+        statement: &Statement,
+    ) -> Result<()> {
+        trace!(name: "write_quoted_triple", ?statement);
+        if statement.object().is_collection() {
+            // a collection is a set of statements and so cannot be quoted.
+            return rdf_star_not_supported_error(super::NAME).into();
+        }
+        write!(w, "{QUOTED_TRIPLE_START}{SPACE_SEPARATOR}")?;
+        match statement.subject() {
+            SubjectNode::Blank(blank) => write!(w, "{BLANK_NODE_PREFIX}{NAME_SEPARATOR}{blank}")?,
+            SubjectNode::Resource(iri) => self.write_iri(w, mappings, iri)?,
+            SubjectNode::Statement(quoted) => {
+                self.write_quoted_triple(w, mappings, quoted.as_ref())?
+            }
+        }
+        write!(w, "{SPACE_SEPARATOR}")?;
+        self.write_inline_verb(w, mappings, statement.predicate())?;
+        write!(w, "{SPACE_SEPARATOR}")?;
+        self.write_inline_object(w, mappings, statement.object())?;
+        write!(w, "{SPACE_SEPARATOR}{QUOTED_TRIPLE_END}")?;
+        Ok(())
+    }
+
+    ///
+    /// ```text
+    /// annotation  ::= '{|' predicateObjectList '|}'
+    /// ```
+    ///
+    fn write_annotation<W: Write>(
+        &self,
+        w: &mut W,
+        graph: &Graph,
+        quoted: &Statement,
+    ) -> Result<()> {
+        trace!(name: "write_annotation", ?quoted);
+        let mappings = graph.prefix_mappings();
+        let subject = SubjectNode::from(quoted);
+        write!(w, "{SPACE_SEPARATOR}{ANNOTATION_START}")?;
+        let predicates = graph
+            .predicates_for(&subject)
+            .into_iter()
+            .sorted_by_cached_key(|iri| self.compress_iri(mappings, iri).unwrap_or_default())
+            .collect_vec();
+        for (idx, predicate) in predicates.iter().enumerate() {
+            if idx > 0 {
+                write!(w, "{PREDICATE_SEPARATOR}")?;
+            }
+            write!(w, "{SPACE_SEPARATOR}")?;
+            self.write_inline_verb(w, mappings, predicate)?;
+            let objects = graph
+                .objects_for(&subject, predicate)
+                .into_iter()
+                .sorted()
+                .collect_vec();
+            for (idx, object) in objects.iter().enumerate() {
+                if idx > 0 {
+                    write!(w, "{OBJECT_SEPARATOR}")?;
+                }
+                write!(w, "{SPACE_SEPARATOR}")?;
+                self.write_inline_object(w, mappings, object)?;
+            }
+        }
+        write!(w, "{SPACE_SEPARATOR}{ANNOTATION_END}")?;
+        Ok(())
+    }
+
+    fn write_inline_verb<W: Write>(
+        &self,
+        w: &mut W,
+        mappings: &IriPrefixMap,
+        predicate: &Iri,
+    ) -> Result<()> {
+        if self.options.use_rdf_type_a && predicate == rdf::a_type() {
+            Ok(write!(w, "{RDF_TYPE_A}")?)
+        } else {
+            self.write_iri(w, mappings, predicate)
+        }
+    }
+
+    ///
+    /// Write an object on a single line, without nesting blank nodes, as required within quoted
+    /// triples and annotations.
+    ///
+    fn write_inline_object<W: Write>(
+        &self,
+        w: &mut W,
+        mappings: &IriPrefixMap,
+        object: &ObjectNode,
+    ) -> Result<()> {
+        match object {
+            ObjectNode::Blank(blank) => write!(w, "{BLANK_NODE_PREFIX}{NAME_SEPARATOR}{blank}")?,
+            ObjectNode::Resource(iri) => self.write_iri(w, mappings, iri)?,
+            ObjectNode::Literal(literal) => self.write_literal(w, mappings, literal)?,
+            ObjectNode::Collection(collection) => {
+                write!(w, "{COLLECTION_START}")?;
+                for object in collection.iter() {
+                    write!(w, "{SPACE_SEPARATOR}")?;
+                    self.write_inline_object(w, mappings, object)?;
+                }
+                write!(w, "{SPACE_SEPARATOR}{COLLECTION_END}")?;
+            }
+            ObjectNode::Statement(quoted) => {
+                self.write_quoted_triple(w, mappings, quoted.as_ref())?
             }
         }
         Ok(())
@@ -886,7 +1147,7 @@ impl TurtleWriter {
 
     ///
     /// ```text
-    /// [15]  collection  ::=	'(' object* ')'
+    /// [15]  collection  ::=    '(' object* ')'
     /// ```
     ///
     #[inline(always)]
@@ -957,7 +1218,9 @@ impl TurtleWriter {
                 is_last_of_predicate: next_object.is_none(),
                 ..flags
             };
-            self.write_predicate_object_object(w, graph, object, max_len, flags)?;
+            self.write_predicate_object_object(
+                w, graph, subject, predicate, object, max_len, flags,
+            )?;
         }
         Ok(())
     }
@@ -977,6 +1240,11 @@ impl TurtleWriter {
         self.indent();
         write!(w, "{BLANK_NODE_START}")?;
         let inner_subject = object.to_subject().unwrap();
+        let _ = self
+            .context
+            .borrow_mut()
+            .blanks_to_write
+            .remove(&inner_subject);
         self.write_predicate_object_list(w, graph, &inner_subject, flags)?;
         self.new_line(w, flags)?;
         write!(w, "{BLANK_NODE_END}")?;
@@ -984,6 +1252,136 @@ impl TurtleWriter {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Implementations > Sink
+// ------------------------------------------------------------------------------------------------
+
+impl<W> StatementSink for TurtleSink<W>
+where
+    W: Write,
+{
+    fn start(&mut self) -> Result<()> {
+        self.turtle.write_base_iri(&mut self.writer)?;
+        self.after_directive = self.turtle.options.id_base().is_some();
+        Ok(())
+    }
+
+    fn prefix(&mut self, prefix: &Namespace, namespace: &Iri) -> Result<()> {
+        self.end_subject()?;
+        self.turtle
+            .write_prefix(&mut self.writer, prefix, namespace)?;
+        self.mappings.insert(prefix.clone(), namespace.clone());
+        self.after_directive = true;
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<()> {
+        let statements = if self.turtle.options.reify_statements() {
+            reify_statement(statement)?
+        } else {
+            vec![statement.clone()]
+        };
+        for statement in statements {
+            if self.subject.as_ref() == Some(statement.subject()) {
+                write!(
+                    self.writer,
+                    "{PREDICATE_SEPARATOR}{END_OF_LINE}{:width$}",
+                    "",
+                    width = self.turtle.options.indent_width()
+                )?;
+            } else {
+                if self.end_subject()? || self.after_directive {
+                    writeln!(self.writer)?;
+                    self.after_directive = false;
+                }
+                self.write_subject(statement.subject())?;
+                write!(self.writer, "{SPACE_SEPARATOR}")?;
+                self.subject = Some(statement.subject().clone());
+            }
+            self.write_predicate(statement.predicate())?;
+            write!(self.writer, "{SPACE_SEPARATOR}")?;
+            self.write_object(statement.object())?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.end_subject()?;
+        Ok(self.writer.flush()?)
+    }
+}
+
+impl<W> TurtleSink<W>
+where
+    W: Write,
+{
+    fn end_subject(&mut self) -> Result<bool> {
+        let ended = self.subject.take().is_some();
+        if ended {
+            writeln!(self.writer, "{END_OF_STATEMENT}")?;
+        }
+        Ok(ended)
+    }
+
+    fn write_subject(&mut self, subject: &SubjectNode) -> Result<()> {
+        match subject {
+            SubjectNode::Blank(blank) => {
+                write!(self.writer, "{BLANK_NODE_PREFIX}{NAME_SEPARATOR}{blank}")?
+            }
+            SubjectNode::Resource(iri) => {
+                self.turtle
+                    .write_iri(&mut self.writer, &self.mappings, iri)?
+            }
+            SubjectNode::Statement(quoted) => self.turtle.write_quoted_triple(
+                &mut self.writer,
+                &self.mappings,
+                quoted.as_ref(),
+            )?,
+        }
+        Ok(())
+    }
+
+    fn write_predicate(&mut self, predicate: &Iri) -> Result<()> {
+        if self.turtle.options.use_rdf_type_a && predicate == rdf::a_type() {
+            write!(self.writer, "{RDF_TYPE_A}")?;
+            Ok(())
+        } else {
+            self.turtle
+                .write_iri(&mut self.writer, &self.mappings, predicate)
+        }
+    }
+
+    fn write_object(&mut self, object: &ObjectNode) -> Result<()> {
+        match object {
+            ObjectNode::Blank(blank) => {
+                write!(self.writer, "{BLANK_NODE_PREFIX}{NAME_SEPARATOR}{blank}")?
+            }
+            ObjectNode::Resource(iri) => {
+                self.turtle
+                    .write_iri(&mut self.writer, &self.mappings, iri)?
+            }
+            ObjectNode::Literal(literal) => {
+                self.turtle
+                    .write_literal(&mut self.writer, &self.mappings, literal)?
+            }
+            ObjectNode::Collection(collection) => {
+                write!(self.writer, "{COLLECTION_START}")?;
+                for object in collection.iter() {
+                    write!(self.writer, "{SPACE_SEPARATOR}")?;
+                    self.write_object(object)?;
+                }
+                write!(self.writer, "{SPACE_SEPARATOR}{COLLECTION_END}")?;
+            }
+            ObjectNode::Statement(quoted) => self.turtle.write_quoted_triple(
+                &mut self.writer,
+                &self.mappings,
+                quoted.as_ref(),
+            )?,
+        }
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations > Ordering
 // ------------------------------------------------------------------------------------------------
@@ -1006,8 +1404,8 @@ impl PredicateGroupOrdering {
     fn group_predicate(predicate: &&&Iri) -> PredicateGroupOrdering {
         PREDICATE_GROUP_MAP
             .get(predicate)
-            .map(|v| *v)
-            .unwrap_or_else(|| PredicateGroupOrdering::Other)
+            .copied()
+            .unwrap_or(PredicateGroupOrdering::Other)
     }
 
     fn new_mapping() -> BTreeMap<Iri, Self> {
@@ -1034,6 +1432,85 @@ impl PredicateGroupOrdering {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns `true` if the literal may be written without quotes, as an `INTEGER`, `DECIMAL`,
+/// `DOUBLE`, or `BooleanLiteral`, and so be read back with the same data type; other numeric
+/// types, such as `xsd:long`, must be written in their quoted form.
+///
+fn is_shorthand_literal(literal: &Literal) -> bool {
+    fn is_digits(value: &str) -> bool {
+        !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+    }
+    let lexical_form = literal.lexical_form().as_str();
+    let unsigned = lexical_form
+        .strip_prefix(['+', '-'])
+        .unwrap_or(lexical_form);
+    match literal.data_type() {
+        Some(DataType::Boolean) => lexical_form == "true" || lexical_form == "false",
+        Some(DataType::Decimal) => unsigned.split_once('.').is_some_and(|(whole, fraction)| {
+            (whole.is_empty() || is_digits(whole)) && is_digits(fraction)
+        }),
+        Some(DataType::Double) => {
+            unsigned
+                .split_once(['e', 'E'])
+                .is_some_and(|(mantissa, exponent)| {
+                    let mantissa_ok = match mantissa.split_once('.') {
+                        Some((whole, "")) => is_digits(whole),
+                        Some(("", fraction)) => is_digits(fraction),
+                        Some((whole, fraction)) => is_digits(whole) && is_digits(fraction),
+                        None => is_digits(mantissa),
+                    };
+                    let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                    mantissa_ok && is_digits(exponent)
+                })
+        }
+        Some(DataType::Other(data_type)) => data_type == xsd::integer() && is_digits(unsigned),
+        _ => false,
+    }
+}
+
+///
+/// Replace any nested statements in `graph` by their reified form, as requested by the
+/// `reify_statements` option.
+///
+fn reify_graph(graph: &Graph) -> Result<Graph> {
+    let mut statements = Vec::default();
+    for statement in graph.statements() {
+        statements.extend(reify_statement(statement)?);
+    }
+    let mut reified_graph = Graph::from(statements);
+    reified_graph.set_prefix_mappings(graph.prefix_mappings().clone());
+    Ok(reified_graph)
+}
+
+///
+/// Collect the blank nodes in `statement`, and in any statement nested within it, which must be
+/// written by label as they cannot be nested in a quoted triple.
+///
+///
+/// Add all the blank nodes in `statement`, including those within quoted triples, to `blanks`.
+///
+pub(crate) fn statement_blank_nodes(statement: &Statement, blanks: &mut HashSet<BlankNode>) {
+    match statement.subject() {
+        SubjectNode::Blank(blank) => {
+            let _ = blanks.insert(blank.clone());
+        }
+        SubjectNode::Statement(statement) => statement_blank_nodes(statement, blanks),
+        SubjectNode::Resource(_) => {}
+    }
+    match statement.object() {
+        ObjectNode::Blank(blank) => {
+            let _ = blanks.insert(blank.clone());
+        }
+        ObjectNode::Statement(statement) => statement_blank_nodes(statement, blanks),
+        _ => {}
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
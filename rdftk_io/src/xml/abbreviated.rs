@@ -0,0 +1,546 @@
+/*!
+The abbreviated form of RDF/XML, as produced by ontology editors, using typed node elements,
+property attributes, nested blank nodes, `rdf:parseType="Resource"`, `rdf:parseType="Collection"`,
+and `xml:lang` inheritance.
+*/
+
+use super::split_name;
+use super::syntax::{
+    ATTRIBUTE_ABOUT, ATTRIBUTE_ABOUT_EACH, ATTRIBUTE_ABOUT_EACH_PREFIX, ATTRIBUTE_BAG_ID,
+    ATTRIBUTE_DATATYPE, ATTRIBUTE_ID, ATTRIBUTE_NODE_ID, ATTRIBUTE_PARSE_TYPE, ATTRIBUTE_RESOURCE,
+    ATTRIBUTE_XML_LANG_PREFIXED, ELEMENT_DESCRIPTION, ELEMENT_LI, ELEMENT_RDF,
+    PARSE_TYPE_COLLECTION, PARSE_TYPE_RESOURCE,
+};
+use crate::common::literal::unescape_lexical_form;
+use rdftk_core::error::{rdf_star_not_supported_error, Error};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::model::statement::{BlankNode, ObjectNode, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap};
+use rdftk_names::rdf;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+use std::str::FromStr;
+use xml::writer::{EventWriter, XmlEvent};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Writes the content of a graph, which must already have been simplified, in the abbreviated
+/// style.
+///
+#[derive(Debug)]
+pub(super) struct AbbreviatedWriter<'a> {
+    graph: &'a Graph,
+    /// Namespace IRI to prefix, for all namespaces declared on the `rdf:RDF` element.
+    prefixes: BTreeMap<String, String>,
+    /// The number of times each blank node is used as an object.
+    references: HashMap<&'a BlankNode, usize>,
+    blank_subjects: HashMap<&'a BlankNode, &'a SubjectNode>,
+    written: HashSet<&'a SubjectNode>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+type Attribute = (String, String);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> AbbreviatedWriter<'a> {
+    ///
+    /// Create a new writer, prefixes are taken from `mappings`, usually those of the original
+    /// graph, then from `defaults`, and otherwise generated.
+    ///
+    pub(super) fn new(
+        graph: &'a Graph,
+        mappings: &IriPrefixMap,
+        defaults: &HashMap<String, String>,
+    ) -> Result<Self, Error> {
+        let mut namespaces: Vec<String> = vec![rdf::namespace_str().to_string()];
+        for statement in graph.statements() {
+            namespaces.push(split_name(statement.predicate())?.0);
+            if statement.predicate() == rdf::a_type() {
+                if let Some((namespace, _)) = statement
+                    .object()
+                    .as_resource()
+                    .and_then(|iri| split_name(iri).ok())
+                {
+                    namespaces.push(namespace);
+                }
+            }
+        }
+
+        let mut prefixes: BTreeMap<String, String> = BTreeMap::default();
+        let mut used: HashSet<String> = HashSet::default();
+        let mut generated = 0;
+        for namespace in namespaces {
+            if prefixes.contains_key(&namespace) {
+                continue;
+            }
+            let prefix = Iri::from_str(&namespace)
+                .ok()
+                .and_then(|iri| mappings.get_prefix(&iri).map(|prefix| prefix.to_string()))
+                .map(|prefix| prefix.trim_end_matches(':').to_string())
+                .filter(|prefix| !prefix.is_empty())
+                .or_else(|| defaults.get(&namespace).cloned())
+                .filter(|prefix| !used.contains(prefix))
+                .unwrap_or_else(|| loop {
+                    let prefix = format!("ns{generated}");
+                    generated += 1;
+                    if !used.contains(&prefix) {
+                        break prefix;
+                    }
+                });
+            let _ = used.insert(prefix.clone());
+            let _ = prefixes.insert(namespace, prefix);
+        }
+
+        let mut references: HashMap<&'a BlankNode, usize> = HashMap::default();
+        for object in graph.statements().map(|statement| statement.object()) {
+            if let Some(blank) = object.as_blank() {
+                *references.entry(blank).or_default() += 1;
+            }
+        }
+
+        let blank_subjects = graph
+            .blank_node_subjects()
+            .into_iter()
+            .filter_map(|subject| subject.as_blank().map(|blank| (blank, subject)))
+            .collect();
+
+        Ok(Self {
+            graph,
+            prefixes,
+            references,
+            blank_subjects,
+            written: HashSet::default(),
+        })
+    }
+
+    pub(super) fn write<W: Write>(&mut self, writer: &mut EventWriter<W>) -> Result<(), Error> {
+        let container_name = self.qualified_name(rdf::namespace_str(), ELEMENT_RDF);
+        let mut element = XmlEvent::start_element(container_name.as_str());
+        for (namespace, prefix) in &self.prefixes {
+            element = element.ns(prefix.as_str(), namespace.as_str());
+        }
+        writer.write(element).map_err(xml_error)?;
+
+        let graph = self.graph;
+        let mut subjects: Vec<&'a SubjectNode> = graph.subjects().into_iter().collect();
+        subjects.sort_by_key(|subject| (subject.is_blank(), subject.to_string()));
+
+        // IRI subjects and those blank nodes that cannot be nested, followed by any blank
+        // nodes that are only referenced from within a cycle of nested blank nodes.
+        for subject in &subjects {
+            if !self.can_nest(subject) {
+                self.write_node_element(writer, subject, None, true)?;
+            }
+        }
+        for subject in &subjects {
+            if !self.written.contains(subject) {
+                self.write_node_element(writer, subject, None, true)?;
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element().name(container_name.as_str()))
+            .map_err(xml_error)?;
+        Ok(())
+    }
+
+    fn write_node_element<W: Write>(
+        &mut self,
+        writer: &mut EventWriter<W>,
+        subject: &'a SubjectNode,
+        language: Option<&LanguageTag>,
+        top_level: bool,
+    ) -> Result<(), Error> {
+        let _ = self.written.insert(subject);
+
+        // SPEC: §2.13 Typed Node Elements
+        let type_element = self
+            .sorted_objects(subject, rdf::a_type())
+            .into_iter()
+            .filter_map(|object| object.as_resource())
+            .find_map(|iri| {
+                split_name(iri)
+                    .ok()
+                    .filter(|(namespace, name)| !is_rdf_syntax_name(namespace, name))
+                    .map(|(namespace, name)| (iri, self.qualified_name(&namespace, &name)))
+            });
+        let element_name = match &type_element {
+            Some((_, name)) => name.clone(),
+            None => self.qualified_name(rdf::namespace_str(), ELEMENT_DESCRIPTION),
+        };
+
+        let mut attributes: Vec<Attribute> = Vec::default();
+        if let Some(iri) = subject.as_resource() {
+            attributes.push((
+                self.qualified_name(rdf::namespace_str(), ATTRIBUTE_ABOUT),
+                format!("{iri:#}"),
+            ));
+        } else if let Some(blank) = subject.as_blank() {
+            if top_level && self.references.contains_key(blank) {
+                attributes.push((
+                    self.qualified_name(rdf::namespace_str(), ATTRIBUTE_NODE_ID),
+                    blank.as_ref().to_string(),
+                ));
+            }
+        } else {
+            return rdf_star_not_supported_error(super::NAME).into();
+        }
+
+        let scope_language = self.scope_language(subject, language);
+        if scope_language.as_ref() != language {
+            attributes.push(language_attribute(scope_language.as_ref()));
+        }
+
+        // SPEC: §2.5 Property Attributes
+        let mut properties: Vec<(&'a Iri, &'a ObjectNode)> = Vec::default();
+        for predicate in self.sorted_predicates(subject) {
+            let objects = self.sorted_objects(subject, predicate);
+            if let [object] = objects.as_slice() {
+                let (namespace, name) = split_name(predicate)?;
+                if predicate != rdf::a_type()
+                    && !is_rdf_syntax_name(&namespace, &name)
+                    && is_attribute_literal(object, scope_language.as_ref())
+                {
+                    attributes.push((
+                        self.qualified_name(&namespace, &name),
+                        unescape_lexical_form(object.as_literal().unwrap().lexical_form()),
+                    ));
+                    continue;
+                }
+            }
+            for object in objects {
+                if let Some((type_iri, _)) = &type_element {
+                    if predicate == rdf::a_type() && object.as_resource() == Some(*type_iri) {
+                        continue;
+                    }
+                }
+                properties.push((predicate, object));
+            }
+        }
+
+        write_start_element(writer, &element_name, &attributes)?;
+        for (predicate, object) in properties {
+            self.write_property_element(writer, predicate, object, scope_language.as_ref())?;
+        }
+        writer
+            .write(XmlEvent::end_element().name(element_name.as_str()))
+            .map_err(xml_error)?;
+        Ok(())
+    }
+
+    fn write_property_element<W: Write>(
+        &mut self,
+        writer: &mut EventWriter<W>,
+        predicate: &'a Iri,
+        object: &'a ObjectNode,
+        language: Option<&LanguageTag>,
+    ) -> Result<(), Error> {
+        let (namespace, name) = split_name(predicate)?;
+        if is_rdf_syntax_name(&namespace, &name) {
+            return Err(Error::InvalidQName {
+                name: format!("{predicate:#}"),
+            });
+        }
+        let element_name = self.qualified_name(&namespace, &name);
+        let mut attributes: Vec<Attribute> = Vec::default();
+
+        if let Some(iri) = object.as_resource() {
+            attributes.push((
+                self.qualified_name(rdf::namespace_str(), ATTRIBUTE_RESOURCE),
+                format!("{iri:#}"),
+            ));
+            write_start_element(writer, &element_name, &attributes)?;
+        } else if let Some(literal) = object.as_literal() {
+            if let Some(data_type) = literal.data_type() {
+                // SPEC: §2.9 Typed Literals: rdf:datatype
+                attributes.push((
+                    self.qualified_name(rdf::namespace_str(), ATTRIBUTE_DATATYPE),
+                    format!("{:#}", data_type.as_iri()),
+                ));
+            } else if literal.language() != language {
+                // SPEC: §2.7 Languages: xml:lang
+                attributes.push(language_attribute(literal.language()));
+            }
+            write_start_element(writer, &element_name, &attributes)?;
+            writer
+                .write(XmlEvent::Characters(&unescape_lexical_form(
+                    literal.lexical_form(),
+                )))
+                .map_err(xml_error)?;
+        } else if let Some(blank) = object.as_blank() {
+            let node = self.subject_for(blank);
+            match node {
+                Some(node) if self.can_nest(node) && !self.written.contains(node) => {
+                    if let Some(members) = self.list_members(blank) {
+                        // SPEC: §2.16 Collections: rdf:parseType="Collection"
+                        attributes.push((
+                            self.qualified_name(rdf::namespace_str(), ATTRIBUTE_PARSE_TYPE),
+                            PARSE_TYPE_COLLECTION.to_string(),
+                        ));
+                        write_start_element(writer, &element_name, &attributes)?;
+                        for (list_node, member) in members {
+                            let _ = self.written.insert(list_node);
+                            self.write_member(writer, member, language)?;
+                        }
+                    } else if self.graph.objects_for(node, rdf::a_type()).is_empty() {
+                        // SPEC: §2.11 Omitting Blank Nodes: rdf:parseType="Resource"
+                        let _ = self.written.insert(node);
+                        attributes.push((
+                            self.qualified_name(rdf::namespace_str(), ATTRIBUTE_PARSE_TYPE),
+                            PARSE_TYPE_RESOURCE.to_string(),
+                        ));
+                        let scope_language = self.scope_language(node, language);
+                        if scope_language.as_ref() != language {
+                            attributes.push(language_attribute(scope_language.as_ref()));
+                        }
+                        write_start_element(writer, &element_name, &attributes)?;
+                        for predicate in self.sorted_predicates(node) {
+                            for object in self.sorted_objects(node, predicate) {
+                                self.write_property_element(
+                                    writer,
+                                    predicate,
+                                    object,
+                                    scope_language.as_ref(),
+                                )?;
+                            }
+                        }
+                    } else {
+                        write_start_element(writer, &element_name, &attributes)?;
+                        self.write_node_element(writer, node, language, false)?;
+                    }
+                }
+                _ => {
+                    attributes.push((
+                        self.qualified_name(rdf::namespace_str(), ATTRIBUTE_NODE_ID),
+                        blank.as_ref().to_string(),
+                    ));
+                    write_start_element(writer, &element_name, &attributes)?;
+                }
+            }
+        } else {
+            return rdf_star_not_supported_error(super::NAME).into();
+        }
+
+        writer
+            .write(XmlEvent::end_element().name(element_name.as_str()))
+            .map_err(xml_error)?;
+        Ok(())
+    }
+
+    fn write_member<W: Write>(
+        &mut self,
+        writer: &mut EventWriter<W>,
+        member: &'a ObjectNode,
+        language: Option<&LanguageTag>,
+    ) -> Result<(), Error> {
+        let description = self.qualified_name(rdf::namespace_str(), ELEMENT_DESCRIPTION);
+        let attribute = if let Some(iri) = member.as_resource() {
+            (
+                self.qualified_name(rdf::namespace_str(), ATTRIBUTE_ABOUT),
+                format!("{iri:#}"),
+            )
+        } else if let Some(blank) = member.as_blank() {
+            match self.subject_for(blank) {
+                Some(node) if self.can_nest(node) && !self.written.contains(node) => {
+                    return self.write_node_element(writer, node, language, false);
+                }
+                _ => (
+                    self.qualified_name(rdf::namespace_str(), ATTRIBUTE_NODE_ID),
+                    blank.as_ref().to_string(),
+                ),
+            }
+        } else {
+            return rdf_star_not_supported_error(super::NAME).into();
+        };
+        write_start_element(writer, &description, &[attribute])?;
+        writer
+            .write(XmlEvent::end_element().name(description.as_str()))
+            .map_err(xml_error)?;
+        Ok(())
+    }
+
+    ///
+    /// A blank node may be nested within the property element that references it if it is
+    /// referenced exactly once.
+    ///
+    fn can_nest(&self, subject: &SubjectNode) -> bool {
+        subject
+            .as_blank()
+            .map(|blank| self.references.get(blank) == Some(&1))
+            .unwrap_or_default()
+    }
+
+    fn subject_for(&self, blank: &BlankNode) -> Option<&'a SubjectNode> {
+        self.blank_subjects.get(blank).copied()
+    }
+
+    ///
+    /// Returns the list nodes and members if `blank` is the head of a well-formed list where
+    /// each node has only `rdf:first` and `rdf:rest`, is referenced once, and no member is a
+    /// literal.
+    ///
+    fn list_members(&self, blank: &BlankNode) -> Option<Vec<(&'a SubjectNode, &'a ObjectNode)>> {
+        let mut members = Vec::default();
+        let mut visited: HashSet<&BlankNode> = HashSet::default();
+        let mut current = blank;
+        loop {
+            if !visited.insert(current) || self.references.get(current) != Some(&1) {
+                return None;
+            }
+            let node = self.subject_for(current)?;
+            let predicates = self.graph.predicates_for(node);
+            if predicates.len() != 2 {
+                return None;
+            }
+            let first = single(self.graph.objects_for(node, rdf::first()))?;
+            let rest = single(self.graph.objects_for(node, rdf::rest()))?;
+            if first.is_literal() || self.written.contains(node) {
+                return None;
+            }
+            members.push((node, first));
+            if rest.as_resource() == Some(rdf::nil()) {
+                return Some(members);
+            }
+            current = rest.as_blank()?;
+        }
+    }
+
+    ///
+    /// Choose the language for a node element, this is the most common language of its plain
+    /// literals, preferring the inherited language.
+    ///
+    fn scope_language(
+        &self,
+        subject: &SubjectNode,
+        inherited: Option<&LanguageTag>,
+    ) -> Option<LanguageTag> {
+        let mut counts: HashMap<Option<&LanguageTag>, usize> = HashMap::default();
+        for predicate in self.graph.predicates_for(subject) {
+            for object in self.graph.objects_for(subject, predicate) {
+                if let Some(literal) = object.as_literal() {
+                    if literal.data_type().is_none() {
+                        *counts.entry(literal.language()).or_default() += 1;
+                    }
+                }
+            }
+        }
+        let mut counts: Vec<(Option<&LanguageTag>, usize)> = counts.into_iter().collect();
+        counts.sort_by_key(|(language, count)| {
+            (
+                std::cmp::Reverse(*count),
+                *language != inherited,
+                language.map(|language| language.to_string()),
+            )
+        });
+        counts
+            .first()
+            .map(|(language, _)| *language)
+            .unwrap_or(inherited)
+            .cloned()
+    }
+
+    fn sorted_predicates(&self, subject: &SubjectNode) -> Vec<&'a Iri> {
+        let mut predicates: Vec<&'a Iri> = self.graph.predicates_for(subject).into_iter().collect();
+        predicates.sort_by_key(|predicate| predicate.to_string());
+        predicates
+    }
+
+    fn sorted_objects(&self, subject: &SubjectNode, predicate: &Iri) -> Vec<&'a ObjectNode> {
+        let mut objects: Vec<&'a ObjectNode> = self
+            .graph
+            .objects_for(subject, predicate)
+            .into_iter()
+            .collect();
+        objects.sort_by_key(|object| object.to_string());
+        objects
+    }
+
+    fn qualified_name(&self, namespace: &str, name: &str) -> String {
+        format!("{}:{}", self.prefixes[namespace], name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn xml_error(e: xml::writer::Error) -> Error {
+    Error::Tokenizer {
+        representation: super::NAME.into(),
+        source: Box::new(e),
+    }
+}
+
+fn write_start_element<W: Write>(
+    writer: &mut EventWriter<W>,
+    name: &str,
+    attributes: &[Attribute],
+) -> Result<(), Error> {
+    let mut element = XmlEvent::start_element(name);
+    for (name, value) in attributes {
+        element = element.attr(name.as_str(), value);
+    }
+    writer.write(element).map_err(xml_error)
+}
+
+fn language_attribute(language: Option<&LanguageTag>) -> Attribute {
+    (
+        ATTRIBUTE_XML_LANG_PREFIXED.to_string(),
+        language
+            .map(|language| language.to_string())
+            .unwrap_or_default(),
+    )
+}
+
+///
+/// Only plain literals in the current language, and that have no white space that would be
+/// normalized by an XML parser, are written as property attributes.
+///
+fn is_attribute_literal(object: &ObjectNode, language: Option<&LanguageTag>) -> bool {
+    object
+        .as_literal()
+        .map(|literal| {
+            literal.data_type().is_none()
+                && literal.language() == language
+                && !unescape_lexical_form(literal.lexical_form()).contains(['\n', '\r', '\t'])
+        })
+        .unwrap_or_default()
+}
+
+fn single<T>(values: HashSet<T>) -> Option<T> {
+    if values.len() == 1 {
+        values.into_iter().next()
+    } else {
+        None
+    }
+}
+
+fn is_rdf_syntax_name(namespace: &str, name: &str) -> bool {
+    namespace == rdf::namespace_str()
+        && [
+            ELEMENT_RDF,
+            ATTRIBUTE_ID,
+            ATTRIBUTE_ABOUT,
+            ATTRIBUTE_PARSE_TYPE,
+            ATTRIBUTE_RESOURCE,
+            ATTRIBUTE_NODE_ID,
+            ATTRIBUTE_DATATYPE,
+            ATTRIBUTE_ABOUT_EACH,
+            ATTRIBUTE_ABOUT_EACH_PREFIX,
+            ATTRIBUTE_BAG_ID,
+            ELEMENT_DESCRIPTION,
+            ELEMENT_LI,
+        ]
+        .contains(&name)
+}
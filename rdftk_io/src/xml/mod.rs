@@ -1,12 +1,45 @@
 /*!
-Provides for writing out in the [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)
-format.
+Provides for reading and writing in the
+[RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/) format.
+
+# Example Reader
+
+The reader implements the complete grammar, including collections, container membership
+elements, and reification. Relative IRIs are resolved against any `xml:base` in the document,
+which is itself resolved against the base IRI provided in the options.
+
+```rust
+use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::ReaderOptions;
+use rdftk_io::xml::XmlReader;
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+let reader = XmlReader::default().with_options(
+    ReaderOptions::default().with_base(Iri::from_str("http://example.org/basket.rdf").unwrap()),
+);
+
+let graph: Graph = reader.read_from_string(r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/stuff/1.0/">
+  <rdf:Description rdf:ID="basket">
+    <ex:hasFruit rdf:parseType="Collection">
+      <rdf:Description rdf:about="banana"/>
+      <rdf:Description rdf:about="apple"/>
+    </ex:hasFruit>
+  </rdf:Description>
+</rdf:RDF>"#).unwrap();
+
+assert_eq!(graph.len(), 5);
+```
 
 # Example Writer
 
 This writer has a number of options, it can be written in a plain, streaming, form or alternatively
 pretty-printed  for readability. It is also possible to pick one of the type styles described
-in the specification, "flat" or "striped".
+in the specification, "flat" or "striped", or the "abbreviated" style which uses typed node
+elements, property attributes, and nested blank nodes to produce a human-editable document.
 
 ```rust
 use rdftk_io::xml::{XmlOptions, XmlWriter};
@@ -23,6 +56,9 @@ println!("{}", writer.write_to_string(&make_graph()).unwrap());
 
 */
 
+use rdftk_core::error::Error;
+use rdftk_iri::Iri;
+
 // ------------------------------------------------------------------------------------------------
 // Public Values
 // ------------------------------------------------------------------------------------------------
@@ -36,6 +72,37 @@ pub const FILE_EXTENSION: &str = "rdf";
 /// The MIME type used for this serialization format.
 pub const MIME_TYPE: &str = "application/rdf+xml";
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Split an IRI into a namespace and a local name which is a valid XML name, as required for
+/// element and attribute names.
+///
+fn split_name(iri: &Iri) -> Result<(String, String), Error> {
+    let iri = format!("{iri:#}");
+    let mut start = iri.len();
+    for (index, c) in iri.char_indices().rev() {
+        if c.is_alphanumeric() || matches!(c, '_' | '-' | '.') {
+            start = index;
+        } else {
+            break;
+        }
+    }
+    while let Some(c) = iri[start..].chars().next() {
+        if c.is_alphabetic() || c == '_' {
+            break;
+        }
+        start += c.len_utf8();
+    }
+    if start == iri.len() {
+        Err(Error::InvalidQName { name: iri })
+    } else {
+        Ok((iri[..start].to_string(), iri[start..].to_string()))
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -45,5 +112,7 @@ mod syntax;
 mod reader;
 pub use reader::XmlReader;
 
+mod abbreviated;
+
 mod writer;
-pub use writer::{XmlOptions, XmlStyle, XmlWriter};
+pub use writer::{XmlOptions, XmlSink, XmlStyle, XmlWriter};
@@ -1,22 +1,27 @@
+use crate::common::relabel::DocumentLabels;
 use crate::xml::syntax::{
-    ATTRIBUTE_ABOUT, ATTRIBUTE_ID, ATTRIBUTE_NODE_ID, ATTRIBUTE_PARSE_TYPE, ATTRIBUTE_RESOURCE,
-    ATTRIBUTE_XML_BASE, ATTRIBUTE_XML_LANG, ELEMENT_DESCRIPTION, ELEMENT_RDF,
-    PARSE_TYPE_COLLECTION, PARSE_TYPE_LITERAL, PARSE_TYPE_RESOURCE, XML_NAMESPACE,
+    ATTRIBUTE_ABOUT, ATTRIBUTE_ABOUT_EACH, ATTRIBUTE_ABOUT_EACH_PREFIX, ATTRIBUTE_BAG_ID,
+    ATTRIBUTE_DATATYPE, ATTRIBUTE_ID, ATTRIBUTE_NODE_ID, ATTRIBUTE_PARSE_TYPE, ATTRIBUTE_RESOURCE,
+    ATTRIBUTE_TYPE, ATTRIBUTE_XML_BASE, ATTRIBUTE_XML_LANG, ELEMENT_DESCRIPTION, ELEMENT_LI,
+    ELEMENT_RDF, PARSE_TYPE_COLLECTION, PARSE_TYPE_RESOURCE, XML_NAMESPACE, XML_PREFIX,
 };
-use objio::ObjectReader;
-use rdftk_core::error::{invalid_state_error, Error};
+use crate::ReaderOptions;
+use objio::{impl_has_options, HasOptions, ObjectReader};
+use rdftk_core::error::{Error, SourceLocation};
 use rdftk_core::model::graph::Graph;
 use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
-use rdftk_core::model::statement::{BlankNode, Statement, SubjectNode};
-use rdftk_iri::Iri;
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, IriPrefixMap, Namespace};
 use rdftk_names::rdf;
+use std::collections::{BTreeMap, HashSet};
 use std::io::Read;
 use std::str::FromStr;
-use tracing::{error, trace};
+use tracing::trace;
 use xml::attribute::OwnedAttribute;
+use xml::common::{Position, TextPosition};
 use xml::name::OwnedName;
 use xml::reader::XmlEvent;
-use xml::{EventReader, EventWriter};
+use xml::ParserConfig;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -26,47 +31,69 @@ use xml::{EventReader, EventWriter};
 /// An implementation of the GraphReader trait to read resources in the XML representation.
 ///
 #[derive(Clone, Debug, Default)]
-pub struct XmlReader {}
+pub struct XmlReader {
+    options: ReaderOptions,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// The document is read into this simple tree form first as the grammar requires look-ahead
+/// to distinguish the different forms of property element.
+///
 #[derive(Clone, Debug)]
-struct ExpectedName {
-    local_name: String,
-    namespace: String,
+struct Element {
+    name: OwnedName,
+    position: TextPosition,
+    attributes: Vec<OwnedAttribute>,
+    children: Vec<Node>,
 }
 
 #[derive(Clone, Debug)]
-enum SubjectType {
-    BlankNamed(String),
-    Resource(Iri),
-    RelativeResource(String),
+enum Node {
+    Element(Element),
+    Text(String),
 }
 
-#[derive(Clone, Debug)]
-enum ParseType {
-    XmlLiteral,
-    Resource,
-    Collection,
+///
+/// The in-scope values of `xml:base` and `xml:lang` for an element.
+///
+#[derive(Clone, Debug, Default)]
+struct Scope {
+    base: Option<url::Url>,
+    language: Option<LanguageTag>,
 }
 
-#[derive(Clone, Debug)]
-struct Attributes<'a> {
-    subject_type: Option<SubjectType>,
-    parse_type: Option<ParseType>,
-    uri_base: Option<Iri>,
-    data_type: Option<Iri>,
-    language: Option<LanguageTag>,
-    resource: Option<Iri>,
-    inner: Vec<&'a OwnedAttribute>,
+///
+/// The syntax attributes of an element, with any remaining property attributes.
+///
+#[derive(Clone, Debug, Default)]
+struct Attributes {
+    id: Option<String>,
+    node_id: Option<String>,
+    about: Option<String>,
+    resource: Option<String>,
+    data_type: Option<String>,
+    parse_type: Option<String>,
+    properties: Vec<(Iri, String)>,
+}
+
+#[derive(Debug, Default)]
+struct Parser {
+    source: Vec<u8>,
+    graph: Graph,
+    blank_nodes: DocumentLabels,
+    ids: HashSet<Iri>,
 }
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_has_options!(XmlReader, ReaderOptions);
+
 impl ObjectReader<Graph> for XmlReader {
     type Error = Error;
 
@@ -74,463 +101,826 @@ impl ObjectReader<Graph> for XmlReader {
     where
         R: Read,
     {
-        let mut event_reader = EventReader::new(r);
-        parse_document(&mut event_reader)
+        let mut source = Vec::default();
+        r.read_to_end(&mut source)?;
+        let (root, mappings) = read_element_tree(&source)?;
+        let scope = Scope {
+            base: self
+                .options
+                .base()
+                .map(|base| AsRef::<url::Url>::as_ref(base).clone()),
+            language: None,
+        };
+        let mut parser = Parser {
+            source,
+            blank_nodes: DocumentLabels::new(self.options.blank_nodes()),
+            ..Default::default()
+        };
+        parser
+            .document(&root, &scope)
+            .map_err(|e| parser.locate(e, &root))?;
+        if self.options.carry_prefixes() {
+            parser.graph.set_prefix_mappings(mappings);
+        }
+        Ok(parser.graph)
+    }
+}
+
+impl XmlReader {
+    pub fn with_options(self, options: ReaderOptions) -> Self {
+        let mut self_mut = self;
+        self_mut.set_options(options);
+        self_mut
     }
 }
 
 // ------------------------------------------------------------------------------------------------
 
-impl ExpectedName {
-    pub(crate) fn new(local_name: &str, namespace: &str) -> Self {
-        Self {
-            local_name: local_name.to_string(),
-            namespace: namespace.to_string(),
+impl Element {
+    fn is_rdf(&self, local_name: &str) -> bool {
+        is_rdf_name(&self.name, local_name)
+    }
+
+    fn iri(&self) -> Result<Iri, Error> {
+        match &self.name.namespace {
+            Some(namespace) => Ok(Iri::from_str(&format!(
+                "{}{}",
+                namespace, self.name.local_name
+            ))?),
+            None => Err(syntax_error(
+                "element",
+                format!("element {} is not in a namespace", self.name.local_name),
+            )),
         }
     }
 
-    pub(crate) fn matches(&self, other: &OwnedName) -> bool {
-        self.local_name == other.local_name && Some(self.namespace.clone()) == other.namespace
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Element(_) => None,
+                Node::Text(text) => Some(text.as_str()),
+            })
+            .collect()
+    }
+
+    ///
+    /// Return the child elements of an element that may only contain elements and white space.
+    ///
+    fn element_content(&self) -> Result<Vec<&Element>, Error> {
+        if !self.text().trim().is_empty() {
+            return Err(syntax_error(
+                "element_content",
+                format!(
+                    "element {} may not contain text content",
+                    self.name.local_name
+                ),
+            ));
+        }
+        Ok(self.elements().collect())
     }
 }
 
 // ------------------------------------------------------------------------------------------------
-// Private Functions
+
+impl Scope {
+    ///
+    /// Return the scope for `element`, within this, the scope of its parent.
+    ///
+    fn enter(&self, element: &Element) -> Result<Self, Error> {
+        let mut scope = self.clone();
+        for attribute in &element.attributes {
+            if attribute.name.namespace.as_deref() == Some(XML_NAMESPACE) {
+                if attribute.name.local_name == ATTRIBUTE_XML_BASE {
+                    // SPEC: §2.14 Abbreviating URIs: rdf:ID and xml:base
+                    let base = match &scope.base {
+                        Some(base) => base.join(&attribute.value)?,
+                        None => url::Url::parse(&attribute.value)?,
+                    };
+                    scope.base = Some(base);
+                } else if attribute.name.local_name == ATTRIBUTE_XML_LANG {
+                    // SPEC: §2.7 Languages: xml:lang
+                    scope.language = if attribute.value.is_empty() {
+                        None
+                    } else {
+                        Some(LanguageTag::from_str(&attribute.value)?)
+                    };
+                }
+            }
+        }
+        Ok(scope)
+    }
+
+    fn resolve(&self, iri: &str) -> Result<Iri, Error> {
+        match &self.base {
+            Some(base) => Ok(Iri::from(base.join(iri)?)),
+            None => Ok(Iri::from_str(iri)?),
+        }
+    }
+
+    fn literal(&self, value: &str) -> Literal {
+        match &self.language {
+            Some(language) => Literal::with_language(value, language.clone()),
+            None => Literal::plain(value),
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 
-macro_rules! trace_event {
-    ($fn_name:expr => $event:expr) => {
-        trace!("XmlReader::{} event: {:?}", $fn_name, $event);
-    };
-    ($fn_name:expr => ignore $event:expr) => {
-        trace!("XmlReader::{} ignoring event: {:?}", $fn_name, &$event);
-    };
+impl Attributes {
+    fn from_element(element: &Element) -> Result<Self, Error> {
+        let mut attributes = Self::default();
+        for attribute in &element.attributes {
+            let name = &attribute.name;
+            let value = attribute.value.clone();
+            if name.namespace.as_deref() == Some(XML_NAMESPACE) || is_reserved_xml_name(name) {
+                // xml:base and xml:lang are handled by Scope, all other names starting with
+                // "xml" are reserved and are ignored.
+                continue;
+            }
+            let local_name = match &name.namespace {
+                Some(namespace) if namespace == rdf::namespace_str() => {
+                    Some(name.local_name.as_str())
+                }
+                None if [
+                    ATTRIBUTE_ABOUT,
+                    ATTRIBUTE_ID,
+                    ATTRIBUTE_PARSE_TYPE,
+                    ATTRIBUTE_RESOURCE,
+                    ATTRIBUTE_TYPE,
+                ]
+                .contains(&name.local_name.as_str()) =>
+                {
+                    // SPEC: §6.1.4 unqualified attribute names are treated as RDF names.
+                    Some(name.local_name.as_str())
+                }
+                None => {
+                    return Err(syntax_error(
+                        "attributes",
+                        format!("attribute {} is not in a namespace", name.local_name),
+                    ));
+                }
+                Some(_) => None,
+            };
+            match local_name {
+                Some(ATTRIBUTE_ID) => attributes.id = Some(value),
+                Some(ATTRIBUTE_NODE_ID) => attributes.node_id = Some(value),
+                Some(ATTRIBUTE_ABOUT) => attributes.about = Some(value),
+                Some(ATTRIBUTE_RESOURCE) => attributes.resource = Some(value),
+                Some(ATTRIBUTE_DATATYPE) => attributes.data_type = Some(value),
+                Some(ATTRIBUTE_PARSE_TYPE) => attributes.parse_type = Some(value),
+                Some(
+                    ATTRIBUTE_ABOUT_EACH
+                    | ATTRIBUTE_ABOUT_EACH_PREFIX
+                    | ATTRIBUTE_BAG_ID
+                    | ELEMENT_LI
+                    | ELEMENT_DESCRIPTION
+                    | ELEMENT_RDF,
+                ) => {
+                    return Err(syntax_error(
+                        "attributes",
+                        format!("rdf:{} is not allowed as an attribute", name.local_name),
+                    ));
+                }
+                Some(local_name) => attributes.properties.push((
+                    Iri::from_str(&format!("{}{}", rdf::namespace_str(), local_name))?,
+                    value,
+                )),
+                None => attributes.properties.push((
+                    Iri::from_str(&format!(
+                        "{}{}",
+                        name.namespace.as_ref().unwrap(),
+                        name.local_name
+                    ))?,
+                    value,
+                )),
+            }
+        }
+        Ok(attributes)
+    }
+
+    fn has_object_attributes(&self) -> bool {
+        self.resource.is_some() || self.node_id.is_some() || !self.properties.is_empty()
+    }
 }
 
-macro_rules! error_event {
-    (parse => $fn_name:expr, $error:expr) => {
-        let inner = Error::Tokenizer {
-            representation: super::NAME.into(),
-            source: Box::new($error.clone()),
+// ------------------------------------------------------------------------------------------------
+
+impl Parser {
+    ///
+    /// Attach the position of `element` to `e`, unless it already has a location.
+    ///
+    fn locate(&self, e: Error, element: &Element) -> Error {
+        if e.location().is_some() {
+            e
+        } else {
+            let location = text_location(&self.source, element.position);
+            e.with_location(super::NAME, location)
+        }
+    }
+
+    fn document(&mut self, root: &Element, scope: &Scope) -> Result<(), Error> {
+        if root.is_rdf(ELEMENT_RDF) {
+            // SPEC: §2.6 Completing the Document: Document Element and XML Declaration
+            let scope = scope.enter(root)?;
+            for element in root.element_content()? {
+                let _ = self.node_element(element, &scope)?;
+            }
+        } else {
+            // SPEC: §7.2.8 Grammar production doc, the rdf:RDF element may be omitted
+            let _ = self.node_element(root, scope)?;
+        }
+        Ok(())
+    }
+
+    fn node_element(&mut self, element: &Element, scope: &Scope) -> Result<SubjectNode, Error> {
+        self.parse_node_element(element, scope)
+            .map_err(|e| self.locate(e, element))
+    }
+
+    fn parse_node_element(
+        &mut self,
+        element: &Element,
+        scope: &Scope,
+    ) -> Result<SubjectNode, Error> {
+        trace!("XmlReader::node_element {:?}", element.name);
+        if is_rdf_syntax_name(&element.name, &[ELEMENT_LI]) {
+            return Err(syntax_error(
+                "node_element",
+                format!(
+                    "rdf:{} is not allowed as a node element",
+                    element.name.local_name
+                ),
+            ));
+        }
+        let scope = scope.enter(element)?;
+        let attributes = Attributes::from_element(element)?;
+        if attributes.resource.is_some()
+            || attributes.data_type.is_some()
+            || attributes.parse_type.is_some()
+        {
+            return Err(syntax_error(
+                "node_element",
+                "rdf:resource, rdf:datatype, and rdf:parseType are not allowed on a node element",
+            ));
+        }
+
+        let subject: SubjectNode = match (&attributes.id, &attributes.node_id, &attributes.about) {
+            // SPEC: §2.14 Abbreviating URIs: rdf:ID and xml:base
+            (Some(id), None, None) => self.id(id, &scope)?.into(),
+            // SPEC: §2.10 Identifying Blank Nodes: rdf:nodeID
+            (None, Some(node_id), None) => self.blank_node(node_id)?.into(),
+            // SPEC: §2.2 Node Elements and Property Elements
+            (None, None, Some(about)) => scope.resolve(about)?.into(),
+            // SPEC: §2.1 Introduction
+            (None, None, None) => BlankNode::generate().into(),
+            _ => {
+                return Err(syntax_error(
+                    "node_element",
+                    "only one of rdf:ID, rdf:nodeID, and rdf:about is allowed",
+                ));
+            }
         };
-        error_event!($fn_name, inner);
-    };
-    (state => $fn_name:expr, $msg:expr) => {
-        error!("Invalid state: {}", $msg,);
-        let inner = invalid_state_error();
-        error_event!($fn_name, inner);
-    };
-    ($fn_name:expr, $error:expr) => {
-        error!("XmlReader::{} {}", $fn_name, $error);
-        return Error::Tokenizer {
-            representation: super::NAME.into(),
-            source: Box::new($error),
+
+        if !element.is_rdf(ELEMENT_DESCRIPTION) {
+            // SPEC: §2.13 Typed Node Elements
+            self.graph.insert(Statement::new(
+                subject.clone(),
+                rdf::a_type().clone(),
+                element.iri()?,
+            ));
         }
-        .into();
-    };
-}
 
-fn parse_document<R: Read>(event_reader: &mut EventReader<&mut R>) -> Result<Graph, Error> {
-    let mut graph = Graph::default();
-    let rdf_element = ExpectedName::new(ELEMENT_RDF, rdf::namespace_str());
+        // SPEC: §2.5 Property Attributes
+        self.property_attributes(&subject, &attributes.properties, &scope)?;
 
-    loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::EndDocument) => {
-                trace_event!("parse_document" => event);
-                return Ok(graph);
+        self.property_elements(&subject, element, &scope)?;
+
+        Ok(subject)
+    }
+
+    fn property_elements(
+        &mut self,
+        subject: &SubjectNode,
+        element: &Element,
+        scope: &Scope,
+    ) -> Result<(), Error> {
+        let mut list_index = 0;
+        for property in element.element_content()? {
+            self.property_element(subject, property, scope, &mut list_index)?;
+        }
+        Ok(())
+    }
+
+    fn property_element(
+        &mut self,
+        subject: &SubjectNode,
+        element: &Element,
+        scope: &Scope,
+        list_index: &mut usize,
+    ) -> Result<(), Error> {
+        self.parse_property_element(subject, element, scope, list_index)
+            .map_err(|e| self.locate(e, element))
+    }
+
+    fn parse_property_element(
+        &mut self,
+        subject: &SubjectNode,
+        element: &Element,
+        scope: &Scope,
+        list_index: &mut usize,
+    ) -> Result<(), Error> {
+        trace!("XmlReader::property_element {:?}", element.name);
+        if is_rdf_syntax_name(&element.name, &[ELEMENT_DESCRIPTION]) {
+            return Err(syntax_error(
+                "property_element",
+                format!(
+                    "rdf:{} is not allowed as a property element",
+                    element.name.local_name
+                ),
+            ));
+        }
+        let predicate = if element.is_rdf(ELEMENT_LI) {
+            // SPEC: §2.15 Container Membership Property Elements: rdf:li and rdf:_n
+            *list_index += 1;
+            rdf::member(*list_index)
+        } else {
+            element.iri()?
+        };
+        let scope = scope.enter(element)?;
+        let attributes = Attributes::from_element(element)?;
+        if attributes.about.is_some() {
+            return Err(syntax_error(
+                "property_element",
+                "rdf:about is not allowed on a property element",
+            ));
+        }
+
+        let object: ObjectNode = if let Some(parse_type) = &attributes.parse_type {
+            if attributes.has_object_attributes() || attributes.data_type.is_some() {
+                return Err(syntax_error(
+                    "property_element",
+                    "only rdf:ID is allowed with rdf:parseType",
+                ));
             }
-            Ok(XmlEvent::StartElement {
-                name,
-                namespace: _,
-                attributes,
-            }) => {
-                trace_event!("parse_document" => event);
-                let attributes = parse_attributes(attributes)?;
-                if rdf_element.matches(name) {
-                    let _ = parse_subject_element(
-                        event_reader,
-                        &attributes.uri_base,
-                        None,
-                        &mut graph,
-                    )?;
-                } else {
-                    error_event!(state => "parse_document", "expecting rdf:RDF");
+            match parse_type.as_str() {
+                PARSE_TYPE_RESOURCE => {
+                    // SPEC: §2.11 Omitting Blank Nodes: rdf:parseType="Resource"
+                    let object: SubjectNode = BlankNode::generate().into();
+                    self.property_elements(&object, element, &scope)?;
+                    object.to_object()
+                }
+                PARSE_TYPE_COLLECTION => {
+                    // SPEC: §2.16 Collections: rdf:parseType="Collection"
+                    let members = element
+                        .element_content()?
+                        .into_iter()
+                        .map(|member| self.node_element(member, &scope))
+                        .collect::<Result<Vec<SubjectNode>, Error>>()?;
+                    self.collection(members)
+                }
+                _ => {
+                    // SPEC: §2.8 XML Literals: rdf:parseType="Literal", any other value is
+                    // treated in the same way.
+                    Literal::with_data_type(canonical_xml(&element.children), DataType::XmlLiteral)
+                        .into()
                 }
             }
-            Ok(_) => {
-                trace_event!("parse_document" => ignore event);
+        } else {
+            let text = element.text();
+            let children: Vec<&Element> = element.elements().collect();
+            if !children.is_empty() {
+                if !text.trim().is_empty() {
+                    return Err(syntax_error(
+                        "property_element",
+                        "a property element may not contain both text and elements",
+                    ));
+                }
+                if children.len() > 1 {
+                    return Err(syntax_error(
+                        "property_element",
+                        "a property element may only contain a single node element",
+                    ));
+                }
+                if attributes.has_object_attributes() || attributes.data_type.is_some() {
+                    return Err(syntax_error(
+                        "property_element",
+                        "only rdf:ID is allowed on a property element with a node element",
+                    ));
+                }
+                self.node_element(children[0], &scope)?.to_object()
+            } else if !text.is_empty()
+                && (!text.trim().is_empty() || !attributes.has_object_attributes())
+            {
+                if attributes.has_object_attributes() {
+                    return Err(syntax_error(
+                        "property_element",
+                        "only rdf:ID and rdf:datatype are allowed on a literal property element",
+                    ));
+                }
+                self.literal(&text, &attributes, &scope)?.into()
+            } else if !attributes.has_object_attributes() {
+                // SPEC: §2.4 Empty Property Elements
+                self.literal("", &attributes, &scope)?.into()
+            } else {
+                // SPEC: §2.12 Omitting Nodes: Property Attributes on an empty Property Element
+                if attributes.data_type.is_some() {
+                    return Err(syntax_error(
+                        "property_element",
+                        "rdf:datatype is not allowed on an empty property element with a resource",
+                    ));
+                }
+                let object: SubjectNode = match (&attributes.resource, &attributes.node_id) {
+                    (Some(resource), None) => scope.resolve(resource)?.into(),
+                    (None, Some(node_id)) => self.blank_node(node_id)?.into(),
+                    (None, None) => BlankNode::generate().into(),
+                    (Some(_), Some(_)) => {
+                        return Err(syntax_error(
+                            "property_element",
+                            "only one of rdf:resource and rdf:nodeID is allowed",
+                        ));
+                    }
+                };
+                self.property_attributes(&object, &attributes.properties, &scope)?;
+                object.to_object()
             }
-            Err(e) => {
-                error_event!(parse => "parse_document", e);
+        };
+
+        self.graph.insert(Statement::new(
+            subject.clone(),
+            predicate.clone(),
+            object.clone(),
+        ));
+
+        if let Some(id) = &attributes.id {
+            // SPEC: §2.17 Reifying Statements: rdf:ID
+            let statement = self.id(id, &scope)?;
+            self.reify(statement, subject, predicate, object);
+        }
+
+        Ok(())
+    }
+
+    fn property_attributes(
+        &mut self,
+        subject: &SubjectNode,
+        properties: &[(Iri, String)],
+        scope: &Scope,
+    ) -> Result<(), Error> {
+        for (predicate, value) in properties {
+            let object: ObjectNode = if predicate == rdf::a_type() {
+                scope.resolve(value)?.into()
+            } else {
+                scope.literal(value).into()
+            };
+            self.graph
+                .insert(Statement::new(subject.clone(), predicate.clone(), object));
+        }
+        Ok(())
+    }
+
+    fn literal(
+        &self,
+        value: &str,
+        attributes: &Attributes,
+        scope: &Scope,
+    ) -> Result<Literal, Error> {
+        Ok(match &attributes.data_type {
+            // SPEC: §2.9 Typed Literals: rdf:datatype
+            Some(data_type) => {
+                Literal::with_data_type(value, DataType::from(scope.resolve(data_type)?))
             }
+            None => scope.literal(value),
+        })
+    }
+
+    fn collection(&mut self, members: Vec<SubjectNode>) -> ObjectNode {
+        let mut rest: ObjectNode = rdf::nil().clone().into();
+        for member in members.into_iter().rev() {
+            let node = BlankNode::generate();
+            self.graph.insert(Statement::new(
+                node.clone(),
+                rdf::first().clone(),
+                member.to_object(),
+            ));
+            self.graph
+                .insert(Statement::new(node.clone(), rdf::rest().clone(), rest));
+            rest = node.into();
         }
+        rest
+    }
+
+    fn reify(&mut self, statement: Iri, subject: &SubjectNode, predicate: Iri, object: ObjectNode) {
+        self.graph.insert(Statement::new(
+            statement.clone(),
+            rdf::a_type().clone(),
+            rdf::statement().clone(),
+        ));
+        self.graph.insert(Statement::new(
+            statement.clone(),
+            rdf::subject().clone(),
+            subject.to_object(),
+        ));
+        self.graph.insert(Statement::new(
+            statement.clone(),
+            rdf::predicate().clone(),
+            predicate,
+        ));
+        self.graph
+            .insert(Statement::new(statement, rdf::object().clone(), object));
+    }
+
+    ///
+    /// Resolve the value of an `rdf:ID` attribute, these must be unique within a document for
+    /// the same base IRI.
+    ///
+    fn id(&mut self, id: &str, scope: &Scope) -> Result<Iri, Error> {
+        if !is_nc_name(id) {
+            return Err(syntax_error(
+                "id",
+                format!("rdf:ID value {id:?} is not a valid XML name"),
+            ));
+        }
+        let iri = scope.resolve(&format!("#{id}"))?;
+        if !self.ids.insert(iri.clone()) {
+            return Err(syntax_error(
+                "id",
+                format!("rdf:ID value {id:?} is used more than once"),
+            ));
+        }
+        Ok(iri)
+    }
+
+    fn blank_node(&mut self, node_id: &str) -> Result<BlankNode, Error> {
+        if !is_nc_name(node_id) {
+            return Err(syntax_error(
+                "blank_node",
+                format!("rdf:nodeID value {node_id:?} is not a valid XML name"),
+            ));
+        }
+        Ok(self.blank_nodes.blank_node(node_id))
     }
 }
 
-fn parse_subject_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    xml_base: &Option<Iri>,
-    _subject: Option<&SubjectNode>,
-    graph: &mut Graph,
-) -> Result<Option<SubjectNode>, Error> {
-    let description_element = ExpectedName::new(ELEMENT_DESCRIPTION, rdf::namespace_str());
-    let mut subject: Option<SubjectNode> = None;
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read the document into a tree of elements, returning the root element along with the
+/// namespaces declared on it.
+///
+fn read_element_tree(source: &[u8]) -> Result<(Element, IriPrefixMap), Error> {
+    let mut event_reader = ParserConfig::new()
+        .trim_whitespace(false)
+        .whitespace_to_characters(true)
+        .cdata_to_characters(true)
+        .coalesce_characters(true)
+        .ignore_comments(true)
+        .create_reader(source);
+    let mut stack: Vec<Element> = Vec::default();
+    let mut root: Option<Element> = None;
+    let mut mappings = IriPrefixMap::empty();
     loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::StartElement {
+        let event = event_reader.next().map_err(|e| xml_error(source, e))?;
+        match event {
+            XmlEvent::StartElement {
                 name,
-                namespace: _,
                 attributes,
-            }) => {
-                trace_event!("parse_subject_element" => event);
-                let attributes = parse_attributes(attributes)?;
-                let subject_node: SubjectNode = match &attributes.subject_type {
-                    None => {
-                        // SPEC: §2.1 Introduction
-                        BlankNode::generate().into()
-                    }
-                    Some(SubjectType::Resource(subject)) => {
-                        // SPEC: §2.2 Node Elements and Property Elements
-                        subject.clone().into()
-                    }
-                    Some(SubjectType::RelativeResource(subject)) => {
-                        // SPEC: 2.14 Abbreviating URIs: rdf:ID and xml:base
-                        let uri = format!("{}{}", xml_base.as_ref().unwrap(), subject);
-                        value_to_iri(&uri)?.into()
-                    }
-                    Some(SubjectType::BlankNamed(subject)) => {
-                        // SPEC: §2.10 Identifying Blank Nodes: rdf:nodeID
-                        BlankNode::from_str(subject)?.into()
-                    }
-                };
-                if !description_element.matches(name) {
-                    // SPEC: §2.13 Typed Node Elements
-                    graph.insert(Statement::new(
-                        subject_node.clone(),
-                        rdf::a_type().clone(),
-                        name_to_iri(name)?,
-                    ));
+                namespace,
+            } => {
+                if stack.is_empty() {
+                    mappings = namespace_mappings(&namespace)?;
                 }
-                parse_predicate_attributes(&attributes.inner, &subject_node, graph)?;
-                parse_predicate_element(
-                    event_reader,
-                    if attributes.uri_base.is_some() {
-                        &attributes.uri_base
-                    } else {
-                        xml_base
-                    },
-                    &subject_node,
-                    graph,
-                )?;
-                // set outer loop value
-                subject = Some(subject_node);
-            }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_subject_element" => event);
-                break;
+                stack.push(Element {
+                    name,
+                    position: event_reader.position(),
+                    attributes,
+                    children: Vec::default(),
+                })
             }
-            Ok(_) => {
-                trace_event!("parse_subject_element" => ignore event);
+            XmlEvent::EndElement { .. } => {
+                let element = stack
+                    .pop()
+                    .ok_or_else(|| syntax_error("read_element_tree", "unbalanced end element"))?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(Node::Element(element)),
+                    None => root = Some(element),
+                }
             }
-            Err(e) => {
-                error_event!(parse => "parse_subject_element", e);
+            XmlEvent::Characters(text) => {
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(Node::Text(text));
+                }
             }
+            XmlEvent::EndDocument => break,
+            event => trace!("XmlReader::read_element_tree ignoring event: {:?}", event),
         }
     }
-    Ok(subject)
+    let root =
+        root.ok_or_else(|| syntax_error("read_element_tree", "document has no root element"))?;
+    Ok((root, mappings))
+}
+
+fn namespace_mappings(namespace: &xml::namespace::Namespace) -> Result<IriPrefixMap, Error> {
+    let mut mappings = IriPrefixMap::empty();
+    for (prefix, uri) in namespace {
+        if uri.is_empty() || prefix == XML_PREFIX || prefix == "xmlns" {
+            continue;
+        }
+        let iri = Iri::from_str(uri)?;
+        if prefix.is_empty() {
+            mappings.set_default_namespace(iri);
+        } else {
+            mappings.insert(Namespace::new_unchecked(prefix), iri);
+        }
+    }
+    Ok(mappings)
+}
+
+fn xml_error(source: &[u8], e: xml::reader::Error) -> Error {
+    // the error's display form is prefixed with its position, which is reported separately.
+    let message = e.to_string();
+    let message = message
+        .strip_prefix(&format!("{} ", e.position()))
+        .unwrap_or(&message);
+    rdftk_core::error::syntax_error(super::NAME, message, text_location(source, e.position()))
+}
+
+///
+/// Convert the 0-based row and column reported by the XML reader into a location.
+///
+fn text_location(source: &[u8], position: TextPosition) -> SourceLocation {
+    SourceLocation::from_line_column(
+        &String::from_utf8_lossy(source),
+        position.row as usize + 1,
+        position.column as usize + 1,
+    )
+}
+
+fn syntax_error<S>(rule_fn: &str, given: S) -> Error
+where
+    S: Into<String>,
+{
+    Error::ParserUnexpected {
+        rule_fn: rule_fn.into(),
+        given: given.into(),
+        expecting: Vec::default(),
+    }
 }
 
 #[inline]
-fn name_to_iri(name: &OwnedName) -> Result<Iri, Error> {
-    Ok(Iri::from_str(&format!(
-        "{}{}",
-        name.namespace.as_ref().unwrap(),
-        name.local_name
-    ))?)
+fn is_rdf_name(name: &OwnedName, local_name: &str) -> bool {
+    name.namespace.as_deref() == Some(rdf::namespace_str()) && name.local_name == local_name
+}
+
+///
+/// Returns `true` if `name` is one of the RDF syntax names that may not be used as a node, or
+/// property, element; `also` names any additional names forbidden in that position.
+///
+fn is_rdf_syntax_name(name: &OwnedName, also: &[&str]) -> bool {
+    [
+        ELEMENT_RDF,
+        ATTRIBUTE_ID,
+        ATTRIBUTE_ABOUT,
+        ATTRIBUTE_PARSE_TYPE,
+        ATTRIBUTE_RESOURCE,
+        ATTRIBUTE_NODE_ID,
+        ATTRIBUTE_DATATYPE,
+        ATTRIBUTE_ABOUT_EACH,
+        ATTRIBUTE_ABOUT_EACH_PREFIX,
+        ATTRIBUTE_BAG_ID,
+    ]
+    .iter()
+    .chain(also.iter())
+    .any(|local_name| is_rdf_name(name, local_name))
 }
 
 #[inline]
-fn value_to_iri(name: &str) -> Result<Iri, Error> {
-    Ok(Iri::from_str(name)?)
+fn is_reserved_xml_name(name: &OwnedName) -> bool {
+    name.prefix
+        .as_deref()
+        .unwrap_or(name.local_name.as_str())
+        .to_lowercase()
+        .starts_with(XML_PREFIX)
 }
 
-fn parse_predicate_attributes(
-    attributes: &[&OwnedAttribute],
-    subject: &SubjectNode,
-    graph: &mut Graph,
-) -> Result<(), Error> {
-    // SPEC: §2.5 Property Attributes
-    // SPEC: §2.12 Omitting Nodes: Property Attributes on an empty Property Element
-    for attribute in attributes {
-        trace!(
-            "XmlReader::parse_predicate_attributes attribute: {:?}",
-            attribute
-        );
-        graph.insert(Statement::new(
-            subject.clone(),
-            name_to_iri(&attribute.name)?,
-            Literal::plain(&attribute.value),
-        ));
+fn is_nc_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => chars.all(|c| {
+            c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '\u{B7}' | '\u{300}'..='\u{36F}')
+        }),
+        _ => false,
     }
-    Ok(())
 }
 
-fn parse_predicate_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    xml_base: &Option<Iri>,
-    subject: &SubjectNode,
-    graph: &mut Graph,
-) -> Result<(), Error> {
-    let mut no_child_elements = false;
-    loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::StartElement {
-                name,
-                namespace: _,
-                attributes,
-            }) => {
-                trace_event!("parse_predicate_element" => event);
-                if no_child_elements {
-                    error_event!(state => "parse_predicate_element", "child elements not allowed here");
-                }
-                let attributes = parse_attributes(attributes)?;
-                if let Some(resource) = attributes.resource {
-                    // SPEC: §2.4 Empty Property Elements
-                    graph.insert(Statement::new(
-                        subject.clone(),
-                        name_to_iri(name)?,
-                        resource,
-                    ));
-                    // set outer loop value
-                    no_child_elements = true;
-                } else {
-                    match attributes.parse_type {
-                        None => {
-                            if let Some(content) =
-                                parse_object_element(event_reader, xml_base, graph)?
-                            {
-                                let literal = if let Some(data_type) = attributes.data_type {
-                                    // SPEC: §2.9 Typed Literals: rdf:datatype
-                                    Literal::with_data_type(&content, DataType::from(data_type))
-                                } else if let Some(language) = attributes.language {
-                                    // SPEC: §2.7 Languages: xml:lang
-                                    Literal::with_language(&content, language)
-                                } else {
-                                    Literal::plain(&content)
-                                };
-                                graph.insert(Statement::new(
-                                    subject.clone(),
-                                    name_to_iri(name)?,
-                                    literal,
-                                ));
-                            }
-                        }
-                        Some(ParseType::XmlLiteral) => {
-                            // SPEC: §2.8 XML Literals: rdf:parseType="Literal"
-                            let content = parse_xml_literal_element(event_reader)?
-                                .replace('<', "&lt;")
-                                .replace('>', "&gt;");
-                            graph.insert(Statement::new(
-                                subject.clone(),
-                                name_to_iri(name)?,
-                                Literal::with_data_type(&content, DataType::XmlLiteral),
-                            ));
-                        }
-                        Some(ParseType::Resource) => {
-                            // SPEC: §2.11 Omitting Blank Nodes: rdf:parseType="Resource"
-                            let subject_node = BlankNode::generate().into();
-                            //parse_predicate_attributes(&attributes.inner, &subject_node, graph)?;
-                            let _subject = parse_subject_element(
-                                event_reader,
-                                if attributes.uri_base.is_some() {
-                                    &attributes.uri_base
-                                } else {
-                                    xml_base
-                                },
-                                Some(&subject_node),
-                                graph,
-                            )?;
-                        }
-                        Some(ParseType::Collection) => {
-                            // SPEC: §2.16 Collections: rdf:parseType="Collection"
-                            todo!()
-                        }
-                    }
-                }
-            }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_predicate_element" => event);
-                return Ok(());
-            }
-            Ok(_) => {
-                trace_event!("parse_predicate_element" => ignore event);
-            }
-            Err(e) => {
-                error_event!(parse => "parse_predicate_element", e);
-            }
-        }
+///
+/// Serialize the content of an element using
+/// [Exclusive XML Canonicalization](https://www.w3.org/TR/xml-exc-c14n/), without comments.
+///
+fn canonical_xml(content: &[Node]) -> String {
+    let mut buffer = String::new();
+    let rendered = BTreeMap::default();
+    for node in content {
+        write_canonical_xml(&mut buffer, node, &rendered);
     }
+    buffer
 }
 
-fn parse_object_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    xml_base: &Option<Iri>,
-    graph: &mut Graph,
-) -> Result<Option<String>, Error> {
-    let mut content = String::new();
-    let mut has_elements = false;
-    let mut has_characters = false;
-    loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::StartElement {
-                name,
-                namespace: _,
-                attributes,
-            }) => {
-                trace_event!("parse_content_element" => event);
-                if has_characters {
-                    error_event!(state => "parse_object_element", &format!("found XML content, parseType != Literal ({:?})", name));
+fn write_canonical_xml(buffer: &mut String, node: &Node, rendered: &BTreeMap<String, String>) {
+    match node {
+        Node::Text(text) => {
+            for c in text.chars() {
+                match c {
+                    '&' => buffer.push_str("&amp;"),
+                    '<' => buffer.push_str("&lt;"),
+                    '>' => buffer.push_str("&gt;"),
+                    '\r' => buffer.push_str("&#xD;"),
+                    c => buffer.push(c),
                 }
-                // set outer loop value
-                has_elements = true;
-                let attributes = parse_attributes(attributes)?;
-                let subject_node = BlankNode::generate().into();
-                let _subject = parse_subject_element(
-                    event_reader,
-                    if attributes.uri_base.is_some() {
-                        &attributes.uri_base
-                    } else {
-                        xml_base
-                    },
-                    Some(&subject_node),
-                    graph,
-                )?;
-            }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_content_element" => event);
-                return Ok(Some(content));
             }
-            Ok(XmlEvent::CData(value)) => {
-                trace_event!("parse_content_element" => event);
-                if has_elements {
-                    error_event!(state => "parse_object_element", format!("found character content after element(s)"));
+        }
+        Node::Element(element) => {
+            // Only namespaces visibly used by the element, or its attributes, are declared,
+            // and then only if not already declared by an output ancestor.
+            let mut rendered = rendered.clone();
+            let mut declarations: BTreeMap<String, String> = BTreeMap::default();
+            for name in std::iter::once(&element.name).chain(
+                element
+                    .attributes
+                    .iter()
+                    .map(|attribute| &attribute.name)
+                    .filter(|name| name.prefix.is_some()),
+            ) {
+                let prefix = name.prefix.clone().unwrap_or_default();
+                let namespace = name.namespace.clone().unwrap_or_default();
+                if prefix != XML_PREFIX
+                    && rendered.get(&prefix).cloned().unwrap_or_default() != namespace
+                {
+                    let _ = declarations.insert(prefix.clone(), namespace.clone());
+                    let _ = rendered.insert(prefix, namespace);
                 }
-                // set outer loop value
-                has_characters = true;
-                content.push_str(value);
             }
-            Ok(XmlEvent::Characters(value)) => {
-                trace_event!("parse_content_element" => event);
-                if has_elements {
-                    error_event!(state => "parse_object_element", "found character content after element(s)");
+
+            let element_name = qualified_name(&element.name);
+            buffer.push('<');
+            buffer.push_str(&element_name);
+            for (prefix, namespace) in declarations {
+                if prefix.is_empty() {
+                    buffer.push_str(" xmlns=\"");
+                } else {
+                    buffer.push_str(&format!(" xmlns:{prefix}=\""));
                 }
-                // set outer loop value
-                has_characters = true;
-                content.push_str(value);
+                escape_attribute_value(buffer, &namespace);
+                buffer.push('"');
             }
-            Ok(_) => {
-                trace_event!("parse_object_element" => ignore event);
+            let mut attributes: Vec<&OwnedAttribute> = element.attributes.iter().collect();
+            attributes.sort_by_key(|attribute| {
+                (
+                    attribute.name.namespace.clone().unwrap_or_default(),
+                    attribute.name.local_name.clone(),
+                )
+            });
+            for attribute in attributes {
+                buffer.push_str(&format!(" {}=\"", qualified_name(&attribute.name)));
+                escape_attribute_value(buffer, &attribute.value);
+                buffer.push('"');
             }
-            Err(e) => {
-                error_event!(parse => "parse_object_element", e);
+            buffer.push('>');
+            for child in &element.children {
+                write_canonical_xml(buffer, child, &rendered);
             }
+            buffer.push_str(&format!("</{element_name}>"));
         }
     }
 }
 
-fn parse_xml_literal_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-) -> Result<String, Error> {
-    let mut content: Vec<u8> = Vec::new();
-    let mut writer_config = xml::EmitterConfig::new();
-    writer_config.write_document_declaration = false;
-    writer_config.normalize_empty_elements = true;
-    let mut writer = EventWriter::new_with_config(&mut content, writer_config);
-    let mut opened: u32 = 0;
-    loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::StartElement { .. }) => {
-                trace_event!("parse_content_element" => event);
-                if let Some(event) = event.unwrap().as_writer_event() {
-                    writer.write(event).unwrap()
-                }
-                opened += 1;
-            }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_content_element" => event);
-                if let Some(event) = event.unwrap().as_writer_event() {
-                    writer.write(event).unwrap()
-                }
-                opened -= 1;
-                if opened == 0 {
-                    return Ok(String::from_utf8(content).unwrap());
-                }
-            }
-            Ok(event) => {
-                // We only leave trim_whitespace=false so that these events are also written out.
-                if let Some(event) = event.as_writer_event() {
-                    writer.write(event).unwrap()
-                }
-            }
-            Err(e) => {
-                error_event!(parse => "parse_content_element", e);
-            }
-        }
+fn qualified_name(name: &OwnedName) -> String {
+    match &name.prefix {
+        Some(prefix) => format!("{prefix}:{}", name.local_name),
+        None => name.local_name.clone(),
     }
 }
 
-fn parse_attributes(attributes: &[OwnedAttribute]) -> Result<Attributes<'_>, Error> {
-    let mut response = Attributes {
-        subject_type: None,
-        parse_type: None,
-        uri_base: None,
-        data_type: None,
-        language: None,
-        resource: None,
-        inner: Default::default(),
-    };
-
-    for attribute in attributes {
-        if attribute.name.namespace == Some(XML_NAMESPACE.to_string()) {
-            if attribute.name.local_name == ATTRIBUTE_XML_BASE {
-                response.uri_base = Some(value_to_iri(&attribute.value)?);
-            } else if attribute.name.local_name == ATTRIBUTE_XML_LANG {
-                response.language = Some(LanguageTag::from_str(&attribute.value)?);
-            }
-        } else if attribute.name.namespace == Some(rdf::namespace_str().to_string()) {
-            if attribute.name.local_name == ATTRIBUTE_ABOUT {
-                response.subject_type =
-                    Some(SubjectType::Resource(value_to_iri(&attribute.value)?));
-            } else if attribute.name.local_name == ATTRIBUTE_NODE_ID {
-                response.subject_type = Some(SubjectType::BlankNamed(attribute.value.to_string()));
-            } else if attribute.name.local_name == ATTRIBUTE_ID {
-                response.subject_type =
-                    Some(SubjectType::RelativeResource(attribute.value.to_string()));
-            } else if attribute.name.local_name == ATTRIBUTE_RESOURCE {
-                response.resource = Some(value_to_iri(&attribute.value)?);
-            } else if attribute.name.local_name == ATTRIBUTE_PARSE_TYPE {
-                if attribute.value == PARSE_TYPE_LITERAL {
-                    response.parse_type = Some(ParseType::XmlLiteral);
-                } else if attribute.value == PARSE_TYPE_RESOURCE {
-                    response.parse_type = Some(ParseType::Resource);
-                } else if attribute.value == PARSE_TYPE_COLLECTION {
-                    response.parse_type = Some(ParseType::Collection);
-                } else {
-                    panic!();
-                }
-            }
-        } else {
-            response.inner.push(attribute);
+fn escape_attribute_value(buffer: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => buffer.push_str("&amp;"),
+            '<' => buffer.push_str("&lt;"),
+            '"' => buffer.push_str("&quot;"),
+            '\t' => buffer.push_str("&#x9;"),
+            '\n' => buffer.push_str("&#xA;"),
+            '\r' => buffer.push_str("&#xD;"),
+            c => buffer.push(c),
         }
     }
-
-    trace!("parse_attributes -> {:?}", response);
-
-    Ok(response)
 }
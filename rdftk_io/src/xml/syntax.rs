@@ -9,11 +9,17 @@ pub(super) const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
 pub(super) const DEFAULT_ENCODING: &str = "utf-8";
 
 pub(super) const ATTRIBUTE_ABOUT: &str = "about";
+pub(super) const ATTRIBUTE_ABOUT_EACH: &str = "aboutEach";
+pub(super) const ATTRIBUTE_ABOUT_EACH_PREFIX: &str = "aboutEachPrefix";
+pub(super) const ATTRIBUTE_BAG_ID: &str = "bagID";
 pub(super) const ATTRIBUTE_DATATYPE: &str = "datatype";
 pub(super) const ATTRIBUTE_ID: &str = "ID";
 pub(super) const ATTRIBUTE_NODE_ID: &str = "nodeID";
 pub(super) const ATTRIBUTE_PARSE_TYPE: &str = "parseType";
 pub(super) const ATTRIBUTE_RESOURCE: &str = "resource";
+pub(super) const ATTRIBUTE_TYPE: &str = "type";
+
+pub(super) const XML_PREFIX: &str = "xml";
 
 pub(super) const ATTRIBUTE_XML_BASE: &str = "base";
 pub(super) const ATTRIBUTE_XML_BASE_PREFIXED: &str = "xml:base";
@@ -21,6 +27,7 @@ pub(super) const ATTRIBUTE_XML_LANG: &str = "lang";
 pub(super) const ATTRIBUTE_XML_LANG_PREFIXED: &str = "xml:lang";
 
 pub(super) const ELEMENT_DESCRIPTION: &str = "Description";
+pub(super) const ELEMENT_LI: &str = "li";
 pub(super) const ELEMENT_RDF: &str = "RDF";
 
 pub(super) const PARSE_TYPE_COLLECTION: &str = "Collection";
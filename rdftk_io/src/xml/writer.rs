@@ -1,15 +1,20 @@
+use super::abbreviated::AbbreviatedWriter;
+use super::split_name;
 use super::syntax::{
     ATTRIBUTE_ABOUT, ATTRIBUTE_DATATYPE, ATTRIBUTE_NODE_ID, ATTRIBUTE_RESOURCE, DEFAULT_ENCODING,
     ELEMENT_DESCRIPTION, ELEMENT_RDF,
 };
+use crate::common::literal::unescape_lexical_form;
+use crate::common::simplify::simplify_statement;
 use crate::xml::syntax::ATTRIBUTE_XML_LANG_PREFIXED;
-use crate::GraphWriter;
+use crate::{GraphWriter, StatementSink};
+use itertools::Itertools;
 use objio::HasOptions;
 use objio::{impl_has_options, ObjectWriter};
 use rdftk_core::error::{rdf_star_not_supported_error, Error};
 use rdftk_core::model::graph::Graph;
-use rdftk_core::model::statement::SubjectNode;
-use rdftk_iri::Iri;
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_iri::{Iri, Namespace};
 use rdftk_names::{dc, foaf, geo, owl, rdf, rdfs, xsd};
 use std::collections::HashMap;
 use std::io::Write;
@@ -30,6 +35,11 @@ pub enum XmlStyle {
     Flat,
     /// Nest blank nodes so that the document only has Iri subjects at the same level.
     Striped,
+    /// The abbreviated, human-editable, form that uses typed node elements, property
+    /// attributes for plain literals, `rdf:parseType="Resource"` and `rdf:parseType="Collection"`
+    /// for nested blank nodes and lists, and `xml:lang` inheritance. Namespaces are declared
+    /// using the graph's prefix mappings.
+    Abbreviated,
 }
 
 ///
@@ -54,6 +64,18 @@ pub struct XmlWriter {
     options: XmlOptions,
 }
 
+///
+/// An implementation of [`StatementSink`], returned by [`XmlWriter::sink`], which writes each
+/// statement as it is received in the flat style. Consecutive statements with the same
+/// subject are written as properties of a single `rdf:Description` element.
+///
+pub struct XmlSink<W> {
+    writer: EventWriter<W>,
+    encoding: String,
+    mappings: HashMap<String, String>,
+    subject: Option<SubjectNode>,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -64,6 +86,7 @@ lazy_static::lazy_static! {
     static ref RDF_DESCRIPTION: String =
         format!("{}:{}", rdf::default_prefix(), ELEMENT_DESCRIPTION);
     static ref RDF_NODE_ID: String = format!("{}:{}", rdf::default_prefix(), ATTRIBUTE_NODE_ID);
+    static ref RDF_RDF: String = format!("{}:{}", rdf::default_prefix(), ELEMENT_RDF);
     static ref RDF_RESOURCE: String = format!("{}:{}", rdf::default_prefix(), ATTRIBUTE_RESOURCE);
 }
 
@@ -94,6 +117,14 @@ impl XmlOptions {
         }
     }
 
+    /// Create an option instance with `XmlStyle::Abbreviated`.
+    pub fn abbreviated(self) -> Self {
+        Self {
+            style: XmlStyle::Abbreviated,
+            ..self
+        }
+    }
+
     /// Set the option to emit pretty-printed XML.
     pub fn pretty(self) -> Self {
         Self {
@@ -150,10 +181,19 @@ impl ObjectWriter<Graph> for XmlWriter {
     where
         W: Write,
     {
-        let config = EmitterConfig::new()
-            .perform_indent(self.options.pretty_print)
-            .normalize_empty_elements(self.options.pretty_print);
-        let mut writer = config.create_writer(w);
+        if self.options.style == XmlStyle::Flat {
+            let mut sink = self.sink(w);
+            sink.start()?;
+            for (prefix, namespace) in graph.prefix_mappings().mappings() {
+                sink.prefix(prefix, namespace)?;
+            }
+            for statement in graph.statements().sorted() {
+                sink.statement(statement)?;
+            }
+            return sink.finish();
+        }
+
+        let mut writer = self.emitter_config().create_writer(w);
 
         writer
             .write(XmlEvent::StartDocument {
@@ -163,6 +203,12 @@ impl ObjectWriter<Graph> for XmlWriter {
             })
             .map_err(xml_error)?;
 
+        if self.options.style == XmlStyle::Abbreviated {
+            let simple_graph = graph.simplify()?;
+            return AbbreviatedWriter::new(&simple_graph, graph.prefix_mappings(), &self.mappings)?
+                .write(&mut writer);
+        }
+
         let container_name = format!("{}:{}", rdf::default_prefix(), ELEMENT_RDF);
         writer
             .write(
@@ -173,14 +219,8 @@ impl ObjectWriter<Graph> for XmlWriter {
 
         let graph = graph.simplify()?;
 
-        if self.options.style == XmlStyle::Flat {
-            for subject in graph.subjects() {
-                self.write_subject(&mut writer, &graph, subject, true)?;
-            }
-        } else {
-            for subject in graph.subjects().iter().filter(|s| s.is_resource()) {
-                self.write_subject(&mut writer, &graph, subject, false)?;
-            }
+        for subject in graph.subjects().iter().filter(|s| s.is_resource()) {
+            self.write_subject(&mut writer, &graph, subject)?;
         }
 
         writer
@@ -194,6 +234,28 @@ impl ObjectWriter<Graph> for XmlWriter {
 impl GraphWriter for XmlWriter {}
 
 impl XmlWriter {
+    ///
+    /// Return a [`StatementSink`] that writes statements to `w` as they are received; the sink
+    /// always uses the flat style, regardless of the style set in this writer's options.
+    ///
+    pub fn sink<W>(&self, w: W) -> XmlSink<W>
+    where
+        W: Write,
+    {
+        XmlSink {
+            writer: self.emitter_config().create_writer(w),
+            encoding: self.options.encoding.clone(),
+            mappings: self.mappings.clone(),
+            subject: None,
+        }
+    }
+
+    fn emitter_config(&self) -> EmitterConfig {
+        EmitterConfig::new()
+            .perform_indent(self.options.pretty_print)
+            .normalize_empty_elements(self.options.pretty_print)
+    }
+
     fn default_mappings() -> HashMap<String, String> {
         let mappings: HashMap<String, String> = [
             (
@@ -236,80 +298,38 @@ impl XmlWriter {
         writer: &mut EventWriter<W>,
         graph: &Graph,
         subject: &SubjectNode,
-        flat: bool,
     ) -> Result<(), Error> {
-        if let Some(blank) = subject.as_blank() {
-            if flat {
-                writer
-                    .write(
-                        XmlEvent::start_element(RDF_DESCRIPTION.as_str())
-                            .attr(RDF_NODE_ID.as_str(), blank.as_ref().as_ref()),
-                    )
-                    .map_err(xml_error)?;
-            } else {
-                writer
-                    .write(XmlEvent::start_element(RDF_DESCRIPTION.as_str()))
-                    .map_err(xml_error)?;
-            }
-        } else if let Some(subject) = subject.as_resource() {
+        if subject.is_blank() {
             writer
-                .write(
-                    XmlEvent::start_element(RDF_DESCRIPTION.as_str())
-                        .attr(RDF_ABOUT.as_str(), subject.as_ref()),
-                )
+                .write(XmlEvent::start_element(RDF_DESCRIPTION.as_str()))
                 .map_err(xml_error)?;
         } else {
-            return rdf_star_not_supported_error(super::NAME).into();
+            start_description(writer, subject)?;
         }
 
         for predicate in graph.predicates_for(subject) {
-            let (ns, mut name) = split_uri(predicate);
-
             for object in graph.objects_for(subject, predicate) {
-                let mut element = if let Some(prefix) = self.mappings.get(&ns) {
-                    name = format!("{}:{}", prefix, name);
-                    XmlEvent::start_element(name.as_str()).ns(prefix, &ns)
-                } else {
-                    XmlEvent::start_element(name.as_str()).default_ns(&ns)
-                };
-
-                if let Some(iri) = object.as_resource() {
-                    let iri = iri.to_string();
-                    element = element.attr(RDF_RESOURCE.as_str(), &iri);
-                    writer.write(element).map_err(xml_error)?;
-                } else if let Some(blank) = object.as_blank() {
-                    if flat {
-                        element = element.attr(RDF_NODE_ID.as_str(), blank.as_ref().as_ref());
-                        writer.write(element).map_err(xml_error)?;
-                    } else {
-                        writer.write(element).map_err(xml_error)?;
-                        self.write_subject(writer, graph, &blank.clone().into(), flat)?;
-                    }
-                } else if let Some(literal) = object.as_literal() {
-                    let language = literal
-                        .language()
-                        .map(|l| l.to_string())
-                        .unwrap_or_default();
-                    if !language.is_empty() {
-                        element = element.attr(ATTRIBUTE_XML_LANG_PREFIXED, &language)
-                    }
-                    if let Some(data_type) = literal.data_type() {
-                        let dt_iri = data_type.as_iri().to_string();
+                if let Some(blank) = object.as_blank() {
+                    let (ns, name) = split_name(predicate)?;
+                    let name = if let Some(prefix) = self.mappings.get(&ns) {
+                        let name = format!("{}:{}", prefix, name);
                         writer
-                            .write(element.attr(RDF_DATATYPE.as_str(), &dt_iri))
-                            .map_err(xml_error)?
+                            .write(XmlEvent::start_element(name.as_str()).ns(prefix, &ns))
+                            .map_err(xml_error)?;
+                        name
                     } else {
-                        writer.write(element).map_err(xml_error)?;
-                    }
+                        writer
+                            .write(XmlEvent::start_element(name.as_str()).default_ns(&ns))
+                            .map_err(xml_error)?;
+                        name
+                    };
+                    self.write_subject(writer, graph, &blank.clone().into())?;
                     writer
-                        .write(XmlEvent::Characters(literal.lexical_form()))
+                        .write(XmlEvent::end_element().name(name.as_str()))
                         .map_err(xml_error)?;
                 } else {
-                    return rdf_star_not_supported_error(super::NAME).into();
+                    write_property(writer, &self.mappings, predicate, object)?;
                 }
-                writer
-                    .write(XmlEvent::end_element().name(name.as_str()))
-                    .map_err(xml_error)?;
             }
         }
 
@@ -321,6 +341,76 @@ impl XmlWriter {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+
+impl<W> StatementSink for XmlSink<W>
+where
+    W: Write,
+{
+    fn start(&mut self) -> Result<(), Error> {
+        self.writer
+            .write(XmlEvent::StartDocument {
+                version: XmlVersion::Version11,
+                encoding: Some(&self.encoding),
+                standalone: None,
+            })
+            .map_err(xml_error)?;
+        self.writer
+            .write(
+                XmlEvent::start_element(RDF_RDF.as_str())
+                    .ns(rdf::default_prefix().as_ref(), rdf::namespace_str()),
+            )
+            .map_err(xml_error)
+    }
+
+    fn prefix(&mut self, prefix: &Namespace, namespace: &Iri) -> Result<(), Error> {
+        if let Some(prefix) = prefix.name_string() {
+            self.mappings
+                .insert(format!("{namespace:#}"), prefix.to_string());
+        }
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        for statement in simplify_statement(statement)? {
+            if self.subject.as_ref() != Some(statement.subject()) {
+                self.end_description()?;
+                start_description(&mut self.writer, statement.subject())?;
+                self.subject = Some(statement.subject().clone());
+            }
+            write_property(
+                &mut self.writer,
+                &self.mappings,
+                statement.predicate(),
+                statement.object(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        self.end_description()?;
+        self.writer
+            .write(XmlEvent::end_element().name(RDF_RDF.as_str()))
+            .map_err(xml_error)?;
+        Ok(self.writer.inner_mut().flush()?)
+    }
+}
+
+impl<W> XmlSink<W>
+where
+    W: Write,
+{
+    fn end_description(&mut self) -> Result<(), Error> {
+        if self.subject.take().is_some() {
+            self.writer
+                .write(XmlEvent::end_element().name(RDF_DESCRIPTION.as_str()))
+                .map_err(xml_error)?;
+        }
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
@@ -333,15 +423,74 @@ fn xml_error(e: xml::writer::Error) -> Error {
     }
 }
 
-fn split_uri(iri: &Iri) -> (String, String) {
-    let iri = iri.to_string();
-    let index = iri
-        .chars()
-        .rev()
-        .enumerate()
-        .find_map(|(i, c)| if c == '#' || c == '/' { Some(i) } else { None })
-        .unwrap();
-    assert_ne!(index, iri.len());
-    let index = iri.len() - index;
-    (iri[..index].to_string(), iri[index..].to_string())
+fn start_description<W: Write>(
+    writer: &mut EventWriter<W>,
+    subject: &SubjectNode,
+) -> Result<(), Error> {
+    let element = XmlEvent::start_element(RDF_DESCRIPTION.as_str());
+    if let Some(blank) = subject.as_blank() {
+        writer
+            .write(element.attr(RDF_NODE_ID.as_str(), blank.as_ref()))
+            .map_err(xml_error)
+    } else if let Some(subject) = subject.as_resource() {
+        writer
+            .write(element.attr(RDF_ABOUT.as_str(), subject.as_ref()))
+            .map_err(xml_error)
+    } else {
+        rdf_star_not_supported_error(super::NAME).into()
+    }
+}
+
+///
+/// Write a single property element, blank node objects are written as a reference using
+/// `rdf:nodeID`.
+///
+fn write_property<W: Write>(
+    writer: &mut EventWriter<W>,
+    mappings: &HashMap<String, String>,
+    predicate: &Iri,
+    object: &ObjectNode,
+) -> Result<(), Error> {
+    let (ns, mut name) = split_name(predicate)?;
+    let mut element = if let Some(prefix) = mappings.get(&ns) {
+        name = format!("{}:{}", prefix, name);
+        XmlEvent::start_element(name.as_str()).ns(prefix, &ns)
+    } else {
+        XmlEvent::start_element(name.as_str()).default_ns(&ns)
+    };
+
+    if let Some(iri) = object.as_resource() {
+        let iri = format!("{iri:#}");
+        element = element.attr(RDF_RESOURCE.as_str(), &iri);
+        writer.write(element).map_err(xml_error)?;
+    } else if let Some(blank) = object.as_blank() {
+        element = element.attr(RDF_NODE_ID.as_str(), blank.as_ref());
+        writer.write(element).map_err(xml_error)?;
+    } else if let Some(literal) = object.as_literal() {
+        let language = literal
+            .language()
+            .map(|l| l.to_string())
+            .unwrap_or_default();
+        if !language.is_empty() {
+            element = element.attr(ATTRIBUTE_XML_LANG_PREFIXED, &language)
+        }
+        if let Some(data_type) = literal.data_type() {
+            let dt_iri = format!("{:#}", data_type.as_iri());
+            writer
+                .write(element.attr(RDF_DATATYPE.as_str(), &dt_iri))
+                .map_err(xml_error)?
+        } else {
+            writer.write(element).map_err(xml_error)?;
+        }
+        writer
+            .write(XmlEvent::Characters(&unescape_lexical_form(
+                literal.lexical_form(),
+            )))
+            .map_err(xml_error)?;
+    } else {
+        return rdf_star_not_supported_error(super::NAME).into();
+    }
+    writer
+        .write(XmlEvent::end_element().name(name.as_str()))
+        .map_err(xml_error)
 }
@@ -0,0 +1,161 @@
+#![cfg(feature = "binary")]
+
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::{DataType, Literal};
+use rdftk_core::model::statement::{BlankNode, Collection, ObjectNode, Statement, SubjectNode};
+use rdftk_io::binary::{BinaryReader, BinaryWriter, BinaryWriterOptions};
+use rdftk_iri::{Iri, IriPrefixMap, Namespace};
+use rdftk_names::{dc, foaf};
+use std::str::FromStr;
+
+mod common;
+pub mod logging;
+
+fn iri(s: &str) -> Iri {
+    Iri::from_str(s).unwrap()
+}
+
+///
+/// The shared people graph, with prefix mappings and the values that only the binary format
+/// carries in its tables: a non-native data type, a collection, and nested statements.
+///
+fn make_graph() -> Graph {
+    let alice = SubjectNode::from(iri("http://example.org/people#alice"));
+    let bob = BlankNode::from_str("bob").unwrap();
+    let claim = Statement::new(alice.clone(), foaf::knows().clone(), bob.clone());
+    let mut mappings = IriPrefixMap::empty();
+    mappings.insert(Namespace::new_unchecked("foaf"), foaf::namespace().clone());
+    let mut graph = common::people_graph().with_mappings(mappings);
+    graph.extend(vec![
+        Statement::new(
+            bob,
+            foaf::age().clone(),
+            Literal::with_data_type(
+                "42",
+                DataType::from(iri("http://www.w3.org/2001/XMLSchema#integer")),
+            ),
+        ),
+        Statement::new(
+            alice.clone(),
+            dc::elements::subject().clone(),
+            Collection::from(vec![
+                ObjectNode::from(iri("http://example.org/topics#rdf")),
+                ObjectNode::from(Literal::plain("binary")),
+                ObjectNode::from(BlankNode::from_str("topic").unwrap()),
+            ]),
+        ),
+        Statement::new(
+            claim.clone(),
+            dc::elements::source().clone(),
+            iri("http://example.org/sources#census"),
+        ),
+        Statement::new(alice, dc::elements::relation().clone(), claim),
+    ]);
+    graph
+}
+
+#[test]
+fn write_and_read_graph() {
+    logging::try_init();
+    let graph = make_graph();
+    let mut buffer = Vec::default();
+    BinaryWriter::default().write(&mut buffer, &graph).unwrap();
+    assert_eq!(&buffer[..4], b"RDFB");
+
+    let result: Graph = BinaryReader::default()
+        .read(&mut buffer.as_slice())
+        .unwrap();
+    assert!(common::graphs_isomorphic(&graph, &result));
+    assert_eq!(
+        result
+            .prefix_mappings()
+            .get_namespace(&Namespace::new_unchecked("foaf")),
+        Some(foaf::namespace())
+    );
+}
+
+#[test]
+fn write_and_read_with_small_tables() {
+    logging::try_init();
+    let graph = make_graph();
+    let writer = BinaryWriter::default().with_options(
+        BinaryWriterOptions::default()
+            .with_prefix_table_size(1)
+            .with_name_table_size(2)
+            .with_datatype_table_size(1),
+    );
+    let mut small = Vec::default();
+    writer.write(&mut small, &graph).unwrap();
+    let mut large = Vec::default();
+    BinaryWriter::default().write(&mut large, &graph).unwrap();
+    assert!(small.len() > large.len());
+
+    let result: Graph = BinaryReader::default().read(&mut small.as_slice()).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &result));
+}
+
+#[test]
+fn write_and_read_data_set() {
+    logging::try_init();
+    let data_set = common::people_data_set(make_graph());
+
+    let mut buffer = Vec::default();
+    BinaryWriter::default()
+        .write(&mut buffer, &data_set)
+        .unwrap();
+    let result: DataSet = BinaryReader::default()
+        .read(&mut buffer.as_slice())
+        .unwrap();
+    assert_eq!(result.len(), 3);
+    assert!(common::data_sets_isomorphic(&data_set, &result));
+
+    let graph: Result<Graph, _> = BinaryReader::default().read(&mut buffer.as_slice());
+    assert!(graph.is_err());
+}
+
+#[test]
+fn read_quads_streaming() {
+    logging::try_init();
+    let mut graph = Graph::named(GraphName::Iri(iri("http://example.org/graphs#one")));
+    graph.insert(Statement::new(
+        iri("http://example.org/people#carol"),
+        foaf::name().clone(),
+        Literal::plain("Carol"),
+    ));
+    let mut buffer = Vec::default();
+    BinaryWriter::default().write(&mut buffer, &graph).unwrap();
+
+    let quads: Vec<_> = BinaryReader::default()
+        .quads(buffer.as_slice())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        quads,
+        vec![(
+            Statement::new(
+                iri("http://example.org/people#carol"),
+                foaf::name().clone(),
+                Literal::plain("Carol"),
+            ),
+            Some(GraphName::Iri(iri("http://example.org/graphs#one")))
+        )]
+    );
+}
+
+#[test]
+fn read_not_binary_fails() {
+    let result: Result<Graph, _> = BinaryReader::default().read(
+        &mut "<http://example.org/a> <http://example.org/b> <http://example.org/c> .".as_bytes(),
+    );
+    assert!(result.is_err());
+
+    let mut buffer = Vec::default();
+    BinaryWriter::default()
+        .write(&mut buffer, &make_graph())
+        .unwrap();
+    buffer.truncate(buffer.len() - 3);
+    let result: Result<Graph, _> = BinaryReader::default().read(&mut buffer.as_slice());
+    assert!(result.is_err());
+}
@@ -1,13 +1,17 @@
 use rdftk_core::model::{
+    data_set::DataSet,
     graph::{Graph, GraphName},
-    literal::Literal,
-    statement::{BlankNode, Statement},
+    literal::{DataType, LanguageTag, Literal},
+    statement::{BlankNode, Collection, ObjectNode, Statement, SubjectNode},
 };
 use rdftk_iri::{
-    Iri, IriPrefixMap, Name, VOCABULARY_DC_ELEMENTS, VOCABULARY_FOAF, VOCABULARY_RDF,
-    VOCABULARY_RDF_SCHEMA,
+    vocab::{VOCABULARY_DC_ELEMENTS, VOCABULARY_FOAF, VOCABULARY_RDF, VOCABULARY_RDF_SCHEMA},
+    Iri, IriPrefixMap, Namespace,
 };
+use rdftk_names::{dc, foaf, rdf};
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::Arc;
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum TonyBennType {
@@ -42,7 +46,7 @@ fn some_tony_benn_graph(graph_type: TonyBennType) -> (IriPrefixMap, Vec<Statemen
         .with_vocabulary(&VOCABULARY_FOAF);
     if graph_type == TonyBennType::TwoTypes {
         mappings.insert(
-            Name::new_unchecked("fibo-fnd-aap-ppl"),
+            Namespace::new_unchecked("fibo-fnd-aap-ppl"),
             Iri::from_str("https://spec.edmcouncil.org/fibo/ontology/FND/AgentsAndPeople/People/")
                 .unwrap(),
         );
@@ -103,11 +107,11 @@ pub fn use_cases_graph() -> Graph {
     let mappings = IriPrefixMap::default()
         .with_vocabulary(&VOCABULARY_RDF)
         .with(
-            Name::new_unchecked("use-case"),
+            Namespace::new_unchecked("use-case"),
             Iri::from_str("https://ekgf.org/ontology/use-case/").unwrap(),
         )
         .with(
-            Name::new_unchecked("test"),
+            Namespace::new_unchecked("test"),
             Iri::from_str("https://whatever.org/ontology/test/").unwrap(),
         );
 
@@ -176,15 +180,15 @@ pub fn many_blank_nodes_graph() -> Graph {
         .with_vocabulary(&VOCABULARY_RDF)
         .with_vocabulary(&VOCABULARY_RDF_SCHEMA)
         .with(
-            Name::new_unchecked("use-case"),
+            Namespace::new_unchecked("use-case"),
             Iri::from_str("https://ekgf.org/ontology/use-case/").unwrap(),
         )
         .with(
-            Name::new_unchecked("concept"),
+            Namespace::new_unchecked("concept"),
             Iri::from_str("https://ekgf.org/ontology/concept/").unwrap(),
         )
         .with(
-            Name::new_unchecked("graph"),
+            Namespace::new_unchecked("graph"),
             Iri::from_str("https://yourcompany.com/graph/").unwrap(),
         );
 
@@ -222,3 +226,303 @@ pub fn many_blank_nodes_graph() -> Graph {
         .with_mappings(mappings)
         .with_statements(statements)
 }
+
+///
+/// A graph with a literal of each of the data types that Turtle writes without quotes, and a
+/// language-tagged literal.
+///
+#[allow(dead_code)]
+pub fn typed_literals_graph() -> Graph {
+    let subject = Iri::from_str("http://example.org/measure").unwrap();
+    let ex = |name: &str| Iri::from_str(&format!("http://example.org/{name}")).unwrap();
+    Graph::default().with_statements(vec![
+        Statement::new(
+            subject.clone(),
+            ex("ratio"),
+            Literal::with_data_type("2.5", DataType::Decimal),
+        ),
+        Statement::new(subject.clone(), ex("count"), Literal::from(23_i64)),
+        Statement::new(subject.clone(), ex("valid"), Literal::from(true)),
+        Statement::new(subject.clone(), ex("scale"), Literal::from(1.5e3_f64)),
+        Statement::new(
+            subject,
+            ex("label"),
+            Literal::with_language("measure", LanguageTag::from_str("en").unwrap()),
+        ),
+    ])
+}
+
+///
+/// A graph with an asserted statement, a statement about it, and a statement with a nested
+/// statement as its object; in Turtle-star:
+///
+/// ```turtle
+/// @prefix ex: <http://example.org/> .
+///
+/// ex:alice ex:knows ex:bob .
+/// << ex:alice ex:knows ex:bob >> ex:since "2020" .
+/// ex:carol ex:claims << ex:bob ex:age 23 >> .
+/// ```
+#[allow(dead_code)]
+pub fn rdf_star_graph() -> Graph {
+    let mappings = IriPrefixMap::default().with(
+        Namespace::new_unchecked("ex"),
+        Iri::from_str("http://example.org/").unwrap(),
+    );
+    let ex = |name: &str| Iri::from_str(&format!("http://example.org/{name}")).unwrap();
+
+    let knows = Statement::new(ex("alice"), ex("knows"), ex("bob"));
+    let statements = vec![
+        knows.clone(),
+        Statement::new(knows, ex("since"), Literal::plain("2020")),
+        Statement::new(
+            ex("carol"),
+            ex("claims"),
+            Statement::new(ex("bob"), ex("age"), Literal::from(23_i64)),
+        ),
+    ];
+
+    Graph::default()
+        .with_mappings(mappings)
+        .with_statements(statements)
+}
+
+///
+/// The RDF-star graph above with an added collection-valued statement, so that every kind of
+/// nested node appears.
+///
+#[allow(dead_code)]
+pub fn nested_nodes_graph() -> Graph {
+    let ex = |name: &str| Iri::from_str(&format!("http://example.org/{name}")).unwrap();
+    let mut graph = rdf_star_graph();
+    graph.insert(Statement::new(
+        ex("team"),
+        ex("members"),
+        Collection::from(vec![
+            ObjectNode::from(ex("alice")),
+            ObjectNode::from(ex("bob")),
+        ]),
+    ));
+    graph
+}
+
+///
+/// A small graph of people, with a blank node, plain, language-tagged and typed literals; the
+/// plain literal includes characters that must be escaped in most serializations.
+///
+#[allow(dead_code)]
+pub fn people_graph() -> Graph {
+    let alice = SubjectNode::from(Iri::from_str("http://example.org/people#alice").unwrap());
+    let bob = BlankNode::from_str("bob").unwrap();
+    Graph::default().with_statements(vec![
+        Statement::new(alice.clone(), rdf::a_type().clone(), foaf::person().clone()),
+        Statement::new(alice.clone(), foaf::knows().clone(), bob.clone()),
+        Statement::new(
+            alice,
+            foaf::name().clone(),
+            Literal::plain("Alice <\"Al\"> &\n\tLiddell"),
+        ),
+        Statement::new(
+            bob.clone(),
+            foaf::name().clone(),
+            Literal::with_language("Robert", LanguageTag::from_str("en-GB").unwrap()),
+        ),
+        Statement::new(
+            bob,
+            dc::elements::date().clone(),
+            Literal::with_data_type("1970-01-01", DataType::Date),
+        ),
+    ])
+}
+
+///
+/// A data set with `default` as its default graph, a graph named by an IRI, and a graph named
+/// by a blank node.
+///
+#[allow(dead_code)]
+pub fn people_data_set(default: Graph) -> DataSet {
+    let person = |name: &str| Iri::from_str(&format!("http://example.org/people#{name}")).unwrap();
+    let mut data_set = DataSet::from(default);
+    let mut named = Graph::named(GraphName::Iri(
+        Iri::from_str("http://example.org/graphs#one").unwrap(),
+    ));
+    named.insert(Statement::new(
+        person("carol"),
+        foaf::name().clone(),
+        Literal::plain("Carol"),
+    ));
+    data_set.insert(named);
+    let mut blank = Graph::named(GraphName::BNode(BlankNode::from_str("g2").unwrap()));
+    blank.insert(Statement::new(
+        person("dave"),
+        foaf::name().clone(),
+        Literal::plain("Dave"),
+    ));
+    data_set.insert(blank);
+    data_set
+}
+
+///
+/// Returns `true` if the two graphs are isomorphic, that is they contain the same set of
+/// statements under some one-to-one mapping between their blank nodes.
+///
+#[allow(dead_code)]
+pub fn graphs_isomorphic(lhs: &Graph, rhs: &Graph) -> bool {
+    quads_isomorphic(
+        lhs.statements().map(|st| (st.clone(), None)).collect(),
+        rhs.statements().map(|st| (st.clone(), None)).collect(),
+    )
+}
+
+///
+/// Returns `true` if the two data sets are isomorphic, that is they contain the same set of
+/// quads under some one-to-one mapping between their blank nodes, including blank graph names.
+/// Empty graphs are not significant.
+///
+#[allow(dead_code)]
+pub fn data_sets_isomorphic(lhs: &DataSet, rhs: &DataSet) -> bool {
+    fn quads(data_set: &DataSet) -> HashSet<Quad> {
+        data_set
+            .graphs()
+            .flat_map(|graph| {
+                graph
+                    .statements()
+                    .map(|st| (st.clone(), graph.name().cloned()))
+            })
+            .collect()
+    }
+    quads_isomorphic(quads(lhs), quads(rhs))
+}
+
+type Quad = (Statement, Option<GraphName>);
+
+fn quads_isomorphic(lhs: HashSet<Quad>, rhs: HashSet<Quad>) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let lhs_blanks = blank_node_signatures(&lhs);
+    let rhs_blanks = blank_node_signatures(&rhs);
+    if lhs_blanks.len() != rhs_blanks.len() {
+        return false;
+    }
+
+    let lhs_blanks: Vec<(BlankNode, Vec<String>)> = lhs_blanks.into_iter().collect();
+    let mut mapping = HashMap::default();
+    let mut used = HashSet::default();
+    find_mapping(
+        &lhs,
+        &rhs,
+        &lhs_blanks,
+        &rhs_blanks,
+        &mut mapping,
+        &mut used,
+    )
+}
+
+fn find_mapping(
+    lhs: &HashSet<Quad>,
+    rhs: &HashSet<Quad>,
+    lhs_blanks: &[(BlankNode, Vec<String>)],
+    rhs_blanks: &HashMap<BlankNode, Vec<String>>,
+    mapping: &mut HashMap<BlankNode, BlankNode>,
+    used: &mut HashSet<BlankNode>,
+) -> bool {
+    if let Some(((node, signature), rest)) = lhs_blanks.split_first() {
+        for (candidate, candidate_signature) in rhs_blanks {
+            if !used.contains(candidate) && candidate_signature == signature {
+                let _ = mapping.insert(node.clone(), candidate.clone());
+                let _ = used.insert(candidate.clone());
+                if find_mapping(lhs, rhs, rest, rhs_blanks, mapping, used) {
+                    return true;
+                }
+                let _ = mapping.remove(node);
+                let _ = used.remove(candidate);
+            }
+        }
+        false
+    } else {
+        lhs.iter()
+            .map(|(st, name)| {
+                (
+                    relabel_statement(st, mapping),
+                    relabel_graph_name(name, mapping),
+                )
+            })
+            .all(|quad| rhs.contains(&quad))
+    }
+}
+
+fn blank_node_signatures(quads: &HashSet<Quad>) -> HashMap<BlankNode, Vec<String>> {
+    fn term(node: Option<&BlankNode>, other: String) -> String {
+        if node.is_some() {
+            "_".to_string()
+        } else {
+            other
+        }
+    }
+    let mut signatures: HashMap<BlankNode, Vec<String>> = HashMap::default();
+    for (statement, name) in quads {
+        let subject = statement.subject();
+        let object = statement.object();
+        let graph = match name {
+            None => String::new(),
+            Some(GraphName::BNode(_)) => "_".to_string(),
+            Some(GraphName::Iri(iri)) => iri.to_string(),
+        };
+        if let Some(node) = subject.as_blank() {
+            signatures.entry(node.clone()).or_default().push(format!(
+                "s {} {} {graph}",
+                statement.predicate(),
+                term(object.as_blank(), object.to_string())
+            ));
+        }
+        if let Some(node) = object.as_blank() {
+            signatures.entry(node.clone()).or_default().push(format!(
+                "o {} {} {graph}",
+                statement.predicate(),
+                term(subject.as_blank(), subject.to_string())
+            ));
+        }
+        if let Some(GraphName::BNode(node)) = name {
+            signatures
+                .entry(node.clone())
+                .or_default()
+                .push(format!("g {}", statement.predicate()));
+        }
+    }
+    for signature in signatures.values_mut() {
+        signature.sort();
+    }
+    signatures
+}
+
+fn relabel_graph_name(
+    name: &Option<GraphName>,
+    mapping: &HashMap<BlankNode, BlankNode>,
+) -> Option<GraphName> {
+    match name {
+        Some(GraphName::BNode(node)) => {
+            Some(GraphName::BNode(mapping.get(node).unwrap_or(node).clone()))
+        }
+        other => other.clone(),
+    }
+}
+
+fn relabel_statement(statement: &Statement, mapping: &HashMap<BlankNode, BlankNode>) -> Statement {
+    let subject = match statement.subject() {
+        SubjectNode::Blank(node) => SubjectNode::Blank(mapping.get(node).unwrap_or(node).clone()),
+        SubjectNode::Statement(st) => {
+            SubjectNode::Statement(Arc::new(relabel_statement(st, mapping)))
+        }
+        other => other.clone(),
+    };
+    let object = match statement.object() {
+        ObjectNode::Blank(node) => ObjectNode::Blank(mapping.get(node).unwrap_or(node).clone()),
+        ObjectNode::Statement(st) => {
+            ObjectNode::Statement(Arc::new(relabel_statement(st, mapping)))
+        }
+        other => other.clone(),
+    };
+    Statement::new(subject, statement.predicate().clone(), object)
+}
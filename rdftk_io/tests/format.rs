@@ -0,0 +1,202 @@
+#![cfg(all(feature = "turtle", feature = "nt", feature = "nq", feature = "xml"))]
+
+use rdftk_io::format::{Document, Format};
+
+#[test]
+fn format_from_extension() {
+    assert_eq!(Format::from_extension("ttl"), Some(Format::Turtle));
+    assert_eq!(Format::from_extension(".NT"), Some(Format::NTriples));
+    assert_eq!(Format::from_extension("owl"), Some(Format::Xml));
+    assert_eq!(Format::from_extension("txt"), None);
+    assert_eq!(Format::from_path("data/example.nq"), Some(Format::NQuads));
+    assert_eq!(Format::from_path("data/example"), None);
+}
+
+#[test]
+fn format_from_mime_type() {
+    assert_eq!(
+        Format::from_mime_type("text/turtle; charset=utf-8"),
+        Some(Format::Turtle)
+    );
+    assert_eq!(
+        Format::from_mime_type("Application/RDF+XML"),
+        Some(Format::Xml)
+    );
+    assert_eq!(Format::from_mime_type("text/html"), None);
+}
+
+#[test]
+fn format_from_accept() {
+    assert_eq!(
+        Format::from_accept("text/turtle;q=0.8, application/n-triples"),
+        Some(Format::NTriples)
+    );
+    assert_eq!(
+        Format::from_accept("application/rdf+xml, text/turtle"),
+        Some(Format::Xml)
+    );
+    assert_eq!(
+        Format::from_accept("text/*;q=0.5, text/turtle;q=0, application/n-quads;q=0.4"),
+        Some(Format::TriG)
+    );
+    assert_eq!(Format::from_accept("*/*"), Some(Format::Turtle));
+    assert_eq!(Format::from_accept("text/html, image/*"), None);
+    assert_eq!(Format::from_accept("text/turtle;q=0"), None);
+}
+
+#[test]
+fn format_sniff() {
+    assert_eq!(
+        Format::sniff(b"<?xml version=\"1.0\"?>\n<rdf:RDF/>"),
+        Some(Format::Xml)
+    );
+    assert_eq!(
+        Format::sniff(b"@prefix ex: <http://example.org/> .\nex:a ex:b ex:c ."),
+        Some(Format::Turtle)
+    );
+    assert_eq!(
+        Format::sniff(b"PREFIX ex: <http://example.org/>\nex:g { ex:a ex:b ex:c . }"),
+        Some(Format::TriG)
+    );
+    assert_eq!(
+        Format::sniff(b"# comment\n<http://example/s> <http://example/p> \"o\"@en .\n"),
+        Some(Format::NTriples)
+    );
+    assert_eq!(
+        Format::sniff(b"_:s <http://example/p> \"a \\\" b\" <http://example/g> . # c\n"),
+        Some(Format::NQuads)
+    );
+    assert_eq!(
+        Format::sniff(b"{ \"@context\": {}, \"@id\": \"http://example/s\" }"),
+        Some(Format::JsonLd)
+    );
+    assert_eq!(Format::sniff(b"just some text"), None);
+}
+
+#[test]
+fn format_factories() {
+    for format in Format::all() {
+        assert!(format.graph_writer().is_some(), "{format}");
+        if format.is_data_set() {
+            assert!(format.data_set_reader().is_some(), "{format}");
+        } else {
+            assert!(format.graph_reader().is_some(), "{format}");
+        }
+    }
+}
+
+#[test]
+fn read_path_by_extension() {
+    let document = rdftk_io::read_path("tests/w3c/turtle/IRI_subject.ttl").unwrap();
+    match document {
+        Document::Graph(graph) => assert_eq!(graph.len(), 1),
+        Document::DataSet(_) => panic!("expected a graph"),
+    }
+
+    let document = rdftk_io::read_path("tests/w3c/nq/literal.nq").unwrap();
+    assert!(document.is_data_set());
+}
+
+#[test]
+fn read_path_by_content() {
+    let path = std::env::temp_dir().join("rdftk_io_read_path_by_content");
+    std::fs::write(
+        &path,
+        "<http://example/s> <http://example/p> <http://example/o> .\n",
+    )
+    .unwrap();
+    let document = rdftk_io::read_path(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(document.unwrap().is_graph());
+
+    assert!(rdftk_io::read_path("tests/w3c/nq/README").is_err());
+}
+
+#[test]
+#[cfg(all(
+    feature = "binary",
+    feature = "hdt",
+    feature = "hextuples",
+    feature = "trix"
+))]
+fn format_for_later_formats() {
+    assert_eq!(Format::from_path("data/example.trix"), Some(Format::TriX));
+    assert_eq!(Format::from_extension("hext"), Some(Format::HexTuples));
+    assert_eq!(Format::from_extension("rdfb"), Some(Format::Binary));
+    assert_eq!(Format::from_extension("hdt"), Some(Format::Hdt));
+    assert_eq!(
+        Format::from_mime_type("application/trix"),
+        Some(Format::TriX)
+    );
+    assert_eq!(
+        Format::from_mime_type("application/hex+x-ndjson"),
+        Some(Format::HexTuples)
+    );
+
+    assert_eq!(
+        Format::sniff(b"<?xml version=\"1.0\"?>\n<!-- <rdf:RDF> -->\n<TriX xmlns=\"http://www.w3.org/2004/03/trix/trix-1/\"/>"),
+        Some(Format::TriX)
+    );
+    assert_eq!(
+        Format::sniff(b"<?xml version=\"1.0\"?>\n<rdf:RDF/>"),
+        Some(Format::Xml)
+    );
+    assert_eq!(
+        Format::sniff(b"[\"http://example/s\", \"http://example/p\", \"o\", \"\", \"\", \"\"]\n"),
+        Some(Format::HexTuples)
+    );
+    assert_eq!(
+        Format::sniff(b"$HDT\x01<http://purl.org/HDT/hdt#HDTv1>"),
+        Some(Format::Hdt)
+    );
+    assert_eq!(Format::sniff(b"RDFB\x01"), Some(Format::Binary));
+}
+
+#[test]
+#[cfg(feature = "trix")]
+fn read_path_trix() {
+    let path = std::env::temp_dir().join("rdftk_io_read_path_trix");
+    std::fs::write(
+        &path,
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/">
+  <graph>
+    <triple>
+      <uri>http://example/s</uri>
+      <uri>http://example/p</uri>
+      <plainLiteral>o</plainLiteral>
+    </triple>
+  </graph>
+</TriX>
+"#,
+    )
+    .unwrap();
+    let by_content = rdftk_io::read_path(&path);
+    let with_extension = path.with_extension("trix");
+    std::fs::rename(&path, &with_extension).unwrap();
+    let by_extension = rdftk_io::read_path(&with_extension);
+    std::fs::remove_file(&with_extension).unwrap();
+
+    for document in [by_content, by_extension] {
+        let data_set = rdftk_core::model::data_set::DataSet::from(document.unwrap());
+        assert_eq!(data_set.graphs().map(|graph| graph.len()).sum::<usize>(), 1);
+    }
+}
+
+#[test]
+#[cfg(feature = "json-ld")]
+fn read_path_json_ld_with_named_graphs() {
+    use rdftk_core::model::graph::GraphName;
+    use rdftk_iri::Iri;
+    use std::str::FromStr;
+
+    assert!(Format::JsonLd.is_data_set());
+    let data_set = match rdftk_io::read_path("tests/json_ld/graphs.jsonld").unwrap() {
+        Document::DataSet(data_set) => data_set,
+        Document::Graph(_) => panic!("expected a data set"),
+    };
+    assert_eq!(data_set.len(), 2);
+    assert_eq!(data_set.default_graph().unwrap().len(), 1);
+    let name = GraphName::from(Iri::from_str("http://example.org/graphs/one").unwrap());
+    assert_eq!(data_set.graph(&Some(name)).unwrap().len(), 2);
+}
@@ -0,0 +1,271 @@
+#![cfg(feature = "hdt")]
+
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{BlankNode, ObjectNode, Statement, SubjectNode};
+use rdftk_io::hdt::{HdtFile, HdtReader, HdtWriter, HdtWriterOptions};
+use rdftk_iri::Iri;
+use rdftk_names::{foaf, rdf};
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[cfg(feature = "nt")]
+mod common;
+
+pub mod logging;
+
+fn iri(s: &str) -> Iri {
+    Iri::from_str(s).unwrap()
+}
+
+fn person(n: usize) -> SubjectNode {
+    iri(&format!("http://example.org/people/{n:03}")).into()
+}
+
+fn make_graph() -> Graph {
+    let mut graph = Graph::default();
+    for n in 0..100 {
+        graph.insert(Statement::new(
+            person(n),
+            rdf::a_type().clone(),
+            foaf::person().clone(),
+        ));
+        graph.insert(Statement::new(
+            person(n),
+            foaf::name().clone(),
+            Literal::plain(format!("Person \"{n}\"")),
+        ));
+        graph.insert(Statement::new(
+            person(n),
+            foaf::knows().clone(),
+            person((n + 1) % 100).to_object(),
+        ));
+    }
+    let alice = BlankNode::from_str("alice").unwrap();
+    graph.insert(Statement::new(
+        alice.clone(),
+        foaf::name().clone(),
+        Literal::with_language("Alice", LanguageTag::from_str("en").unwrap()),
+    ));
+    graph.insert(Statement::new(
+        alice.clone(),
+        foaf::age().clone(),
+        Literal::with_data_type(
+            "42",
+            DataType::from(iri("http://www.w3.org/2001/XMLSchema#integer")),
+        ),
+    ));
+    graph.insert(Statement::new(person(7), foaf::knows().clone(), alice));
+    graph
+}
+
+///
+/// `people.hdt` was not written by this crate, see `hdt/make_fixture.py` for how it was made.
+///
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/hdt")
+        .join(name)
+}
+
+fn write(graph: &Graph) -> Vec<u8> {
+    logging::try_init();
+    let writer = HdtWriter::default().with_options(HdtWriterOptions::default().with_block_size(4));
+    let mut buffer = Vec::default();
+    writer.write(&mut buffer, graph).unwrap();
+    buffer
+}
+
+fn collect<I>(statements: I) -> HashSet<Statement>
+where
+    I: Iterator<Item = Result<Statement, rdftk_core::error::Error>>,
+{
+    statements.map(|statement| statement.unwrap()).collect()
+}
+
+#[test]
+fn write_and_read_round_trip() {
+    let graph = make_graph();
+    let buffer = write(&graph);
+    assert_eq!(&buffer[..4], b"$HDT");
+
+    let result: Graph = HdtReader::default().read(&mut buffer.as_slice()).unwrap();
+    assert_eq!(result.len(), graph.len());
+    for statement in graph.statements() {
+        assert!(result.contains(statement), "missing {statement}");
+    }
+}
+
+#[test]
+fn file_matches_subject() {
+    let file = HdtFile::new(Cursor::new(write(&make_graph()))).unwrap();
+    assert_eq!(file.len(), 303);
+
+    let found = collect(file.matches(Some(&person(7)), None, None).unwrap());
+    assert_eq!(found.len(), 4);
+    assert!(found
+        .iter()
+        .all(|statement| statement.subject() == &person(7)));
+    assert!(found.contains(&Statement::new(
+        person(7),
+        foaf::knows().clone(),
+        person(8).to_object(),
+    )));
+
+    let found = collect(
+        file.matches(Some(&person(99)), Some(foaf::knows()), None)
+            .unwrap(),
+    );
+    assert_eq!(
+        found,
+        [Statement::new(
+            person(99),
+            foaf::knows().clone(),
+            person(0).to_object()
+        )]
+        .into_iter()
+        .collect()
+    );
+}
+
+#[test]
+fn file_matches_predicate_and_object() {
+    let file = HdtFile::new(Cursor::new(write(&make_graph()))).unwrap();
+
+    let found = collect(file.matches(None, Some(foaf::name()), None).unwrap());
+    assert_eq!(found.len(), 101);
+
+    let found = collect(
+        file.matches(None, None, Some(&person(0).to_object()))
+            .unwrap(),
+    );
+    assert_eq!(
+        found,
+        [Statement::new(
+            person(99),
+            foaf::knows().clone(),
+            person(0).to_object()
+        )]
+        .into_iter()
+        .collect()
+    );
+
+    let english = ObjectNode::from(Literal::with_language(
+        "Alice",
+        LanguageTag::from_str("en").unwrap(),
+    ));
+    let found = collect(file.matches(None, None, Some(&english)).unwrap());
+    assert_eq!(found.len(), 1);
+    assert!(found.iter().all(|statement| statement.subject().is_blank()));
+}
+
+#[test]
+fn file_matches_missing_term() {
+    let file = HdtFile::new(Cursor::new(write(&make_graph()))).unwrap();
+
+    assert_eq!(
+        file.matches(Some(&person(100)), None, None)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert_eq!(
+        file.matches(None, Some(foaf::mbox()), None)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert_eq!(
+        file.matches(None, None, Some(&Literal::plain("Nobody").into()))
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn read_fixture() {
+    let file = HdtFile::open(fixture("people.hdt")).unwrap();
+    assert_eq!(file.len(), 34);
+
+    let bob = SubjectNode::from(iri("http://example.org/people/bob"));
+    let found = collect(file.matches(Some(&bob), None, None).unwrap());
+    assert_eq!(
+        found,
+        [
+            Statement::new(bob.clone(), rdf::a_type().clone(), foaf::person().clone()),
+            Statement::new(
+                bob.clone(),
+                foaf::name().clone(),
+                Literal::plain("Bob \"the builder\""),
+            ),
+            Statement::new(
+                bob.clone(),
+                foaf::age().clone(),
+                Literal::with_data_type(
+                    "42",
+                    DataType::from(iri("http://www.w3.org/2001/XMLSchema#integer")),
+                ),
+            ),
+            Statement::new(
+                bob.clone(),
+                foaf::knows().clone(),
+                iri("http://example.org/people/alice"),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    );
+
+    let found = collect(
+        file.matches(None, Some(foaf::knows()), Some(&bob.to_object()))
+            .unwrap(),
+    );
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().any(|statement| statement.subject().is_blank()));
+
+    let found = collect(
+        file.matches(None, None, Some(&Literal::plain("Person 12").into()))
+            .unwrap(),
+    );
+    assert_eq!(
+        found,
+        [Statement::new(
+            iri("http://example.org/people/012"),
+            foaf::name().clone(),
+            Literal::plain("Person 12"),
+        )]
+        .into_iter()
+        .collect()
+    );
+}
+
+#[test]
+#[cfg(feature = "nt")]
+fn read_fixture_matches_ntriples() {
+    use rdftk_io::nt::NTripleReader;
+
+    let result: Graph = HdtReader::default()
+        .read(&mut std::fs::File::open(fixture("people.hdt")).unwrap())
+        .unwrap();
+    let expected: Graph = NTripleReader::default()
+        .read(&mut std::fs::File::open(fixture("people.nt")).unwrap())
+        .unwrap();
+    assert_eq!(result.len(), expected.len());
+    assert!(common::graphs_isomorphic(&result, &expected));
+}
+
+#[test]
+fn read_not_hdt_fails() {
+    let result: Result<Graph, _> = HdtReader::default().read(
+        &mut "<http://example.org/a> <http://example.org/b> <http://example.org/c> .".as_bytes(),
+    );
+    assert!(result.is_err());
+
+    let mut buffer = write(&make_graph());
+    buffer[6] ^= 0xFF;
+    assert!(HdtFile::new(Cursor::new(buffer)).is_err());
+}
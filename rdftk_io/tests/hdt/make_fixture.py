@@ -0,0 +1,229 @@
+#!/usr/bin/env python3
+"""
+Writes people.hdt from people.nt.
+
+This is a minimal, standalone HDT encoder that follows the on-disk layout of the reference
+implementation, hdt-cpp: control information protected by CRC16, a Four Section dictionary of
+Plain Front Coding sections, and Bitmap Triples in SPO order; log sequences and bitmaps
+are protected by CRC8 headers and CRC32C data. It does not use any code from this crate, so
+that the fixture checks the reader against the format rather than against the crate's own
+writer.
+
+Usage: python3 make_fixture.py
+"""
+
+import os
+import re
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+BLOCK_SIZE = 16
+BASE_URI = "http://example.org/people"
+
+# -----------------------------------------------------------------------------------------------
+# Checksums
+# -----------------------------------------------------------------------------------------------
+
+
+def crc8(data):
+    # CRC-8/SMBUS, polynomial 0x07, no reflection, initial value 0.
+    crc = 0
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x07) & 0xFF if crc & 0x80 else (crc << 1) & 0xFF
+    return bytes([crc])
+
+
+def crc16(data):
+    # CRC-16/ARC, reflected polynomial 0xA001, initial value 0.
+    crc = 0
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = (crc >> 1) ^ 0xA001 if crc & 1 else crc >> 1
+    return crc.to_bytes(2, "little")
+
+
+def crc32c(data):
+    # CRC-32C (Castagnoli), reflected polynomial 0x82F63B78.
+    crc = 0xFFFFFFFF
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = (crc >> 1) ^ 0x82F63B78 if crc & 1 else crc >> 1
+    return (crc ^ 0xFFFFFFFF).to_bytes(4, "little")
+
+
+# -----------------------------------------------------------------------------------------------
+# Encodings
+# -----------------------------------------------------------------------------------------------
+
+
+def vbyte(value):
+    # Seven bits per byte, least significant first, the high bit marks the final byte.
+    out = bytearray()
+    while value > 127:
+        out.append(value & 127)
+        value >>= 7
+    out.append(value | 0x80)
+    return bytes(out)
+
+
+def bits(value):
+    return value.bit_length()
+
+
+def pack_bits(values, width):
+    packed = 0
+    for i, value in enumerate(values):
+        packed |= value << (i * width)
+    return packed.to_bytes((len(values) * width + 7) // 8, "little")
+
+
+def control_info(control_type, format, properties):
+    out = b"$HDT" + bytes([control_type]) + format.encode() + b"\0"
+    out += "".join(f"{k}={v};" for k, v in properties).encode() + b"\0"
+    return out + crc16(out)
+
+
+def log_sequence(values):
+    width = bits(max(values, default=0))
+    header = bytes([1, width]) + vbyte(len(values))
+    data = pack_bits(values, width)
+    return header + crc8(header) + data + crc32c(data)
+
+
+def bitmap(flags):
+    header = bytes([1]) + vbyte(len(flags))
+    data = pack_bits([int(flag) for flag in flags], 1)
+    return header + crc8(header) + data + crc32c(data)
+
+
+def front_coded(strings):
+    data = bytearray()
+    offsets = []
+    previous = b""
+    for i, string in enumerate(strings):
+        string = string.encode()
+        if i % BLOCK_SIZE == 0:
+            offsets.append(len(data))
+            data += string
+        else:
+            shared = 0
+            while (
+                shared < min(len(string), len(previous))
+                and string[shared] == previous[shared]
+            ):
+                shared += 1
+            data += vbyte(shared) + string[shared:]
+        data += b"\0"
+        previous = string
+    offsets.append(len(data))
+    header = bytes([2]) + vbyte(len(strings)) + vbyte(len(data)) + vbyte(BLOCK_SIZE)
+    return header + crc8(header) + log_sequence(offsets) + bytes(data) + crc32c(bytes(data))
+
+
+# -----------------------------------------------------------------------------------------------
+# N-Triples terms, as stored by HDT
+# -----------------------------------------------------------------------------------------------
+
+TERM = re.compile(r'<([^>]*)>|(_:\S+)|"((?:[^"\\]|\\.)*)"(@[A-Za-z0-9-]+|\^\^<[^>]*>)?')
+
+
+def unescape(lexical):
+    return re.sub(r"\\(.)", lambda m: {"n": "\n", "r": "\r", "t": "\t"}.get(m[1], m[1]), lexical)
+
+
+def read_triples(path):
+    triples = []
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            terms = []
+            for match in TERM.finditer(line):
+                iri, blank, lexical, suffix = match.groups()
+                if iri is not None:
+                    terms.append(iri)
+                elif blank is not None:
+                    terms.append(blank)
+                else:
+                    # literals are stored with their lexical form unescaped.
+                    suffix = suffix or ""
+                    if suffix.startswith("^^"):
+                        suffix = "^^<" + suffix[3:-1] + ">"
+                    terms.append('"' + unescape(lexical) + '"' + suffix)
+            assert len(terms) == 3, line
+            triples.append(tuple(terms))
+    return triples
+
+
+# -----------------------------------------------------------------------------------------------
+# Main
+# -----------------------------------------------------------------------------------------------
+
+
+def main():
+    triples = sorted(set(read_triples(os.path.join(HERE, "people.nt"))))
+
+    subjects = {s for s, _, _ in triples}
+    objects = {o for _, _, o in triples}
+    shared = sorted(subjects & objects, key=str.encode)
+    subjects_only = sorted(subjects - objects, key=str.encode)
+    objects_only = sorted(objects - subjects, key=str.encode)
+    predicates = sorted({p for _, p, _ in triples}, key=str.encode)
+
+    subject_id = {s: i + 1 for i, s in enumerate(shared + subjects_only)}
+    object_id = {o: i + 1 for i, o in enumerate(shared + objects_only)}
+    predicate_id = {p: i + 1 for i, p in enumerate(predicates)}
+
+    ids = sorted((subject_id[s], predicate_id[p], object_id[o]) for s, p, o in triples)
+
+    array_y, bitmap_y, array_z, bitmap_z = [], [], [], []
+    for i, (s, p, o) in enumerate(ids):
+        following = ids[i + 1] if i + 1 < len(ids) else None
+        array_z.append(o)
+        bitmap_z.append(following is None or following[:2] != (s, p))
+        if following is None or following[:2] != (s, p):
+            array_y.append(p)
+            bitmap_y.append(following is None or following[0] != s)
+
+    strings = shared + subjects_only + predicates + objects_only
+    size_strings = sum(len(string.encode()) for string in strings)
+
+    header = "".join(
+        f"{s} {p} {o} .\n"
+        for s, p, o in [
+            (f"<{BASE_URI}>", "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>",
+             "<http://purl.org/HDT/hdt#Dataset>"),
+            (f"<{BASE_URI}>", "<http://rdfs.org/ns/void#triples>", f'"{len(ids)}"'),
+            (f"<{BASE_URI}>", "<http://rdfs.org/ns/void#properties>", f'"{len(predicates)}"'),
+            (f"<{BASE_URI}>", "<http://rdfs.org/ns/void#distinctSubjects>", f'"{len(subjects)}"'),
+            (f"<{BASE_URI}>", "<http://rdfs.org/ns/void#distinctObjects>", f'"{len(objects)}"'),
+        ]
+    ).encode()
+
+    out = control_info(1, "<http://purl.org/HDT/hdt#HDTv1>", [])
+    out += control_info(2, "ntriples", [("length", len(header))]) + header
+    out += control_info(
+        3,
+        "<http://purl.org/HDT/hdt#dictionaryFour>",
+        [("mapping", 1), ("sizeStrings", size_strings)],
+    )
+    for section in (shared, subjects_only, predicates, objects_only):
+        out += front_coded(section)
+    out += control_info(
+        4,
+        "<http://purl.org/HDT/hdt#triplesBitmap>",
+        [("numTriples", len(ids)), ("order", 1)],
+    )
+    out += bitmap(bitmap_y) + bitmap(bitmap_z) + log_sequence(array_y) + log_sequence(array_z)
+
+    with open(os.path.join(HERE, "people.hdt"), "wb") as f:
+        f.write(out)
+
+
+if __name__ == "__main__":
+    main()
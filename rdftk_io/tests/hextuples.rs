@@ -0,0 +1,116 @@
+#![cfg(feature = "hextuples")]
+
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::Statement;
+use rdftk_io::hextuples::{HexTuplesReader, HexTuplesWriter};
+use rdftk_io::{ReaderOptions, StatementSink};
+use rdftk_iri::Iri;
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+mod common;
+pub mod logging;
+
+fn iri(s: &str) -> Iri {
+    Iri::from_str(s).unwrap()
+}
+
+#[test]
+fn write_and_read_graph() {
+    logging::try_init();
+    let graph = common::people_graph();
+    let ndjson = HexTuplesWriter::default().write_to_string(&graph).unwrap();
+    println!("{ndjson}");
+    assert_eq!(ndjson.lines().count(), 5);
+    assert!(ndjson.contains(
+        r#"["_:bob","http://xmlns.com/foaf/0.1/name","Robert","http://www.w3.org/1999/02/22-rdf-syntax-ns#langString","en-GB",""]"#
+    ));
+    assert!(ndjson.contains(
+        r#"["http://example.org/people#alice","http://xmlns.com/foaf/0.1/knows","_:bob","localId","",""]"#
+    ));
+
+    let result: Graph = HexTuplesReader::default()
+        .read_from_string(&ndjson)
+        .unwrap();
+    assert!(common::graphs_isomorphic(&graph, &result));
+}
+
+#[test]
+fn write_and_read_data_set() {
+    logging::try_init();
+    let data_set = common::people_data_set(common::people_graph());
+
+    let ndjson = HexTuplesWriter::default()
+        .write_to_string(&data_set)
+        .unwrap();
+    let result: DataSet = HexTuplesReader::default()
+        .read_from_string(&ndjson)
+        .unwrap();
+    assert_eq!(result.len(), 3);
+    assert!(common::data_sets_isomorphic(&data_set, &result));
+
+    let graph: Result<Graph, _> = HexTuplesReader::default().read_from_string(&ndjson);
+    assert!(graph.is_err());
+}
+
+#[test]
+fn stream_through_sink_and_quads() {
+    logging::try_init();
+    let graph_name = GraphName::Iri(iri("http://example.org/graphs#one"));
+    let statement = Statement::new(
+        iri("http://example.org/people#carol"),
+        foaf::name().clone(),
+        Literal::plain("Carol"),
+    );
+    let mut buffer = Vec::default();
+    let mut sink = HexTuplesWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    sink.quad(&statement, Some(&graph_name)).unwrap();
+    sink.statement(&statement).unwrap();
+    sink.finish().unwrap();
+
+    let quads: Vec<_> = HexTuplesReader::default()
+        .quads(buffer.as_slice())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        quads,
+        vec![(statement.clone(), Some(graph_name)), (statement, None)]
+    );
+}
+
+#[test]
+fn read_invalid_lines() {
+    logging::try_init();
+    let source = r#"["http://example.org/a", "http://example.org/b", "c", "", "", ""]
+
+["http://example.org/a", "http://example.org/b"]
+["http://example.org/a", "http://example.org/b", "c", "", "", 1]
+["http://example.org/a", "http://example.org/b", "c", "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString", "", ""]
+["http://example.org/a", "http://example.org/b", "c", "", "", ""
+"#;
+    let errors: Vec<_> = HexTuplesReader::default()
+        .quads(source.as_bytes())
+        .filter_map(Result::err)
+        .collect();
+    assert_eq!(errors.len(), 4);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| e.location().unwrap().line())
+            .collect::<Vec<_>>(),
+        vec![3, 4, 5, 6]
+    );
+
+    let result: Result<Graph, _> = HexTuplesReader::default().read_from_string(source);
+    assert!(result.is_err());
+
+    let graph: Graph = HexTuplesReader::default()
+        .with_options(ReaderOptions::default().with_lenient(true))
+        .read_from_string(source)
+        .unwrap();
+    assert_eq!(graph.len(), 1);
+}
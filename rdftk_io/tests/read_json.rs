@@ -63,3 +63,85 @@ fn read_example_03() {
     println!("{:?}", graph);
     assert_eq!(graph.len(), 2);
 }
+
+#[test]
+fn syntax_error_has_location() {
+    let mut json = r##"{
+  "http://example.org/about" : {
+      "http://purl.org/dc/terms/title" : [ { "value" : "Anna's Homepage",
+                                             "type" : literal } ]
+  }
+}"##
+    .as_bytes();
+
+    let reader = JsonReader::default();
+    let error = reader.read(&mut json).unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 4);
+    assert_eq!(location.column(), 55);
+    assert!(location.source_line().ends_with("\"type\" : literal } ]"));
+}
+
+#[test]
+fn unknown_object_type_error_has_member_path() {
+    let mut json = r##"{
+  "http://example.org/about" : {
+      "http://purl.org/dc/terms/title" : [ { "value" : "Anna's Homepage",
+                                             "type" : "literal" },
+                                           { "value" : "Anna",
+                                             "type" : "text" } ]
+  }
+}"##
+    .as_bytes();
+
+    let reader = JsonReader::default();
+    let error = reader.read(&mut json).unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 6);
+    assert_eq!(location.column(), 55);
+    assert!(error.to_string().starts_with(
+        "JSON syntax error at line 6, column 55: unknown object type `text`, expecting one of \
+         `literal`, `bnode` or `uri` for member \
+         `/http:~1~1example.org~1about/http:~1~1purl.org~1dc~1terms~1title/1/type`\n"
+    ));
+}
+
+#[test]
+fn structural_errors_have_member_path() {
+    let reader = JsonReader::default();
+    for (source, line, column, pointer) in [
+        ("[]", 1, 1, "the document"),
+        (
+            "{ \"http://example.org/s\" : [] }",
+            1,
+            28,
+            "member `/http:~1~1example.org~1s`",
+        ),
+        (
+            "{ \"http://example.org/s\" : {\n  \"http://example.org/p\" : {} } }",
+            2,
+            28,
+            "member `/http:~1~1example.org~1s/http:~1~1example.org~1p`",
+        ),
+        (
+            "{ \"http://example.org/s\" : {\n  \"http://example.org/p\" : [ { \"type\" : \"uri\" } ] } }",
+            2,
+            30,
+            "member `/http:~1~1example.org~1s/http:~1~1example.org~1p/0`",
+        ),
+        (
+            "{ \"http://example.org/s\" : {\n  \"http://example.org/p\" : [ { \"type\" : \"uri\", \"value\" : 1 } ] } }",
+            2,
+            58,
+            "member `/http:~1~1example.org~1s/http:~1~1example.org~1p/0/value`",
+        ),
+    ] {
+        let error = reader.read(&mut source.as_bytes()).unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line(), location.column()), (line, column), "{error}");
+        assert!(
+            error.to_string().lines().next().unwrap().ends_with(pointer),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,375 @@
+#![cfg(all(feature = "json-ld", feature = "nq"))]
+
+use objio::ObjectReader;
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::json_ld::{FileDocumentLoader, JsonLdReader, JsonLdReaderOptions};
+use rdftk_io::nq::NQuadReader;
+use rdftk_iri::Iri;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+mod common;
+
+fn test_files() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/json_ld")
+}
+
+fn reader() -> JsonLdReader {
+    let loader = FileDocumentLoader::default().with_mapping(
+        "https://example.org/contexts/",
+        test_files().join("contexts"),
+    );
+    JsonLdReader::default().with_options(
+        JsonLdReaderOptions::default()
+            .with_base(Iri::from_str("https://example.org/").unwrap())
+            .with_loader(loader),
+    )
+}
+
+fn read(source: &str) -> DataSet {
+    let result: Result<DataSet, Error> = reader().read_from_string(source);
+    match result {
+        Ok(data_set) => data_set,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+fn quads(source: &str) -> DataSet {
+    NQuadReader::default().read_from_string(source).unwrap()
+}
+
+fn assert_reads_as(json_ld: &str, n_quads: &str) {
+    let actual = read(json_ld);
+    let expected = quads(n_quads);
+    assert!(
+        common::data_sets_isomorphic(&actual, &expected),
+        "actual: {actual:#?}\nexpected: {expected:#?}"
+    );
+}
+
+fn expand(document: Value) -> Value {
+    reader().expand(&document).unwrap()
+}
+
+#[test]
+fn read_inline_context() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "name": "http://xmlns.com/foaf/0.1/name",
+    "homepage": { "@id": "http://xmlns.com/foaf/0.1/homepage", "@type": "@id" }
+  },
+  "@id": "people/alice",
+  "name": "Alice",
+  "homepage": "http://alice.example.org/"
+}"#,
+        r#"<https://example.org/people/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+<https://example.org/people/alice> <http://xmlns.com/foaf/0.1/homepage> <http://alice.example.org/> .
+"#,
+    );
+}
+
+#[test]
+fn read_remote_context_from_file() {
+    let graph: Graph = reader()
+        .read_from_file(test_files().join("alice.jsonld"))
+        .unwrap();
+    let expected: DataSet = quads(
+        r#"<https://example.org/people/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> .
+<https://example.org/people/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+<https://example.org/people/alice> <http://xmlns.com/foaf/0.1/birthday> "1990-01-01"^^<http://www.w3.org/2001/XMLSchema#date> .
+<https://example.org/people/alice> <http://xmlns.com/foaf/0.1/age> "34"^^<http://www.w3.org/2001/XMLSchema#integer> .
+<https://example.org/people/alice> <http://xmlns.com/foaf/0.1/knows> <https://example.org/people/bob> .
+"#,
+    );
+    assert!(common::graphs_isomorphic(
+        &graph,
+        expected.default_graph().unwrap()
+    ));
+}
+
+#[test]
+fn read_imported_context() {
+    assert_reads_as(
+        r#"{
+  "@context": "https://example.org/contexts/imports.jsonld",
+  "@id": "https://example.org/people/bob",
+  "nick": ["bobby", "rob"],
+  "knows": "https://example.org/people/alice"
+}"#,
+        r#"<https://example.org/people/bob> <http://xmlns.com/foaf/0.1/nick> "bobby" .
+<https://example.org/people/bob> <http://xmlns.com/foaf/0.1/nick> "rob" .
+<https://example.org/people/bob> <http://xmlns.com/foaf/0.1/knows> <https://example.org/people/alice> .
+"#,
+    );
+}
+
+#[test]
+fn read_remote_context_without_loader_fails() {
+    let reader = JsonLdReader::default();
+    let result: Result<DataSet, Error> = reader.read_from_string(
+        r#"{
+  "@context": "https://example.org/contexts/person.jsonld",
+  "name": "Alice"
+}"#,
+    );
+    let error = result.unwrap_err();
+    assert!(matches!(&error, Error::Io(_)), "{error}");
+    assert!(error.to_string().contains(
+        "no document loader configured to load <https://example.org/contexts/person.jsonld>"
+    ));
+}
+
+#[test]
+fn read_named_graphs() {
+    assert_reads_as(
+        r#"{
+  "@context": { "@vocab": "http://example.org/vocab#" },
+  "@graph": [
+    {
+      "@id": "http://example.org/graphs/one",
+      "@graph": { "@id": "http://example.org/a", "value": 1 }
+    },
+    { "@id": "http://example.org/b", "value": true }
+  ]
+}"#,
+        r#"<http://example.org/a> <http://example.org/vocab#value> "1"^^<http://www.w3.org/2001/XMLSchema#integer> <http://example.org/graphs/one> .
+<http://example.org/b> <http://example.org/vocab#value> "true"^^<http://www.w3.org/2001/XMLSchema#boolean> .
+"#,
+    );
+}
+
+#[test]
+fn read_named_graphs_as_graph_fails() {
+    let result: Result<Graph, Error> = reader().read_from_string(
+        r#"{
+  "@id": "http://example.org/graphs/one",
+  "@graph": { "@id": "http://example.org/a", "http://example.org/p": "o" }
+}"#,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_list_and_set() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "@vocab": "http://example.org/vocab#",
+    "ordered": { "@container": "@list" },
+    "unordered": { "@container": "@set" }
+  },
+  "@id": "http://example.org/s",
+  "ordered": ["a", "b"],
+  "unordered": ["c", "c", "d"],
+  "empty": { "@list": [] }
+}"#,
+        r#"<http://example.org/s> <http://example.org/vocab#ordered> _:l1 .
+_:l1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "a" .
+_:l1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:l2 .
+_:l2 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "b" .
+_:l2 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .
+<http://example.org/s> <http://example.org/vocab#unordered> "c" .
+<http://example.org/s> <http://example.org/vocab#unordered> "d" .
+<http://example.org/s> <http://example.org/vocab#empty> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .
+"#,
+    );
+}
+
+#[test]
+fn read_reverse_properties() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "name": "http://xmlns.com/foaf/0.1/name",
+    "children": { "@reverse": "http://example.org/vocab#parent" }
+  },
+  "@id": "http://example.org/homer",
+  "name": "Homer",
+  "children": [{ "@id": "http://example.org/bart" }, { "name": "Lisa" }],
+  "@reverse": { "http://example.org/vocab#spouse": { "@id": "http://example.org/marge" } }
+}"#,
+        r#"<http://example.org/homer> <http://xmlns.com/foaf/0.1/name> "Homer" .
+<http://example.org/bart> <http://example.org/vocab#parent> <http://example.org/homer> .
+_:lisa <http://xmlns.com/foaf/0.1/name> "Lisa" .
+_:lisa <http://example.org/vocab#parent> <http://example.org/homer> .
+<http://example.org/marge> <http://example.org/vocab#spouse> <http://example.org/homer> .
+"#,
+    );
+}
+
+#[test]
+fn read_type_coercion() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "@vocab": "http://example.org/vocab#",
+    "xsd": "http://www.w3.org/2001/XMLSchema#",
+    "ref": { "@type": "@id" },
+    "term": { "@type": "@vocab" },
+    "when": { "@type": "xsd:dateTime" },
+    "data": { "@type": "@json" }
+  },
+  "@id": "http://example.org/s",
+  "ref": "other",
+  "term": "Thing",
+  "when": "2024-01-01T00:00:00Z",
+  "weight": 2.5,
+  "data": { "b": [1, 2], "a": null }
+}"#,
+        r#"<http://example.org/s> <http://example.org/vocab#ref> <https://example.org/other> .
+<http://example.org/s> <http://example.org/vocab#term> <http://example.org/vocab#Thing> .
+<http://example.org/s> <http://example.org/vocab#when> "2024-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+<http://example.org/s> <http://example.org/vocab#weight> "2.5E0"^^<http://www.w3.org/2001/XMLSchema#double> .
+<http://example.org/s> <http://example.org/vocab#data> "{\"a\":null,\"b\":[1,2]}"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON> .
+"#,
+    );
+}
+
+#[test]
+fn read_language_maps() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "@language": "en",
+    "label": { "@id": "http://www.w3.org/2000/01/rdf-schema#label", "@container": "@language" },
+    "comment": "http://www.w3.org/2000/01/rdf-schema#comment",
+    "code": { "@id": "http://example.org/vocab#code", "@language": null }
+  },
+  "@id": "http://example.org/s",
+  "label": { "en": "Queen", "de": ["Königin"], "@none": "Regina" },
+  "comment": "A monarch",
+  "code": "Q1"
+}"#,
+        r#"<http://example.org/s> <http://www.w3.org/2000/01/rdf-schema#label> "Queen"@en .
+<http://example.org/s> <http://www.w3.org/2000/01/rdf-schema#label> "Königin"@de .
+<http://example.org/s> <http://www.w3.org/2000/01/rdf-schema#label> "Regina" .
+<http://example.org/s> <http://www.w3.org/2000/01/rdf-schema#comment> "A monarch"@en .
+<http://example.org/s> <http://example.org/vocab#code> "Q1" .
+"#,
+    );
+}
+
+#[test]
+fn read_included_and_id_maps() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "@vocab": "http://example.org/vocab#",
+    "members": { "@container": "@id" },
+    "tags": { "@container": "@index" }
+  },
+  "@id": "http://example.org/team",
+  "members": {
+    "http://example.org/alice": { "name": "Alice" }
+  },
+  "tags": { "first": "red", "second": "blue" },
+  "@included": [{ "@id": "http://example.org/bob", "name": "Bob" }]
+}"#,
+        r#"<http://example.org/team> <http://example.org/vocab#members> <http://example.org/alice> .
+<http://example.org/alice> <http://example.org/vocab#name> "Alice" .
+<http://example.org/team> <http://example.org/vocab#tags> "red" .
+<http://example.org/team> <http://example.org/vocab#tags> "blue" .
+<http://example.org/bob> <http://example.org/vocab#name> "Bob" .
+"#,
+    );
+}
+
+#[test]
+fn read_scoped_contexts_and_nesting() {
+    assert_reads_as(
+        r#"{
+  "@context": {
+    "@vocab": "http://example.org/vocab#",
+    "Book": { "@id": "http://example.org/vocab#Book", "@context": { "title": "http://purl.org/dc/terms/title" } },
+    "author": { "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" } },
+    "details": "@nest"
+  },
+  "@id": "http://example.org/book",
+  "@type": "Book",
+  "details": { "title": "Moby Dick" },
+  "author": { "name": "Herman Melville" }
+}"#,
+        r#"<http://example.org/book> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/vocab#Book> .
+<http://example.org/book> <http://purl.org/dc/terms/title> "Moby Dick" .
+<http://example.org/book> <http://example.org/vocab#author> _:a .
+_:a <http://xmlns.com/foaf/0.1/name> "Herman Melville" .
+"#,
+    );
+}
+
+#[test]
+fn expand_document() {
+    let expanded = expand(json!({
+        "@context": {
+            "name": "http://xmlns.com/foaf/0.1/name",
+            "knows": { "@id": "http://xmlns.com/foaf/0.1/knows", "@type": "@id" }
+        },
+        "@id": "http://example.org/alice",
+        "name": "Alice",
+        "knows": ["http://example.org/bob"],
+        "ignored": "not a term"
+    }));
+    assert_eq!(
+        expanded,
+        json!([{
+            "@id": "http://example.org/alice",
+            "http://xmlns.com/foaf/0.1/name": [{ "@value": "Alice" }],
+            "http://xmlns.com/foaf/0.1/knows": [{ "@id": "http://example.org/bob" }]
+        }])
+    );
+}
+
+#[test]
+fn expand_with_expand_context() {
+    let reader = JsonLdReader::default().with_options(
+        JsonLdReaderOptions::default()
+            .with_expand_context(json!({ "@vocab": "http://example.org/vocab#" })),
+    );
+    let expanded = reader
+        .expand(&json!({ "@id": "http://example.org/s", "p": "o" }))
+        .unwrap();
+    assert_eq!(
+        expanded,
+        json!([{
+            "@id": "http://example.org/s",
+            "http://example.org/vocab#p": [{ "@value": "o" }]
+        }])
+    );
+}
+
+#[test]
+fn expand_protected_term_redefinition_fails() {
+    let result = reader().expand(&json!({
+        "@context": [
+            { "@protected": true, "name": "http://xmlns.com/foaf/0.1/name" },
+            { "name": "http://schema.org/name" }
+        ],
+        "name": "Alice"
+    }));
+    let error = result.unwrap_err();
+    assert!(
+        matches!(&error, Error::Processing { code, value, .. }
+            if code == "protected term redefinition" && value == "name"),
+        "{error}"
+    );
+    assert_eq!(
+        error.to_string(),
+        "JSON-LD processing error, protected term redefinition: `name`."
+    );
+}
+
+#[test]
+fn syntax_error_has_location() {
+    let reader = JsonLdReader::default();
+    let result: Result<DataSet, Error> =
+        reader.read_from_string("{\n  \"@id\": \"http://example.org/a\",\n  \"@type\" \"x\"\n}");
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 3);
+    assert_eq!(location.column(), 11);
+    assert_eq!(location.source_line(), "  \"@type\" \"x\"");
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "microdata")]
+
+use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_io::microdata::MicrodataReader;
+use rdftk_io::ReaderOptions;
+use rdftk_iri::Iri;
+use rdftk_names::rdf;
+use std::str::FromStr;
+
+pub mod logging;
+
+fn iri(s: &str) -> Iri {
+    Iri::from_str(s).unwrap()
+}
+
+fn read(html: &str) -> Graph {
+    logging::try_init();
+    let reader = MicrodataReader::default()
+        .with_options(ReaderOptions::default().with_base(iri("http://example.org/page")));
+    let graph: Graph = reader.read_from_string(html).unwrap();
+    println!("{graph:#?}");
+    graph
+}
+
+fn objects<'a>(graph: &'a Graph, subject: &SubjectNode, predicate: &str) -> Vec<&'a ObjectNode> {
+    graph
+        .objects_for(subject, &iri(predicate))
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn read_item_with_itemid_and_values() {
+    let graph = read(
+        r##"<html lang="en">
+  <body>
+    <div itemscope itemid="#event" itemtype="https://schema.org/Event">
+      <h1 itemprop="name">Launch</h1>
+      <time itemprop="startDate" datetime="2024-05-01T19:00:00Z">May 1st</time>
+      <meta itemprop="inLanguage" content="en-GB">
+      <data itemprop="maximumAttendeeCapacity" value="120">lots</data>
+      <img itemprop="image" src="launch.png">
+      <span itemprop="https://example.org/ns#note" lang="fr">bonjour</span>
+    </div>
+  </body>
+</html>"##,
+    );
+
+    let event = SubjectNode::from(iri("http://example.org/page#event"));
+    let english = LanguageTag::from_str("en").unwrap();
+    assert_eq!(graph.len(), 7);
+    assert!(graph.contains(&Statement::new(
+        event.clone(),
+        rdf::a_type().clone(),
+        iri("https://schema.org/Event"),
+    )));
+    assert_eq!(
+        objects(&graph, &event, "https://schema.org/name"),
+        vec![&ObjectNode::from(Literal::with_language(
+            "Launch",
+            english.clone()
+        ))]
+    );
+    assert_eq!(
+        objects(&graph, &event, "https://schema.org/startDate"),
+        vec![&ObjectNode::from(Literal::with_data_type(
+            "2024-05-01T19:00:00Z",
+            DataType::DateTime
+        ))]
+    );
+    assert_eq!(
+        objects(&graph, &event, "https://schema.org/inLanguage"),
+        vec![&ObjectNode::from(Literal::with_language("en-GB", english))]
+    );
+    assert_eq!(
+        objects(&graph, &event, "https://schema.org/maximumAttendeeCapacity"),
+        vec![&ObjectNode::from(Literal::with_data_type(
+            "120",
+            DataType::from(iri("http://www.w3.org/2001/XMLSchema#integer"))
+        ))]
+    );
+    assert_eq!(
+        objects(&graph, &event, "https://schema.org/image"),
+        vec![&ObjectNode::from(iri("http://example.org/launch.png"))]
+    );
+    assert_eq!(
+        objects(&graph, &event, "https://example.org/ns#note"),
+        vec![&ObjectNode::from(Literal::with_language(
+            "bonjour",
+            LanguageTag::from_str("fr").unwrap()
+        ))]
+    );
+}
+
+#[test]
+fn read_nested_items_and_itemref() {
+    let graph = read(
+        r##"<html>
+  <body>
+    <div itemscope itemtype="http://schema.org/Person" itemref="contact">
+      <span itemprop="name">Alice</span>
+      <div itemprop="knows" itemscope>
+        <span itemprop="name">Bob</span>
+      </div>
+    </div>
+    <p id="contact"><a itemprop="email" href="mailto:alice@example.org">email</a></p>
+  </body>
+</html>"##,
+    );
+
+    let person = graph
+        .statements()
+        .find(|st| st.object() == &ObjectNode::from(iri("http://schema.org/Person")))
+        .map(|st| st.subject().clone())
+        .unwrap();
+    assert_eq!(
+        objects(&graph, &person, "http://schema.org/email"),
+        vec![&ObjectNode::from(iri("mailto:alice@example.org"))]
+    );
+    let known = objects(&graph, &person, "http://schema.org/knows");
+    assert_eq!(known.len(), 1);
+    let bob = SubjectNode::from(known[0].as_blank().unwrap());
+    assert_eq!(
+        objects(&graph, &bob, "http://schema.org/name"),
+        vec![&ObjectNode::from(Literal::plain("Bob"))]
+    );
+    assert_eq!(graph.len(), 5);
+}
+
+#[test]
+fn ignore_untyped_items() {
+    let graph = read(
+        r##"<html>
+  <body>
+    <div itemscope><span itemprop="name">Nobody</span></div>
+  </body>
+</html>"##,
+    );
+    assert!(graph.is_empty());
+}
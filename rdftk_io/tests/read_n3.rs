@@ -0,0 +1,199 @@
+#![cfg(feature = "n3")]
+
+use objio::ObjectReader;
+use rdftk_core::error::Error;
+use rdftk_core::model::formulae::Formula;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::{BlankNode, ObjectNode, SubjectNode};
+use rdftk_io::n3::N3Reader;
+use rdftk_iri::Iri;
+use rdftk_names::{log, owl, rdf};
+use std::str::FromStr;
+
+fn example(local: &str) -> Iri {
+    Iri::from_str(&format!("http://example.org/#{local}")).unwrap()
+}
+
+fn read(source: &str) -> Formula {
+    let reader = N3Reader::default();
+    let result: Result<Formula, Error> = reader.read_from_string(source);
+    match result {
+        Ok(formula) => formula,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+#[test]
+fn read_plain_graph() {
+    let reader = N3Reader::default();
+    let result: Result<Graph, Error> = reader.read_from_string(
+        r###"@prefix : <http://example.org/#> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+
+:alice a foaf:Person ;
+    foaf:name "Alice" ;
+    foaf:knows :bob, [ foaf:name "Carol" ] .
+:bob = :robert ."###,
+    );
+    let graph = result.unwrap();
+    assert_eq!(graph.len(), 6);
+    assert!(graph.contains_subject(&example("bob").into()));
+    assert!(graph
+        .statements()
+        .any(|st| st.predicate() == owl::same_as()));
+}
+
+#[test]
+fn read_graph_with_formula_fails() {
+    let reader = N3Reader::default();
+    let result: Result<Graph, Error> = reader.read_from_string(
+        r###"@prefix : <http://example.org/#> .
+{ :a :b :c } :says :d ."###,
+    );
+    assert!(matches!(result, Err(Error::FormulaeNotSupported { .. })));
+}
+
+#[test]
+fn read_quoted_formula() {
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+:joe :says { :sky :color :blue . :grass :color :green } ."###,
+    );
+    assert_eq!(formula.len(), 1);
+    let statement = formula.statements().next().unwrap();
+    assert_eq!(statement.predicate(), &example("says"));
+    let node = statement.object().as_blank().unwrap();
+    let quoted = formula.formula(node).unwrap();
+    assert_eq!(quoted.len(), 2);
+    assert!(quoted.is_plain_graph());
+}
+
+#[test]
+fn read_nested_formulae() {
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+:joe :believes { :mary :says { :sky :color :green } } ."###,
+    );
+    let node = formula
+        .statements()
+        .next()
+        .unwrap()
+        .object()
+        .as_blank()
+        .unwrap();
+    let quoted = formula.formula(node).unwrap();
+    let node = quoted
+        .statements()
+        .next()
+        .unwrap()
+        .object()
+        .as_blank()
+        .unwrap();
+    assert_eq!(quoted.formula(node).unwrap().len(), 1);
+}
+
+#[test]
+fn read_quantified_variables() {
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+@forAll :x, :y .
+@forSome :z .
+{ :x :parent :z . :z :parent :y } => { :x :grandparent :y } ."###,
+    );
+    assert_eq!(formula.universals().count(), 2);
+    assert_eq!(formula.existentials().count(), 1);
+    assert!(formula.has_variable(&example("x")));
+    assert!(formula.has_variable(&example("z")));
+    assert_eq!(formula.formulae().count(), 2);
+    let statement = formula.statements().next().unwrap();
+    assert_eq!(statement.predicate(), log::implies());
+}
+
+#[test]
+fn read_reverse_implication() {
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+{ :x :grandparent :y } <= { :x :parent :z . :z :parent :y } ."###,
+    );
+    let statement = formula.statements().next().unwrap();
+    assert_eq!(statement.predicate(), log::implies());
+    let antecedent = statement.subject().as_blank().unwrap();
+    assert_eq!(formula.formula(antecedent).unwrap().len(), 2);
+    let consequent = statement.object().as_blank().unwrap();
+    assert_eq!(formula.formula(consequent).unwrap().len(), 1);
+}
+
+#[test]
+fn read_is_of() {
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+:bob is :parent of :alice ."###,
+    );
+    let statement = formula.statements().next().unwrap();
+    assert_eq!(statement.subject(), &SubjectNode::from(example("alice")));
+    assert_eq!(statement.predicate(), &example("parent"));
+    assert_eq!(statement.object(), &ObjectNode::from(example("bob")));
+}
+
+#[test]
+fn read_paths() {
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+:joe!:mother!:sister a :Aunt ."###,
+    );
+    assert_eq!(formula.len(), 3);
+    let typed = formula
+        .statements()
+        .find(|st| st.predicate() == rdf::a_type())
+        .unwrap();
+    assert!(typed.subject().is_blank());
+
+    let formula = read(
+        r###"@prefix : <http://example.org/#> .
+:joe^:mother a :Parent ."###,
+    );
+    assert_eq!(formula.len(), 2);
+    let inverse = formula
+        .statements()
+        .find(|st| st.predicate() == &example("mother"))
+        .unwrap();
+    assert!(inverse.subject().is_blank());
+    assert_eq!(inverse.object(), &ObjectNode::from(example("joe")));
+}
+
+#[test]
+fn read_quick_variables() {
+    let formula = read(
+        r###"@base <http://example.org/> .
+@prefix : <http://example.org/#> .
+{ ?x :parent ?y } => { ?y :child ?x } ."###,
+    );
+    assert_eq!(formula.universals().count(), 2);
+    assert!(formula.has_variable(&example("x")));
+    assert!(formula.has_variable(&example("y")));
+}
+
+#[test]
+fn labels_never_clash_with_anonymous_nodes() {
+    let label = next_generated_label();
+    let graph: Graph = N3Reader::default()
+        .read_from_string(format!(
+            r#"@prefix : <http://example.org/> .
+_:{label} :p "labelled" .
+[ :p "anon" ] .
+"#
+        ))
+        .unwrap();
+    let subjects: Vec<_> = graph
+        .statements()
+        .map(|st| st.subject().as_blank().unwrap().clone())
+        .collect();
+    assert_eq!(subjects.len(), 2);
+    assert_ne!(subjects[0], subjects[1]);
+}
+
+/// The label of the next blank node to be generated, unless another thread generates one first.
+fn next_generated_label() -> String {
+    let id: u64 = BlankNode::generate().as_ref()[1..].parse().unwrap();
+    format!("B{}", id + 1)
+}
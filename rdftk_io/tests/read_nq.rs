@@ -1,7 +1,9 @@
 #![cfg(feature = "nq")]
 
 use objio::ObjectReader;
+use rdftk_core::error::Error;
 use rdftk_io::nq::NQuadReader;
+use rdftk_io::ReaderOptions;
 
 #[test]
 fn parse_simple_1() {
@@ -27,3 +29,56 @@ _:bob <http://xmlns.com/foaf/0.1/knows> _:alice <http://example.org/graphs/james
         )
         .is_ok());
 }
+
+#[test]
+fn stream_quads() {
+    let source = r###"<http://one.example/subject1> <http://one.example/predicate1> <http://one.example/object1> <http://example.org/graph3> . # comments here
+# or on a line by themselves
+_:subject1 <http://an.example/predicate1> "object1" .
+"###;
+    let reader = NQuadReader::default();
+    let quads = reader
+        .quads(source.as_bytes())
+        .collect::<Result<Vec<_>, Error>>()
+        .unwrap();
+    assert_eq!(quads.len(), 2);
+    assert!(quads[0].1.is_some());
+    assert!(quads[1].1.is_none());
+}
+
+#[test]
+fn lenient_read_skips_bad_lines() {
+    let reader = NQuadReader::default().with_options(ReaderOptions::default().with_lenient(true));
+    let result = reader.read_with_diagnostics(
+        &mut "<http://example.org/a> <http://example.org/b> \"one\" <http://example.org/g> .\n\
+              <http://example.org/a> \"two\" <http://example.org/g> .\n\
+              <http://example.org/a> <http://example.org/b> \"three\" .\n"
+            .as_bytes(),
+    );
+    let (data_set, diagnostics) = result.unwrap();
+    assert_eq!(data_set.graphs().map(|graph| graph.len()).sum::<usize>(), 2);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].location().unwrap().line(), 2);
+}
+
+#[test]
+fn parse_quoted_triples() {
+    let reader = NQuadReader::default();
+    let quads = reader
+        .quads(
+            "<< <http://example.org/s> <http://example.org/p> \"o\" >> <http://example.org/q> _:b <http://example.org/g> .\n"
+                .as_bytes(),
+        )
+        .collect::<Result<Vec<_>, Error>>()
+        .unwrap();
+    assert_eq!(quads.len(), 1);
+    assert!(quads[0].0.subject().as_statement().is_some());
+    assert!(quads[0].1.is_some());
+
+    // a quoted triple may not be used as a graph label.
+    assert!(reader
+        .read_from_string(
+            "<http://example.org/s> <http://example.org/p> \"o\" << <http://example.org/s> <http://example.org/p> \"o\" >> .\n",
+        )
+        .is_err());
+}
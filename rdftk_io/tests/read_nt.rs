@@ -3,6 +3,7 @@
 use objio::{ObjectReader, ObjectWriter};
 use rdftk_core::{error::Error, model::graph::Graph};
 use rdftk_io::nt::{NTripleReader, NTripleWriter};
+use rdftk_io::{BlankNodeLabels, ReaderOptions};
 
 fn write_graph(graph: &Graph) {
     let writer = NTripleWriter::default();
@@ -55,3 +56,134 @@ _:subject2 <http://an.example/predicate2> "object2" .
         }
     }
 }
+
+#[test]
+fn stream_statements() {
+    let source = r###"# a comment on its own line
+<http://example.org/show/218> <http://www.w3.org/2000/01/rdf-schema#label> "That Seventies Show" .
+
+_:alice <http://xmlns.com/foaf/0.1/knows> _:bob .
+<http://example.org/show/218> <http://example.org/show/localName> "That Seventies Show"@en .
+"###;
+    let reader = NTripleReader::default();
+    let statements = reader
+        .statements(source.as_bytes())
+        .collect::<Result<Vec<_>, Error>>()
+        .unwrap();
+    assert_eq!(statements.len(), 3);
+    assert!(statements[1].subject().is_blank());
+}
+
+#[test]
+fn stream_statements_continues_after_error() {
+    let source = r###"<http://example.org/a> <http://example.org/p> "one" .
+<http://example.org/a> <http://example.org/p> .
+<http://example.org/a> <http://example.org/p> "three" .
+"###;
+    let reader = NTripleReader::default();
+    let results: Vec<_> = reader.statements(source.as_bytes()).collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn syntax_error_has_location() {
+    let reader = NTripleReader::default();
+    let result: Result<Graph, Error> = reader.read_from_string(
+        "<http://example.org/a> <http://example.org/b> \"one\" .\n\
+         # a comment\n\
+         <http://example.org/a> <http://example.org/b> \"two\"  <oops> .\n",
+    );
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 3);
+    assert_eq!(location.column(), 54);
+    assert_eq!(location.offset(), 54 + 12 + 53);
+    assert_eq!(
+        location.source_line(),
+        "<http://example.org/a> <http://example.org/b> \"two\"  <oops> ."
+    );
+}
+
+#[test]
+fn lenient_read_skips_bad_lines() {
+    let reader = NTripleReader::default().with_options(ReaderOptions::default().with_lenient(true));
+    let result = reader.read_with_diagnostics(
+        &mut "<http://example.org/a> <http://example.org/b> \"one\" .\n\
+              <http://example.org/a> <http://example.org/b> .\n\
+              <http://example.org/a> <http://example.org/b> \"two\" .\n\
+              <http://example.org/a> \"three\" \"four\" .\n"
+            .as_bytes(),
+    );
+    let (graph, diagnostics) = result.unwrap();
+    assert_eq!(graph.len(), 2);
+    let lines: Vec<usize> = diagnostics
+        .iter()
+        .map(|e| e.location().unwrap().line())
+        .collect();
+    assert_eq!(lines, vec![2, 4]);
+}
+
+#[test]
+fn strict_read_fails_on_bad_line() {
+    let reader = NTripleReader::default();
+    let result = reader
+        .read_with_diagnostics(&mut "<http://example.org/a> <http://example.org/b> .\n".as_bytes());
+    assert!(result.is_err());
+}
+
+#[test]
+fn relabel_blank_nodes() {
+    let reader = NTripleReader::default()
+        .with_options(ReaderOptions::default().with_blank_nodes(BlankNodeLabels::Relabel));
+    let graph: Graph = reader
+        .read_from_string(
+            "_:a <http://example.org/p> _:b .\n\
+             _:b <http://example.org/p> _:a .\n",
+        )
+        .unwrap();
+    let mut labels: Vec<String> = graph
+        .statements()
+        .flat_map(|st| {
+            [
+                st.subject().as_blank().unwrap().to_string(),
+                st.object().as_blank().unwrap().to_string(),
+            ]
+        })
+        .collect();
+    labels.sort();
+    labels.dedup();
+    assert_eq!(labels.len(), 2);
+    assert!(!labels.contains(&"_:a".to_string()));
+    assert!(!labels.contains(&"_:b".to_string()));
+}
+
+#[test]
+fn parse_quoted_triples() {
+    let reader = NTripleReader::default();
+    let graph: Graph = reader
+        .read_from_string(
+            r###"<< _:a <http://example.org/p> << <http://example.org/s> <http://example.org/p> "o"@en >> >> <http://example.org/q> "1" .
+<http://example.org/s> <http://example.org/p> <<<http://example.org/s> <http://example.org/p> _:a>> .
+"###,
+        )
+        .unwrap();
+    assert_eq!(graph.len(), 2);
+    let outer = graph
+        .statements()
+        .find_map(|st| st.subject().as_statement())
+        .unwrap();
+    assert!(outer.subject().is_blank());
+    let inner = outer.object().as_statement().unwrap();
+    assert!(inner.object().is_literal());
+    assert!(graph
+        .statements()
+        .any(|st| st.object().as_statement().is_some()));
+
+    let result: Result<Graph, Error> = reader.read_from_string(
+        "<< <http://example.org/s> <http://example.org/p> >> <http://example.org/q> \"1\" .\n",
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,244 @@
+#![cfg(feature = "rdfa")]
+
+use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag, Literal};
+use rdftk_core::model::statement::{ObjectNode, Statement, SubjectNode};
+use rdftk_io::rdfa::RdfaReader;
+use rdftk_io::ReaderOptions;
+use rdftk_iri::{Iri, Namespace};
+use rdftk_names::rdf;
+use std::str::FromStr;
+
+pub mod logging;
+
+fn iri(s: &str) -> Iri {
+    Iri::from_str(s).unwrap()
+}
+
+fn read(html: &str) -> Graph {
+    logging::try_init();
+    let reader = RdfaReader::default()
+        .with_options(ReaderOptions::default().with_base(iri("http://example.org/page")));
+    let graph: Graph = reader.read_from_string(html).unwrap();
+    println!("{graph:#?}");
+    graph
+}
+
+fn objects<'a>(graph: &'a Graph, subject: &SubjectNode, predicate: &str) -> Vec<&'a ObjectNode> {
+    graph
+        .objects_for(subject, &iri(predicate))
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn read_vocab_typeof_and_property() {
+    let graph = read(
+        r##"<html>
+  <body vocab="http://schema.org/">
+    <div typeof="Person" about="#alice">
+      <span property="name">Alice</span>
+      <a property="knows" href="#bob">Bob</a>
+    </div>
+  </body>
+</html>"##,
+    );
+
+    let alice = SubjectNode::from(iri("http://example.org/page#alice"));
+    assert_eq!(graph.len(), 4);
+    assert!(graph.contains(&Statement::new(
+        iri("http://example.org/page"),
+        iri("http://www.w3.org/ns/rdfa#usesVocabulary"),
+        iri("http://schema.org/"),
+    )));
+    assert!(graph.contains(&Statement::new(
+        alice.clone(),
+        rdf::a_type().clone(),
+        iri("http://schema.org/Person"),
+    )));
+    assert!(graph.contains(&Statement::new(
+        alice.clone(),
+        iri("http://schema.org/name"),
+        Literal::plain("Alice"),
+    )));
+    assert!(graph.contains(&Statement::new(
+        alice,
+        iri("http://schema.org/knows"),
+        iri("http://example.org/page#bob"),
+    )));
+}
+
+#[test]
+fn read_prefix_content_datatype_and_language() {
+    let graph = read(
+        r##"<html prefix="ex: http://example.org/ns#">
+  <head>
+    <title property="dc:title" lang="en">A Page</title>
+    <meta property="ex:published" content="2024-01-01" datatype="xsd:date"/>
+  </head>
+  <body>
+    <div resource="[ex:thing]" typeof="ex:Thing">
+      <span property="ex:size" datatype="xsd:integer">42</span>
+    </div>
+  </body>
+</html>"##,
+    );
+
+    let page = SubjectNode::from(iri("http://example.org/page"));
+    assert_eq!(
+        objects(&graph, &page, "http://purl.org/dc/terms/title"),
+        vec![&ObjectNode::from(Literal::with_language(
+            "A Page",
+            LanguageTag::from_str("en").unwrap()
+        ))]
+    );
+    assert_eq!(
+        objects(&graph, &page, "http://example.org/ns#published"),
+        vec![&ObjectNode::from(Literal::with_data_type(
+            "2024-01-01",
+            DataType::Date
+        ))]
+    );
+    let thing = SubjectNode::from(iri("http://example.org/ns#thing"));
+    assert!(graph.contains(&Statement::new(
+        thing.clone(),
+        rdf::a_type().clone(),
+        iri("http://example.org/ns#Thing"),
+    )));
+    assert_eq!(
+        objects(&graph, &thing, "http://example.org/ns#size"),
+        vec![&ObjectNode::from(Literal::with_data_type(
+            "42",
+            DataType::from(iri("http://www.w3.org/2001/XMLSchema#integer"))
+        ))]
+    );
+
+    let mappings = graph.prefix_mappings();
+    assert_eq!(
+        mappings.get_namespace(&Namespace::new_unchecked("ex")),
+        Some(&iri("http://example.org/ns#"))
+    );
+    assert_eq!(
+        mappings.get_namespace(&Namespace::new_unchecked("dc")),
+        Some(&iri("http://purl.org/dc/terms/"))
+    );
+    assert!(mappings
+        .get_namespace(&Namespace::new_unchecked("foaf"))
+        .is_none());
+}
+
+#[test]
+fn read_chained_rel_with_typeof() {
+    let graph = read(
+        r##"<html>
+  <body prefix="foaf: http://xmlns.com/foaf/0.1/">
+    <div about="#me" rel="foaf:knows">
+      <div typeof="foaf:Person"><span property="foaf:name">Bob</span></div>
+      <a href="#carol">Carol</a>
+      <span about="#dave">Dave</span>
+    </div>
+  </body>
+</html>"##,
+    );
+
+    let me = SubjectNode::from(iri("http://example.org/page#me"));
+    let known = objects(&graph, &me, "http://xmlns.com/foaf/0.1/knows");
+    assert_eq!(known.len(), 3);
+    let bob = known
+        .iter()
+        .find(|object| object.is_blank())
+        .map(|object| SubjectNode::from(object.as_blank().unwrap()))
+        .unwrap();
+    assert!(graph.contains(&Statement::new(
+        bob.clone(),
+        rdf::a_type().clone(),
+        iri("http://xmlns.com/foaf/0.1/Person"),
+    )));
+    assert!(graph.contains(&Statement::new(
+        bob,
+        iri("http://xmlns.com/foaf/0.1/name"),
+        Literal::plain("Bob"),
+    )));
+    assert!(known.contains(&&ObjectNode::from(iri("http://example.org/page#carol"))));
+    assert!(known.contains(&&ObjectNode::from(iri("http://example.org/page#dave"))));
+}
+
+#[test]
+fn read_inlist() {
+    let graph = read(
+        r##"<html>
+  <body>
+    <p about="#book" prefix="dc: http://purl.org/dc/elements/1.1/">
+      <span property="dc:creator" inlist="">Alice</span>
+      <span property="dc:creator" inlist="">Bob</span>
+      <a rel="dc:source" inlist="" href="#other"></a>
+    </p>
+  </body>
+</html>"##,
+    );
+
+    let book = SubjectNode::from(iri("http://example.org/page#book"));
+    let mut node = objects(&graph, &book, "http://purl.org/dc/elements/1.1/creator")[0].clone();
+    let mut members = Vec::default();
+    while let Some(blank) = node.as_blank() {
+        let subject = SubjectNode::from(blank);
+        members.push(
+            objects(
+                &graph,
+                &subject,
+                "http://www.w3.org/1999/02/22-rdf-syntax-ns#first",
+            )[0]
+            .clone(),
+        );
+        node = objects(
+            &graph,
+            &subject,
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest",
+        )[0]
+        .clone();
+    }
+    assert_eq!(node, ObjectNode::from(rdf::nil().clone()));
+    assert_eq!(
+        members,
+        vec![
+            ObjectNode::from(Literal::plain("Alice")),
+            ObjectNode::from(Literal::plain("Bob")),
+        ]
+    );
+
+    let source = objects(&graph, &book, "http://purl.org/dc/elements/1.1/source")[0];
+    let source = SubjectNode::from(source.as_blank().unwrap());
+    assert_eq!(
+        objects(
+            &graph,
+            &source,
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#first"
+        ),
+        vec![&ObjectNode::from(iri("http://example.org/page#other"))]
+    );
+}
+
+#[test]
+fn read_base_element() {
+    let graph: Graph = RdfaReader::default()
+        .read_from_string(
+            r##"<html>
+  <head><base href="http://example.com/base/"/></head>
+  <body><span property="license" resource="licence.html"></span></body>
+</html>"##,
+        )
+        .unwrap();
+    assert!(graph.contains(&Statement::new(
+        iri("http://example.com/base/"),
+        iri("http://www.w3.org/1999/xhtml/vocab#license"),
+        iri("http://example.com/base/licence.html"),
+    )));
+}
+
+#[test]
+fn read_without_base_fails() {
+    let result: Result<Graph, _> =
+        RdfaReader::default().read_from_string("<html><body></body></html>");
+    assert!(result.is_err());
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "trig")]
+
+use objio::ObjectReader;
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::GraphName;
+use rdftk_core::model::statement::BlankNode;
+use rdftk_io::trig::TrigReader;
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+#[test]
+fn parse_simple_trig() {
+    let reader = TrigReader::default();
+    let data_set = reader
+        .read_from_string(
+            r###"@prefix ex: <http://www.example.org/vocabulary#> .
+@prefix : <http://www.example.org/exampleDocument#> .
+
+:G1 { :Monica a ex:Person ;
+              ex:name "Monica Murphy" ;
+              ex:homepage <http://www.monicamurphy.org> ;
+              ex:email <mailto:monica@monicamurphy.org> . }
+
+GRAPH :G2 { :Monica ex:hasSkill ex:Management }
+
+_:G3 { :Monica ex:hasSkill ex:Programming . }
+
+{ :G1 ex:author :Monica . }
+
+:G2 ex:author :Monica ."###,
+        )
+        .unwrap();
+    assert_eq!(data_set.len(), 4);
+
+    let g1 = GraphName::from(Iri::from_str("http://www.example.org/exampleDocument#G1").unwrap());
+    assert_eq!(data_set.graph_named(&g1).unwrap().len(), 4);
+
+    let g2 = GraphName::from(Iri::from_str("http://www.example.org/exampleDocument#G2").unwrap());
+    assert_eq!(data_set.graph_named(&g2).unwrap().len(), 1);
+
+    let g3 = data_set
+        .graphs()
+        .find(|graph| matches!(graph.name(), Some(GraphName::BNode(_))))
+        .unwrap();
+    assert_eq!(g3.len(), 1);
+
+    let default_graph = data_set.default_graph().unwrap();
+    assert_eq!(default_graph.len(), 2);
+    assert!(default_graph
+        .prefix_mappings()
+        .get_default_namespace()
+        .is_some());
+}
+
+#[test]
+fn parse_trig_prefixes_carry_across_graphs() {
+    let reader = TrigReader::default();
+    let data_set = reader
+        .read_from_string(
+            r###"@prefix ex: <http://example.org/> .
+ex:g1 { ex:s ex:p ex:o . }
+PREFIX ex2: <http://example.org/2/>
+graph ex:g2 { ex2:s ex:p ex2:o . }"###,
+        )
+        .unwrap();
+    assert_eq!(data_set.len(), 2);
+    assert!(data_set.graphs().all(|graph| graph.len() == 1));
+}
+
+#[test]
+fn parse_trig_directive_in_graph_is_error() {
+    let reader = TrigReader::default();
+    assert!(reader
+        .read_from_string(
+            r###"@prefix ex: <http://example.org/> .
+ex:g1 { @prefix ex2: <http://example.org/2/> . ex:s ex:p ex:o . }"###,
+        )
+        .is_err());
+}
+
+#[test]
+fn undefined_prefix_error_has_location() {
+    let reader = TrigReader::default();
+    let result: Result<DataSet, Error> = reader.read_from_string(
+        "@prefix ex: <http://example.org/> .\nex:g {\n  ex:a nope:b \"one\" .\n}\n",
+    );
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!((location.line(), location.column()), (3, 8));
+    assert!(error
+        .to_string()
+        .starts_with("TriG syntax error at line 3, column 8: undeclared prefix `nope`\n"));
+}
+
+#[test]
+fn labels_never_clash_with_anonymous_nodes() {
+    let label = next_generated_label();
+    let data_set: DataSet = TrigReader::default()
+        .read_from_string(format!(
+            r#"@prefix : <http://example.org/> .
+GRAPH :g {{ _:{label} :p "labelled" . [] :p "anon" . }}
+"#
+        ))
+        .unwrap();
+    let graph = data_set.graphs().next().unwrap();
+    let subjects: Vec<_> = graph
+        .statements()
+        .map(|st| st.subject().as_blank().unwrap().clone())
+        .collect();
+    assert_eq!(subjects.len(), 2);
+    assert_ne!(subjects[0], subjects[1]);
+}
+
+/// The label of the next blank node to be generated, unless another thread generates one first.
+fn next_generated_label() -> String {
+    let id: u64 = BlankNode::generate().as_ref()[1..].parse().unwrap();
+    format!("B{}", id + 1)
+}
@@ -1,11 +1,16 @@
 #![cfg(feature = "turtle")]
 
 use objio::ObjectReader;
+use rdftk_core::model::statement::BlankNode;
 use rdftk_core::{error::Error, model::graph::Graph};
 use rdftk_io::turtle::TurtleReader;
+use rdftk_io::{BlankNodeLabels, ReaderOptions};
+use rdftk_iri::{Iri, Namespace};
+use std::str::FromStr;
+
+mod common;
 
 #[test]
-#[ignore]
 fn parse_simple_turtle() {
     let reader = TurtleReader::default();
     let result: Result<Graph, Error> = reader.read_from_string(
@@ -27,3 +32,190 @@ fn parse_simple_turtle() {
     );
     assert!(result.is_ok());
 }
+
+#[test]
+fn syntax_error_has_location() {
+    let reader = TurtleReader::default();
+    let result: Result<Graph, Error> = reader.read_from_string(
+        "@prefix ex: <http://example.org/> .\n\nex:a ex:b \"one\" ;\n    ex:c ex:d ex:e .\n",
+    );
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 4);
+    assert_eq!(location.column(), 15);
+    assert_eq!(location.offset(), 36 + 1 + 18 + 14);
+    assert_eq!(location.source_line(), "    ex:c ex:d ex:e .");
+    assert!(error
+        .to_string()
+        .ends_with("4 |     ex:c ex:d ex:e .\n  |               ^"));
+}
+
+#[test]
+fn undefined_prefix_error_has_location() {
+    let reader = TurtleReader::default();
+    let result: Result<Graph, Error> = reader.read_from_string(
+        "@prefix ex: <http://example.org/> .\n\nex:a ex:b \"one\" .\nex:a nope:b \"two\" .\n",
+    );
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 4);
+    assert_eq!(location.column(), 6);
+    assert_eq!(location.source_line(), "ex:a nope:b \"two\" .");
+    assert!(error
+        .to_string()
+        .starts_with("Turtle syntax error at line 4, column 6: undeclared prefix `nope`\n"));
+}
+
+#[test]
+fn invalid_term_errors_have_location() {
+    let reader = TurtleReader::default();
+    for (source, column) in [
+        ("<http://example.org/a> <http://example.org/b> <c> .\n", 47),
+        (
+            "<http://example.org/a> <http://example.org/b> \"c\"@abcdefghijk .\n",
+            50,
+        ),
+    ] {
+        let result: Result<Graph, Error> = reader.read_from_string(source);
+        let error = result.unwrap_err();
+        println!("{error}");
+        let location = error.location().unwrap();
+        assert_eq!(location.line(), 1);
+        assert_eq!(location.column(), column, "{source}");
+    }
+}
+
+#[test]
+fn lenient_read_skips_bad_statements() {
+    let reader = TurtleReader::default().with_options(ReaderOptions::default().with_lenient(true));
+    let result = reader.read_with_diagnostics(
+        &mut r#"@prefix ex: <http://example.org/> .
+
+ex:a ex:b "one" ;
+    ex:c .
+ex:a ex:b "two" .
+ex:a nope:b "three" . ex:a ex:b "four" .
+ex:a ex:b "five" ,
+"#
+        .as_bytes(),
+    );
+    let (graph, diagnostics) = result.unwrap();
+    let mut objects: Vec<String> = graph
+        .statements()
+        .map(|st| st.object().to_string())
+        .collect();
+    objects.sort();
+    assert_eq!(objects, vec!["\"four\"", "\"two\""]);
+    let lines: Vec<(usize, usize)> = diagnostics
+        .iter()
+        .map(|e| {
+            let location = e.location().unwrap();
+            (location.line(), location.column())
+        })
+        .collect();
+    assert_eq!(lines, vec![(4, 10), (6, 6), (8, 1)]);
+}
+
+#[test]
+fn read_with_base_and_prefix_options() {
+    const SOURCE: &str = r#"@prefix ex: <http://example.org/> .
+<thing> ex:label "thing" ;
+    ex:other _:b1 .
+_:b1 ex:label "other" .
+"#;
+
+    let reader = TurtleReader::default().with_options(
+        ReaderOptions::default()
+            .with_base(Iri::from_str("http://example.com/base/").unwrap())
+            .with_carry_prefixes(false),
+    );
+    let graph: Graph = reader.read_from_string(SOURCE).unwrap();
+    assert!(graph.statements().all(|st| st.subject().is_blank()
+        || st.subject().as_resource().unwrap().to_string() == "<http://example.com/base/thing>"));
+    assert!(graph
+        .prefix_mappings()
+        .get_namespace(&Namespace::from_str("ex:").unwrap())
+        .is_none());
+    assert!(graph
+        .statements()
+        .filter_map(|st| st.subject().as_blank())
+        .all(|node| node.as_ref() == "b1"));
+
+    let reader = TurtleReader::default()
+        .with_options(ReaderOptions::default().with_blank_nodes(BlankNodeLabels::Relabel));
+    let graph: Graph = reader
+        .read_from_string(SOURCE.replace("<thing>", "<http://example.com/thing>"))
+        .unwrap();
+    assert!(graph
+        .prefix_mappings()
+        .get_namespace(&Namespace::from_str("ex:").unwrap())
+        .is_some());
+    let blanks: Vec<_> = graph
+        .statements()
+        .filter_map(|st| st.subject().as_blank())
+        .collect();
+    assert_eq!(blanks.len(), 1);
+    assert_ne!(blanks[0].as_ref(), "b1");
+}
+
+#[test]
+fn read_annotations() {
+    let graph: Graph = TurtleReader::default()
+        .read_from_string(
+            r#"@prefix ex: <http://example.org/> .
+ex:alice ex:knows ex:bob {| ex:since "2020" ; ex:source ex:census {| ex:checked true |} |},
+    ex:carol .
+"#,
+        )
+        .unwrap();
+    let expected: Graph = TurtleReader::default()
+        .read_from_string(
+            r#"@prefix ex: <http://example.org/> .
+ex:alice ex:knows ex:bob, ex:carol .
+<< ex:alice ex:knows ex:bob >> ex:since "2020" ; ex:source ex:census .
+<< << ex:alice ex:knows ex:bob >> ex:source ex:census >> ex:checked true .
+"#,
+        )
+        .unwrap();
+    assert_eq!(graph.len(), 5);
+    assert!(common::graphs_isomorphic(&graph, &expected));
+
+    let result: Result<Graph, Error> = TurtleReader::default().read_from_string(
+        "@prefix ex: <http://example.org/> .\nex:alice ex:knows {| ex:since \"2020\" |} .\n",
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn labels_never_clash_with_anonymous_nodes() {
+    let label = next_generated_label();
+    let graph: Graph = TurtleReader::default()
+        .read_from_string(format!(
+            r#"@prefix : <http://example.org/> .
+_:{label} :p "labelled" .
+[] :p "anon1" .
+_:b1 :p "kept" .
+_:{label} :q "again" .
+"#
+        ))
+        .unwrap();
+    let subject = |value: &str| {
+        graph
+            .statements()
+            .find(|st| st.object().as_literal().unwrap().lexical_form() == value)
+            .unwrap()
+            .subject()
+            .as_blank()
+            .unwrap()
+            .clone()
+    };
+    assert_ne!(subject("labelled"), subject("anon1"));
+    assert_eq!(subject("labelled"), subject("again"));
+    assert_eq!(subject("kept").as_ref(), "b1");
+}
+
+/// The label of the next blank node to be generated, unless another thread generates one first.
+fn next_generated_label() -> String {
+    let id: u64 = BlankNode::generate().as_ref()[1..].parse().unwrap();
+    format!("B{}", id + 1)
+}
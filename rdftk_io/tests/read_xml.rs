@@ -1,7 +1,13 @@
 #![cfg(feature = "xml")]
 
 use objio::ObjectReader;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::{BlankNode, ObjectNode};
 use rdftk_io::xml::XmlReader;
+use rdftk_io::{BlankNodeLabels, ReaderOptions};
+use rdftk_iri::Namespace;
+use rdftk_names::rdf;
+use std::str::FromStr;
 
 // https://www.w3.org/RDF/Validator/rdfval
 
@@ -28,7 +34,6 @@ fn read_example_empty_graph() {
 }
 
 #[test]
-#[ignore]
 fn read_example_01() {
     let mut xml = r##"<?xml version="1.0"?>
 <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
@@ -60,3 +65,192 @@ fn read_example_01() {
     }
     assert_eq!(graph.len(), 6);
 }
+
+#[test]
+fn read_example_collection() {
+    let mut xml = r##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+            xmlns:ex="http://example.org/stuff/1.0/">
+
+  <rdf:Description rdf:about="http://example.org/basket">
+    <ex:hasFruit rdf:parseType="Collection">
+      <rdf:Description rdf:about="http://example.org/banana"/>
+      <rdf:Description rdf:about="http://example.org/apple"/>
+      <rdf:Description rdf:about="http://example.org/pear"/>
+    </ex:hasFruit>
+  </rdf:Description>
+
+</rdf:RDF>"##
+        .as_bytes();
+
+    logging::try_init();
+
+    let reader = XmlReader::default();
+    let graph: Graph = reader.read(&mut xml).unwrap();
+    for st in graph.statements() {
+        println!("{}", st);
+    }
+    assert_eq!(graph.len(), 7);
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st.predicate() == rdf::first())
+            .count(),
+        3
+    );
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st.predicate() == rdf::rest())
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn read_example_members_and_reification() {
+    let mut xml = r##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+            xmlns:ex="http://example.org/stuff/1.0/"
+            xml:base="http://example.org/triples/">
+
+  <rdf:Seq rdf:about="http://example.org/favourite-fruit">
+    <rdf:li rdf:resource="http://example.org/banana"/>
+    <rdf:li rdf:resource="http://example.org/apple" rdf:ID="triple1"/>
+    <rdf:li ex:name="pear"/>
+  </rdf:Seq>
+
+</rdf:RDF>"##
+        .as_bytes();
+
+    logging::try_init();
+
+    let reader = XmlReader::default();
+    let graph: Graph = reader.read(&mut xml).unwrap();
+    for st in graph.statements() {
+        println!("{}", st);
+    }
+    assert_eq!(graph.len(), 9);
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st.predicate() == &rdf::member(3))
+            .count(),
+        1
+    );
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st.object() == &ObjectNode::from(rdf::statement()))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn malformed_xml_error_has_location() {
+    let reader = XmlReader::default();
+    let result: Result<Graph, _> = reader.read_from_string(
+        r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about="http://example.org/a">
+  </rdf:Descripton>
+</rdf:RDF>"#,
+    );
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 4);
+    assert_eq!(location.source_line(), "  </rdf:Descripton>");
+}
+
+#[test]
+fn grammar_error_has_element_location() {
+    let reader = XmlReader::default();
+    let result: Result<Graph, _> = reader.read_from_string(
+        r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/">
+  <rdf:Description rdf:about="http://example.org/a">
+    <ex:b rdf:about="http://example.org/c"/>
+  </rdf:Description>
+</rdf:RDF>"#,
+    );
+    let error = result.unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.line(), 5);
+    assert_eq!(location.column(), 5);
+    assert_eq!(
+        location.source_line(),
+        r#"    <ex:b rdf:about="http://example.org/c"/>"#
+    );
+}
+
+#[test]
+fn carry_namespaces_and_preserve_node_ids() {
+    const SOURCE: &str = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/stuff/1.0/">
+  <rdf:Description rdf:nodeID="abc">
+    <ex:editor rdf:nodeID="def"/>
+  </rdf:Description>
+</rdf:RDF>"#;
+
+    let graph: Graph = XmlReader::default().read_from_string(SOURCE).unwrap();
+    let ex = Namespace::from_str("ex:").unwrap();
+    assert_eq!(
+        graph
+            .prefix_mappings()
+            .get_namespace(&ex)
+            .unwrap()
+            .to_string(),
+        "<http://example.org/stuff/1.0/>"
+    );
+    let statement = graph.statements().next().unwrap();
+    assert_eq!(statement.subject().as_blank().unwrap().as_ref(), "abc");
+    assert_eq!(statement.object().as_blank().unwrap().as_ref(), "def");
+
+    let reader = XmlReader::default().with_options(
+        ReaderOptions::default()
+            .with_carry_prefixes(false)
+            .with_blank_nodes(BlankNodeLabels::Relabel),
+    );
+    let graph: Graph = reader.read_from_string(SOURCE).unwrap();
+    assert!(graph.prefix_mappings().get_namespace(&ex).is_none());
+    let statement = graph.statements().next().unwrap();
+    assert_ne!(statement.subject().as_blank().unwrap().as_ref(), "abc");
+}
+
+#[test]
+fn node_ids_never_clash_with_anonymous_nodes() {
+    let label = next_generated_label();
+    let graph: Graph = XmlReader::default()
+        .read_from_string(format!(
+            r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/">
+  <rdf:Description rdf:nodeID="{label}"><ex:p>labelled</ex:p></rdf:Description>
+  <rdf:Description><ex:p>anon</ex:p></rdf:Description>
+  <rdf:Description rdf:nodeID="b3"><ex:p>kept</ex:p></rdf:Description>
+</rdf:RDF>"#
+        ))
+        .unwrap();
+    let subjects: Vec<_> = graph
+        .statements()
+        .map(|st| st.subject().as_blank().unwrap().clone())
+        .collect();
+    assert_eq!(subjects.len(), 3);
+    assert!(subjects.iter().any(|node| node.as_ref() == "b3"));
+    assert_eq!(
+        subjects
+            .iter()
+            .collect::<std::collections::HashSet<_>>()
+            .len(),
+        3
+    );
+}
+
+/// The label of the next blank node to be generated, unless another thread generates one first.
+fn next_generated_label() -> String {
+    let id: u64 = BlankNode::generate().as_ref()[1..].parse().unwrap();
+    format!("B{}", id + 1)
+}
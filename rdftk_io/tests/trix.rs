@@ -0,0 +1,121 @@
+#![cfg(feature = "trix")]
+
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::{DataType, Literal};
+use rdftk_core::model::statement::Statement;
+use rdftk_io::trix::{TrixReader, TrixWriter, TrixWriterOptions};
+use rdftk_io::{BlankNodeLabels, ReaderOptions};
+use rdftk_iri::Iri;
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+mod common;
+pub mod logging;
+
+fn iri(s: &str) -> Iri {
+    Iri::from_str(s).unwrap()
+}
+
+#[test]
+fn write_and_read_data_set() {
+    logging::try_init();
+    let data_set = common::people_data_set(common::people_graph());
+    for pretty_print in [false, true] {
+        let writer = TrixWriter::default()
+            .with_options(TrixWriterOptions::default().with_pretty_print(pretty_print));
+        let trix = writer.write_to_string(&data_set).unwrap();
+        println!("{trix}");
+        assert!(trix.contains("<TriX xmlns=\"http://www.w3.org/2004/03/trix/trix-1/\">"));
+
+        let result: DataSet = TrixReader::default().read_from_string(&trix).unwrap();
+        assert_eq!(result.len(), 3);
+        assert!(common::data_sets_isomorphic(&data_set, &result));
+    }
+}
+
+#[test]
+fn write_graph() {
+    let mut graph = Graph::named(GraphName::Iri(iri("http://example.org/graphs#one")));
+    graph.insert(Statement::new(
+        iri("http://example.org/people#carol"),
+        foaf::age().clone(),
+        Literal::with_data_type(
+            "42",
+            DataType::from(iri("http://www.w3.org/2001/XMLSchema#integer")),
+        ),
+    ));
+    let trix = TrixWriter::default().write_to_string(&graph).unwrap();
+    assert!(trix.contains(
+        "<graph><uri>http://example.org/graphs#one</uri><triple>\
+        <uri>http://example.org/people#carol</uri>\
+        <uri>http://xmlns.com/foaf/0.1/age</uri>\
+        <typedLiteral datatype=\"http://www.w3.org/2001/XMLSchema#integer\">42</typedLiteral>\
+        </triple></graph>"
+    ));
+}
+
+#[test]
+fn read_merges_graphs_and_relabels() {
+    logging::try_init();
+    let reader = TrixReader::default().with_options(
+        ReaderOptions::default()
+            .with_base(iri("http://example.org/"))
+            .with_blank_nodes(BlankNodeLabels::Relabel),
+    );
+    let data_set: DataSet = reader
+        .read_from_string(
+            r#"<?xml version="1.0"?>
+<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/">
+  <graph>
+    <id>g</id>
+    <triple><id>x</id><uri>people#name</uri><plainLiteral>  X  </plainLiteral></triple>
+  </graph>
+  <graph>
+    <id>g</id>
+    <triple><id>x</id><uri>people#knows</uri><id>y</id></triple>
+  </graph>
+  <graph/>
+</TriX>"#,
+        )
+        .unwrap();
+    assert_eq!(data_set.len(), 2);
+    assert!(data_set.default_graph().unwrap().is_empty());
+    let graph = data_set.graphs().find(|graph| graph.is_named()).unwrap();
+    assert_eq!(graph.len(), 2);
+    assert!(graph.name().unwrap().as_blank().unwrap().as_ref() != "g");
+    let subjects = graph.subjects();
+    assert_eq!(subjects.len(), 1);
+    assert!(graph
+        .statements()
+        .any(|st| st.object().as_literal() == Some(&Literal::plain("  X  "))));
+    assert!(graph
+        .statements()
+        .any(|st| st.predicate() == &iri("http://example.org/people#knows")));
+}
+
+#[test]
+fn read_invalid_documents_fails() {
+    for source in [
+        r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"/>"#,
+        r#"<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/"><triple/></TriX>"#,
+        r#"<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/"><graph><triple>
+            <uri>http://example.org/a</uri><uri>http://example.org/b</uri>
+        </triple></graph></TriX>"#,
+        r#"<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/"><graph><triple>
+            <plainLiteral>a</plainLiteral><uri>http://example.org/b</uri><id>c</id>
+        </triple></graph></TriX>"#,
+        r#"<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/"><graph><triple>
+            <uri>http://example.org/a</uri><id>b</id><id>c</id>
+        </triple></graph></TriX>"#,
+        r#"<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/"><graph><triple>
+            <uri>http://example.org/a</uri><uri>http://example.org/b</uri>
+            <typedLiteral>c</typedLiteral>
+        </triple></graph></TriX>"#,
+        r#"<TriX xmlns="http://www.w3.org/2004/03/trix/trix-1/"><graph>"#,
+    ] {
+        let result: Result<DataSet, _> = TrixReader::default().read_from_string(source);
+        assert!(result.is_err(), "expected an error reading {source}");
+    }
+}
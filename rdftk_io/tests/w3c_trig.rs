@@ -0,0 +1,2116 @@
+#![cfg(all(feature = "trig", feature = "nq"))]
+
+use objio::ObjectReader;
+use rdftk_core::error::eprint_trace;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_io::nq::NQuadReader;
+use rdftk_io::trig::TrigReader;
+use std::path::PathBuf;
+
+mod common;
+
+macro_rules! positive_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            match read_test_file($file) {
+                Ok(_) => {}
+                Err(e) => {
+                    eprint_trace(&e);
+                    panic!("{}", e);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! negative_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            assert!(read_test_file($file).is_err());
+        }
+    };
+}
+
+macro_rules! eval_test {
+    ($name:ident, $comment:expr, $file:expr, $result_file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            let data_set = match read_test_file($file) {
+                Ok(data_set) => data_set,
+                Err(e) => {
+                    eprint_trace(&e);
+                    panic!("{}", e);
+                }
+            };
+            let expected = read_result_file($result_file);
+            assert!(common::data_sets_isomorphic(&data_set, &expected));
+        }
+    };
+}
+
+const TEST_BASE: &str = "http://www.w3.org/2013/TriGTests/";
+
+fn read_test_file(file_name: &str) -> Result<DataSet, rdftk_core::error::Error> {
+    let file_path = PathBuf::from(format!("tests/w3c/trig/{}.trig", file_name));
+    let source = std::fs::read_to_string(file_path).unwrap();
+    // The base IRI for each test is the retrieval IRI of the test file.
+    let source = format!("@base <{TEST_BASE}{file_name}.trig> .\n{source}");
+    let reader = TrigReader::default();
+    reader.read_from_string(source)
+}
+
+fn read_result_file(file_name: &str) -> DataSet {
+    let file_path = PathBuf::from(format!("tests/w3c/trig/{}", file_name));
+    let reader = NQuadReader::default();
+    reader.read_from_file(file_path).unwrap()
+}
+
+eval_test!(
+    anonymous_blank_node_graph,
+    "anonymous blank node graph",
+    "anonymous_blank_node_graph",
+    "labeled_blank_node_graph.nq"
+);
+
+eval_test!(
+    labeled_blank_node_graph,
+    "labeled blank node graph",
+    "labeled_blank_node_graph",
+    "labeled_blank_node_graph.nq"
+);
+
+eval_test!(
+    alternating_iri_graphs,
+    "alternating graphs with IRI names",
+    "alternating_iri_graphs",
+    "alternating_iri_graphs.nq"
+);
+
+eval_test!(
+    alternating_bnode_graphs,
+    "alternating graphs with BNode names",
+    "alternating_bnode_graphs",
+    "alternating_bnode_graphs.nq"
+);
+
+negative_test!(
+    trig_syntax_bad_base_04,
+    "@base inside graph (negative test)",
+    "trig-syntax-bad-base-04"
+);
+
+negative_test!(
+    trig_syntax_bad_base_05,
+    "BASE inside graph (negative test)",
+    "trig-syntax-bad-base-05"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_06,
+    "@prefix inside graph (negative test)",
+    "trig-syntax-bad-prefix-06"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_07,
+    "PREFIX inside graph (negative test)",
+    "trig-syntax-bad-prefix-07"
+);
+
+positive_test!(
+    trig_syntax_struct_06,
+    "missing '.'",
+    "trig-syntax-struct-06"
+);
+
+positive_test!(
+    trig_syntax_struct_07,
+    "trailing ';' no '.'",
+    "trig-syntax-struct-07"
+);
+
+positive_test!(
+    trig_syntax_minimal_whitespace_01,
+    "tests absense of whitespace in various positions",
+    "trig-syntax-minimal-whitespace-01"
+);
+
+eval_test!(iri_subject, "IRI subject", "IRI_subject", "IRI_spo.nq");
+
+eval_test!(
+    iri_with_four_digit_numeric_escape,
+    "IRI with four digit numeric escape (\\u)",
+    "IRI_with_four_digit_numeric_escape",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    iri_with_eight_digit_numeric_escape,
+    "IRI with eight digit numeric escape (\\U)",
+    "IRI_with_eight_digit_numeric_escape",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    iri_with_all_punctuation,
+    "IRI with all punctuation",
+    "IRI_with_all_punctuation",
+    "IRI_with_all_punctuation.nq"
+);
+
+eval_test!(
+    bareword_a_predicate,
+    "bareword a predicate",
+    "bareword_a_predicate",
+    "bareword_a_predicate.nq"
+);
+
+eval_test!(
+    old_style_prefix,
+    "old-style prefix",
+    "old_style_prefix",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    sparql_style_prefix,
+    "SPARQL-style prefix",
+    "SPARQL_style_prefix",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    prefixed_iri_predicate,
+    "prefixed IRI predicate",
+    "prefixed_IRI_predicate",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    prefixed_iri_object,
+    "prefixed IRI object",
+    "prefixed_IRI_object",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    prefix_only_iri,
+    "prefix-only IRI (p:)",
+    "prefix_only_IRI",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    prefix_with_pn_chars_base_character_boundaries,
+    "prefix with PN CHARS BASE character boundaries (prefix: AZazÀÖØöø...:)",
+    "prefix_with_PN_CHARS_BASE_character_boundaries",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    prefix_with_non_leading_extras,
+    "prefix with_non_leading_extras (_:a·̀ͯ‿.⁀)",
+    "prefix_with_non_leading_extras",
+    "IRI_spo.nq"
+);
+
+eval_test!(localname_with_assigned_nfc_bmp_pn_chars_base_character_boundaries, "localName with assigned, NFC-normalized, basic-multilingual-plane PN CHARS BASE character boundaries (p:AZazÀÖØöø...)", "localName_with_assigned_nfc_bmp_PN_CHARS_BASE_character_boundaries", "localName_with_assigned_nfc_bmp_PN_CHARS_BASE_character_boundaries.nq");
+
+eval_test!(
+    localname_with_assigned_nfc_pn_chars_base_character_boundaries,
+    "localName with assigned, NFC-normalized PN CHARS BASE character boundaries (p:AZazÀÖØöø...)",
+    "localName_with_assigned_nfc_PN_CHARS_BASE_character_boundaries",
+    "localName_with_assigned_nfc_PN_CHARS_BASE_character_boundaries.nq"
+);
+
+eval_test!(
+    localname_with_nfc_pn_chars_base_character_boundaries,
+    "localName with nfc-normalize PN CHARS BASE character boundaries (p:AZazÀÖØöø...)",
+    "localName_with_nfc_PN_CHARS_BASE_character_boundaries",
+    "localName_with_nfc_PN_CHARS_BASE_character_boundaries.nq"
+);
+
+eval_test!(
+    default_namespace_iri,
+    "default namespace IRI (:ln)",
+    "default_namespace_IRI",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    prefix_reassigned_and_used,
+    "prefix reassigned and used",
+    "prefix_reassigned_and_used",
+    "prefix_reassigned_and_used.nq"
+);
+
+eval_test!(
+    reserved_escaped_localname,
+    "reserved-escaped local name",
+    "reserved_escaped_localName",
+    "reserved_escaped_localName.nq"
+);
+
+eval_test!(
+    percent_escaped_localname,
+    "percent-escaped local name",
+    "percent_escaped_localName",
+    "percent_escaped_localName.nq"
+);
+
+eval_test!(
+    hyphen_minus_in_localname,
+    "HYPHEN-MINUS in local name",
+    "HYPHEN_MINUS_in_localName",
+    "HYPHEN_MINUS_in_localName.nq"
+);
+
+eval_test!(
+    underscore_in_localname,
+    "underscore in local name",
+    "underscore_in_localName",
+    "underscore_in_localName.nq"
+);
+
+eval_test!(
+    localname_with_colon,
+    "localname with COLON",
+    "localname_with_COLON",
+    "localname_with_COLON.nq"
+);
+
+eval_test!(
+    localname_with_leading_underscore,
+    "localName with leading underscore (p:_)",
+    "localName_with_leading_underscore",
+    "localName_with_leading_underscore.nq"
+);
+
+eval_test!(
+    localname_with_leading_digit,
+    "localName with leading digit (p:_)",
+    "localName_with_leading_digit",
+    "localName_with_leading_digit.nq"
+);
+
+eval_test!(
+    localname_with_non_leading_extras,
+    "localName with_non_leading_extras (_:a·̀ͯ‿.⁀)",
+    "localName_with_non_leading_extras",
+    "localName_with_non_leading_extras.nq"
+);
+
+eval_test!(
+    old_style_base,
+    "old-style base",
+    "old_style_base",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    sparql_style_base,
+    "SPARQL-style base",
+    "SPARQL_style_base",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    labeled_blank_node_subject,
+    "labeled blank node subject",
+    "labeled_blank_node_subject",
+    "labeled_blank_node_subject.nq"
+);
+
+eval_test!(
+    labeled_blank_node_object,
+    "labeled blank node object",
+    "labeled_blank_node_object",
+    "labeled_blank_node_object.nq"
+);
+
+eval_test!(
+    labeled_blank_node_with_pn_chars_base_character_boundaries,
+    "labeled blank node with PN_CHARS_BASE character boundaries (_:AZazÀÖØöø...)",
+    "labeled_blank_node_with_PN_CHARS_BASE_character_boundaries",
+    "labeled_blank_node_object.nq"
+);
+
+eval_test!(
+    labeled_blank_node_with_leading_underscore,
+    "labeled blank node with_leading_underscore (_:_)",
+    "labeled_blank_node_with_leading_underscore",
+    "labeled_blank_node_object.nq"
+);
+
+eval_test!(
+    labeled_blank_node_with_leading_digit,
+    "labeled blank node with_leading_digit (_:0)",
+    "labeled_blank_node_with_leading_digit",
+    "labeled_blank_node_object.nq"
+);
+
+eval_test!(
+    labeled_blank_node_with_non_leading_extras,
+    "labeled blank node with_non_leading_extras (_:a·̀ͯ‿.⁀)",
+    "labeled_blank_node_with_non_leading_extras",
+    "labeled_blank_node_object.nq"
+);
+
+eval_test!(
+    anonymous_blank_node_subject,
+    "anonymous blank node subject",
+    "anonymous_blank_node_subject",
+    "anonymous_blank_node_subject.nq"
+);
+
+eval_test!(
+    anonymous_blank_node_object,
+    "anonymous blank node object",
+    "anonymous_blank_node_object",
+    "anonymous_blank_node_object.nq"
+);
+
+eval_test!(
+    sole_blanknodepropertylist,
+    "sole blankNodePropertyList [ <p> <o> ] .",
+    "sole_blankNodePropertyList",
+    "sole_blankNodePropertyList.nq"
+);
+
+eval_test!(
+    blanknodepropertylist_as_subject,
+    "blankNodePropertyList as subject [ … ] <p> <o> .",
+    "blankNodePropertyList_as_subject",
+    "blankNodePropertyList_as_subject.nq"
+);
+
+eval_test!(
+    blanknodepropertylist_as_object,
+    "blankNodePropertyList as object <s> <p> [ … ] .",
+    "blankNodePropertyList_as_object",
+    "blankNodePropertyList_as_object.nq"
+);
+
+eval_test!(
+    blanknodepropertylist_with_multiple_triples,
+    "blankNodePropertyList with multiple triples [ <s> <p> ; <s2> <p2> ]",
+    "blankNodePropertyList_with_multiple_triples",
+    "blankNodePropertyList_with_multiple_triples.nq"
+);
+
+eval_test!(
+    nested_blanknodepropertylists,
+    "nested blankNodePropertyLists [ <p1> [ <p2> <o2> ] ; <p3> <o3> ]",
+    "nested_blankNodePropertyLists",
+    "nested_blankNodePropertyLists.nq"
+);
+
+eval_test!(
+    blanknodepropertylist_containing_collection,
+    "blankNodePropertyList containing collection [ <p1> ( … ) ]",
+    "blankNodePropertyList_containing_collection",
+    "blankNodePropertyList_containing_collection.nq"
+);
+
+eval_test!(
+    collection_subject,
+    "collection subject",
+    "collection_subject",
+    "collection_subject.nq"
+);
+
+eval_test!(
+    collection_object,
+    "collection object",
+    "collection_object",
+    "collection_object.nq"
+);
+
+eval_test!(
+    empty_collection,
+    "empty collection ()",
+    "empty_collection",
+    "empty_collection.nq"
+);
+
+eval_test!(
+    nested_collection,
+    "nested collection (())",
+    "nested_collection",
+    "nested_collection.nq"
+);
+
+eval_test!(
+    first,
+    "first, not last, non-empty nested collection",
+    "first",
+    "first.nq"
+);
+
+eval_test!(
+    last,
+    "last, not first, non-empty nested collection",
+    "last",
+    "last.nq"
+);
+
+eval_test!(literal1, "LITERAL1 'x'", "LITERAL1", "LITERAL1.nq");
+
+eval_test!(
+    literal1_ascii_boundaries,
+    "LITERAL1_ascii_boundaries '\\x00\\x09\\x0b\\x0c\\x0e\\x26\\x28...'",
+    "LITERAL1_ascii_boundaries",
+    "LITERAL1_ascii_boundaries.nq"
+);
+
+eval_test!(
+    literal1_with_utf8_boundaries,
+    "LITERAL1_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL1_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries.nq"
+);
+
+eval_test!(
+    literal1_all_controls,
+    "LITERAL1_all_controls '\\x00\\x01\\x02\\x03\\x04...'",
+    "LITERAL1_all_controls",
+    "LITERAL1_all_controls.nq"
+);
+
+eval_test!(
+    literal1_all_punctuation,
+    "LITERAL1_all_punctuation '!\"#$%&()...'",
+    "LITERAL1_all_punctuation",
+    "LITERAL1_all_punctuation.nq"
+);
+
+eval_test!(
+    literal_long1,
+    "LITERAL_LONG1 '''x'''",
+    "LITERAL_LONG1",
+    "LITERAL1.nq"
+);
+
+eval_test!(
+    literal_long1_ascii_boundaries,
+    "LITERAL_LONG1_ascii_boundaries '\\x00\\x26\\x28...'",
+    "LITERAL_LONG1_ascii_boundaries",
+    "LITERAL_LONG1_ascii_boundaries.nq"
+);
+
+eval_test!(
+    literal_long1_with_utf8_boundaries,
+    "LITERAL_LONG1_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL_LONG1_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries.nq"
+);
+
+eval_test!(
+    literal_long1_with_1_squote,
+    "LITERAL_LONG1 with 1 squote '''a'b'''",
+    "LITERAL_LONG1_with_1_squote",
+    "LITERAL_LONG1_with_1_squote.nq"
+);
+
+eval_test!(
+    literal_long1_with_2_squotes,
+    "LITERAL_LONG1 with 2 squotes '''a''b'''",
+    "LITERAL_LONG1_with_2_squotes",
+    "LITERAL_LONG1_with_2_squotes.nq"
+);
+
+eval_test!(literal2, "LITERAL2 \"x\"", "LITERAL2", "LITERAL1.nq");
+
+eval_test!(
+    literal2_ascii_boundaries,
+    "LITERAL2_ascii_boundaries '\\x00\\x09\\x0b\\x0c\\x0e\\x21\\x23...'",
+    "LITERAL2_ascii_boundaries",
+    "LITERAL2_ascii_boundaries.nq"
+);
+
+eval_test!(
+    literal2_with_utf8_boundaries,
+    "LITERAL2_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL2_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries.nq"
+);
+
+eval_test!(
+    literal_long2,
+    "LITERAL_LONG2 \"\"\"x\"\"\"",
+    "LITERAL_LONG2",
+    "LITERAL1.nq"
+);
+
+eval_test!(
+    literal_long2_ascii_boundaries,
+    "LITERAL_LONG2_ascii_boundaries '\\x00\\x21\\x23...'",
+    "LITERAL_LONG2_ascii_boundaries",
+    "LITERAL_LONG2_ascii_boundaries.nq"
+);
+
+eval_test!(
+    literal_long2_with_utf8_boundaries,
+    "LITERAL_LONG2_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL_LONG2_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries.nq"
+);
+
+eval_test!(
+    literal_long2_with_1_squote,
+    "LITERAL_LONG2 with 1 squote \"\"\"a\"b\"\"\"",
+    "LITERAL_LONG2_with_1_squote",
+    "LITERAL_LONG2_with_1_squote.nq"
+);
+
+eval_test!(
+    literal_long2_with_2_squotes,
+    "LITERAL_LONG2 with 2 squotes \"\"\"a\"\"b\"\"\"",
+    "LITERAL_LONG2_with_2_squotes",
+    "LITERAL_LONG2_with_2_squotes.nq"
+);
+
+eval_test!(
+    literal_with_character_tabulation,
+    "literal with CHARACTER TABULATION",
+    "literal_with_CHARACTER_TABULATION",
+    "literal_with_CHARACTER_TABULATION.nq"
+);
+
+eval_test!(
+    literal_with_backspace,
+    "literal with BACKSPACE",
+    "literal_with_BACKSPACE",
+    "literal_with_BACKSPACE.nq"
+);
+
+eval_test!(
+    literal_with_line_feed,
+    "literal with LINE FEED",
+    "literal_with_LINE_FEED",
+    "literal_with_LINE_FEED.nq"
+);
+
+eval_test!(
+    literal_with_carriage_return,
+    "literal with CARRIAGE RETURN",
+    "literal_with_CARRIAGE_RETURN",
+    "literal_with_CARRIAGE_RETURN.nq"
+);
+
+eval_test!(
+    literal_with_form_feed,
+    "literal with FORM FEED",
+    "literal_with_FORM_FEED",
+    "literal_with_FORM_FEED.nq"
+);
+
+eval_test!(
+    literal_with_reverse_solidus,
+    "literal with REVERSE SOLIDUS",
+    "literal_with_REVERSE_SOLIDUS",
+    "literal_with_REVERSE_SOLIDUS.nq"
+);
+
+eval_test!(
+    literal_with_escaped_character_tabulation,
+    "literal with escaped CHARACTER TABULATION",
+    "literal_with_escaped_CHARACTER_TABULATION",
+    "literal_with_CHARACTER_TABULATION.nq"
+);
+
+eval_test!(
+    literal_with_escaped_backspace,
+    "literal with escaped BACKSPACE",
+    "literal_with_escaped_BACKSPACE",
+    "literal_with_BACKSPACE.nq"
+);
+
+eval_test!(
+    literal_with_escaped_line_feed,
+    "literal with escaped LINE FEED",
+    "literal_with_escaped_LINE_FEED",
+    "literal_with_LINE_FEED.nq"
+);
+
+eval_test!(
+    literal_with_escaped_carriage_return,
+    "literal with escaped CARRIAGE RETURN",
+    "literal_with_escaped_CARRIAGE_RETURN",
+    "literal_with_CARRIAGE_RETURN.nq"
+);
+
+eval_test!(
+    literal_with_escaped_form_feed,
+    "literal with escaped FORM FEED",
+    "literal_with_escaped_FORM_FEED",
+    "literal_with_FORM_FEED.nq"
+);
+
+eval_test!(
+    literal_with_numeric_escape4,
+    "literal with numeric escape4 \\u",
+    "literal_with_numeric_escape4",
+    "literal_with_numeric_escape4.nq"
+);
+
+eval_test!(
+    literal_with_numeric_escape8,
+    "literal with numeric escape8 \\U",
+    "literal_with_numeric_escape8",
+    "literal_with_numeric_escape4.nq"
+);
+
+eval_test!(
+    iriref_datatype,
+    "IRIREF datatype \"\"^^<t>",
+    "IRIREF_datatype",
+    "IRIREF_datatype.nq"
+);
+
+eval_test!(
+    prefixed_name_datatype,
+    "prefixed name datatype \"\"^^p:t",
+    "prefixed_name_datatype",
+    "IRIREF_datatype.nq"
+);
+
+eval_test!(
+    bareword_integer,
+    "bareword integer",
+    "bareword_integer",
+    "IRIREF_datatype.nq"
+);
+
+eval_test!(
+    bareword_decimal,
+    "bareword decimal",
+    "bareword_decimal",
+    "bareword_decimal.nq"
+);
+
+eval_test!(
+    bareword_double,
+    "bareword double",
+    "bareword_double",
+    "bareword_double.nq"
+);
+
+eval_test!(
+    double_lower_case_e,
+    "double lower case e",
+    "double_lower_case_e",
+    "double_lower_case_e.nq"
+);
+
+eval_test!(
+    negative_numeric,
+    "negative numeric",
+    "negative_numeric",
+    "negative_numeric.nq"
+);
+
+eval_test!(
+    positive_numeric,
+    "positive numeric",
+    "positive_numeric",
+    "positive_numeric.nq"
+);
+
+eval_test!(
+    numeric_with_leading_0,
+    "numeric with leading 0",
+    "numeric_with_leading_0",
+    "numeric_with_leading_0.nq"
+);
+
+eval_test!(
+    literal_true,
+    "literal true",
+    "literal_true",
+    "literal_true.nq"
+);
+
+eval_test!(
+    literal_false,
+    "literal false",
+    "literal_false",
+    "literal_false.nq"
+);
+
+eval_test!(
+    langtagged_non_long,
+    "langtagged non-LONG \"x\"@en",
+    "langtagged_non_LONG",
+    "langtagged_non_LONG.nq"
+);
+
+eval_test!(
+    langtagged_long,
+    "langtagged LONG \"\"\"x\"\"\"@en",
+    "langtagged_LONG",
+    "langtagged_non_LONG.nq"
+);
+
+eval_test!(
+    lantag_with_subtag,
+    "lantag with subtag \"x\"@en-us",
+    "lantag_with_subtag",
+    "lantag_with_subtag.nq"
+);
+
+eval_test!(
+    objectlist_with_two_objects,
+    "objectList with two objects … <o1>,<o2>",
+    "objectList_with_two_objects",
+    "objectList_with_two_objects.nq"
+);
+
+eval_test!(
+    predicateobjectlist_with_two_objectlists,
+    "predicateObjectList with two objectLists … <o1>,<o2>",
+    "predicateObjectList_with_two_objectLists",
+    "predicateObjectList_with_two_objectLists.nq"
+);
+
+eval_test!(
+    repeated_semis_at_end,
+    "repeated semis at end <s> <p> <o> ;; <p2> <o2> .",
+    "repeated_semis_at_end",
+    "predicateObjectList_with_two_objectLists.nq"
+);
+
+eval_test!(
+    repeated_semis_not_at_end,
+    "repeated semis not at end <s> <p> <o> ;;.",
+    "repeated_semis_not_at_end",
+    "repeated_semis_not_at_end.nq"
+);
+
+positive_test!(trig_syntax_file_01, "Empty file", "trig-syntax-file-01");
+
+positive_test!(trig_syntax_file_02, "Only comment", "trig-syntax-file-02");
+
+positive_test!(
+    trig_syntax_file_03,
+    "One comment, one empty line",
+    "trig-syntax-file-03"
+);
+
+positive_test!(trig_syntax_uri_01, "Only IRIs", "trig-syntax-uri-01");
+
+positive_test!(
+    trig_syntax_uri_02,
+    "IRIs with Unicode escape",
+    "trig-syntax-uri-02"
+);
+
+positive_test!(
+    trig_syntax_uri_03,
+    "IRIs with long Unicode escape",
+    "trig-syntax-uri-03"
+);
+
+positive_test!(trig_syntax_uri_04, "Legal IRIs", "trig-syntax-uri-04");
+
+positive_test!(trig_syntax_base_01, "@base", "trig-syntax-base-01");
+
+positive_test!(trig_syntax_base_02, "BASE", "trig-syntax-base-02");
+
+positive_test!(
+    trig_syntax_base_03,
+    "@base with relative IRIs",
+    "trig-syntax-base-03"
+);
+
+positive_test!(
+    trig_syntax_base_04,
+    "base with relative IRIs",
+    "trig-syntax-base-04"
+);
+
+positive_test!(trig_syntax_prefix_01, "@prefix", "trig-syntax-prefix-01");
+
+positive_test!(trig_syntax_prefix_02, "PreFIX", "trig-syntax-prefix-02");
+
+positive_test!(
+    trig_syntax_prefix_03,
+    "Empty PREFIX",
+    "trig-syntax-prefix-03"
+);
+
+positive_test!(
+    trig_syntax_prefix_04,
+    "Empty @prefix with % escape",
+    "trig-syntax-prefix-04"
+);
+
+positive_test!(
+    trig_syntax_prefix_05,
+    "@prefix with no suffix",
+    "trig-syntax-prefix-05"
+);
+
+positive_test!(
+    trig_syntax_prefix_06,
+    "colon is a legal pname character",
+    "trig-syntax-prefix-06"
+);
+
+positive_test!(
+    trig_syntax_prefix_07,
+    "dash is a legal pname character",
+    "trig-syntax-prefix-07"
+);
+
+positive_test!(
+    trig_syntax_prefix_08,
+    "underscore is a legal pname character",
+    "trig-syntax-prefix-08"
+);
+
+positive_test!(
+    trig_syntax_prefix_09,
+    "percents in pnames",
+    "trig-syntax-prefix-09"
+);
+
+positive_test!(
+    trig_syntax_string_01,
+    "string literal",
+    "trig-syntax-string-01"
+);
+
+positive_test!(
+    trig_syntax_string_02,
+    "langString literal",
+    "trig-syntax-string-02"
+);
+
+positive_test!(
+    trig_syntax_string_03,
+    "langString literal with region",
+    "trig-syntax-string-03"
+);
+
+positive_test!(
+    trig_syntax_string_04,
+    "squote string literal",
+    "trig-syntax-string-04"
+);
+
+positive_test!(
+    trig_syntax_string_05,
+    "squote langString literal",
+    "trig-syntax-string-05"
+);
+
+positive_test!(
+    trig_syntax_string_06,
+    "squote langString literal with region",
+    "trig-syntax-string-06"
+);
+
+positive_test!(
+    trig_syntax_string_07,
+    "long string literal with embedded single- and double-quotes",
+    "trig-syntax-string-07"
+);
+
+positive_test!(
+    trig_syntax_string_08,
+    "long string literal with embedded newline",
+    "trig-syntax-string-08"
+);
+
+positive_test!(
+    trig_syntax_string_09,
+    "squote long string literal with embedded single- and double-quotes",
+    "trig-syntax-string-09"
+);
+
+positive_test!(
+    trig_syntax_string_10,
+    "long langString literal with embedded newline",
+    "trig-syntax-string-10"
+);
+
+positive_test!(
+    trig_syntax_string_11,
+    "squote long langString literal with embedded newline",
+    "trig-syntax-string-11"
+);
+
+positive_test!(
+    trig_syntax_str_esc_01,
+    "string literal with escaped newline",
+    "trig-syntax-str-esc-01"
+);
+
+positive_test!(
+    trig_syntax_str_esc_02,
+    "string literal with Unicode escape",
+    "trig-syntax-str-esc-02"
+);
+
+positive_test!(
+    trig_syntax_str_esc_03,
+    "string literal with long Unicode escape",
+    "trig-syntax-str-esc-03"
+);
+
+positive_test!(
+    trig_syntax_pname_esc_01,
+    "pname with back-slash escapes",
+    "trig-syntax-pname-esc-01"
+);
+
+positive_test!(
+    trig_syntax_pname_esc_02,
+    "pname with back-slash escapes (2)",
+    "trig-syntax-pname-esc-02"
+);
+
+positive_test!(
+    trig_syntax_pname_esc_03,
+    "pname with back-slash escapes (3)",
+    "trig-syntax-pname-esc-03"
+);
+
+positive_test!(
+    trig_syntax_bnode_01,
+    "bnode subject",
+    "trig-syntax-bnode-01"
+);
+
+positive_test!(trig_syntax_bnode_02, "bnode object", "trig-syntax-bnode-02");
+
+positive_test!(
+    trig_syntax_bnode_03,
+    "bnode property list object",
+    "trig-syntax-bnode-03"
+);
+
+positive_test!(
+    trig_syntax_bnode_04,
+    "bnode property list object (2)",
+    "trig-syntax-bnode-04"
+);
+
+positive_test!(
+    trig_syntax_bnode_05,
+    "bnode property list subject",
+    "trig-syntax-bnode-05"
+);
+
+positive_test!(
+    trig_syntax_bnode_06,
+    "labeled bnode subject",
+    "trig-syntax-bnode-06"
+);
+
+positive_test!(
+    trig_syntax_bnode_07,
+    "labeled bnode subject and object",
+    "trig-syntax-bnode-07"
+);
+
+positive_test!(
+    trig_syntax_bnode_08,
+    "bare bnode property list",
+    "trig-syntax-bnode-08"
+);
+
+positive_test!(
+    trig_syntax_bnode_09,
+    "bnode property list",
+    "trig-syntax-bnode-09"
+);
+
+positive_test!(
+    trig_syntax_bnode_10,
+    "mixed bnode property list and triple",
+    "trig-syntax-bnode-10"
+);
+
+positive_test!(
+    trig_syntax_number_01,
+    "integer literal",
+    "trig-syntax-number-01"
+);
+
+positive_test!(
+    trig_syntax_number_02,
+    "negative integer literal",
+    "trig-syntax-number-02"
+);
+
+positive_test!(
+    trig_syntax_number_03,
+    "positive integer literal",
+    "trig-syntax-number-03"
+);
+
+positive_test!(
+    trig_syntax_number_04,
+    "decimal literal",
+    "trig-syntax-number-04"
+);
+
+positive_test!(
+    trig_syntax_number_05,
+    "decimal literal (no leading digits)",
+    "trig-syntax-number-05"
+);
+
+positive_test!(
+    trig_syntax_number_06,
+    "negative decimal literal",
+    "trig-syntax-number-06"
+);
+
+positive_test!(
+    trig_syntax_number_07,
+    "positive decimal literal",
+    "trig-syntax-number-07"
+);
+
+positive_test!(
+    trig_syntax_number_08,
+    "integer literal with decimal lexical confusion",
+    "trig-syntax-number-08"
+);
+
+positive_test!(
+    trig_syntax_number_09,
+    "double literal",
+    "trig-syntax-number-09"
+);
+
+positive_test!(
+    trig_syntax_number_10,
+    "negative double literal",
+    "trig-syntax-number-10"
+);
+
+positive_test!(
+    trig_syntax_number_11,
+    "double literal no fraction",
+    "trig-syntax-number-11"
+);
+
+positive_test!(
+    trig_syntax_datatypes_01,
+    "xsd:byte literal",
+    "trig-syntax-datatypes-01"
+);
+
+positive_test!(
+    trig_syntax_datatypes_02,
+    "integer as xsd:string",
+    "trig-syntax-datatypes-02"
+);
+
+positive_test!(
+    trig_syntax_kw_01,
+    "boolean literal (true)",
+    "trig-syntax-kw-01"
+);
+
+positive_test!(
+    trig_syntax_kw_02,
+    "boolean literal (false)",
+    "trig-syntax-kw-02"
+);
+
+positive_test!(trig_syntax_kw_03, "'a' as keyword", "trig-syntax-kw-03");
+
+positive_test!(
+    trig_syntax_struct_01,
+    "object list",
+    "trig-syntax-struct-01"
+);
+
+positive_test!(
+    trig_syntax_struct_02,
+    "predicate list with object list",
+    "trig-syntax-struct-02"
+);
+
+positive_test!(
+    trig_syntax_struct_03,
+    "predicate list with object list and dangling ';'",
+    "trig-syntax-struct-03"
+);
+
+positive_test!(
+    trig_syntax_struct_04,
+    "predicate list with multiple ;;",
+    "trig-syntax-struct-04"
+);
+
+positive_test!(
+    trig_syntax_struct_05,
+    "predicate list with multiple ;;",
+    "trig-syntax-struct-05"
+);
+
+positive_test!(trig_syntax_lists_01, "empty list", "trig-syntax-lists-01");
+
+positive_test!(trig_syntax_lists_02, "mixed list", "trig-syntax-lists-02");
+
+positive_test!(
+    trig_syntax_lists_03,
+    "isomorphic list as subject and object",
+    "trig-syntax-lists-03"
+);
+
+positive_test!(
+    trig_syntax_lists_04,
+    "lists of lists",
+    "trig-syntax-lists-04"
+);
+
+positive_test!(
+    trig_syntax_lists_05,
+    "mixed lists with embedded lists",
+    "trig-syntax-lists-05"
+);
+
+negative_test!(
+    trig_syntax_bad_uri_01,
+    "Bad IRI : space (negative test)",
+    "trig-syntax-bad-uri-01"
+);
+
+negative_test!(
+    trig_syntax_bad_uri_02,
+    "Bad IRI : bad escape (negative test)",
+    "trig-syntax-bad-uri-02"
+);
+
+negative_test!(
+    trig_syntax_bad_uri_03,
+    "Bad IRI : bad long escape (negative test)",
+    "trig-syntax-bad-uri-03"
+);
+
+negative_test!(
+    trig_syntax_bad_uri_04,
+    "Bad IRI : character escapes not allowed (negative test)",
+    "trig-syntax-bad-uri-04"
+);
+
+negative_test!(
+    trig_syntax_bad_uri_05,
+    "Bad IRI : character escapes not allowed (2) (negative test)",
+    "trig-syntax-bad-uri-05"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_01,
+    "No prefix (negative test)",
+    "trig-syntax-bad-prefix-01"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_02,
+    "No prefix (2) (negative test)",
+    "trig-syntax-bad-prefix-02"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_03,
+    "@prefix without URI (negative test)",
+    "trig-syntax-bad-prefix-03"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_04,
+    "@prefix without prefix name (negative test)",
+    "trig-syntax-bad-prefix-04"
+);
+
+negative_test!(
+    trig_syntax_bad_prefix_05,
+    "@prefix without ':' (negative test)",
+    "trig-syntax-bad-prefix-05"
+);
+
+negative_test!(
+    trig_syntax_bad_base_01,
+    "@base without URI (negative test)",
+    "trig-syntax-bad-base-01"
+);
+
+negative_test!(
+    trig_syntax_bad_base_02,
+    "@base in wrong case (negative test)",
+    "trig-syntax-bad-base-02"
+);
+
+negative_test!(
+    trig_syntax_bad_base_03,
+    "BASE without URI (negative test)",
+    "trig-syntax-bad-base-03"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_02,
+    "Turtle is not N3 (negative test)",
+    "trig-syntax-bad-struct-02"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_03,
+    "Turtle is not NQuads (negative test)",
+    "trig-syntax-bad-struct-03"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_04,
+    "Turtle does not allow literals-as-subjects (negative test)",
+    "trig-syntax-bad-struct-04"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_05,
+    "Turtle does not allow literals-as-predicates (negative test)",
+    "trig-syntax-bad-struct-05"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_06,
+    "Turtle does not allow bnodes-as-predicates (negative test)",
+    "trig-syntax-bad-struct-06"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_07,
+    "Turtle does not allow labeled bnodes-as-predicates (negative test)",
+    "trig-syntax-bad-struct-07"
+);
+
+negative_test!(
+    trig_syntax_bad_kw_01,
+    "'A' is not a keyword (negative test)",
+    "trig-syntax-bad-kw-01"
+);
+
+negative_test!(
+    trig_syntax_bad_kw_02,
+    "'a' cannot be used as subject (negative test)",
+    "trig-syntax-bad-kw-02"
+);
+
+negative_test!(
+    trig_syntax_bad_kw_03,
+    "'a' cannot be used as object (negative test)",
+    "trig-syntax-bad-kw-03"
+);
+
+negative_test!(
+    trig_syntax_bad_kw_04,
+    "'true' cannot be used as subject (negative test)",
+    "trig-syntax-bad-kw-04"
+);
+
+negative_test!(
+    trig_syntax_bad_kw_05,
+    "'true' cannot be used as object (negative test)",
+    "trig-syntax-bad-kw-05"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_01,
+    "{{}} fomulae not in Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-01"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_02,
+    "= is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-02"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_03,
+    "N3 paths not in Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-03"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_04,
+    "N3 paths not in Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-04"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_05,
+    "N3 is...of not in Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-05"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_06,
+    "N3 paths not in Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-06"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_07,
+    "@keywords is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-07"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_08,
+    "@keywords is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-08"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_09,
+    "=> is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-09"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_10,
+    "<= is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-10"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_11,
+    "@forSome is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-11"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_12,
+    "@forAll is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-12"
+);
+
+negative_test!(
+    trig_syntax_bad_n3_extras_13,
+    "@keywords is not Turtle (negative test)",
+    "trig-syntax-bad-n3-extras-13"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_09,
+    "extra '.' (negative test)",
+    "trig-syntax-bad-struct-09"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_10,
+    "extra '.' (negative test)",
+    "trig-syntax-bad-struct-10"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_12,
+    "subject, predicate, no object (negative test)",
+    "trig-syntax-bad-struct-12"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_13,
+    "subject, predicate, no object (negative test)",
+    "trig-syntax-bad-struct-13"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_14,
+    "literal as subject (negative test)",
+    "trig-syntax-bad-struct-14"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_15,
+    "literal as predicate (negative test)",
+    "trig-syntax-bad-struct-15"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_16,
+    "bnode as predicate (negative test)",
+    "trig-syntax-bad-struct-16"
+);
+
+negative_test!(
+    trig_syntax_bad_struct_17,
+    "labeled bnode as predicate (negative test)",
+    "trig-syntax-bad-struct-17"
+);
+
+negative_test!(
+    trig_syntax_bad_lang_01,
+    "langString with bad lang (negative test)",
+    "trig-syntax-bad-lang-01"
+);
+
+negative_test!(
+    trig_syntax_bad_esc_01,
+    "Bad string escape (negative test)",
+    "trig-syntax-bad-esc-01"
+);
+
+negative_test!(
+    trig_syntax_bad_esc_02,
+    "Bad string escape (negative test)",
+    "trig-syntax-bad-esc-02"
+);
+
+negative_test!(
+    trig_syntax_bad_esc_03,
+    "Bad string escape (negative test)",
+    "trig-syntax-bad-esc-03"
+);
+
+negative_test!(
+    trig_syntax_bad_esc_04,
+    "Bad string escape (negative test)",
+    "trig-syntax-bad-esc-04"
+);
+
+negative_test!(
+    trig_syntax_bad_pname_01,
+    "'~' must be escaped in pname (negative test)",
+    "trig-syntax-bad-pname-01"
+);
+
+negative_test!(
+    trig_syntax_bad_pname_02,
+    "Bad %-sequence in pname (negative test)",
+    "trig-syntax-bad-pname-02"
+);
+
+negative_test!(
+    trig_syntax_bad_pname_03,
+    "Bad unicode escape in pname (negative test)",
+    "trig-syntax-bad-pname-03"
+);
+
+negative_test!(
+    trig_syntax_bad_string_01,
+    "mismatching string literal open/close (negative test)",
+    "trig-syntax-bad-string-01"
+);
+
+negative_test!(
+    trig_syntax_bad_string_02,
+    "mismatching string literal open/close (negative test)",
+    "trig-syntax-bad-string-02"
+);
+
+negative_test!(
+    trig_syntax_bad_string_03,
+    "mismatching string literal long/short (negative test)",
+    "trig-syntax-bad-string-03"
+);
+
+negative_test!(
+    trig_syntax_bad_string_04,
+    "mismatching long string literal open/close (negative test)",
+    "trig-syntax-bad-string-04"
+);
+
+negative_test!(
+    trig_syntax_bad_string_05,
+    "Long literal with missing end (negative test)",
+    "trig-syntax-bad-string-05"
+);
+
+negative_test!(
+    trig_syntax_bad_string_06,
+    "Long literal with extra quote (negative test)",
+    "trig-syntax-bad-string-06"
+);
+
+negative_test!(
+    trig_syntax_bad_string_07,
+    "Long literal with extra squote (negative test)",
+    "trig-syntax-bad-string-07"
+);
+
+negative_test!(
+    trig_syntax_bad_num_01,
+    "Bad number format (negative test)",
+    "trig-syntax-bad-num-01"
+);
+
+negative_test!(
+    trig_syntax_bad_num_02,
+    "Bad number format (negative test)",
+    "trig-syntax-bad-num-02"
+);
+
+negative_test!(
+    trig_syntax_bad_num_03,
+    "Bad number format (negative test)",
+    "trig-syntax-bad-num-03"
+);
+
+negative_test!(
+    trig_syntax_bad_num_04,
+    "Bad number format (negative test)",
+    "trig-syntax-bad-num-04"
+);
+
+negative_test!(
+    trig_syntax_bad_num_05,
+    "Bad number format (negative test)",
+    "trig-syntax-bad-num-05"
+);
+
+eval_test!(
+    trig_eval_struct_01,
+    "triple with IRIs",
+    "trig-eval-struct-01",
+    "trig-eval-struct-01.nq"
+);
+
+eval_test!(
+    trig_eval_struct_02,
+    "triple with IRIs and embedded whitespace",
+    "trig-eval-struct-02",
+    "trig-eval-struct-02.nq"
+);
+
+eval_test!(
+    trig_subm_01,
+    "Blank subject",
+    "trig-subm-01",
+    "trig-subm-01.nq"
+);
+
+eval_test!(
+    trig_subm_02,
+    "@prefix and qnames",
+    "trig-subm-02",
+    "trig-subm-02.nq"
+);
+
+eval_test!(
+    trig_subm_03,
+    ", operator",
+    "trig-subm-03",
+    "trig-subm-03.nq"
+);
+
+eval_test!(
+    trig_subm_04,
+    "; operator",
+    "trig-subm-04",
+    "trig-subm-04.nq"
+);
+
+eval_test!(
+    trig_subm_05,
+    "empty [] as subject and object",
+    "trig-subm-05",
+    "trig-subm-05.nq"
+);
+
+eval_test!(
+    trig_subm_06,
+    "non-empty [] as subject and object",
+    "trig-subm-06",
+    "trig-subm-06.nq"
+);
+
+eval_test!(
+    trig_subm_07,
+    "'a' as predicate",
+    "trig-subm-07",
+    "trig-subm-07.nq"
+);
+
+eval_test!(
+    trig_subm_08,
+    "simple collection",
+    "trig-subm-08",
+    "trig-subm-08.nq"
+);
+
+eval_test!(
+    trig_subm_09,
+    "empty collection",
+    "trig-subm-09",
+    "trig-subm-09.nq"
+);
+
+eval_test!(
+    trig_subm_10,
+    "integer datatyped literal",
+    "trig-subm-10",
+    "trig-subm-10.nq"
+);
+
+eval_test!(
+    trig_subm_11,
+    "decimal integer canonicalization",
+    "trig-subm-11",
+    "trig-subm-11.nq"
+);
+
+eval_test!(
+    trig_subm_12,
+    "- and _ in names and qnames",
+    "trig-subm-12",
+    "trig-subm-12.nq"
+);
+
+eval_test!(
+    trig_subm_13,
+    "tests for rdf:_<numbers> and other qnames starting with _",
+    "trig-subm-13",
+    "trig-subm-13.nq"
+);
+
+eval_test!(
+    trig_subm_14,
+    "bare : allowed",
+    "trig-subm-14",
+    "trig-subm-14.nq"
+);
+
+eval_test!(
+    trig_subm_15,
+    "simple long literal",
+    "trig-subm-15",
+    "trig-subm-15.nq"
+);
+
+eval_test!(
+    trig_subm_16,
+    "long literals with escapes",
+    "trig-subm-16",
+    "trig-subm-16.nq"
+);
+
+eval_test!(
+    trig_subm_17,
+    "floating point number",
+    "trig-subm-17",
+    "trig-subm-17.nq"
+);
+
+eval_test!(
+    trig_subm_18,
+    "empty literals, normal and long variant",
+    "trig-subm-18",
+    "trig-subm-18.nq"
+);
+
+eval_test!(
+    trig_subm_19,
+    "positive integer, decimal and doubles",
+    "trig-subm-19",
+    "trig-subm-19.nq"
+);
+
+eval_test!(
+    trig_subm_20,
+    "negative integer, decimal and doubles",
+    "trig-subm-20",
+    "trig-subm-20.nq"
+);
+
+eval_test!(
+    trig_subm_21,
+    "long literal ending in double quote",
+    "trig-subm-21",
+    "trig-subm-21.nq"
+);
+
+eval_test!(
+    trig_subm_22,
+    "boolean literals",
+    "trig-subm-22",
+    "trig-subm-22.nq"
+);
+
+eval_test!(trig_subm_23, "comments", "trig-subm-23", "trig-subm-23.nq");
+
+eval_test!(
+    trig_subm_24,
+    "no final mewline",
+    "trig-subm-24",
+    "trig-subm-24.nq"
+);
+
+eval_test!(
+    trig_subm_25,
+    "repeating a @prefix changes pname definition",
+    "trig-subm-25",
+    "trig-subm-25.nq"
+);
+
+eval_test!(
+    trig_subm_26,
+    "Variations on decimal canonicalization",
+    "trig-subm-26",
+    "trig-subm-26.nq"
+);
+
+eval_test!(
+    trig_subm_27,
+    "Repeating @base changes base for relative IRI lookup",
+    "trig-subm-27",
+    "trig-subm-27.nq"
+);
+
+negative_test!(
+    trig_eval_bad_01,
+    "Bad IRI : good escape, bad charcater (negative evaluation test)",
+    "trig-eval-bad-01"
+);
+
+negative_test!(
+    trig_eval_bad_02,
+    "Bad IRI : hex 3C is < (negative evaluation test)",
+    "trig-eval-bad-02"
+);
+
+negative_test!(
+    trig_eval_bad_03,
+    "Bad IRI : hex 3E is  (negative evaluation test)",
+    "trig-eval-bad-03"
+);
+
+negative_test!(
+    trig_eval_bad_04,
+    "Bad IRI : {{abc}} (negative evaluation test)",
+    "trig-eval-bad-04"
+);
+
+eval_test!(
+    comment_following_localname,
+    "comment following localName",
+    "comment_following_localName",
+    "IRI_spo.nq"
+);
+
+eval_test!(
+    number_sign_following_localname,
+    "number sign following localName",
+    "number_sign_following_localName",
+    "number_sign_following_localName.nq"
+);
+
+eval_test!(
+    comment_following_pname_ns,
+    "comment following PNAME_NS",
+    "comment_following_PNAME_NS",
+    "comment_following_PNAME_NS.nq"
+);
+
+eval_test!(
+    number_sign_following_pname_ns,
+    "number sign following PNAME_NS",
+    "number_sign_following_PNAME_NS",
+    "number_sign_following_PNAME_NS.nq"
+);
+
+eval_test!(
+    literal_long2_with_reverse_solidus,
+    "REVERSE SOLIDUS at end of LITERAL_LONG2",
+    "LITERAL_LONG2_with_REVERSE_SOLIDUS",
+    "LITERAL_LONG2_with_REVERSE_SOLIDUS.nq"
+);
+
+negative_test!(
+    trig_syntax_bad_literal2_with_langtag_and_datatype,
+    "Bad number format (negative test)",
+    "trig-syntax-bad-LITERAL2_with_langtag_and_datatype"
+);
+
+eval_test!(
+    two_literal_long2s,
+    "two LITERAL_LONG2s testing quote delimiter overrun",
+    "two_LITERAL_LONG2s",
+    "two_LITERAL_LONG2s.nq"
+);
+
+eval_test!(
+    langtagged_long_with_subtag,
+    "langtagged LONG with subtag \"\"\"Cheers\"\"\"@en-UK",
+    "langtagged_LONG_with_subtag",
+    "langtagged_LONG_with_subtag.nq"
+);
+
+negative_test!(
+    trig_syntax_bad_blank_label_dot_end,
+    "Blank node label must not end in dot",
+    "trig-syntax-bad-blank-label-dot-end"
+);
+
+negative_test!(
+    trig_syntax_bad_number_dot_in_anon,
+    "Dot delimeter may not appear in anonymous nodes",
+    "trig-syntax-bad-number-dot-in-anon"
+);
+
+negative_test!(
+    trig_syntax_bad_ln_dash_start,
+    "Local name must not begin with dash",
+    "trig-syntax-bad-ln-dash-start"
+);
+
+negative_test!(
+    trig_syntax_bad_ln_escape,
+    "Bad hex escape in local name",
+    "trig-syntax-bad-ln-escape"
+);
+
+negative_test!(
+    trig_syntax_bad_ln_escape_start,
+    "Bad hex escape at start of local name",
+    "trig-syntax-bad-ln-escape-start"
+);
+
+negative_test!(
+    trig_syntax_bad_ns_dot_end,
+    "Prefix must not end in dot",
+    "trig-syntax-bad-ns-dot-end"
+);
+
+negative_test!(
+    trig_syntax_bad_ns_dot_start,
+    "Prefix must not start with dot",
+    "trig-syntax-bad-ns-dot-start"
+);
+
+negative_test!(trig_syntax_bad_missing_ns_dot_end, "Prefix must not end in dot (error in triple, not prefix directive like trig-syntax-bad-ns-dot-end)", "trig-syntax-bad-missing-ns-dot-end");
+
+negative_test!(trig_syntax_bad_missing_ns_dot_start, "Prefix must not start with dot (error in triple, not prefix directive like trig-syntax-bad-ns-dot-end)", "trig-syntax-bad-missing-ns-dot-start");
+
+negative_test!(
+    trig_syntax_bad_list_01,
+    "Free-standing list outside {{}} : bad syntax",
+    "trig-syntax-bad-list-01"
+);
+
+negative_test!(
+    trig_syntax_bad_list_02,
+    "Free-standing list of zero-elements outside {{}} : bad syntax",
+    "trig-syntax-bad-list-02"
+);
+
+negative_test!(
+    trig_syntax_bad_list_03,
+    "Free-standing list inside {{}} : bad syntax",
+    "trig-syntax-bad-list-03"
+);
+
+negative_test!(
+    trig_syntax_bad_list_04,
+    "Free-standing list of zero elements : bad syntax",
+    "trig-syntax-bad-list-04"
+);
+
+positive_test!(
+    trig_syntax_ln_dots,
+    "Dots in pname local names",
+    "trig-syntax-ln-dots"
+);
+
+positive_test!(
+    trig_syntax_ln_colons,
+    "Colons in pname local names",
+    "trig-syntax-ln-colons"
+);
+
+positive_test!(
+    trig_syntax_ns_dots,
+    "Dots in namespace names",
+    "trig-syntax-ns-dots"
+);
+
+positive_test!(
+    trig_syntax_blank_label,
+    "Characters allowed in blank node labels",
+    "trig-syntax-blank-label"
+);
+
+positive_test!(
+    trig_kw_graph_01,
+    "Named graphs can be proceeded by GRAPH",
+    "trig-kw-graph-01"
+);
+
+positive_test!(
+    trig_kw_graph_02,
+    "Trailing . not necessary inside {{}}",
+    "trig-kw-graph-02"
+);
+
+positive_test!(
+    trig_kw_graph_03,
+    "Named graph may be empty",
+    "trig-kw-graph-03"
+);
+
+positive_test!(trig_kw_graph_04, "", "trig-kw-graph-04");
+
+positive_test!(
+    trig_kw_graph_05,
+    "Use of empty prefix inside named graph",
+    "trig-kw-graph-05"
+);
+
+positive_test!(trig_kw_graph_06, "", "trig-kw-graph-06");
+
+positive_test!(
+    trig_kw_graph_07,
+    "Named graph may be named with BNode _:a",
+    "trig-kw-graph-07"
+);
+
+positive_test!(
+    trig_kw_graph_08,
+    "Named graph may be named with BNode []",
+    "trig-kw-graph-08"
+);
+
+positive_test!(
+    trig_kw_graph_09,
+    "Named graph may be named with PNAME",
+    "trig-kw-graph-09"
+);
+
+positive_test!(
+    trig_kw_graph_10,
+    "Named graph with PNAME and empty graph",
+    "trig-kw-graph-10"
+);
+
+negative_test!(
+    trig_graph_bad_01,
+    "GRAPH but no name - GRAPH is not used with the default graph",
+    "trig-graph-bad-01"
+);
+
+negative_test!(
+    trig_graph_bad_02,
+    "GRAPH not followed by DOT",
+    "trig-graph-bad-02"
+);
+
+negative_test!(trig_graph_bad_03, "GRAPH needs {{}}", "trig-graph-bad-03");
+
+negative_test!(trig_graph_bad_04, "GRAPH needs {{}}", "trig-graph-bad-04");
+
+negative_test!(
+    trig_graph_bad_05,
+    "GRAPH and a name, not several",
+    "trig-graph-bad-05"
+);
+
+negative_test!(
+    trig_graph_bad_06,
+    "GRAPH - Must close {{}}",
+    "trig-graph-bad-06"
+);
+
+negative_test!(
+    trig_graph_bad_07,
+    "GRAPH may not include a GRAPH",
+    "trig-graph-bad-07"
+);
+
+negative_test!(
+    trig_graph_bad_08,
+    "@graph is not a keyword",
+    "trig-graph-bad-08"
+);
+
+negative_test!(
+    trig_graph_bad_09,
+    "Directives not allowed inside GRAPH",
+    "trig-graph-bad-09"
+);
+
+negative_test!(
+    trig_graph_bad_10,
+    "A graph may not be named with an empty collection",
+    "trig-graph-bad-10"
+);
+
+negative_test!(
+    trig_graph_bad_11,
+    "A graph may not be named with a collection",
+    "trig-graph-bad-11"
+);
+
+negative_test!(
+    trig_bnodeplist_graph_01,
+    "A graph may not be named with a blankNodePropertyList",
+    "trig-bnodeplist-graph-bad-01"
+);
+
+negative_test!(
+    trig_collection_graph_01,
+    "A graph may not be named with an empty collection",
+    "trig-collection-graph-bad-01"
+);
+
+negative_test!(
+    trig_collection_graph_02,
+    "A graph may not be named with a collection",
+    "trig-collection-graph-bad-02"
+);
+
+positive_test!(trig_turtle_01, "TriG can parse Turtle", "trig-turtle-01");
+
+positive_test!(
+    trig_turtle_02,
+    "TriG can parse Turtle (repeated PREFIX)",
+    "trig-turtle-02"
+);
+
+positive_test!(
+    trig_turtle_03,
+    "TriG can parse Turtle (blankNodePropertyList subject)",
+    "trig-turtle-03"
+);
+
+positive_test!(
+    trig_turtle_04,
+    "TriG can parse Turtle (blankNodePropertyList subject)",
+    "trig-turtle-04"
+);
+
+positive_test!(
+    trig_turtle_05,
+    "TriG can parse Turtle (bare blankNodePropertyList)",
+    "trig-turtle-05"
+);
+
+positive_test!(
+    trig_turtle_06,
+    "TriG can parse Turtle (collection subject and object)",
+    "trig-turtle-06"
+);
+
+negative_test!(
+    trig_turtle_bad_01,
+    "Trailing dot required in Turtle block",
+    "trig-turtle-bad-01"
+);
+
+negative_test!(
+    trig_turtle_bad_02,
+    "TriG is not N-Quads",
+    "trig-turtle-bad-02"
+);
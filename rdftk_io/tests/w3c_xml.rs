@@ -0,0 +1,1151 @@
+#![cfg(all(feature = "xml", feature = "nt"))]
+
+use objio::ObjectReader;
+use rdftk_core::error::eprint_trace;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::nt::NTripleReader;
+use rdftk_io::xml::XmlReader;
+use rdftk_io::ReaderOptions;
+use rdftk_iri::Iri;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+mod common;
+
+macro_rules! negative_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            assert!(read_test_file($file).is_err());
+        }
+    };
+}
+
+macro_rules! eval_test {
+    ($name:ident, $comment:expr, $file:expr, $result_file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            let graph = match read_test_file($file) {
+                Ok(graph) => graph,
+                Err(e) => {
+                    eprint_trace(&e);
+                    panic!("{}", e);
+                }
+            };
+            let expected = read_result_file($result_file);
+            assert!(common::graphs_isomorphic(&graph, &expected));
+        }
+    };
+}
+
+const TEST_BASE: &str = "http://www.w3.org/2013/RDFXMLTests/";
+
+fn read_test_file(file_name: &str) -> Result<Graph, rdftk_core::error::Error> {
+    let file_path = PathBuf::from(format!("tests/w3c/xml/{}.rdf", file_name));
+    // The base IRI for each test is the retrieval IRI of the test file.
+    let base = Iri::from_str(&format!("{TEST_BASE}{file_name}.rdf")).unwrap();
+    let reader = XmlReader::default().with_options(ReaderOptions::default().with_base(base));
+    reader.read_from_file(file_path)
+}
+
+fn read_result_file(file_name: &str) -> Graph {
+    let file_path = PathBuf::from(format!("tests/w3c/xml/{}", file_name));
+    let reader = NTripleReader::default();
+    reader.read_from_file(file_path).unwrap()
+}
+
+eval_test!(
+    amp_in_url_test001,
+    "Description: the purpose of this test case is to show how one of XML's Predefined ...",
+    "amp-in-url/test001",
+    "amp-in-url/test001.nt"
+);
+
+eval_test!(
+    datatypes_test001,
+    "A simple datatype production; a language+datatype production.",
+    "datatypes/test001",
+    "datatypes/test001.nt"
+);
+
+eval_test!(
+    datatypes_test002,
+    "A parser is not required to know about well-formed datatyped literals.",
+    "datatypes/test002",
+    "datatypes/test002.nt"
+);
+
+eval_test!(
+    rdf_charmod_literals_test001,
+    "Does the treatment of literals conform to charmod ?",
+    "rdf-charmod-literals/test001",
+    "rdf-charmod-literals/test001.nt"
+);
+
+eval_test!(
+    rdf_charmod_uris_test001,
+    "A uriref is allowed to match non-US ASCII forms conforming to Unicode Normal Form C.",
+    "rdf-charmod-uris/test001",
+    "rdf-charmod-uris/test001.nt"
+);
+
+eval_test!(
+    rdf_charmod_uris_test002,
+    "A uriref which already has % escaping is permitted.",
+    "rdf-charmod-uris/test002",
+    "rdf-charmod-uris/test002.nt"
+);
+
+negative_test!(
+    rdf_containers_syntax_vs_schema_error001,
+    "rdf:li is not allowed as as an attribute",
+    "rdf-containers-syntax-vs-schema/error001"
+);
+
+negative_test!(
+    rdf_containers_syntax_vs_schema_error002,
+    "rdf:li elements as typed nodes - a bizarre case As specified in ...",
+    "rdf-containers-syntax-vs-schema/error002"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test001,
+    "Simple container",
+    "rdf-containers-syntax-vs-schema/test001",
+    "rdf-containers-syntax-vs-schema/test001.nt"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test002,
+    "rdf:li is unaffected by other rdf:_nnn properties.",
+    "rdf-containers-syntax-vs-schema/test002",
+    "rdf-containers-syntax-vs-schema/test002.nt"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test003,
+    "rdf:li elements can exist in any description element",
+    "rdf-containers-syntax-vs-schema/test003",
+    "rdf-containers-syntax-vs-schema/test003.nt"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test004,
+    "rdf:li elements match any of the property element productions",
+    "rdf-containers-syntax-vs-schema/test004",
+    "rdf-containers-syntax-vs-schema/test004.nt"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test006,
+    "containers match the typed node production",
+    "rdf-containers-syntax-vs-schema/test006",
+    "rdf-containers-syntax-vs-schema/test006.nt"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test007,
+    "rdf:li processing within each element is independent",
+    "rdf-containers-syntax-vs-schema/test007",
+    "rdf-containers-syntax-vs-schema/test007.nt"
+);
+
+eval_test!(
+    rdf_containers_syntax_vs_schema_test008,
+    "rdf:li processing is per element, not per resource.",
+    "rdf-containers-syntax-vs-schema/test008",
+    "rdf-containers-syntax-vs-schema/test008.nt"
+);
+
+eval_test!(
+    rdf_element_not_mandatory_test001,
+    "A surrounding rdf:RDF element is no longer mandatory.",
+    "rdf-element-not-mandatory/test001",
+    "rdf-element-not-mandatory/test001.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0001,
+    "RDF attributes that are required to have an rdf: prefix about aboutEach ID bagID type ...",
+    "rdf-ns-prefix-confusion/test0001",
+    "rdf-ns-prefix-confusion/test0001.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0003,
+    "RDF attributes that are required to have an rdf: prefix about aboutEach ID bagID type ...",
+    "rdf-ns-prefix-confusion/test0003",
+    "rdf-ns-prefix-confusion/test0003.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0004,
+    "RDF attributes that are required to have an rdf: prefix about aboutEach ID bagID type ...",
+    "rdf-ns-prefix-confusion/test0004",
+    "rdf-ns-prefix-confusion/test0004.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0005,
+    "RDF attributes that are required to have an rdf: prefix about aboutEach ID bagID type ...",
+    "rdf-ns-prefix-confusion/test0005",
+    "rdf-ns-prefix-confusion/test0005.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0006,
+    "RDF attributes that are required to have an rdf: prefix about aboutEach ID bagID type ...",
+    "rdf-ns-prefix-confusion/test0006",
+    "rdf-ns-prefix-confusion/test0006.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0009,
+    "Namespace qualification MUST be used for all property attributes.",
+    "rdf-ns-prefix-confusion/test0009",
+    "rdf-ns-prefix-confusion/test0009.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0010,
+    "Non-prefixed RDF elements (NOT attributes) are allowed when a default XML element ...",
+    "rdf-ns-prefix-confusion/test0010",
+    "rdf-ns-prefix-confusion/test0010.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0011,
+    "Non-prefixed RDF elements (NOT attributes) are allowed when a default XML element ...",
+    "rdf-ns-prefix-confusion/test0011",
+    "rdf-ns-prefix-confusion/test0011.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0012,
+    "Non-prefixed RDF elements (NOT attributes) are allowed when a default XML element ...",
+    "rdf-ns-prefix-confusion/test0012",
+    "rdf-ns-prefix-confusion/test0012.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0013,
+    "Non-prefixed RDF elements (NOT attributes) are allowed when a default XML element ...",
+    "rdf-ns-prefix-confusion/test0013",
+    "rdf-ns-prefix-confusion/test0013.nt"
+);
+
+eval_test!(
+    rdf_ns_prefix_confusion_test0014,
+    "Non-prefixed RDF elements (NOT attributes) are allowed when a default XML element ...",
+    "rdf-ns-prefix-confusion/test0014",
+    "rdf-ns-prefix-confusion/test0014.nt"
+);
+
+negative_test!(
+    rdfms_abouteach_error001,
+    "aboutEach removed from the RDF specifications.",
+    "rdfms-abouteach/error001"
+);
+
+negative_test!(
+    rdfms_abouteach_error002,
+    "aboutEach removed from the RDF specifications.",
+    "rdfms-abouteach/error002"
+);
+
+negative_test!(
+    rdfms_difference_between_id_and_about_error1,
+    "two elements cannot use the same ID",
+    "rdfms-difference-between-ID-and-about/error1"
+);
+
+eval_test!(
+    rdfms_difference_between_id_and_about_test1,
+    "A statement with an rdf:ID creates a regular triple.",
+    "rdfms-difference-between-ID-and-about/test1",
+    "rdfms-difference-between-ID-and-about/test1.nt"
+);
+
+eval_test!(
+    rdfms_difference_between_id_and_about_test2,
+    "This test shows the treatment of non-ASCII characters in the value of rdf:ID attribute.",
+    "rdfms-difference-between-ID-and-about/test2",
+    "rdfms-difference-between-ID-and-about/test2.nt"
+);
+
+eval_test!(
+    rdfms_difference_between_id_and_about_test3,
+    "This test shows the treatment of non-ASCII characters in the value of rdf:about attribute.",
+    "rdfms-difference-between-ID-and-about/test3",
+    "rdfms-difference-between-ID-and-about/test3.nt"
+);
+
+eval_test!(
+    rdfms_duplicate_member_props_test001,
+    "The question posed to the RDF WG was: should an RDF document containing multiple ...",
+    "rdfms-duplicate-member-props/test001",
+    "rdfms-duplicate-member-props/test001.nt"
+);
+
+negative_test!(
+    rdfms_empty_property_elements_error001,
+    "This is not legal RDF; specifying an rdf:parseType of \"Literal\" and an rdf:resource ...",
+    "rdfms-empty-property-elements/error001"
+);
+
+negative_test!(
+    rdfms_empty_property_elements_error002,
+    "This is not legal RDF; specifying an rdf:parseType of \"Literal\" and an rdf:resource ...",
+    "rdfms-empty-property-elements/error002"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test001,
+    "The rdf:resource attribute means that the value of this property element is a resource.",
+    "rdfms-empty-property-elements/test001",
+    "rdfms-empty-property-elements/test001.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test002,
+    "The basic case.",
+    "rdfms-empty-property-elements/test002",
+    "rdfms-empty-property-elements/test002.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test004,
+    "If the parseType indicates the value is a resource, we must create one.",
+    "rdfms-empty-property-elements/test004",
+    "rdfms-empty-property-elements/test004.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test005,
+    "An empty property element just gives an empty literal.",
+    "rdfms-empty-property-elements/test005",
+    "rdfms-empty-property-elements/test005.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test006,
+    "Here the parseType indicates that we should create a resource.",
+    "rdfms-empty-property-elements/test006",
+    "rdfms-empty-property-elements/test006.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test007,
+    "As test001.rdf; this uses an explicit closing tag.",
+    "rdfms-empty-property-elements/test007",
+    "rdfms-empty-property-elements/test007.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test008,
+    "As test002.rdf; this uses an explicit closing tag.",
+    "rdfms-empty-property-elements/test008",
+    "rdfms-empty-property-elements/test008.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test010,
+    "As test004.rdf; this uses an explicit closing tag.",
+    "rdfms-empty-property-elements/test010",
+    "rdfms-empty-property-elements/test010.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test011,
+    "As test005.rdf; this uses an explicit closing tag.",
+    "rdfms-empty-property-elements/test011",
+    "rdfms-empty-property-elements/test011.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test012,
+    "As test006.rdf; this uses an explicit closing tag.",
+    "rdfms-empty-property-elements/test012",
+    "rdfms-empty-property-elements/test012.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test013,
+    "Test of the last alternative for production [6.12], interpreted according to RDFMS ...",
+    "rdfms-empty-property-elements/test013",
+    "rdfms-empty-property-elements/test013.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test014,
+    "Test of the last alternative for production [6.12], interpreted according to RDFMS ...",
+    "rdfms-empty-property-elements/test014",
+    "rdfms-empty-property-elements/test014.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test015,
+    "Test of the last alternative for production [6.12], interpreted according to RDFMS ...",
+    "rdfms-empty-property-elements/test015",
+    "rdfms-empty-property-elements/test015.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test016,
+    "Like rdfms-empty-property-elements/test001.rdf but with a processing instruction as ...",
+    "rdfms-empty-property-elements/test016",
+    "rdfms-empty-property-elements/test016.nt"
+);
+
+eval_test!(
+    rdfms_empty_property_elements_test017,
+    "Like rdfms-empty-property-elements/test001.rdf but with a comment as the only content ...",
+    "rdfms-empty-property-elements/test017",
+    "rdfms-empty-property-elements/test017.nt"
+);
+
+eval_test!(
+    rdfms_identity_anon_resources_test001,
+    "a RDF Description with no ID or about attribute describes an un-named resource, aka a ...",
+    "rdfms-identity-anon-resources/test001",
+    "rdfms-identity-anon-resources/test001.nt"
+);
+
+eval_test!(
+    rdfms_identity_anon_resources_test002,
+    "a RDF Description with no ID or about attribute describes an un-named resource, aka a ...",
+    "rdfms-identity-anon-resources/test002",
+    "rdfms-identity-anon-resources/test002.nt"
+);
+
+eval_test!(
+    rdfms_identity_anon_resources_test003,
+    "a RDF container (in this case a Bag) without an ID attribute describes an un-named ...",
+    "rdfms-identity-anon-resources/test003",
+    "rdfms-identity-anon-resources/test003.nt"
+);
+
+eval_test!(
+    rdfms_identity_anon_resources_test004,
+    "a RDF container (in this case an Alt) without an ID attribute describes an un-named ...",
+    "rdfms-identity-anon-resources/test004",
+    "rdfms-identity-anon-resources/test004.nt"
+);
+
+eval_test!(
+    rdfms_identity_anon_resources_test005,
+    "a RDF container (in this case an Seq) without an ID attribute describes an un-named ...",
+    "rdfms-identity-anon-resources/test005",
+    "rdfms-identity-anon-resources/test005.nt"
+);
+
+eval_test!(
+    rdfms_not_id_and_resource_attr_test001,
+    "rdf:ID on an empty property element indicates reification.",
+    "rdfms-not-id-and-resource-attr/test001",
+    "rdfms-not-id-and-resource-attr/test001.nt"
+);
+
+eval_test!(
+    rdfms_not_id_and_resource_attr_test002,
+    "rdf:reource on an empty property element indicates the URI of the object.",
+    "rdfms-not-id-and-resource-attr/test002",
+    "rdfms-not-id-and-resource-attr/test002.nt"
+);
+
+eval_test!(
+    rdfms_not_id_and_resource_attr_test004,
+    "rdf:ID and rdf:resource are allowed together on empty property element.",
+    "rdfms-not-id-and-resource-attr/test004",
+    "rdfms-not-id-and-resource-attr/test004.nt"
+);
+
+eval_test!(
+    rdfms_not_id_and_resource_attr_test005,
+    "rdf:ID and rdf:resource are allowed together on empty property element.",
+    "rdfms-not-id-and-resource-attr/test005",
+    "rdfms-not-id-and-resource-attr/test005.nt"
+);
+
+eval_test!(
+    rdfms_para196_test001,
+    "test case showing that the 2nd URI in M Paragraph 196 is permitted as a namespace URI ...",
+    "rdfms-para196/test001",
+    "rdfms-para196/test001.nt"
+);
+
+negative_test!(
+    rdfms_rdf_id_error001,
+    "The value of rdf:ID must match the XML Name production, (as modified by XML Namespaces).",
+    "rdfms-rdf-id/error001"
+);
+
+negative_test!(
+    rdfms_rdf_id_error002,
+    "The value of rdf:ID must match the XML Name production, (as modified by XML Namespaces).",
+    "rdfms-rdf-id/error002"
+);
+
+negative_test!(
+    rdfms_rdf_id_error003,
+    "The value of rdf:ID must match the XML Name production, (as modified by XML Namespaces).",
+    "rdfms-rdf-id/error003"
+);
+
+negative_test!(
+    rdfms_rdf_id_error004,
+    "The value of rdf:ID must match the XML Name production, (as modified by XML Namespaces).",
+    "rdfms-rdf-id/error004"
+);
+
+negative_test!(
+    rdfms_rdf_id_error005,
+    "The value of rdf:ID must match the XML Name production, (as modified by XML Namespaces).",
+    "rdfms-rdf-id/error005"
+);
+
+negative_test!(
+    rdfms_rdf_id_error006,
+    "The value of rdf:bagID must match the XML Name production, (as modified by XML ...",
+    "rdfms-rdf-id/error006"
+);
+
+negative_test!(
+    rdfms_rdf_id_error007,
+    "The value of rdf:bagID must match the XML Name production, (as modified by XML ...",
+    "rdfms-rdf-id/error007"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_001,
+    "RDF is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-001"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_002,
+    "ID is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-002"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_003,
+    "about is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-003"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_004,
+    "bagID is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-004"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_005,
+    "parseType is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-005"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_006,
+    "resource is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-006"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_007,
+    "nodeID is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-007"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_008,
+    "li is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-008"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_009,
+    "aboutEach is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-009"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_010,
+    "aboutEachPrefix is forbidden as a node element name.",
+    "rdfms-rdf-names-use/error-010"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_011,
+    "Description is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-011"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_012,
+    "RDF is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-012"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_013,
+    "ID is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-013"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_014,
+    "about is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-014"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_015,
+    "bagID is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-015"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_016,
+    "parseType is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-016"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_017,
+    "resource is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-017"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_018,
+    "nodeID is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-018"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_019,
+    "aboutEach is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-019"
+);
+
+negative_test!(
+    rdfms_rdf_names_use_error_020,
+    "aboutEachPrefix is forbidden as a property element name.",
+    "rdfms-rdf-names-use/error-020"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_001,
+    "Description is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-001",
+    "rdfms-rdf-names-use/test-001.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_002,
+    "Seq is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-002",
+    "rdfms-rdf-names-use/test-002.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_003,
+    "Bag is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-003",
+    "rdfms-rdf-names-use/test-003.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_004,
+    "Alt is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-004",
+    "rdfms-rdf-names-use/test-004.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_005,
+    "Statement is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-005",
+    "rdfms-rdf-names-use/test-005.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_006,
+    "Property is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-006",
+    "rdfms-rdf-names-use/test-006.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_007,
+    "List is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-007",
+    "rdfms-rdf-names-use/test-007.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_008,
+    "subject is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-008",
+    "rdfms-rdf-names-use/test-008.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_009,
+    "predicate is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-009",
+    "rdfms-rdf-names-use/test-009.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_010,
+    "object is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-010",
+    "rdfms-rdf-names-use/test-010.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_011,
+    "type is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-011",
+    "rdfms-rdf-names-use/test-011.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_012,
+    "value is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-012",
+    "rdfms-rdf-names-use/test-012.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_013,
+    "first is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-013",
+    "rdfms-rdf-names-use/test-013.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_014,
+    "rest is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-014",
+    "rdfms-rdf-names-use/test-014.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_015,
+    "_1 is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-015",
+    "rdfms-rdf-names-use/test-015.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_016,
+    "nil is allowed as a node element name.",
+    "rdfms-rdf-names-use/test-016",
+    "rdfms-rdf-names-use/test-016.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_017,
+    "Seq is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-017",
+    "rdfms-rdf-names-use/test-017.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_018,
+    "Bag is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-018",
+    "rdfms-rdf-names-use/test-018.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_019,
+    "Alt is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-019",
+    "rdfms-rdf-names-use/test-019.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_020,
+    "Statement is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-020",
+    "rdfms-rdf-names-use/test-020.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_021,
+    "Property is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-021",
+    "rdfms-rdf-names-use/test-021.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_022,
+    "List is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-022",
+    "rdfms-rdf-names-use/test-022.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_023,
+    "subject is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-023",
+    "rdfms-rdf-names-use/test-023.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_024,
+    "predicate is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-024",
+    "rdfms-rdf-names-use/test-024.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_025,
+    "object is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-025",
+    "rdfms-rdf-names-use/test-025.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_026,
+    "type is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-026",
+    "rdfms-rdf-names-use/test-026.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_027,
+    "value is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-027",
+    "rdfms-rdf-names-use/test-027.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_028,
+    "first is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-028",
+    "rdfms-rdf-names-use/test-028.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_029,
+    "rest is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-029",
+    "rdfms-rdf-names-use/test-029.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_030,
+    "_1 is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-030",
+    "rdfms-rdf-names-use/test-030.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_031,
+    "li is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-031",
+    "rdfms-rdf-names-use/test-031.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_032,
+    "Seq is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-032",
+    "rdfms-rdf-names-use/test-032.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_033,
+    "Bag is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-033",
+    "rdfms-rdf-names-use/test-033.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_034,
+    "Alt is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-034",
+    "rdfms-rdf-names-use/test-034.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_035,
+    "Statement is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-035",
+    "rdfms-rdf-names-use/test-035.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_036,
+    "Property is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-036",
+    "rdfms-rdf-names-use/test-036.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_test_037,
+    "List is allowed as a property element name.",
+    "rdfms-rdf-names-use/test-037",
+    "rdfms-rdf-names-use/test-037.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_warn_001,
+    "foo is allowed with warnings as a node element name.",
+    "rdfms-rdf-names-use/warn-001",
+    "rdfms-rdf-names-use/warn-001.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_warn_002,
+    "foo is allowed with warnings as a property element name.",
+    "rdfms-rdf-names-use/warn-002",
+    "rdfms-rdf-names-use/warn-002.nt"
+);
+
+eval_test!(
+    rdfms_rdf_names_use_warn_003,
+    "foo is allowed with warnings as a property attribute name.",
+    "rdfms-rdf-names-use/warn-003",
+    "rdfms-rdf-names-use/warn-003.nt"
+);
+
+eval_test!(
+    rdfms_reification_required_test001,
+    "A parser is not required to generate a bag of reified statements for all description ...",
+    "rdfms-reification-required/test001",
+    "rdfms-reification-required/test001.nt"
+);
+
+eval_test!(
+    rdfms_seq_representation_test001,
+    "rdf:parseType=\"Collection\" is parsed like the nonstandard daml:collection.",
+    "rdfms-seq-representation/test001",
+    "rdfms-seq-representation/test001.nt"
+);
+
+eval_test!(
+    rdfms_syntax_incomplete_test001,
+    "rdf:nodeID can be used to label a blank node.",
+    "rdfms-syntax-incomplete/test001",
+    "rdfms-syntax-incomplete/test001.nt"
+);
+
+eval_test!(
+    rdfms_syntax_incomplete_test002,
+    "rdf:nodeID can be used to label a blank node.",
+    "rdfms-syntax-incomplete/test002",
+    "rdfms-syntax-incomplete/test002.nt"
+);
+
+eval_test!(
+    rdfms_syntax_incomplete_test003,
+    "On an rdf:Description or typed node rdf:nodeID behaves similarly to an rdf:about.",
+    "rdfms-syntax-incomplete/test003",
+    "rdfms-syntax-incomplete/test003.nt"
+);
+
+eval_test!(
+    rdfms_syntax_incomplete_test004,
+    "On a property element rdf:nodeID behaves similarly to rdf:resource.",
+    "rdfms-syntax-incomplete/test004",
+    "rdfms-syntax-incomplete/test004.nt"
+);
+
+negative_test!(
+    rdfms_syntax_incomplete_error001,
+    "The value of rdf:nodeID must match the XML Name production, (as modified by XML ...",
+    "rdfms-syntax-incomplete/error001"
+);
+
+negative_test!(
+    rdfms_syntax_incomplete_error002,
+    "The value of rdf:nodeID must match the XML Name production, (as modified by XML ...",
+    "rdfms-syntax-incomplete/error002"
+);
+
+negative_test!(
+    rdfms_syntax_incomplete_error003,
+    "The value of rdf:nodeID must match the XML Name production, (as modified by XML ...",
+    "rdfms-syntax-incomplete/error003"
+);
+
+negative_test!(
+    rdfms_syntax_incomplete_error004,
+    "Cannot have rdf:nodeID and rdf:ID.",
+    "rdfms-syntax-incomplete/error004"
+);
+
+negative_test!(
+    rdfms_syntax_incomplete_error005,
+    "Cannot have rdf:nodeID and rdf:about.",
+    "rdfms-syntax-incomplete/error005"
+);
+
+negative_test!(
+    rdfms_syntax_incomplete_error006,
+    "Cannot have rdf:nodeID and rdf:resource.",
+    "rdfms-syntax-incomplete/error006"
+);
+
+eval_test!(
+    rdfms_uri_substructure_test001,
+    "Demonstrates the Recommended partitioning of a URI into a namespace part and a ...",
+    "rdfms-uri-substructure/test001",
+    "rdfms-uri-substructure/test001.nt"
+);
+
+eval_test!(
+    rdfms_xmllang_test003,
+    "In-scope xml:lang applies to element content literal values",
+    "rdfms-xmllang/test003",
+    "rdfms-xmllang/test003.nt"
+);
+
+eval_test!(
+    rdfms_xmllang_test004,
+    "In-scope xml:lang applies to element content literal values",
+    "rdfms-xmllang/test004",
+    "rdfms-xmllang/test004.nt"
+);
+
+eval_test!(
+    rdfms_xmllang_test005,
+    "In-scope xml:lang applies to element content literal values",
+    "rdfms-xmllang/test005",
+    "rdfms-xmllang/test005.nt"
+);
+
+eval_test!(
+    rdfms_xmllang_test006,
+    "In-scope xml:lang applies to element content literal values",
+    "rdfms-xmllang/test006",
+    "rdfms-xmllang/test006.nt"
+);
+
+eval_test!(
+    rdfs_domain_and_range_test001,
+    "a RDF Property may have more than one domain property",
+    "rdfs-domain-and-range/test001",
+    "rdfs-domain-and-range/test001.nt"
+);
+
+eval_test!(
+    rdfs_domain_and_range_test002,
+    "a RDF Property may have more than one domain property",
+    "rdfs-domain-and-range/test002",
+    "rdfs-domain-and-range/test002.nt"
+);
+
+eval_test!(
+    unrecognised_xml_attributes_test001,
+    "Unrecognized attributes in the xml namespace should be ignored.",
+    "unrecognised-xml-attributes/test001",
+    "unrecognised-xml-attributes/test001.nt"
+);
+
+eval_test!(
+    unrecognised_xml_attributes_test002,
+    "Unrecognized attributes in the xml namespace should be ignored.",
+    "unrecognised-xml-attributes/test002",
+    "unrecognised-xml-attributes/test002.nt"
+);
+
+eval_test!(
+    xml_canon_test001,
+    "Demonstrating the canonicalisation of XMLLiterals.",
+    "xml-canon/test001",
+    "xml-canon/test001.nt"
+);
+
+eval_test!(
+    xmlbase_test001,
+    "xml:base applies to an rdf:ID on an rdf:Description element.",
+    "xmlbase/test001",
+    "xmlbase/test001.nt"
+);
+
+eval_test!(
+    xmlbase_test002,
+    "xml:base applies to an rdf:resource attribute.",
+    "xmlbase/test002",
+    "xmlbase/test002.nt"
+);
+
+eval_test!(
+    xmlbase_test003,
+    "xml:base applies to an rdf:about attribute.",
+    "xmlbase/test003",
+    "xmlbase/test003.nt"
+);
+
+eval_test!(
+    xmlbase_test004,
+    "xml:base applies to an rdf:ID on a property element.",
+    "xmlbase/test004",
+    "xmlbase/test004.nt"
+);
+
+eval_test!(
+    xmlbase_test006,
+    "xml:base scoping.",
+    "xmlbase/test006",
+    "xmlbase/test006.nt"
+);
+
+eval_test!(
+    xmlbase_test007,
+    "example of relative URI resolution.",
+    "xmlbase/test007",
+    "xmlbase/test007.nt"
+);
+
+eval_test!(
+    xmlbase_test008,
+    "example of empty same document ref resolution.",
+    "xmlbase/test008",
+    "xmlbase/test008.nt"
+);
+
+eval_test!(
+    xmlbase_test009,
+    "Example of relative uri with absolute path resolution.",
+    "xmlbase/test009",
+    "xmlbase/test009.nt"
+);
+
+eval_test!(
+    xmlbase_test010,
+    "Example of relative uri with net path resolution.",
+    "xmlbase/test010",
+    "xmlbase/test010.nt"
+);
+
+eval_test!(
+    xmlbase_test011,
+    "Example of xml:base with no path component.",
+    "xmlbase/test011",
+    "xmlbase/test011.nt"
+);
+
+eval_test!(
+    xmlbase_test013,
+    "With an xml:base with fragment the fragment is ignored.",
+    "xmlbase/test013",
+    "xmlbase/test013.nt"
+);
+
+eval_test!(
+    xmlbase_test014,
+    "Test output corrected to use correct base URL.",
+    "xmlbase/test014",
+    "xmlbase/test014.nt"
+);
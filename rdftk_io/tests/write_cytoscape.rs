@@ -0,0 +1,119 @@
+#![cfg(feature = "cytoscape")]
+
+use objio::ObjectWriter;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{LanguageTag, Literal};
+use rdftk_core::model::statement::Statement;
+use rdftk_io::cytoscape::{CytoscapeOptions, CytoscapeWriter};
+use rdftk_iri::Iri;
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+mod common;
+
+fn make_graph() -> Graph {
+    let alice = Iri::from_str("http://example.org/alice").unwrap();
+    let name = Iri::from_str("http://xmlns.com/foaf/0.1/name").unwrap();
+    let mut graph = Graph::default();
+    graph.extend(vec![
+        Statement::new(alice.clone(), name.clone(), Literal::plain("Alice")),
+        Statement::new(
+            alice.clone(),
+            name,
+            Literal::with_language("Alicia", LanguageTag::from_str("es").unwrap()),
+        ),
+        Statement::new(
+            alice,
+            Iri::from_str("http://xmlns.com/foaf/0.1/knows").unwrap(),
+            Iri::from_str("http://example.org/bob").unwrap(),
+        ),
+    ]);
+    graph
+}
+
+#[test]
+fn write_to_cytoscape() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let output = CytoscapeWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: cytoscape\n{}", output);
+    let elements: Value = serde_json::from_str(&output).unwrap();
+    let nodes = elements["nodes"].as_array().unwrap();
+    let edges = elements["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), graph.len());
+    assert_eq!(
+        nodes[0],
+        json!({"data": {
+            "id": "n1",
+            "kind": "iri",
+            "label": "http://en.wikipedia.org/wiki/Tony_Benn",
+            "value": "http://en.wikipedia.org/wiki/Tony_Benn",
+        }})
+    );
+    assert_eq!(nodes[3]["data"]["kind"], "blank");
+    assert_eq!(
+        edges[0],
+        json!({"data": {
+            "id": "e1",
+            "source": "n1",
+            "target": "n2",
+            "label": "dc:title",
+            "predicate": "http://purl.org/dc/elements/1.1/title",
+        }})
+    );
+}
+
+#[test]
+fn write_to_cytoscape_with_folded_literals() {
+    let graph = make_graph();
+
+    let output = CytoscapeWriter::default().write_to_string(&graph).unwrap();
+    let elements: Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(elements["nodes"].as_array().unwrap().len(), 4);
+    let literal = elements["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|node| node["data"]["language"] == "es")
+        .unwrap();
+    assert_eq!(literal["data"]["value"], "Alicia");
+
+    let writer = CytoscapeWriter::default().with_options(
+        CytoscapeOptions::default()
+            .with_fold_literals(true)
+            .with_pretty_print(true),
+    );
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: cytoscape\n{}", output);
+    let elements: Value = serde_json::from_str(&output).unwrap();
+    let nodes = elements["nodes"].as_array().unwrap();
+    assert_eq!(nodes.len(), 2);
+    let names = nodes[0]["data"]["http://xmlns.com/foaf/0.1/name"]
+        .as_array()
+        .unwrap();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&json!("Alice")));
+    assert!(names.contains(&json!("Alicia")));
+    assert_eq!(elements["edges"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn write_nested_nodes_to_cytoscape() {
+    let graph = common::nested_nodes_graph();
+
+    let output = CytoscapeWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: cytoscape\n{}", output);
+    let elements: Value = serde_json::from_str(&output).unwrap();
+    let kinds = elements["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["data"]["kind"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(kinds.iter().filter(|kind| **kind == "statement").count(), 2);
+    assert_eq!(
+        kinds.iter().filter(|kind| **kind == "collection").count(),
+        1
+    );
+    assert_eq!(kinds.iter().filter(|kind| **kind == "literal").count(), 1);
+}
@@ -1,7 +1,14 @@
 #![cfg(feature = "dot")]
 
 use objio::{HasOptions, ObjectWriter};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::{Graph, GraphName};
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::Statement;
 use rdftk_io::dot::{DotOptions, DotWriter};
+use rdftk_iri::Iri;
+use rdftk_names::{dc, foaf, rdf};
+use std::str::FromStr;
 
 mod common;
 
@@ -48,3 +55,126 @@ fn write_to_dot_with_options() {
     assert!(output.contains("\"node_5\" [URL=\"http://xmlns.com/foaf/0.1/Person\",label=\"http://xmlns.com/foaf/0.1/Person\",shape=ellipse,color=black];"));
     assert!(output.ends_with("}\n"));
 }
+
+#[test]
+fn write_data_set_to_dot() {
+    let mut data_set = DataSet::from(common::tony_benn_graph(common::TonyBennType::OneType));
+    let mut named = Graph::named(GraphName::from(
+        Iri::from_str("http://example.org/graphs#one").unwrap(),
+    ));
+    named.insert(Statement::new(
+        Iri::from_str("http://en.wikipedia.org/wiki/Tony_Benn").unwrap(),
+        Iri::from_str("http://purl.org/dc/elements/1.1/source").unwrap(),
+        Literal::plain("Hansard"),
+    ));
+    data_set.insert(named);
+
+    let writer = DotWriter::default();
+
+    let result = writer.write_to_string(&data_set);
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    println!("# format: dot\n{}", output);
+    assert!(output.starts_with("digraph {"));
+    assert_eq!(output.matches("subgraph cluster_").count(), 1);
+    assert!(output.contains(
+        "    subgraph cluster_1 {\n        label=\"http://example.org/graphs#one\";\n        \"node_"
+    ));
+    assert!(output.contains("[label=\"Hansard\",shape=record,color=black];\n    }\n"));
+    assert_eq!(
+        output
+            .matches("[URL=\"http://en.wikipedia.org/wiki/Tony_Benn\"")
+            .count(),
+        1
+    );
+    assert!(output.contains("\n    \"node_1\" [URL=\"http://en.wikipedia.org/wiki/Tony_Benn\""));
+    assert!(output.ends_with("}\n"));
+}
+
+#[test]
+fn write_to_dot_with_layout_and_prefixed_labels() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let writer = DotWriter::default().with_options(
+        DotOptions::default()
+            .with_prefixed_labels(true)
+            .with_rank_direction("LR")
+            .with_layout("neato")
+            .with_exclude_predicates(vec![rdf::a_type().clone()]),
+    );
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: dot\n{}", output);
+    assert!(output.starts_with("digraph {\n    rankdir=LR\n"));
+    assert!(output.contains("    layout=neato;\n"));
+    assert!(!output.contains("rdf:type"));
+    assert!(!output.contains("foaf:Person"));
+    assert!(output.contains("\"node_1\" -> \"node_2\" [label=\"dc:title\"];"));
+}
+
+#[test]
+fn write_to_dot_with_folded_literals() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let writer = DotWriter::default().with_options(
+        DotOptions::default()
+            .with_fold_literals(true)
+            .with_include_predicates(vec![
+                dc::elements::title().clone(),
+                dc::elements::publisher().clone(),
+            ]),
+    );
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: dot\n{}", output);
+    assert!(output.contains("\"node_1\" -> \"node_2\" [label=\"\"];"));
+    assert!(output.contains(
+        "\"node_2\" [label=\"{dc:title: Tony Benn|dc:publisher: Wikipedia}\",shape=record,color=black];"
+    ));
+    assert_eq!(output.matches(" -> ").count(), 1);
+}
+
+#[test]
+fn write_nested_statement_to_dot() {
+    let alice = Iri::from_str("http://example.org/alice").unwrap();
+    let nested = Statement::new(alice.clone(), foaf::name().clone(), Literal::plain("Alice"));
+    let mut graph = Graph::default();
+    graph.insert(Statement::new(
+        nested,
+        dc::elements::source().clone(),
+        Iri::from_str("http://example.org/registry").unwrap(),
+    ));
+
+    let output = DotWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: dot\n{}", output);
+    assert!(output.contains("    compound=true;\n"));
+    assert!(output.contains(
+        "    subgraph cluster_statement_1 {\n        label=\"\";\n        style=dashed;\n        \"node_1\" [URL=\"http://example.org/alice\""
+    ));
+    assert!(output.contains("\"node_1\" -> \"node_2\" [label=\"http://xmlns.com/foaf/0.1/name\"];"));
+    assert!(output.contains(
+        "\"node_1\" -> \"node_3\" [label=\"http://purl.org/dc/elements/1.1/source\",ltail=cluster_statement_1];"
+    ));
+}
+
+#[test]
+fn write_literal_labels_to_dot() {
+    let mut graph = Graph::default();
+    graph.insert(Statement::new(
+        Iri::from_str("http://example.org/a").unwrap(),
+        dc::elements::title().clone(),
+        Literal::plain("{a|b} <c> \"e\u{301}\"\nd"),
+    ));
+
+    let output = DotWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: dot\n{}", output);
+    assert!(output.contains(
+        "\"node_2\" [label=\"\\{a\\|b\\} \\<c\\> \\\"e\u{301}\\\"\\nd\",shape=record,color=black];"
+    ));
+
+    let writer = DotWriter::default().with_options(DotOptions::default().with_literal_shape("box"));
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: dot\n{}", output);
+    assert!(output
+        .contains("\"node_2\" [label=\"{a|b} <c> \\\"e\u{301}\\\"\\nd\",shape=box,color=black];"));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "graphml")]
+
+use objio::ObjectWriter;
+use rdftk_io::graphml::{GraphMlOptions, GraphMlWriter};
+
+mod common;
+
+#[test]
+fn write_to_graphml() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let output = GraphMlWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: graphml\n{}", output);
+    assert!(output.contains("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"));
+    assert!(output
+        .contains("<key id=\"edge_label\" for=\"edge\" attr.name=\"label\" attr.type=\"string\">"));
+    assert!(output.contains("<graph id=\"G\" edgedefault=\"directed\">"));
+    assert!(output.contains(
+        "<node id=\"n1\"><data key=\"kind\">iri</data><data key=\"label\">http://en.wikipedia.org/wiki/Tony_Benn</data>"
+    ));
+    assert!(output.contains("<node id=\"n4\"><data key=\"kind\">blank</data>"));
+    assert!(output.contains(
+        "<node id=\"n2\"><data key=\"kind\">literal</data><data key=\"label\">Tony Benn</data>"
+    ));
+    assert!(output.contains(
+        "<edge id=\"e1\" source=\"n1\" target=\"n2\"><data key=\"edge_label\">dc:title</data><data key=\"predicate\">http://purl.org/dc/elements/1.1/title</data></edge>"
+    ));
+    assert!(!output.contains("property_"));
+}
+
+#[test]
+fn write_to_graphml_with_folded_literals() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let writer = GraphMlWriter::default().with_options(
+        GraphMlOptions::default()
+            .with_fold_literals(true)
+            .with_prefixed_labels(true),
+    );
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: graphml\n{}", output);
+    assert!(output.contains(
+        "<key id=\"property_1\" for=\"node\" attr.name=\"dc:title\" attr.type=\"string\">"
+    ));
+    assert!(output.contains("<data key=\"property_1\">Tony Benn</data>"));
+    assert!(!output.contains("<data key=\"kind\">literal</data>"));
+    assert!(output.contains("<data key=\"label\">foaf:Person</data>"));
+}
+
+#[test]
+fn write_nested_nodes_to_graphml() {
+    let graph = common::nested_nodes_graph();
+
+    let writer =
+        GraphMlWriter::default().with_options(GraphMlOptions::default().with_prefixed_labels(true));
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: graphml\n{}", output);
+    assert!(output.contains(
+        "<data key=\"kind\">statement</data><data key=\"label\">&lt;&lt; ex:alice ex:knows ex:bob &gt;&gt;</data>"
+    ));
+    assert!(output.contains(
+        "<data key=\"kind\">statement</data><data key=\"label\">&lt;&lt; ex:bob ex:age 23 &gt;&gt;</data>"
+    ));
+    assert!(output.contains(
+        "<data key=\"kind\">collection</data><data key=\"label\">( ex:alice ex:bob )</data>"
+    ));
+    assert_eq!(
+        output.matches("<data key=\"kind\">literal</data>").count(),
+        1
+    );
+}
@@ -1 +1,591 @@
+#![cfg(all(feature = "json-ld", feature = "nq"))]
 
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::error::Error;
+use rdftk_core::model::data_set::DataSet;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::Statement;
+use rdftk_io::json_ld::{JsonLdEmbed, JsonLdForm, JsonLdReader, JsonLdWriter, JsonLdWriterOptions};
+use rdftk_io::nq::NQuadReader;
+use rdftk_iri::Iri;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use std::sync::Arc;
+
+mod common;
+
+fn quads(source: &str) -> DataSet {
+    NQuadReader::default().read_from_string(source).unwrap()
+}
+
+fn writer(options: JsonLdWriterOptions) -> JsonLdWriter {
+    JsonLdWriter::default().with_options(options)
+}
+
+fn assert_round_trips(writer: &JsonLdWriter, data_set: &DataSet) {
+    let output = writer.write_to_string(data_set).unwrap();
+    println!("# format: JSON-LD\n{output}");
+    let result: DataSet = JsonLdReader::default().read_from_string(&output).unwrap();
+    assert!(
+        common::data_sets_isomorphic(&result, data_set),
+        "actual: {result:#?}\nexpected: {data_set:#?}"
+    );
+}
+
+const PEOPLE: &str = r#"<http://example.org/people/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> .
+<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/age> "42"^^<http://www.w3.org/2001/XMLSchema#integer> .
+<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/knows> <http://example.org/people/bob> .
+<http://example.org/people/bob> <http://xmlns.com/foaf/0.1/name> "Bob"@en .
+<http://example.org/people/bob> <http://xmlns.com/foaf/0.1/name> "Robert"@fr .
+"#;
+
+#[test]
+fn write_expanded() {
+    let writer = writer(JsonLdWriterOptions::default().with_form(JsonLdForm::Expanded));
+    let document = writer.to_document(&quads(PEOPLE)).unwrap();
+    assert_eq!(
+        document,
+        json!([
+            {
+                "@id": "http://example.org/people/alice",
+                "@type": ["http://xmlns.com/foaf/0.1/Person"],
+                "http://xmlns.com/foaf/0.1/age": [
+                    { "@value": "42", "@type": "http://www.w3.org/2001/XMLSchema#integer" }
+                ],
+                "http://xmlns.com/foaf/0.1/knows": [{ "@id": "http://example.org/people/bob" }],
+                "http://xmlns.com/foaf/0.1/name": [{ "@value": "Alice" }]
+            },
+            {
+                "@id": "http://example.org/people/bob",
+                "http://xmlns.com/foaf/0.1/name": [
+                    { "@value": "Bob", "@language": "en" },
+                    { "@value": "Robert", "@language": "fr" }
+                ]
+            }
+        ])
+    );
+}
+
+#[test]
+fn write_compacted_with_context() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_use_native_types(true)
+            .with_context(json!({
+                "@vocab": "http://xmlns.com/foaf/0.1/",
+                "knows": { "@type": "@id" },
+                "names": { "@id": "http://xmlns.com/foaf/0.1/name", "@container": "@language" }
+            })),
+    );
+    let document = writer.to_document(&quads(PEOPLE)).unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": {
+                "@vocab": "http://xmlns.com/foaf/0.1/",
+                "knows": { "@type": "@id" },
+                "names": { "@id": "http://xmlns.com/foaf/0.1/name", "@container": "@language" }
+            },
+            "@graph": [
+                {
+                    "@id": "http://example.org/people/alice",
+                    "@type": "Person",
+                    "age": 42,
+                    "knows": "http://example.org/people/bob",
+                    "names": { "@none": "Alice" }
+                },
+                {
+                    "@id": "http://example.org/people/bob",
+                    "names": { "en": "Bob", "fr": "Robert" }
+                }
+            ]
+        })
+    );
+    assert_round_trips(&writer, &quads(PEOPLE));
+}
+
+#[test]
+fn write_compacted_from_prefix_mappings() {
+    let graph = common::tony_benn_graph(common::TonyBennType::default());
+    let writer = writer(JsonLdWriterOptions::default().with_pretty_print(true));
+    let document = writer.to_document(&DataSet::from(graph.clone())).unwrap();
+    assert_eq!(
+        document["@context"]["dc"],
+        Value::String("http://purl.org/dc/elements/1.1/".into())
+    );
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: JSON-LD (compacted)\n{output}");
+    assert!(output.contains(r#""dc:title": "Tony Benn""#));
+    assert!(output.contains(r#""@type": "foaf:Person""#));
+    assert_round_trips(&writer, &DataSet::from(graph));
+}
+
+#[test]
+fn write_flattened() {
+    let graph = common::tony_benn_graph(common::TonyBennType::default());
+    let writer = writer(JsonLdWriterOptions::default().with_form(JsonLdForm::Flattened));
+    let document = writer.to_document(&DataSet::from(graph.clone())).unwrap();
+    let nodes = document.as_array().unwrap();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0]["@id"], Value::String("_:b0".into()));
+    assert_eq!(
+        nodes[1]["http://purl.org/dc/elements/1.1/description"],
+        json!([{ "@id": "_:b0" }])
+    );
+    assert_round_trips(&writer, &DataSet::from(graph));
+}
+
+#[test]
+fn write_flattened_with_context() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Flattened)
+            .with_context(json!({ "foaf": "http://xmlns.com/foaf/0.1/" })),
+    );
+    let document = writer.to_document(&quads(PEOPLE)).unwrap();
+    assert_eq!(
+        document["@graph"][1],
+        json!({
+            "@id": "http://example.org/people/bob",
+            "foaf:name": [
+                { "@value": "Bob", "@language": "en" },
+                { "@value": "Robert", "@language": "fr" }
+            ]
+        })
+    );
+    assert_round_trips(&writer, &quads(PEOPLE));
+}
+
+#[test]
+fn write_named_graphs() {
+    let data_set = quads(
+        r#"<http://example.org/a> <http://example.org/p> "default" .
+<http://example.org/a> <http://example.org/p> "one" <http://example.org/graphs/one> .
+_:b <http://example.org/p> "two" _:g .
+"#,
+    );
+    for form in [
+        JsonLdForm::Expanded,
+        JsonLdForm::Compacted,
+        JsonLdForm::Flattened,
+    ] {
+        assert_round_trips(
+            &writer(JsonLdWriterOptions::default().with_form(form)),
+            &data_set,
+        );
+    }
+}
+
+#[test]
+fn write_lists() {
+    let data_set = quads(
+        r#"<http://example.org/s> <http://example.org/p> _:l1 .
+_:l1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "a" .
+_:l1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:l2 .
+_:l2 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> _:n1 .
+_:l2 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .
+_:n1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "b" .
+_:n1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .
+<http://example.org/s> <http://example.org/q> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .
+"#,
+    );
+    let writer = writer(
+        JsonLdWriterOptions::default().with_context(json!({ "@vocab": "http://example.org/" })),
+    );
+    let document = writer.to_document(&data_set).unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": { "@vocab": "http://example.org/" },
+            "@id": "http://example.org/s",
+            "p": { "@list": ["a", { "@list": ["b"] }] },
+            "q": { "@list": [] }
+        })
+    );
+    assert_round_trips(&writer, &data_set);
+}
+
+#[test]
+fn write_json_literals_and_escapes() {
+    let data_set = quads(
+        r#"<http://example.org/s> <http://example.org/p> "{\"a\":null,\"b\":[1,2]}"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON> .
+<http://example.org/s> <http://example.org/q> "say \"hello\"\n\tworld" .
+"#,
+    );
+    let writer = writer(JsonLdWriterOptions::default().with_form(JsonLdForm::Expanded));
+    let document = writer.to_document(&data_set).unwrap();
+    assert_eq!(
+        document[0]["http://example.org/p"],
+        json!([{ "@value": { "a": null, "b": [1, 2] }, "@type": "@json" }])
+    );
+    assert_eq!(
+        document[0]["http://example.org/q"],
+        json!([{ "@value": "say \"hello\"\n\tworld" }])
+    );
+    assert_round_trips(&writer, &data_set);
+}
+
+#[test]
+fn write_without_compact_arrays() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_compact_arrays(false)
+            .with_context(json!({ "@vocab": "http://xmlns.com/foaf/0.1/" })),
+    );
+    let document = writer
+        .to_document(&quads(
+            r#"<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+"#,
+        ))
+        .unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+            "@graph": [{ "@id": "http://example.org/people/alice", "name": ["Alice"] }]
+        })
+    );
+}
+
+#[test]
+fn write_rdf_star_fails() {
+    let inner = Statement::new(
+        Iri::from_str("http://example.org/s").unwrap(),
+        Iri::from_str("http://example.org/p").unwrap(),
+        Literal::plain("o"),
+    );
+    let graph = Graph::from(Statement::new(
+        Iri::from_str("http://example.org/a").unwrap(),
+        Iri::from_str("http://example.org/says").unwrap(),
+        Arc::new(inner),
+    ));
+    let result: Result<String, Error> = JsonLdWriter::default().write_to_string(&graph);
+    assert!(matches!(result, Err(Error::RdfStarNotSupported { .. })));
+}
+
+const LIBRARY: &str = r#"<http://example.org/library> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/vocab#Library> .
+<http://example.org/library> <http://example.org/vocab#contains> <http://example.org/library/the-republic> .
+<http://example.org/library/the-republic> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/vocab#Book> .
+<http://example.org/library/the-republic> <http://purl.org/dc/elements/1.1/creator> "Plato" .
+<http://example.org/library/the-republic> <http://purl.org/dc/elements/1.1/title> "The Republic" .
+<http://example.org/library/the-republic> <http://example.org/vocab#contains> <http://example.org/library/the-republic#introduction> .
+<http://example.org/library/the-republic#introduction> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/vocab#Chapter> .
+<http://example.org/library/the-republic#introduction> <http://purl.org/dc/elements/1.1/description> "An introductory chapter on The Republic." .
+<http://example.org/library/the-republic#introduction> <http://purl.org/dc/elements/1.1/title> "The Introduction" .
+"#;
+
+fn library_context() -> Value {
+    json!({
+        "dc": "http://purl.org/dc/elements/1.1/",
+        "ex": "http://example.org/vocab#",
+        "ex:contains": { "@type": "@id" }
+    })
+}
+
+#[test]
+fn write_framed() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_frame(json!({
+                "@context": library_context(),
+                "@type": "ex:Library",
+                "ex:contains": {
+                    "@type": "ex:Book",
+                    "ex:contains": { "@type": "ex:Chapter" }
+                }
+            })),
+    );
+    let document = writer.to_document(&quads(LIBRARY)).unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": library_context(),
+            "@id": "http://example.org/library",
+            "@type": "ex:Library",
+            "ex:contains": {
+                "@id": "http://example.org/library/the-republic",
+                "@type": "ex:Book",
+                "dc:creator": "Plato",
+                "dc:title": "The Republic",
+                "ex:contains": {
+                    "@id": "http://example.org/library/the-republic#introduction",
+                    "@type": "ex:Chapter",
+                    "dc:description": "An introductory chapter on The Republic.",
+                    "dc:title": "The Introduction"
+                }
+            }
+        })
+    );
+    assert_round_trips(&writer, &quads(LIBRARY));
+}
+
+#[test]
+fn write_framed_when_frame_is_set() {
+    let options = JsonLdWriterOptions::default().with_frame(json!({
+        "@context": library_context(),
+        "@type": "ex:Chapter"
+    }));
+    assert_eq!(options.form(), JsonLdForm::Framed);
+
+    let document = writer(options).to_document(&quads(LIBRARY)).unwrap();
+    assert_eq!(
+        document.get("@id"),
+        Some(&json!(
+            "http://example.org/library/the-republic#introduction"
+        ))
+    );
+}
+
+#[test]
+fn write_framed_explicit_with_defaults() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_frame(json!({
+                "@context": library_context(),
+                "@type": "ex:Book",
+                "@explicit": true,
+                "dc:title": {},
+                "dc:publisher": {},
+                "dc:language": { "@default": "en" },
+                "dc:subject": { "@omitDefault": true }
+            })),
+    );
+    let document = writer.to_document(&quads(LIBRARY)).unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": library_context(),
+            "@id": "http://example.org/library/the-republic",
+            "@type": "ex:Book",
+            "dc:language": "en",
+            "dc:publisher": null,
+            "dc:title": "The Republic"
+        })
+    );
+}
+
+#[test]
+fn write_framed_omit_default_option() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_omit_default(true)
+            .with_explicit(true)
+            .with_frame(json!({
+                "@context": library_context(),
+                "@type": "ex:Chapter",
+                "dc:title": {},
+                "dc:publisher": {}
+            })),
+    );
+    let document = writer.to_document(&quads(LIBRARY)).unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": library_context(),
+            "@id": "http://example.org/library/the-republic#introduction",
+            "@type": "ex:Chapter",
+            "dc:title": "The Introduction"
+        })
+    );
+}
+
+#[test]
+fn write_framed_require_all() {
+    let frame = json!({
+        "@context": library_context(),
+        "@requireAll": true,
+        "dc:title": {},
+        "dc:creator": {}
+    });
+    let document = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_explicit(true)
+            .with_frame(frame.clone()),
+    )
+    .to_document(&quads(LIBRARY))
+    .unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": library_context(),
+            "@id": "http://example.org/library/the-republic",
+            "@type": "ex:Book",
+            "dc:creator": "Plato",
+            "dc:title": "The Republic"
+        })
+    );
+
+    // without @requireAll the chapter matches on its title alone.
+    let mut frame = frame;
+    let _ = frame.as_object_mut().unwrap().remove("@requireAll");
+    let document = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_explicit(true)
+            .with_omit_default(true)
+            .with_frame(frame),
+    )
+    .to_document(&quads(LIBRARY))
+    .unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": library_context(),
+            "@graph": [
+                {
+                    "@id": "http://example.org/library/the-republic",
+                    "@type": "ex:Book",
+                    "dc:creator": "Plato",
+                    "dc:title": "The Republic"
+                },
+                {
+                    "@id": "http://example.org/library/the-republic#introduction",
+                    "@type": "ex:Chapter",
+                    "dc:title": "The Introduction"
+                }
+            ]
+        })
+    );
+}
+
+const FRIENDS: &str = r#"<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/knows> _:bob .
+<http://example.org/people/carol> <http://xmlns.com/foaf/0.1/knows> _:bob .
+<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+<http://example.org/people/carol> <http://xmlns.com/foaf/0.1/name> "Carol" .
+_:bob <http://xmlns.com/foaf/0.1/name> "Bob" .
+"#;
+
+fn friends_frame() -> Value {
+    json!({
+        "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+        "@id": [
+            "http://example.org/people/alice",
+            "http://example.org/people/carol"
+        ]
+    })
+}
+
+#[test]
+fn write_framed_embed() {
+    let framed = |embed: JsonLdEmbed| {
+        writer(
+            JsonLdWriterOptions::default()
+                .with_form(JsonLdForm::Framed)
+                .with_embed(embed)
+                .with_frame(friends_frame()),
+        )
+        .to_document(&quads(FRIENDS))
+        .unwrap()
+    };
+
+    // each top-level node is framed separately, so bob is embedded in both and, as it is used
+    // more than once, keeps its blank node identifier.
+    let document = framed(JsonLdEmbed::Once);
+    assert_eq!(
+        document["@graph"],
+        json!([
+            {
+                "@id": "http://example.org/people/alice",
+                "knows": { "@id": "_:b0", "name": "Bob" },
+                "name": "Alice"
+            },
+            {
+                "@id": "http://example.org/people/carol",
+                "knows": { "@id": "_:b0", "name": "Bob" },
+                "name": "Carol"
+            }
+        ])
+    );
+    assert_eq!(framed(JsonLdEmbed::Always), document);
+
+    let document = framed(JsonLdEmbed::Never);
+    assert_eq!(
+        document["@graph"],
+        json!([
+            {
+                "@id": "http://example.org/people/alice",
+                "knows": { "@id": "_:b0" },
+                "name": "Alice"
+            },
+            {
+                "@id": "http://example.org/people/carol",
+                "knows": { "@id": "_:b0" },
+                "name": "Carol"
+            }
+        ])
+    );
+}
+
+#[test]
+fn write_framed_embed_in_frame() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_frame(json!({
+                "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+                "@id": "http://example.org/people/alice",
+                "knows": { "@embed": "@never" }
+            })),
+    );
+    let document = writer.to_document(&quads(FRIENDS)).unwrap();
+    assert_eq!(
+        document,
+        json!({
+            "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+            "@id": "http://example.org/people/alice",
+            "knows": { "@id": "_:b0" },
+            "name": "Alice"
+        })
+    );
+}
+
+#[test]
+fn write_framed_invalid_embed_fails() {
+    let writer = writer(
+        JsonLdWriterOptions::default()
+            .with_form(JsonLdForm::Framed)
+            .with_frame(json!({ "@embed": "@link" })),
+    );
+    assert!(writer.to_document(&quads(FRIENDS)).is_err());
+}
+
+#[test]
+fn write_framed_embed_once_or_always() {
+    let data_set = quads(
+        r#"<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/knows> <http://example.org/people/bob> .
+<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/workplaceHomepage> <http://example.org/people/bob> .
+<http://example.org/people/bob> <http://xmlns.com/foaf/0.1/name> "Bob" .
+"#,
+    );
+    let framed = |embed: JsonLdEmbed| {
+        writer(
+            JsonLdWriterOptions::default()
+                .with_form(JsonLdForm::Framed)
+                .with_embed(embed)
+                .with_frame(json!({
+                    "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+                    "@id": "http://example.org/people/alice"
+                })),
+        )
+        .to_document(&data_set)
+        .unwrap()
+    };
+    let bob = json!({ "@id": "http://example.org/people/bob", "name": "Bob" });
+
+    let document = framed(JsonLdEmbed::Once);
+    assert_eq!(document["knows"], bob);
+    assert_eq!(
+        document["workplaceHomepage"],
+        json!({ "@id": "http://example.org/people/bob" })
+    );
+
+    let document = framed(JsonLdEmbed::Always);
+    assert_eq!(document["knows"], bob);
+    assert_eq!(document["workplaceHomepage"], bob);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "mermaid")]
+
+use objio::ObjectWriter;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::Statement;
+use rdftk_io::mermaid::{MermaidOptions, MermaidShape, MermaidWriter};
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn write_to_mermaid() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let output = MermaidWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: mermaid\n{}", output);
+    assert!(output.starts_with("flowchart BT\n"));
+    assert!(output.contains("    node_1([\"http://en.wikipedia.org/wiki/Tony_Benn\"])\n"));
+    assert!(output.contains("    node_2[\"Tony Benn\"]\n"));
+    assert!(output.contains("    node_4((\" \"))\n"));
+    assert!(output.contains("    node_1 -->|\"dc:title\"| node_2\n"));
+    assert!(output.contains("    classDef iri stroke:blue\n"));
+    assert!(output.contains("    class node_4 blank\n"));
+}
+
+#[test]
+fn write_to_mermaid_with_options() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let writer = MermaidWriter::default().with_options(
+        MermaidOptions::default()
+            .with_direction("LR")
+            .with_blank_labels(true)
+            .with_iri_shape(MermaidShape::Hexagon)
+            .with_literal_color("gold")
+            .with_prefixed_labels(true),
+    );
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: mermaid\n{}", output);
+    assert!(output.starts_with("flowchart LR\n"));
+    assert!(output.contains("    node_4((\"B1\"))\n"));
+    assert!(output.contains("{{\"foaf:Person\"}}\n"));
+    assert!(output.contains("    classDef literal stroke:gold\n"));
+}
+
+#[test]
+fn write_escaped_labels_to_mermaid() {
+    let mut graph = Graph::default();
+    graph.insert(Statement::new(
+        Iri::from_str("http://example.org/a#b").unwrap(),
+        Iri::from_str("http://example.org/c").unwrap(),
+        Literal::plain("say \"<hi>\"\nthere"),
+    ));
+
+    let output = MermaidWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: mermaid\n{}", output);
+    assert!(output.contains("    node_1([\"http://example.org/a#35;b\"])\n"));
+    assert!(output.contains("    node_2[\"say #quot;#lt;hi#gt;#quot;<br>there\"]\n"));
+}
+
+#[test]
+fn write_nested_nodes_to_mermaid() {
+    let graph = common::nested_nodes_graph();
+
+    let writer =
+        MermaidWriter::default().with_options(MermaidOptions::default().with_prefixed_labels(true));
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: mermaid\n{}", output);
+    assert!(output.contains("{{\"#lt;#lt; ex:alice ex:knows ex:bob #gt;#gt;\"}}\n"));
+    assert!(output.contains("{{\"#lt;#lt; ex:bob ex:age 23 #gt;#gt;\"}}\n"));
+    assert!(output.contains("[[\"( ex:alice ex:bob )\"]]\n"));
+    assert!(output.contains("    classDef statement stroke:orange\n"));
+    assert!(output.contains("    classDef collection stroke:purple\n"));
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "n3")]
+
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::formulae::Formula;
+use rdftk_core::model::graph::Graph;
+use rdftk_io::n3::{N3Reader, N3Writer, N3WriterOptions};
+
+mod common;
+
+const RULES: &str = r###"@prefix : <http://example.org/#> .
+@forAll :x, :y .
+@forSome :z .
+:joe :says { :sky :color "blue"@en } .
+{ :x :parent :z . :z :parent :y } => { :x :grandparent :y } .
+"###;
+
+#[test]
+fn write_graph_to_n3() {
+    let graph = common::tony_benn_graph(Default::default());
+
+    let writer = N3Writer::default();
+
+    let result = writer.write_to_string(&graph);
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    println!("# format: n3\n{}", output);
+
+    assert!(output.contains("@prefix dc: <http://purl.org/dc/elements/1.1/> .\n"));
+    assert!(output.contains("<http://en.wikipedia.org/wiki/Tony_Benn> "));
+    assert!(output.contains(" a foaf:Person"));
+    assert!(output.contains("foaf:name \"Tony Benn\""));
+
+    let reader = N3Reader::default();
+    let round_trip: Graph = reader.read_from_string(&output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &round_trip));
+}
+
+#[test]
+fn write_formula_to_n3() {
+    let reader = N3Reader::default();
+    let formula: Formula = reader.read_from_string(RULES).unwrap();
+
+    let writer = N3Writer::default();
+    let output = writer.write_to_string(&formula).unwrap();
+    println!("# format: n3\n{}", output);
+
+    assert!(output.starts_with("@prefix : <http://example.org/#> .\n"));
+    assert!(output.contains("@forAll :x, :y .\n"));
+    assert!(output.contains("@forSome :z .\n"));
+    assert!(output.contains(":joe :says {\n    :sky :color \"blue\"@en .\n} .\n"));
+    assert!(output.contains("} => {\n    :x :grandparent :y .\n} .\n"));
+}
+
+#[test]
+fn write_formula_without_verb_shorthand() {
+    let reader = N3Reader::default();
+    let formula: Formula = reader.read_from_string(RULES).unwrap();
+
+    let writer = N3Writer::default().with_options(
+        N3WriterOptions::default()
+            .with_indent_width(2)
+            .with_verb_shorthand(false),
+    );
+    let output = writer.write_to_string(&formula).unwrap();
+    println!("# format: n3\n{}", output);
+
+    assert!(output.contains(
+        "} <http://www.w3.org/2000/10/swap/log#implies> {\n  :x :grandparent :y .\n} .\n"
+    ));
+}
+
+#[test]
+fn write_formula_round_trip() {
+    let reader = N3Reader::default();
+    let formula: Formula = reader.read_from_string(RULES).unwrap();
+
+    let writer = N3Writer::default();
+    let output = writer.write_to_string(&formula).unwrap();
+
+    let round_trip: Formula = reader.read_from_string(&output).unwrap();
+    assert_eq!(round_trip.len(), formula.len());
+    assert_eq!(round_trip.universals().count(), 2);
+    assert_eq!(round_trip.existentials().count(), 1);
+    let mut lengths = round_trip
+        .formulae()
+        .map(|(_, quoted)| quoted.len())
+        .collect::<Vec<_>>();
+    lengths.sort();
+    assert_eq!(lengths, vec![1, 1, 2]);
+    assert_eq!(writer.write_to_string(&round_trip).unwrap(), output);
+}
@@ -1,8 +1,14 @@
 #![cfg(feature = "nq")]
 
-use objio::ObjectWriter;
+use objio::{HasOptions, ObjectReader, ObjectWriter};
 use rdftk_core::model::data_set::DataSet;
-use rdftk_io::nq::NQuadWriter;
+use rdftk_core::model::graph::GraphName;
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::Statement;
+use rdftk_io::nq::{NQuadReader, NQuadWriter, NQuadWriterOptions};
+use rdftk_io::StatementSink;
+use rdftk_iri::Iri;
+use std::str::FromStr;
 
 mod common;
 
@@ -26,3 +32,77 @@ fn write_to_nquads() {
     assert!(output.contains("_:B1 <http://xmlns.com/foaf/0.1/name> \"Tony Benn\" <http://en.wikipedia.org/wiki/Tony_Benn> .\n"));
     assert!(output.contains("_:B1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> <http://en.wikipedia.org/wiki/Tony_Benn> .\n"));
 }
+
+#[test]
+fn write_quads_to_sink() {
+    let subject = Iri::from_str("http://example.org/a").unwrap();
+    let predicate = Iri::from_str("http://example.org/p").unwrap();
+    let graph_name = GraphName::from(Iri::from_str("http://example.org/g").unwrap());
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = NQuadWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    sink.statement(&Statement::new(
+        subject.clone(),
+        predicate.clone(),
+        Literal::plain("one"),
+    ))
+    .unwrap();
+    sink.quad(
+        &Statement::new(subject, predicate, Literal::plain("two")),
+        Some(&graph_name),
+    )
+    .unwrap();
+    sink.finish().unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    assert_eq!(
+        output,
+        r###"<http://example.org/a> <http://example.org/p> "one" .
+<http://example.org/a> <http://example.org/p> "two" <http://example.org/g> .
+"###
+    );
+}
+
+#[test]
+fn write_typed_literals_round_trip() {
+    let data_set = DataSet::from(common::typed_literals_graph());
+
+    let output = NQuadWriter::default().write_to_string(&data_set).unwrap();
+    println!("# output: N-Quads\n{}", output);
+
+    assert!(output.contains(
+        "<http://example.org/ratio> \"2.5\"^^<http://www.w3.org/2001/XMLSchema#decimal> .\n"
+    ));
+    let read: DataSet = NQuadReader::default().read(&mut output.as_bytes()).unwrap();
+    assert!(common::data_sets_isomorphic(&data_set, &read));
+}
+
+#[test]
+fn write_quoted_triples_round_trip() {
+    let data_set = DataSet::from(common::rdf_star_graph());
+
+    let output = NQuadWriter::default().write_to_string(&data_set).unwrap();
+    println!("# output: N-Quads\n{}", output);
+
+    assert!(output.contains("<< <http://example.org/alice> <http://example.org/knows> <http://example.org/bob> >> <http://example.org/since> \"2020\" .\n"));
+    assert!(!output.contains("rdf-syntax-ns#subject"));
+    let read: DataSet = NQuadReader::default().read(&mut output.as_bytes()).unwrap();
+    assert!(common::data_sets_isomorphic(&data_set, &read));
+}
+
+#[test]
+fn write_reified_statements_to_nquads() {
+    let data_set = DataSet::from(common::rdf_star_graph());
+
+    let options = NQuadWriterOptions::default().reify_statements(true);
+    let writer = NQuadWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&data_set).unwrap();
+    println!("# output: N-Quads\n{}", output);
+
+    assert!(!output.contains("<<"));
+    assert!(output.contains(
+        "<http://www.w3.org/1999/02/22-rdf-syntax-ns#subject> <http://example.org/alice> .\n"
+    ));
+}
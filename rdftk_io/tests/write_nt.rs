@@ -1,7 +1,8 @@
 #![cfg(feature = "nt")]
 
-use objio::ObjectWriter;
-use rdftk_io::nt::NTripleWriter;
+use objio::{HasOptions, ObjectReader, ObjectWriter};
+use rdftk_io::nt::{NTripleReader, NTripleWriter, NTripleWriterOptions};
+use rdftk_io::StatementSink;
 
 mod common;
 
@@ -22,3 +23,105 @@ fn write_to_ntriples() {
     assert!(output.contains("_:B1 <http://xmlns.com/foaf/0.1/name> \"Tony Benn\" .\n"));
     assert!(output.contains("_:B1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> .\n"));
 }
+
+#[test]
+fn stream_ntriples_through_sink() {
+    let source = r###"<http://example.org/a> <http://example.org/p> "one" .
+<http://example.org/a> <http://example.org/q> _:b1 .
+_:b1 <http://example.org/p> "two"@en .
+"###;
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = NTripleWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    for statement in NTripleReader::default().statements(source.as_bytes()) {
+        sink.statement(&statement.unwrap()).unwrap();
+    }
+    sink.finish().unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    println!("{output}");
+    assert_eq!(output, source);
+}
+
+#[test]
+fn write_quoted_triples_to_ntriples() {
+    let graph = common::rdf_star_graph();
+
+    let writer = NTripleWriter::default();
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: N-Triples\n{}", output);
+
+    assert!(output.contains("<< <http://example.org/alice> <http://example.org/knows> <http://example.org/bob> >> <http://example.org/since> \"2020\" .\n"));
+    assert!(output.contains("<http://example.org/carol> <http://example.org/claims> << <http://example.org/bob> <http://example.org/age> \"23\"^^<http://www.w3.org/2001/XMLSchema#long> >> .\n"));
+    assert!(!output.contains("rdf-syntax-ns#subject"));
+}
+
+#[test]
+fn write_reified_statements_to_ntriples() {
+    let graph = common::rdf_star_graph();
+
+    let options = NTripleWriterOptions::default().reify_statements(true);
+    let writer = NTripleWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: N-Triples\n{}", output);
+
+    assert!(!output.contains("<<"));
+    assert!(output.contains(
+        "<http://www.w3.org/1999/02/22-rdf-syntax-ns#subject> <http://example.org/alice> .\n"
+    ));
+    assert!(output.contains("<http://www.w3.org/1999/02/22-rdf-syntax-ns#object> \"23\"^^<http://www.w3.org/2001/XMLSchema#long> .\n"));
+}
+
+#[test]
+fn write_typed_literals_round_trip() {
+    let graph = common::typed_literals_graph();
+
+    let output = NTripleWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: N-Triples\n{}", output);
+
+    assert!(output.contains(
+        "<http://example.org/ratio> \"2.5\"^^<http://www.w3.org/2001/XMLSchema#decimal> .\n"
+    ));
+    let read = NTripleReader::default()
+        .read(&mut output.as_bytes())
+        .unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_quoted_triples_round_trip() {
+    let graph = common::rdf_star_graph();
+
+    let output = NTripleWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: N-Triples\n{}", output);
+
+    let read = NTripleReader::default()
+        .read(&mut output.as_bytes())
+        .unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_escaped_literals_round_trip() {
+    let source =
+        "<http://example.org/a> <http://example.org/p> \"e\u{301} \\u0001 \\t \\\" \\\\\" .\n";
+    let graph = NTripleReader::default().read_from_string(source).unwrap();
+
+    let output = NTripleWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: N-Triples\n{}", output);
+    assert_eq!(output, source);
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = NTripleWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    for statement in graph.statements() {
+        sink.statement(statement).unwrap();
+    }
+    sink.finish().unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), source);
+
+    let read = NTripleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
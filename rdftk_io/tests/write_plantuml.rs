@@ -0,0 +1,75 @@
+#![cfg(feature = "plantuml")]
+
+use objio::ObjectWriter;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::Statement;
+use rdftk_io::plantuml::{PlantUmlOptions, PlantUmlWriter};
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn write_to_plantuml() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let output = PlantUmlWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: plantuml\n{}", output);
+    assert!(output.starts_with("@startuml\n"));
+    assert!(output
+        .contains("usecase \"http://en.wikipedia.org/wiki/Tony_Benn\" as node_1 #line:blue\n"));
+    assert!(output.contains("rectangle \"Tony Benn\" as node_2 #line:black\n"));
+    assert!(output.contains("circle \" \" as node_4 #line:green\n"));
+    assert!(output.contains("node_1 --> node_2 : dc:title\n"));
+    assert!(output.ends_with("@enduml\n"));
+}
+
+#[test]
+fn write_to_plantuml_with_options() {
+    let graph = common::tony_benn_graph(common::TonyBennType::OneType);
+
+    let writer = PlantUmlWriter::default().with_options(
+        PlantUmlOptions::default()
+            .with_left_to_right(true)
+            .with_literal_shape("card")
+            .with_iri_color("red")
+            .with_prefixed_labels(true),
+    );
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: plantuml\n{}", output);
+    assert!(output.starts_with("@startuml\nleft to right direction\n"));
+    assert!(output.contains("card \"Tony Benn\" as node_2 #line:black\n"));
+    assert!(output.contains("usecase \"foaf:Person\" as node_5 #line:red\n"));
+}
+
+#[test]
+fn write_escaped_labels_to_plantuml() {
+    let mut graph = Graph::default();
+    graph.insert(Statement::new(
+        Iri::from_str("http://example.org/a").unwrap(),
+        Iri::from_str("http://example.org/c").unwrap(),
+        Literal::plain("say \"hi\"\\\nthere"),
+    ));
+
+    let output = PlantUmlWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: plantuml\n{}", output);
+    assert!(output.contains("rectangle \"say &#34;hi&#34;&#92;\\nthere\" as node_2 #line:black\n"));
+}
+
+#[test]
+fn write_nested_nodes_to_plantuml() {
+    let graph = common::nested_nodes_graph();
+
+    let writer = PlantUmlWriter::default()
+        .with_options(PlantUmlOptions::default().with_prefixed_labels(true));
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: plantuml\n{}", output);
+    assert!(output.contains("card \"<< ex:alice ex:knows ex:bob >>\" as node_"));
+    assert!(output.contains("card \"<< ex:bob ex:age 23 >>\" as node_"));
+    assert!(output.contains("stack \"( ex:alice ex:bob )\" as node_"));
+    assert_eq!(output.matches(" #line:orange\n").count(), 2);
+    assert_eq!(output.matches(" #line:purple\n").count(), 1);
+}
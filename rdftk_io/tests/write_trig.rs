@@ -0,0 +1,101 @@
+#![cfg(feature = "trig")]
+
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::data_set::DataSet;
+use rdftk_io::trig::{TrigReader, TrigWriter, TrigWriterOptions};
+use rdftk_io::turtle::TurtleWriterOptions;
+use rdftk_iri::Iri;
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn write_to_trig() {
+    let graph = common::tony_benn_named_graph(common::TonyBennType::OneType);
+    let data_set = DataSet::from(graph);
+
+    let writer = TrigWriter::default();
+
+    let result = writer.write_to_string(&data_set);
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    println!("# format: trig\n{}", output);
+
+    assert!(output.contains("@prefix dc: <http://purl.org/dc/elements/1.1/> .\n"));
+    assert!(output.contains("GRAPH <http://en.wikipedia.org/wiki/Tony_Benn> {\n"));
+    assert!(output.contains("    foaf:name \"Tony Benn\""));
+    assert!(output.ends_with("}\n"));
+}
+
+#[test]
+fn write_to_trig_without_graph_keyword() {
+    let graph = common::tony_benn_named_graph(common::TonyBennType::OneType);
+
+    let writer = TrigWriter::default()
+        .with_options(TrigWriterOptions::default().with_omit_graph_keyword(true));
+
+    let result = writer.write_to_string(&graph);
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    println!("# format: trig\n{}", output);
+
+    assert!(!output.contains("GRAPH"));
+    assert!(output.contains("<http://en.wikipedia.org/wiki/Tony_Benn> {\n"));
+}
+
+#[test]
+fn write_trig_round_trip() {
+    let data_set = DataSet::from(vec![
+        common::use_cases_graph(),
+        common::tony_benn_named_graph(common::TonyBennType::TwoTypes),
+    ]);
+
+    let writer = TrigWriter::default();
+    let output = writer.write_to_string(&data_set).unwrap();
+    println!("# format: trig\n{}", output);
+
+    let reader = TrigReader::default();
+    let result = reader.read_from_string(output).unwrap();
+
+    assert!(common::data_sets_isomorphic(&data_set, &result));
+}
+
+#[test]
+fn write_quoted_triples_to_trig() {
+    let mut graph = common::rdf_star_graph();
+    graph.set_name(Iri::from_str("http://example.org/claims").unwrap().into());
+    let data_set = DataSet::from(graph);
+
+    let options = TrigWriterOptions::from(TurtleWriterOptions::default().with_annotations());
+    let writer = TrigWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&data_set).unwrap();
+    println!("# format: trig\n{}", output);
+
+    assert!(output.contains("ex:knows ex:bob {| ex:since \"2020\" |} .\n"));
+
+    let result = TrigReader::default().read_from_string(output).unwrap();
+    assert!(common::data_sets_isomorphic(&data_set, &result));
+}
+
+#[test]
+fn write_blank_nodes_shared_between_graphs_to_trig() {
+    let data_set = TrigReader::default()
+        .read_from_string(
+            r#"@prefix ex: <http://example.org/> .
+_:b1 ex:name "x" .
+GRAPH <http://ex/g> {
+    << ex:a ex:b ex:c >> ex:said _:b1 .
+}
+"#,
+        )
+        .unwrap();
+
+    let output = TrigWriter::default().write_to_string(&data_set).unwrap();
+    println!("# format: trig\n{}", output);
+
+    assert!(!output.contains("[]"));
+
+    let result = TrigReader::default().read_from_string(output).unwrap();
+    assert!(common::data_sets_isomorphic(&data_set, &result));
+}
@@ -1,8 +1,12 @@
 #![cfg(feature = "turtle")]
 
-use objio::ObjectWriter;
-use rdftk_io::turtle::{TurtleWriter, TurtleWriterOptions};
-use rdftk_iri::Iri;
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::Literal;
+use rdftk_core::model::statement::{BlankNode, Statement};
+use rdftk_io::turtle::{TurtleReader, TurtleWriter, TurtleWriterOptions};
+use rdftk_io::StatementSink;
+use rdftk_iri::{Iri, Namespace};
 use std::str::FromStr;
 
 mod common;
@@ -74,3 +78,161 @@ fn write_to_turtle_with_options() {
     assert!(output.contains("  ] ;\n"));
     assert!(output.contains("  dc:publisher   \"Wikipedia\" .\n"));
 }
+
+#[test]
+fn write_statements_to_sink() {
+    let graph = common::tony_benn_graph(Default::default());
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = TurtleWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    sink.prefix(
+        &Namespace::new_named("dc").unwrap(),
+        &Iri::from_str("http://purl.org/dc/elements/1.1/").unwrap(),
+    )
+    .unwrap();
+    for statement in graph.statements() {
+        sink.statement(statement).unwrap();
+    }
+    sink.finish().unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    println!("# format: Turtle\n{}", output);
+    assert!(output.starts_with("@prefix dc: <http://purl.org/dc/elements/1.1/> .\n\n"));
+    assert!(output.contains("dc:publisher \"Wikipedia\""));
+
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_quoted_triples_to_turtle() {
+    let graph = common::rdf_star_graph();
+
+    let writer = TurtleWriter::default();
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("<< ex:alice ex:knows ex:bob >>\n    ex:since \"2020\" .\n"));
+    assert!(!output.contains("rdf:subject"));
+
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_annotations_to_turtle() {
+    let graph = common::rdf_star_graph();
+
+    let options = TurtleWriterOptions::default().with_annotations();
+    let writer = TurtleWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("ex:knows ex:bob {| ex:since \"2020\" |} .\n"));
+    assert!(!output.contains("<< ex:alice"));
+
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_reified_statements_to_turtle() {
+    let graph = common::rdf_star_graph();
+
+    let options = TurtleWriterOptions::default().with_reified_statements();
+    let writer = TurtleWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(!output.contains("<<"));
+    assert!(output.contains("rdf:subject"));
+
+    // reification does not preserve nested statements, so compare with the reified graph.
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    let reified: Graph = TurtleReader::default()
+        .read_from_string(
+            TurtleWriter::default()
+                .with_options(TurtleWriterOptions::default().with_reified_statements())
+                .write_to_string(&read)
+                .unwrap(),
+        )
+        .unwrap();
+    assert!(common::graphs_isomorphic(&read, &reified));
+}
+
+#[test]
+fn write_quoted_triples_to_sink() {
+    let graph = common::rdf_star_graph();
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = TurtleWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    for statement in graph.statements() {
+        sink.statement(statement).unwrap();
+    }
+    sink.finish().unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    println!("{output}");
+    assert!(output.contains("<< <http://example.org/alice> <http://example.org/knows> <http://example.org/bob> >> <http://example.org/since> \"2020\" .\n"));
+
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_quoted_blank_node_by_label() {
+    let ex = |name: &str| Iri::from_str(&format!("http://example.org/{name}")).unwrap();
+    let blank = BlankNode::from_str("B1").unwrap();
+    let knows = Statement::new(ex("alice"), ex("knows"), blank.clone());
+    let graph = Graph::from(vec![
+        knows.clone(),
+        Statement::new(blank, ex("name"), Literal::plain("Bob")),
+        Statement::new(knows, ex("since"), Literal::plain("2020")),
+    ]);
+
+    let writer = TurtleWriter::default();
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains(
+        "<< <http://example.org/alice> <http://example.org/knows> _:B1 >>\n    <http://example.org/since> \"2020\" .\n"
+    ));
+
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_typed_literals_round_trip() {
+    let graph = common::typed_literals_graph();
+
+    let output = TurtleWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("2.5"));
+    assert!(output.contains("\"23\"^^xsd:long"));
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn write_escaped_literals_round_trip() {
+    let graph: Graph = TurtleReader::default()
+        .read_from_string(
+            "<http://example.org/a> <http://example.org/p> \"e\u{301} \\u0001 \\n\" .",
+        )
+        .unwrap();
+
+    let output = TurtleWriter::default().write_to_string(&graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("\"e\u{301} \\u0001 \\n\""));
+    let read: Graph = TurtleReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
@@ -1,7 +1,10 @@
 #![cfg(feature = "xml")]
 
-use objio::ObjectWriter;
-use rdftk_io::xml::{XmlOptions, XmlWriter};
+use objio::{ObjectReader, ObjectWriter};
+use rdftk_core::model::graph::Graph;
+#[cfg(feature = "turtle")]
+use rdftk_io::turtle::TurtleReader;
+use rdftk_io::xml::{XmlOptions, XmlReader, XmlWriter};
 
 mod common;
 
@@ -33,3 +36,150 @@ fn write_to_striped_xml() {
     let output = result.unwrap();
     println!("# format: XML\n{}", output);
 }
+
+#[test]
+fn write_to_abbreviated_xml() {
+    let graph = common::tony_benn_graph(Default::default());
+
+    let options = XmlOptions::default().abbreviated().pretty();
+    let writer = XmlWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: XML\n{}", output);
+
+    assert!(output.contains("xmlns:dc=\"http://purl.org/dc/elements/1.1/\""));
+    assert!(output.contains("<foaf:Person foaf:name=\"Tony Benn\""));
+    assert!(!output.contains("rdf:nodeID"));
+
+    let read: Graph = XmlReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+#[cfg(feature = "turtle")]
+fn write_abbreviated_collections_and_languages() {
+    let graph: Graph = TurtleReader::default()
+        .read_from_string(
+            r#"@prefix ex: <http://example.org/ns#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+ex:basket a ex:Basket ;
+    rdfs:label "panier"@fr ;
+    rdfs:comment "un panier de fruits"@fr, "a basket of fruit"@en ;
+    ex:weight "2.5"^^xsd:decimal ;
+    ex:note "line one\nline two" ;
+    ex:contents ( ex:banana ex:apple [ a ex:Pear ; ex:ripe "true"^^xsd:boolean ] ) ;
+    ex:owner [ ex:name "Alice" ; ex:knows _:bob ] ;
+    ex:maker _:bob .
+
+_:bob ex:name "Bob" .
+
+_:a ex:next _:b .
+_:b ex:next _:a .
+"#,
+        )
+        .unwrap();
+
+    let options = XmlOptions::default().abbreviated().pretty();
+    let writer = XmlWriter::default().with_options(options);
+
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: XML\n{}", output);
+
+    assert!(output.contains("xmlns:ex=\"http://example.org/ns#\""));
+    assert!(output.contains("<ex:Basket rdf:about=\"http://example.org/ns#basket\""));
+    assert!(output.contains("xml:lang=\"fr\""));
+    assert!(output.contains("rdf:parseType=\"Collection\""));
+    assert!(output.contains("rdf:parseType=\"Resource\""));
+    assert!(output.contains("<ex:Pear"));
+    assert!(!output.contains("rdf:first"));
+
+    let read: Graph = XmlReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+fn flat_xml_round_trips() {
+    let graph = common::tony_benn_graph(Default::default());
+
+    let writer = XmlWriter::default().with_options(XmlOptions::default().flat());
+    let output = writer.write_to_string(&graph).unwrap();
+    println!("# format: XML\n{}", output);
+
+    assert!(output.contains("<dc:title xmlns:dc=\"http://purl.org/dc/elements/1.1/\">"));
+
+    let read: Graph = XmlReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
+
+#[test]
+#[cfg(feature = "nt")]
+fn stream_ntriples_to_xml_sink() {
+    use rdftk_io::nt::NTripleReader;
+    use rdftk_io::StatementSink;
+
+    let source = r###"<http://example.org/a> <http://example.org/ns#p> "one" .
+<http://example.org/a> <http://example.org/ns#q> _:b1 .
+_:b1 <http://example.org/ns#p> "two"@en .
+"###;
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = XmlWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    for statement in NTripleReader::default().statements(source.as_bytes()) {
+        sink.statement(&statement.unwrap()).unwrap();
+    }
+    sink.finish().unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    println!("# format: XML\n{}", output);
+
+    let read: Graph = XmlReader::default().read_from_string(output).unwrap();
+    let expected: Graph = NTripleReader::default().read_from_string(source).unwrap();
+    assert!(common::graphs_isomorphic(&expected, &read));
+}
+
+#[test]
+fn local_names_are_xml_names() {
+    use rdftk_core::model::literal::Literal;
+    use rdftk_core::model::statement::{BlankNode, Statement};
+    use rdftk_io::StatementSink;
+    use rdftk_iri::Iri;
+    use std::str::FromStr;
+
+    let subject = Iri::from_str("http://example.org/a").unwrap();
+    let predicate = Iri::from_str("http://example.org/1p").unwrap();
+    let blank = BlankNode::generate();
+    let graph = Graph::from(vec![
+        Statement::new(subject.clone(), predicate.clone(), Literal::plain("one")),
+        Statement::new(subject, predicate.clone(), blank.clone()),
+        Statement::new(blank, predicate, Literal::plain("two")),
+    ]);
+
+    for options in [
+        XmlOptions::default().flat(),
+        XmlOptions::default().striped(),
+    ] {
+        let writer = XmlWriter::default().with_options(options);
+        let output = writer.write_to_string(&graph).unwrap();
+        println!("# format: XML\n{}", output);
+
+        assert!(!output.contains("<1p"));
+        let read: Graph = XmlReader::default().read_from_string(output).unwrap();
+        assert!(common::graphs_isomorphic(&graph, &read));
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut sink = XmlWriter::default().sink(&mut buffer);
+    sink.start().unwrap();
+    for statement in graph.statements() {
+        sink.statement(statement).unwrap();
+    }
+    sink.finish().unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    println!("# format: XML\n{}", output);
+
+    let read: Graph = XmlReader::default().read_from_string(output).unwrap();
+    assert!(common::graphs_isomorphic(&graph, &read));
+}
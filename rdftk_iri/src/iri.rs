@@ -40,7 +40,7 @@ pub struct Iri(url::Url);
 /// ## Specification
 ///
 /// ```text
-/// [67]  	IRIref	  ::=  	IRI_REF | PrefixedName
+/// [67]    IRIref    ::=   IRI_REF | PrefixedName
 /// ```
 ///
 /// For `IRI_REF` see [`Iri`], and for `PrefixedName` see [`PrefixedName`].
@@ -90,7 +90,7 @@ impl TryFrom<&str> for Iri {
 
     #[inline(always)]
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        Ok(Self::from_str(s)?)
+        Self::from_str(s)
     }
 }
 
@@ -321,7 +321,7 @@ impl Iri {
             && !self.0.path().ends_with("/")
             && self.0.query().is_none()
         {
-            if let Ok(name) = Name::from_str(self.0.path_segments().unwrap().last().unwrap()) {
+            if let Ok(name) = Name::from_str(self.0.path_segments().unwrap().next_back().unwrap()) {
                 let path = self.0.path();
                 let path = &path[0..path.len() - name.as_ref().len()];
                 Some((self.with_new_path(path), name))
@@ -478,7 +478,7 @@ impl Iri {
             .encode_lower(&mut uuid::Uuid::encode_buffer())
             .to_string();
         let path = format!("/.well-known/genid/{new_uuid}");
-        Ok(Self(self.0.join(&path).map_err(|e| Error::Url(e))?))
+        Ok(Self(self.0.join(&path).map_err(Error::Url)?))
     }
 }
 
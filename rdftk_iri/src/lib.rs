@@ -14,19 +14,19 @@
 //! From [SPARQL](https://www.w3.org/TR/rdf-sparql-query/) §[A.8 Grammar](https://www.w3.org/TR/2008/REC-rdf-sparql-query-20080115/#sparqlGrammar):
 //!
 //! ```text
-//! [68]  	PrefixedName	  ::=  	PNAME_LN | PNAME_NS
+//! [68]    PrefixedName      ::=   PNAME_LN | PNAME_NS
 //!
-//! [71]  	PNAME_NS	  ::=  	PN_PREFIX? ':'
-//! [72]  	PNAME_LN	  ::=  	PNAME_NS PN_LOCAL
-//! [95]  	PN_CHARS_BASE ::=  	[A-Z] | [a-z] | [#x00C0-#x00D6] | [#x00D8-#x00F6] | [#x00F8-#x02FF]
+//! [71]    PNAME_NS      ::=   PN_PREFIX? ':'
+//! [72]    PNAME_LN      ::=   PNAME_NS PN_LOCAL
+//! [95]    PN_CHARS_BASE ::=   [A-Z] | [a-z] | [#x00C0-#x00D6] | [#x00D8-#x00F6] | [#x00F8-#x02FF]
 //!                             | [#x0370-#x037D] | [#x037F-#x1FFF] | [#x200C-#x200D] | [#x2070-#x218F]
 //!                             | [#x2C00-#x2FEF] | [#x3001-#xD7FF] | [#xF900-#xFDCF] | [#xFDF0-#xFFFD]
 //!                             | [#x10000-#xEFFFF]
-//! [96]  	PN_CHARS_U	  ::=  	PN_CHARS_BASE | '_'
+//! [96]    PN_CHARS_U    ::=   PN_CHARS_BASE | '_'
 //!
-//! [98]  	PN_CHARS	  ::=  	PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
-//! [99]  	PN_PREFIX	  ::=  	PN_CHARS_BASE ((PN_CHARS|'.')* PN_CHARS)?
-//! [100]  	PN_LOCAL	  ::=  	( PN_CHARS_U | [0-9] ) ((PN_CHARS|'.')* PN_CHARS)?
+//! [98]    PN_CHARS      ::=   PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
+//! [99]    PN_PREFIX     ::=   PN_CHARS_BASE ((PN_CHARS|'.')* PN_CHARS)?
+//! [100]   PN_LOCAL      ::=   ( PN_CHARS_U | [0-9] ) ((PN_CHARS|'.')* PN_CHARS)?
 //!         /* Note that SPARQL local names allow leading digits while XML local names do not. */
 //! ```
 //!
@@ -262,13 +262,8 @@ impl IriPrefixMap {
     /// portion to the IRI associated with the prefix.
     ///
     pub fn expand(&self, local_name: &LocalName) -> Option<Iri> {
-        match self
-            .get_namespace(local_name.namespace())
-            .map(|ns| ns.make_name(local_name.name().clone()))
-        {
-            Some(expanded) => expanded,
-            None => None,
-        }
+        self.get_namespace(local_name.namespace())
+            .and_then(|ns| ns.make_name(local_name.name().clone()))
     }
 
     ///
@@ -298,10 +293,8 @@ impl IriPrefixMap {
         } else {
             return None;
         };
-        match self.get_prefix(&iri) {
-            None => None,
-            Some(prefix) => Some(LocalName::new(prefix.clone(), name)),
-        }
+        self.get_prefix(&iri)
+            .map(|prefix| LocalName::new(prefix.clone(), name))
     }
 
     ///
@@ -42,7 +42,7 @@ use serde::{Deserialize, Serialize};
 /// ## Specification
 ///
 /// ```text
-/// [68]  	PrefixedName	  ::=  	PNAME_LN | PNAME_NS
+/// [68]    PrefixedName      ::=   PNAME_LN | PNAME_NS
 /// ```
 /// For `PNAME_LN` see [`LocalName`], for `PNAME_NS` see [`Namespace`].
 ///
@@ -92,8 +92,8 @@ pub enum PrefixedName {
 /// ## Specification
 ///
 /// ```text
-/// [71]  	PNAME_NS	  ::=  	PN_PREFIX? ':'
-/// [99]  	PN_PREFIX	  ::=  	PN_CHARS_BASE ((PN_CHARS|'.')* PN_CHARS)?
+/// [71]    PNAME_NS      ::=   PN_PREFIX? ':'
+/// [99]    PN_PREFIX     ::=   PN_CHARS_BASE ((PN_CHARS|'.')* PN_CHARS)?
 /// ```
 ///
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -140,7 +140,7 @@ pub const NAMESPACE_DEFAULT_STRING: &str = ":";
 /// ## Specification
 ///
 /// ```text
-/// PN_LOCAL	  ::=  	( PN_CHARS_U | [0-9] ) ((PN_CHARS|'.')* PN_CHARS)?
+/// PN_LOCAL      ::=   ( PN_CHARS_U | [0-9] ) ((PN_CHARS|'.')* PN_CHARS)?
 /// ```
 ///
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -178,7 +178,7 @@ pub struct Name(String);
 /// ## Specification
 ///
 /// ```text
-/// [72]  	PNAME_LN	  ::=  	PNAME_NS PN_LOCAL
+/// [72]    PNAME_LN      ::=   PNAME_NS PN_LOCAL
 /// ```
 ///
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -474,7 +474,7 @@ impl FromStr for LocalName {
                 name: Name::from_str(&s[separator + 1..])?,
             })
         } else {
-            Err(NameParseError::MissingSeparator(s.to_string()).into())
+            Err(NameParseError::MissingSeparator(s.to_string()))
         }
     }
 }
@@ -569,7 +569,8 @@ impl LocalName {
 // ------------------------------------------------------------------------------------------------
 
 fn is_pn_chars_base(c: char) -> bool {
-    match c {
+    matches!(
+        c,
         'A'..='Z'
         | 'a'..='z'
         | '\u{00C0}'..='\u{00D6}'
@@ -583,9 +584,8 @@ fn is_pn_chars_base(c: char) -> bool {
         | '\u{3001}'..='\u{D7FF}'
         | '\u{F900}'..='\u{FDCF}'
         | '\u{FDF0}'..='\u{FFFD}'
-        | '\u{10000}'..='\u{EFFFF}' => true,
-        _ => false,
-    }
+        | '\u{10000}'..='\u{EFFFF}'
+    )
 }
 
 fn is_pn_chars_u(c: char) -> bool {
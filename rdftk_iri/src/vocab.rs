@@ -32,7 +32,7 @@ use core::str::FromStr;
 /// pub const MY_NS: Vocabulary = Vocabulary::new("ex", "https://example.org/ns#")
 ///     .with_description("An example vocabulary.");
 ///
-/// let map = IriPrefixMap::default().with_vocabulary(&MY_NS);
+/// let map = IriPrefixMap::empty().with_vocabulary(&MY_NS);
 /// assert_eq!(map.len(), 1);
 /// ```
 ///
@@ -86,7 +86,7 @@ pub const VOCABULARY_DC_ELEMENTS: Vocabulary =
 
 ///
 /// Dublin Core Metadata Initiative's "Terms" namespace.
-////
+///
 /// ## Details
 ///
 /// * **prefix** -- "dcterms"
@@ -393,7 +393,6 @@ impl Vocabulary {
     ///
     /// This method panics if the `iri` string is not a valid value for `Iri`.
     ///
-
     pub fn iri_as_iri(&self) -> Iri {
         Iri::from_str(self.iri).expect("provided string is not a valid Iri")
     }
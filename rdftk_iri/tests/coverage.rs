@@ -218,7 +218,7 @@ fn iri_extra_genid_format() {
 
 #[test]
 fn prefix_map_empty_and_iterators() {
-    let mut map = IriPrefixMap::default();
+    let mut map = IriPrefixMap::empty();
     assert!(map.is_empty());
     assert_eq!(map.len(), 0);
     assert_eq!(map.mappings().count(), 0);
@@ -236,7 +236,7 @@ fn prefix_map_empty_and_iterators() {
 
 #[test]
 fn prefix_map_insert_vocabulary_and_remove_clear() {
-    let mut map = IriPrefixMap::default();
+    let mut map = IriPrefixMap::empty();
     map.insert_vocabulary(&VOCABULARY_RDF);
     assert_eq!(map.len(), 1);
 
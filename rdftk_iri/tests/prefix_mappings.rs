@@ -2,7 +2,7 @@ use rdftk_iri::{Iri, IriPrefixMap, LocalName, Name, Namespace};
 use std::str::FromStr;
 
 fn make_mappings() -> IriPrefixMap {
-    IriPrefixMap::common().with_default(Iri::from_str("http://xmlns.com/foaf/0.1/").unwrap())
+    IriPrefixMap::default().with_default(Iri::from_str("http://xmlns.com/foaf/0.1/").unwrap())
 }
 
 #[test]
@@ -52,7 +52,7 @@ fn test_mapping_expand() {
         Some(Iri::from_str("http://www.w3.org/1999/02/22-rdf-syntax-ns#Bag").unwrap())
     );
     assert_eq!(
-        mappings.expand(&&LocalName::new_in_default(Name::new_unchecked("knows"))),
+        mappings.expand(&LocalName::new_in_default(Name::new_unchecked("knows"))),
         Some(Iri::from_str("http://xmlns.com/foaf/0.1/knows").unwrap())
     );
     assert_eq!(
@@ -132,7 +132,7 @@ macro_rules! namespace {
                 let _ = cache.insert(
                     $name.to_string(),
                     (
-                        <::rdftk_iri::Iri as ::rdftk_iri::IriExtra>::make_name(
+                        <::rdftk_iri::Iri>::make_name(
                                 &NS_IRI,
                                 <::rdftk_iri::Name as ::std::str::FromStr>::from_str($name).unwrap()
                             ).unwrap(),
@@ -210,6 +210,8 @@ pub mod foaf;
 
 pub mod geo;
 
+pub mod log;
+
 pub mod owl;
 
 pub mod rdf;
@@ -228,7 +230,6 @@ pub mod xsd;
 mod tests {
     #![allow(unreachable_pub)]
 
-    use super::*;
     use rdftk_iri::Iri;
     use std::str::FromStr;
 
@@ -251,14 +252,14 @@ mod tests {
 
     #[test]
     fn test_expand_member_foo() {
-        assert_eq!(foo().to_string(), "heep://schema/com/p#Foo");
+        assert_eq!(format!("{:#}", foo()), "heep://schema/com/p#Foo");
         assert_eq!(foo_str(), "Foo");
         assert_eq!(foo_qname(), "p:Foo");
     }
 
     #[test]
     fn test_expand_member_bar() {
-        assert_eq!(bar().to_string(), "heep://schema/com/p#Bar");
+        assert_eq!(format!("{:#}", bar()), "heep://schema/com/p#Bar");
         assert_eq!(bar_str(), "Bar");
         assert_eq!(bar_qname(), "p:Bar");
     }
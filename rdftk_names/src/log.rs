@@ -0,0 +1,25 @@
+/*!
+Functions that create IRIs for the W3C [SWAP log](https://www.w3.org/2000/10/swap/log) namespace,
+used by Notation3 for implication and formula operations.
+*/
+
+namespace! {
+    "log",
+    "http://www.w3.org/2000/10/swap/log#",
+    {
+        implies, "implies",
+        includes, "includes",
+        not_includes, "notIncludes",
+        semantics, "semantics",
+        conclusion, "conclusion",
+        conjunction, "conjunction",
+        content, "content",
+        equal_to, "equalTo",
+        not_equal_to, "notEqualTo",
+        n3_string, "n3String",
+        parsed_as_n3, "parsedAsN3",
+        uri, "uri",
+        truth, "Truth",
+        chaff, "Chaff"
+    }
+}